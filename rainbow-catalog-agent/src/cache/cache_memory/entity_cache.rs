@@ -0,0 +1,181 @@
+use crate::cache::cache_memory::memory_store::MemoryCacheStore;
+use crate::cache::cache_traits::entity_cache_trait::EntityCacheTrait;
+use crate::cache::cache_traits::lookup_cache_trait::LookupCacheTrait;
+use crate::cache::cache_traits::utils_trait::UtilsCacheTrait;
+use crate::cache::cache_traits::DESIRED_CACHE_TTL;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::marker::PhantomData;
+use std::sync::Arc;
+use std::time::Duration;
+use urn::Urn;
+
+/// Same keys and semantics as the Redis entity caches, over a MemoryCacheStore
+pub struct EntityCacheForMemory<D> {
+    store: Arc<MemoryCacheStore>,
+    entity_name: &'static str,
+    _dto: PhantomData<fn() -> D>,
+}
+
+impl<D> EntityCacheForMemory<D> {
+    pub fn new(store: Arc<MemoryCacheStore>, entity_name: &'static str) -> Self {
+        Self { store, entity_name, _dto: PhantomData }
+    }
+
+    fn ttl() -> Duration {
+        Duration::from_secs(DESIRED_CACHE_TTL as u64)
+    }
+}
+
+impl<D> EntityCacheForMemory<D>
+where
+    D: DeserializeOwned + Send + Sync,
+{
+    fn hydrate_from_multiple_keys(&self, keys: Vec<String>) -> anyhow::Result<Vec<D>> {
+        let mut results = Vec::with_capacity(keys.len());
+        for value in self.store.get_many(&keys) {
+            results.push(serde_json::from_value(value)?);
+        }
+        Ok(results)
+    }
+
+    fn hydrate_from_single_key(&self, key: &str) -> anyhow::Result<Option<D>> {
+        match self.store.get(key) {
+            Some(value) => Ok(Some(serde_json::from_value(value)?)),
+            None => Ok(None),
+        }
+    }
+}
+
+impl<D> UtilsCacheTrait for EntityCacheForMemory<D>
+where
+    D: DeserializeOwned + Send + Sync,
+{
+    type Dto = D;
+}
+
+#[async_trait::async_trait]
+impl<D> LookupCacheTrait<D> for EntityCacheForMemory<D>
+where
+    D: Serialize + DeserializeOwned + Send + Sync + 'static,
+{
+    async fn get_by_relation(
+        &self,
+        parent_name: &str,
+        parent_id: &Urn,
+        limit: Option<u64>,
+        page: Option<u64>,
+    ) -> anyhow::Result<Vec<D>> {
+        tracing::debug!("memory cache: get by relation");
+        let lookup_key = self.format_key_name_lookup(self.entity_name, parent_name, parent_id);
+        let (start, stop) = self.compute_pagination_range(limit, page);
+        let keys = self.store.rev_range(&lookup_key, start, stop);
+        self.hydrate_from_multiple_keys(keys)
+    }
+
+    async fn add_to_relation(
+        &self,
+        parent_name: &str,
+        parent_id: &Urn,
+        child_id: &Urn,
+        score: f64,
+    ) -> anyhow::Result<()> {
+        tracing::debug!("memory cache: add to relation");
+        let lookup_key = self.format_key_name_lookup(self.entity_name, parent_name, parent_id);
+        let child_key = self.format_key_name_with_id(self.entity_name, child_id);
+        self.store.add_to_sorted_set(&lookup_key, &child_key, score);
+        Ok(())
+    }
+
+    async fn remove_from_relation(
+        &self,
+        parent_name: &str,
+        parent_id: &Urn,
+        child_id: &Urn,
+    ) -> anyhow::Result<()> {
+        tracing::debug!("memory cache: remove from relation");
+        let lookup_key = self.format_key_name_lookup(self.entity_name, parent_name, parent_id);
+        let child_key = self.format_key_name_with_id(self.entity_name, child_id);
+        self.store.remove_from_sorted_set(&lookup_key, &child_key);
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl<D> EntityCacheTrait<D> for EntityCacheForMemory<D>
+where
+    D: Serialize + DeserializeOwned + Send + Sync + 'static,
+{
+    async fn get_single(&self, id: &Urn) -> anyhow::Result<Option<D>> {
+        tracing::debug!("memory cache: get single");
+        let key = self.format_key_name_with_id(self.entity_name, id);
+        self.hydrate_from_single_key(&key)
+    }
+
+    async fn set_single(&self, id: &Urn, model: &D) -> anyhow::Result<()> {
+        tracing::debug!("memory cache: set single");
+        let key = self.format_key_name_with_id(self.entity_name, id);
+        self.store.set(&key, serde_json::to_value(model)?, Self::ttl());
+        Ok(())
+    }
+
+    async fn delete_single(&self, id: &Urn) -> anyhow::Result<()> {
+        tracing::debug!("memory cache: delete single");
+        let key = self.format_key_name_with_id(self.entity_name, id);
+        self.store.delete(&key);
+        Ok(())
+    }
+
+    async fn get_main(&self) -> anyhow::Result<Option<D>> {
+        tracing::debug!("memory cache: get main");
+        let main_key = self.format_key_name_main(self.entity_name);
+        match self.store.get_pointer(&main_key) {
+            Some(key) => self.hydrate_from_single_key(&key),
+            None => Ok(None),
+        }
+    }
+
+    async fn set_main(&self, id: &Urn, model: &D) -> anyhow::Result<()> {
+        tracing::debug!("memory cache: set main");
+        let main_key = self.format_key_name_main(self.entity_name);
+        let key = self.format_key_name_with_id(self.entity_name, id);
+        self.set_single(id, model).await?;
+        self.store.set_pointer(&main_key, &key);
+        Ok(())
+    }
+
+    async fn get_collection(
+        &self,
+        limit: Option<u64>,
+        page: Option<u64>,
+    ) -> anyhow::Result<Vec<D>> {
+        tracing::debug!("memory cache: get collection all");
+        let collection_key = self.format_key_name_all(self.entity_name);
+        let (start, stop) = self.compute_pagination_range(limit, page);
+        let keys = self.store.rev_range(&collection_key, start, stop);
+        self.hydrate_from_multiple_keys(keys)
+    }
+
+    async fn add_to_collection(&self, id: &Urn, score: f64) -> anyhow::Result<()> {
+        tracing::debug!("memory cache: add to collection all");
+        let key = self.format_key_name_with_id(self.entity_name, id);
+        let collection_key = self.format_key_name_all(self.entity_name);
+        self.store.add_to_sorted_set(&collection_key, &key, score);
+        Ok(())
+    }
+
+    async fn remove_from_collection(&self, id: &Urn) -> anyhow::Result<()> {
+        tracing::debug!("memory cache: remove from collection all");
+        let key = self.format_key_name_with_id(self.entity_name, id);
+        let collection_key = self.format_key_name_all(self.entity_name);
+        self.store.remove_from_sorted_set(&collection_key, &key);
+        Ok(())
+    }
+
+    async fn get_batch(&self, ids: &Vec<Urn>) -> anyhow::Result<Vec<D>> {
+        tracing::debug!("memory cache: get batch");
+        let keys: Vec<String> =
+            ids.iter().map(|id| self.format_key_name_with_id(self.entity_name, id)).collect();
+        self.hydrate_from_multiple_keys(keys)
+    }
+}
@@ -0,0 +1,218 @@
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, Mutex, MutexGuard, OnceLock};
+use std::time::{Duration, Instant};
+
+/// Max number of entities kept by the process wide store before the least recently used is evicted
+pub(crate) const MEMORY_CACHE_CAPACITY: usize = 10_000;
+
+struct MemoryEntry {
+    value: Value,
+    expires_at: Instant,
+    last_used: u64,
+}
+
+#[derive(Default)]
+struct MemoryCacheState {
+    entries: HashMap<String, MemoryEntry>,
+    /// last_used tick -> key, the first one is the next to evict
+    recency: BTreeMap<u64, String>,
+    pointers: HashMap<String, String>,
+    sorted_sets: HashMap<String, HashMap<String, f64>>,
+    clock: u64,
+}
+
+impl MemoryCacheState {
+    fn tick(&mut self) -> u64 {
+        self.clock += 1;
+        self.clock
+    }
+
+    fn remove_entry(&mut self, key: &str) {
+        if let Some(entry) = self.entries.remove(key) {
+            self.recency.remove(&entry.last_used);
+        }
+    }
+}
+
+/// Bounded LRU with TTL standing in for the Redis server.
+/// Entities expire and get evicted, while main pointers and sorted sets only hold keys,
+/// so a key whose entity is gone is skipped on hydration as it happens with Redis.
+pub struct MemoryCacheStore {
+    capacity: usize,
+    state: Mutex<MemoryCacheState>,
+}
+
+impl MemoryCacheStore {
+    pub fn new(capacity: usize) -> Self {
+        Self { capacity: capacity.max(1), state: Mutex::new(MemoryCacheState::default()) }
+    }
+
+    /// Store shared by every worker of the process, the same way they share a Redis server
+    pub fn shared() -> Arc<Self> {
+        static SHARED: OnceLock<Arc<MemoryCacheStore>> = OnceLock::new();
+        SHARED.get_or_init(|| Arc::new(Self::new(MEMORY_CACHE_CAPACITY))).clone()
+    }
+
+    fn state(&self) -> MutexGuard<'_, MemoryCacheState> {
+        self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    pub fn get(&self, key: &str) -> Option<Value> {
+        let mut guard = self.state();
+        let state = &mut *guard;
+        if state.entries.get(key)?.expires_at <= Instant::now() {
+            state.remove_entry(key);
+            return None;
+        }
+        let tick = state.tick();
+        let entry = state.entries.get_mut(key)?;
+        let previous = std::mem::replace(&mut entry.last_used, tick);
+        state.recency.remove(&previous);
+        state.recency.insert(tick, key.to_string());
+        Some(entry.value.clone())
+    }
+
+    /// Values of the keys still alive, in the same order
+    pub fn get_many(&self, keys: &[String]) -> Vec<Value> {
+        keys.iter().filter_map(|key| self.get(key)).collect()
+    }
+
+    pub fn set(&self, key: &str, value: Value, ttl: Duration) {
+        let mut guard = self.state();
+        let state = &mut *guard;
+        let tick = state.tick();
+        let entry = MemoryEntry { value, expires_at: Instant::now() + ttl, last_used: tick };
+        if let Some(previous) = state.entries.insert(key.to_string(), entry) {
+            state.recency.remove(&previous.last_used);
+        }
+        state.recency.insert(tick, key.to_string());
+        while state.entries.len() > self.capacity {
+            match state.recency.pop_first() {
+                Some((_, evicted)) => {
+                    state.entries.remove(&evicted);
+                }
+                None => break,
+            }
+        }
+    }
+
+    pub fn delete(&self, key: &str) {
+        self.state().remove_entry(key);
+    }
+
+    pub fn get_pointer(&self, pointer_key: &str) -> Option<String> {
+        self.state().pointers.get(pointer_key).cloned()
+    }
+
+    pub fn set_pointer(&self, pointer_key: &str, key: &str) {
+        self.state().pointers.insert(pointer_key.to_string(), key.to_string());
+    }
+
+    pub fn add_to_sorted_set(&self, set_key: &str, member: &str, score: f64) {
+        self.state()
+            .sorted_sets
+            .entry(set_key.to_string())
+            .or_default()
+            .insert(member.to_string(), score);
+    }
+
+    pub fn remove_from_sorted_set(&self, set_key: &str, member: &str) {
+        let mut state = self.state();
+        if let Some(set) = state.sorted_sets.get_mut(set_key) {
+            set.remove(member);
+            if set.is_empty() {
+                state.sorted_sets.remove(set_key);
+            }
+        }
+    }
+
+    /// Members from the highest score down with ZREVRANGE semantics, both bounds inclusive
+    /// and negative ones counted from the end
+    pub fn rev_range(&self, set_key: &str, start: isize, stop: isize) -> Vec<String> {
+        let state = self.state();
+        let Some(set) = state.sorted_sets.get(set_key) else {
+            return vec![];
+        };
+        let mut members: Vec<(&String, &f64)> = set.iter().collect();
+        members.sort_by(|a, b| b.1.total_cmp(a.1).then_with(|| b.0.cmp(a.0)));
+
+        let len = members.len() as isize;
+        let start = if start < 0 { (len + start).max(0) } else { start };
+        let stop = if stop < 0 { len + stop } else { stop.min(len - 1) };
+        if start > stop || start >= len {
+            return vec![];
+        }
+        members[start as usize..=stop as usize]
+            .iter()
+            .map(|(member, _)| (*member).clone())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test_memory_store {
+    use super::*;
+    use serde_json::json;
+
+    const TTL: Duration = Duration::from_secs(60);
+
+    #[test]
+    fn test_lru_eviction() {
+        let store = MemoryCacheStore::new(2);
+        store.set("a", json!(1), TTL);
+        store.set("b", json!(2), TTL);
+
+        // touching "a" leaves "b" as the least recently used
+        assert_eq!(store.get("a"), Some(json!(1)));
+        store.set("c", json!(3), TTL);
+
+        assert_eq!(store.get("a"), Some(json!(1)));
+        assert_eq!(store.get("b"), None);
+        assert_eq!(store.get("c"), Some(json!(3)));
+    }
+
+    #[test]
+    fn test_overwrite_does_not_evict() {
+        let store = MemoryCacheStore::new(2);
+        store.set("a", json!(1), TTL);
+        store.set("b", json!(2), TTL);
+        store.set("a", json!(10), TTL);
+
+        assert_eq!(store.get("a"), Some(json!(10)));
+        assert_eq!(store.get("b"), Some(json!(2)));
+    }
+
+    #[test]
+    fn test_ttl_expiry() {
+        let store = MemoryCacheStore::new(10);
+        store.set("short", json!("gone"), Duration::from_millis(20));
+        store.set("long", json!("kept"), TTL);
+
+        std::thread::sleep(Duration::from_millis(40));
+
+        assert_eq!(store.get("short"), None);
+        assert_eq!(store.get("long"), Some(json!("kept")));
+        assert_eq!(
+            store.get_many(&["short".to_string(), "long".to_string()]),
+            vec![json!("kept")]
+        );
+    }
+
+    #[test]
+    fn test_rev_range() {
+        let store = MemoryCacheStore::new(10);
+        store.add_to_sorted_set("set", "low", 1.0);
+        store.add_to_sorted_set("set", "high", 3.0);
+        store.add_to_sorted_set("set", "mid", 2.0);
+
+        assert_eq!(store.rev_range("set", 0, -1), vec!["high", "mid", "low"]);
+        assert_eq!(store.rev_range("set", 1, 1), vec!["mid"]);
+        assert_eq!(store.rev_range("set", 2, 10), vec!["low"]);
+        assert!(store.rev_range("set", 3, 5).is_empty());
+        assert!(store.rev_range("missing", 0, -1).is_empty());
+
+        store.remove_from_sorted_set("set", "high");
+        assert_eq!(store.rev_range("set", 0, 0), vec!["mid"]);
+    }
+}
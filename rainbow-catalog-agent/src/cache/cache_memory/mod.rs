@@ -0,0 +1,3 @@
+pub(crate) mod entity_cache;
+pub(crate) mod memory_store;
+pub(crate) mod peer_catalog_cache;
@@ -0,0 +1,41 @@
+use crate::cache::cache_memory::memory_store::MemoryCacheStore;
+use crate::cache::cache_traits::peer_catalog_cache_trait::PeerCatalogCacheTrait;
+use crate::cache::cache_traits::utils_trait::UtilsCacheTrait;
+use crate::cache::cache_traits::PEER_CATALOG_DESIRED_CACHE_TTL;
+use crate::protocols::dsp::types::catalog_definition::Catalog;
+use std::sync::Arc;
+use std::time::Duration;
+
+pub struct DcatCatalogCacheForMemory {
+    store: Arc<MemoryCacheStore>,
+}
+
+impl DcatCatalogCacheForMemory {
+    pub fn new(store: Arc<MemoryCacheStore>) -> Self {
+        Self { store }
+    }
+}
+
+impl UtilsCacheTrait for DcatCatalogCacheForMemory {
+    type Dto = Catalog;
+}
+
+#[async_trait::async_trait]
+impl PeerCatalogCacheTrait for DcatCatalogCacheForMemory {
+    async fn get_catalog(&self, participant_id: &String) -> anyhow::Result<Option<Catalog>> {
+        tracing::debug!("memory cache: get peer catalog");
+        let key = self.format_key_name_with_string("peer-catalog", participant_id);
+        match self.store.get(&key) {
+            Some(value) => Ok(Some(serde_json::from_value(value)?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn set_catalog(&self, participant_id: &String, catalog: &Catalog) -> anyhow::Result<()> {
+        tracing::debug!("memory cache: set peer catalog");
+        let key = self.format_key_name_with_string("peer-catalog", participant_id);
+        let ttl = Duration::from_secs(PEER_CATALOG_DESIRED_CACHE_TTL as u64);
+        self.store.set(&key, serde_json::to_value(catalog)?, ttl);
+        Ok(())
+    }
+}
@@ -0,0 +1,102 @@
+use crate::cache::cache_traits::entity_cache_trait::EntityCacheTrait;
+use crate::cache::cache_traits::lookup_cache_trait::LookupCacheTrait;
+use std::marker::PhantomData;
+use urn::Urn;
+
+/// Entity cache that stores nothing, every read is a miss and falls through to the repo
+pub struct EntityCacheForNoop<D> {
+    _dto: PhantomData<fn() -> D>,
+}
+
+impl<D> EntityCacheForNoop<D> {
+    pub fn new() -> Self {
+        Self { _dto: PhantomData }
+    }
+}
+
+impl<D> Default for EntityCacheForNoop<D> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait::async_trait]
+impl<D> LookupCacheTrait<D> for EntityCacheForNoop<D>
+where
+    D: Send + Sync + 'static,
+{
+    async fn get_by_relation(
+        &self,
+        _parent_name: &str,
+        _parent_id: &Urn,
+        _limit: Option<u64>,
+        _page: Option<u64>,
+    ) -> anyhow::Result<Vec<D>> {
+        Ok(vec![])
+    }
+
+    async fn add_to_relation(
+        &self,
+        _parent_name: &str,
+        _parent_id: &Urn,
+        _child_id: &Urn,
+        _score: f64,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    async fn remove_from_relation(
+        &self,
+        _parent_name: &str,
+        _parent_id: &Urn,
+        _child_id: &Urn,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl<D> EntityCacheTrait<D> for EntityCacheForNoop<D>
+where
+    D: Send + Sync + 'static,
+{
+    async fn get_single(&self, _id: &Urn) -> anyhow::Result<Option<D>> {
+        Ok(None)
+    }
+
+    async fn set_single(&self, _id: &Urn, _model: &D) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    async fn delete_single(&self, _id: &Urn) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    async fn get_main(&self) -> anyhow::Result<Option<D>> {
+        Ok(None)
+    }
+
+    async fn set_main(&self, _id: &Urn, _model: &D) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    async fn get_collection(
+        &self,
+        _limit: Option<u64>,
+        _page: Option<u64>,
+    ) -> anyhow::Result<Vec<D>> {
+        Ok(vec![])
+    }
+
+    async fn add_to_collection(&self, _id: &Urn, _score: f64) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    async fn remove_from_collection(&self, _id: &Urn) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    async fn get_batch(&self, _ids: &Vec<Urn>) -> anyhow::Result<Vec<D>> {
+        Ok(vec![])
+    }
+}
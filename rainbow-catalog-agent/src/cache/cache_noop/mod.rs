@@ -0,0 +1,2 @@
+pub(crate) mod entity_cache;
+pub(crate) mod peer_catalog_cache;
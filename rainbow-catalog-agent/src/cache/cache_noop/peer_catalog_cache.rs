@@ -0,0 +1,26 @@
+use crate::cache::cache_traits::peer_catalog_cache_trait::PeerCatalogCacheTrait;
+use crate::protocols::dsp::types::catalog_definition::Catalog;
+
+#[derive(Default)]
+pub struct DcatCatalogCacheForNoop {}
+
+impl DcatCatalogCacheForNoop {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+#[async_trait::async_trait]
+impl PeerCatalogCacheTrait for DcatCatalogCacheForNoop {
+    async fn get_catalog(&self, _participant_id: &String) -> anyhow::Result<Option<Catalog>> {
+        Ok(None)
+    }
+
+    async fn set_catalog(
+        &self,
+        _participant_id: &String,
+        _catalog: &Catalog,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
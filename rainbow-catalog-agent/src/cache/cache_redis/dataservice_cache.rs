@@ -65,6 +65,11 @@ mod test_dataservice {
                 dct_identifier: Some(ds_id.to_string()),
                 dct_modified: None,
                 dspace_main_data_service: false,
+                dcat_keyword: None,
+                dcat_theme: None,
+                dct_license: None,
+                dct_access_rights: None,
+                dcat_contact_point: None,
             },
         };
 
@@ -0,0 +1,53 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use crate::cache::cache_memory::memory_store::MemoryCacheStore;
+use crate::cache::factory_memory::CatalogAgentCacheForMemory;
+use crate::cache::factory_noop::CatalogAgentCacheForNoop;
+use crate::cache::factory_redis::CatalogAgentCacheForRedis;
+use crate::cache::factory_trait::CatalogAgentCacheTrait;
+use anyhow::bail;
+use rainbow_common::config::services::CatalogConfig;
+use rainbow_common::config::traits::CacheConfigTrait;
+use rainbow_common::config::types::cache::CacheType;
+use std::sync::Arc;
+
+/// Cache backend selected by `cache_type`.
+/// Memory is shared by all the workers of the process, Noop sends every read to the repo.
+pub async fn create_catalog_agent_cache(
+    config: &CatalogConfig,
+) -> anyhow::Result<Arc<dyn CatalogAgentCacheTrait>> {
+    let cache: Arc<dyn CatalogAgentCacheTrait> = match config.cache_config().cache_type {
+        CacheType::Redis => {
+            let cache_connection_url = config.get_full_cache_url();
+            let redis_client = redis::Client::open(cache_connection_url)?;
+            let redis_connection = redis_client
+                .get_multiplexed_async_connection()
+                .await
+                .expect("Redis connection failed");
+            Arc::new(CatalogAgentCacheForRedis::create_repo(redis_connection))
+        }
+        CacheType::Memory => {
+            Arc::new(CatalogAgentCacheForMemory::create_repo(MemoryCacheStore::shared()))
+        }
+        CacheType::Noop => Arc::new(CatalogAgentCacheForNoop::create_repo()),
+        CacheType::Memcached => bail!("Memcached cache is not supported by the catalog agent"),
+    };
+    Ok(cache)
+}
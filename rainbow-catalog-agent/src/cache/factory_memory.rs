@@ -0,0 +1,75 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use crate::cache::cache_memory::entity_cache::EntityCacheForMemory;
+use crate::cache::cache_memory::memory_store::MemoryCacheStore;
+use crate::cache::cache_memory::peer_catalog_cache::DcatCatalogCacheForMemory;
+use crate::cache::cache_traits::entity_cache_trait::EntityCacheTrait;
+use crate::cache::cache_traits::peer_catalog_cache_trait::PeerCatalogCacheTrait;
+use crate::cache::factory_trait::CatalogAgentCacheTrait;
+use crate::{CatalogDto, DataServiceDto, DatasetDto, DistributionDto, OdrlPolicyDto};
+use std::sync::Arc;
+
+pub struct CatalogAgentCacheForMemory {
+    catalog_repo: Arc<dyn EntityCacheTrait<CatalogDto>>,
+    dataservice_repo: Arc<dyn EntityCacheTrait<DataServiceDto>>,
+    dataset_repo: Arc<dyn EntityCacheTrait<DatasetDto>>,
+    distribution_repo: Arc<dyn EntityCacheTrait<DistributionDto>>,
+    odrl_offer_repo: Arc<dyn EntityCacheTrait<OdrlPolicyDto>>,
+    peer_catalog_repo: Arc<dyn PeerCatalogCacheTrait>,
+}
+
+impl CatalogAgentCacheForMemory {
+    pub fn create_repo(store: Arc<MemoryCacheStore>) -> Self {
+        Self {
+            catalog_repo: Arc::new(EntityCacheForMemory::new(store.clone(), "catalogs")),
+            dataservice_repo: Arc::new(EntityCacheForMemory::new(store.clone(), "data-services")),
+            dataset_repo: Arc::new(EntityCacheForMemory::new(store.clone(), "datasets")),
+            distribution_repo: Arc::new(EntityCacheForMemory::new(store.clone(), "distributions")),
+            odrl_offer_repo: Arc::new(EntityCacheForMemory::new(store.clone(), "odrl-offers")),
+            peer_catalog_repo: Arc::new(DcatCatalogCacheForMemory::new(store)),
+        }
+    }
+}
+
+impl CatalogAgentCacheTrait for CatalogAgentCacheForMemory {
+    fn get_catalog_cache(&self) -> Arc<dyn EntityCacheTrait<CatalogDto>> {
+        self.catalog_repo.clone()
+    }
+
+    fn get_dataservice_cache(&self) -> Arc<dyn EntityCacheTrait<DataServiceDto>> {
+        self.dataservice_repo.clone()
+    }
+
+    fn get_dataset_cache(&self) -> Arc<dyn EntityCacheTrait<DatasetDto>> {
+        self.dataset_repo.clone()
+    }
+
+    fn get_distribution_cache(&self) -> Arc<dyn EntityCacheTrait<DistributionDto>> {
+        self.distribution_repo.clone()
+    }
+
+    fn get_odrl_offer_cache(&self) -> Arc<dyn EntityCacheTrait<OdrlPolicyDto>> {
+        self.odrl_offer_repo.clone()
+    }
+
+    fn get_peer_catalog_cache(&self) -> Arc<dyn PeerCatalogCacheTrait> {
+        self.peer_catalog_repo.clone()
+    }
+}
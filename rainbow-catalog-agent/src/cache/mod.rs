@@ -1,4 +1,11 @@
+pub(crate) mod cache_memory;
+pub(crate) mod cache_noop;
 pub(crate) mod cache_redis;
 pub(crate) mod cache_traits;
+pub(crate) mod factory;
+pub(crate) mod factory_memory;
+pub(crate) mod factory_noop;
 pub(crate) mod factory_redis;
 pub(crate) mod factory_trait;
+#[cfg(test)]
+mod test_cache_backends;
@@ -25,7 +25,7 @@ use urn::{Urn, UrnBuilder};
 #[sea_orm(table_name = "catalog_catalogs")]
 #[serde(rename_all = "camelCase")]
 pub struct Model {
-    #[sea_orm(primary_key)]
+    #[sea_orm(primary_key, auto_increment = false)]
     pub id: String,
     pub foaf_home_page: Option<String>,
     pub dct_conforms_to: Option<String>,
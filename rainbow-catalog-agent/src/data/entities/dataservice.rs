@@ -25,7 +25,7 @@ use urn::{Urn, UrnBuilder};
 #[sea_orm(table_name = "catalog_data_services")]
 #[serde(rename_all = "camelCase")]
 pub struct Model {
-    #[sea_orm(primary_key)]
+    #[sea_orm(primary_key, auto_increment = false)]
     pub id: String,
     pub dcat_endpoint_description: Option<String>,
     pub dcat_endpoint_url: String,
@@ -38,6 +38,11 @@ pub struct Model {
     pub dct_description: Option<String>,
     pub catalog_id: String,
     pub dspace_main_data_service: bool,
+    pub dcat_keyword: Option<Json>,
+    pub dcat_theme: Option<Json>,
+    pub dct_license: Option<String>,
+    pub dct_access_rights: Option<String>,
+    pub dcat_contact_point: Option<String>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -66,6 +71,16 @@ impl Related<super::odrl_offer::Entity> for Entity {
 
 impl ActiveModelBehavior for ActiveModel {}
 
+impl Model {
+    pub fn keywords(&self) -> Vec<String> {
+        super::string_list(&self.dcat_keyword)
+    }
+
+    pub fn themes(&self) -> Vec<String> {
+        super::string_list(&self.dcat_theme)
+    }
+}
+
 #[derive(Clone)]
 pub struct NewDataServiceModel {
     pub id: Option<Urn>,
@@ -77,6 +92,11 @@ pub struct NewDataServiceModel {
     pub dct_description: Option<String>,
     pub catalog_id: Urn,
     pub dspace_main_data_service: bool,
+    pub dcat_keyword: Option<Vec<String>>,
+    pub dcat_theme: Option<Vec<String>>,
+    pub dct_license: Option<String>,
+    pub dct_access_rights: Option<String>,
+    pub dcat_contact_point: Option<String>,
 }
 
 impl From<NewDataServiceModel> for ActiveModel {
@@ -99,6 +119,11 @@ impl From<NewDataServiceModel> for ActiveModel {
             dct_description: ActiveValue::Set(dto.dct_description),
             catalog_id: ActiveValue::Set(dto.catalog_id.to_string()),
             dspace_main_data_service: ActiveValue::Set(dto.dspace_main_data_service),
+            dcat_keyword: ActiveValue::Set(dto.dcat_keyword.map(Json::from)),
+            dcat_theme: ActiveValue::Set(dto.dcat_theme.map(Json::from)),
+            dct_license: ActiveValue::Set(dto.dct_license),
+            dct_access_rights: ActiveValue::Set(dto.dct_access_rights),
+            dcat_contact_point: ActiveValue::Set(dto.dcat_contact_point),
         }
     }
 }
@@ -116,4 +141,9 @@ pub struct EditDataServiceModel {
     pub dct_creator: Option<String>,
     pub dct_title: Option<String>,
     pub dct_description: Option<String>,
+    pub dcat_keyword: Option<Option<Vec<String>>>,
+    pub dcat_theme: Option<Option<Vec<String>>>,
+    pub dct_license: Option<Option<String>>,
+    pub dct_access_rights: Option<Option<String>>,
+    pub dcat_contact_point: Option<Option<String>>,
 }
@@ -24,7 +24,7 @@ use urn::{Urn, UrnBuilder};
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
 #[sea_orm(table_name = "catalog_datasets")]
 pub struct Model {
-    #[sea_orm(primary_key)]
+    #[sea_orm(primary_key, auto_increment = false)]
     pub id: String,
     pub dct_conforms_to: Option<String>,
     pub dct_creator: Option<String>,
@@ -34,6 +34,17 @@ pub struct Model {
     pub dct_title: Option<String>,
     pub dct_description: Option<String>,
     pub catalog_id: String,
+    pub dcat_keyword: Option<Json>,
+    pub dcat_theme: Option<Json>,
+    pub dct_language: Option<Json>,
+    pub dct_license: Option<String>,
+    pub dct_access_rights: Option<String>,
+    pub dct_spatial: Option<String>,
+    pub dct_temporal_start_date: Option<DateTimeWithTimeZone>,
+    pub dct_temporal_end_date: Option<DateTimeWithTimeZone>,
+    pub dcat_contact_point: Option<String>,
+    pub dcat_version: Option<String>,
+    pub dcat_previous_version: Option<String>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -70,6 +81,20 @@ impl Related<super::odrl_offer::Entity> for Entity {
 
 impl ActiveModelBehavior for ActiveModel {}
 
+impl Model {
+    pub fn keywords(&self) -> Vec<String> {
+        super::string_list(&self.dcat_keyword)
+    }
+
+    pub fn themes(&self) -> Vec<String> {
+        super::string_list(&self.dcat_theme)
+    }
+
+    pub fn languages(&self) -> Vec<String> {
+        super::string_list(&self.dct_language)
+    }
+}
+
 #[derive(Clone)]
 pub struct NewDatasetModel {
     pub id: Option<Urn>,
@@ -78,6 +103,17 @@ pub struct NewDatasetModel {
     pub dct_title: Option<String>,
     pub dct_description: Option<String>,
     pub catalog_id: Urn,
+    pub dcat_keyword: Option<Vec<String>>,
+    pub dcat_theme: Option<Vec<String>>,
+    pub dct_language: Option<Vec<String>>,
+    pub dct_license: Option<String>,
+    pub dct_access_rights: Option<String>,
+    pub dct_spatial: Option<String>,
+    pub dct_temporal_start_date: Option<DateTimeWithTimeZone>,
+    pub dct_temporal_end_date: Option<DateTimeWithTimeZone>,
+    pub dcat_contact_point: Option<String>,
+    pub dcat_version: Option<String>,
+    pub dcat_previous_version: Option<String>,
 }
 
 impl From<NewDatasetModel> for ActiveModel {
@@ -97,6 +133,17 @@ impl From<NewDatasetModel> for ActiveModel {
             dct_title: ActiveValue::Set(dto.dct_title),
             dct_description: ActiveValue::Set(dto.dct_description),
             catalog_id: ActiveValue::Set(dto.catalog_id.to_string()),
+            dcat_keyword: ActiveValue::Set(dto.dcat_keyword.map(Json::from)),
+            dcat_theme: ActiveValue::Set(dto.dcat_theme.map(Json::from)),
+            dct_language: ActiveValue::Set(dto.dct_language.map(Json::from)),
+            dct_license: ActiveValue::Set(dto.dct_license),
+            dct_access_rights: ActiveValue::Set(dto.dct_access_rights),
+            dct_spatial: ActiveValue::Set(dto.dct_spatial),
+            dct_temporal_start_date: ActiveValue::Set(dto.dct_temporal_start_date),
+            dct_temporal_end_date: ActiveValue::Set(dto.dct_temporal_end_date),
+            dcat_contact_point: ActiveValue::Set(dto.dcat_contact_point),
+            dcat_version: ActiveValue::Set(dto.dcat_version),
+            dcat_previous_version: ActiveValue::Set(dto.dcat_previous_version),
         }
     }
 }
@@ -112,4 +159,15 @@ pub struct EditDatasetModel {
     pub dct_creator: Option<String>,
     pub dct_title: Option<String>,
     pub dct_description: Option<String>,
+    pub dcat_keyword: Option<Option<Vec<String>>>,
+    pub dcat_theme: Option<Option<Vec<String>>>,
+    pub dct_language: Option<Option<Vec<String>>>,
+    pub dct_license: Option<Option<String>>,
+    pub dct_access_rights: Option<Option<String>>,
+    pub dct_spatial: Option<Option<String>>,
+    pub dct_temporal_start_date: Option<Option<DateTimeWithTimeZone>>,
+    pub dct_temporal_end_date: Option<Option<DateTimeWithTimeZone>>,
+    pub dcat_contact_point: Option<Option<String>>,
+    pub dcat_version: Option<Option<String>>,
+    pub dcat_previous_version: Option<Option<String>>,
 }
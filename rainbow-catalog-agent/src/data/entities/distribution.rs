@@ -25,7 +25,7 @@ use urn::{Urn, UrnBuilder};
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
 #[sea_orm(table_name = "catalog_distributions")]
 pub struct Model {
-    #[sea_orm(primary_key)]
+    #[sea_orm(primary_key, auto_increment = false)]
     pub id: String,
     pub dct_issued: DateTimeWithTimeZone,
     pub dct_modified: Option<DateTimeWithTimeZone>,
@@ -34,6 +34,12 @@ pub struct Model {
     pub dcat_access_service: String,
     pub dataset_id: String,
     pub dct_format: Option<String>,
+    pub dcat_byte_size: Option<i64>,
+    pub dcat_media_type: Option<String>,
+    pub spdx_checksum_algorithm: Option<String>,
+    pub spdx_checksum_value: Option<String>,
+    pub dct_license: Option<String>,
+    pub dct_access_rights: Option<String>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -82,6 +88,12 @@ pub struct NewDistributionModel {
     pub dct_formats: Option<DctFormats>,
     pub dcat_access_service: String,
     pub dataset_id: Urn,
+    pub dcat_byte_size: Option<i64>,
+    pub dcat_media_type: Option<String>,
+    pub spdx_checksum_algorithm: Option<String>,
+    pub spdx_checksum_value: Option<String>,
+    pub dct_license: Option<String>,
+    pub dct_access_rights: Option<String>,
 }
 
 impl From<NewDistributionModel> for ActiveModel {
@@ -98,6 +110,12 @@ impl From<NewDistributionModel> for ActiveModel {
             dcat_access_service: ActiveValue::Set(dto.dcat_access_service),
             dataset_id: ActiveValue::Set(dto.dataset_id.to_string()),
             dct_format: ActiveValue::Set(dto.dct_formats.map(|d| d.to_string())),
+            dcat_byte_size: ActiveValue::Set(dto.dcat_byte_size),
+            dcat_media_type: ActiveValue::Set(dto.dcat_media_type),
+            spdx_checksum_algorithm: ActiveValue::Set(dto.spdx_checksum_algorithm),
+            spdx_checksum_value: ActiveValue::Set(dto.spdx_checksum_value),
+            dct_license: ActiveValue::Set(dto.dct_license),
+            dct_access_rights: ActiveValue::Set(dto.dct_access_rights),
         }
     }
 }
@@ -112,4 +130,10 @@ pub struct EditDistributionModel {
     pub dct_title: Option<String>,
     pub dct_description: Option<String>,
     pub dcat_access_service: Option<String>,
+    pub dcat_byte_size: Option<Option<i64>>,
+    pub dcat_media_type: Option<Option<String>>,
+    pub spdx_checksum_algorithm: Option<Option<String>>,
+    pub spdx_checksum_value: Option<Option<String>>,
+    pub dct_license: Option<Option<String>>,
+    pub dct_access_rights: Option<Option<String>>,
 }
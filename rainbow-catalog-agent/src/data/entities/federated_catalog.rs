@@ -0,0 +1,74 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use sea_orm::entity::prelude::*;
+use sea_orm::ActiveValue;
+use serde::{Deserialize, Serialize};
+
+/// Last known catalog of a peer, as it was served over DSP
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "federated_catalogs")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub participant_id: String,
+    pub catalog_id: String,
+    pub dct_title: Option<String>,
+    pub content: serde_json::Value,
+    pub fetched_at: DateTimeWithTimeZone,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(has_many = "super::federated_dataset::Entity")]
+    FederatedDataset,
+}
+
+impl Related<super::federated_dataset::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::FederatedDataset.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+#[derive(Clone)]
+pub struct NewFederatedCatalogModel {
+    pub participant_id: String,
+    pub catalog_id: String,
+    pub dct_title: Option<String>,
+    pub content: serde_json::Value,
+}
+
+impl From<NewFederatedCatalogModel> for ActiveModel {
+    fn from(dto: NewFederatedCatalogModel) -> Self {
+        Self {
+            participant_id: ActiveValue::Set(dto.participant_id),
+            catalog_id: ActiveValue::Set(dto.catalog_id),
+            dct_title: ActiveValue::Set(dto.dct_title),
+            content: ActiveValue::Set(dto.content),
+            fetched_at: ActiveValue::Set(chrono::Utc::now().into()),
+        }
+    }
+}
+
+impl From<&NewFederatedCatalogModel> for ActiveModel {
+    fn from(dto: &NewFederatedCatalogModel) -> Self {
+        dto.clone().into()
+    }
+}
@@ -0,0 +1,81 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use sea_orm::entity::prelude::*;
+use sea_orm::ActiveValue;
+use serde::{Deserialize, Serialize};
+
+/// Dataset offered by a peer, flattened out of its catalog so all peers can be queried at once
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "federated_datasets")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub participant_id: String,
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: String,
+    pub catalog_id: String,
+    pub dct_title: Option<String>,
+    pub dct_description: Option<String>,
+    pub dct_conforms_to: Option<String>,
+    pub content: serde_json::Value,
+    pub fetched_at: DateTimeWithTimeZone,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::federated_catalog::Entity",
+        from = "Column::ParticipantId",
+        to = "super::federated_catalog::Column::ParticipantId"
+    )]
+    FederatedCatalog,
+}
+
+impl Related<super::federated_catalog::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::FederatedCatalog.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+#[derive(Clone)]
+pub struct NewFederatedDatasetModel {
+    pub id: String,
+    pub catalog_id: String,
+    pub dct_title: Option<String>,
+    pub dct_description: Option<String>,
+    pub dct_conforms_to: Option<String>,
+    pub content: serde_json::Value,
+}
+
+impl NewFederatedDatasetModel {
+    pub fn into_active_model(self, participant_id: &str) -> ActiveModel {
+        ActiveModel {
+            participant_id: ActiveValue::Set(participant_id.to_string()),
+            id: ActiveValue::Set(self.id),
+            catalog_id: ActiveValue::Set(self.catalog_id),
+            dct_title: ActiveValue::Set(self.dct_title),
+            dct_description: ActiveValue::Set(self.dct_description),
+            dct_conforms_to: ActiveValue::Set(self.dct_conforms_to),
+            content: ActiveValue::Set(self.content),
+            fetched_at: ActiveValue::Set(chrono::Utc::now().into()),
+        }
+    }
+}
@@ -18,8 +18,23 @@
  */
 
 pub(crate) mod catalog;
+pub(crate) mod catalog_tree_version;
 pub(crate) mod dataservice;
 pub(crate) mod dataset;
 pub(crate) mod distribution;
+pub(crate) mod federated_catalog;
+pub(crate) mod federated_crawl_state;
+pub(crate) mod federated_dataset;
 pub(crate) mod odrl_offer;
 pub(crate) mod policy_template;
+
+/// Multi-valued DCAT properties (keywords, themes, languages) are stored as json arrays
+pub(crate) fn string_list(value: &Option<serde_json::Value>) -> Vec<String> {
+    match value {
+        Some(serde_json::Value::Array(values)) => {
+            values.iter().filter_map(|v| v.as_str().map(|v| v.to_string())).collect()
+        }
+        Some(serde_json::Value::String(value)) => vec![value.clone()],
+        _ => vec![],
+    }
+}
@@ -19,15 +19,19 @@
 
 use crate::data::factory_trait::CatalogAgentRepoTrait;
 use crate::data::repo_traits::catalog_repo::CatalogRepositoryTrait;
+use crate::data::repo_traits::catalog_tree_repo::CatalogTreeRepositoryTrait;
 use crate::data::repo_traits::dataservice_repo::DataServiceRepositoryTrait;
 use crate::data::repo_traits::dataset_repo::DatasetRepositoryTrait;
 use crate::data::repo_traits::distribution_repo::DistributionRepositoryTrait;
+use crate::data::repo_traits::federated_catalog_repo::FederatedCatalogRepositoryTrait;
 use crate::data::repo_traits::odrl_offer_repo::OdrlOfferRepositoryTrait;
 use crate::data::repo_traits::policy_template_repo::PolicyTemplatesRepositoryTrait;
 use crate::data::repos_sql::catalog_repo::CatalogRepositoryForSql;
+use crate::data::repos_sql::catalog_tree_repo::CatalogTreeRepositoryForSql;
 use crate::data::repos_sql::dataservice_repo::DataServiceRepositoryForSql;
 use crate::data::repos_sql::dataset_repo::DatasetRepositoryForSql;
 use crate::data::repos_sql::distribution_repo::DistributionRepositoryForSql;
+use crate::data::repos_sql::federated_catalog_repo::FederatedCatalogRepositoryForSql;
 use crate::data::repos_sql::odrl_offer_repo::OdrlOfferRepositoryForSql;
 use crate::data::repos_sql::policy_template_repo::PolicyTemplatesRepositoryForSql;
 use sea_orm::DatabaseConnection;
@@ -35,9 +39,11 @@ use std::sync::Arc;
 
 pub struct CatalogAgentRepoForSql {
     catalog_repo: Arc<dyn CatalogRepositoryTrait>,
+    catalog_tree_repo: Arc<dyn CatalogTreeRepositoryTrait>,
     dataservice_repo: Arc<dyn DataServiceRepositoryTrait>,
     dataset_repo: Arc<dyn DatasetRepositoryTrait>,
     distribution_repo: Arc<dyn DistributionRepositoryTrait>,
+    federated_catalog_repo: Arc<dyn FederatedCatalogRepositoryTrait>,
     odrl_offer_repo: Arc<dyn OdrlOfferRepositoryTrait>,
     policy_template_repo: Arc<dyn PolicyTemplatesRepositoryTrait>,
 }
@@ -46,9 +52,13 @@ impl CatalogAgentRepoForSql {
     pub fn create_repo(db_connection: DatabaseConnection) -> Self {
         Self {
             catalog_repo: Arc::new(CatalogRepositoryForSql::new(db_connection.clone())),
+            catalog_tree_repo: Arc::new(CatalogTreeRepositoryForSql::new(db_connection.clone())),
             dataservice_repo: Arc::new(DataServiceRepositoryForSql::new(db_connection.clone())),
             dataset_repo: Arc::new(DatasetRepositoryForSql::new(db_connection.clone())),
             distribution_repo: Arc::new(DistributionRepositoryForSql::new(db_connection.clone())),
+            federated_catalog_repo: Arc::new(FederatedCatalogRepositoryForSql::new(
+                db_connection.clone(),
+            )),
             odrl_offer_repo: Arc::new(OdrlOfferRepositoryForSql::new(db_connection.clone())),
             policy_template_repo: Arc::new(PolicyTemplatesRepositoryForSql::new(
                 db_connection.clone(),
@@ -62,6 +72,10 @@ impl CatalogAgentRepoTrait for CatalogAgentRepoForSql {
         self.catalog_repo.clone()
     }
 
+    fn get_catalog_tree_repo(&self) -> Arc<dyn CatalogTreeRepositoryTrait> {
+        self.catalog_tree_repo.clone()
+    }
+
     fn get_dataservice_repo(&self) -> Arc<dyn DataServiceRepositoryTrait> {
         self.dataservice_repo.clone()
     }
@@ -74,6 +88,10 @@ impl CatalogAgentRepoTrait for CatalogAgentRepoForSql {
         self.distribution_repo.clone()
     }
 
+    fn get_federated_catalog_repo(&self) -> Arc<dyn FederatedCatalogRepositoryTrait> {
+        self.federated_catalog_repo.clone()
+    }
+
     fn get_odrl_offer_repo(&self) -> Arc<dyn OdrlOfferRepositoryTrait> {
         self.odrl_offer_repo.clone()
     }
@@ -18,9 +18,11 @@
  */
 
 use crate::data::repo_traits::catalog_repo::CatalogRepositoryTrait;
+use crate::data::repo_traits::catalog_tree_repo::CatalogTreeRepositoryTrait;
 use crate::data::repo_traits::dataservice_repo::DataServiceRepositoryTrait;
 use crate::data::repo_traits::dataset_repo::DatasetRepositoryTrait;
 use crate::data::repo_traits::distribution_repo::DistributionRepositoryTrait;
+use crate::data::repo_traits::federated_catalog_repo::FederatedCatalogRepositoryTrait;
 use crate::data::repo_traits::odrl_offer_repo::OdrlOfferRepositoryTrait;
 use crate::data::repo_traits::policy_template_repo::PolicyTemplatesRepositoryTrait;
 use std::sync::Arc;
@@ -28,9 +30,11 @@ use std::sync::Arc;
 #[mockall::automock]
 pub trait CatalogAgentRepoTrait: Send + Sync + 'static {
     fn get_catalog_repo(&self) -> Arc<dyn CatalogRepositoryTrait>;
+    fn get_catalog_tree_repo(&self) -> Arc<dyn CatalogTreeRepositoryTrait>;
     fn get_dataservice_repo(&self) -> Arc<dyn DataServiceRepositoryTrait>;
     fn get_dataset_repo(&self) -> Arc<dyn DatasetRepositoryTrait>;
     fn get_distribution_repo(&self) -> Arc<dyn DistributionRepositoryTrait>;
+    fn get_federated_catalog_repo(&self) -> Arc<dyn FederatedCatalogRepositoryTrait>;
     fn get_odrl_offer_repo(&self) -> Arc<dyn OdrlOfferRepositoryTrait>;
     fn get_policy_template_repo(&self) -> Arc<dyn PolicyTemplatesRepositoryTrait>;
 }
@@ -0,0 +1,167 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use sea_orm_migration::prelude::*;
+
+pub struct Migration;
+impl MigrationName for Migration {
+    fn name(&self) -> &str {
+        "m20241111_000007_federated_catalogs"
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(FederatedCrawlStates::Table)
+                    .col(
+                        ColumnDef::new(FederatedCrawlStates::ParticipantId)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(FederatedCrawlStates::Enabled).boolean().not_null())
+                    .col(
+                        ColumnDef::new(FederatedCrawlStates::CrawlInterval)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(FederatedCrawlStates::Etag).string())
+                    .col(ColumnDef::new(FederatedCrawlStates::LastModified).string())
+                    .col(ColumnDef::new(FederatedCrawlStates::ContentHash).string())
+                    .col(
+                        ColumnDef::new(FederatedCrawlStates::LastCrawledAt)
+                            .timestamp_with_time_zone(),
+                    )
+                    .col(
+                        ColumnDef::new(FederatedCrawlStates::NextCrawlAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(FederatedCrawlStates::ConsecutiveFailures)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(ColumnDef::new(FederatedCrawlStates::LastError).string())
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_table(
+                Table::create()
+                    .table(FederatedCatalogs::Table)
+                    .col(
+                        ColumnDef::new(FederatedCatalogs::ParticipantId)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(FederatedCatalogs::CatalogId).string().not_null())
+                    .col(ColumnDef::new(FederatedCatalogs::DctTitle).string())
+                    .col(ColumnDef::new(FederatedCatalogs::Content).json_binary().not_null())
+                    .col(
+                        ColumnDef::new(FederatedCatalogs::FetchedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_table(
+                Table::create()
+                    .table(FederatedDatasets::Table)
+                    .col(ColumnDef::new(FederatedDatasets::ParticipantId).string().not_null())
+                    .col(ColumnDef::new(FederatedDatasets::Id).string().not_null())
+                    .col(ColumnDef::new(FederatedDatasets::CatalogId).string().not_null())
+                    .col(ColumnDef::new(FederatedDatasets::DctTitle).string())
+                    .col(ColumnDef::new(FederatedDatasets::DctDescription).string())
+                    .col(ColumnDef::new(FederatedDatasets::DctConformsTo).string())
+                    .col(ColumnDef::new(FederatedDatasets::Content).json_binary().not_null())
+                    .col(
+                        ColumnDef::new(FederatedDatasets::FetchedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .primary_key(
+                        Index::create()
+                            .col(FederatedDatasets::ParticipantId)
+                            .col(FederatedDatasets::Id),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_federated_dataset_catalog")
+                            .from(FederatedDatasets::Table, FederatedDatasets::ParticipantId)
+                            .to(FederatedCatalogs::Table, FederatedCatalogs::ParticipantId)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.drop_table(Table::drop().table(FederatedDatasets::Table).to_owned()).await?;
+        manager.drop_table(Table::drop().table(FederatedCatalogs::Table).to_owned()).await?;
+        manager.drop_table(Table::drop().table(FederatedCrawlStates::Table).to_owned()).await
+    }
+}
+
+#[derive(Iden)]
+pub enum FederatedCrawlStates {
+    Table,
+    ParticipantId,
+    Enabled,
+    CrawlInterval,
+    Etag,
+    LastModified,
+    ContentHash,
+    LastCrawledAt,
+    NextCrawlAt,
+    ConsecutiveFailures,
+    LastError,
+}
+
+#[derive(Iden)]
+pub enum FederatedCatalogs {
+    Table,
+    ParticipantId,
+    CatalogId,
+    DctTitle,
+    Content,
+    FetchedAt,
+}
+
+#[derive(Iden)]
+pub enum FederatedDatasets {
+    Table,
+    ParticipantId,
+    Id,
+    CatalogId,
+    DctTitle,
+    DctDescription,
+    DctConformsTo,
+    Content,
+    FetchedAt,
+}
@@ -0,0 +1,189 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use sea_orm_migration::prelude::*;
+
+pub struct Migration;
+impl MigrationName for Migration {
+    fn name(&self) -> &str {
+        "m20241111_000008_dcat3_metadata"
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // one column per statement, sqlite cannot add several columns in one alter
+        for column in Self::dataset_columns() {
+            manager
+                .alter_table(
+                    Table::alter().table(CatalogDatasets::Table).add_column(column).to_owned(),
+                )
+                .await?;
+        }
+        for column in Self::distribution_columns() {
+            manager
+                .alter_table(
+                    Table::alter().table(CatalogDistributions::Table).add_column(column).to_owned(),
+                )
+                .await?;
+        }
+        for column in Self::data_service_columns() {
+            manager
+                .alter_table(
+                    Table::alter().table(CatalogDataServices::Table).add_column(column).to_owned(),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for column in [
+            CatalogDatasets::DcatKeyword,
+            CatalogDatasets::DcatTheme,
+            CatalogDatasets::DctLanguage,
+            CatalogDatasets::DctLicense,
+            CatalogDatasets::DctAccessRights,
+            CatalogDatasets::DctSpatial,
+            CatalogDatasets::DctTemporalStartDate,
+            CatalogDatasets::DctTemporalEndDate,
+            CatalogDatasets::DcatContactPoint,
+            CatalogDatasets::DcatVersion,
+            CatalogDatasets::DcatPreviousVersion,
+        ] {
+            manager
+                .alter_table(
+                    Table::alter().table(CatalogDatasets::Table).drop_column(column).to_owned(),
+                )
+                .await?;
+        }
+        for column in [
+            CatalogDistributions::DcatByteSize,
+            CatalogDistributions::DcatMediaType,
+            CatalogDistributions::SpdxChecksumAlgorithm,
+            CatalogDistributions::SpdxChecksumValue,
+            CatalogDistributions::DctLicense,
+            CatalogDistributions::DctAccessRights,
+        ] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(CatalogDistributions::Table)
+                        .drop_column(column)
+                        .to_owned(),
+                )
+                .await?;
+        }
+        for column in [
+            CatalogDataServices::DcatKeyword,
+            CatalogDataServices::DcatTheme,
+            CatalogDataServices::DctLicense,
+            CatalogDataServices::DctAccessRights,
+            CatalogDataServices::DcatContactPoint,
+        ] {
+            manager
+                .alter_table(
+                    Table::alter().table(CatalogDataServices::Table).drop_column(column).to_owned(),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+}
+
+impl Migration {
+    fn dataset_columns() -> Vec<ColumnDef> {
+        vec![
+            ColumnDef::new(CatalogDatasets::DcatKeyword).json_binary().to_owned(),
+            ColumnDef::new(CatalogDatasets::DcatTheme).json_binary().to_owned(),
+            ColumnDef::new(CatalogDatasets::DctLanguage).json_binary().to_owned(),
+            ColumnDef::new(CatalogDatasets::DctLicense).string().to_owned(),
+            ColumnDef::new(CatalogDatasets::DctAccessRights).string().to_owned(),
+            ColumnDef::new(CatalogDatasets::DctSpatial).string().to_owned(),
+            ColumnDef::new(CatalogDatasets::DctTemporalStartDate)
+                .timestamp_with_time_zone()
+                .to_owned(),
+            ColumnDef::new(CatalogDatasets::DctTemporalEndDate)
+                .timestamp_with_time_zone()
+                .to_owned(),
+            ColumnDef::new(CatalogDatasets::DcatContactPoint).string().to_owned(),
+            ColumnDef::new(CatalogDatasets::DcatVersion).string().to_owned(),
+            ColumnDef::new(CatalogDatasets::DcatPreviousVersion).string().to_owned(),
+        ]
+    }
+
+    fn distribution_columns() -> Vec<ColumnDef> {
+        vec![
+            ColumnDef::new(CatalogDistributions::DcatByteSize).big_integer().to_owned(),
+            ColumnDef::new(CatalogDistributions::DcatMediaType).string().to_owned(),
+            ColumnDef::new(CatalogDistributions::SpdxChecksumAlgorithm).string().to_owned(),
+            ColumnDef::new(CatalogDistributions::SpdxChecksumValue).string().to_owned(),
+            ColumnDef::new(CatalogDistributions::DctLicense).string().to_owned(),
+            ColumnDef::new(CatalogDistributions::DctAccessRights).string().to_owned(),
+        ]
+    }
+
+    fn data_service_columns() -> Vec<ColumnDef> {
+        vec![
+            ColumnDef::new(CatalogDataServices::DcatKeyword).json_binary().to_owned(),
+            ColumnDef::new(CatalogDataServices::DcatTheme).json_binary().to_owned(),
+            ColumnDef::new(CatalogDataServices::DctLicense).string().to_owned(),
+            ColumnDef::new(CatalogDataServices::DctAccessRights).string().to_owned(),
+            ColumnDef::new(CatalogDataServices::DcatContactPoint).string().to_owned(),
+        ]
+    }
+}
+
+#[derive(Iden)]
+pub enum CatalogDatasets {
+    Table,
+    DcatKeyword,
+    DcatTheme,
+    DctLanguage,
+    DctLicense,
+    DctAccessRights,
+    DctSpatial,
+    DctTemporalStartDate,
+    DctTemporalEndDate,
+    DcatContactPoint,
+    DcatVersion,
+    DcatPreviousVersion,
+}
+
+#[derive(Iden)]
+pub enum CatalogDistributions {
+    Table,
+    DcatByteSize,
+    DcatMediaType,
+    SpdxChecksumAlgorithm,
+    SpdxChecksumValue,
+    DctLicense,
+    DctAccessRights,
+}
+
+#[derive(Iden)]
+pub enum CatalogDataServices {
+    Table,
+    DcatKeyword,
+    DcatTheme,
+    DctLicense,
+    DctAccessRights,
+    DcatContactPoint,
+}
@@ -0,0 +1,68 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use sea_orm_migration::prelude::*;
+
+pub struct Migration;
+impl MigrationName for Migration {
+    fn name(&self) -> &str {
+        "m20241111_000009_catalog_tree_version"
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(CatalogTreeVersion::Table)
+                    .col(ColumnDef::new(CatalogTreeVersion::Id).integer().not_null().primary_key())
+                    .col(
+                        ColumnDef::new(CatalogTreeVersion::Version)
+                            .big_integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        // the single row every replica reads before trusting its snapshot
+        manager
+            .exec_stmt(
+                Query::insert()
+                    .into_table(CatalogTreeVersion::Table)
+                    .columns([CatalogTreeVersion::Id, CatalogTreeVersion::Version])
+                    .values_panic([1.into(), 0.into()])
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.drop_table(Table::drop().table(CatalogTreeVersion::Table).to_owned()).await
+    }
+}
+
+#[derive(Iden)]
+pub enum CatalogTreeVersion {
+    Table,
+    Id,
+    Version,
+}
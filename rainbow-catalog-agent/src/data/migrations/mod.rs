@@ -25,6 +25,9 @@ mod m20241111_000003_distribution;
 mod m20241111_000004_dataservice;
 mod m20241111_000005_policy_templates;
 mod m20241111_000006_policies;
+mod m20241111_000007_federated_catalogs;
+mod m20241111_000008_dcat3_metadata;
+mod m20241111_000009_catalog_tree_version;
 
 pub fn get_catalog_migrations() -> Vec<Box<dyn MigrationTrait>> {
     vec![
@@ -34,6 +37,9 @@ pub fn get_catalog_migrations() -> Vec<Box<dyn MigrationTrait>> {
         Box::new(m20241111_000004_dataservice::Migration),
         Box::new(m20241111_000005_policy_templates::Migration),
         Box::new(m20241111_000006_policies::Migration),
+        Box::new(m20241111_000007_federated_catalogs::Migration),
+        Box::new(m20241111_000008_dcat3_metadata::Migration),
+        Box::new(m20241111_000009_catalog_tree_version::Migration),
     ]
 }
 pub struct Migrator;
@@ -15,6 +15,10 @@ pub enum CatalogAgentRepoErrors {
     OdrlOfferRepoErrors(OdrlOfferRepoErrors),
     #[error("Policy Templates Repo error: {0}")]
     PolicyTemplatesRepoErrors(PolicyTemplatesRepoErrors),
+    #[error("Federated Catalog Repo error: {0}")]
+    FederatedCatalogRepoErrors(FederatedCatalogRepoErrors),
+    #[error("Catalog Tree Repo error: {0}")]
+    CatalogTreeRepoErrors(CatalogTreeRepoErrors),
 }
 
 #[derive(Error, Debug)]
@@ -100,3 +104,25 @@ pub enum PolicyTemplatesRepoErrors {
     #[error("Error deleting policy template. {0}")]
     ErrorDeletingPolicyTemplate(Error),
 }
+
+#[derive(Error, Debug)]
+pub enum FederatedCatalogRepoErrors {
+    #[error("Crawl state not found")]
+    CrawlStateNotFound,
+    #[error("Error fetching crawl state. {0}")]
+    ErrorFetchingCrawlState(Error),
+    #[error("Error saving crawl state. {0}")]
+    ErrorSavingCrawlState(Error),
+    #[error("Error fetching federated catalog. {0}")]
+    ErrorFetchingFederatedCatalog(Error),
+    #[error("Error replacing federated catalog. {0}")]
+    ErrorReplacingFederatedCatalog(Error),
+}
+
+#[derive(Error, Debug)]
+pub enum CatalogTreeRepoErrors {
+    #[error("Error fetching catalog tree version. {0}")]
+    ErrorFetchingCatalogTreeVersion(Error),
+    #[error("Error bumping catalog tree version. {0}")]
+    ErrorBumpingCatalogTreeVersion(Error),
+}
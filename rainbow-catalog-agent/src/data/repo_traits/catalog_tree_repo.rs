@@ -0,0 +1,20 @@
+use crate::data::entities::{catalog, dataservice, dataset, distribution, odrl_offer};
+use crate::data::repo_traits::catalog_db_errors::CatalogAgentRepoErrors;
+
+/// Every row of the catalog tree, loaded with one query per table
+pub struct CatalogTreeModel {
+    /// Version read before the tables, so a write racing with the load shows up as newer
+    pub version: i64,
+    pub catalogs: Vec<catalog::Model>,
+    pub datasets: Vec<dataset::Model>,
+    pub distributions: Vec<distribution::Model>,
+    pub data_services: Vec<dataservice::Model>,
+    pub odrl_offers: Vec<odrl_offer::Model>,
+}
+
+#[async_trait::async_trait]
+pub trait CatalogTreeRepositoryTrait: Send + Sync {
+    async fn get_catalog_tree(&self) -> anyhow::Result<CatalogTreeModel, CatalogAgentRepoErrors>;
+    /// Bumped in the database by every write to a table of the catalog tree, from any replica
+    async fn get_catalog_tree_version(&self) -> anyhow::Result<i64, CatalogAgentRepoErrors>;
+}
@@ -0,0 +1,35 @@
+use crate::data::entities::federated_catalog::NewFederatedCatalogModel;
+use crate::data::entities::federated_dataset::NewFederatedDatasetModel;
+use crate::data::entities::{federated_catalog, federated_crawl_state, federated_dataset};
+use crate::data::repo_traits::catalog_db_errors::CatalogAgentRepoErrors;
+
+#[async_trait::async_trait]
+pub trait FederatedCatalogRepositoryTrait: Send + Sync {
+    async fn get_all_crawl_states(
+        &self,
+    ) -> anyhow::Result<Vec<federated_crawl_state::Model>, CatalogAgentRepoErrors>;
+    async fn get_crawl_state_by_participant(
+        &self,
+        participant_id: &String,
+    ) -> anyhow::Result<Option<federated_crawl_state::Model>, CatalogAgentRepoErrors>;
+    async fn put_crawl_state(
+        &self,
+        crawl_state: &federated_crawl_state::Model,
+    ) -> anyhow::Result<federated_crawl_state::Model, CatalogAgentRepoErrors>;
+    async fn get_federated_catalog_by_participant(
+        &self,
+        participant_id: &String,
+    ) -> anyhow::Result<Option<federated_catalog::Model>, CatalogAgentRepoErrors>;
+    async fn replace_federated_catalog(
+        &self,
+        new_catalog: &NewFederatedCatalogModel,
+        new_datasets: &Vec<NewFederatedDatasetModel>,
+    ) -> anyhow::Result<federated_catalog::Model, CatalogAgentRepoErrors>;
+    async fn get_federated_datasets(
+        &self,
+        limit: Option<u64>,
+        page: Option<u64>,
+        participant_id: Option<String>,
+        keyword: Option<String>,
+    ) -> anyhow::Result<Vec<federated_dataset::Model>, CatalogAgentRepoErrors>;
+}
@@ -19,8 +19,10 @@
 
 pub(crate) mod catalog_db_errors;
 pub(crate) mod catalog_repo;
+pub(crate) mod catalog_tree_repo;
 pub(crate) mod dataservice_repo;
 pub(crate) mod dataset_repo;
 pub(crate) mod distribution_repo;
+pub(crate) mod federated_catalog_repo;
 pub(crate) mod odrl_offer_repo;
 pub(crate) mod policy_template_repo;
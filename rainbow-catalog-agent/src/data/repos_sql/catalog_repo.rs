@@ -2,6 +2,7 @@ use crate::data::entities::catalog;
 use crate::data::entities::catalog::{EditCatalogModel, NewCatalogModel};
 use crate::data::repo_traits::catalog_db_errors::{CatalogAgentRepoErrors, CatalogRepoErrors};
 use crate::data::repo_traits::catalog_repo::CatalogRepositoryTrait;
+use crate::data::repos_sql::catalog_tree_repo::bump_catalog_tree_version;
 use sea_orm::{
     ActiveModelTrait, ActiveValue, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter,
     QuerySelect,
@@ -140,12 +141,13 @@ impl CatalogRepositoryTrait for CatalogRepositoryForSql {
         old_active_model.dct_modified = ActiveValue::Set(Some(chrono::Utc::now().into()));
 
         let model = old_active_model.update(&self.db_connection).await;
-        match model {
+        let written = match model {
             Ok(model) => Ok(model),
             Err(err) => Err(CatalogAgentRepoErrors::CatalogRepoErrors(
                 CatalogRepoErrors::ErrorUpdatingCatalog(err.into()),
             )),
-        }
+        };
+        bump_catalog_tree_version(&self.db_connection, written).await
     }
 
     async fn create_catalog(
@@ -162,12 +164,13 @@ impl CatalogRepositoryTrait for CatalogRepositoryForSql {
         }
         let model: catalog::ActiveModel = new_catalog_model.clone().into();
         let catalog = catalog::Entity::insert(model).exec_with_returning(&self.db_connection).await;
-        match catalog {
+        let written = match catalog {
             Ok(catalog) => Ok(catalog),
             Err(err) => Err(CatalogAgentRepoErrors::CatalogRepoErrors(
                 CatalogRepoErrors::ErrorCreatingCatalog(err.into()),
             )),
-        }
+        };
+        bump_catalog_tree_version(&self.db_connection, written).await
     }
 
     async fn create_main_catalog(
@@ -182,12 +185,13 @@ impl CatalogRepositoryTrait for CatalogRepositoryForSql {
         let mut model: catalog::ActiveModel = new_catalog_model.into();
         model.dspace_main_catalog = ActiveValue::Set(true);
         let catalog = catalog::Entity::insert(model).exec_with_returning(&self.db_connection).await;
-        match catalog {
+        let written = match catalog {
             Ok(catalog) => Ok(catalog),
             Err(err) => Err(CatalogAgentRepoErrors::CatalogRepoErrors(
                 CatalogRepoErrors::ErrorCreatingCatalog(err.into()),
             )),
-        }
+        };
+        bump_catalog_tree_version(&self.db_connection, written).await
     }
 
     async fn delete_catalog_by_id(
@@ -196,7 +200,7 @@ impl CatalogRepositoryTrait for CatalogRepositoryForSql {
     ) -> anyhow::Result<(), CatalogAgentRepoErrors> {
         let catalog_id = catalog_id.to_string();
         let catalog = catalog::Entity::delete_by_id(catalog_id).exec(&self.db_connection).await;
-        match catalog {
+        let written = match catalog {
             Ok(delete_result) => match delete_result.rows_affected {
                 0 => Err(CatalogAgentRepoErrors::CatalogRepoErrors(
                     CatalogRepoErrors::CatalogNotFound,
@@ -206,6 +210,7 @@ impl CatalogRepositoryTrait for CatalogRepositoryForSql {
             Err(err) => Err(CatalogAgentRepoErrors::CatalogRepoErrors(
                 CatalogRepoErrors::ErrorDeletingCatalog(err.into()),
             )),
-        }
+        };
+        bump_catalog_tree_version(&self.db_connection, written).await
     }
 }
@@ -0,0 +1,131 @@
+use crate::data::entities::catalog_tree_version::CATALOG_TREE_VERSION_ID;
+use crate::data::entities::{
+    catalog, catalog_tree_version, dataservice, dataset, distribution, odrl_offer,
+};
+use crate::data::repo_traits::catalog_db_errors::{
+    CatalogAgentRepoErrors, CatalogRepoErrors, CatalogTreeRepoErrors, DataServiceRepoErrors,
+    DatasetRepoErrors, DistributionRepoErrors, OdrlOfferRepoErrors,
+};
+use crate::data::repo_traits::catalog_tree_repo::{CatalogTreeModel, CatalogTreeRepositoryTrait};
+use sea_orm::sea_query::Expr;
+use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder};
+
+pub struct CatalogTreeRepositoryForSql {
+    db_connection: DatabaseConnection,
+}
+
+impl CatalogTreeRepositoryForSql {
+    pub fn new(db_connection: DatabaseConnection) -> Self {
+        Self { db_connection }
+    }
+}
+
+/// Passes a write result through, bumping the catalog tree version when the write went through.
+/// Writes that fail change nothing, so they leave every snapshot valid.
+pub(crate) async fn bump_catalog_tree_version<T>(
+    db_connection: &DatabaseConnection,
+    write_result: anyhow::Result<T, CatalogAgentRepoErrors>,
+) -> anyhow::Result<T, CatalogAgentRepoErrors> {
+    let written = write_result?;
+    catalog_tree_version::Entity::update_many()
+        .col_expr(
+            catalog_tree_version::Column::Version,
+            Expr::col(catalog_tree_version::Column::Version).add(1),
+        )
+        .filter(catalog_tree_version::Column::Id.eq(CATALOG_TREE_VERSION_ID))
+        .exec(db_connection)
+        .await
+        .map_err(|err| {
+            CatalogAgentRepoErrors::CatalogTreeRepoErrors(
+                CatalogTreeRepoErrors::ErrorBumpingCatalogTreeVersion(err.into()),
+            )
+        })?;
+    Ok(written)
+}
+
+#[async_trait::async_trait]
+impl CatalogTreeRepositoryTrait for CatalogTreeRepositoryForSql {
+    async fn get_catalog_tree(&self) -> anyhow::Result<CatalogTreeModel, CatalogAgentRepoErrors> {
+        let version = self.get_catalog_tree_version().await?;
+        // stable ordering keeps DSP pagination consistent between requests
+        let catalogs = catalog::Entity::find()
+            .order_by_asc(catalog::Column::DctIssued)
+            .order_by_asc(catalog::Column::Id)
+            .all(&self.db_connection)
+            .await
+            .map_err(|err| {
+                CatalogAgentRepoErrors::CatalogRepoErrors(CatalogRepoErrors::ErrorFetchingCatalog(
+                    err.into(),
+                ))
+            })?;
+        let datasets = dataset::Entity::find()
+            .order_by_asc(dataset::Column::DctIssued)
+            .order_by_asc(dataset::Column::Id)
+            .all(&self.db_connection)
+            .await
+            .map_err(|err| {
+                CatalogAgentRepoErrors::DatasetRepoErrors(DatasetRepoErrors::ErrorFetchingDataset(
+                    err.into(),
+                ))
+            })?;
+        let distributions = distribution::Entity::find()
+            .order_by_asc(distribution::Column::DctIssued)
+            .order_by_asc(distribution::Column::Id)
+            .all(&self.db_connection)
+            .await
+            .map_err(|err| {
+                CatalogAgentRepoErrors::DistributionRepoErrors(
+                    DistributionRepoErrors::ErrorFetchingDistribution(err.into()),
+                )
+            })?;
+        let data_services = dataservice::Entity::find()
+            .order_by_asc(dataservice::Column::DctIssued)
+            .order_by_asc(dataservice::Column::Id)
+            .all(&self.db_connection)
+            .await
+            .map_err(|err| {
+                CatalogAgentRepoErrors::DataServiceRepoErrors(
+                    DataServiceRepoErrors::ErrorFetchingDataService(err.into()),
+                )
+            })?;
+        let odrl_offers = odrl_offer::Entity::find()
+            .order_by_asc(odrl_offer::Column::CreatedAt)
+            .order_by_asc(odrl_offer::Column::Id)
+            .all(&self.db_connection)
+            .await
+            .map_err(|err| {
+                CatalogAgentRepoErrors::OdrlOfferRepoErrors(
+                    OdrlOfferRepoErrors::ErrorFetchingOdrlOffer(err.into()),
+                )
+            })?;
+
+        Ok(CatalogTreeModel {
+            version,
+            catalogs,
+            datasets,
+            distributions,
+            data_services,
+            odrl_offers,
+        })
+    }
+
+    async fn get_catalog_tree_version(&self) -> anyhow::Result<i64, CatalogAgentRepoErrors> {
+        let version = catalog_tree_version::Entity::find_by_id(CATALOG_TREE_VERSION_ID)
+            .one(&self.db_connection)
+            .await
+            .map_err(|err| {
+                CatalogAgentRepoErrors::CatalogTreeRepoErrors(
+                    CatalogTreeRepoErrors::ErrorFetchingCatalogTreeVersion(err.into()),
+                )
+            })?;
+        // the row is created by the migration, without it snapshots could never be invalidated
+        match version {
+            Some(version) => Ok(version.version),
+            None => Err(CatalogAgentRepoErrors::CatalogTreeRepoErrors(
+                CatalogTreeRepoErrors::ErrorFetchingCatalogTreeVersion(anyhow::anyhow!(
+                    "Catalog tree version row is missing"
+                )),
+            )),
+        }
+    }
+}
@@ -4,6 +4,7 @@ use crate::data::repo_traits::catalog_db_errors::{
     CatalogAgentRepoErrors, CatalogRepoErrors, DataServiceRepoErrors, DistributionRepoErrors,
 };
 use crate::data::repo_traits::dataservice_repo::DataServiceRepositoryTrait;
+use crate::data::repos_sql::catalog_tree_repo::bump_catalog_tree_version;
 use sea_orm::{
     ActiveModelTrait, ActiveValue, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter,
     QuerySelect,
@@ -167,14 +168,32 @@ impl DataServiceRepositoryTrait for DataServiceRepositoryForSql {
             old_active_model.dct_description = ActiveValue::Set(Some(dct_description.clone()));
         }
 
+        if let Some(dcat_keyword) = &edit_data_service_model.dcat_keyword {
+            old_active_model.dcat_keyword =
+                ActiveValue::Set(dcat_keyword.clone().map(serde_json::Value::from));
+        }
+        if let Some(dcat_theme) = &edit_data_service_model.dcat_theme {
+            old_active_model.dcat_theme =
+                ActiveValue::Set(dcat_theme.clone().map(serde_json::Value::from));
+        }
+        if let Some(dct_license) = &edit_data_service_model.dct_license {
+            old_active_model.dct_license = ActiveValue::Set(dct_license.clone());
+        }
+        if let Some(dct_access_rights) = &edit_data_service_model.dct_access_rights {
+            old_active_model.dct_access_rights = ActiveValue::Set(dct_access_rights.clone());
+        }
+        if let Some(dcat_contact_point) = &edit_data_service_model.dcat_contact_point {
+            old_active_model.dcat_contact_point = ActiveValue::Set(dcat_contact_point.clone());
+        }
         old_active_model.dct_modified = ActiveValue::Set(Some(chrono::Utc::now().into()));
         let model = old_active_model.update(&self.db_connection).await;
-        match model {
+        let written = match model {
             Ok(model) => Ok(model),
             Err(err) => Err(CatalogAgentRepoErrors::DataServiceRepoErrors(
                 DataServiceRepoErrors::ErrorUpdatingDataService(err.into()),
             )),
-        }
+        };
+        bump_catalog_tree_version(&self.db_connection, written).await
     }
 
     async fn create_data_service(
@@ -198,12 +217,13 @@ impl DataServiceRepositoryTrait for DataServiceRepositoryForSql {
         let model: dataservice::ActiveModel = new_data_service_model.into();
         let data_service =
             dataservice::Entity::insert(model).exec_with_returning(&self.db_connection).await;
-        match data_service {
+        let written = match data_service {
             Ok(data_service) => Ok(data_service),
             Err(err) => Err(CatalogAgentRepoErrors::DataServiceRepoErrors(
                 DataServiceRepoErrors::ErrorCreatingDataService(err.into()),
             )),
-        }
+        };
+        bump_catalog_tree_version(&self.db_connection, written).await
     }
 
     async fn create_main_data_service(
@@ -234,12 +254,13 @@ impl DataServiceRepositoryTrait for DataServiceRepositoryForSql {
         model.dspace_main_data_service = ActiveValue::Set(true);
         let data_service =
             dataservice::Entity::insert(model).exec_with_returning(&self.db_connection).await;
-        match data_service {
+        let written = match data_service {
             Ok(data_service) => Ok(data_service),
             Err(err) => Err(CatalogAgentRepoErrors::DataServiceRepoErrors(
                 DataServiceRepoErrors::ErrorCreatingDataService(err.into()),
             )),
-        }
+        };
+        bump_catalog_tree_version(&self.db_connection, written).await
     }
 
     async fn delete_data_service_by_id(
@@ -249,7 +270,7 @@ impl DataServiceRepositoryTrait for DataServiceRepositoryForSql {
         let data_service_id = data_service_id.to_string();
         let data_service =
             dataservice::Entity::delete_by_id(data_service_id).exec(&self.db_connection).await;
-        match data_service {
+        let written = match data_service {
             Ok(delete_result) => match delete_result.rows_affected {
                 0 => Err(CatalogAgentRepoErrors::DataServiceRepoErrors(
                     DataServiceRepoErrors::DataServiceNotFound,
@@ -259,6 +280,7 @@ impl DataServiceRepositoryTrait for DataServiceRepositoryForSql {
             Err(err) => Err(CatalogAgentRepoErrors::DataServiceRepoErrors(
                 DataServiceRepoErrors::ErrorDeletingDataService(err.into()),
             )),
-        }
+        };
+        bump_catalog_tree_version(&self.db_connection, written).await
     }
 }
@@ -4,6 +4,7 @@ use crate::data::repo_traits::catalog_db_errors::{
     CatalogAgentRepoErrors, CatalogRepoErrors, DatasetRepoErrors, DistributionRepoErrors,
 };
 use crate::data::repo_traits::dataset_repo::DatasetRepositoryTrait;
+use crate::data::repos_sql::catalog_tree_repo::bump_catalog_tree_version;
 use sea_orm::{
     ActiveModelTrait, ActiveValue, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter,
     QuerySelect,
@@ -143,15 +144,53 @@ impl DatasetRepositoryTrait for DatasetRepositoryForSql {
         if let Some(dct_description) = &edit_dataset_model.dct_description {
             old_active_model.dct_description = ActiveValue::Set(Some(dct_description.clone()));
         }
+        if let Some(dcat_keyword) = &edit_dataset_model.dcat_keyword {
+            old_active_model.dcat_keyword =
+                ActiveValue::Set(dcat_keyword.clone().map(serde_json::Value::from));
+        }
+        if let Some(dcat_theme) = &edit_dataset_model.dcat_theme {
+            old_active_model.dcat_theme =
+                ActiveValue::Set(dcat_theme.clone().map(serde_json::Value::from));
+        }
+        if let Some(dct_language) = &edit_dataset_model.dct_language {
+            old_active_model.dct_language =
+                ActiveValue::Set(dct_language.clone().map(serde_json::Value::from));
+        }
+        if let Some(dct_license) = &edit_dataset_model.dct_license {
+            old_active_model.dct_license = ActiveValue::Set(dct_license.clone());
+        }
+        if let Some(dct_access_rights) = &edit_dataset_model.dct_access_rights {
+            old_active_model.dct_access_rights = ActiveValue::Set(dct_access_rights.clone());
+        }
+        if let Some(dct_spatial) = &edit_dataset_model.dct_spatial {
+            old_active_model.dct_spatial = ActiveValue::Set(dct_spatial.clone());
+        }
+        if let Some(dct_temporal_start_date) = &edit_dataset_model.dct_temporal_start_date {
+            old_active_model.dct_temporal_start_date = ActiveValue::Set(*dct_temporal_start_date);
+        }
+        if let Some(dct_temporal_end_date) = &edit_dataset_model.dct_temporal_end_date {
+            old_active_model.dct_temporal_end_date = ActiveValue::Set(*dct_temporal_end_date);
+        }
+        if let Some(dcat_contact_point) = &edit_dataset_model.dcat_contact_point {
+            old_active_model.dcat_contact_point = ActiveValue::Set(dcat_contact_point.clone());
+        }
+        if let Some(dcat_version) = &edit_dataset_model.dcat_version {
+            old_active_model.dcat_version = ActiveValue::Set(dcat_version.clone());
+        }
+        if let Some(dcat_previous_version) = &edit_dataset_model.dcat_previous_version {
+            old_active_model.dcat_previous_version =
+                ActiveValue::Set(dcat_previous_version.clone());
+        }
         old_active_model.dct_modified = ActiveValue::Set(Some(chrono::Utc::now().into()));
 
         let model = old_active_model.update(&self.db_connection).await;
-        match model {
+        let written = match model {
             Ok(model) => Ok(model),
             Err(err) => Err(CatalogAgentRepoErrors::DatasetRepoErrors(
                 DatasetRepoErrors::ErrorUpdatingDataset(err.into()),
             )),
-        }
+        };
+        bump_catalog_tree_version(&self.db_connection, written).await
     }
 
     async fn create_dataset(
@@ -174,12 +213,13 @@ impl DatasetRepositoryTrait for DatasetRepositoryForSql {
 
         let model: dataset::ActiveModel = new_dataset_model.into();
         let dataset = dataset::Entity::insert(model).exec_with_returning(&self.db_connection).await;
-        match dataset {
+        let written = match dataset {
             Ok(dataset) => Ok(dataset),
             Err(err) => Err(CatalogAgentRepoErrors::DatasetRepoErrors(
                 DatasetRepoErrors::ErrorCreatingDataset(err.into()),
             )),
-        }
+        };
+        bump_catalog_tree_version(&self.db_connection, written).await
     }
 
     async fn delete_dataset_by_id(
@@ -188,7 +228,7 @@ impl DatasetRepositoryTrait for DatasetRepositoryForSql {
     ) -> anyhow::Result<(), CatalogAgentRepoErrors> {
         let dataset_id = dataset_id.to_string();
         let dataset = dataset::Entity::delete_by_id(dataset_id).exec(&self.db_connection).await;
-        match dataset {
+        let written = match dataset {
             Ok(delete_result) => match delete_result.rows_affected {
                 0 => Err(CatalogAgentRepoErrors::DatasetRepoErrors(
                     DatasetRepoErrors::DatasetNotFound,
@@ -198,6 +238,7 @@ impl DatasetRepositoryTrait for DatasetRepositoryForSql {
             Err(err) => Err(CatalogAgentRepoErrors::DatasetRepoErrors(
                 DatasetRepoErrors::ErrorDeletingDataset(err.into()),
             )),
-        }
+        };
+        bump_catalog_tree_version(&self.db_connection, written).await
     }
 }
@@ -4,6 +4,7 @@ use crate::data::repo_traits::catalog_db_errors::{
     CatalogAgentRepoErrors, DataServiceRepoErrors, DatasetRepoErrors, DistributionRepoErrors,
 };
 use crate::data::repo_traits::distribution_repo::DistributionRepositoryTrait;
+use crate::data::repos_sql::catalog_tree_repo::bump_catalog_tree_version;
 use rainbow_common::dcat_formats::DctFormats;
 use sea_orm::{
     ActiveModelTrait, ActiveValue, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter,
@@ -189,14 +190,34 @@ impl DistributionRepositoryTrait for DistributionRepositoryForSql {
         if let Some(dcat_access_service) = &edit_distribution_model.dcat_access_service {
             old_active_model.dcat_access_service = ActiveValue::Set(dcat_access_service.clone());
         }
+        if let Some(dcat_byte_size) = &edit_distribution_model.dcat_byte_size {
+            old_active_model.dcat_byte_size = ActiveValue::Set(*dcat_byte_size);
+        }
+        if let Some(dcat_media_type) = &edit_distribution_model.dcat_media_type {
+            old_active_model.dcat_media_type = ActiveValue::Set(dcat_media_type.clone());
+        }
+        if let Some(spdx_checksum_algorithm) = &edit_distribution_model.spdx_checksum_algorithm {
+            old_active_model.spdx_checksum_algorithm =
+                ActiveValue::Set(spdx_checksum_algorithm.clone());
+        }
+        if let Some(spdx_checksum_value) = &edit_distribution_model.spdx_checksum_value {
+            old_active_model.spdx_checksum_value = ActiveValue::Set(spdx_checksum_value.clone());
+        }
+        if let Some(dct_license) = &edit_distribution_model.dct_license {
+            old_active_model.dct_license = ActiveValue::Set(dct_license.clone());
+        }
+        if let Some(dct_access_rights) = &edit_distribution_model.dct_access_rights {
+            old_active_model.dct_access_rights = ActiveValue::Set(dct_access_rights.clone());
+        }
         old_active_model.dct_modified = ActiveValue::Set(Some(chrono::Utc::now().into()));
         let model = old_active_model.update(&self.db_connection).await;
-        match model {
+        let written = match model {
             Ok(model) => Ok(model),
             Err(err) => Err(CatalogAgentRepoErrors::DistributionRepoErrors(
                 DistributionRepoErrors::ErrorUpdatingDistribution(err.into()),
             )),
-        }
+        };
+        bump_catalog_tree_version(&self.db_connection, written).await
     }
 
     async fn create_distribution(
@@ -236,12 +257,13 @@ impl DistributionRepositoryTrait for DistributionRepositoryForSql {
         let model: distribution::ActiveModel = new_distribution_model.into();
         let distribution =
             distribution::Entity::insert(model).exec_with_returning(&self.db_connection).await;
-        match distribution {
+        let written = match distribution {
             Ok(distribution) => Ok(distribution),
             Err(err) => Err(CatalogAgentRepoErrors::DistributionRepoErrors(
                 DistributionRepoErrors::ErrorCreatingDistribution(err.into()),
             )),
-        }
+        };
+        bump_catalog_tree_version(&self.db_connection, written).await
     }
 
     async fn delete_distribution_by_id(
@@ -251,7 +273,7 @@ impl DistributionRepositoryTrait for DistributionRepositoryForSql {
         let distribution_id = distribution_id.to_string();
         let distribution =
             distribution::Entity::delete_by_id(distribution_id).exec(&self.db_connection).await;
-        match distribution {
+        let written = match distribution {
             Ok(delete_result) => match delete_result.rows_affected {
                 0 => Err(CatalogAgentRepoErrors::DistributionRepoErrors(
                     DistributionRepoErrors::DistributionNotFound,
@@ -261,6 +283,7 @@ impl DistributionRepositoryTrait for DistributionRepositoryForSql {
             Err(err) => Err(CatalogAgentRepoErrors::DistributionRepoErrors(
                 DistributionRepoErrors::ErrorDeletingDistribution(err.into()),
             )),
-        }
+        };
+        bump_catalog_tree_version(&self.db_connection, written).await
     }
 }
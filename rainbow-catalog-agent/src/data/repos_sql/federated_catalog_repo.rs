@@ -0,0 +1,181 @@
+use crate::data::entities::federated_catalog::NewFederatedCatalogModel;
+use crate::data::entities::federated_dataset::NewFederatedDatasetModel;
+use crate::data::entities::{federated_catalog, federated_crawl_state, federated_dataset};
+use crate::data::repo_traits::catalog_db_errors::{
+    CatalogAgentRepoErrors, FederatedCatalogRepoErrors,
+};
+use crate::data::repo_traits::federated_catalog_repo::FederatedCatalogRepositoryTrait;
+use sea_orm::sea_query::OnConflict;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, Condition, DatabaseConnection, EntityTrait, QueryFilter,
+    QueryOrder, QuerySelect, TransactionTrait,
+};
+
+/// Keeps each insert well below the bind parameter limits of Postgres and SQLite
+const DATASET_INSERT_CHUNK: usize = 500;
+
+pub struct FederatedCatalogRepositoryForSql {
+    db_connection: DatabaseConnection,
+}
+
+impl FederatedCatalogRepositoryForSql {
+    pub fn new(db_connection: DatabaseConnection) -> Self {
+        Self { db_connection }
+    }
+}
+
+#[async_trait::async_trait]
+impl FederatedCatalogRepositoryTrait for FederatedCatalogRepositoryForSql {
+    async fn get_all_crawl_states(
+        &self,
+    ) -> anyhow::Result<Vec<federated_crawl_state::Model>, CatalogAgentRepoErrors> {
+        match federated_crawl_state::Entity::find()
+            .order_by_asc(federated_crawl_state::Column::NextCrawlAt)
+            .all(&self.db_connection)
+            .await
+        {
+            Ok(states) => Ok(states),
+            Err(err) => Err(CatalogAgentRepoErrors::FederatedCatalogRepoErrors(
+                FederatedCatalogRepoErrors::ErrorFetchingCrawlState(err.into()),
+            )),
+        }
+    }
+
+    async fn get_crawl_state_by_participant(
+        &self,
+        participant_id: &String,
+    ) -> anyhow::Result<Option<federated_crawl_state::Model>, CatalogAgentRepoErrors> {
+        match federated_crawl_state::Entity::find_by_id(participant_id.clone())
+            .one(&self.db_connection)
+            .await
+        {
+            Ok(state) => Ok(state),
+            Err(err) => Err(CatalogAgentRepoErrors::FederatedCatalogRepoErrors(
+                FederatedCatalogRepoErrors::ErrorFetchingCrawlState(err.into()),
+            )),
+        }
+    }
+
+    async fn put_crawl_state(
+        &self,
+        crawl_state: &federated_crawl_state::Model,
+    ) -> anyhow::Result<federated_crawl_state::Model, CatalogAgentRepoErrors> {
+        let active_model: federated_crawl_state::ActiveModel = crawl_state.clone().into();
+        let on_conflict = OnConflict::column(federated_crawl_state::Column::ParticipantId)
+            .update_columns([
+                federated_crawl_state::Column::Enabled,
+                federated_crawl_state::Column::CrawlInterval,
+                federated_crawl_state::Column::Etag,
+                federated_crawl_state::Column::LastModified,
+                federated_crawl_state::Column::ContentHash,
+                federated_crawl_state::Column::LastCrawledAt,
+                federated_crawl_state::Column::NextCrawlAt,
+                federated_crawl_state::Column::ConsecutiveFailures,
+                federated_crawl_state::Column::LastError,
+            ])
+            .to_owned();
+        match federated_crawl_state::Entity::insert(active_model)
+            .on_conflict(on_conflict)
+            .exec_with_returning(&self.db_connection)
+            .await
+        {
+            Ok(state) => Ok(state),
+            Err(err) => Err(CatalogAgentRepoErrors::FederatedCatalogRepoErrors(
+                FederatedCatalogRepoErrors::ErrorSavingCrawlState(err.into()),
+            )),
+        }
+    }
+
+    async fn get_federated_catalog_by_participant(
+        &self,
+        participant_id: &String,
+    ) -> anyhow::Result<Option<federated_catalog::Model>, CatalogAgentRepoErrors> {
+        match federated_catalog::Entity::find_by_id(participant_id.clone())
+            .one(&self.db_connection)
+            .await
+        {
+            Ok(catalog) => Ok(catalog),
+            Err(err) => Err(CatalogAgentRepoErrors::FederatedCatalogRepoErrors(
+                FederatedCatalogRepoErrors::ErrorFetchingFederatedCatalog(err.into()),
+            )),
+        }
+    }
+
+    async fn replace_federated_catalog(
+        &self,
+        new_catalog: &NewFederatedCatalogModel,
+        new_datasets: &Vec<NewFederatedDatasetModel>,
+    ) -> anyhow::Result<federated_catalog::Model, CatalogAgentRepoErrors> {
+        let to_repo_error = |err: sea_orm::DbErr| {
+            CatalogAgentRepoErrors::FederatedCatalogRepoErrors(
+                FederatedCatalogRepoErrors::ErrorReplacingFederatedCatalog(err.into()),
+            )
+        };
+        let participant_id = new_catalog.participant_id.clone();
+
+        // the whole snapshot of a peer is swapped at once, so readers never see half a crawl
+        let txn = self.db_connection.begin().await.map_err(to_repo_error)?;
+        federated_dataset::Entity::delete_many()
+            .filter(federated_dataset::Column::ParticipantId.eq(participant_id.clone()))
+            .exec(&txn)
+            .await
+            .map_err(to_repo_error)?;
+        federated_catalog::Entity::delete_by_id(participant_id.clone())
+            .exec(&txn)
+            .await
+            .map_err(to_repo_error)?;
+        let active_model: federated_catalog::ActiveModel = new_catalog.into();
+        let catalog = active_model.insert(&txn).await.map_err(to_repo_error)?;
+        for chunk in new_datasets.chunks(DATASET_INSERT_CHUNK) {
+            let models = chunk
+                .iter()
+                .cloned()
+                .map(|dataset| dataset.into_active_model(participant_id.as_str()))
+                .collect::<Vec<_>>();
+            federated_dataset::Entity::insert_many(models)
+                .exec(&txn)
+                .await
+                .map_err(to_repo_error)?;
+        }
+        txn.commit().await.map_err(to_repo_error)?;
+
+        Ok(catalog)
+    }
+
+    async fn get_federated_datasets(
+        &self,
+        limit: Option<u64>,
+        page: Option<u64>,
+        participant_id: Option<String>,
+        keyword: Option<String>,
+    ) -> anyhow::Result<Vec<federated_dataset::Model>, CatalogAgentRepoErrors> {
+        let page_limit = limit.unwrap_or(25);
+        let page_number = page.unwrap_or(1);
+        let calculated_offset = (page_number.max(1) - 1) * page_limit;
+
+        let mut query = federated_dataset::Entity::find();
+        if let Some(participant_id) = participant_id {
+            query = query.filter(federated_dataset::Column::ParticipantId.eq(participant_id));
+        }
+        if let Some(keyword) = keyword {
+            query = query.filter(
+                Condition::any()
+                    .add(federated_dataset::Column::DctTitle.contains(keyword.as_str()))
+                    .add(federated_dataset::Column::DctDescription.contains(keyword.as_str())),
+            );
+        }
+        match query
+            .order_by_asc(federated_dataset::Column::ParticipantId)
+            .order_by_asc(federated_dataset::Column::Id)
+            .limit(page_limit)
+            .offset(calculated_offset)
+            .all(&self.db_connection)
+            .await
+        {
+            Ok(datasets) => Ok(datasets),
+            Err(err) => Err(CatalogAgentRepoErrors::FederatedCatalogRepoErrors(
+                FederatedCatalogRepoErrors::ErrorFetchingFederatedCatalog(err.into()),
+            )),
+        }
+    }
+}
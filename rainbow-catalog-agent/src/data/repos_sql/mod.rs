@@ -18,8 +18,14 @@
  */
 
 pub(crate) mod catalog_repo;
+pub(crate) mod catalog_tree_repo;
 pub(crate) mod dataservice_repo;
 pub(crate) mod dataset_repo;
 pub(crate) mod distribution_repo;
+pub(crate) mod federated_catalog_repo;
 pub(crate) mod odrl_offer_repo;
 pub(crate) mod policy_template_repo;
+#[cfg(test)]
+mod test_dcat_metadata;
+#[cfg(test)]
+mod test_federated_catalog_repo;
@@ -5,6 +5,7 @@ use crate::data::repo_traits::catalog_db_errors::{
     DistributionRepoErrors, OdrlOfferRepoErrors,
 };
 use crate::data::repo_traits::odrl_offer_repo::OdrlOfferRepositoryTrait;
+use crate::data::repos_sql::catalog_tree_repo::bump_catalog_tree_version;
 use crate::entities::odrl_policies::CatalogEntityTypes;
 use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QuerySelect};
 use urn::Urn;
@@ -168,12 +169,13 @@ impl OdrlOfferRepositoryTrait for OdrlOfferRepositoryForSql {
             }
         };
 
-        match odrl_offer {
+        let written = match odrl_offer {
             Ok(odrl_offer) => Ok(odrl_offer),
             Err(err) => Err(CatalogAgentRepoErrors::OdrlOfferRepoErrors(
                 OdrlOfferRepoErrors::ErrorCreatingOdrlOffer(err.into()),
             )),
-        }
+        };
+        bump_catalog_tree_version(&self.db_connection, written).await
     }
 
     async fn delete_odrl_offer_by_id(
@@ -183,7 +185,7 @@ impl OdrlOfferRepositoryTrait for OdrlOfferRepositoryForSql {
         let odrl_offer_id = odrl_offer_id.to_string();
         let odrl_offer =
             odrl_offer::Entity::delete_by_id(odrl_offer_id).exec(&self.db_connection).await;
-        match odrl_offer {
+        let written = match odrl_offer {
             Ok(delete_result) => match delete_result.rows_affected {
                 0 => Err(CatalogAgentRepoErrors::OdrlOfferRepoErrors(
                     OdrlOfferRepoErrors::OdrlOfferNotFound,
@@ -193,7 +195,8 @@ impl OdrlOfferRepositoryTrait for OdrlOfferRepositoryForSql {
             Err(err) => Err(CatalogAgentRepoErrors::OdrlOfferRepoErrors(
                 OdrlOfferRepoErrors::ErrorDeletingOdrlOffer(err.into()),
             )),
-        }
+        };
+        bump_catalog_tree_version(&self.db_connection, written).await
     }
 
     async fn delete_odrl_offers_by_entity(
@@ -205,7 +208,7 @@ impl OdrlOfferRepositoryTrait for OdrlOfferRepositoryForSql {
             .filter(odrl_offer::Column::Entity.eq(entity_id))
             .exec(&self.db_connection)
             .await;
-        match odrl_offer {
+        let written = match odrl_offer {
             Ok(delete_result) => match delete_result.rows_affected {
                 0 => Err(CatalogAgentRepoErrors::OdrlOfferRepoErrors(
                     OdrlOfferRepoErrors::OdrlOfferNotFound,
@@ -215,6 +218,7 @@ impl OdrlOfferRepositoryTrait for OdrlOfferRepositoryForSql {
             Err(err) => Err(CatalogAgentRepoErrors::OdrlOfferRepoErrors(
                 OdrlOfferRepoErrors::ErrorDeletingOdrlOffer(err.into()),
             )),
-        }
+        };
+        bump_catalog_tree_version(&self.db_connection, written).await
     }
 }
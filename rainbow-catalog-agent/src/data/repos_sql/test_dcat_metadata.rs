@@ -0,0 +1,246 @@
+//! DCAT 3 metadata through the dataset, data service and distribution repositories
+//! List columns round trip and edits keep, replace or clear the optional fields
+
+use crate::data::entities::catalog::NewCatalogModel;
+use crate::data::entities::dataservice::{EditDataServiceModel, NewDataServiceModel};
+use crate::data::entities::dataset::{EditDatasetModel, NewDatasetModel};
+use crate::data::entities::distribution::{EditDistributionModel, NewDistributionModel};
+use crate::data::entities::{dataservice, dataset, string_list};
+use crate::data::factory_sql::CatalogAgentRepoForSql;
+use crate::data::factory_trait::CatalogAgentRepoTrait;
+use crate::data::migrations::Migrator;
+use crate::entities::data_services::EditDataServiceDto;
+use crate::entities::datasets::EditDatasetDto;
+use crate::entities::distributions::EditDistributionDto;
+use sea_orm::{ConnectOptions, Database};
+use sea_orm_migration::MigratorTrait;
+use serde_json::{json, Value};
+use std::str::FromStr;
+use urn::Urn;
+
+const CATALOG_ID: &str = "urn:catalog:dcat";
+
+async fn repo() -> CatalogAgentRepoForSql {
+    // a single connection, every new in-memory connection is a new empty database
+    let mut options = ConnectOptions::new("sqlite::memory:");
+    options.max_connections(1).sqlx_logging(false);
+    let db = Database::connect(options).await.unwrap();
+    Migrator::up(&db, None).await.unwrap();
+    let repo = CatalogAgentRepoForSql::create_repo(db);
+    repo.get_catalog_repo()
+        .create_main_catalog(&NewCatalogModel {
+            id: Some(Urn::from_str(CATALOG_ID).unwrap()),
+            foaf_home_page: None,
+            dct_conforms_to: None,
+            dct_creator: None,
+            dct_title: Some("DCAT catalog".to_string()),
+            dspace_participant_id: None,
+        })
+        .await
+        .unwrap();
+    repo
+}
+
+fn strings(values: &[&str]) -> Option<Vec<String>> {
+    Some(values.iter().map(|value| value.to_string()).collect())
+}
+
+fn id_of(id: &str) -> Urn {
+    Urn::from_str(id).unwrap()
+}
+
+async fn create_dataset(repo: &CatalogAgentRepoForSql) -> dataset::Model {
+    repo.get_dataset_repo()
+        .create_dataset(&NewDatasetModel {
+            id: None,
+            dct_conforms_to: None,
+            dct_creator: None,
+            dct_title: Some("Air quality".to_string()),
+            dct_description: None,
+            catalog_id: id_of(CATALOG_ID),
+            dcat_keyword: strings(&["air", "quality"]),
+            dcat_theme: strings(&[
+                "http://publications.europa.eu/resource/authority/data-theme/ENVI",
+            ]),
+            dct_language: None,
+            dct_license: Some("https://creativecommons.org/licenses/by/4.0/".to_string()),
+            dct_access_rights: Some("public".to_string()),
+            dct_spatial: Some("Madrid".to_string()),
+            dct_temporal_start_date: Some(chrono::Utc::now().into()),
+            dct_temporal_end_date: None,
+            dcat_contact_point: Some("mailto:data@example.org".to_string()),
+            dcat_version: Some("1.0".to_string()),
+            dcat_previous_version: None,
+        })
+        .await
+        .unwrap()
+}
+
+async fn create_data_service(repo: &CatalogAgentRepoForSql) -> dataservice::Model {
+    repo.get_dataservice_repo()
+        .create_data_service(&NewDataServiceModel {
+            id: None,
+            dcat_endpoint_description: None,
+            dcat_endpoint_url: "http://localhost:1234/data".to_string(),
+            dct_conforms_to: None,
+            dct_creator: None,
+            dct_title: None,
+            dct_description: None,
+            catalog_id: id_of(CATALOG_ID),
+            dspace_main_data_service: false,
+            dcat_keyword: strings(&["api"]),
+            dcat_theme: strings(&["sensors"]),
+            dct_license: Some("https://opensource.org/licenses/MIT".to_string()),
+            dct_access_rights: None,
+            dcat_contact_point: Some("mailto:api@example.org".to_string()),
+        })
+        .await
+        .unwrap()
+}
+
+/// Edit bodies go through the same JSON parsing the HTTP API does
+fn edit<T: serde::de::DeserializeOwned>(body: Value) -> T {
+    serde_json::from_value(body).unwrap()
+}
+
+#[test]
+fn string_list_reads_arrays_and_single_strings() {
+    assert_eq!(string_list(&Some(json!(["a", 1, "b"]))), vec!["a", "b"]);
+    assert_eq!(string_list(&Some(json!("a"))), vec!["a"]);
+    assert!(string_list(&Some(json!({ "a": "b" }))).is_empty());
+    assert!(string_list(&None).is_empty());
+}
+
+#[tokio::test]
+async fn list_columns_round_trip() {
+    let repo = repo().await;
+    let created = create_dataset(&repo).await;
+
+    let stored =
+        repo.get_dataset_repo().get_dataset_by_id(&id_of(&created.id)).await.unwrap().unwrap();
+    assert_eq!(stored.keywords(), vec!["air", "quality"]);
+    assert_eq!(
+        stored.themes(),
+        vec!["http://publications.europa.eu/resource/authority/data-theme/ENVI"]
+    );
+    // never set stays a null column, not an empty array
+    assert_eq!(stored.dct_language, None);
+    assert!(stored.languages().is_empty());
+
+    let data_service = create_data_service(&repo).await;
+    let stored = repo
+        .get_dataservice_repo()
+        .get_data_service_by_id(&id_of(&data_service.id))
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(stored.keywords(), vec!["api"]);
+    assert_eq!(stored.themes(), vec!["sensors"]);
+}
+
+#[tokio::test]
+async fn dataset_edit_keeps_replaces_and_clears() {
+    let repo = repo().await;
+    let created = create_dataset(&repo).await;
+    let dataset_id = id_of(&created.id);
+
+    let edit_model = EditDatasetModel::from(edit::<EditDatasetDto>(json!({
+        "dctLicense": null,
+        "dctTemporalStartDate": null,
+        "dcatKeyword": ["pollution"],
+        "dctLanguage": ["es", "en"]
+    })));
+    let edited = repo.get_dataset_repo().put_dataset_by_id(&dataset_id, &edit_model).await.unwrap();
+    assert_eq!(edited.dct_license, None);
+    assert_eq!(edited.dct_temporal_start_date, None);
+    assert_eq!(edited.keywords(), vec!["pollution"]);
+    assert_eq!(edited.languages(), vec!["es", "en"]);
+    // left out of the body, so untouched
+    assert_eq!(edited.dct_title, created.dct_title);
+    assert_eq!(edited.dct_access_rights, created.dct_access_rights);
+    assert_eq!(edited.dct_spatial, created.dct_spatial);
+    assert_eq!(edited.dcat_contact_point, created.dcat_contact_point);
+    assert_eq!(edited.themes(), created.themes());
+
+    let edit_model = EditDatasetModel::from(edit::<EditDatasetDto>(json!({
+        "dcatKeyword": null,
+        "dcatTheme": null,
+        "dcatVersion": null
+    })));
+    repo.get_dataset_repo().put_dataset_by_id(&dataset_id, &edit_model).await.unwrap();
+    let stored = repo.get_dataset_repo().get_dataset_by_id(&dataset_id).await.unwrap().unwrap();
+    assert_eq!(stored.dcat_keyword, None);
+    assert_eq!(stored.dcat_theme, None);
+    assert_eq!(stored.dcat_version, None);
+    assert_eq!(stored.languages(), vec!["es", "en"]);
+}
+
+#[tokio::test]
+async fn data_service_edit_clears_optional_fields() {
+    let repo = repo().await;
+    let created = create_data_service(&repo).await;
+    let data_service_id = id_of(&created.id);
+
+    let edit_model = EditDataServiceModel::from(edit::<EditDataServiceDto>(json!({
+        "dctLicense": null,
+        "dcatContactPoint": null,
+        "dcatTheme": ["sensors", "mobility"]
+    })));
+    let edited = repo
+        .get_dataservice_repo()
+        .put_data_service_by_id(&data_service_id, &edit_model)
+        .await
+        .unwrap();
+    assert_eq!(edited.dct_license, None);
+    assert_eq!(edited.dcat_contact_point, None);
+    assert_eq!(edited.themes(), vec!["sensors", "mobility"]);
+    assert_eq!(edited.keywords(), vec!["api"]);
+    assert_eq!(edited.dcat_endpoint_url, created.dcat_endpoint_url);
+}
+
+#[tokio::test]
+async fn distribution_edit_clears_optional_fields() {
+    let repo = repo().await;
+    let dataset = create_dataset(&repo).await;
+    let data_service = create_data_service(&repo).await;
+    let created = repo
+        .get_distribution_repo()
+        .create_distribution(&NewDistributionModel {
+            id: None,
+            dct_title: Some("CSV dump".to_string()),
+            dct_description: None,
+            dct_formats: None,
+            dcat_access_service: data_service.id.clone(),
+            dataset_id: id_of(&dataset.id),
+            dcat_byte_size: Some(2048),
+            dcat_media_type: Some("text/csv".to_string()),
+            spdx_checksum_algorithm: Some("sha256".to_string()),
+            spdx_checksum_value: Some("9f86d081884c7d65".to_string()),
+            dct_license: None,
+            dct_access_rights: None,
+        })
+        .await
+        .unwrap();
+    let distribution_id = id_of(&created.id);
+
+    let edit_model = EditDistributionModel::from(edit::<EditDistributionDto>(json!({
+        "dcatByteSize": null,
+        "spdxChecksumAlgorithm": null,
+        "spdxChecksumValue": null,
+        "dctLicense": "https://creativecommons.org/publicdomain/zero/1.0/"
+    })));
+    let edited = repo
+        .get_distribution_repo()
+        .put_distribution_by_id(&distribution_id, &edit_model)
+        .await
+        .unwrap();
+    assert_eq!(edited.dcat_byte_size, None);
+    assert_eq!(edited.spdx_checksum_algorithm, None);
+    assert_eq!(edited.spdx_checksum_value, None);
+    assert_eq!(
+        edited.dct_license.as_deref(),
+        Some("https://creativecommons.org/publicdomain/zero/1.0/")
+    );
+    assert_eq!(edited.dcat_media_type, created.dcat_media_type);
+    assert_eq!(edited.dct_title, created.dct_title);
+}
@@ -0,0 +1,183 @@
+//! Federated catalog repository against an in-memory SQLite database
+//! Crawl state upserts, snapshot replacement and the federated dataset search
+
+use crate::data::entities::federated_catalog::NewFederatedCatalogModel;
+use crate::data::entities::federated_crawl_state;
+use crate::data::entities::federated_dataset::NewFederatedDatasetModel;
+use crate::data::migrations::Migrator;
+use crate::data::repo_traits::federated_catalog_repo::FederatedCatalogRepositoryTrait;
+use crate::data::repos_sql::federated_catalog_repo::FederatedCatalogRepositoryForSql;
+use sea_orm::{ConnectOptions, Database};
+use sea_orm_migration::MigratorTrait;
+use serde_json::json;
+
+async fn repo() -> FederatedCatalogRepositoryForSql {
+    // a single connection, every new in-memory connection is a new empty database
+    let mut options = ConnectOptions::new("sqlite::memory:");
+    options.max_connections(1).sqlx_logging(false);
+    let db = Database::connect(options).await.unwrap();
+    Migrator::up(&db, None).await.unwrap();
+    FederatedCatalogRepositoryForSql::new(db)
+}
+
+fn crawl_state(participant_id: &str, next_crawl_in_secs: i64) -> federated_crawl_state::Model {
+    federated_crawl_state::Model {
+        participant_id: participant_id.to_string(),
+        enabled: true,
+        crawl_interval: 60,
+        etag: None,
+        last_modified: None,
+        content_hash: None,
+        last_crawled_at: None,
+        next_crawl_at: (chrono::Utc::now() + chrono::Duration::seconds(next_crawl_in_secs)).into(),
+        consecutive_failures: 0,
+        last_error: None,
+    }
+}
+
+fn new_catalog(participant_id: &str) -> NewFederatedCatalogModel {
+    NewFederatedCatalogModel {
+        participant_id: participant_id.to_string(),
+        catalog_id: format!("urn:catalog:{}", participant_id),
+        dct_title: Some(format!("Catalog of {}", participant_id)),
+        content: json!({}),
+    }
+}
+
+fn new_dataset(id: &str, title: &str, description: Option<&str>) -> NewFederatedDatasetModel {
+    NewFederatedDatasetModel {
+        id: id.to_string(),
+        catalog_id: "urn:catalog:peer".to_string(),
+        dct_title: Some(title.to_string()),
+        dct_description: description.map(str::to_string),
+        dct_conforms_to: None,
+        content: json!({ "@id": id }),
+    }
+}
+
+#[tokio::test]
+async fn test_crawl_state_is_upserted() {
+    let repo = repo().await;
+    repo.put_crawl_state(&crawl_state("did:web:late.example", 600)).await.unwrap();
+    repo.put_crawl_state(&crawl_state("did:web:early.example", 0)).await.unwrap();
+
+    let mut failed = crawl_state("did:web:late.example", 1200);
+    failed.etag = Some("\"v1\"".to_string());
+    failed.consecutive_failures = 2;
+    failed.last_error = Some("peer unreachable".to_string());
+    let saved = repo.put_crawl_state(&failed).await.unwrap();
+    assert_eq!(saved.consecutive_failures, 2);
+
+    let stored =
+        repo.get_crawl_state_by_participant(&"did:web:late.example".to_string()).await.unwrap();
+    let stored = stored.expect("crawl state is stored");
+    assert_eq!(stored.etag.as_deref(), Some("\"v1\""));
+    assert_eq!(stored.last_error.as_deref(), Some("peer unreachable"));
+
+    // soonest crawl first
+    let participants = repo
+        .get_all_crawl_states()
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|state| state.participant_id)
+        .collect::<Vec<_>>();
+    assert_eq!(participants, vec!["did:web:early.example", "did:web:late.example"]);
+}
+
+#[tokio::test]
+async fn test_replacing_a_catalog_drops_the_previous_datasets() {
+    let repo = repo().await;
+    let participant_id = "did:web:peer.example".to_string();
+    repo.replace_federated_catalog(
+        &new_catalog(&participant_id),
+        &vec![
+            new_dataset("urn:dataset:1", "Weather", None),
+            new_dataset("urn:dataset:2", "Traffic", None),
+        ],
+    )
+    .await
+    .unwrap();
+    repo.replace_federated_catalog(
+        &new_catalog(&participant_id),
+        &vec![new_dataset("urn:dataset:3", "Air quality", None)],
+    )
+    .await
+    .unwrap();
+
+    let catalog = repo.get_federated_catalog_by_participant(&participant_id).await.unwrap();
+    assert_eq!(catalog.unwrap().catalog_id, "urn:catalog:did:web:peer.example");
+    let datasets =
+        repo.get_federated_datasets(None, None, Some(participant_id), None).await.unwrap();
+    assert_eq!(
+        datasets.into_iter().map(|dataset| dataset.id).collect::<Vec<_>>(),
+        vec!["urn:dataset:3"]
+    );
+}
+
+#[tokio::test]
+async fn test_large_catalog_is_inserted_in_chunks() {
+    let repo = repo().await;
+    let participant_id = "did:web:peer.example".to_string();
+    let datasets = (0..1200)
+        .map(|i| new_dataset(format!("urn:dataset:{:04}", i).as_str(), "Dataset", None))
+        .collect::<Vec<_>>();
+    repo.replace_federated_catalog(&new_catalog(&participant_id), &datasets).await.unwrap();
+
+    let last_page =
+        repo.get_federated_datasets(Some(500), Some(3), Some(participant_id), None).await.unwrap();
+    assert_eq!(last_page.len(), 200);
+    assert_eq!(last_page[0].id, "urn:dataset:1000");
+}
+
+#[tokio::test]
+async fn test_federated_datasets_are_filtered_and_paged() {
+    let repo = repo().await;
+    repo.replace_federated_catalog(
+        &new_catalog("did:web:a.example"),
+        &vec![
+            new_dataset("urn:dataset:a1", "Weather stations", None),
+            new_dataset(
+                "urn:dataset:a2",
+                "Bus stops",
+                Some("Stops of the weather-proof lines"),
+            ),
+        ],
+    )
+    .await
+    .unwrap();
+    repo.replace_federated_catalog(
+        &new_catalog("did:web:b.example"),
+        &vec![new_dataset("urn:dataset:b1", "Weather forecast", None)],
+    )
+    .await
+    .unwrap();
+
+    let ids = |datasets: Vec<crate::data::entities::federated_dataset::Model>| {
+        datasets.into_iter().map(|dataset| dataset.id).collect::<Vec<_>>()
+    };
+    // ordered by participant, then by id
+    let all = repo.get_federated_datasets(None, None, None, None).await.unwrap();
+    assert_eq!(ids(all), vec!["urn:dataset:a1", "urn:dataset:a2", "urn:dataset:b1"]);
+
+    // the keyword matches the title or the description
+    let proof = repo
+        .get_federated_datasets(
+            None,
+            None,
+            Some("did:web:a.example".to_string()),
+            Some("proof".to_string()),
+        )
+        .await
+        .unwrap();
+    assert_eq!(ids(proof), vec!["urn:dataset:a2"]);
+    let forecast =
+        repo.get_federated_datasets(None, None, None, Some("forecast".to_string())).await.unwrap();
+    assert_eq!(ids(forecast), vec!["urn:dataset:b1"]);
+
+    // pages start at 1, page 0 is read as the first one
+    let second_page = repo.get_federated_datasets(Some(2), Some(2), None, None).await.unwrap();
+    assert_eq!(ids(second_page), vec!["urn:dataset:b1"]);
+    let first_page = repo.get_federated_datasets(Some(2), Some(0), None, None).await.unwrap();
+    assert_eq!(ids(first_page), vec!["urn:dataset:a1", "urn:dataset:a2"]);
+}
@@ -0,0 +1,107 @@
+use crate::data::factory_trait::CatalogAgentRepoTrait;
+use crate::data::repo_traits::catalog_tree_repo::CatalogTreeModel;
+use crate::entities::catalog_tree::{CatalogTreeEntityTrait, CatalogTreeSnapshot};
+use log::debug;
+use rainbow_common::errors::CommonErrors;
+use std::sync::Arc;
+use tokio::sync::{Mutex, RwLock};
+
+pub struct CatalogTreeEntities {
+    repo: Arc<dyn CatalogAgentRepoTrait>,
+    snapshot: RwLock<Option<Arc<CatalogTreeSnapshot>>>,
+    // only one request rebuilds a stale snapshot, the rest wait and reuse it
+    reload: Mutex<()>,
+}
+
+impl CatalogTreeEntities {
+    pub fn new(repo: Arc<dyn CatalogAgentRepoTrait>) -> Self {
+        Self { repo, snapshot: RwLock::new(None), reload: Mutex::new(()) }
+    }
+
+    async fn current(&self, version: i64) -> Option<Arc<CatalogTreeSnapshot>> {
+        self.snapshot.read().await.as_ref().filter(|s| s.version == version).cloned()
+    }
+
+    async fn version(&self) -> anyhow::Result<i64> {
+        let version = self
+            .repo
+            .get_catalog_tree_repo()
+            .get_catalog_tree_version()
+            .await
+            .map_err(|e| CommonErrors::database_new(&e.to_string()))?;
+        Ok(version)
+    }
+
+    fn index(tree: CatalogTreeModel) -> CatalogTreeSnapshot {
+        let mut snapshot = CatalogTreeSnapshot { version: tree.version, ..Default::default() };
+        for catalog in tree.catalogs {
+            if catalog.dspace_main_catalog && snapshot.main_catalog.is_none() {
+                snapshot.main_catalog = Some(catalog.clone().into());
+            }
+            snapshot.catalogs.push(catalog.into());
+        }
+        for dataset in tree.datasets {
+            snapshot
+                .datasets_by_catalog
+                .entry(dataset.catalog_id.clone())
+                .or_default()
+                .push(dataset.clone().into());
+            snapshot.datasets_by_id.insert(dataset.id.clone(), dataset.into());
+        }
+        for distribution in tree.distributions {
+            snapshot
+                .distributions_by_dataset
+                .entry(distribution.dataset_id.clone())
+                .or_default()
+                .push(distribution.into());
+        }
+        for data_service in tree.data_services {
+            if data_service.dspace_main_data_service && snapshot.main_data_service.is_none() {
+                snapshot.main_data_service = Some(data_service.clone().into());
+            }
+            snapshot
+                .data_services_by_catalog
+                .entry(data_service.catalog_id.clone())
+                .or_default()
+                .push(data_service.clone().into());
+            snapshot.data_services_by_id.insert(data_service.id.clone(), data_service.into());
+        }
+        for odrl_offer in tree.odrl_offers {
+            snapshot
+                .odrl_offers_by_entity
+                .entry(odrl_offer.entity.clone())
+                .or_default()
+                .push(odrl_offer.into());
+        }
+        snapshot
+    }
+}
+
+#[async_trait::async_trait]
+impl CatalogTreeEntityTrait for CatalogTreeEntities {
+    async fn get_catalog_tree(&self) -> anyhow::Result<Arc<CatalogTreeSnapshot>> {
+        // writes from any replica bump the version in the database
+        if let Some(snapshot) = self.current(self.version().await?).await {
+            return Ok(snapshot);
+        }
+
+        let _reload = self.reload.lock().await;
+        if let Some(snapshot) = self.current(self.version().await?).await {
+            return Ok(snapshot);
+        }
+        let tree = self
+            .repo
+            .get_catalog_tree_repo()
+            .get_catalog_tree()
+            .await
+            .map_err(|e| CommonErrors::database_new(&e.to_string()))?;
+        let snapshot = Arc::new(Self::index(tree));
+        debug!(
+            "Catalog tree snapshot {} built with {} datasets",
+            snapshot.version,
+            snapshot.datasets_by_id.len()
+        );
+        *self.snapshot.write().await = Some(snapshot.clone());
+        Ok(snapshot)
+    }
+}
@@ -0,0 +1,54 @@
+pub(crate) mod catalog_tree;
+
+use crate::entities::catalogs::CatalogDto;
+use crate::entities::data_services::DataServiceDto;
+use crate::entities::datasets::DatasetDto;
+use crate::entities::distributions::DistributionDto;
+use crate::entities::odrl_policies::OdrlPolicyDto;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Whole catalog tree indexed for DSP assembly, rebuilt after any write to it
+#[derive(Debug, Default)]
+pub struct CatalogTreeSnapshot {
+    /// Catalog tree version in the database when the snapshot was loaded
+    pub version: i64,
+    pub main_catalog: Option<CatalogDto>,
+    pub main_data_service: Option<DataServiceDto>,
+    pub catalogs: Vec<CatalogDto>,
+    pub datasets_by_id: HashMap<String, DatasetDto>,
+    pub datasets_by_catalog: HashMap<String, Vec<DatasetDto>>,
+    pub distributions_by_dataset: HashMap<String, Vec<DistributionDto>>,
+    pub data_services_by_id: HashMap<String, DataServiceDto>,
+    pub data_services_by_catalog: HashMap<String, Vec<DataServiceDto>>,
+    pub odrl_offers_by_entity: HashMap<String, Vec<OdrlPolicyDto>>,
+}
+
+impl CatalogTreeSnapshot {
+    pub fn sub_catalogs(&self) -> impl Iterator<Item = &CatalogDto> {
+        self.catalogs.iter().filter(|catalog| !catalog.inner.dspace_main_catalog)
+    }
+
+    pub fn datasets_of(&self, catalog_id: &str) -> &[DatasetDto] {
+        self.datasets_by_catalog.get(catalog_id).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    pub fn distributions_of(&self, dataset_id: &str) -> &[DistributionDto] {
+        self.distributions_by_dataset.get(dataset_id).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    pub fn data_services_of(&self, catalog_id: &str) -> &[DataServiceDto] {
+        self.data_services_by_catalog.get(catalog_id).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    pub fn odrl_offers_of(&self, entity_id: &str) -> &[OdrlPolicyDto] {
+        self.odrl_offers_by_entity.get(entity_id).map(Vec::as_slice).unwrap_or_default()
+    }
+}
+
+#[mockall::automock]
+#[async_trait::async_trait]
+pub trait CatalogTreeEntityTrait: Send + Sync {
+    /// Current snapshot, reloaded from the database when a write happened since it was built
+    async fn get_catalog_tree(&self) -> anyhow::Result<Arc<CatalogTreeSnapshot>>;
+}
@@ -2,8 +2,11 @@ use crate::cache::factory_trait::CatalogAgentCacheTrait;
 use crate::data::entities::catalog::{EditCatalogModel, NewCatalogModel};
 use crate::data::factory_trait::CatalogAgentRepoTrait;
 use crate::entities::catalogs::{CatalogDto, CatalogEntityTrait, EditCatalogDto, NewCatalogDto};
+use crate::entities::notifications::catalog_entity_event;
 use log::error;
 use rainbow_common::errors::{CommonErrors, ErrorLog};
+use rainbow_events::core::notification::notification_publisher::RainbowEventsPublisherTrait;
+use rainbow_events::core::notification::notification_types::RainbowEventsNotificationMessageOperation;
 use std::str::FromStr;
 use std::sync::Arc;
 use urn::Urn;
@@ -11,14 +14,16 @@ use urn::Urn;
 pub struct CatalogEntities {
     repo: Arc<dyn CatalogAgentRepoTrait>,
     cache: Arc<dyn CatalogAgentCacheTrait>,
+    events: Arc<dyn RainbowEventsPublisherTrait>,
 }
 
 impl CatalogEntities {
     pub fn new(
         repo: Arc<dyn CatalogAgentRepoTrait>,
         cache: Arc<dyn CatalogAgentCacheTrait>,
+        events: Arc<dyn RainbowEventsPublisherTrait>,
     ) -> Self {
-        Self { repo, cache }
+        Self { repo, cache, events }
     }
 }
 
@@ -165,6 +170,12 @@ impl CatalogEntityTrait for CatalogEntities {
         let _ =
             cache.add_to_collection(&catalog_urn, dto.inner.dct_issued.timestamp() as f64).await;
 
+        self.events.publish(
+            catalog_entity_event("Catalog", RainbowEventsNotificationMessageOperation::Update)
+                .with_entity_id("catalogId", &dto.inner.id)
+                .with_snapshot(&dto),
+        );
+
         Ok(dto)
     }
 
@@ -189,6 +200,12 @@ impl CatalogEntityTrait for CatalogEntities {
         let _ =
             cache.add_to_collection(&catalog_urn, dto.inner.dct_issued.timestamp() as f64).await;
 
+        self.events.publish(
+            catalog_entity_event("Catalog", RainbowEventsNotificationMessageOperation::Creation)
+                .with_entity_id("catalogId", &dto.inner.id)
+                .with_snapshot(&dto),
+        );
+
         Ok(dto)
     }
 
@@ -208,6 +225,13 @@ impl CatalogEntityTrait for CatalogEntities {
 
         // cache
         self.cache.get_catalog_cache().set_main(&catalog_urn, &dto).await;
+
+        self.events.publish(
+            catalog_entity_event("Catalog", RainbowEventsNotificationMessageOperation::Creation)
+                .with_entity_id("catalogId", &dto.inner.id)
+                .with_snapshot(&dto),
+        );
+
         Ok(dto)
     }
 
@@ -222,6 +246,11 @@ impl CatalogEntityTrait for CatalogEntities {
         let _ = self.cache.get_catalog_cache().delete_single(catalog_id).await;
         let _ = self.cache.get_catalog_cache().remove_from_collection(catalog_id).await;
 
+        self.events.publish(
+            catalog_entity_event("Catalog", RainbowEventsNotificationMessageOperation::Deletion)
+                .with_entity_id("catalogId", catalog_id),
+        );
+
         Ok(())
     }
 }
@@ -1,4 +1,4 @@
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(untagged)]
@@ -6,3 +6,13 @@ pub enum PolicyTemplateAllowedDefaultValues {
     Stringable(String),
     Numerable(f32),
 }
+
+/// Tells an absent field from an explicit null in edit requests.
+/// Absent keeps the stored value (`None`), null clears it (`Some(None)`).
+pub(crate) fn nullable<'de, D, T>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Some)
+}
@@ -4,8 +4,11 @@ use crate::data::factory_trait::CatalogAgentRepoTrait;
 use crate::entities::data_services::{
     DataServiceDto, DataServiceEntityTrait, EditDataServiceDto, NewDataServiceDto,
 };
+use crate::entities::notifications::catalog_entity_event;
 use log::error;
 use rainbow_common::errors::{CommonErrors, ErrorLog};
+use rainbow_events::core::notification::notification_publisher::RainbowEventsPublisherTrait;
+use rainbow_events::core::notification::notification_types::RainbowEventsNotificationMessageOperation;
 use std::str::FromStr;
 use std::sync::Arc;
 use urn::Urn;
@@ -13,14 +16,16 @@ use urn::Urn;
 pub struct DataServiceEntities {
     repo: Arc<dyn CatalogAgentRepoTrait>,
     cache: Arc<dyn CatalogAgentCacheTrait>,
+    events: Arc<dyn RainbowEventsPublisherTrait>,
 }
 
 impl DataServiceEntities {
     pub fn new(
         repo: Arc<dyn CatalogAgentRepoTrait>,
         cache: Arc<dyn CatalogAgentCacheTrait>,
+        events: Arc<dyn RainbowEventsPublisherTrait>,
     ) -> Self {
-        Self { repo, cache }
+        Self { repo, cache, events }
     }
 }
 
@@ -209,6 +214,13 @@ impl DataServiceEntityTrait for DataServiceEntities {
         let _ = cache.set_single(&ds_urn, &dto).await;
         let _ = cache.add_to_collection(&ds_urn, dto.inner.dct_issued.timestamp() as f64).await;
 
+        self.events.publish(
+            catalog_entity_event("DataService", RainbowEventsNotificationMessageOperation::Update)
+                .with_entity_id("dataServiceId", &dto.inner.id)
+                .with_entity_id("catalogId", &dto.inner.catalog_id)
+                .with_snapshot(&dto),
+        );
+
         Ok(dto)
     }
 
@@ -239,6 +251,16 @@ impl DataServiceEntityTrait for DataServiceEntities {
             let _ = cache.add_to_relation("catalogs", &catalog_id, &ds_urn, score).await;
         }
 
+        self.events.publish(
+            catalog_entity_event(
+                "DataService",
+                RainbowEventsNotificationMessageOperation::Creation,
+            )
+            .with_entity_id("dataServiceId", &dto.inner.id)
+            .with_entity_id("catalogId", &dto.inner.catalog_id)
+            .with_snapshot(&dto),
+        );
+
         Ok(dto)
     }
 
@@ -260,6 +282,17 @@ impl DataServiceEntityTrait for DataServiceEntities {
         if let Ok(id) = Urn::from_str(dto.inner.id.as_str()) {
             let _ = self.cache.get_dataservice_cache().set_main(&id, &dto).await;
         }
+
+        self.events.publish(
+            catalog_entity_event(
+                "DataService",
+                RainbowEventsNotificationMessageOperation::Creation,
+            )
+            .with_entity_id("dataServiceId", &dto.inner.id)
+            .with_entity_id("catalogId", &dto.inner.catalog_id)
+            .with_snapshot(&dto),
+        );
+
         Ok(dto)
     }
 
@@ -280,12 +313,21 @@ impl DataServiceEntityTrait for DataServiceEntities {
         let _ = cache.remove_from_collection(data_service_id).await;
 
         // lookup invalidation
-        if let Some(dto) = current {
+        if let Some(dto) = &current {
             if let Ok(catalog_id) = Urn::from_str(&*dto.inner.catalog_id) {
                 let _ = cache.remove_from_relation("catalogs", &catalog_id, data_service_id).await;
             }
         }
 
+        self.events.publish(
+            catalog_entity_event(
+                "DataService",
+                RainbowEventsNotificationMessageOperation::Deletion,
+            )
+            .with_entity_id("dataServiceId", data_service_id)
+            .with_snapshot(&current),
+        );
+
         Ok(())
     }
 }
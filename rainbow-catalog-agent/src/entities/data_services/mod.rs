@@ -2,6 +2,7 @@ pub(crate) mod data_services;
 
 use crate::data::entities::dataservice;
 use crate::data::entities::dataservice::{EditDataServiceModel, Model, NewDataServiceModel};
+use crate::entities::common::nullable;
 use serde::{Deserialize, Serialize};
 use std::str::FromStr;
 use urn::{Urn, UrnBuilder};
@@ -25,6 +26,11 @@ pub struct NewDataServiceDto {
     pub dct_title: Option<String>,
     pub dct_description: Option<String>,
     pub catalog_id: Urn,
+    pub dcat_keyword: Option<Vec<String>>,
+    pub dcat_theme: Option<Vec<String>>,
+    pub dct_license: Option<String>,
+    pub dct_access_rights: Option<String>,
+    pub dcat_contact_point: Option<String>,
 }
 
 impl Default for NewDataServiceDto {
@@ -38,10 +44,16 @@ impl Default for NewDataServiceDto {
             dct_title: None,
             dct_description: None,
             catalog_id: Urn::from_str("urn:fake-urn:000").unwrap(),
+            dcat_keyword: None,
+            dcat_theme: None,
+            dct_license: None,
+            dct_access_rights: None,
+            dcat_contact_point: None,
         }
     }
 }
 
+/// Fields left out are kept, the optional DCAT 3 ones are cleared with an explicit null
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 #[serde(deny_unknown_fields)]
@@ -52,6 +64,16 @@ pub struct EditDataServiceDto {
     pub dct_creator: Option<String>,
     pub dct_title: Option<String>,
     pub dct_description: Option<String>,
+    #[serde(default, deserialize_with = "nullable", skip_serializing_if = "Option::is_none")]
+    pub dcat_keyword: Option<Option<Vec<String>>>,
+    #[serde(default, deserialize_with = "nullable", skip_serializing_if = "Option::is_none")]
+    pub dcat_theme: Option<Option<Vec<String>>>,
+    #[serde(default, deserialize_with = "nullable", skip_serializing_if = "Option::is_none")]
+    pub dct_license: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable", skip_serializing_if = "Option::is_none")]
+    pub dct_access_rights: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable", skip_serializing_if = "Option::is_none")]
+    pub dcat_contact_point: Option<Option<String>>,
 }
 
 impl From<NewDataServiceDto> for NewDataServiceModel {
@@ -66,6 +88,11 @@ impl From<NewDataServiceDto> for NewDataServiceModel {
             dct_description: dto.dct_description,
             catalog_id: dto.catalog_id,
             dspace_main_data_service: false,
+            dcat_keyword: dto.dcat_keyword,
+            dcat_theme: dto.dcat_theme,
+            dct_license: dto.dct_license,
+            dct_access_rights: dto.dct_access_rights,
+            dcat_contact_point: dto.dcat_contact_point,
         }
     }
 }
@@ -79,6 +106,11 @@ impl From<EditDataServiceDto> for EditDataServiceModel {
             dct_creator: dto.dct_creator,
             dct_title: dto.dct_title,
             dct_description: dto.dct_description,
+            dcat_keyword: dto.dcat_keyword,
+            dcat_theme: dto.dcat_theme,
+            dct_license: dto.dct_license,
+            dct_access_rights: dto.dct_access_rights,
+            dcat_contact_point: dto.dcat_contact_point,
         }
     }
 }
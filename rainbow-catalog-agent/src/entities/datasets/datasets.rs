@@ -1,8 +1,11 @@
 use crate::cache::factory_trait::CatalogAgentCacheTrait;
 use crate::data::factory_trait::CatalogAgentRepoTrait;
 use crate::entities::datasets::{DatasetDto, DatasetEntityTrait, EditDatasetDto, NewDatasetDto};
+use crate::entities::notifications::catalog_entity_event;
 use log::error;
 use rainbow_common::errors::{CommonErrors, ErrorLog};
+use rainbow_events::core::notification::notification_publisher::RainbowEventsPublisherTrait;
+use rainbow_events::core::notification::notification_types::RainbowEventsNotificationMessageOperation;
 use std::str::FromStr;
 use std::sync::Arc;
 use urn::Urn;
@@ -10,14 +13,16 @@ use urn::Urn;
 pub struct DatasetEntities {
     repo: Arc<dyn CatalogAgentRepoTrait>,
     cache: Arc<dyn CatalogAgentCacheTrait>,
+    events: Arc<dyn RainbowEventsPublisherTrait>,
 }
 
 impl DatasetEntities {
     pub fn new(
         repo: Arc<dyn CatalogAgentRepoTrait>,
         cache: Arc<dyn CatalogAgentCacheTrait>,
+        events: Arc<dyn RainbowEventsPublisherTrait>,
     ) -> Self {
-        Self { repo, cache }
+        Self { repo, cache, events }
     }
 }
 
@@ -170,6 +175,13 @@ impl DatasetEntityTrait for DatasetEntities {
         let _ = cache.set_single(&ds_urn, &dto).await;
         let _ = cache.add_to_collection(&ds_urn, dto.inner.dct_issued.timestamp() as f64).await;
 
+        self.events.publish(
+            catalog_entity_event("Dataset", RainbowEventsNotificationMessageOperation::Update)
+                .with_entity_id("datasetId", &dto.inner.id)
+                .with_entity_id("catalogId", &dto.inner.catalog_id)
+                .with_snapshot(&dto),
+        );
+
         Ok(dto)
     }
 
@@ -200,6 +212,13 @@ impl DatasetEntityTrait for DatasetEntities {
             let _ = cache.add_to_relation("catalogs", &catalog_id, &ds_urn, score).await;
         }
 
+        self.events.publish(
+            catalog_entity_event("Dataset", RainbowEventsNotificationMessageOperation::Creation)
+                .with_entity_id("datasetId", &dto.inner.id)
+                .with_entity_id("catalogId", &dto.inner.catalog_id)
+                .with_snapshot(&dto),
+        );
+
         Ok(dto)
     }
 
@@ -220,12 +239,18 @@ impl DatasetEntityTrait for DatasetEntities {
         let _ = cache.remove_from_collection(dataset_id).await;
 
         // Relation invalidation
-        if let Some(dto) = current {
+        if let Some(dto) = &current {
             if let Ok(catalog_id) = Urn::from_str(&*dto.inner.catalog_id) {
                 let _ = cache.remove_from_relation("catalogs", &catalog_id, dataset_id).await;
             }
         }
 
+        self.events.publish(
+            catalog_entity_event("Dataset", RainbowEventsNotificationMessageOperation::Deletion)
+                .with_entity_id("datasetId", dataset_id)
+                .with_snapshot(&current),
+        );
+
         Ok(())
     }
 }
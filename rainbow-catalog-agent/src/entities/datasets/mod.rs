@@ -2,6 +2,8 @@ pub(crate) mod datasets;
 
 use crate::data::entities::dataset;
 use crate::data::entities::dataset::{EditDatasetModel, Model, NewDatasetModel};
+use crate::entities::common::nullable;
+use sea_orm::prelude::DateTimeWithTimeZone;
 use serde::{Deserialize, Serialize};
 use urn::Urn;
 
@@ -22,8 +24,20 @@ pub struct NewDatasetDto {
     pub dct_title: Option<String>,
     pub dct_description: Option<String>,
     pub catalog_id: Urn,
+    pub dcat_keyword: Option<Vec<String>>,
+    pub dcat_theme: Option<Vec<String>>,
+    pub dct_language: Option<Vec<String>>,
+    pub dct_license: Option<String>,
+    pub dct_access_rights: Option<String>,
+    pub dct_spatial: Option<String>,
+    pub dct_temporal_start_date: Option<DateTimeWithTimeZone>,
+    pub dct_temporal_end_date: Option<DateTimeWithTimeZone>,
+    pub dcat_contact_point: Option<String>,
+    pub dcat_version: Option<String>,
+    pub dcat_previous_version: Option<String>,
 }
 
+/// Fields left out are kept, the optional DCAT 3 ones are cleared with an explicit null
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 #[serde(deny_unknown_fields)]
@@ -32,6 +46,28 @@ pub struct EditDatasetDto {
     pub dct_creator: Option<String>,
     pub dct_title: Option<String>,
     pub dct_description: Option<String>,
+    #[serde(default, deserialize_with = "nullable", skip_serializing_if = "Option::is_none")]
+    pub dcat_keyword: Option<Option<Vec<String>>>,
+    #[serde(default, deserialize_with = "nullable", skip_serializing_if = "Option::is_none")]
+    pub dcat_theme: Option<Option<Vec<String>>>,
+    #[serde(default, deserialize_with = "nullable", skip_serializing_if = "Option::is_none")]
+    pub dct_language: Option<Option<Vec<String>>>,
+    #[serde(default, deserialize_with = "nullable", skip_serializing_if = "Option::is_none")]
+    pub dct_license: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable", skip_serializing_if = "Option::is_none")]
+    pub dct_access_rights: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable", skip_serializing_if = "Option::is_none")]
+    pub dct_spatial: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable", skip_serializing_if = "Option::is_none")]
+    pub dct_temporal_start_date: Option<Option<DateTimeWithTimeZone>>,
+    #[serde(default, deserialize_with = "nullable", skip_serializing_if = "Option::is_none")]
+    pub dct_temporal_end_date: Option<Option<DateTimeWithTimeZone>>,
+    #[serde(default, deserialize_with = "nullable", skip_serializing_if = "Option::is_none")]
+    pub dcat_contact_point: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable", skip_serializing_if = "Option::is_none")]
+    pub dcat_version: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable", skip_serializing_if = "Option::is_none")]
+    pub dcat_previous_version: Option<Option<String>>,
 }
 
 impl From<NewDatasetDto> for NewDatasetModel {
@@ -43,6 +79,17 @@ impl From<NewDatasetDto> for NewDatasetModel {
             dct_title: dto.dct_title,
             dct_description: dto.dct_description,
             catalog_id: dto.catalog_id,
+            dcat_keyword: dto.dcat_keyword,
+            dcat_theme: dto.dcat_theme,
+            dct_language: dto.dct_language,
+            dct_license: dto.dct_license,
+            dct_access_rights: dto.dct_access_rights,
+            dct_spatial: dto.dct_spatial,
+            dct_temporal_start_date: dto.dct_temporal_start_date,
+            dct_temporal_end_date: dto.dct_temporal_end_date,
+            dcat_contact_point: dto.dcat_contact_point,
+            dcat_version: dto.dcat_version,
+            dcat_previous_version: dto.dcat_previous_version,
         }
     }
 }
@@ -54,6 +101,17 @@ impl From<EditDatasetDto> for EditDatasetModel {
             dct_creator: dto.dct_creator,
             dct_title: dto.dct_title,
             dct_description: dto.dct_description,
+            dcat_keyword: dto.dcat_keyword,
+            dcat_theme: dto.dcat_theme,
+            dct_language: dto.dct_language,
+            dct_license: dto.dct_license,
+            dct_access_rights: dto.dct_access_rights,
+            dct_spatial: dto.dct_spatial,
+            dct_temporal_start_date: dto.dct_temporal_start_date,
+            dct_temporal_end_date: dto.dct_temporal_end_date,
+            dcat_contact_point: dto.dcat_contact_point,
+            dcat_version: dto.dcat_version,
+            dcat_previous_version: dto.dcat_previous_version,
         }
     }
 }
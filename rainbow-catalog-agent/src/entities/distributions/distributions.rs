@@ -3,8 +3,11 @@ use crate::data::factory_trait::CatalogAgentRepoTrait;
 use crate::entities::distributions::{
     DistributionDto, DistributionEntityTrait, EditDistributionDto, NewDistributionDto,
 };
+use crate::entities::notifications::catalog_entity_event;
 use rainbow_common::dcat_formats::DctFormats;
 use rainbow_common::errors::{CommonErrors, ErrorLog};
+use rainbow_events::core::notification::notification_publisher::RainbowEventsPublisherTrait;
+use rainbow_events::core::notification::notification_types::RainbowEventsNotificationMessageOperation;
 use std::str::FromStr;
 use std::sync::Arc;
 use tracing::error;
@@ -13,14 +16,16 @@ use urn::Urn;
 pub struct DistributionEntities {
     repo: Arc<dyn CatalogAgentRepoTrait>,
     cache: Arc<dyn CatalogAgentCacheTrait>,
+    events: Arc<dyn RainbowEventsPublisherTrait>,
 }
 
 impl DistributionEntities {
     pub fn new(
         repo: Arc<dyn CatalogAgentRepoTrait>,
         cache: Arc<dyn CatalogAgentCacheTrait>,
+        events: Arc<dyn RainbowEventsPublisherTrait>,
     ) -> Self {
-        Self { repo, cache }
+        Self { repo, cache, events }
     }
 }
 
@@ -205,6 +210,13 @@ impl DistributionEntityTrait for DistributionEntities {
         let _ = cache.set_single(&dist_urn, &dto).await;
         let _ = cache.add_to_collection(&dist_urn, dto.inner.dct_issued.timestamp() as f64).await;
 
+        self.events.publish(
+            catalog_entity_event("Distribution", RainbowEventsNotificationMessageOperation::Update)
+                .with_entity_id("distributionId", &dto.inner.id)
+                .with_entity_id("datasetId", &dto.inner.dataset_id)
+                .with_snapshot(&dto),
+        );
+
         Ok(dto)
     }
 
@@ -234,6 +246,16 @@ impl DistributionEntityTrait for DistributionEntities {
             let _ = cache.add_to_relation("datasets", &dataset_id, &dist_urn, score).await;
         }
 
+        self.events.publish(
+            catalog_entity_event(
+                "Distribution",
+                RainbowEventsNotificationMessageOperation::Creation,
+            )
+            .with_entity_id("distributionId", &dto.inner.id)
+            .with_entity_id("datasetId", &dto.inner.dataset_id)
+            .with_snapshot(&dto),
+        );
+
         Ok(dto)
     }
 
@@ -253,12 +275,21 @@ impl DistributionEntityTrait for DistributionEntities {
         let _ = cache.remove_from_collection(distribution_id).await;
 
         // lookup invalidation
-        if let Some(dto) = current {
+        if let Some(dto) = &current {
             if let Ok(dataset_id) = Urn::from_str(&*dto.inner.dataset_id) {
                 let _ = cache.remove_from_relation("datasets", &dataset_id, distribution_id).await;
             }
         }
 
+        self.events.publish(
+            catalog_entity_event(
+                "Distribution",
+                RainbowEventsNotificationMessageOperation::Deletion,
+            )
+            .with_entity_id("distributionId", distribution_id)
+            .with_snapshot(&current),
+        );
+
         Ok(())
     }
 }
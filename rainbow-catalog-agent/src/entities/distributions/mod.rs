@@ -2,6 +2,7 @@ pub(crate) mod distributions;
 
 use crate::data::entities::distribution;
 use crate::data::entities::distribution::{EditDistributionModel, Model, NewDistributionModel};
+use crate::entities::common::nullable;
 use rainbow_common::dcat_formats::DctFormats;
 use serde::{Deserialize, Serialize};
 use urn::Urn;
@@ -23,8 +24,15 @@ pub struct NewDistributionDto {
     pub dct_formats: Option<DctFormats>,
     pub dcat_access_service: String,
     pub dataset_id: Urn,
+    pub dcat_byte_size: Option<i64>,
+    pub dcat_media_type: Option<String>,
+    pub spdx_checksum_algorithm: Option<String>,
+    pub spdx_checksum_value: Option<String>,
+    pub dct_license: Option<String>,
+    pub dct_access_rights: Option<String>,
 }
 
+/// Fields left out are kept, the optional DCAT 3 ones are cleared with an explicit null
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 #[serde(deny_unknown_fields)]
@@ -32,6 +40,18 @@ pub struct EditDistributionDto {
     pub dct_title: Option<String>,
     pub dct_description: Option<String>,
     pub dcat_access_service: Option<String>,
+    #[serde(default, deserialize_with = "nullable", skip_serializing_if = "Option::is_none")]
+    pub dcat_byte_size: Option<Option<i64>>,
+    #[serde(default, deserialize_with = "nullable", skip_serializing_if = "Option::is_none")]
+    pub dcat_media_type: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable", skip_serializing_if = "Option::is_none")]
+    pub spdx_checksum_algorithm: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable", skip_serializing_if = "Option::is_none")]
+    pub spdx_checksum_value: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable", skip_serializing_if = "Option::is_none")]
+    pub dct_license: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable", skip_serializing_if = "Option::is_none")]
+    pub dct_access_rights: Option<Option<String>>,
 }
 
 impl From<NewDistributionDto> for NewDistributionModel {
@@ -43,6 +63,12 @@ impl From<NewDistributionDto> for NewDistributionModel {
             dct_formats: dto.dct_formats,
             dcat_access_service: dto.dcat_access_service,
             dataset_id: dto.dataset_id,
+            dcat_byte_size: dto.dcat_byte_size,
+            dcat_media_type: dto.dcat_media_type,
+            spdx_checksum_algorithm: dto.spdx_checksum_algorithm,
+            spdx_checksum_value: dto.spdx_checksum_value,
+            dct_license: dto.dct_license,
+            dct_access_rights: dto.dct_access_rights,
         }
     }
 }
@@ -53,6 +79,12 @@ impl From<EditDistributionDto> for EditDistributionModel {
             dct_title: dto.dct_title,
             dct_description: dto.dct_description,
             dcat_access_service: dto.dcat_access_service,
+            dcat_byte_size: dto.dcat_byte_size,
+            dcat_media_type: dto.dcat_media_type,
+            spdx_checksum_algorithm: dto.spdx_checksum_algorithm,
+            spdx_checksum_value: dto.spdx_checksum_value,
+            dct_license: dto.dct_license,
+            dct_access_rights: dto.dct_access_rights,
         }
     }
 }
@@ -1,9 +1,13 @@
+pub(crate) mod catalog_tree;
 pub(crate) mod catalogs;
 pub(crate) mod common;
 pub(crate) mod data_services;
 pub(crate) mod datasets;
 pub(crate) mod distributions;
 pub(crate) mod instantiation_engine;
+pub(crate) mod notifications;
 pub(crate) mod odrl_policies;
 pub(crate) mod peer_catalogs;
 pub(crate) mod policy_templates;
+#[cfg(test)]
+mod test_notifications;
@@ -0,0 +1,17 @@
+use rainbow_events::core::notification::notification_types::{
+    RainbowEventsNotificationEvent, RainbowEventsNotificationMessageCategory,
+    RainbowEventsNotificationMessageOperation, RainbowEventsNotificationMessageTypes,
+};
+
+/// Event for a change in one of the catalog entities, published once it is persisted
+pub(crate) fn catalog_entity_event(
+    subcategory: &str,
+    operation: RainbowEventsNotificationMessageOperation,
+) -> RainbowEventsNotificationEvent {
+    RainbowEventsNotificationEvent::new(
+        RainbowEventsNotificationMessageCategory::Catalog,
+        subcategory,
+        RainbowEventsNotificationMessageTypes::RainbowEntitiesMessage,
+        operation,
+    )
+}
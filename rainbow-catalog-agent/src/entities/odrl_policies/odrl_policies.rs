@@ -1,7 +1,10 @@
 use crate::cache::factory_trait::CatalogAgentCacheTrait;
 use crate::data::factory_trait::CatalogAgentRepoTrait;
+use crate::entities::notifications::catalog_entity_event;
 use crate::entities::odrl_policies::{NewOdrlPolicyDto, OdrlPolicyDto, OdrlPolicyEntityTrait};
 use rainbow_common::errors::{CommonErrors, ErrorLog};
+use rainbow_events::core::notification::notification_publisher::RainbowEventsPublisherTrait;
+use rainbow_events::core::notification::notification_types::RainbowEventsNotificationMessageOperation;
 use std::str::FromStr;
 use std::sync::Arc;
 use tracing::error;
@@ -10,14 +13,16 @@ use urn::Urn;
 pub struct OdrlPolicyEntities {
     repo: Arc<dyn CatalogAgentRepoTrait>,
     cache: Arc<dyn CatalogAgentCacheTrait>,
+    events: Arc<dyn RainbowEventsPublisherTrait>,
 }
 
 impl OdrlPolicyEntities {
     pub fn new(
         repo: Arc<dyn CatalogAgentRepoTrait>,
         cache: Arc<dyn CatalogAgentCacheTrait>,
+        events: Arc<dyn RainbowEventsPublisherTrait>,
     ) -> Self {
-        Self { repo, cache }
+        Self { repo, cache, events }
     }
 }
 
@@ -173,6 +178,13 @@ impl OdrlPolicyEntityTrait for OdrlPolicyEntities {
             let _ = cache.add_to_relation("target", &target_urn, &policy_id, 0.0).await;
         }
 
+        self.events.publish(
+            catalog_entity_event("OdrlOffer", RainbowEventsNotificationMessageOperation::Creation)
+                .with_entity_id("odrlOfferId", &dto.inner.id)
+                .with_entity_id("entityId", &dto.inner.entity)
+                .with_snapshot(&dto),
+        );
+
         Ok(dto)
     }
 
@@ -192,12 +204,18 @@ impl OdrlPolicyEntityTrait for OdrlPolicyEntities {
         let _ = cache.remove_from_collection(odrl_offer_id).await;
 
         // lookup invalidation
-        if let Some(dto) = current {
+        if let Some(dto) = &current {
             if let Ok(target_urn) = Urn::from_str(&dto.inner.entity) {
                 let _ = cache.remove_from_relation("target", &target_urn, odrl_offer_id).await;
             }
         }
 
+        self.events.publish(
+            catalog_entity_event("OdrlOffer", RainbowEventsNotificationMessageOperation::Deletion)
+                .with_entity_id("odrlOfferId", odrl_offer_id)
+                .with_snapshot(&current),
+        );
+
         Ok(())
     }
 
@@ -224,6 +242,11 @@ impl OdrlPolicyEntityTrait for OdrlPolicyEntities {
         // lookup invalidation
         let _ = cache.remove_from_relation("target", entity_id, &Urn::from_str("nil:nil")?).await; // dummy trigger
 
+        self.events.publish(
+            catalog_entity_event("OdrlOffer", RainbowEventsNotificationMessageOperation::Deletion)
+                .with_entity_id("entityId", entity_id),
+        );
+
         Ok(())
     }
 }
@@ -1,9 +1,83 @@
 pub(crate) mod peer_catalogs;
 
+use crate::data::entities::{federated_crawl_state, federated_dataset};
 use crate::protocols::dsp::types::catalog_definition::Catalog;
+use serde::{Deserialize, Serialize};
 
+/// Crawl interval given to peers discovered without an explicit schedule
+pub const DEFAULT_CRAWL_INTERVAL_SECS: i64 = 300;
+
+/// Dataset of a peer, `content` is kept exactly as the peer served it
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FederatedDatasetDto {
+    pub participant_id: String,
+    pub id: String,
+    pub catalog_id: String,
+    pub dct_title: Option<String>,
+    pub dct_description: Option<String>,
+    pub dct_conforms_to: Option<String>,
+    pub content: serde_json::Value,
+    pub fetched_at: chrono::DateTime<chrono::FixedOffset>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CrawlStateDto {
+    #[serde(flatten)]
+    pub inner: federated_crawl_state::Model,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
+pub struct EditCrawlScheduleDto {
+    pub crawl_interval: Option<i64>,
+    pub enabled: Option<bool>,
+}
+
+impl From<federated_dataset::Model> for FederatedDatasetDto {
+    fn from(value: federated_dataset::Model) -> Self {
+        Self {
+            participant_id: value.participant_id,
+            id: value.id,
+            catalog_id: value.catalog_id,
+            dct_title: value.dct_title,
+            dct_description: value.dct_description,
+            dct_conforms_to: value.dct_conforms_to,
+            content: value.content,
+            fetched_at: value.fetched_at,
+        }
+    }
+}
+
+impl From<federated_crawl_state::Model> for CrawlStateDto {
+    fn from(value: federated_crawl_state::Model) -> Self {
+        Self { inner: value }
+    }
+}
+
+#[mockall::automock]
 #[async_trait::async_trait]
 pub trait PeerCatalogTrait: Send + Sync {
     async fn get_peer_catalog(&self, peer_id: &String) -> anyhow::Result<Option<Catalog>>;
     async fn set_peer_catalog(&self, peer_id: &String, catalog: &Catalog) -> anyhow::Result<()>;
+    async fn get_federated_datasets(
+        &self,
+        limit: Option<u64>,
+        page: Option<u64>,
+        peer_id: Option<String>,
+        keyword: Option<String>,
+    ) -> anyhow::Result<Vec<FederatedDatasetDto>>;
+    async fn get_crawl_states(&self) -> anyhow::Result<Vec<CrawlStateDto>>;
+    async fn get_crawl_state(&self, peer_id: &String) -> anyhow::Result<Option<CrawlStateDto>>;
+    async fn put_crawl_state(
+        &self,
+        crawl_state: &federated_crawl_state::Model,
+    ) -> anyhow::Result<CrawlStateDto>;
+    async fn put_crawl_schedule(
+        &self,
+        peer_id: &String,
+        edit_schedule: &EditCrawlScheduleDto,
+    ) -> anyhow::Result<CrawlStateDto>;
 }
@@ -1,27 +1,257 @@
 use crate::cache::factory_trait::CatalogAgentCacheTrait;
-use crate::entities::peer_catalogs::PeerCatalogTrait;
-use crate::protocols::dsp::types::catalog_definition::Catalog;
+use crate::data::entities::federated_catalog::NewFederatedCatalogModel;
+use crate::data::entities::federated_crawl_state;
+use crate::data::entities::federated_dataset::NewFederatedDatasetModel;
+use crate::data::factory_trait::CatalogAgentRepoTrait;
+use crate::entities::peer_catalogs::{
+    CrawlStateDto, EditCrawlScheduleDto, FederatedDatasetDto, PeerCatalogTrait,
+    DEFAULT_CRAWL_INTERVAL_SECS,
+};
+use crate::protocols::dsp::types::catalog_definition::{
+    Catalog, CatalogCatalogTypes, CatalogDatasetTypes,
+};
+use crate::protocols::dsp::types::dataset_definition::DatasetDctDeclaration;
+use log::error;
+use rainbow_common::errors::helpers::BadFormat;
+use rainbow_common::errors::{CommonErrors, ErrorLog};
+use serde::Serialize;
 use std::sync::Arc;
 
 pub struct PeerCatalogEntities {
+    repo: Arc<dyn CatalogAgentRepoTrait>,
     cache: Arc<dyn CatalogAgentCacheTrait>,
 }
 
 impl PeerCatalogEntities {
-    pub(crate) fn new(cache: Arc<dyn CatalogAgentCacheTrait>) -> Self {
-        PeerCatalogEntities { cache }
+    pub(crate) fn new(
+        repo: Arc<dyn CatalogAgentRepoTrait>,
+        cache: Arc<dyn CatalogAgentCacheTrait>,
+    ) -> Self {
+        PeerCatalogEntities { repo, cache }
+    }
+
+    fn flatten_datasets(
+        catalog_id: &str,
+        datasets: &CatalogDatasetTypes,
+        out: &mut Vec<NewFederatedDatasetModel>,
+    ) -> anyhow::Result<()> {
+        match datasets {
+            CatalogDatasetTypes::DatasetMultipleMinimized(datasets) => {
+                for dataset in datasets {
+                    out.push(Self::federated_dataset(
+                        catalog_id,
+                        &dataset.id,
+                        &dataset.dct,
+                        dataset,
+                    )?);
+                }
+            }
+            CatalogDatasetTypes::DatasetMultipleOriginal(datasets) => {
+                for dataset in datasets {
+                    out.push(Self::federated_dataset(
+                        catalog_id,
+                        &dataset.id,
+                        &dataset.dct,
+                        dataset,
+                    )?);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn federated_dataset<T: Serialize>(
+        catalog_id: &str,
+        dataset_id: &str,
+        dct: &DatasetDctDeclaration,
+        dataset: &T,
+    ) -> anyhow::Result<NewFederatedDatasetModel> {
+        let description = dct.description.join("\n");
+        Ok(NewFederatedDatasetModel {
+            id: dataset_id.to_string(),
+            catalog_id: catalog_id.to_string(),
+            dct_title: dct.title.clone(),
+            dct_description: (!description.is_empty()).then_some(description),
+            dct_conforms_to: dct.conforms_to.clone(),
+            content: serde_json::to_value(dataset)?,
+        })
+    }
+
+    /// Flattens the datasets of the catalog and of its sub catalogs into queryable rows
+    fn federated_models(
+        peer_id: &String,
+        catalog: &Catalog,
+    ) -> anyhow::Result<(NewFederatedCatalogModel, Vec<NewFederatedDatasetModel>)> {
+        let mut datasets = Vec::new();
+        Self::flatten_datasets(catalog.id.as_str(), &catalog.datasets, &mut datasets)?;
+        match &catalog.catalogs {
+            CatalogCatalogTypes::CatalogMultipleMinimized(sub_catalogs) => {
+                for sub_catalog in sub_catalogs {
+                    Self::flatten_datasets(
+                        sub_catalog.id.as_str(),
+                        &sub_catalog.datasets,
+                        &mut datasets,
+                    )?;
+                }
+            }
+            CatalogCatalogTypes::CatalogMultipleOriginal(sub_catalogs) => {
+                for sub_catalog in sub_catalogs {
+                    Self::flatten_datasets(
+                        sub_catalog.id.as_str(),
+                        &sub_catalog.datasets,
+                        &mut datasets,
+                    )?;
+                }
+            }
+        }
+        let new_catalog = NewFederatedCatalogModel {
+            participant_id: peer_id.clone(),
+            catalog_id: catalog.id.to_string(),
+            dct_title: catalog.dct.title.clone(),
+            content: serde_json::to_value(catalog)?,
+        };
+        Ok((new_catalog, datasets))
     }
 }
 
 #[async_trait::async_trait]
 impl PeerCatalogTrait for PeerCatalogEntities {
     async fn get_peer_catalog(&self, peer_id: &String) -> anyhow::Result<Option<Catalog>> {
-        let peer_catalog = self.cache.get_peer_catalog_cache().get_catalog(peer_id).await;
-        peer_catalog
+        // cache
+        if let Ok(Some(catalog)) = self.cache.get_peer_catalog_cache().get_catalog(peer_id).await {
+            return Ok(Some(catalog));
+        }
+
+        // db
+        let federated_catalog = self
+            .repo
+            .get_federated_catalog_repo()
+            .get_federated_catalog_by_participant(peer_id)
+            .await
+            .map_err(|e| CommonErrors::database_new(&e.to_string()))?;
+        let federated_catalog = match federated_catalog {
+            Some(federated_catalog) => federated_catalog,
+            None => return Ok(None),
+        };
+        let catalog =
+            serde_json::from_value::<Catalog>(federated_catalog.content).map_err(|e| {
+                let err = CommonErrors::format_new(
+                    BadFormat::Unknown,
+                    format!("Stored catalog of peer {} is not a DSP catalog: {}", peer_id, e)
+                        .as_str(),
+                );
+                error!("{}", err.log());
+                err
+            })?;
+
+        // hydration
+        let _ = self.cache.get_peer_catalog_cache().set_catalog(peer_id, &catalog).await;
+        Ok(Some(catalog))
     }
 
     async fn set_peer_catalog(&self, peer_id: &String, catalog: &Catalog) -> anyhow::Result<()> {
-        let peer_catalog = self.cache.get_peer_catalog_cache().set_catalog(peer_id, catalog).await;
-        peer_catalog
+        let (new_catalog, new_datasets) = Self::federated_models(peer_id, catalog)?;
+        self.repo
+            .get_federated_catalog_repo()
+            .replace_federated_catalog(&new_catalog, &new_datasets)
+            .await
+            .map_err(|e| CommonErrors::database_new(&e.to_string()))?;
+        self.cache.get_peer_catalog_cache().set_catalog(peer_id, catalog).await
+    }
+
+    async fn get_federated_datasets(
+        &self,
+        limit: Option<u64>,
+        page: Option<u64>,
+        peer_id: Option<String>,
+        keyword: Option<String>,
+    ) -> anyhow::Result<Vec<FederatedDatasetDto>> {
+        let datasets = self
+            .repo
+            .get_federated_catalog_repo()
+            .get_federated_datasets(limit, page, peer_id, keyword)
+            .await
+            .map_err(|e| CommonErrors::database_new(&e.to_string()))?;
+        Ok(datasets.into_iter().map(Into::into).collect())
+    }
+
+    async fn get_crawl_states(&self) -> anyhow::Result<Vec<CrawlStateDto>> {
+        let states = self
+            .repo
+            .get_federated_catalog_repo()
+            .get_all_crawl_states()
+            .await
+            .map_err(|e| CommonErrors::database_new(&e.to_string()))?;
+        Ok(states.into_iter().map(Into::into).collect())
+    }
+
+    async fn get_crawl_state(&self, peer_id: &String) -> anyhow::Result<Option<CrawlStateDto>> {
+        let state = self
+            .repo
+            .get_federated_catalog_repo()
+            .get_crawl_state_by_participant(peer_id)
+            .await
+            .map_err(|e| CommonErrors::database_new(&e.to_string()))?;
+        Ok(state.map(Into::into))
+    }
+
+    async fn put_crawl_state(
+        &self,
+        crawl_state: &federated_crawl_state::Model,
+    ) -> anyhow::Result<CrawlStateDto> {
+        let state = self
+            .repo
+            .get_federated_catalog_repo()
+            .put_crawl_state(crawl_state)
+            .await
+            .map_err(|e| CommonErrors::database_new(&e.to_string()))?;
+        Ok(state.into())
+    }
+
+    async fn put_crawl_schedule(
+        &self,
+        peer_id: &String,
+        edit_schedule: &EditCrawlScheduleDto,
+    ) -> anyhow::Result<CrawlStateDto> {
+        if let Some(crawl_interval) = edit_schedule.crawl_interval {
+            if crawl_interval <= 0 {
+                let err = CommonErrors::format_new(
+                    BadFormat::Received,
+                    "Crawl interval must be a positive number of seconds",
+                );
+                error!("{}", err.log());
+                return Err(err.into());
+            }
+        }
+
+        let now = chrono::Utc::now();
+        let mut state = match self.get_crawl_state(peer_id).await? {
+            Some(state) => state.inner,
+            None => federated_crawl_state::Model {
+                participant_id: peer_id.clone(),
+                enabled: true,
+                crawl_interval: DEFAULT_CRAWL_INTERVAL_SECS,
+                etag: None,
+                last_modified: None,
+                content_hash: None,
+                last_crawled_at: None,
+                next_crawl_at: now.into(),
+                consecutive_failures: 0,
+                last_error: None,
+            },
+        };
+        if let Some(enabled) = edit_schedule.enabled {
+            state.enabled = enabled;
+        }
+        if let Some(crawl_interval) = edit_schedule.crawl_interval {
+            state.crawl_interval = crawl_interval;
+            // a shorter interval should take effect now rather than after the old one
+            let next_crawl_at = state
+                .last_crawled_at
+                .map(|last| last + chrono::Duration::seconds(crawl_interval))
+                .unwrap_or(now.into());
+            state.next_crawl_at = next_crawl_at.min(state.next_crawl_at);
+        }
+        self.put_crawl_state(&state).await
     }
 }
@@ -0,0 +1,159 @@
+//! Events published by the catalog entities once a change is persisted
+//! Every change names its entity and parents in the entity ids and carries the entity snapshot
+
+use crate::cache::factory_noop::CatalogAgentCacheForNoop;
+use crate::data::factory_sql::CatalogAgentRepoForSql;
+use crate::data::migrations::Migrator;
+use crate::entities::catalogs::catalogs::CatalogEntities;
+use crate::entities::catalogs::{CatalogEntityTrait, EditCatalogDto, NewCatalogDto};
+use crate::entities::datasets::datasets::DatasetEntities;
+use crate::entities::datasets::{DatasetEntityTrait, NewDatasetDto};
+use rainbow_events::core::notification::notification_publisher::MockRainbowEventsPublisherTrait;
+use rainbow_events::core::notification::notification_types::{
+    RainbowEventsNotificationEvent, RainbowEventsNotificationMessageCategory,
+    RainbowEventsNotificationMessageOperation, RainbowEventsNotificationMessageTypes,
+};
+use sea_orm::{ConnectOptions, Database};
+use sea_orm_migration::MigratorTrait;
+use serde_json::json;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+use urn::Urn;
+
+type Published = Arc<Mutex<Vec<RainbowEventsNotificationEvent>>>;
+
+async fn repo() -> Arc<CatalogAgentRepoForSql> {
+    // a single connection, every new in-memory connection is a new empty database
+    let mut options = ConnectOptions::new("sqlite::memory:");
+    options.max_connections(1).sqlx_logging(false);
+    let db = Database::connect(options).await.unwrap();
+    Migrator::up(&db, None).await.unwrap();
+    Arc::new(CatalogAgentRepoForSql::create_repo(db))
+}
+
+/// Publisher keeping every event it is handed
+fn publisher() -> (Arc<MockRainbowEventsPublisherTrait>, Published) {
+    let published: Published = Arc::new(Mutex::new(vec![]));
+    let recorded = published.clone();
+    let mut events = MockRainbowEventsPublisherTrait::new();
+    events.expect_publish().returning(move |event| recorded.lock().unwrap().push(event));
+    (Arc::new(events), published)
+}
+
+fn take(published: &Published) -> Vec<RainbowEventsNotificationEvent> {
+    std::mem::take(&mut *published.lock().unwrap())
+}
+
+fn assert_catalog_event(
+    event: &RainbowEventsNotificationEvent,
+    subcategory: &str,
+    operation: RainbowEventsNotificationMessageOperation,
+    entity_ids: &[(&str, &str)],
+) {
+    assert_eq!(event.category, RainbowEventsNotificationMessageCategory::Catalog);
+    assert_eq!(event.subcategory, subcategory);
+    assert_eq!(
+        event.message_type,
+        RainbowEventsNotificationMessageTypes::RainbowEntitiesMessage
+    );
+    assert_eq!(event.message_operation, operation);
+    let expected =
+        entity_ids.iter().map(|(name, id)| (name.to_string(), id.to_string())).collect::<Vec<_>>();
+    assert_eq!(
+        event.content.entity_ids.clone().into_iter().collect::<Vec<_>>(),
+        expected
+    );
+}
+
+#[tokio::test]
+async fn catalog_changes_are_published() {
+    let (events, published) = publisher();
+    let catalogs = CatalogEntities::new(
+        repo().await,
+        Arc::new(CatalogAgentCacheForNoop::create_repo()),
+        events,
+    );
+
+    let created = catalogs
+        .create_catalog(&NewCatalogDto {
+            dct_title: Some("Air quality".to_string()),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    let catalog_id = created.inner.id.clone();
+    let catalog_urn = Urn::from_str(&catalog_id).unwrap();
+    catalogs
+        .put_catalog_by_id(
+            &catalog_urn,
+            &EditCatalogDto {
+                foaf_home_page: None,
+                dct_conforms_to: None,
+                dct_creator: None,
+                dct_title: Some("Air and water quality".to_string()),
+            },
+        )
+        .await
+        .unwrap();
+    catalogs.delete_catalog_by_id(&catalog_urn).await.unwrap();
+
+    let events = take(&published);
+    assert_eq!(events.len(), 3);
+    let ids = [("catalogId", catalog_id.as_str())];
+    assert_catalog_event(
+        &events[0],
+        "Catalog",
+        RainbowEventsNotificationMessageOperation::Creation,
+        &ids,
+    );
+    assert_eq!(events[0].content.snapshot["dctTitle"], json!("Air quality"));
+    assert_catalog_event(
+        &events[1],
+        "Catalog",
+        RainbowEventsNotificationMessageOperation::Update,
+        &ids,
+    );
+    assert_eq!(events[1].content.snapshot["dctTitle"], json!("Air and water quality"));
+    assert_catalog_event(
+        &events[2],
+        "Catalog",
+        RainbowEventsNotificationMessageOperation::Deletion,
+        &ids,
+    );
+}
+
+#[tokio::test]
+async fn dataset_changes_name_their_catalog() {
+    let repo = repo().await;
+    let cache = Arc::new(CatalogAgentCacheForNoop::create_repo());
+    let (events, published) = publisher();
+    let catalogs = CatalogEntities::new(repo.clone(), cache.clone(), events.clone());
+    let datasets = DatasetEntities::new(repo, cache, events);
+    let catalog = catalogs.create_main_catalog(&NewCatalogDto::default()).await.unwrap();
+    let catalog_id = catalog.inner.id.clone();
+    take(&published);
+
+    let new_dataset: NewDatasetDto =
+        serde_json::from_value(json!({ "catalogId": catalog_id, "dctTitle": "Sensors" })).unwrap();
+    let dataset = datasets.create_dataset(&new_dataset).await.unwrap();
+    let dataset_id = dataset.inner.id.clone();
+    datasets.delete_dataset_by_id(&Urn::from_str(&dataset_id).unwrap()).await.unwrap();
+
+    let events = take(&published);
+    assert_eq!(events.len(), 2);
+    assert_catalog_event(
+        &events[0],
+        "Dataset",
+        RainbowEventsNotificationMessageOperation::Creation,
+        &[("catalogId", catalog_id.as_str()), ("datasetId", dataset_id.as_str())],
+    );
+    assert_eq!(events[0].content.snapshot["dctTitle"], json!("Sensors"));
+    // the deleted dataset is still described by its last snapshot
+    assert_catalog_event(
+        &events[1],
+        "Dataset",
+        RainbowEventsNotificationMessageOperation::Deletion,
+        &[("datasetId", dataset_id.as_str())],
+    );
+    assert_eq!(events[1].content.snapshot["dctTitle"], json!("Sensors"));
+}
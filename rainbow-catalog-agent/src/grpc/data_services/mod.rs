@@ -168,7 +168,7 @@ impl DataServiceEntityService for DataServiceEntityGrpc {
     ) -> Result<Response<DataServiceResponse>, Status> {
         let req = request.into_inner();
         let urn = Urn::from_str(&req.id).map_err(|_| Status::invalid_argument("Invalid URN"))?;
-        let edit_dto: EditDataServiceDto = req.into();
+        let edit_dto: EditDataServiceDto = req.try_into()?;
 
         let updated_dto = self
             .service
@@ -121,7 +121,7 @@ impl DatasetEntityService for DatasetEntityGrpc {
     ) -> Result<Response<DatasetResponse>, Status> {
         let req = request.into_inner();
         let urn = Urn::from_str(&req.id).map_err(|_| Status::invalid_argument("Invalid URN"))?;
-        let edit_dto: EditDatasetDto = req.into();
+        let edit_dto: EditDatasetDto = req.try_into()?;
 
         let updated_dto = self
             .service
@@ -162,7 +162,7 @@ impl DistributionEntityService for DistributionEntityGrpc {
     ) -> Result<Response<DistributionResponse>, Status> {
         let req = request.into_inner();
         let urn = Urn::from_str(&req.id).map_err(|_| Status::invalid_argument("Invalid URN"))?;
-        let edit_dto: EditDistributionDto = req.into();
+        let edit_dto: EditDistributionDto = req.try_into()?;
 
         let updated_dto = self
             .service
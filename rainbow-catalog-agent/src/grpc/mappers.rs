@@ -14,10 +14,39 @@ use crate::grpc::api::catalog_agent::{
 use prost_types::Struct;
 use rainbow_common::dcat_formats::DctFormats;
 use rainbow_common::dsp_common::odrl::OdrlPolicyInfo;
+use sea_orm::prelude::DateTimeWithTimeZone;
 use std::str::FromStr;
 use tonic::Status;
 use urn::Urn;
 
+fn non_empty(values: Vec<String>) -> Option<Vec<String>> {
+    (!values.is_empty()).then_some(values)
+}
+
+/// A value replaces the stored one, a field named in `clear_fields` is cleared, anything else
+/// is kept
+fn edited<T>(value: Option<T>, field: &str, clear_fields: &[String]) -> Option<Option<T>> {
+    match clear_fields.iter().any(|cleared| cleared == field) {
+        true => Some(None),
+        false => value.map(Some),
+    }
+}
+
+fn check_clear_fields(clear_fields: &[String], clearable: &[&str]) -> Result<(), Status> {
+    match clear_fields.iter().find(|cleared| !clearable.contains(&cleared.as_str())) {
+        Some(field) => Err(Status::invalid_argument(format!("Field {} cannot be cleared", field))),
+        None => Ok(()),
+    }
+}
+
+fn parse_date(date: Option<String>) -> Result<Option<DateTimeWithTimeZone>, Status> {
+    date.map(|d| {
+        DateTimeWithTimeZone::parse_from_rfc3339(&d)
+            .map_err(|_| Status::invalid_argument("Invalid RFC 3339 date"))
+    })
+    .transpose()
+}
+
 fn proto_value_to_json(v: prost_types::Value) -> serde_json::Value {
     match v.kind {
         Some(prost_types::value::Kind::NullValue(_)) => serde_json::Value::Null,
@@ -151,6 +180,11 @@ impl From<DataServiceDto> for DataService {
             dct_title: model.dct_title,
             dct_description: model.dct_description,
             catalog_id: model.catalog_id,
+            dcat_keyword: model.keywords(),
+            dcat_theme: model.themes(),
+            dct_license: model.dct_license,
+            dct_access_rights: model.dct_access_rights,
+            dcat_contact_point: model.dcat_contact_point,
         }
     }
 }
@@ -179,20 +213,43 @@ impl TryFrom<CreateDataServiceRequest> for NewDataServiceDto {
             dct_title: req.dct_title,
             dct_description: req.dct_description,
             catalog_id,
+            dcat_keyword: non_empty(req.dcat_keyword),
+            dcat_theme: non_empty(req.dcat_theme),
+            dct_license: req.dct_license,
+            dct_access_rights: req.dct_access_rights,
+            dcat_contact_point: req.dcat_contact_point,
         })
     }
 }
 
-impl From<PutDataServiceRequest> for EditDataServiceDto {
-    fn from(req: PutDataServiceRequest) -> Self {
-        Self {
+impl TryFrom<PutDataServiceRequest> for EditDataServiceDto {
+    type Error = Status;
+
+    fn try_from(req: PutDataServiceRequest) -> Result<Self, Self::Error> {
+        let cleared = req.clear_fields.as_slice();
+        check_clear_fields(
+            cleared,
+            &[
+                "dcat_keyword",
+                "dcat_theme",
+                "dct_license",
+                "dct_access_rights",
+                "dcat_contact_point",
+            ],
+        )?;
+        Ok(Self {
             dcat_endpoint_description: req.dcat_endpoint_description,
             dcat_endpoint_url: req.dcat_endpoint_url,
             dct_conforms_to: req.dct_conforms_to,
             dct_creator: req.dct_creator,
             dct_title: req.dct_title,
             dct_description: req.dct_description,
-        }
+            dcat_keyword: edited(non_empty(req.dcat_keyword), "dcat_keyword", cleared),
+            dcat_theme: edited(non_empty(req.dcat_theme), "dcat_theme", cleared),
+            dct_license: edited(req.dct_license, "dct_license", cleared),
+            dct_access_rights: edited(req.dct_access_rights, "dct_access_rights", cleared),
+            dcat_contact_point: edited(req.dcat_contact_point, "dcat_contact_point", cleared),
+        })
     }
 }
 
@@ -212,6 +269,17 @@ impl From<DatasetDto> for Dataset {
             dct_title: model.dct_title,
             dct_description: model.dct_description,
             catalog_id: model.catalog_id,
+            dcat_keyword: model.keywords(),
+            dcat_theme: model.themes(),
+            dct_language: model.languages(),
+            dct_license: model.dct_license,
+            dct_access_rights: model.dct_access_rights,
+            dct_spatial: model.dct_spatial,
+            dct_temporal_start_date: model.dct_temporal_start_date.map(|d| d.to_rfc3339()),
+            dct_temporal_end_date: model.dct_temporal_end_date.map(|d| d.to_rfc3339()),
+            dcat_contact_point: model.dcat_contact_point,
+            dcat_version: model.dcat_version,
+            dcat_previous_version: model.dcat_previous_version,
         }
     }
 }
@@ -238,18 +306,71 @@ impl TryFrom<CreateDatasetRequest> for NewDatasetDto {
             dct_title: req.dct_title,
             dct_description: req.dct_description,
             catalog_id,
+            dcat_keyword: non_empty(req.dcat_keyword),
+            dcat_theme: non_empty(req.dcat_theme),
+            dct_language: non_empty(req.dct_language),
+            dct_license: req.dct_license,
+            dct_access_rights: req.dct_access_rights,
+            dct_spatial: req.dct_spatial,
+            dct_temporal_start_date: parse_date(req.dct_temporal_start_date)?,
+            dct_temporal_end_date: parse_date(req.dct_temporal_end_date)?,
+            dcat_contact_point: req.dcat_contact_point,
+            dcat_version: req.dcat_version,
+            dcat_previous_version: req.dcat_previous_version,
         })
     }
 }
 
-impl From<PutDatasetRequest> for EditDatasetDto {
-    fn from(req: PutDatasetRequest) -> Self {
-        Self {
+impl TryFrom<PutDatasetRequest> for EditDatasetDto {
+    type Error = Status;
+
+    fn try_from(req: PutDatasetRequest) -> Result<Self, Self::Error> {
+        let cleared = req.clear_fields.as_slice();
+        check_clear_fields(
+            cleared,
+            &[
+                "dcat_keyword",
+                "dcat_theme",
+                "dct_language",
+                "dct_license",
+                "dct_access_rights",
+                "dct_spatial",
+                "dct_temporal_start_date",
+                "dct_temporal_end_date",
+                "dcat_contact_point",
+                "dcat_version",
+                "dcat_previous_version",
+            ],
+        )?;
+        Ok(Self {
             dct_conforms_to: req.dct_conforms_to,
             dct_creator: req.dct_creator,
             dct_title: req.dct_title,
             dct_description: req.dct_description,
-        }
+            dcat_keyword: edited(non_empty(req.dcat_keyword), "dcat_keyword", cleared),
+            dcat_theme: edited(non_empty(req.dcat_theme), "dcat_theme", cleared),
+            dct_language: edited(non_empty(req.dct_language), "dct_language", cleared),
+            dct_license: edited(req.dct_license, "dct_license", cleared),
+            dct_access_rights: edited(req.dct_access_rights, "dct_access_rights", cleared),
+            dct_spatial: edited(req.dct_spatial, "dct_spatial", cleared),
+            dct_temporal_start_date: edited(
+                parse_date(req.dct_temporal_start_date)?,
+                "dct_temporal_start_date",
+                cleared,
+            ),
+            dct_temporal_end_date: edited(
+                parse_date(req.dct_temporal_end_date)?,
+                "dct_temporal_end_date",
+                cleared,
+            ),
+            dcat_contact_point: edited(req.dcat_contact_point, "dcat_contact_point", cleared),
+            dcat_version: edited(req.dcat_version, "dcat_version", cleared),
+            dcat_previous_version: edited(
+                req.dcat_previous_version,
+                "dcat_previous_version",
+                cleared,
+            ),
+        })
     }
 }
 
@@ -271,6 +392,12 @@ impl From<DistributionDto> for Distribution {
             dcat_access_service: model.dcat_access_service,
             dataset_id: model.dataset_id,
             dct_format,
+            dcat_byte_size: model.dcat_byte_size,
+            dcat_media_type: model.dcat_media_type,
+            spdx_checksum_algorithm: model.spdx_checksum_algorithm,
+            spdx_checksum_value: model.spdx_checksum_value,
+            dct_license: model.dct_license,
+            dct_access_rights: model.dct_access_rights,
         }
     }
 }
@@ -302,17 +429,47 @@ impl TryFrom<CreateDistributionRequest> for NewDistributionDto {
             dct_formats: Some(dct_formats),
             dcat_access_service: req.dcat_access_service,
             dataset_id,
+            dcat_byte_size: req.dcat_byte_size,
+            dcat_media_type: req.dcat_media_type,
+            spdx_checksum_algorithm: req.spdx_checksum_algorithm,
+            spdx_checksum_value: req.spdx_checksum_value,
+            dct_license: req.dct_license,
+            dct_access_rights: req.dct_access_rights,
         })
     }
 }
 
-impl From<PutDistributionRequest> for EditDistributionDto {
-    fn from(req: PutDistributionRequest) -> Self {
-        Self {
+impl TryFrom<PutDistributionRequest> for EditDistributionDto {
+    type Error = Status;
+
+    fn try_from(req: PutDistributionRequest) -> Result<Self, Self::Error> {
+        let cleared = req.clear_fields.as_slice();
+        check_clear_fields(
+            cleared,
+            &[
+                "dcat_byte_size",
+                "dcat_media_type",
+                "spdx_checksum_algorithm",
+                "spdx_checksum_value",
+                "dct_license",
+                "dct_access_rights",
+            ],
+        )?;
+        Ok(Self {
             dct_title: req.dct_title,
             dct_description: req.dct_description,
             dcat_access_service: req.dcat_access_service,
-        }
+            dcat_byte_size: edited(req.dcat_byte_size, "dcat_byte_size", cleared),
+            dcat_media_type: edited(req.dcat_media_type, "dcat_media_type", cleared),
+            spdx_checksum_algorithm: edited(
+                req.spdx_checksum_algorithm,
+                "spdx_checksum_algorithm",
+                cleared,
+            ),
+            spdx_checksum_value: edited(req.spdx_checksum_value, "spdx_checksum_value", cleared),
+            dct_license: edited(req.dct_license, "dct_license", cleared),
+            dct_access_rights: edited(req.dct_access_rights, "dct_access_rights", cleared),
+        })
     }
 }
 
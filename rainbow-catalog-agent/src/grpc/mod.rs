@@ -5,6 +5,8 @@ pub(crate) mod distributions;
 pub(super) mod mappers;
 pub(crate) mod odrl_policies;
 pub(crate) mod policy_templates;
+#[cfg(test)]
+mod test_mappers;
 
 pub(crate) mod api {
     pub mod catalog_agent {
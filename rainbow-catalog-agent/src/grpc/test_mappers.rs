@@ -0,0 +1,55 @@
+//! gRPC edit requests mapped onto the edit DTOs
+//! Unset fields are kept, values replace and `clear_fields` nulls the optional DCAT 3 ones
+
+use crate::entities::datasets::EditDatasetDto;
+use crate::entities::distributions::EditDistributionDto;
+use crate::grpc::api::catalog_agent::{PutDatasetRequest, PutDistributionRequest};
+use tonic::Code;
+
+#[test]
+fn put_dataset_keeps_replaces_and_clears() {
+    let dto = EditDatasetDto::try_from(PutDatasetRequest {
+        id: "urn:dataset:1".to_string(),
+        dct_title: Some("Air quality".to_string()),
+        dcat_keyword: vec!["air".to_string()],
+        dct_license: Some("ignored, the field is cleared".to_string()),
+        clear_fields: vec!["dct_license".to_string(), "dct_language".to_string()],
+        ..Default::default()
+    })
+    .unwrap();
+
+    assert_eq!(dto.dct_title.as_deref(), Some("Air quality"));
+    assert_eq!(dto.dcat_keyword, Some(Some(vec!["air".to_string()])));
+    assert_eq!(dto.dct_license, Some(None));
+    assert_eq!(dto.dct_language, Some(None));
+    // an empty repeated field is indistinguishable from unset, so it keeps the stored list
+    assert_eq!(dto.dcat_theme, None);
+    assert_eq!(dto.dct_spatial, None);
+}
+
+#[test]
+fn put_distribution_clears_numeric_fields() {
+    let dto = EditDistributionDto::try_from(PutDistributionRequest {
+        id: "urn:distribution:1".to_string(),
+        dcat_byte_size: Some(10),
+        clear_fields: vec!["dcat_byte_size".to_string()],
+        ..Default::default()
+    })
+    .unwrap();
+
+    assert_eq!(dto.dcat_byte_size, Some(None));
+    assert_eq!(dto.dcat_media_type, None);
+}
+
+#[test]
+fn fields_outside_dcat_cannot_be_cleared() {
+    let status = EditDatasetDto::try_from(PutDatasetRequest {
+        id: "urn:dataset:1".to_string(),
+        clear_fields: vec!["dct_title".to_string()],
+        ..Default::default()
+    })
+    .unwrap_err();
+
+    assert_eq!(status.code(), Code::InvalidArgument);
+    assert_eq!(status.message(), "Field dct_title cannot be cleared");
+}
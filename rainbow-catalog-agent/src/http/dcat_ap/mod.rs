@@ -0,0 +1,170 @@
+use crate::errors::error_adapter::CustomToResponse;
+use crate::http::common::parse_urn;
+use crate::protocols::dcat_ap::rdf::{Graph, RdfFormat};
+use crate::protocols::dcat_ap::DcatApTrait;
+use axum::extract::{FromRef, Path, Query, State};
+use axum::http::header::{ACCEPT, CONTENT_TYPE};
+use axum::http::HeaderMap;
+use axum::response::{IntoResponse, Response};
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use rainbow_common::errors::helpers::BadFormat;
+use rainbow_common::errors::{CommonErrors, ErrorLog};
+use reqwest::StatusCode;
+use serde::Deserialize;
+use serde_json::json;
+use std::sync::Arc;
+use tracing::error;
+
+#[derive(Clone)]
+pub struct DcatApRouter {
+    service: Arc<dyn DcatApTrait>,
+}
+
+/// `format` overrides the Accept header, e.g. ?format=ttl
+#[derive(Deserialize)]
+pub struct DcatApFormatParams {
+    pub format: Option<String>,
+}
+
+impl FromRef<DcatApRouter> for Arc<dyn DcatApTrait> {
+    fn from_ref(state: &DcatApRouter) -> Self {
+        state.service.clone()
+    }
+}
+
+impl DcatApRouter {
+    pub fn new(service: Arc<dyn DcatApTrait>) -> Self {
+        Self { service }
+    }
+
+    pub fn router(self) -> Router {
+        Router::new()
+            .route("/catalog", get(Self::handle_get_catalog))
+            .route("/datasets/{dataset_id}", get(Self::handle_get_dataset))
+            .route("/peer-catalogs/{peer_id}", get(Self::handle_get_peer_catalog))
+            .route("/import", post(Self::handle_import))
+            .with_state(self)
+    }
+
+    fn negotiate(headers: &HeaderMap, params: &DcatApFormatParams) -> Result<RdfFormat, Response> {
+        let format = match params.format.as_deref() {
+            Some(name) => RdfFormat::from_name(name),
+            None => {
+                RdfFormat::negotiate(headers.get(ACCEPT).and_then(|accept| accept.to_str().ok()))
+            }
+        };
+        format.ok_or_else(|| {
+            let supported: Vec<&str> = RdfFormat::ALL.iter().map(|f| f.content_type()).collect();
+            error!(
+                "No supported RDF serialization is acceptable, supported ones are {:?}",
+                supported
+            );
+            (
+                StatusCode::NOT_ACCEPTABLE,
+                Json(json!({
+                    "error": "No acceptable RDF serialization",
+                    "supported": supported
+                })),
+            )
+                .into_response()
+        })
+    }
+
+    fn rdf_response(graph: Graph, format: RdfFormat) -> Response {
+        match graph.serialize(format) {
+            Ok(body) => {
+                (StatusCode::OK, [(CONTENT_TYPE, format.content_type())], body).into_response()
+            }
+            Err(err) => err.to_response(),
+        }
+    }
+
+    async fn handle_get_catalog(
+        State(state): State<DcatApRouter>,
+        headers: HeaderMap,
+        Query(params): Query<DcatApFormatParams>,
+    ) -> impl IntoResponse {
+        let format = match Self::negotiate(&headers, &params) {
+            Ok(format) => format,
+            Err(e) => return e,
+        };
+        match state.service.export_catalog().await {
+            Ok(graph) => Self::rdf_response(graph, format),
+            Err(err) => err.to_response(),
+        }
+    }
+
+    async fn handle_get_dataset(
+        State(state): State<DcatApRouter>,
+        headers: HeaderMap,
+        Path(dataset_id): Path<String>,
+        Query(params): Query<DcatApFormatParams>,
+    ) -> impl IntoResponse {
+        let format = match Self::negotiate(&headers, &params) {
+            Ok(format) => format,
+            Err(e) => return e,
+        };
+        let dataset_id = match parse_urn(&dataset_id) {
+            Ok(urn) => urn,
+            Err(e) => return e,
+        };
+        match state.service.export_dataset(&dataset_id).await {
+            Ok(Some(graph)) => Self::rdf_response(graph, format),
+            Ok(None) => {
+                let err = CommonErrors::missing_resource_new(
+                    &dataset_id.to_string(),
+                    "Dataset not found",
+                );
+                err.into_response()
+            }
+            Err(err) => err.to_response(),
+        }
+    }
+
+    async fn handle_get_peer_catalog(
+        State(state): State<DcatApRouter>,
+        headers: HeaderMap,
+        Path(peer_id): Path<String>,
+        Query(params): Query<DcatApFormatParams>,
+    ) -> impl IntoResponse {
+        let format = match Self::negotiate(&headers, &params) {
+            Ok(format) => format,
+            Err(e) => return e,
+        };
+        match state.service.export_peer_catalog(&peer_id).await {
+            Ok(Some(graph)) => Self::rdf_response(graph, format),
+            Ok(None) => {
+                let err =
+                    CommonErrors::missing_resource_new(peer_id.as_str(), "Peer Catalog not found");
+                err.into_response()
+            }
+            Err(err) => err.to_response(),
+        }
+    }
+
+    /// Turtle body, answered with the validation report whether it was imported or not
+    async fn handle_import(
+        State(state): State<DcatApRouter>,
+        headers: HeaderMap,
+        body: String,
+    ) -> impl IntoResponse {
+        let content_type = headers.get(CONTENT_TYPE).and_then(|c| c.to_str().ok());
+        if let Some(content_type) = content_type {
+            let media_type = content_type.split(';').next().unwrap_or_default();
+            if RdfFormat::from_media_type(media_type) != Some(RdfFormat::Turtle) {
+                let err = CommonErrors::format_new(
+                    BadFormat::Received,
+                    &format!("Only text/turtle can be imported, received {}", content_type),
+                );
+                error!("{}", err.log());
+                return err.into_response();
+            }
+        }
+        match state.service.import_turtle(body.as_str()).await {
+            Ok(report) if report.imported => (StatusCode::CREATED, Json(report)).into_response(),
+            Ok(report) => (StatusCode::UNPROCESSABLE_ENTITY, Json(report)).into_response(),
+            Err(err) => err.to_response(),
+        }
+    }
+}
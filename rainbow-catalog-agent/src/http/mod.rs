@@ -1,6 +1,7 @@
 pub(crate) mod catalogs;
 pub(crate) mod data_services;
 pub(crate) mod datasets;
+pub(crate) mod dcat_ap;
 pub(crate) mod distributions;
 pub(crate) mod odrl_policies;
 pub(crate) mod peer_catalog;
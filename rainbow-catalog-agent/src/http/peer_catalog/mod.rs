@@ -1,5 +1,5 @@
 use crate::entities::catalogs::{CatalogEntityTrait, EditCatalogDto, NewCatalogDto};
-use crate::entities::peer_catalogs::PeerCatalogTrait;
+use crate::entities::peer_catalogs::{EditCrawlScheduleDto, PeerCatalogTrait};
 use crate::errors::error_adapter::CustomToResponse;
 use crate::http::common::to_camel_case::ToCamelCase;
 use crate::http::common::{extract_payload, parse_urn};
@@ -20,6 +20,15 @@ pub struct PeerCatalogEntityRouter {
     service: Arc<dyn PeerCatalogTrait>,
 }
 
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FederatedDatasetParams {
+    pub limit: Option<u64>,
+    pub page: Option<u64>,
+    pub peer_id: Option<String>,
+    pub keyword: Option<String>,
+}
+
 impl FromRef<PeerCatalogEntityRouter> for Arc<dyn PeerCatalogTrait> {
     fn from_ref(state: &PeerCatalogEntityRouter) -> Self {
         state.service.clone()
@@ -32,7 +41,68 @@ impl PeerCatalogEntityRouter {
     }
 
     pub fn router(self) -> Router {
-        Router::new().route("/{peer_id}", get(Self::handle_get_catalog_by_peer_id)).with_state(self)
+        Router::new()
+            .route("/datasets", get(Self::handle_get_federated_datasets))
+            .route("/crawl-states", get(Self::handle_get_crawl_states))
+            .route("/{peer_id}", get(Self::handle_get_catalog_by_peer_id))
+            .route("/{peer_id}/crawl-state", get(Self::handle_get_crawl_state))
+            .route("/{peer_id}/crawl-state", put(Self::handle_put_crawl_schedule))
+            .with_state(self)
+    }
+
+    async fn handle_get_federated_datasets(
+        State(state): State<PeerCatalogEntityRouter>,
+        Query(params): Query<FederatedDatasetParams>,
+    ) -> impl IntoResponse {
+        match state
+            .service
+            .get_federated_datasets(params.limit, params.page, params.peer_id, params.keyword)
+            .await
+        {
+            Ok(datasets) => (StatusCode::OK, Json(datasets)).into_response(),
+            Err(err) => err.to_response(),
+        }
+    }
+
+    async fn handle_get_crawl_states(
+        State(state): State<PeerCatalogEntityRouter>,
+    ) -> impl IntoResponse {
+        match state.service.get_crawl_states().await {
+            Ok(states) => (StatusCode::OK, Json(ToCamelCase(states))).into_response(),
+            Err(err) => err.to_response(),
+        }
+    }
+
+    async fn handle_get_crawl_state(
+        State(state): State<PeerCatalogEntityRouter>,
+        Path(peer_id): Path<String>,
+    ) -> impl IntoResponse {
+        match state.service.get_crawl_state(&peer_id).await {
+            Ok(Some(crawl_state)) => {
+                (StatusCode::OK, Json(ToCamelCase(crawl_state))).into_response()
+            }
+            Ok(None) => {
+                let err =
+                    CommonErrors::missing_resource_new(peer_id.as_str(), "Crawl state not found");
+                err.into_response()
+            }
+            Err(err) => err.to_response(),
+        }
+    }
+
+    async fn handle_put_crawl_schedule(
+        State(state): State<PeerCatalogEntityRouter>,
+        Path(peer_id): Path<String>,
+        input: Result<Json<EditCrawlScheduleDto>, JsonRejection>,
+    ) -> impl IntoResponse {
+        let input = match extract_payload(input) {
+            Ok(v) => v,
+            Err(e) => return e,
+        };
+        match state.service.put_crawl_schedule(&peer_id, &input).await {
+            Ok(crawl_state) => (StatusCode::OK, Json(ToCamelCase(crawl_state))).into_response(),
+            Err(err) => err.to_response(),
+        }
     }
 
     async fn handle_get_catalog_by_peer_id(
@@ -0,0 +1,172 @@
+use crate::entities::catalog_tree::CatalogTreeEntityTrait;
+use crate::entities::catalogs::CatalogEntityTrait;
+use crate::entities::data_services::DataServiceEntityTrait;
+use crate::entities::datasets::DatasetEntityTrait;
+use crate::entities::distributions::DistributionEntityTrait;
+use crate::entities::peer_catalogs::PeerCatalogTrait;
+use crate::protocols::dcat_ap::export::{catalog_tree_graph, dataset_graph, peer_catalog_graph};
+use crate::protocols::dcat_ap::import::{plan_import, DcatApImportPlan};
+use crate::protocols::dcat_ap::rdf::{Graph, Term};
+use crate::protocols::dcat_ap::{DcatApImportReport, DcatApImportedEntity, DcatApTrait};
+use log::{error, info, warn};
+use std::str::FromStr;
+use std::sync::Arc;
+use urn::Urn;
+
+pub struct DcatApService {
+    catalog_tree_service: Arc<dyn CatalogTreeEntityTrait>,
+    peer_catalog_service: Arc<dyn PeerCatalogTrait>,
+    catalog_service: Arc<dyn CatalogEntityTrait>,
+    data_service_service: Arc<dyn DataServiceEntityTrait>,
+    dataset_service: Arc<dyn DatasetEntityTrait>,
+    distribution_service: Arc<dyn DistributionEntityTrait>,
+}
+
+impl DcatApService {
+    pub fn new(
+        catalog_tree_service: Arc<dyn CatalogTreeEntityTrait>,
+        peer_catalog_service: Arc<dyn PeerCatalogTrait>,
+        catalog_service: Arc<dyn CatalogEntityTrait>,
+        data_service_service: Arc<dyn DataServiceEntityTrait>,
+        dataset_service: Arc<dyn DatasetEntityTrait>,
+        distribution_service: Arc<dyn DistributionEntityTrait>,
+    ) -> Self {
+        Self {
+            catalog_tree_service,
+            peer_catalog_service,
+            catalog_service,
+            data_service_service,
+            dataset_service,
+            distribution_service,
+        }
+    }
+
+    /// Parents first, so every reference is resolvable when its child is created.
+    /// Created entities are written in the report as they go.
+    async fn create_planned(
+        &self,
+        plan: &DcatApImportPlan,
+        report: &mut DcatApImportReport,
+    ) -> anyhow::Result<()> {
+        for (source, new_catalog) in plan.catalogs.iter() {
+            let catalog = self.catalog_service.create_catalog(new_catalog).await?;
+            report.catalogs.push(Self::imported(source, &catalog.inner.id));
+        }
+        for (source, new_data_service) in plan.data_services.iter() {
+            let data_service =
+                self.data_service_service.create_data_service(new_data_service).await?;
+            report.data_services.push(Self::imported(source, &data_service.inner.id));
+        }
+        for (source, new_dataset) in plan.datasets.iter() {
+            let dataset = self.dataset_service.create_dataset(new_dataset).await?;
+            report.datasets.push(Self::imported(source, &dataset.inner.id));
+        }
+        for (source, new_distribution) in plan.distributions.iter() {
+            let distribution =
+                self.distribution_service.create_distribution(new_distribution).await?;
+            report.distributions.push(Self::imported(source, &distribution.inner.id));
+        }
+        Ok(())
+    }
+
+    /// Deletes what was created, children first. The entity services keep cache and events in sync.
+    async fn roll_back(&self, report: &DcatApImportReport) {
+        for imported in report.distributions.iter().rev() {
+            let result = match Urn::from_str(&imported.id) {
+                Ok(id) => self.distribution_service.delete_distribution_by_id(&id).await,
+                Err(err) => Err(err.into()),
+            };
+            if let Err(err) = result {
+                warn!(
+                    "Imported distribution {} could not be rolled back: {}",
+                    imported.id, err
+                );
+            }
+        }
+        for imported in report.datasets.iter().rev() {
+            let result = match Urn::from_str(&imported.id) {
+                Ok(id) => self.dataset_service.delete_dataset_by_id(&id).await,
+                Err(err) => Err(err.into()),
+            };
+            if let Err(err) = result {
+                warn!("Imported dataset {} could not be rolled back: {}", imported.id, err);
+            }
+        }
+        for imported in report.data_services.iter().rev() {
+            let result = match Urn::from_str(&imported.id) {
+                Ok(id) => self.data_service_service.delete_data_service_by_id(&id).await,
+                Err(err) => Err(err.into()),
+            };
+            if let Err(err) = result {
+                warn!(
+                    "Imported data service {} could not be rolled back: {}",
+                    imported.id, err
+                );
+            }
+        }
+        for imported in report.catalogs.iter().rev() {
+            let result = match Urn::from_str(&imported.id) {
+                Ok(id) => self.catalog_service.delete_catalog_by_id(&id).await,
+                Err(err) => Err(err.into()),
+            };
+            if let Err(err) = result {
+                warn!("Imported catalog {} could not be rolled back: {}", imported.id, err);
+            }
+        }
+    }
+
+    fn imported(source: &Term, id: &str) -> DcatApImportedEntity {
+        DcatApImportedEntity {
+            source: source.as_iri().map(str::to_string).unwrap_or_else(|| source.to_string()),
+            id: id.to_string(),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl DcatApTrait for DcatApService {
+    async fn export_catalog(&self) -> anyhow::Result<Graph> {
+        let tree = self.catalog_tree_service.get_catalog_tree().await?;
+        Ok(catalog_tree_graph(&tree))
+    }
+
+    async fn export_dataset(&self, dataset_id: &Urn) -> anyhow::Result<Option<Graph>> {
+        let tree = self.catalog_tree_service.get_catalog_tree().await?;
+        Ok(dataset_graph(&tree, &dataset_id.to_string()))
+    }
+
+    async fn export_peer_catalog(&self, peer_id: &String) -> anyhow::Result<Option<Graph>> {
+        let catalog = self.peer_catalog_service.get_peer_catalog(peer_id).await?;
+        Ok(catalog.as_ref().map(peer_catalog_graph))
+    }
+
+    async fn import_turtle(&self, input: &str) -> anyhow::Result<DcatApImportReport> {
+        let tree = self.catalog_tree_service.get_catalog_tree().await?;
+        let mut report = DcatApImportReport::default();
+        let plan = plan_import(input, &tree, &mut report);
+        if report.has_violations() {
+            return Ok(report);
+        }
+
+        if let Err(err) = self.create_planned(&plan, &mut report).await {
+            // nothing of a failed import is kept, the report tells what went wrong
+            error!("DCAT-AP import failed, rolling back: {}", err);
+            self.roll_back(&report).await;
+            report.catalogs.clear();
+            report.data_services.clear();
+            report.datasets.clear();
+            report.distributions.clear();
+            report.violation(None, None, format!("Import failed and was rolled back: {}", err));
+            return Ok(report);
+        }
+        report.imported = true;
+        info!(
+            "DCAT-AP import created {} catalogs, {} data services, {} datasets and {} distributions",
+            report.catalogs.len(),
+            report.data_services.len(),
+            report.datasets.len(),
+            report.distributions.len()
+        );
+        Ok(report)
+    }
+}
@@ -0,0 +1,672 @@
+use crate::entities::catalog_tree::CatalogTreeSnapshot;
+use crate::entities::catalogs::CatalogDto;
+use crate::entities::data_services::DataServiceDto;
+use crate::entities::datasets::DatasetDto;
+use crate::entities::distributions::DistributionDto;
+use crate::protocols::dcat_ap::rdf::vocab::*;
+use crate::protocols::dcat_ap::rdf::{Graph, Term};
+use crate::protocols::dsp::types::catalog_definition::{
+    Catalog, CatalogCatalogTypes, CatalogDSpaceDeclaration, CatalogDatasetTypes,
+    CatalogDctDeclaration, CatalogFoafDeclaration, CatalogServiceTypes,
+};
+use crate::protocols::dsp::types::dataservice_definition::{
+    DataServiceDcatDeclaration, DataServiceDctDeclaration,
+};
+use crate::protocols::dsp::types::dataset_definition::{
+    DatasetDcatDeclaration, DatasetDctDeclaration, DatasetDistributionTypes,
+};
+use crate::protocols::dsp::types::distribution_definition::{
+    DistributionDcatDeclaration, DistributionDctDeclaration,
+};
+use chrono::{NaiveDateTime, SecondsFormat};
+use sea_orm::prelude::DateTimeWithTimeZone;
+use url::Url;
+
+pub const IANA_MEDIA_TYPES: &str = "https://www.iana.org/assignments/media-types/";
+
+/// Local catalog tree as DCAT-AP, sub catalogs hanging from the main catalog
+pub fn catalog_tree_graph(tree: &CatalogTreeSnapshot) -> Graph {
+    let mut graph = Graph::new();
+    match tree.main_catalog.as_ref() {
+        Some(main_catalog) => {
+            let main_node = add_catalog(&mut graph, tree, main_catalog);
+            for catalog in tree.sub_catalogs() {
+                let node = add_catalog(&mut graph, tree, catalog);
+                graph.add(&main_node, DCAT_CATALOG_PROP, node);
+            }
+        }
+        None => {
+            for catalog in tree.catalogs.iter() {
+                add_catalog(&mut graph, tree, catalog);
+            }
+        }
+    }
+    graph
+}
+
+/// Single dataset with its distributions and the data services serving them
+pub fn dataset_graph(tree: &CatalogTreeSnapshot, dataset_id: &str) -> Option<Graph> {
+    let dataset = tree.datasets_by_id.get(dataset_id)?;
+    let mut graph = Graph::new();
+    add_dataset(&mut graph, tree, dataset);
+    for distribution in tree.distributions_of(dataset_id) {
+        if let Some(data_service) =
+            tree.data_services_by_id.get(&distribution.inner.dcat_access_service)
+        {
+            add_data_service(&mut graph, data_service);
+        }
+    }
+    Some(graph)
+}
+
+/// Peer catalog as received through DSP
+pub fn peer_catalog_graph(catalog: &Catalog) -> Graph {
+    let mut graph = Graph::new();
+    let node = Term::iri(catalog.id.to_string());
+    add_dsp_catalog(
+        &mut graph,
+        &node,
+        (&catalog.foaf, &catalog.dct, &catalog.dspace),
+        &catalog.datasets,
+        &catalog.data_services,
+    );
+    let sub_catalogs: Vec<(Term, _, _, _)> = match &catalog.catalogs {
+        CatalogCatalogTypes::CatalogMultipleMinimized(catalogs) => catalogs
+            .iter()
+            .map(|c| {
+                (
+                    Term::iri(c.id.to_string()),
+                    (&c.foaf, &c.dct, &c.dspace),
+                    &c.datasets,
+                    &c.data_services,
+                )
+            })
+            .collect(),
+        CatalogCatalogTypes::CatalogMultipleOriginal(catalogs) => catalogs
+            .iter()
+            .map(|c| {
+                (
+                    Term::iri(c.id.to_string()),
+                    (&c.foaf, &c.dct, &c.dspace),
+                    &c.datasets,
+                    &c.data_services,
+                )
+            })
+            .collect(),
+    };
+    for (sub_node, declarations, datasets, data_services) in sub_catalogs {
+        add_dsp_catalog(&mut graph, &sub_node, declarations, datasets, data_services);
+        graph.add(&node, DCAT_CATALOG_PROP, sub_node);
+    }
+    graph
+}
+
+fn add_catalog(graph: &mut Graph, tree: &CatalogTreeSnapshot, catalog: &CatalogDto) -> Term {
+    let catalog = &catalog.inner;
+    let node = Term::iri(catalog.id.as_str());
+    graph.add(&node, RDF_TYPE, Term::iri(DCAT_CATALOG));
+    graph.add_opt(&node, DCT_TITLE, catalog.dct_title.as_deref().map(Term::literal));
+    graph.add(
+        &node,
+        DCT_IDENTIFIER,
+        Term::literal(catalog.dct_identifier.as_deref().unwrap_or(catalog.id.as_str())),
+    );
+    graph.add(&node, DCT_ISSUED, date_time(&catalog.dct_issued));
+    graph.add_opt(&node, DCT_MODIFIED, catalog.dct_modified.as_ref().map(date_time));
+    graph.add_opt(
+        &node,
+        FOAF_HOMEPAGE,
+        catalog.foaf_home_page.as_deref().map(resource_or_literal),
+    );
+    graph.add_opt(
+        &node,
+        DCT_CONFORMS_TO,
+        catalog.dct_conforms_to.as_deref().map(resource_or_literal),
+    );
+    if let Some(creator) = catalog.dct_creator.as_deref() {
+        let agent = agent(graph, creator);
+        graph.add(&node, DCT_CREATOR, agent);
+    }
+    if let Some(participant_id) = catalog.dspace_participant_id.as_deref() {
+        let agent = agent(graph, participant_id);
+        graph.add(&node, DCT_PUBLISHER, agent);
+    }
+    for dataset in tree.datasets_of(catalog.id.as_str()) {
+        let dataset_node = add_dataset(graph, tree, dataset);
+        graph.add(&node, DCAT_DATASET_PROP, dataset_node);
+    }
+    for data_service in tree.data_services_of(catalog.id.as_str()) {
+        let data_service_node = add_data_service(graph, data_service);
+        graph.add(&node, DCAT_SERVICE, data_service_node);
+    }
+    node
+}
+
+fn add_dataset(graph: &mut Graph, tree: &CatalogTreeSnapshot, dataset: &DatasetDto) -> Term {
+    let inner = &dataset.inner;
+    let node = Term::iri(inner.id.as_str());
+    graph.add(&node, RDF_TYPE, Term::iri(DCAT_DATASET));
+    graph.add_opt(&node, DCT_TITLE, inner.dct_title.as_deref().map(Term::literal));
+    graph.add_opt(
+        &node,
+        DCT_DESCRIPTION,
+        inner.dct_description.as_deref().map(Term::literal),
+    );
+    graph.add(
+        &node,
+        DCT_IDENTIFIER,
+        Term::literal(inner.dct_identifier.as_deref().unwrap_or(inner.id.as_str())),
+    );
+    graph.add(&node, DCT_ISSUED, date_time(&inner.dct_issued));
+    graph.add_opt(&node, DCT_MODIFIED, inner.dct_modified.as_ref().map(date_time));
+    graph.add_opt(
+        &node,
+        DCT_CONFORMS_TO,
+        inner.dct_conforms_to.as_deref().map(resource_or_literal),
+    );
+    if let Some(creator) = inner.dct_creator.as_deref() {
+        let agent = agent(graph, creator);
+        graph.add(&node, DCT_CREATOR, agent);
+    }
+    for keyword in inner.keywords() {
+        graph.add(&node, DCAT_KEYWORD, Term::literal(keyword));
+    }
+    for theme in inner.themes() {
+        graph.add(&node, DCAT_THEME, resource_or_literal(&theme));
+    }
+    for language in inner.languages() {
+        graph.add(&node, DCT_LANGUAGE, resource_or_literal(&language));
+    }
+    graph.add_opt(
+        &node,
+        DCT_LICENSE,
+        inner.dct_license.as_deref().map(resource_or_literal),
+    );
+    graph.add_opt(
+        &node,
+        DCT_ACCESS_RIGHTS,
+        inner.dct_access_rights.as_deref().map(resource_or_literal),
+    );
+    graph.add_opt(
+        &node,
+        DCT_SPATIAL,
+        inner.dct_spatial.as_deref().map(resource_or_literal),
+    );
+    if inner.dct_temporal_start_date.is_some() || inner.dct_temporal_end_date.is_some() {
+        let period = graph.new_blank_node();
+        graph.add(&period, RDF_TYPE, Term::iri(DCT_PERIOD_OF_TIME));
+        graph.add_opt(
+            &period,
+            DCAT_START_DATE,
+            inner.dct_temporal_start_date.as_ref().map(date_time),
+        );
+        graph.add_opt(
+            &period,
+            DCAT_END_DATE,
+            inner.dct_temporal_end_date.as_ref().map(date_time),
+        );
+        graph.add(&node, DCT_TEMPORAL, period);
+    }
+    if let Some(contact_point) = inner.dcat_contact_point.as_deref() {
+        let contact = contact_point_node(graph, contact_point);
+        graph.add(&node, DCAT_CONTACT_POINT, contact);
+    }
+    graph.add_opt(&node, DCAT_VERSION, inner.dcat_version.as_deref().map(Term::literal));
+    graph.add_opt(
+        &node,
+        DCAT_PREVIOUS_VERSION,
+        inner.dcat_previous_version.as_deref().map(resource_or_literal),
+    );
+    for distribution in tree.distributions_of(inner.id.as_str()) {
+        let distribution_node = add_distribution(graph, tree, distribution);
+        graph.add(&node, DCAT_DISTRIBUTION_PROP, distribution_node);
+    }
+    node
+}
+
+fn add_distribution(
+    graph: &mut Graph,
+    tree: &CatalogTreeSnapshot,
+    distribution: &DistributionDto,
+) -> Term {
+    let inner = &distribution.inner;
+    let node = Term::iri(inner.id.as_str());
+    graph.add(&node, RDF_TYPE, Term::iri(DCAT_DISTRIBUTION));
+    graph.add_opt(&node, DCT_TITLE, inner.dct_title.as_deref().map(Term::literal));
+    graph.add_opt(
+        &node,
+        DCT_DESCRIPTION,
+        inner.dct_description.as_deref().map(Term::literal),
+    );
+    graph.add(&node, DCT_ISSUED, date_time(&inner.dct_issued));
+    graph.add_opt(&node, DCT_MODIFIED, inner.dct_modified.as_ref().map(date_time));
+    graph.add_opt(&node, DCT_FORMAT, inner.dct_format.as_deref().map(Term::literal));
+    let access_service = Term::iri(inner.dcat_access_service.as_str());
+    graph.add(&node, DCAT_ACCESS_SERVICE, access_service.clone());
+    // the dataset is reachable through the endpoint of its access service
+    if let Some(data_service) = tree.data_services_by_id.get(&inner.dcat_access_service) {
+        graph.add(
+            &node,
+            DCAT_ACCESS_URL,
+            resource_or_literal(&data_service.inner.dcat_endpoint_url),
+        );
+    }
+    graph.add(
+        &access_service,
+        DCAT_SERVES_DATASET,
+        Term::iri(inner.dataset_id.as_str()),
+    );
+    add_distribution_extras(
+        graph,
+        &node,
+        inner.dcat_byte_size,
+        inner.dcat_media_type.as_deref(),
+        inner.spdx_checksum_algorithm.as_deref().zip(inner.spdx_checksum_value.as_deref()),
+    );
+    graph.add_opt(
+        &node,
+        DCT_LICENSE,
+        inner.dct_license.as_deref().map(resource_or_literal),
+    );
+    graph.add_opt(
+        &node,
+        DCT_ACCESS_RIGHTS,
+        inner.dct_access_rights.as_deref().map(resource_or_literal),
+    );
+    node
+}
+
+fn add_data_service(graph: &mut Graph, data_service: &DataServiceDto) -> Term {
+    let inner = &data_service.inner;
+    let node = Term::iri(inner.id.as_str());
+    graph.add(&node, RDF_TYPE, Term::iri(DCAT_DATA_SERVICE));
+    graph.add_opt(&node, DCT_TITLE, inner.dct_title.as_deref().map(Term::literal));
+    graph.add_opt(
+        &node,
+        DCT_DESCRIPTION,
+        inner.dct_description.as_deref().map(Term::literal),
+    );
+    graph.add(
+        &node,
+        DCT_IDENTIFIER,
+        Term::literal(inner.dct_identifier.as_deref().unwrap_or(inner.id.as_str())),
+    );
+    graph.add(&node, DCT_ISSUED, date_time(&inner.dct_issued));
+    graph.add_opt(&node, DCT_MODIFIED, inner.dct_modified.as_ref().map(date_time));
+    graph.add(
+        &node,
+        DCAT_ENDPOINT_URL,
+        resource_or_literal(&inner.dcat_endpoint_url),
+    );
+    graph.add_opt(
+        &node,
+        DCAT_ENDPOINT_DESCRIPTION,
+        inner.dcat_endpoint_description.as_deref().map(resource_or_literal),
+    );
+    graph.add_opt(
+        &node,
+        DCT_CONFORMS_TO,
+        inner.dct_conforms_to.as_deref().map(resource_or_literal),
+    );
+    if let Some(creator) = inner.dct_creator.as_deref() {
+        let agent = agent(graph, creator);
+        graph.add(&node, DCT_CREATOR, agent);
+    }
+    for keyword in inner.keywords() {
+        graph.add(&node, DCAT_KEYWORD, Term::literal(keyword));
+    }
+    for theme in inner.themes() {
+        graph.add(&node, DCAT_THEME, resource_or_literal(&theme));
+    }
+    graph.add_opt(
+        &node,
+        DCT_LICENSE,
+        inner.dct_license.as_deref().map(resource_or_literal),
+    );
+    graph.add_opt(
+        &node,
+        DCT_ACCESS_RIGHTS,
+        inner.dct_access_rights.as_deref().map(resource_or_literal),
+    );
+    if let Some(contact_point) = inner.dcat_contact_point.as_deref() {
+        let contact = contact_point_node(graph, contact_point);
+        graph.add(&node, DCAT_CONTACT_POINT, contact);
+    }
+    node
+}
+
+fn add_dsp_catalog(
+    graph: &mut Graph,
+    node: &Term,
+    (foaf, dct, dspace): (
+        &CatalogFoafDeclaration,
+        &CatalogDctDeclaration,
+        &CatalogDSpaceDeclaration,
+    ),
+    datasets: &CatalogDatasetTypes,
+    data_services: &CatalogServiceTypes,
+) {
+    graph.add(node, RDF_TYPE, Term::iri(DCAT_CATALOG));
+    graph.add_opt(node, DCT_TITLE, dct.title.as_deref().map(Term::literal));
+    for description in dct.description.iter() {
+        graph.add(node, DCT_DESCRIPTION, Term::literal(description));
+    }
+    graph.add(node, DCT_IDENTIFIER, Term::literal(dct.identifier.as_str()));
+    graph.add(node, DCT_ISSUED, naive_date_time(&dct.issued));
+    graph.add_opt(node, DCT_MODIFIED, dct.modified.as_ref().map(naive_date_time));
+    graph.add_opt(node, FOAF_HOMEPAGE, foaf.homepage.as_deref().map(resource_or_literal));
+    graph.add_opt(
+        node,
+        DCT_CONFORMS_TO,
+        dct.conforms_to.as_deref().map(resource_or_literal),
+    );
+    if let Some(creator) = dct.creator.as_deref() {
+        let agent = agent(graph, creator);
+        graph.add(node, DCT_CREATOR, agent);
+    }
+    if let Some(participant_id) = dspace.participant_id.as_deref() {
+        let agent = agent(graph, participant_id);
+        graph.add(node, DCT_PUBLISHER, agent);
+    }
+    let datasets: Vec<(
+        &String,
+        &DatasetDcatDeclaration,
+        &DatasetDctDeclaration,
+        &DatasetDistributionTypes,
+    )> = match datasets {
+        CatalogDatasetTypes::DatasetMultipleMinimized(datasets) => {
+            datasets.iter().map(|d| (&d.id, &d.dcat, &d.dct, &d.distribution)).collect()
+        }
+        CatalogDatasetTypes::DatasetMultipleOriginal(datasets) => {
+            datasets.iter().map(|d| (&d.id, &d.dcat, &d.dct, &d.distribution)).collect()
+        }
+    };
+    for (id, dcat, dct, distributions) in datasets {
+        let dataset_node = Term::iri(id.as_str());
+        add_dsp_dataset(graph, &dataset_node, dcat, dct, distributions);
+        graph.add(node, DCAT_DATASET_PROP, dataset_node);
+    }
+    for (id, dcat, dct) in dsp_data_services(data_services) {
+        let data_service_node = Term::iri(id.as_str());
+        add_dsp_data_service(graph, &data_service_node, dcat, dct);
+        graph.add(node, DCAT_SERVICE, data_service_node);
+    }
+}
+
+fn add_dsp_dataset(
+    graph: &mut Graph,
+    node: &Term,
+    dcat: &DatasetDcatDeclaration,
+    dct: &DatasetDctDeclaration,
+    distributions: &DatasetDistributionTypes,
+) {
+    graph.add(node, RDF_TYPE, Term::iri(DCAT_DATASET));
+    graph.add_opt(node, DCT_TITLE, dct.title.as_deref().map(Term::literal));
+    for description in dct.description.iter() {
+        graph.add(node, DCT_DESCRIPTION, Term::literal(description));
+    }
+    graph.add(node, DCT_IDENTIFIER, Term::literal(dct.identifier.as_str()));
+    graph.add(node, DCT_ISSUED, naive_date_time(&dct.issued));
+    graph.add_opt(node, DCT_MODIFIED, dct.modified.as_ref().map(naive_date_time));
+    graph.add_opt(
+        node,
+        DCT_CONFORMS_TO,
+        dct.conforms_to.as_deref().map(resource_or_literal),
+    );
+    if let Some(creator) = dct.creator.as_deref() {
+        let agent = agent(graph, creator);
+        graph.add(node, DCT_CREATOR, agent);
+    }
+    for keyword in dcat.keyword.iter() {
+        graph.add(node, DCAT_KEYWORD, Term::literal(keyword));
+    }
+    for theme in dcat.theme.iter() {
+        graph.add(node, DCAT_THEME, resource_or_literal(theme));
+    }
+    for language in dct.language.iter() {
+        graph.add(node, DCT_LANGUAGE, resource_or_literal(language));
+    }
+    graph.add_opt(node, DCT_LICENSE, dct.license.as_deref().map(resource_or_literal));
+    graph.add_opt(
+        node,
+        DCT_ACCESS_RIGHTS,
+        dct.access_rights.as_deref().map(resource_or_literal),
+    );
+    graph.add_opt(node, DCT_SPATIAL, dct.spatial.as_deref().map(resource_or_literal));
+    if let Some(temporal) = dct.temporal.as_ref() {
+        let period = graph.new_blank_node();
+        graph.add(&period, RDF_TYPE, Term::iri(DCT_PERIOD_OF_TIME));
+        graph.add_opt(
+            &period,
+            DCAT_START_DATE,
+            temporal.start_date.as_ref().map(naive_date_time),
+        );
+        graph.add_opt(
+            &period,
+            DCAT_END_DATE,
+            temporal.end_date.as_ref().map(naive_date_time),
+        );
+        graph.add(node, DCT_TEMPORAL, period);
+    }
+    if let Some(contact_point) = dcat.contact_point.as_deref() {
+        let contact = contact_point_node(graph, contact_point);
+        graph.add(node, DCAT_CONTACT_POINT, contact);
+    }
+    graph.add_opt(node, DCAT_VERSION, dcat.version.as_deref().map(Term::literal));
+    graph.add_opt(
+        node,
+        DCAT_PREVIOUS_VERSION,
+        dcat.previous_version.as_deref().map(resource_or_literal),
+    );
+    let distributions: Vec<(&String, &DistributionDcatDeclaration, &DistributionDctDeclaration)> =
+        match distributions {
+            DatasetDistributionTypes::DistributionMultipleMinimized(distributions) => {
+                distributions.iter().map(|d| (&d.id, &d.dcat, &d.dct)).collect()
+            }
+            DatasetDistributionTypes::DistributionMultipleOriginal(distributions) => {
+                distributions.iter().map(|d| (&d.id, &d.dcat, &d.dct)).collect()
+            }
+        };
+    for (id, dcat, dct) in distributions {
+        let distribution_node = Term::iri(id.as_str());
+        add_dsp_distribution(graph, &distribution_node, dcat, dct);
+        graph.add(node, DCAT_DISTRIBUTION_PROP, distribution_node);
+    }
+}
+
+fn add_dsp_distribution(
+    graph: &mut Graph,
+    node: &Term,
+    dcat: &DistributionDcatDeclaration,
+    dct: &DistributionDctDeclaration,
+) {
+    graph.add(node, RDF_TYPE, Term::iri(DCAT_DISTRIBUTION));
+    graph.add_opt(node, DCT_TITLE, dct.title.as_deref().map(Term::literal));
+    for description in dct.description.iter() {
+        graph.add(node, DCT_DESCRIPTION, Term::literal(description));
+    }
+    graph.add(node, DCT_ISSUED, naive_date_time(&dct.issued));
+    graph.add_opt(node, DCT_MODIFIED, dct.modified.as_ref().map(naive_date_time));
+    graph.add(node, DCT_FORMAT, Term::literal(dct.formats.to_string()));
+    if let Some(access_service) = dcat.access_service.as_ref() {
+        for (id, service_dcat, service_dct) in dsp_data_services(access_service) {
+            let service_node = Term::iri(id.as_str());
+            add_dsp_data_service(graph, &service_node, service_dcat, service_dct);
+            graph.add(node, DCAT_ACCESS_SERVICE, service_node);
+            graph.add(node, DCAT_ACCESS_URL, resource_or_literal(&service_dcat.endpoint_url));
+        }
+    }
+    add_distribution_extras(
+        graph,
+        node,
+        dcat.byte_size,
+        dcat.media_type.as_deref(),
+        dcat.checksum.as_ref().map(|c| (c.algorithm.as_str(), c.checksum_value.as_str())),
+    );
+    graph.add_opt(node, DCT_LICENSE, dct.license.as_deref().map(resource_or_literal));
+    graph.add_opt(
+        node,
+        DCT_ACCESS_RIGHTS,
+        dct.access_rights.as_deref().map(resource_or_literal),
+    );
+}
+
+fn add_dsp_data_service(
+    graph: &mut Graph,
+    node: &Term,
+    dcat: &DataServiceDcatDeclaration,
+    dct: Option<&DataServiceDctDeclaration>,
+) {
+    graph.add(node, RDF_TYPE, Term::iri(DCAT_DATA_SERVICE));
+    graph.add(node, DCAT_ENDPOINT_URL, resource_or_literal(&dcat.endpoint_url));
+    graph.add_opt(
+        node,
+        DCAT_ENDPOINT_DESCRIPTION,
+        dcat.endpoint_description.as_deref().map(resource_or_literal),
+    );
+    for keyword in dcat.keyword.iter() {
+        graph.add(node, DCAT_KEYWORD, Term::literal(keyword));
+    }
+    for theme in dcat.theme.iter() {
+        graph.add(node, DCAT_THEME, resource_or_literal(theme));
+    }
+    if let Some(contact_point) = dcat.contact_point.as_deref() {
+        let contact = contact_point_node(graph, contact_point);
+        graph.add(node, DCAT_CONTACT_POINT, contact);
+    }
+    // minimized data services only carry their dcat declaration
+    let Some(dct) = dct else {
+        return;
+    };
+    graph.add_opt(node, DCT_TITLE, dct.title.as_deref().map(Term::literal));
+    for description in dct.description.iter() {
+        graph.add(node, DCT_DESCRIPTION, Term::literal(description));
+    }
+    graph.add(node, DCT_IDENTIFIER, Term::literal(dct.identifier.as_str()));
+    graph.add(node, DCT_ISSUED, naive_date_time(&dct.issued));
+    graph.add_opt(node, DCT_MODIFIED, dct.modified.as_ref().map(naive_date_time));
+    graph.add_opt(
+        node,
+        DCT_CONFORMS_TO,
+        dct.conforms_to.as_deref().map(resource_or_literal),
+    );
+    if let Some(creator) = dct.creator.as_deref() {
+        let agent = agent(graph, creator);
+        graph.add(node, DCT_CREATOR, agent);
+    }
+    graph.add_opt(node, DCT_LICENSE, dct.license.as_deref().map(resource_or_literal));
+    graph.add_opt(
+        node,
+        DCT_ACCESS_RIGHTS,
+        dct.access_rights.as_deref().map(resource_or_literal),
+    );
+}
+
+fn dsp_data_services(
+    data_services: &CatalogServiceTypes,
+) -> Vec<(
+    &String,
+    &DataServiceDcatDeclaration,
+    Option<&DataServiceDctDeclaration>,
+)> {
+    match data_services {
+        CatalogServiceTypes::ServiceOnly(s) => vec![(&s.id, &s.dcat, Some(&s.dct))],
+        CatalogServiceTypes::ServiceMinimized(s) => vec![(&s.id, &s.dcat, None)],
+        CatalogServiceTypes::ServiceMultiple(services) => {
+            services.iter().map(|s| (&s.id, &s.dcat, Some(&s.dct))).collect()
+        }
+    }
+}
+
+fn add_distribution_extras(
+    graph: &mut Graph,
+    node: &Term,
+    byte_size: Option<i64>,
+    media_type: Option<&str>,
+    checksum: Option<(&str, &str)>,
+) {
+    graph.add_opt(
+        node,
+        DCAT_BYTE_SIZE,
+        byte_size.map(|size| Term::typed_literal(size.to_string(), XSD_NON_NEGATIVE_INTEGER)),
+    );
+    graph.add_opt(node, DCAT_MEDIA_TYPE, media_type.map(media_type_term));
+    if let Some((algorithm, value)) = checksum {
+        let checksum = graph.new_blank_node();
+        graph.add(&checksum, RDF_TYPE, Term::iri(SPDX_CHECKSUM_CLASS));
+        graph.add(&checksum, SPDX_ALGORITHM, checksum_algorithm_term(algorithm));
+        graph.add(
+            &checksum,
+            SPDX_CHECKSUM_VALUE,
+            Term::typed_literal(value, XSD_HEX_BINARY),
+        );
+        graph.add(node, SPDX_CHECKSUM, checksum);
+    }
+}
+
+/// Strings holding an absolute IRI (http, urn, mailto, did...) are linked, anything else is text
+pub fn is_iri(value: &str) -> bool {
+    !value.is_empty() && !value.contains(char::is_whitespace) && Url::parse(value).is_ok()
+}
+
+fn resource_or_literal(value: &str) -> Term {
+    match is_iri(value) {
+        true => Term::iri(value),
+        false => Term::literal(value),
+    }
+}
+
+/// foaf:Agent, named by a blank node when the agent is not an IRI
+fn agent(graph: &mut Graph, value: &str) -> Term {
+    if is_iri(value) {
+        return Term::iri(value);
+    }
+    let node = graph.new_blank_node();
+    graph.add(&node, RDF_TYPE, Term::iri(FOAF_AGENT));
+    graph.add(&node, FOAF_NAME, Term::literal(value));
+    node
+}
+
+/// vcard:Kind with an email when the contact point looks like one, a name otherwise
+fn contact_point_node(graph: &mut Graph, value: &str) -> Term {
+    if is_iri(value) && !value.starts_with("mailto:") {
+        return Term::iri(value);
+    }
+    let node = graph.new_blank_node();
+    graph.add(&node, RDF_TYPE, Term::iri(VCARD_KIND));
+    if value.starts_with("mailto:") {
+        graph.add(&node, VCARD_HAS_EMAIL, Term::iri(value));
+    } else if value.contains('@') && !value.contains(char::is_whitespace) {
+        graph.add(&node, VCARD_HAS_EMAIL, Term::iri(format!("mailto:{}", value)));
+    } else {
+        graph.add(&node, VCARD_FN, Term::literal(value));
+    }
+    node
+}
+
+fn media_type_term(media_type: &str) -> Term {
+    match is_iri(media_type) {
+        true => Term::iri(media_type),
+        false => Term::iri(format!("{}{}", IANA_MEDIA_TYPES, media_type)),
+    }
+}
+
+/// spdx individuals drop the dash, as in spdx:checksumAlgorithm_sha256
+fn checksum_algorithm_term(algorithm: &str) -> Term {
+    match is_iri(algorithm) {
+        true => Term::iri(algorithm),
+        false => Term::iri(format!(
+            "{}{}",
+            SPDX_ALGORITHM_PREFIX,
+            algorithm.to_lowercase().replace('-', "")
+        )),
+    }
+}
+
+fn date_time(value: &DateTimeWithTimeZone) -> Term {
+    Term::typed_literal(value.to_rfc3339_opts(SecondsFormat::Secs, true), XSD_DATE_TIME)
+}
+
+fn naive_date_time(value: &NaiveDateTime) -> Term {
+    Term::typed_literal(value.format("%Y-%m-%dT%H:%M:%S").to_string(), XSD_DATE_TIME)
+}
@@ -0,0 +1,541 @@
+use crate::entities::catalog_tree::CatalogTreeSnapshot;
+use crate::entities::catalogs::NewCatalogDto;
+use crate::entities::data_services::NewDataServiceDto;
+use crate::entities::datasets::NewDatasetDto;
+use crate::entities::distributions::NewDistributionDto;
+use crate::protocols::dcat_ap::export::IANA_MEDIA_TYPES;
+use crate::protocols::dcat_ap::rdf::vocab::*;
+use crate::protocols::dcat_ap::rdf::{Graph, RdfFormat, Term};
+use crate::protocols::dcat_ap::DcatApImportReport;
+use chrono::{DateTime, NaiveDate, NaiveDateTime};
+use rainbow_common::dcat_formats::DctFormats;
+use rainbow_common::utils::get_urn;
+use sea_orm::prelude::DateTimeWithTimeZone;
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+use urn::Urn;
+
+/// Entities to create, each with the node it comes from, in creation order
+#[derive(Debug, Default)]
+pub struct DcatApImportPlan {
+    pub catalogs: Vec<(Term, NewCatalogDto)>,
+    pub data_services: Vec<(Term, NewDataServiceDto)>,
+    pub datasets: Vec<(Term, NewDatasetDto)>,
+    pub distributions: Vec<(Term, NewDistributionDto)>,
+}
+
+/// Parses and validates a DCAT-AP Turtle document against the current catalog tree.
+/// Issues are written in the report, and the plan is only usable when it has no violations.
+pub fn plan_import(
+    input: &str,
+    tree: &CatalogTreeSnapshot,
+    report: &mut DcatApImportReport,
+) -> DcatApImportPlan {
+    let graph = match Graph::parse(input, RdfFormat::Turtle, None) {
+        Ok(graph) => graph,
+        Err(err) => {
+            report.violation(None, None, err.to_string());
+            return DcatApImportPlan::default();
+        }
+    };
+    let mut planner = ImportPlanner::new(&graph, tree);
+    planner.assign_ids(report);
+    let plan = DcatApImportPlan {
+        catalogs: planner.catalogs(report),
+        data_services: planner.data_services(report),
+        datasets: planner.datasets(report),
+        distributions: planner.distributions(report),
+    };
+    if plan.catalogs.is_empty()
+        && plan.data_services.is_empty()
+        && plan.datasets.is_empty()
+        && plan.distributions.is_empty()
+        && !report.has_violations()
+    {
+        report.violation(
+            None,
+            None,
+            "Document holds no dcat:Catalog, dcat:Dataset, dcat:Distribution nor dcat:DataService",
+        );
+    }
+    plan
+}
+
+struct ImportPlanner<'a> {
+    graph: &'a Graph,
+    tree: &'a CatalogTreeSnapshot,
+    ids: HashMap<&'a Term, Urn>,
+    /// endpoint url of every data service known after the import, to resolve dcat:accessURL
+    endpoints: HashMap<String, Urn>,
+}
+
+impl<'a> ImportPlanner<'a> {
+    fn new(graph: &'a Graph, tree: &'a CatalogTreeSnapshot) -> Self {
+        let endpoints = tree
+            .data_services_by_id
+            .values()
+            .filter_map(|ds| {
+                Some((ds.inner.dcat_endpoint_url.clone(), Urn::from_str(&ds.inner.id).ok()?))
+            })
+            .collect();
+        Self { graph, tree, ids: HashMap::new(), endpoints }
+    }
+
+    fn exists(&self, id: &str) -> bool {
+        self.tree.catalogs.iter().any(|c| c.inner.id == id)
+            || self.tree.datasets_by_id.contains_key(id)
+            || self.tree.data_services_by_id.contains_key(id)
+            || self.tree.distributions_by_dataset.values().flatten().any(|d| d.inner.id == id)
+    }
+
+    /// URN subjects keep their id, any other node gets a fresh one
+    fn assign_ids(&mut self, report: &mut DcatApImportReport) {
+        let classes = [DCAT_CATALOG, DCAT_DATA_SERVICE, DCAT_DATASET, DCAT_DISTRIBUTION];
+        let mut taken = HashSet::new();
+        for class in classes {
+            for subject in self.graph.subjects_of_type(class) {
+                if self.ids.contains_key(subject) {
+                    report.violation(
+                        Some(subject),
+                        Some(RDF_TYPE),
+                        "Node is declared with several DCAT classes",
+                    );
+                    continue;
+                }
+                let urn = match subject.as_iri().filter(|iri| iri.starts_with("urn:")) {
+                    Some(iri) => match Urn::from_str(iri) {
+                        Ok(urn) => urn,
+                        Err(err) => {
+                            report.violation(
+                                Some(subject),
+                                None,
+                                format!("Identifier is not a valid URN: {}", err),
+                            );
+                            continue;
+                        }
+                    },
+                    None => get_urn(None),
+                };
+                if self.exists(&urn.to_string()) {
+                    report.violation(
+                        Some(subject),
+                        None,
+                        "An entity with this identifier already exists",
+                    );
+                    continue;
+                }
+                if !taken.insert(urn.to_string()) {
+                    report.violation(Some(subject), None, "Identifier is used by several nodes");
+                    continue;
+                }
+                self.ids.insert(subject, urn);
+            }
+        }
+    }
+
+    fn catalogs(&mut self, report: &mut DcatApImportReport) -> Vec<(Term, NewCatalogDto)> {
+        let mut catalogs = vec![];
+        for subject in self.graph.subjects_of_type(DCAT_CATALOG) {
+            let Some(id) = self.ids.get(subject).cloned() else {
+                continue;
+            };
+            let dct_title = self.required_text(report, subject, DCT_TITLE);
+            catalogs.push((
+                subject.clone(),
+                NewCatalogDto {
+                    id: Some(id),
+                    foaf_home_page: self.text(subject, FOAF_HOMEPAGE),
+                    dct_conforms_to: self.text(subject, DCT_CONFORMS_TO),
+                    dct_creator: self.agent(subject, DCT_CREATOR),
+                    dct_title,
+                    dspace_participant_id: self.agent(subject, DCT_PUBLISHER),
+                },
+            ));
+        }
+        catalogs
+    }
+
+    fn data_services(&mut self, report: &mut DcatApImportReport) -> Vec<(Term, NewDataServiceDto)> {
+        let mut data_services = vec![];
+        for subject in self.graph.subjects_of_type(DCAT_DATA_SERVICE) {
+            let Some(id) = self.ids.get(subject).cloned() else {
+                continue;
+            };
+            let dct_title = self.required_text(report, subject, DCT_TITLE);
+            self.recommended_text(report, subject, DCT_DESCRIPTION);
+            let Some(dcat_endpoint_url) = self.required_text(report, subject, DCAT_ENDPOINT_URL)
+            else {
+                continue;
+            };
+            let Some(catalog_id) = self.parent_catalog(report, subject, DCAT_SERVICE) else {
+                continue;
+            };
+            self.endpoints.insert(dcat_endpoint_url.clone(), id.clone());
+            data_services.push((
+                subject.clone(),
+                NewDataServiceDto {
+                    id: Some(id),
+                    dcat_endpoint_description: self.text(subject, DCAT_ENDPOINT_DESCRIPTION),
+                    dcat_endpoint_url,
+                    dct_conforms_to: self.text(subject, DCT_CONFORMS_TO),
+                    dct_creator: self.agent(subject, DCT_CREATOR),
+                    dct_title,
+                    dct_description: self.text(subject, DCT_DESCRIPTION),
+                    catalog_id,
+                    dcat_keyword: self.texts(subject, DCAT_KEYWORD),
+                    dcat_theme: self.texts(subject, DCAT_THEME),
+                    dct_license: self.text(subject, DCT_LICENSE),
+                    dct_access_rights: self.text(subject, DCT_ACCESS_RIGHTS),
+                    dcat_contact_point: self.contact_point(subject),
+                },
+            ));
+        }
+        data_services
+    }
+
+    fn datasets(&mut self, report: &mut DcatApImportReport) -> Vec<(Term, NewDatasetDto)> {
+        let mut datasets = vec![];
+        for subject in self.graph.subjects_of_type(DCAT_DATASET) {
+            let Some(id) = self.ids.get(subject).cloned() else {
+                continue;
+            };
+            let dct_title = self.required_text(report, subject, DCT_TITLE);
+            self.recommended_text(report, subject, DCT_DESCRIPTION);
+            let (dct_temporal_start_date, dct_temporal_end_date) = self.temporal(report, subject);
+            let Some(catalog_id) = self.parent_catalog(report, subject, DCAT_DATASET_PROP) else {
+                continue;
+            };
+            datasets.push((
+                subject.clone(),
+                NewDatasetDto {
+                    id: Some(id),
+                    dct_conforms_to: self.text(subject, DCT_CONFORMS_TO),
+                    dct_creator: self.agent(subject, DCT_CREATOR),
+                    dct_title,
+                    dct_description: self.text(subject, DCT_DESCRIPTION),
+                    catalog_id,
+                    dcat_keyword: self.texts(subject, DCAT_KEYWORD),
+                    dcat_theme: self.texts(subject, DCAT_THEME),
+                    dct_language: self.texts(subject, DCT_LANGUAGE),
+                    dct_license: self.text(subject, DCT_LICENSE),
+                    dct_access_rights: self.text(subject, DCT_ACCESS_RIGHTS),
+                    dct_spatial: self.text(subject, DCT_SPATIAL),
+                    dct_temporal_start_date,
+                    dct_temporal_end_date,
+                    dcat_contact_point: self.contact_point(subject),
+                    dcat_version: self.text(subject, DCAT_VERSION),
+                    dcat_previous_version: self.text(subject, DCAT_PREVIOUS_VERSION),
+                },
+            ));
+        }
+        datasets
+    }
+
+    fn distributions(
+        &mut self,
+        report: &mut DcatApImportReport,
+    ) -> Vec<(Term, NewDistributionDto)> {
+        let mut distributions = vec![];
+        for subject in self.graph.subjects_of_type(DCAT_DISTRIBUTION) {
+            let Some(id) = self.ids.get(subject).cloned() else {
+                continue;
+            };
+            if self.text(subject, DCT_TITLE).is_none() {
+                report.warning(Some(subject), Some(DCT_TITLE), "Distribution has no title");
+            }
+            let dct_formats = self.format(report, subject);
+            let dcat_byte_size = self.byte_size(report, subject);
+            let (spdx_checksum_algorithm, spdx_checksum_value) = self.checksum(report, subject);
+            let Some(dataset_id) = self.parent_dataset(report, subject) else {
+                continue;
+            };
+            let Some(dcat_access_service) = self.access_service(report, subject) else {
+                continue;
+            };
+            distributions.push((
+                subject.clone(),
+                NewDistributionDto {
+                    id: Some(id),
+                    dct_title: self.text(subject, DCT_TITLE),
+                    dct_description: self.text(subject, DCT_DESCRIPTION),
+                    dct_formats,
+                    dcat_access_service: dcat_access_service.to_string(),
+                    dataset_id,
+                    dcat_byte_size,
+                    dcat_media_type: self
+                        .text(subject, DCAT_MEDIA_TYPE)
+                        .map(|m| m.strip_prefix(IANA_MEDIA_TYPES).map(str::to_string).unwrap_or(m)),
+                    spdx_checksum_algorithm,
+                    spdx_checksum_value,
+                    dct_license: self.text(subject, DCT_LICENSE),
+                    dct_access_rights: self.text(subject, DCT_ACCESS_RIGHTS),
+                },
+            ));
+        }
+        distributions
+    }
+
+    fn text(&self, subject: &Term, predicate: &str) -> Option<String> {
+        self.graph.object(subject, predicate).and_then(Term::as_str).map(str::to_string)
+    }
+
+    fn texts(&self, subject: &Term, predicate: &str) -> Option<Vec<String>> {
+        let values: Vec<String> = self
+            .graph
+            .objects(subject, predicate)
+            .into_iter()
+            .filter_map(Term::as_str)
+            .map(str::to_string)
+            .collect();
+        (!values.is_empty()).then_some(values)
+    }
+
+    fn required_text(
+        &self,
+        report: &mut DcatApImportReport,
+        subject: &Term,
+        predicate: &str,
+    ) -> Option<String> {
+        let value = self.text(subject, predicate);
+        if value.is_none() {
+            report.violation(Some(subject), Some(predicate), "Mandatory property is missing");
+        }
+        value
+    }
+
+    fn recommended_text(&self, report: &mut DcatApImportReport, subject: &Term, predicate: &str) {
+        if self.text(subject, predicate).is_none() {
+            report.warning(Some(subject), Some(predicate), "Recommended property is missing");
+        }
+    }
+
+    /// foaf:Agent given by its IRI or by its foaf:name
+    fn agent(&self, subject: &Term, predicate: &str) -> Option<String> {
+        match self.graph.object(subject, predicate)? {
+            node @ Term::BlankNode(_) => self.text(node, FOAF_NAME),
+            term => term.as_str().map(str::to_string),
+        }
+    }
+
+    /// vcard:Kind reduced to its email, or to its name when it has none
+    fn contact_point(&self, subject: &Term) -> Option<String> {
+        match self.graph.object(subject, DCAT_CONTACT_POINT)? {
+            node @ Term::BlankNode(_) => self
+                .text(node, VCARD_HAS_EMAIL)
+                .map(|email| email.strip_prefix("mailto:").map(str::to_string).unwrap_or(email))
+                .or_else(|| self.text(node, VCARD_FN)),
+            term => term.as_str().map(str::to_string),
+        }
+    }
+
+    fn parent_catalog(
+        &self,
+        report: &mut DcatApImportReport,
+        subject: &Term,
+        predicate: &str,
+    ) -> Option<Urn> {
+        if let Some(parent) = self.graph.subjects(predicate, subject).into_iter().next() {
+            if let Some(id) = self.ids.get(parent) {
+                return Some(id.clone());
+            }
+            if let Some(iri) = parent.as_iri() {
+                if self.tree.catalogs.iter().any(|c| c.inner.id == iri) {
+                    return Urn::from_str(iri).ok();
+                }
+            }
+            report.violation(
+                Some(subject),
+                Some(predicate),
+                "Parent catalog is neither imported nor known",
+            );
+            return None;
+        }
+        match self.tree.main_catalog.as_ref().and_then(|c| Urn::from_str(&c.inner.id).ok()) {
+            Some(main_catalog) => {
+                report.warning(
+                    Some(subject),
+                    Some(predicate),
+                    "Not listed by any catalog, added to the main catalog",
+                );
+                Some(main_catalog)
+            }
+            None => {
+                report.violation(
+                    Some(subject),
+                    Some(predicate),
+                    "Not listed by any catalog and there is no main catalog",
+                );
+                None
+            }
+        }
+    }
+
+    fn parent_dataset(&self, report: &mut DcatApImportReport, subject: &Term) -> Option<Urn> {
+        let Some(parent) = self.graph.subjects(DCAT_DISTRIBUTION_PROP, subject).into_iter().next()
+        else {
+            report.violation(
+                Some(subject),
+                Some(DCAT_DISTRIBUTION_PROP),
+                "Distribution is not listed by any dataset",
+            );
+            return None;
+        };
+        if let Some(id) = self.ids.get(parent) {
+            return Some(id.clone());
+        }
+        if let Some(iri) = parent.as_iri() {
+            if self.tree.datasets_by_id.contains_key(iri) {
+                return Urn::from_str(iri).ok();
+            }
+        }
+        report.violation(
+            Some(subject),
+            Some(DCAT_DISTRIBUTION_PROP),
+            "Parent dataset is neither imported nor known",
+        );
+        None
+    }
+
+    /// dcat:accessService, else the service whose endpoint is the dcat:accessURL, else the main data service
+    fn access_service(&self, report: &mut DcatApImportReport, subject: &Term) -> Option<Urn> {
+        if let Some(service) = self.graph.object(subject, DCAT_ACCESS_SERVICE) {
+            if let Some(id) = self.ids.get(service) {
+                return Some(id.clone());
+            }
+            if let Some(iri) = service.as_iri() {
+                if self.tree.data_services_by_id.contains_key(iri) {
+                    return Urn::from_str(iri).ok();
+                }
+            }
+            report.violation(
+                Some(subject),
+                Some(DCAT_ACCESS_SERVICE),
+                "Access service is neither imported nor known",
+            );
+            return None;
+        }
+        let access_url = self.text(subject, DCAT_ACCESS_URL);
+        if let Some(id) = access_url.as_ref().and_then(|url| self.endpoints.get(url)) {
+            return Some(id.clone());
+        }
+        match self.tree.main_data_service.as_ref().and_then(|ds| Urn::from_str(&ds.inner.id).ok()) {
+            Some(main_data_service) => {
+                report.warning(
+                    Some(subject),
+                    Some(DCAT_ACCESS_SERVICE),
+                    "No access service matches the distribution, served by the main data service",
+                );
+                Some(main_data_service)
+            }
+            None => {
+                report.violation(
+                    Some(subject),
+                    Some(DCAT_ACCESS_SERVICE),
+                    "No access service matches the distribution and there is no main data service",
+                );
+                None
+            }
+        }
+    }
+
+    /// dct:format only maps when it is one of the dataspace formats such as http+pull
+    fn format(&self, report: &mut DcatApImportReport, subject: &Term) -> Option<DctFormats> {
+        let format = self.text(subject, DCT_FORMAT)?;
+        match format.parse::<DctFormats>() {
+            Ok(format) => Some(format),
+            Err(_) => {
+                report.warning(
+                    Some(subject),
+                    Some(DCT_FORMAT),
+                    format!("Format {} is not a dataspace format and is ignored", format),
+                );
+                None
+            }
+        }
+    }
+
+    fn byte_size(&self, report: &mut DcatApImportReport, subject: &Term) -> Option<i64> {
+        let byte_size = self.text(subject, DCAT_BYTE_SIZE)?;
+        match byte_size.parse::<i64>() {
+            Ok(size) if size >= 0 => Some(size),
+            _ => {
+                report.violation(
+                    Some(subject),
+                    Some(DCAT_BYTE_SIZE),
+                    format!("Byte size {} is not a non negative integer", byte_size),
+                );
+                None
+            }
+        }
+    }
+
+    fn checksum(
+        &self,
+        report: &mut DcatApImportReport,
+        subject: &Term,
+    ) -> (Option<String>, Option<String>) {
+        let Some(checksum) = self.graph.object(subject, SPDX_CHECKSUM) else {
+            return (None, None);
+        };
+        let algorithm = self
+            .text(checksum, SPDX_ALGORITHM)
+            .map(|a| a.strip_prefix(SPDX_ALGORITHM_PREFIX).map(str::to_string).unwrap_or(a));
+        let value = self.text(checksum, SPDX_CHECKSUM_VALUE);
+        if algorithm.is_none() || value.is_none() {
+            report.violation(
+                Some(subject),
+                Some(SPDX_CHECKSUM),
+                "Checksum needs both an algorithm and a value",
+            );
+            return (None, None);
+        }
+        (algorithm, value)
+    }
+
+    fn temporal(
+        &self,
+        report: &mut DcatApImportReport,
+        subject: &Term,
+    ) -> (Option<DateTimeWithTimeZone>, Option<DateTimeWithTimeZone>) {
+        let Some(period) = self.graph.object(subject, DCT_TEMPORAL) else {
+            return (None, None);
+        };
+        let mut date = |predicate: &str| {
+            let value = self.text(period, predicate)?;
+            let parsed = parse_date(&value);
+            if parsed.is_none() {
+                report.violation(
+                    Some(subject),
+                    Some(predicate),
+                    format!("Date {} is not a xsd:date nor a xsd:dateTime", value),
+                );
+            }
+            parsed
+        };
+        let start = date(DCAT_START_DATE);
+        let end = date(DCAT_END_DATE);
+        if let (Some(start), Some(end)) = (start.as_ref(), end.as_ref()) {
+            if start > end {
+                report.violation(
+                    Some(subject),
+                    Some(DCT_TEMPORAL),
+                    "Period of time starts after it ends",
+                );
+            }
+        }
+        (start, end)
+    }
+}
+
+fn parse_date(value: &str) -> Option<DateTimeWithTimeZone> {
+    if let Ok(date_time) = DateTime::parse_from_rfc3339(value) {
+        return Some(date_time);
+    }
+    if let Ok(date_time) = NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S%.f") {
+        return Some(date_time.and_utc().fixed_offset());
+    }
+    NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .ok()
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .map(|date_time| date_time.and_utc().fixed_offset())
+}
@@ -0,0 +1,99 @@
+pub(crate) mod dcat_ap;
+pub(crate) mod export;
+pub(crate) mod import;
+pub(crate) mod rdf;
+#[cfg(test)]
+mod test_dcat_ap_import;
+
+use crate::protocols::dcat_ap::rdf::{Graph, Term};
+use serde::Serialize;
+use urn::Urn;
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+pub enum DcatApIssueSeverity {
+    Violation,
+    Warning,
+}
+
+/// Finding of the DCAT-AP validation, `path` being the offending property
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DcatApIssue {
+    pub severity: DcatApIssueSeverity,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subject: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    pub message: String,
+}
+
+/// Entity created from a node of the imported graph
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DcatApImportedEntity {
+    pub source: String,
+    pub id: String,
+}
+
+/// Outcome of an import, nothing is written when there is any violation
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DcatApImportReport {
+    pub imported: bool,
+    pub catalogs: Vec<DcatApImportedEntity>,
+    pub data_services: Vec<DcatApImportedEntity>,
+    pub datasets: Vec<DcatApImportedEntity>,
+    pub distributions: Vec<DcatApImportedEntity>,
+    pub issues: Vec<DcatApIssue>,
+}
+
+impl DcatApImportReport {
+    pub fn has_violations(&self) -> bool {
+        self.issues.iter().any(|issue| issue.severity == DcatApIssueSeverity::Violation)
+    }
+
+    pub(crate) fn violation(
+        &mut self,
+        subject: Option<&Term>,
+        path: Option<&str>,
+        message: impl Into<String>,
+    ) {
+        self.push(DcatApIssueSeverity::Violation, subject, path, message.into());
+    }
+
+    pub(crate) fn warning(
+        &mut self,
+        subject: Option<&Term>,
+        path: Option<&str>,
+        message: impl Into<String>,
+    ) {
+        self.push(DcatApIssueSeverity::Warning, subject, path, message.into());
+    }
+
+    fn push(
+        &mut self,
+        severity: DcatApIssueSeverity,
+        subject: Option<&Term>,
+        path: Option<&str>,
+        message: String,
+    ) {
+        self.issues.push(DcatApIssue {
+            severity,
+            subject: subject
+                .map(|s| s.as_iri().map(str::to_string).unwrap_or_else(|| s.to_string())),
+            path: path.map(|p| p.to_string()),
+            message,
+        });
+    }
+}
+
+#[mockall::automock]
+#[async_trait::async_trait]
+pub trait DcatApTrait: Send + Sync {
+    /// Main catalog with its sub catalogs, datasets, distributions and data services
+    async fn export_catalog(&self) -> anyhow::Result<Graph>;
+    async fn export_dataset(&self, dataset_id: &Urn) -> anyhow::Result<Option<Graph>>;
+    /// Last crawled catalog of a peer
+    async fn export_peer_catalog(&self, peer_id: &String) -> anyhow::Result<Option<Graph>>;
+    async fn import_turtle(&self, input: &str) -> anyhow::Result<DcatApImportReport>;
+}
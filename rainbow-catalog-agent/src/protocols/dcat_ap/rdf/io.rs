@@ -0,0 +1,91 @@
+//! Reading and writing graphs through oxrdfio, which owns the syntaxes.
+//! Only the mapping between its terms and the DCAT-AP graph model lives here.
+
+use crate::protocols::dcat_ap::rdf::vocab::{PREFIXES, XSD_STRING};
+use crate::protocols::dcat_ap::rdf::{Graph, RdfFormat, Term};
+use anyhow::bail;
+use oxrdf::{BlankNode, NamedNode, NamedOrBlankNode};
+use oxrdfio::{RdfParser, RdfSerializer};
+
+impl RdfFormat {
+    fn to_oxrdfio(self) -> oxrdfio::RdfFormat {
+        oxrdfio::RdfFormat::from_media_type(self.content_type())
+            .expect("every RdfFormat content type is known to oxrdfio")
+    }
+}
+
+impl Graph {
+    /// Parses a whole document, relative iris are resolved against `base`
+    pub fn parse(input: &str, format: RdfFormat, base: Option<&str>) -> anyhow::Result<Graph> {
+        let mut parser = RdfParser::from_format(format.to_oxrdfio());
+        if let Some(base) = base {
+            parser = parser.with_base_iri(base)?;
+        }
+        let mut graph = Graph::new();
+        for quad in parser.for_reader(input.as_bytes()) {
+            let quad = quad?;
+            graph.add(
+                &from_oxrdf(quad.subject.into()),
+                quad.predicate.as_str(),
+                from_oxrdf(quad.object),
+            );
+        }
+        Ok(graph)
+    }
+
+    pub fn serialize(&self, format: RdfFormat) -> anyhow::Result<String> {
+        let mut serializer = RdfSerializer::from_format(format.to_oxrdfio());
+        for (prefix, namespace) in PREFIXES {
+            serializer = serializer.with_prefix(prefix, namespace)?;
+        }
+        let mut writer = serializer.for_writer(Vec::new());
+        for triple in self.triples() {
+            writer.serialize_triple(&oxrdf::Triple::new(
+                to_oxrdf_subject(&triple.subject)?,
+                NamedNode::new(&triple.predicate)?,
+                to_oxrdf(&triple.object)?,
+            ))?;
+        }
+        Ok(String::from_utf8(writer.finish()?)?)
+    }
+}
+
+fn from_oxrdf(term: oxrdf::Term) -> Term {
+    match term {
+        oxrdf::Term::NamedNode(node) => Term::Iri(node.into_string()),
+        oxrdf::Term::BlankNode(node) => Term::BlankNode(node.into_string()),
+        oxrdf::Term::Literal(literal) => {
+            let datatype = literal.datatype().as_str();
+            match literal.language() {
+                Some(language) => Term::lang_literal(literal.value(), language),
+                // plain strings keep no datatype, as Term::literal builds them
+                None if datatype == XSD_STRING => Term::literal(literal.value()),
+                None => Term::typed_literal(literal.value(), datatype),
+            }
+        }
+    }
+}
+
+fn to_oxrdf_subject(term: &Term) -> anyhow::Result<NamedOrBlankNode> {
+    Ok(match term {
+        Term::Iri(iri) => NamedNode::new(iri)?.into(),
+        Term::BlankNode(id) => BlankNode::new(id)?.into(),
+        Term::Literal(_) => bail!("Literal {} can't be the subject of a triple", term),
+    })
+}
+
+fn to_oxrdf(term: &Term) -> anyhow::Result<oxrdf::Term> {
+    Ok(match term {
+        Term::Iri(iri) => NamedNode::new(iri)?.into(),
+        Term::BlankNode(id) => BlankNode::new(id)?.into(),
+        Term::Literal(literal) => match (&literal.language, &literal.datatype) {
+            (Some(language), _) => {
+                oxrdf::Literal::new_language_tagged_literal(&literal.value, language)?.into()
+            }
+            (None, Some(datatype)) => {
+                oxrdf::Literal::new_typed_literal(&literal.value, NamedNode::new(datatype)?).into()
+            }
+            (None, None) => oxrdf::Literal::new_simple_literal(&literal.value).into(),
+        },
+    })
+}
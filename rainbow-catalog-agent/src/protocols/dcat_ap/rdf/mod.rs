@@ -0,0 +1,243 @@
+pub(crate) mod io;
+#[cfg(test)]
+mod test_rdf_formats;
+pub(crate) mod vocab;
+
+use std::collections::HashSet;
+use std::fmt::Display;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Term {
+    Iri(String),
+    BlankNode(String),
+    Literal(Literal),
+}
+
+/// Literal without datatype nor language is a xsd:string
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Literal {
+    pub value: String,
+    pub datatype: Option<String>,
+    pub language: Option<String>,
+}
+
+impl Term {
+    pub fn iri(iri: impl Into<String>) -> Self {
+        Term::Iri(iri.into())
+    }
+
+    pub fn literal(value: impl Into<String>) -> Self {
+        Term::Literal(Literal { value: value.into(), datatype: None, language: None })
+    }
+
+    pub fn typed_literal(value: impl Into<String>, datatype: &str) -> Self {
+        Term::Literal(Literal {
+            value: value.into(),
+            datatype: Some(datatype.to_string()),
+            language: None,
+        })
+    }
+
+    pub fn lang_literal(value: impl Into<String>, language: &str) -> Self {
+        Term::Literal(Literal {
+            value: value.into(),
+            datatype: None,
+            language: Some(language.to_lowercase()),
+        })
+    }
+
+    pub fn as_iri(&self) -> Option<&str> {
+        match self {
+            Term::Iri(iri) => Some(iri),
+            _ => None,
+        }
+    }
+
+    /// Lexical value of a literal, or the iri itself
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Term::Iri(iri) => Some(iri),
+            Term::Literal(literal) => Some(&literal.value),
+            Term::BlankNode(_) => None,
+        }
+    }
+
+    pub fn is_resource(&self) -> bool {
+        !matches!(self, Term::Literal(_))
+    }
+}
+
+impl Display for Term {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Term::Iri(iri) => write!(f, "<{}>", iri),
+            Term::BlankNode(id) => write!(f, "_:{}", id),
+            Term::Literal(literal) => write!(f, "\"{}\"", literal.value),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Triple {
+    pub subject: Term,
+    pub predicate: String,
+    pub object: Term,
+}
+
+/// In-memory RDF graph keeping insertion order, so serializations are stable
+#[derive(Debug, Default, Clone)]
+pub struct Graph {
+    triples: Vec<Triple>,
+    seen: HashSet<Triple>,
+    blank_nodes: usize,
+}
+
+impl Graph {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add(&mut self, subject: &Term, predicate: &str, object: Term) {
+        let triple = Triple { subject: subject.clone(), predicate: predicate.to_string(), object };
+        if self.seen.insert(triple.clone()) {
+            self.triples.push(triple);
+        }
+    }
+
+    pub fn add_opt(&mut self, subject: &Term, predicate: &str, object: Option<Term>) {
+        if let Some(object) = object {
+            self.add(subject, predicate, object);
+        }
+    }
+
+    pub fn new_blank_node(&mut self) -> Term {
+        self.blank_nodes += 1;
+        Term::BlankNode(format!("b{}", self.blank_nodes))
+    }
+
+    pub fn triples(&self) -> &[Triple] {
+        &self.triples
+    }
+
+    pub fn len(&self) -> usize {
+        self.triples.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.triples.is_empty()
+    }
+
+    pub fn objects<'a>(&'a self, subject: &'a Term, predicate: &'a str) -> Vec<&'a Term> {
+        self.triples
+            .iter()
+            .filter(|t| &t.subject == subject && t.predicate == predicate)
+            .map(|t| &t.object)
+            .collect()
+    }
+
+    pub fn object<'a>(&'a self, subject: &'a Term, predicate: &'a str) -> Option<&'a Term> {
+        self.triples
+            .iter()
+            .find(|t| &t.subject == subject && t.predicate == predicate)
+            .map(|t| &t.object)
+    }
+
+    /// Subjects pointing to `object` through `predicate`
+    pub fn subjects<'a>(&'a self, predicate: &'a str, object: &'a Term) -> Vec<&'a Term> {
+        self.triples
+            .iter()
+            .filter(|t| t.predicate == predicate && &t.object == object)
+            .map(|t| &t.subject)
+            .collect()
+    }
+
+    pub fn subjects_of_type(&self, rdf_type: &str) -> Vec<&Term> {
+        let mut subjects = vec![];
+        for triple in &self.triples {
+            if triple.predicate == vocab::RDF_TYPE
+                && triple.object.as_iri() == Some(rdf_type)
+                && !subjects.contains(&&triple.subject)
+            {
+                subjects.push(&triple.subject);
+            }
+        }
+        subjects
+    }
+
+    pub fn has_type(&self, subject: &Term, rdf_type: &str) -> bool {
+        self.objects(subject, vocab::RDF_TYPE).iter().any(|t| t.as_iri() == Some(rdf_type))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RdfFormat {
+    Turtle,
+    NTriples,
+    RdfXml,
+    JsonLd,
+}
+
+impl RdfFormat {
+    pub const ALL: [RdfFormat; 4] =
+        [RdfFormat::Turtle, RdfFormat::NTriples, RdfFormat::RdfXml, RdfFormat::JsonLd];
+
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            RdfFormat::Turtle => "text/turtle",
+            RdfFormat::NTriples => "application/n-triples",
+            RdfFormat::RdfXml => "application/rdf+xml",
+            RdfFormat::JsonLd => "application/ld+json",
+        }
+    }
+
+    pub fn from_media_type(media_type: &str) -> Option<Self> {
+        match media_type.trim().to_lowercase().as_str() {
+            "text/turtle" | "application/x-turtle" => Some(RdfFormat::Turtle),
+            "application/n-triples" | "text/plain" => Some(RdfFormat::NTriples),
+            "application/rdf+xml" | "application/xml" | "text/xml" => Some(RdfFormat::RdfXml),
+            "application/ld+json" | "application/json" => Some(RdfFormat::JsonLd),
+            _ => None,
+        }
+    }
+
+    /// Short names accepted in the `format` query param
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "ttl" | "turtle" => Some(RdfFormat::Turtle),
+            "nt" | "ntriples" | "n-triples" => Some(RdfFormat::NTriples),
+            "rdf" | "xml" | "rdfxml" | "rdf-xml" => Some(RdfFormat::RdfXml),
+            "jsonld" | "json-ld" => Some(RdfFormat::JsonLd),
+            _ => RdfFormat::from_media_type(name),
+        }
+    }
+
+    /// Picks the format from an Accept header, honouring q values. Wildcards get Turtle.
+    pub fn negotiate(accept: Option<&str>) -> Option<Self> {
+        let Some(accept) = accept.filter(|a| !a.trim().is_empty()) else {
+            return Some(RdfFormat::Turtle);
+        };
+        let mut candidates = vec![];
+        for (position, range) in accept.split(',').enumerate() {
+            let mut parts = range.split(';');
+            let media_type = parts.next().unwrap_or_default().trim().to_lowercase();
+            let quality = parts
+                .filter_map(|p| p.trim().strip_prefix("q="))
+                .find_map(|q| q.trim().parse::<f32>().ok())
+                .unwrap_or(1.0);
+            if quality <= 0.0 {
+                continue;
+            }
+            let format = match media_type.as_str() {
+                "*/*" | "text/*" => Some(RdfFormat::Turtle),
+                "application/*" => Some(RdfFormat::JsonLd),
+                other => RdfFormat::from_media_type(other),
+            };
+            if let Some(format) = format {
+                candidates.push((quality, position, format));
+            }
+        }
+        // highest quality first, header order breaks ties
+        candidates.sort_by(|a, b| b.0.total_cmp(&a.0).then(a.1.cmp(&b.1)));
+        candidates.first().map(|(_, _, format)| *format)
+    }
+}
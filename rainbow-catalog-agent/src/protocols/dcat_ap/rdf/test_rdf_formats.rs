@@ -0,0 +1,189 @@
+//! Mapping between the graph model and oxrdfio: parsed terms land as the DCAT-AP mapping
+//! expects them, and whatever is written in any format reads back as the same graph
+
+use crate::protocols::dcat_ap::rdf::vocab::{
+    DCAT_DATASET, DCAT_KEYWORD, DCT_TITLE, RDF_TYPE, XSD_BOOLEAN, XSD_DECIMAL, XSD_DOUBLE,
+    XSD_INTEGER,
+};
+use crate::protocols::dcat_ap::rdf::{Graph, RdfFormat, Term};
+use std::collections::{BTreeSet, HashMap};
+
+const TESTS: &str = "http://www.w3.org/2013/TurtleTests/";
+
+fn iri(local: &str) -> Term {
+    Term::iri(format!("{}{}", TESTS, local))
+}
+
+fn parse(input: &str) -> Graph {
+    Graph::parse(input, RdfFormat::Turtle, None).unwrap_or_else(|e| panic!("{}\n{}", e, input))
+}
+
+/// Triples as N-Triples lines, blank node labels replaced by the description of the node,
+/// so graphs parsed at different times compare equal
+fn canonical(graph: &Graph) -> BTreeSet<String> {
+    let mut descriptions: HashMap<&Term, Vec<String>> = HashMap::new();
+    for triple in graph.triples() {
+        if let Term::BlankNode(_) = triple.subject {
+            let object = match &triple.object {
+                Term::BlankNode(_) => "[]".to_string(),
+                object => object.to_string(),
+            };
+            descriptions
+                .entry(&triple.subject)
+                .or_default()
+                .push(format!("{} {}", triple.predicate, object));
+        }
+    }
+    let describe = |term: &Term| match term {
+        Term::BlankNode(_) => {
+            let mut description = descriptions.get(term).cloned().unwrap_or_default();
+            description.sort();
+            format!("[{}]", description.join("; "))
+        }
+        term => format!("{:?}", term),
+    };
+    graph
+        .triples()
+        .iter()
+        .map(|t| format!("{} <{}> {}", describe(&t.subject), t.predicate, describe(&t.object)))
+        .collect()
+}
+
+fn sample_graph() -> Graph {
+    let mut graph = Graph::new();
+    let dataset = Term::iri("urn:dataset:1");
+    graph.add(&dataset, RDF_TYPE, Term::iri(DCAT_DATASET));
+    graph.add(&dataset, DCT_TITLE, Term::lang_literal("Datos \"abiertos\"\n", "es"));
+    graph.add(&dataset, DCAT_KEYWORD, Term::literal("tab\there"));
+    graph.add(&dataset, DCAT_KEYWORD, Term::literal("back\\slash"));
+    graph.add(
+        &dataset,
+        "http://example.org/vocab#size",
+        Term::typed_literal("42", XSD_INTEGER),
+    );
+    let period = graph.new_blank_node();
+    graph.add(&dataset, "http://purl.org/dc/terms/temporal", period.clone());
+    graph.add(
+        &period,
+        "http://www.w3.org/ns/dcat#startDate",
+        Term::literal("2026-01-01"),
+    );
+    graph
+}
+
+#[test]
+fn test_parsed_terms() {
+    let graph = parse(
+        "@prefix : <http://www.w3.org/2013/TurtleTests/> .\n\
+         @prefix xsd: <http://www.w3.org/2001/XMLSchema#> .\n\
+         :s a :C ;\n\
+            :p \"x\"@EN, 1, 1.5, 1e3, true, \"plain\"^^xsd:string ;\n\
+            :q ( :a ) .",
+    );
+    let s = iri("s");
+    assert_eq!(graph.objects(&s, RDF_TYPE), vec![&iri("C")]);
+    assert_eq!(
+        graph.objects(&s, &format!("{}p", TESTS)),
+        vec![
+            &Term::lang_literal("x", "en"),
+            &Term::typed_literal("1", XSD_INTEGER),
+            &Term::typed_literal("1.5", XSD_DECIMAL),
+            &Term::typed_literal("1e3", XSD_DOUBLE),
+            &Term::typed_literal("true", XSD_BOOLEAN),
+            &Term::literal("plain"),
+        ]
+    );
+    let q = format!("{}q", TESTS);
+    let list = graph.object(&s, &q).unwrap();
+    let first = "http://www.w3.org/1999/02/22-rdf-syntax-ns#first";
+    let rest = "http://www.w3.org/1999/02/22-rdf-syntax-ns#rest";
+    assert_eq!(graph.object(list, first), Some(&iri("a")));
+    assert_eq!(
+        graph.object(list, rest),
+        Some(&Term::iri("http://www.w3.org/1999/02/22-rdf-syntax-ns#nil"))
+    );
+}
+
+#[test]
+fn test_blank_node_labels_are_shared_within_a_document() {
+    let graph = parse(
+        "@prefix : <http://www.w3.org/2013/TurtleTests/> .\n\
+         :s :p _:x .\n\
+         :t :p _:x .",
+    );
+    let p = format!("{}p", TESTS);
+    assert_eq!(graph.object(&iri("s"), &p), graph.object(&iri("t"), &p));
+}
+
+#[test]
+fn test_relative_iris_resolve_against_the_base() {
+    let graph = Graph::parse("<s> <p> <o> .", RdfFormat::Turtle, Some(TESTS)).unwrap();
+    assert_eq!(graph.object(&iri("s"), &format!("{}p", TESTS)), Some(&iri("o")));
+}
+
+#[test]
+fn test_invalid_documents_are_errors() {
+    let cases = [
+        // undeclared prefix
+        ":s <http://www.w3.org/2013/TurtleTests/p> \"x\" .",
+        // relative iri without a base
+        "<s> <p> <o> .",
+        // missing object, missing dot
+        "<http://www.w3.org/2013/TurtleTests/s> <http://www.w3.org/2013/TurtleTests/p> .",
+        "<http://www.w3.org/2013/TurtleTests/s> <http://www.w3.org/2013/TurtleTests/p> <http://www.w3.org/2013/TurtleTests/o>",
+        // unterminated string
+        "<http://www.w3.org/2013/TurtleTests/s> <http://www.w3.org/2013/TurtleTests/p> \"abc .",
+    ];
+    for case in cases {
+        let error = Graph::parse(case, RdfFormat::Turtle, None).unwrap_err();
+        assert!(!error.to_string().is_empty(), "{}", case);
+    }
+}
+
+#[test]
+fn test_every_format_round_trips() {
+    let graph = sample_graph();
+    for format in RdfFormat::ALL {
+        let written = graph.serialize(format).unwrap();
+        let read = Graph::parse(&written, format, None)
+            .unwrap_or_else(|e| panic!("{:?}: {}\n{}", format, e, written));
+        assert_eq!(canonical(&read), canonical(&graph), "{:?}\n{}", format, written);
+    }
+}
+
+#[test]
+fn test_turtle_round_trip_of_parsed_documents() {
+    let input = "@prefix : <http://www.w3.org/2013/TurtleTests/> .\n\
+                 :s :p [ :q [ :r \"deep\" ] ], \"a\"@en ;\n\
+                    :list (1 2) .\n\
+                 _:shared :p :o .\n\
+                 :t :p _:shared .\n\
+                 :u :p _:shared .";
+    let graph = parse(input);
+    let read = parse(&graph.serialize(RdfFormat::Turtle).unwrap());
+    assert_eq!(canonical(&read), canonical(&graph));
+}
+
+#[test]
+fn test_turtle_uses_the_known_prefixes() {
+    let written = sample_graph().serialize(RdfFormat::Turtle).unwrap();
+    assert!(
+        written.contains("@prefix dcat: <http://www.w3.org/ns/dcat#> ."),
+        "{}",
+        written
+    );
+    assert!(written.contains("dct:title"), "{}", written);
+}
+
+#[test]
+fn test_invalid_iris_are_not_written() {
+    let mut graph = sample_graph();
+    graph.add(
+        &Term::iri("urn:dataset:1"),
+        "http://example.org/vocab#odd",
+        Term::iri("http://example.org/a b"),
+    );
+    for format in RdfFormat::ALL {
+        assert!(graph.serialize(format).is_err(), "{:?}", format);
+    }
+}
@@ -0,0 +1,80 @@
+/// Prefixes used when writing Turtle and RDF/XML, and known when reading Turtle
+pub const PREFIXES: [(&str, &str); 11] = [
+    ("rdf", "http://www.w3.org/1999/02/22-rdf-syntax-ns#"),
+    ("rdfs", "http://www.w3.org/2000/01/rdf-schema#"),
+    ("xsd", "http://www.w3.org/2001/XMLSchema#"),
+    ("dcat", "http://www.w3.org/ns/dcat#"),
+    ("dct", "http://purl.org/dc/terms/"),
+    ("foaf", "http://xmlns.com/foaf/0.1/"),
+    ("vcard", "http://www.w3.org/2006/vcard/ns#"),
+    ("spdx", "http://spdx.org/rdf/terms#"),
+    ("skos", "http://www.w3.org/2004/02/skos/core#"),
+    ("adms", "http://www.w3.org/ns/adms#"),
+    ("odrl", "http://www.w3.org/ns/odrl/2/"),
+];
+
+pub const RDF_TYPE: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#type";
+
+pub const XSD_STRING: &str = "http://www.w3.org/2001/XMLSchema#string";
+pub const XSD_BOOLEAN: &str = "http://www.w3.org/2001/XMLSchema#boolean";
+pub const XSD_INTEGER: &str = "http://www.w3.org/2001/XMLSchema#integer";
+pub const XSD_DECIMAL: &str = "http://www.w3.org/2001/XMLSchema#decimal";
+pub const XSD_DOUBLE: &str = "http://www.w3.org/2001/XMLSchema#double";
+pub const XSD_NON_NEGATIVE_INTEGER: &str = "http://www.w3.org/2001/XMLSchema#nonNegativeInteger";
+pub const XSD_DATE: &str = "http://www.w3.org/2001/XMLSchema#date";
+pub const XSD_DATE_TIME: &str = "http://www.w3.org/2001/XMLSchema#dateTime";
+pub const XSD_HEX_BINARY: &str = "http://www.w3.org/2001/XMLSchema#hexBinary";
+
+pub const DCAT_CATALOG: &str = "http://www.w3.org/ns/dcat#Catalog";
+pub const DCAT_DATASET: &str = "http://www.w3.org/ns/dcat#Dataset";
+pub const DCAT_DISTRIBUTION: &str = "http://www.w3.org/ns/dcat#Distribution";
+pub const DCAT_DATA_SERVICE: &str = "http://www.w3.org/ns/dcat#DataService";
+pub const DCAT_CATALOG_PROP: &str = "http://www.w3.org/ns/dcat#catalog";
+pub const DCAT_DATASET_PROP: &str = "http://www.w3.org/ns/dcat#dataset";
+pub const DCAT_DISTRIBUTION_PROP: &str = "http://www.w3.org/ns/dcat#distribution";
+pub const DCAT_SERVICE: &str = "http://www.w3.org/ns/dcat#service";
+pub const DCAT_ACCESS_SERVICE: &str = "http://www.w3.org/ns/dcat#accessService";
+pub const DCAT_ACCESS_URL: &str = "http://www.w3.org/ns/dcat#accessURL";
+pub const DCAT_ENDPOINT_URL: &str = "http://www.w3.org/ns/dcat#endpointURL";
+pub const DCAT_ENDPOINT_DESCRIPTION: &str = "http://www.w3.org/ns/dcat#endpointDescription";
+pub const DCAT_SERVES_DATASET: &str = "http://www.w3.org/ns/dcat#servesDataset";
+pub const DCAT_KEYWORD: &str = "http://www.w3.org/ns/dcat#keyword";
+pub const DCAT_THEME: &str = "http://www.w3.org/ns/dcat#theme";
+pub const DCAT_CONTACT_POINT: &str = "http://www.w3.org/ns/dcat#contactPoint";
+pub const DCAT_VERSION: &str = "http://www.w3.org/ns/dcat#version";
+pub const DCAT_PREVIOUS_VERSION: &str = "http://www.w3.org/ns/dcat#previousVersion";
+pub const DCAT_BYTE_SIZE: &str = "http://www.w3.org/ns/dcat#byteSize";
+pub const DCAT_MEDIA_TYPE: &str = "http://www.w3.org/ns/dcat#mediaType";
+pub const DCAT_START_DATE: &str = "http://www.w3.org/ns/dcat#startDate";
+pub const DCAT_END_DATE: &str = "http://www.w3.org/ns/dcat#endDate";
+
+pub const DCT_TITLE: &str = "http://purl.org/dc/terms/title";
+pub const DCT_DESCRIPTION: &str = "http://purl.org/dc/terms/description";
+pub const DCT_IDENTIFIER: &str = "http://purl.org/dc/terms/identifier";
+pub const DCT_ISSUED: &str = "http://purl.org/dc/terms/issued";
+pub const DCT_MODIFIED: &str = "http://purl.org/dc/terms/modified";
+pub const DCT_CONFORMS_TO: &str = "http://purl.org/dc/terms/conformsTo";
+pub const DCT_CREATOR: &str = "http://purl.org/dc/terms/creator";
+pub const DCT_PUBLISHER: &str = "http://purl.org/dc/terms/publisher";
+pub const DCT_LANGUAGE: &str = "http://purl.org/dc/terms/language";
+pub const DCT_LICENSE: &str = "http://purl.org/dc/terms/license";
+pub const DCT_ACCESS_RIGHTS: &str = "http://purl.org/dc/terms/accessRights";
+pub const DCT_SPATIAL: &str = "http://purl.org/dc/terms/spatial";
+pub const DCT_TEMPORAL: &str = "http://purl.org/dc/terms/temporal";
+pub const DCT_FORMAT: &str = "http://purl.org/dc/terms/format";
+pub const DCT_PERIOD_OF_TIME: &str = "http://purl.org/dc/terms/PeriodOfTime";
+
+pub const FOAF_HOMEPAGE: &str = "http://xmlns.com/foaf/0.1/homepage";
+pub const FOAF_AGENT: &str = "http://xmlns.com/foaf/0.1/Agent";
+pub const FOAF_NAME: &str = "http://xmlns.com/foaf/0.1/name";
+
+pub const VCARD_KIND: &str = "http://www.w3.org/2006/vcard/ns#Kind";
+pub const VCARD_FN: &str = "http://www.w3.org/2006/vcard/ns#fn";
+pub const VCARD_HAS_EMAIL: &str = "http://www.w3.org/2006/vcard/ns#hasEmail";
+
+pub const SPDX_CHECKSUM: &str = "http://spdx.org/rdf/terms#checksum";
+pub const SPDX_CHECKSUM_CLASS: &str = "http://spdx.org/rdf/terms#Checksum";
+pub const SPDX_ALGORITHM: &str = "http://spdx.org/rdf/terms#algorithm";
+pub const SPDX_CHECKSUM_VALUE: &str = "http://spdx.org/rdf/terms#checksumValue";
+/// spdx algorithms are individuals such as spdx:checksumAlgorithm_sha256
+pub const SPDX_ALGORITHM_PREFIX: &str = "http://spdx.org/rdf/terms#checksumAlgorithm_";
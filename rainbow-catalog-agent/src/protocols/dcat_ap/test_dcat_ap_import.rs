@@ -0,0 +1,264 @@
+//! Validation report of DCAT-AP imports, and the rollback of imports failing halfway
+
+use crate::data::entities::catalog;
+use crate::entities::catalog_tree::{CatalogTreeSnapshot, MockCatalogTreeEntityTrait};
+use crate::entities::catalogs::{CatalogDto, MockCatalogEntityTrait};
+use crate::entities::data_services::MockDataServiceEntityTrait;
+use crate::entities::datasets::MockDatasetEntityTrait;
+use crate::entities::distributions::MockDistributionEntityTrait;
+use crate::entities::peer_catalogs::MockPeerCatalogTrait;
+use crate::protocols::dcat_ap::dcat_ap::DcatApService;
+use crate::protocols::dcat_ap::import::plan_import;
+use crate::protocols::dcat_ap::rdf::vocab::{
+    DCAT_DISTRIBUTION_PROP, DCT_DESCRIPTION, DCT_FORMAT, DCT_TITLE,
+};
+use crate::protocols::dcat_ap::{DcatApImportReport, DcatApIssueSeverity, DcatApTrait};
+use std::sync::Arc;
+use urn::Urn;
+
+const PREFIXES: &str = "@prefix dcat: <http://www.w3.org/ns/dcat#> .\n\
+                        @prefix dct: <http://purl.org/dc/terms/> .\n\
+                        @prefix xsd: <http://www.w3.org/2001/XMLSchema#> .\n";
+
+const CATALOG: &str = "<urn:catalog:imported> a dcat:Catalog ;\n\
+                           dct:title \"Imported\" ;\n\
+                           dcat:service <urn:data-service:imported> ;\n\
+                           dcat:dataset <urn:dataset:imported> .\n\
+                       <urn:data-service:imported> a dcat:DataService ;\n\
+                           dct:title \"Service\" ;\n\
+                           dct:description \"Serves the imported data\" ;\n\
+                           dcat:endpointURL \"http://provider.example/data\" .\n\
+                       <urn:dataset:imported> a dcat:Dataset ;\n\
+                           dct:title \"Dataset\" ;\n\
+                           dct:description \"Imported data\" ;\n\
+                           dcat:keyword \"a\", \"b\" ;\n\
+                           dcat:distribution <urn:distribution:imported> .\n\
+                       <urn:distribution:imported> a dcat:Distribution ;\n\
+                           dct:title \"Distribution\" ;\n\
+                           dcat:byteSize \"1024\"^^xsd:nonNegativeInteger ;\n\
+                           dcat:accessURL \"http://provider.example/data\" .\n";
+
+fn main_catalog(id: &str) -> CatalogDto {
+    CatalogDto {
+        inner: catalog::Model {
+            id: id.to_string(),
+            foaf_home_page: None,
+            dct_conforms_to: None,
+            dct_creator: None,
+            dct_identifier: None,
+            dct_issued: chrono::Utc::now().into(),
+            dct_modified: None,
+            dct_title: Some("Main".to_string()),
+            dspace_participant_id: None,
+            dspace_main_catalog: true,
+        },
+    }
+}
+
+fn plan(input: &str, tree: &CatalogTreeSnapshot) -> DcatApImportReport {
+    let mut report = DcatApImportReport::default();
+    plan_import(&format!("{}{}", PREFIXES, input), tree, &mut report);
+    report
+}
+
+fn messages(report: &DcatApImportReport, severity: DcatApIssueSeverity) -> Vec<String> {
+    report
+        .issues
+        .iter()
+        .filter(|issue| issue.severity == severity)
+        .map(|issue| issue.message.clone())
+        .collect()
+}
+
+#[test]
+fn test_complete_document_is_planned_with_its_ids() {
+    let tree = CatalogTreeSnapshot::default();
+    let mut report = DcatApImportReport::default();
+    let plan = plan_import(&format!("{}{}", PREFIXES, CATALOG), &tree, &mut report);
+
+    assert!(!report.has_violations(), "{:?}", report.issues);
+    assert_eq!(plan.catalogs.len(), 1);
+    assert_eq!(plan.data_services.len(), 1);
+    assert_eq!(plan.datasets.len(), 1);
+    assert_eq!(plan.distributions.len(), 1);
+
+    let (_, dataset) = &plan.datasets[0];
+    assert_eq!(
+        dataset.id.as_ref().map(Urn::to_string).as_deref(),
+        Some("urn:dataset:imported")
+    );
+    assert_eq!(dataset.catalog_id.to_string(), "urn:catalog:imported");
+    assert_eq!(dataset.dcat_keyword, Some(vec!["a".to_string(), "b".to_string()]));
+    let (_, distribution) = &plan.distributions[0];
+    assert_eq!(distribution.dataset_id.to_string(), "urn:dataset:imported");
+    // the access url resolves to the imported service with that endpoint
+    assert_eq!(distribution.dcat_access_service, "urn:data-service:imported");
+    assert_eq!(distribution.dcat_byte_size, Some(1024));
+}
+
+#[test]
+fn test_syntax_errors_are_a_single_violation() {
+    let report = plan("<urn:catalog:broken> a dcat:Catalog", &CatalogTreeSnapshot::default());
+    let violations = messages(&report, DcatApIssueSeverity::Violation);
+    assert_eq!(violations.len(), 1);
+    assert!(violations[0].starts_with("Turtle syntax error"), "{}", violations[0]);
+}
+
+#[test]
+fn test_document_without_dcat_entities_is_refused() {
+    let report = plan(
+        "<urn:thing:1> dct:title \"Not a catalog\" .",
+        &CatalogTreeSnapshot::default(),
+    );
+    assert_eq!(
+        messages(&report, DcatApIssueSeverity::Violation),
+        vec![
+            "Document holds no dcat:Catalog, dcat:Dataset, dcat:Distribution nor dcat:DataService"
+        ]
+    );
+}
+
+#[test]
+fn test_issues_name_their_subject_and_property() {
+    let report = plan(
+        "<urn:catalog:untitled> a dcat:Catalog .\n\
+         <urn:dataset:orphan-distribution> a dcat:Dataset ;\n\
+             dct:title \"Dataset\" .\n\
+         <urn:distribution:orphan> a dcat:Distribution ;\n\
+             dct:format \"csv\" .",
+        &CatalogTreeSnapshot {
+            main_catalog: Some(main_catalog("urn:catalog:main")),
+            ..Default::default()
+        },
+    );
+
+    let issue = |subject: &str, path: &str| {
+        report
+            .issues
+            .iter()
+            .find(|issue| {
+                issue.subject.as_deref() == Some(subject) && issue.path.as_deref() == Some(path)
+            })
+            .unwrap_or_else(|| panic!("no issue on {} {}: {:?}", subject, path, report.issues))
+    };
+    let untitled = issue("urn:catalog:untitled", DCT_TITLE);
+    assert_eq!(untitled.severity, DcatApIssueSeverity::Violation);
+    assert_eq!(untitled.message, "Mandatory property is missing");
+    let description = issue("urn:dataset:orphan-distribution", DCT_DESCRIPTION);
+    assert_eq!(description.severity, DcatApIssueSeverity::Warning);
+    let orphan = issue("urn:distribution:orphan", DCAT_DISTRIBUTION_PROP);
+    assert_eq!(orphan.severity, DcatApIssueSeverity::Violation);
+    assert_eq!(orphan.message, "Distribution is not listed by any dataset");
+    let format = issue("urn:distribution:orphan", DCT_FORMAT);
+    assert_eq!(format.severity, DcatApIssueSeverity::Warning);
+}
+
+#[test]
+fn test_unlisted_datasets_go_to_the_main_catalog_when_there_is_one() {
+    let dataset = "<urn:dataset:unlisted> a dcat:Dataset ;\n\
+                       dct:title \"Dataset\" ;\n\
+                       dct:description \"Not listed by any catalog\" .";
+
+    let tree = CatalogTreeSnapshot {
+        main_catalog: Some(main_catalog("urn:catalog:main")),
+        ..Default::default()
+    };
+    let mut report = DcatApImportReport::default();
+    let planned = plan_import(&format!("{}{}", PREFIXES, dataset), &tree, &mut report);
+    assert!(!report.has_violations(), "{:?}", report.issues);
+    assert_eq!(planned.datasets[0].1.catalog_id.to_string(), "urn:catalog:main");
+    assert_eq!(
+        messages(&report, DcatApIssueSeverity::Warning),
+        vec!["Not listed by any catalog, added to the main catalog"]
+    );
+
+    let report = plan(dataset, &CatalogTreeSnapshot::default());
+    assert_eq!(
+        messages(&report, DcatApIssueSeverity::Violation),
+        vec!["Not listed by any catalog and there is no main catalog"]
+    );
+}
+
+#[test]
+fn test_identifiers_must_be_new_and_unique() {
+    let tree = CatalogTreeSnapshot {
+        catalogs: vec![main_catalog("urn:catalog:imported")],
+        ..Default::default()
+    };
+    let report = plan(CATALOG, &tree);
+    assert!(messages(&report, DcatApIssueSeverity::Violation)
+        .contains(&"An entity with this identifier already exists".to_string()));
+
+    let report = plan(
+        "<urn:catalog:twice> a dcat:Catalog, dcat:Dataset ;\n\
+             dct:title \"Twice\" .",
+        &CatalogTreeSnapshot::default(),
+    );
+    assert!(messages(&report, DcatApIssueSeverity::Violation)
+        .contains(&"Node is declared with several DCAT classes".to_string()));
+}
+
+#[test]
+fn test_invalid_values_are_violations() {
+    let report = plan(
+        "<urn:catalog:values> a dcat:Catalog ;\n\
+             dct:title \"Values\" ;\n\
+             dcat:dataset <urn:dataset:values> .\n\
+         <urn:dataset:values> a dcat:Dataset ;\n\
+             dct:title \"Dataset\" ;\n\
+             dct:temporal [ dcat:startDate \"2026-12-31\" ; dcat:endDate \"2026-01-01\" ] ;\n\
+             dcat:distribution <urn:distribution:values> .\n\
+         <urn:distribution:values> a dcat:Distribution ;\n\
+             dcat:byteSize \"-1\" .",
+        &CatalogTreeSnapshot::default(),
+    );
+    let violations = messages(&report, DcatApIssueSeverity::Violation);
+    assert!(violations.contains(&"Period of time starts after it ends".to_string()));
+    assert!(violations.contains(&"Byte size -1 is not a non negative integer".to_string()));
+}
+
+#[tokio::test]
+async fn test_import_failing_halfway_is_rolled_back() {
+    let mut catalog_tree_service = MockCatalogTreeEntityTrait::new();
+    catalog_tree_service
+        .expect_get_catalog_tree()
+        .returning(|| Ok(Arc::new(CatalogTreeSnapshot::default())));
+    let mut catalog_service = MockCatalogEntityTrait::new();
+    catalog_service.expect_create_catalog().times(1).returning(|new_catalog| {
+        let id = new_catalog.id.as_ref().unwrap().to_string();
+        let mut catalog = main_catalog(&id);
+        catalog.inner.dspace_main_catalog = false;
+        Ok(catalog)
+    });
+    catalog_service
+        .expect_delete_catalog_by_id()
+        .withf(|id| id.to_string() == "urn:catalog:imported")
+        .times(1)
+        .returning(|_| Ok(()));
+    let mut data_service_service = MockDataServiceEntityTrait::new();
+    data_service_service
+        .expect_create_data_service()
+        .times(1)
+        .returning(|_| Err(anyhow::anyhow!("database is gone")));
+    // nothing after the failure is created, so nothing else is deleted
+    let dataset_service = MockDatasetEntityTrait::new();
+    let distribution_service = MockDistributionEntityTrait::new();
+
+    let service = DcatApService::new(
+        Arc::new(catalog_tree_service),
+        Arc::new(MockPeerCatalogTrait::new()),
+        Arc::new(catalog_service),
+        Arc::new(data_service_service),
+        Arc::new(dataset_service),
+        Arc::new(distribution_service),
+    );
+    let report = service.import_turtle(&format!("{}{}", PREFIXES, CATALOG)).await.unwrap();
+
+    assert!(!report.imported);
+    assert!(report.catalogs.is_empty());
+    assert!(report.data_services.is_empty());
+    let violations = messages(&report, DcatApIssueSeverity::Violation);
+    assert_eq!(violations.len(), 1);
+    assert!(violations[0].contains("rolled back"), "{}", violations[0]);
+    assert!(violations[0].contains("database is gone"), "{}", violations[0]);
+}
@@ -0,0 +1,369 @@
+use crate::data::entities::federated_crawl_state;
+use crate::entities::peer_catalogs::{PeerCatalogTrait, DEFAULT_CRAWL_INTERVAL_SECS};
+use crate::protocols::dsp::crawler::CatalogCrawlerTrait;
+use crate::protocols::dsp::facades::FacadeTrait;
+use crate::protocols::dsp::protocol_types::{
+    CatalogMessageType, CatalogMessageWrapper, CatalogRequestMessageDto,
+};
+use crate::protocols::dsp::types::catalog_definition::{
+    Catalog, CatalogCatalogTypes, CatalogDatasetTypes, CatalogMinimized,
+};
+use crate::protocols::dsp::types::dataset_definition::DatasetMinimized;
+use rainbow_common::dsp_common::context_field::ContextField;
+use rainbow_common::errors::helpers::BadFormat;
+use rainbow_common::errors::{CommonErrors, ErrorLog};
+use rainbow_common::facades::ssi_auth_facade::{
+    send_with_peer_token, MatesFacadeTrait, PeerTokenFacadeTrait,
+};
+use rainbow_common::http_client::{HttpClient, RequestOptions};
+use rainbow_common::well_known::rpc::WellKnownRPCRequest;
+use reqwest::header::{
+    HeaderMap, HeaderName, HeaderValue, ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED, LINK,
+};
+use reqwest::StatusCode;
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use std::sync::Arc;
+use tracing::{debug, error, info};
+
+/// Upper bound of the failure backoff, whatever the crawl interval is
+const MAX_CRAWL_BACKOFF_SECS: i64 = 6 * 60 * 60;
+/// Guards against peers whose `next` links never end
+const MAX_CATALOG_PAGES: usize = 1000;
+
+enum CrawlOutcome {
+    NotModified,
+    Fetched { catalog: Catalog, etag: Option<String>, last_modified: Option<String> },
+}
+
+pub struct CatalogCrawlerService {
+    peer_catalog_service: Arc<dyn PeerCatalogTrait>,
+    mates_facade: Arc<dyn MatesFacadeTrait>,
+    peer_token_facade: Arc<dyn PeerTokenFacadeTrait>,
+    facades: Arc<dyn FacadeTrait>,
+    http_client: Arc<HttpClient>,
+}
+
+impl CatalogCrawlerService {
+    pub fn new(
+        peer_catalog_service: Arc<dyn PeerCatalogTrait>,
+        mates_facade: Arc<dyn MatesFacadeTrait>,
+        peer_token_facade: Arc<dyn PeerTokenFacadeTrait>,
+        facades: Arc<dyn FacadeTrait>,
+        http_client: Arc<HttpClient>,
+    ) -> Self {
+        Self { peer_catalog_service, mates_facade, peer_token_facade, facades, http_client }
+    }
+
+    fn new_crawl_state(participant_id: &str) -> federated_crawl_state::Model {
+        federated_crawl_state::Model {
+            participant_id: participant_id.to_string(),
+            enabled: true,
+            crawl_interval: DEFAULT_CRAWL_INTERVAL_SECS,
+            etag: None,
+            last_modified: None,
+            content_hash: None,
+            last_crawled_at: None,
+            next_crawl_at: chrono::Utc::now().into(),
+            consecutive_failures: 0,
+            last_error: None,
+        }
+    }
+
+    /// `interval * 2^failures`, capped so a long outage is still retried a few times a day
+    pub(crate) fn failure_backoff(
+        crawl_interval: i64,
+        consecutive_failures: i32,
+    ) -> chrono::Duration {
+        let exponent = consecutive_failures.clamp(0, 16) as u32;
+        let backoff = crawl_interval.max(1).saturating_mul(2i64.saturating_pow(exponent));
+        chrono::Duration::seconds(backoff.min(MAX_CRAWL_BACKOFF_SECS.max(crawl_interval)))
+    }
+
+    fn header_string(headers: &HeaderMap, name: HeaderName) -> Option<String> {
+        headers.get(name).and_then(|v| v.to_str().ok()).map(|v| v.to_string())
+    }
+
+    /// Target of the `rel="next"` entry of a Link header, resolved against the current page
+    pub(crate) fn next_page_url(headers: &HeaderMap, current_url: &str) -> Option<String> {
+        let link = headers.get(LINK)?.to_str().ok()?;
+        let target = link.split(',').find_map(|entry| {
+            let mut parts = entry.split(';');
+            let target = parts.next()?.trim().strip_prefix('<')?.strip_suffix('>')?;
+            parts
+                .any(|param| {
+                    let param = param.trim().replace(' ', "");
+                    param == "rel=\"next\"" || param == "rel=next"
+                })
+                .then_some(target)
+        })?;
+        let base = reqwest::Url::parse(current_url).ok()?;
+        base.join(target).ok().map(|url| url.to_string())
+    }
+
+    /// Hex SHA-256 of the canonical JSON of the catalog. It is persisted, so it has to be
+    /// the same across processes and releases.
+    pub(crate) fn content_hash(catalog: &Catalog) -> anyhow::Result<String> {
+        let canonical = Self::canonical_json(serde_json::to_value(catalog)?);
+        Ok(format!("{:x}", Sha256::digest(serde_json::to_vec(&canonical)?)))
+    }
+
+    /// Object keys sorted at every level, whatever order the map keeps them in
+    pub(crate) fn canonical_json(value: Value) -> Value {
+        match value {
+            Value::Object(object) => {
+                let mut entries = object.into_iter().collect::<Vec<_>>();
+                entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+                Value::Object(
+                    entries
+                        .into_iter()
+                        .map(|(key, value)| (key, Self::canonical_json(value)))
+                        .collect(),
+                )
+            }
+            Value::Array(items) => {
+                Value::Array(items.into_iter().map(Self::canonical_json).collect())
+            }
+            value => value,
+        }
+    }
+
+    /// Merges a further page into the catalog, sub catalogs split across pages are joined by id
+    pub(crate) fn append_page(catalog: &mut Catalog, page: Catalog) {
+        Self::merge_datasets(&mut catalog.datasets, page.datasets);
+        let catalogs = std::mem::replace(
+            &mut catalog.catalogs,
+            CatalogCatalogTypes::CatalogMultipleMinimized(vec![]),
+        );
+        let mut sub_catalogs = Self::minimized_catalogs(catalogs);
+        for page_sub_catalog in Self::minimized_catalogs(page.catalogs) {
+            match sub_catalogs.iter_mut().find(|sub_catalog| sub_catalog.id == page_sub_catalog.id)
+            {
+                Some(sub_catalog) => {
+                    Self::merge_datasets(&mut sub_catalog.datasets, page_sub_catalog.datasets)
+                }
+                None => sub_catalogs.push(page_sub_catalog),
+            }
+        }
+        catalog.catalogs = CatalogCatalogTypes::CatalogMultipleMinimized(sub_catalogs);
+    }
+
+    fn merge_datasets(datasets: &mut CatalogDatasetTypes, page_datasets: CatalogDatasetTypes) {
+        let current =
+            std::mem::replace(datasets, CatalogDatasetTypes::DatasetMultipleMinimized(vec![]));
+        *datasets = match (current, page_datasets) {
+            (
+                CatalogDatasetTypes::DatasetMultipleOriginal(mut current),
+                CatalogDatasetTypes::DatasetMultipleOriginal(page_datasets),
+            ) => {
+                current.extend(page_datasets);
+                CatalogDatasetTypes::DatasetMultipleOriginal(current)
+            }
+            (current, page_datasets) => {
+                let mut current = Self::minimized_datasets(current);
+                current.extend(Self::minimized_datasets(page_datasets));
+                CatalogDatasetTypes::DatasetMultipleMinimized(current)
+            }
+        };
+    }
+
+    fn minimized_datasets(datasets: CatalogDatasetTypes) -> Vec<DatasetMinimized> {
+        match datasets {
+            CatalogDatasetTypes::DatasetMultipleMinimized(datasets) => datasets,
+            CatalogDatasetTypes::DatasetMultipleOriginal(datasets) => {
+                datasets.into_iter().map(Into::into).collect()
+            }
+        }
+    }
+
+    fn minimized_catalogs(catalogs: CatalogCatalogTypes) -> Vec<CatalogMinimized> {
+        match catalogs {
+            CatalogCatalogTypes::CatalogMultipleMinimized(catalogs) => catalogs,
+            CatalogCatalogTypes::CatalogMultipleOriginal(catalogs) => {
+                catalogs.into_iter().map(Into::into).collect()
+            }
+        }
+    }
+
+    async fn post_catalog_request(
+        &self,
+        participant_id: &str,
+        url: &str,
+        options: RequestOptions,
+    ) -> anyhow::Result<reqwest::Response> {
+        let body = CatalogMessageWrapper {
+            context: ContextField::default(),
+            _type: CatalogMessageType::CatalogRequestMessage,
+            dto: CatalogRequestMessageDto { filter: serde_json::Value::Null },
+        };
+        // a catalog request has no side effects, so it can be retried like a GET
+        let options = options.idempotent();
+        send_with_peer_token(self.peer_token_facade.as_ref(), participant_id, options, |options| {
+            let body = &body;
+            async move {
+                self.http_client
+                    .post_json_response_with_options::<_, reqwest::Response>(url, body, &options)
+                    .await
+            }
+        })
+        .await
+    }
+
+    async fn fetch_catalog(
+        &self,
+        crawl_state: &federated_crawl_state::Model,
+    ) -> anyhow::Result<CrawlOutcome> {
+        let participant_id = crawl_state.participant_id.clone();
+        let provider_address = self
+            .facades
+            .get_catalog_rpc_path_facade()
+            .await
+            .resolve_dataspace_current_path(&WellKnownRPCRequest {
+                participant_id: participant_id.clone(),
+            })
+            .await?;
+        let first_page_url = format!("{}/catalog/request", provider_address);
+
+        // only the first page carries the validators of the whole catalog
+        let mut options = RequestOptions::new();
+        if let Some(etag) = crawl_state.etag.as_ref().and_then(|v| HeaderValue::from_str(v).ok()) {
+            options = options.with_header(IF_NONE_MATCH, etag);
+        }
+        if let Some(last_modified) =
+            crawl_state.last_modified.as_ref().and_then(|v| HeaderValue::from_str(v).ok())
+        {
+            options = options.with_header(IF_MODIFIED_SINCE, last_modified);
+        }
+        let response =
+            self.post_catalog_request(&participant_id, first_page_url.as_str(), options).await?;
+        if response.status() == StatusCode::NOT_MODIFIED {
+            return Ok(CrawlOutcome::NotModified);
+        }
+        let etag = Self::header_string(response.headers(), ETAG);
+        let last_modified = Self::header_string(response.headers(), LAST_MODIFIED);
+        let mut next_url = Self::next_page_url(response.headers(), first_page_url.as_str());
+        let mut catalog = response.json::<Catalog>().await?;
+
+        let mut pages = 1;
+        while let Some(page_url) = next_url.take() {
+            if pages >= MAX_CATALOG_PAGES {
+                let err = CommonErrors::format_new(
+                    BadFormat::Received,
+                    format!("Catalog of peer {} exceeds {} pages", participant_id, pages).as_str(),
+                );
+                error!("{}", err.log());
+                anyhow::bail!(err);
+            }
+            let response = self
+                .post_catalog_request(&participant_id, page_url.as_str(), RequestOptions::new())
+                .await?;
+            next_url = Self::next_page_url(response.headers(), page_url.as_str());
+            let page = response.json::<Catalog>().await?;
+            Self::append_page(&mut catalog, page);
+            pages += 1;
+        }
+
+        Ok(CrawlOutcome::Fetched { catalog, etag, last_modified })
+    }
+
+    async fn crawl_and_store(
+        &self,
+        crawl_state: &mut federated_crawl_state::Model,
+    ) -> anyhow::Result<()> {
+        match self.fetch_catalog(crawl_state).await? {
+            CrawlOutcome::NotModified => {
+                debug!("Catalog of peer {} not modified", crawl_state.participant_id);
+            }
+            CrawlOutcome::Fetched { catalog, etag, last_modified } => {
+                // peers without validators are compared by content
+                let content_hash = Self::content_hash(&catalog)?;
+                if crawl_state.content_hash.as_deref() != Some(content_hash.as_str()) {
+                    self.peer_catalog_service
+                        .set_peer_catalog(&crawl_state.participant_id, &catalog)
+                        .await?;
+                    info!("Catalog of peer {} updated", crawl_state.participant_id);
+                }
+                crawl_state.etag = etag;
+                crawl_state.last_modified = last_modified;
+                crawl_state.content_hash = Some(content_hash);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl CatalogCrawlerTrait for CatalogCrawlerService {
+    async fn crawl_due_peers(&self) -> anyhow::Result<()> {
+        let mut crawl_states = self
+            .peer_catalog_service
+            .get_crawl_states()
+            .await?
+            .into_iter()
+            .map(|state| state.inner)
+            .collect::<Vec<_>>();
+
+        // every mate gets a schedule the first time it is seen
+        let mates = self.mates_facade.get_all_mates().await?;
+        for mate in mates.iter().filter(|mate| !mate.is_me) {
+            if crawl_states.iter().any(|state| state.participant_id == mate.participant_id) {
+                continue;
+            }
+            let state = self
+                .peer_catalog_service
+                .put_crawl_state(&Self::new_crawl_state(mate.participant_id.as_str()))
+                .await?;
+            crawl_states.push(state.inner);
+        }
+
+        let now = chrono::Utc::now();
+        for crawl_state in crawl_states {
+            if !crawl_state.enabled || crawl_state.next_crawl_at > now {
+                continue;
+            }
+            if !mates.iter().any(|mate| mate.participant_id == crawl_state.participant_id) {
+                continue;
+            }
+            // a failing peer must not stop the rest of the round
+            let participant_id = crawl_state.participant_id.clone();
+            if let Err(err) = self.crawl_peer(crawl_state).await {
+                error!("Crawl state of peer {} could not be saved: {}", participant_id, err);
+            }
+        }
+        Ok(())
+    }
+
+    async fn crawl_peer(
+        &self,
+        mut crawl_state: federated_crawl_state::Model,
+    ) -> anyhow::Result<federated_crawl_state::Model> {
+        let result = self.crawl_and_store(&mut crawl_state).await;
+        let now = chrono::Utc::now();
+        crawl_state.last_crawled_at = Some(now.into());
+        match result {
+            Ok(()) => {
+                crawl_state.consecutive_failures = 0;
+                crawl_state.last_error = None;
+                crawl_state.next_crawl_at =
+                    (now + chrono::Duration::seconds(crawl_state.crawl_interval.max(1))).into();
+            }
+            Err(err) => {
+                error!(
+                    "Crawling catalog of peer {} failed: {}",
+                    crawl_state.participant_id, err
+                );
+                crawl_state.last_error = Some(err.to_string());
+                crawl_state.next_crawl_at = (now
+                    + Self::failure_backoff(
+                        crawl_state.crawl_interval,
+                        crawl_state.consecutive_failures,
+                    ))
+                .into();
+                crawl_state.consecutive_failures =
+                    crawl_state.consecutive_failures.saturating_add(1);
+            }
+        }
+        let crawl_state = self.peer_catalog_service.put_crawl_state(&crawl_state).await?;
+        Ok(crawl_state.inner)
+    }
+}
@@ -0,0 +1,16 @@
+use crate::data::entities::federated_crawl_state;
+
+pub(crate) mod crawler;
+#[cfg(test)]
+mod test_crawler;
+
+#[async_trait::async_trait]
+pub trait CatalogCrawlerTrait: Send + Sync {
+    /// Registers newly known mates and crawls every peer whose schedule is due
+    async fn crawl_due_peers(&self) -> anyhow::Result<()>;
+    /// Pulls the catalog of a single peer and returns its updated crawl state
+    async fn crawl_peer(
+        &self,
+        crawl_state: federated_crawl_state::Model,
+    ) -> anyhow::Result<federated_crawl_state::Model>;
+}
@@ -0,0 +1,381 @@
+//! Crawler scheduling, paging and change detection, against mocked peers and a local
+//! catalog endpoint standing in for a remote one
+
+use crate::data::entities::federated_crawl_state;
+use crate::entities::peer_catalogs::{CrawlStateDto, MockPeerCatalogTrait};
+use crate::protocols::dsp::crawler::crawler::CatalogCrawlerService;
+use crate::protocols::dsp::crawler::CatalogCrawlerTrait;
+use crate::protocols::dsp::facades::well_known_rpc_facade::MockWellKnownRPCFacadeTrait;
+use crate::protocols::dsp::facades::MockFacadeTrait;
+use crate::protocols::dsp::types::catalog_definition::{
+    Catalog, CatalogCatalogTypes, CatalogDatasetTypes,
+};
+use axum::http::{HeaderMap, StatusCode};
+use axum::response::IntoResponse;
+use axum::routing::post;
+use axum::Router;
+use rainbow_common::facades::ssi_auth_facade::{MockMatesFacadeTrait, MockPeerTokenFacadeTrait};
+use rainbow_common::http_client::HttpClient;
+use rainbow_common::mates::Mates;
+use reqwest::header::{HeaderValue, LINK};
+use serde_json::{json, Value};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+const PEER: &str = "did:web:peer.example";
+const ETAG_V1: &str = "\"v1\"";
+
+fn dataset_json(id: &str) -> Value {
+    json!({
+        "@type": "Dataset",
+        "@id": id,
+        "identifier": id,
+        "issued": "2025-01-01T00:00:00",
+        "hasPolicy": [],
+        "distribution": []
+    })
+}
+
+fn catalog_json(id: &str, datasets: &[&str], sub_catalogs: Vec<Value>) -> Value {
+    json!({
+        "@context": "https://w3id.org/dspace/2025/1/context.jsonld",
+        "@type": "Catalog",
+        "@id": id,
+        "identifier": id,
+        "issued": "2025-01-01T00:00:00",
+        "catalog": sub_catalogs,
+        "dataset": datasets.iter().map(|id| dataset_json(id)).collect::<Vec<_>>(),
+        "service": []
+    })
+}
+
+fn sub_catalog_json(id: &str, datasets: &[&str]) -> Value {
+    let mut sub_catalog = catalog_json(id, datasets, vec![]);
+    let sub_catalog_fields = sub_catalog.as_object_mut().unwrap();
+    sub_catalog_fields.remove("@context");
+    sub_catalog_fields.remove("catalog");
+    sub_catalog
+}
+
+fn catalog(datasets: &[&str]) -> Catalog {
+    serde_json::from_value(catalog_json("urn:catalog:peer", datasets, vec![])).unwrap()
+}
+
+fn dataset_ids(datasets: &CatalogDatasetTypes) -> Vec<String> {
+    match datasets {
+        CatalogDatasetTypes::DatasetMultipleMinimized(datasets) => {
+            datasets.iter().map(|dataset| dataset.id.clone()).collect()
+        }
+        CatalogDatasetTypes::DatasetMultipleOriginal(datasets) => {
+            datasets.iter().map(|dataset| dataset.id.clone()).collect()
+        }
+    }
+}
+
+fn mate(participant_id: &str, is_me: bool) -> Mates {
+    let now = chrono::Utc::now().naive_utc();
+    Mates {
+        participant_id: participant_id.to_string(),
+        participant_slug: participant_id.to_string(),
+        participant_type: "Provider".to_string(),
+        base_url: None,
+        token: None,
+        token_actions: None,
+        saved_at: now,
+        last_interaction: now,
+        is_me,
+    }
+}
+
+fn crawl_state(participant_id: &str) -> federated_crawl_state::Model {
+    federated_crawl_state::Model {
+        participant_id: participant_id.to_string(),
+        enabled: true,
+        crawl_interval: 60,
+        etag: None,
+        last_modified: None,
+        content_hash: None,
+        last_crawled_at: None,
+        next_crawl_at: chrono::Utc::now().into(),
+        consecutive_failures: 0,
+        last_error: None,
+    }
+}
+
+/// Peer catalog service that stores whatever crawl state it is given
+fn peer_catalogs() -> MockPeerCatalogTrait {
+    let mut peer_catalogs = MockPeerCatalogTrait::new();
+    peer_catalogs
+        .expect_put_crawl_state()
+        .returning(|state| Ok(CrawlStateDto::from(state.clone())));
+    peer_catalogs
+}
+
+/// Facades resolving every peer to `provider_address`, or failing when there is none
+fn facades(provider_address: Option<String>) -> MockFacadeTrait {
+    let mut rpc_path_facade = MockWellKnownRPCFacadeTrait::new();
+    rpc_path_facade.expect_resolve_dataspace_current_path().returning(move |_| {
+        provider_address.clone().ok_or_else(|| anyhow::anyhow!("peer unreachable"))
+    });
+    let rpc_path_facade = Arc::new(rpc_path_facade);
+    let mut facades = MockFacadeTrait::new();
+    facades.expect_get_catalog_rpc_path_facade().returning(move || rpc_path_facade.clone());
+    facades
+}
+
+fn crawler(
+    peer_catalogs: MockPeerCatalogTrait,
+    mates: MockMatesFacadeTrait,
+    facades: MockFacadeTrait,
+) -> CatalogCrawlerService {
+    let mut peer_tokens = MockPeerTokenFacadeTrait::new();
+    peer_tokens.expect_get_peer_token().returning(|_| Ok("peer-token".to_string()));
+    CatalogCrawlerService::new(
+        Arc::new(peer_catalogs),
+        Arc::new(mates),
+        Arc::new(peer_tokens),
+        Arc::new(facades),
+        Arc::new(HttpClient::new(1, 1)),
+    )
+}
+
+/// Catalog endpoint answering 304 when the consumer already holds `etag`, counting requests
+async fn serve_catalog(body: Value, etag: Option<&'static str>) -> (String, Arc<AtomicUsize>) {
+    let requests = Arc::new(AtomicUsize::new(0));
+    let counter = requests.clone();
+    let app = Router::new().route(
+        "/catalog/request",
+        post(move |headers: HeaderMap| {
+            let body = body.clone();
+            counter.fetch_add(1, Ordering::SeqCst);
+            async move {
+                let mut response_headers = HeaderMap::new();
+                if let Some(etag) = etag {
+                    if headers.get("if-none-match").is_some_and(|value| value == etag) {
+                        return StatusCode::NOT_MODIFIED.into_response();
+                    }
+                    response_headers.insert("etag", HeaderValue::from_static(etag));
+                }
+                (response_headers, axum::Json(body)).into_response()
+            }
+        }),
+    );
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = format!("http://{}", listener.local_addr().unwrap());
+    tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+    (address, requests)
+}
+
+#[test]
+fn test_content_hash_is_a_sha256_of_the_catalog() {
+    let hash = CatalogCrawlerService::content_hash(&catalog(&["urn:dataset:1"])).unwrap();
+    assert_eq!(hash.len(), 64);
+    assert!(hash.chars().all(|c| c.is_ascii_hexdigit() && !c.is_ascii_uppercase()));
+    assert_eq!(
+        hash,
+        CatalogCrawlerService::content_hash(&catalog(&["urn:dataset:1"])).unwrap()
+    );
+    assert_ne!(
+        hash,
+        CatalogCrawlerService::content_hash(&catalog(&["urn:dataset:1", "urn:dataset:2"])).unwrap()
+    );
+}
+
+#[test]
+fn test_canonical_json_sorts_keys_at_every_level() {
+    let canonical = CatalogCrawlerService::canonical_json(json!({
+        "b": [{ "z": 1, "y": 2 }],
+        "a": { "d": 3, "c": 4 }
+    }));
+    assert_eq!(
+        serde_json::to_string(&canonical).unwrap(),
+        r#"{"a":{"c":4,"d":3},"b":[{"y":2,"z":1}]}"#
+    );
+}
+
+#[test]
+fn test_failure_backoff_doubles_up_to_the_cap() {
+    let backoff = |failures| CatalogCrawlerService::failure_backoff(60, failures).num_seconds();
+    assert_eq!(backoff(0), 60);
+    assert_eq!(backoff(1), 120);
+    assert_eq!(backoff(3), 480);
+    assert_eq!(backoff(30), 6 * 60 * 60);
+    // an interval longer than the cap is never shortened
+    assert_eq!(CatalogCrawlerService::failure_backoff(86400, 5).num_seconds(), 86400);
+}
+
+#[test]
+fn test_next_page_url_follows_the_next_link_only() {
+    let mut headers = reqwest::header::HeaderMap::new();
+    headers.insert(
+        LINK,
+        HeaderValue::from_static(concat!(
+            "</catalog/request?page=1>; rel=\"prev\", ",
+            "</catalog/request?page=3>; rel=\"next\""
+        )),
+    );
+    assert_eq!(
+        CatalogCrawlerService::next_page_url(&headers, "http://peer.example/api/catalog/request"),
+        Some("http://peer.example/catalog/request?page=3".to_string())
+    );
+
+    headers.insert(
+        LINK,
+        HeaderValue::from_static("<http://other.example/page/2>; rel=next"),
+    );
+    assert_eq!(
+        CatalogCrawlerService::next_page_url(&headers, "http://peer.example/catalog/request"),
+        Some("http://other.example/page/2".to_string())
+    );
+
+    headers.insert(
+        LINK,
+        HeaderValue::from_static("</catalog/request?page=1>; rel=\"prev\""),
+    );
+    assert_eq!(
+        CatalogCrawlerService::next_page_url(&headers, "http://peer.example/catalog/request"),
+        None
+    );
+    assert_eq!(
+        CatalogCrawlerService::next_page_url(
+            &reqwest::header::HeaderMap::new(),
+            "http://peer.example/catalog/request"
+        ),
+        None
+    );
+}
+
+#[test]
+fn test_pages_are_merged_by_sub_catalog() {
+    let mut first: Catalog = serde_json::from_value(catalog_json(
+        "urn:catalog:peer",
+        &["urn:dataset:1"],
+        vec![sub_catalog_json("urn:catalog:sub", &["urn:dataset:sub-1"])],
+    ))
+    .unwrap();
+    let second: Catalog = serde_json::from_value(catalog_json(
+        "urn:catalog:peer",
+        &["urn:dataset:2"],
+        vec![
+            sub_catalog_json("urn:catalog:sub", &["urn:dataset:sub-2"]),
+            sub_catalog_json("urn:catalog:other", &["urn:dataset:other-1"]),
+        ],
+    ))
+    .unwrap();
+
+    CatalogCrawlerService::append_page(&mut first, second);
+
+    assert_eq!(dataset_ids(&first.datasets), vec!["urn:dataset:1", "urn:dataset:2"]);
+    let CatalogCatalogTypes::CatalogMultipleMinimized(sub_catalogs) = &first.catalogs else {
+        panic!("sub catalogs are kept minimized");
+    };
+    assert_eq!(sub_catalogs.len(), 2);
+    assert_eq!(sub_catalogs[0].id.to_string(), "urn:catalog:sub");
+    assert_eq!(
+        dataset_ids(&sub_catalogs[0].datasets),
+        vec!["urn:dataset:sub-1", "urn:dataset:sub-2"]
+    );
+    assert_eq!(sub_catalogs[1].id.to_string(), "urn:catalog:other");
+}
+
+#[tokio::test]
+async fn test_failed_crawl_backs_off_and_keeps_the_error() {
+    let crawler = crawler(peer_catalogs(), MockMatesFacadeTrait::new(), facades(None));
+
+    let before = chrono::Utc::now();
+    let state = crawler.crawl_peer(crawl_state(PEER)).await.unwrap();
+    assert_eq!(state.consecutive_failures, 1);
+    assert!(state.last_error.as_deref().is_some_and(|e| e.contains("peer unreachable")));
+    assert!(state.last_crawled_at.is_some());
+    assert!(state.next_crawl_at >= before + chrono::Duration::seconds(60));
+
+    // the second failure in a row waits twice as long
+    let before = chrono::Utc::now();
+    let state = crawler.crawl_peer(state).await.unwrap();
+    assert_eq!(state.consecutive_failures, 2);
+    assert!(state.next_crawl_at >= before + chrono::Duration::seconds(120));
+    assert!(state.next_crawl_at < before + chrono::Duration::seconds(180));
+}
+
+#[tokio::test]
+async fn test_unchanged_catalog_is_not_stored_again() {
+    let (address, requests) =
+        serve_catalog(catalog_json("urn:catalog:peer", &["urn:dataset:1"], vec![]), None).await;
+    let mut peer_catalogs = peer_catalogs();
+    peer_catalogs.expect_set_peer_catalog().times(1).returning(|_, _| Ok(()));
+    let crawler = crawler(peer_catalogs, MockMatesFacadeTrait::new(), facades(Some(address)));
+
+    let state = crawler.crawl_peer(crawl_state(PEER)).await.unwrap();
+    assert_eq!(state.consecutive_failures, 0);
+    assert!(state.last_error.is_none());
+    assert_eq!(
+        state.content_hash,
+        Some(CatalogCrawlerService::content_hash(&catalog(&["urn:dataset:1"])).unwrap())
+    );
+
+    // a peer without validators is compared by content
+    let state = crawler.crawl_peer(state).await.unwrap();
+    assert_eq!(state.consecutive_failures, 0);
+    assert_eq!(requests.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn test_not_modified_catalog_keeps_its_validators() {
+    let (address, requests) = serve_catalog(
+        catalog_json("urn:catalog:peer", &["urn:dataset:1"], vec![]),
+        Some(ETAG_V1),
+    )
+    .await;
+    let mut peer_catalogs = peer_catalogs();
+    peer_catalogs.expect_set_peer_catalog().times(1).returning(|_, _| Ok(()));
+    let crawler = crawler(peer_catalogs, MockMatesFacadeTrait::new(), facades(Some(address)));
+
+    let state = crawler.crawl_peer(crawl_state(PEER)).await.unwrap();
+    assert_eq!(state.etag.as_deref(), Some(ETAG_V1));
+    let content_hash = state.content_hash.clone();
+
+    let state = crawler.crawl_peer(state).await.unwrap();
+    assert_eq!(state.consecutive_failures, 0);
+    assert_eq!(state.etag.as_deref(), Some(ETAG_V1));
+    assert_eq!(state.content_hash, content_hash);
+    assert_eq!(requests.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn test_round_schedules_new_mates_and_skips_the_rest() {
+    let mut mates = MockMatesFacadeTrait::new();
+    mates.expect_get_all_mates().returning(|| {
+        Ok(vec![
+            mate("did:web:me.example", true),
+            mate(PEER, false),
+            mate("did:web:disabled.example", false),
+        ])
+    });
+    let mut peer_catalogs = MockPeerCatalogTrait::new();
+    peer_catalogs.expect_get_crawl_states().returning(|| {
+        let disabled = federated_crawl_state::Model {
+            enabled: false,
+            ..crawl_state("did:web:disabled.example")
+        };
+        // no longer a mate, so never crawled
+        let gone = crawl_state("did:web:gone.example");
+        Ok(vec![disabled.into(), gone.into()])
+    });
+    // registered once, then saved again after its first crawl
+    peer_catalogs
+        .expect_put_crawl_state()
+        .withf(|state| state.participant_id == PEER)
+        .times(2)
+        .returning(|state| Ok(CrawlStateDto::from(state.clone())));
+    let mut rpc_path_facade = MockWellKnownRPCFacadeTrait::new();
+    rpc_path_facade
+        .expect_resolve_dataspace_current_path()
+        .withf(|request| request.participant_id == PEER)
+        .times(1)
+        .returning(|_| Err(anyhow::anyhow!("peer unreachable")));
+    let rpc_path_facade = Arc::new(rpc_path_facade);
+    let mut facades = MockFacadeTrait::new();
+    facades.expect_get_catalog_rpc_path_facade().returning(move || rpc_path_facade.clone());
+
+    crawler(peer_catalogs, mates, facades).crawl_due_peers().await.unwrap();
+}
@@ -21,6 +21,7 @@ use std::sync::Arc;
 
 pub(crate) mod well_known_rpc_facade;
 
+#[mockall::automock]
 #[async_trait::async_trait]
 pub trait FacadeTrait: Send + Sync {
     async fn get_catalog_rpc_path_facade(&self) -> Arc<dyn WellKnownRPCFacadeTrait>;
@@ -5,6 +5,7 @@ use urn::Urn;
 
 pub(crate) mod well_known_rpc_facade;
 
+#[mockall::automock]
 #[async_trait::async_trait]
 #[allow(unused)]
 pub trait WellKnownRPCFacadeTrait: Send + Sync {
@@ -19,3 +19,5 @@
 
 pub(crate) mod protocol;
 pub(crate) mod rpc;
+#[cfg(test)]
+mod test_catalog_pagination;
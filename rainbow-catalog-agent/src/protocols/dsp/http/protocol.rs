@@ -17,12 +17,19 @@
  *
  */
 
+use crate::protocols::dsp::orchestrator::protocol::catalog_filter::{
+    CatalogPage, CatalogPagination,
+};
 use crate::protocols::dsp::orchestrator::OrchestratorTrait;
 use crate::protocols::dsp::protocol_types::{
     CatalogMessageWrapper, CatalogRequestMessageDto, DatasetRequestMessage,
 };
 use axum::{
-    extract::{rejection::JsonRejection, FromRef, Path, State},
+    extract::{
+        rejection::{JsonRejection, QueryRejection},
+        FromRef, OriginalUri, Path, Query, State,
+    },
+    http::header::LINK,
     response::IntoResponse,
     routing::get,
     routing::post,
@@ -34,6 +41,8 @@ use std::sync::Arc;
 #[derive(Clone)]
 pub struct DspRouter {
     orchestrator: Arc<dyn OrchestratorTrait>,
+    /// Public base url of the agent, pagination links are absolute
+    base_url: String,
 }
 
 impl FromRef<DspRouter> for Arc<dyn OrchestratorTrait> {
@@ -43,8 +52,8 @@ impl FromRef<DspRouter> for Arc<dyn OrchestratorTrait> {
 }
 
 impl DspRouter {
-    pub fn new(service: Arc<dyn OrchestratorTrait>) -> Self {
-        Self { orchestrator: service }
+    pub fn new(service: Arc<dyn OrchestratorTrait>, base_url: String) -> Self {
+        Self { orchestrator: service, base_url }
     }
 
     pub fn router(self) -> Router {
@@ -56,18 +65,57 @@ impl DspRouter {
 
     async fn handle_catalog_request(
         State(state): State<DspRouter>,
+        OriginalUri(uri): OriginalUri,
+        pagination: Result<Query<CatalogPagination>, QueryRejection>,
         input: Result<Json<CatalogMessageWrapper<CatalogRequestMessageDto>>, JsonRejection>,
     ) -> impl IntoResponse {
+        let pagination = match pagination {
+            Ok(pagination) => pagination.0,
+            Err(e) => return (StatusCode::BAD_REQUEST, e.body_text()).into_response(),
+        };
         let input = match input {
             Ok(input) => input.0,
             Err(e) => return (StatusCode::BAD_REQUEST, e.body_text()).into_response(),
         };
-        match state.orchestrator.get_protocol_service().on_catalog_request(&input).await {
-            Ok(catalog) => (StatusCode::OK, Json(catalog)).into_response(),
+        match state
+            .orchestrator
+            .get_protocol_service()
+            .on_catalog_request(&input, &pagination)
+            .await
+        {
+            Ok((catalog, page)) => {
+                match Self::pagination_links(&state.base_url, uri.path(), &page) {
+                    Some(links) => (StatusCode::OK, [(LINK, links)], Json(catalog)).into_response(),
+                    None => (StatusCode::OK, Json(catalog)).into_response(),
+                }
+            }
             Err(e) => (StatusCode::BAD_REQUEST, e.to_string()).into_response(),
         }
     }
 
+    pub(crate) fn pagination_links(
+        base_url: &str,
+        path: &str,
+        page: &CatalogPage,
+    ) -> Option<String> {
+        let base_url = base_url.trim_end_matches('/');
+        let link = |pagination: CatalogPagination, rel: &str| {
+            format!(
+                "<{}{}?offset={}&limit={}>; rel=\"{}\"",
+                base_url,
+                path,
+                pagination.offset,
+                pagination.limit(),
+                rel
+            )
+        };
+        let links = [page.next().map(|p| link(p, "next")), page.prev().map(|p| link(p, "prev"))]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>();
+        (!links.is_empty()).then(|| links.join(", "))
+    }
+
     async fn handle_dataset_request(
         State(state): State<DspRouter>,
         Path(id): Path<String>,
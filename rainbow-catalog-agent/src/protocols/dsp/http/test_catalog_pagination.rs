@@ -0,0 +1,61 @@
+//! Pagination query params of the catalog request and the Link header built from them
+
+use crate::protocols::dsp::http::protocol::DspRouter;
+use crate::protocols::dsp::orchestrator::protocol::catalog_filter::{
+    CatalogPagination, DEFAULT_CATALOG_PAGE_LIMIT, MAX_CATALOG_PAGE_LIMIT,
+};
+use axum::extract::Query;
+use axum::http::Uri;
+
+const BASE_URL: &str = "http://127.0.0.1:1200";
+const PATH: &str = "/api/v1/catalog/request";
+
+/// Read the way the catalog request handler reads its query
+fn pagination(query: &str) -> CatalogPagination {
+    let uri = format!("{}?{}", PATH, query).parse::<Uri>().unwrap();
+    Query::<CatalogPagination>::try_from_uri(&uri).unwrap().0
+}
+
+#[test]
+fn request_without_limit_or_offset_is_not_paged() {
+    let page = pagination("").page(120);
+    assert_eq!((page.offset, page.limit), (0, 120));
+    assert!((0..120).all(|position| page.contains(position)));
+    assert!(page.next().is_none());
+    assert_eq!(DspRouter::pagination_links(BASE_URL, PATH, &page), None);
+}
+
+#[test]
+fn offset_alone_pages_with_the_default_limit() {
+    let page = pagination("offset=10").page(120);
+    assert_eq!((page.offset, page.limit), (10, DEFAULT_CATALOG_PAGE_LIMIT));
+    assert_eq!(pagination("limit=0").page(120).limit, 1);
+    assert_eq!(
+        pagination("limit=100000").page(1_000_000).limit,
+        MAX_CATALOG_PAGE_LIMIT
+    );
+}
+
+#[test]
+fn links_are_absolute() {
+    let first = pagination("limit=50").page(120);
+    assert_eq!(
+        DspRouter::pagination_links(BASE_URL, PATH, &first).as_deref(),
+        Some("<http://127.0.0.1:1200/api/v1/catalog/request?offset=50&limit=50>; rel=\"next\"")
+    );
+
+    let middle = pagination("offset=50&limit=50").page(120);
+    assert_eq!(
+        DspRouter::pagination_links(&format!("{}/", BASE_URL), PATH, &middle).as_deref(),
+        Some(
+            "<http://127.0.0.1:1200/api/v1/catalog/request?offset=100&limit=50>; rel=\"next\", \
+             <http://127.0.0.1:1200/api/v1/catalog/request?offset=0&limit=50>; rel=\"prev\""
+        )
+    );
+
+    let last = pagination("offset=100&limit=50").page(120);
+    assert_eq!(
+        DspRouter::pagination_links(BASE_URL, PATH, &last).as_deref(),
+        Some("<http://127.0.0.1:1200/api/v1/catalog/request?offset=50&limit=50>; rel=\"prev\"")
+    );
+}
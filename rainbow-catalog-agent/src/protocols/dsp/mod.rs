@@ -1,8 +1,4 @@
-use crate::entities::catalogs::CatalogEntityTrait;
-use crate::entities::data_services::DataServiceEntityTrait;
-use crate::entities::datasets::DatasetEntityTrait;
-use crate::entities::distributions::DistributionEntityTrait;
-use crate::entities::odrl_policies::OdrlPolicyEntityTrait;
+use crate::entities::catalog_tree::CatalogTreeEntityTrait;
 use crate::entities::peer_catalogs::PeerCatalogTrait;
 use crate::protocols::dsp::facades::well_known_rpc_facade::well_known_rpc_facade::WellKnownRPCFacadeForDSProtocol;
 use crate::protocols::dsp::facades::FacadeService;
@@ -19,11 +15,19 @@ use crate::protocols::dsp::validator::validators::validate_payload::ValidatePayl
 use crate::protocols::dsp::validator::validators::validation_helpers::ValidationHelperService;
 use crate::protocols::protocol::ProtocolPluginTrait;
 use axum::Router;
+use rainbow_common::auth::ssi::{with_ssi_auth, SsiAuthLayer, DEFAULT_PEER_CACHE_TTL};
 use rainbow_common::config::services::CatalogConfig;
+use rainbow_common::config::traits::CommonConfigTrait;
+use rainbow_common::facades::ssi_auth_facade::mates_facade::MatesFacadeService;
+use rainbow_common::facades::ssi_auth_facade::peer_token_facade::PeerTokenFacadeService;
+use rainbow_common::facades::ssi_auth_facade::ssi_auth_facade::SSIAuthFacadeService;
 use rainbow_common::facades::ssi_auth_facade::MatesFacadeTrait;
 use rainbow_common::http_client::HttpClient;
 use std::sync::Arc;
+use ymir::config::traits::HostsConfigTrait;
+use ymir::config::types::HostType;
 
+pub(crate) mod crawler;
 mod errors;
 pub(crate) mod facades;
 pub(crate) mod http;
@@ -33,11 +37,7 @@ pub(crate) mod types;
 pub(crate) mod validator;
 
 pub struct CatalogDSP {
-    pub catalog_entities_service: Arc<dyn CatalogEntityTrait>,
-    pub data_service_entities_service: Arc<dyn DataServiceEntityTrait>,
-    pub dataset_entities_service: Arc<dyn DatasetEntityTrait>,
-    pub odrl_policies_service: Arc<dyn OdrlPolicyEntityTrait>,
-    pub distributions_entity_service: Arc<dyn DistributionEntityTrait>,
+    pub catalog_tree_entities_service: Arc<dyn CatalogTreeEntityTrait>,
     pub peer_catalog_entity_service: Arc<dyn PeerCatalogTrait>,
     pub mates_facade: Arc<dyn MatesFacadeTrait>,
     config: Arc<CatalogConfig>,
@@ -45,21 +45,13 @@ pub struct CatalogDSP {
 
 impl CatalogDSP {
     pub fn new(
-        catalog_entities_service: Arc<dyn CatalogEntityTrait>,
-        data_service_entities_service: Arc<dyn DataServiceEntityTrait>,
-        dataset_entities_service: Arc<dyn DatasetEntityTrait>,
-        odrl_policies_service: Arc<dyn OdrlPolicyEntityTrait>,
-        distributions_entity_service: Arc<dyn DistributionEntityTrait>,
+        catalog_tree_entities_service: Arc<dyn CatalogTreeEntityTrait>,
         peer_catalog_entity_service: Arc<dyn PeerCatalogTrait>,
         mates_facade: Arc<dyn MatesFacadeTrait>,
         config: Arc<CatalogConfig>,
     ) -> Self {
         Self {
-            catalog_entities_service,
-            data_service_entities_service,
-            dataset_entities_service,
-            odrl_policies_service,
-            distributions_entity_service,
+            catalog_tree_entities_service,
             peer_catalog_entity_service,
             mates_facade,
             config: config,
@@ -104,13 +96,19 @@ impl ProtocolPluginTrait for CatalogDSP {
         ));
         let facades = Arc::new(FacadeService::new(catalog_well_known_rpc_facade.clone()));
 
+        // outbound tokens per peer, straight from the SSI auth service
+        let ssi_auth_config = Arc::new(self.config.ssi_auth());
+        let mates_facade =
+            Arc::new(MatesFacadeService::new(ssi_auth_config.clone(), http_client.clone()));
+        let peer_token_facade = Arc::new(PeerTokenFacadeService::new(
+            ssi_auth_config.clone(),
+            http_client.clone(),
+            mates_facade,
+        ));
+
         // persistence
         let dsp_persistence = Arc::new(OrchestrationPersistenceForProtocol::new(
-            self.catalog_entities_service.clone(),
-            self.data_service_entities_service.clone(),
-            self.dataset_entities_service.clone(),
-            self.odrl_policies_service.clone(),
-            self.distributions_entity_service.clone(),
+            self.catalog_tree_entities_service.clone(),
         ));
         let rpc_persistence = Arc::new(OrchestrationPersistenceForProtocolForRPC::new(
             self.peer_catalog_entity_service.clone(),
@@ -127,17 +125,33 @@ impl ProtocolPluginTrait for CatalogDSP {
             http_client.clone(),
             facades.clone(),
             rpc_persistence.clone(),
+            peer_token_facade,
         ));
         let orchestrator_service = Arc::new(OrchestratorService::new(
             dsp_orchestator.clone(),
             rpc_orchestrator.clone(),
         ));
 
+        // every DSP message must come from a peer known to the SSI service
+        let ssi_auth_facade =
+            Arc::new(SSIAuthFacadeService::new(ssi_auth_config, http_client.clone()));
+        let ssi_auth_layer =
+            SsiAuthLayer::new(ssi_auth_facade, DEFAULT_PEER_CACHE_TTL, "CatalogError");
+
         // router
-        let dsp_router = DspRouter::new(orchestrator_service.clone());
+        let dsp_router = DspRouter::new(
+            orchestrator_service.clone(),
+            self.config.common().get_host(HostType::Http),
+        );
         let rpc_router = RpcRouter::new(orchestrator_service.clone());
 
-        Ok(Router::new().merge(dsp_router.router()).merge(rpc_router.router()))
+        let dsp_router = with_ssi_auth(
+            dsp_router.router(),
+            ssi_auth_layer,
+            self.config.common().verify_ssi_tokens(),
+        );
+
+        Ok(Router::new().merge(dsp_router).merge(rpc_router.router()))
     }
 
     fn build_grpc_router(&self) -> anyhow::Result<Option<Router>> {
@@ -0,0 +1,211 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use crate::entities::catalogs::CatalogDto;
+use crate::entities::datasets::DatasetDto;
+use crate::entities::distributions::DistributionDto;
+use anyhow::bail;
+use rainbow_common::dcat_formats::DctFormats;
+use rainbow_common::errors::helpers::BadFormat;
+use rainbow_common::errors::{CommonErrors, ErrorLog};
+use serde::Deserialize;
+use serde_json::{Map, Value};
+use tracing::error;
+
+pub const DEFAULT_CATALOG_PAGE_LIMIT: u64 = 50;
+pub const MAX_CATALOG_PAGE_LIMIT: u64 = 500;
+
+/// Supported keys of the `filter` in a CatalogRequestMessage, all of them optional
+/// and combined with AND. Keys are accepted with or without their vocabulary prefix.
+#[derive(Debug, Clone, Default)]
+pub struct CatalogFilter {
+    pub keyword: Option<String>,
+    pub title: Option<String>,
+    pub format: Option<DctFormats>,
+    pub conforms_to: Option<String>,
+    pub participant_id: Option<String>,
+}
+
+impl CatalogFilter {
+    pub fn from_value(filter: &Value) -> anyhow::Result<Self> {
+        let mut parsed = Self::default();
+        match filter {
+            Value::Null => {}
+            Value::Object(fields) => parsed.read_fields(fields)?,
+            // a list of filters is the same as one filter with all their fields
+            Value::Array(filters) => {
+                for filter in filters {
+                    match filter {
+                        Value::Object(fields) => parsed.read_fields(fields)?,
+                        _ => bail!(Self::format_error("Every catalog filter must be an object")),
+                    }
+                }
+            }
+            _ => bail!(Self::format_error(
+                "Catalog filter must be an object or a list of objects"
+            )),
+        }
+        Ok(parsed)
+    }
+
+    fn read_fields(&mut self, fields: &Map<String, Value>) -> anyhow::Result<()> {
+        for (key, value) in fields {
+            // json-ld keywords such as @type or @context carry no filtering
+            if key.starts_with('@') {
+                continue;
+            }
+            let Some(value) = value.as_str().map(|v| v.trim()).filter(|v| !v.is_empty()) else {
+                bail!(Self::format_error(
+                    format!("Catalog filter {} must be a string", key).as_str()
+                ));
+            };
+            match key.as_str() {
+                "keyword" | "dcat:keyword" => self.keyword = Some(value.to_lowercase()),
+                "title" | "dct:title" => self.title = Some(value.to_lowercase()),
+                "format" | "dct:format" => {
+                    self.format = Some(value.parse::<DctFormats>().map_err(|_| {
+                        Self::format_error(
+                            format!("Catalog filter format {} is not supported", value).as_str(),
+                        )
+                    })?)
+                }
+                "conformsTo" | "dct:conformsTo" => self.conforms_to = Some(value.to_string()),
+                "participantId" | "dspace:participantId" => {
+                    self.participant_id = Some(value.to_string())
+                }
+                _ => bail!(Self::format_error(
+                    format!("Catalog filter {} is not supported", key).as_str()
+                )),
+            }
+        }
+        Ok(())
+    }
+
+    fn format_error(cause: &str) -> CommonErrors {
+        let err = CommonErrors::format_new(BadFormat::Received, cause);
+        error!("{}", err.log());
+        err
+    }
+
+    pub fn matches_catalog(&self, catalog: &CatalogDto) -> bool {
+        match self.participant_id.as_ref() {
+            Some(participant_id) => {
+                catalog.inner.dspace_participant_id.as_ref() == Some(participant_id)
+            }
+            None => true,
+        }
+    }
+
+    /// Everything but the format, which needs the dataset distributions
+    pub fn matches_dataset(&self, dataset: &DatasetDto) -> bool {
+        let contains = |field: &Option<String>, needle: &str| {
+            field.as_ref().map(|f| f.to_lowercase().contains(needle)).unwrap_or(false)
+        };
+        if let Some(title) = self.title.as_ref() {
+            if !contains(&dataset.inner.dct_title, title) {
+                return false;
+            }
+        }
+        if let Some(keyword) = self.keyword.as_ref() {
+            let found = contains(&dataset.inner.dct_title, keyword)
+                || contains(&dataset.inner.dct_description, keyword)
+                || contains(&dataset.inner.dct_identifier, keyword)
+                || dataset.inner.id.to_lowercase().contains(keyword)
+                || dataset.inner.keywords().iter().any(|k| k.to_lowercase().contains(keyword));
+            if !found {
+                return false;
+            }
+        }
+        if let Some(conforms_to) = self.conforms_to.as_ref() {
+            if dataset.inner.dct_conforms_to.as_ref() != Some(conforms_to) {
+                return false;
+            }
+        }
+        true
+    }
+
+    pub fn matches_distributions(&self, distributions: &[DistributionDto]) -> bool {
+        let Some(format) = self.format.as_ref() else {
+            return true;
+        };
+        distributions.iter().any(|distribution| {
+            distribution
+                .inner
+                .dct_format
+                .as_ref()
+                .and_then(|f| f.parse::<DctFormats>().ok())
+                .map(|f| f.protocol == format.protocol && f.action == format.action)
+                .unwrap_or(false)
+        })
+    }
+}
+
+/// Window over the datasets of a catalog, read from the `offset` and `limit` query params.
+/// Without either of them the whole catalog is returned, as peers that do not follow
+/// `Link` headers expect.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct CatalogPagination {
+    #[serde(default)]
+    pub offset: u64,
+    #[serde(default)]
+    pub limit: Option<u64>,
+}
+
+impl CatalogPagination {
+    pub fn is_paged(&self) -> bool {
+        self.offset > 0 || self.limit.is_some()
+    }
+
+    /// An offset alone pages with the default limit
+    pub fn limit(&self) -> u64 {
+        self.limit.unwrap_or(DEFAULT_CATALOG_PAGE_LIMIT).clamp(1, MAX_CATALOG_PAGE_LIMIT)
+    }
+
+    pub fn page(&self, total: u64) -> CatalogPage {
+        match self.is_paged() {
+            true => CatalogPage { offset: self.offset, limit: self.limit(), total },
+            false => CatalogPage { offset: 0, limit: total, total },
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CatalogPage {
+    pub offset: u64,
+    pub limit: u64,
+    pub total: u64,
+}
+
+impl CatalogPage {
+    pub fn contains(&self, position: u64) -> bool {
+        position >= self.offset && position < self.offset + self.limit
+    }
+
+    pub fn next(&self) -> Option<CatalogPagination> {
+        let offset = self.offset + self.limit;
+        (offset < self.total).then(|| CatalogPagination { offset, limit: Some(self.limit) })
+    }
+
+    pub fn prev(&self) -> Option<CatalogPagination> {
+        (self.offset > 0).then(|| CatalogPagination {
+            offset: self.offset.saturating_sub(self.limit),
+            limit: Some(self.limit),
+        })
+    }
+}
@@ -17,9 +17,13 @@
  *
  */
 
+pub(crate) mod catalog_filter;
 pub(crate) mod persistence;
 pub(crate) mod protocol;
 
+use crate::protocols::dsp::orchestrator::protocol::catalog_filter::{
+    CatalogPage, CatalogPagination,
+};
 use crate::protocols::dsp::protocol_types::{
     CatalogMessageWrapper, CatalogRequestMessageDto, DatasetRequestMessage,
 };
@@ -31,7 +35,8 @@ pub trait ProtocolOrchestratorTrait: Send + Sync + 'static {
     async fn on_catalog_request(
         &self,
         input: &CatalogMessageWrapper<CatalogRequestMessageDto>,
-    ) -> anyhow::Result<Catalog>;
+        pagination: &CatalogPagination,
+    ) -> anyhow::Result<(Catalog, CatalogPage)>;
     async fn on_dataset_request(
         &self,
         input: &CatalogMessageWrapper<DatasetRequestMessage>,
@@ -1,8 +1,12 @@
-use crate::entities::catalogs::{CatalogDto, CatalogEntityTrait};
-use crate::entities::data_services::{DataServiceDto, DataServiceEntityTrait};
-use crate::entities::datasets::{DatasetDto, DatasetEntityTrait};
-use crate::entities::distributions::{DistributionDto, DistributionEntityTrait};
-use crate::entities::odrl_policies::{OdrlPolicyDto, OdrlPolicyEntityTrait};
+use crate::entities::catalog_tree::{CatalogTreeEntityTrait, CatalogTreeSnapshot};
+use crate::entities::catalogs::CatalogDto;
+use crate::entities::data_services::DataServiceDto;
+use crate::entities::datasets::DatasetDto;
+use crate::entities::distributions::DistributionDto;
+use crate::entities::odrl_policies::OdrlPolicyDto;
+use crate::protocols::dsp::orchestrator::protocol::catalog_filter::{
+    CatalogFilter, CatalogPage, CatalogPagination,
+};
 use crate::protocols::dsp::types::catalog_definition::{
     Catalog, CatalogCatalogTypes, CatalogDSpaceDeclaration, CatalogDatasetTypes,
     CatalogDcatDeclaration, CatalogDctDeclaration, CatalogFoafDeclaration, CatalogMinimized,
@@ -12,10 +16,10 @@ use crate::protocols::dsp::types::dataservice_definition::{
     DataService, DataServiceDcatDeclaration, DataServiceDctDeclaration,
 };
 use crate::protocols::dsp::types::dataset_definition::{
-    Dataset, DatasetDcatDeclaration, DatasetDctDeclaration, DatasetDistributionTypes,
+    Dataset, DatasetDcatDeclaration, DatasetDctDeclaration, DatasetDistributionTypes, PeriodOfTime,
 };
 use crate::protocols::dsp::types::distribution_definition::{
-    Distribution, DistributionDcatDeclaration, DistributionDctDeclaration,
+    Checksum, Distribution, DistributionDcatDeclaration, DistributionDctDeclaration,
 };
 use anyhow::bail;
 use rainbow_common::dcat_formats::{DctFormats, FormatAction, FormatProtocol};
@@ -23,162 +27,183 @@ use rainbow_common::dsp_common::context_field::ContextField;
 use rainbow_common::dsp_common::odrl::{OdrlOffer, OdrlPolicyInfo, OdrlTypes};
 use rainbow_common::errors::{CommonErrors, ErrorLog};
 use rainbow_common::facades::ssi_auth_facade::MatesFacadeTrait;
-use std::collections::HashMap;
 use std::str::FromStr;
 use std::sync::Arc;
 use tracing::{debug, error};
 use urn::Urn;
 
 pub struct OrchestrationPersistenceForProtocol {
-    pub catalog_entities_service: Arc<dyn CatalogEntityTrait>,
-    pub data_service_entities_service: Arc<dyn DataServiceEntityTrait>,
-    pub dataset_entities_service: Arc<dyn DatasetEntityTrait>,
-    pub odrl_policies_service: Arc<dyn OdrlPolicyEntityTrait>,
-    pub distributions_entity_service: Arc<dyn DistributionEntityTrait>,
+    pub catalog_tree_entities_service: Arc<dyn CatalogTreeEntityTrait>,
 }
 
 impl OrchestrationPersistenceForProtocol {
-    pub fn new(
-        catalog_entities_service: Arc<dyn CatalogEntityTrait>,
-        data_service_entities_service: Arc<dyn DataServiceEntityTrait>,
-        dataset_entities_service: Arc<dyn DatasetEntityTrait>,
-        odrl_policies_service: Arc<dyn OdrlPolicyEntityTrait>,
-        distributions_entity_service: Arc<dyn DistributionEntityTrait>,
-    ) -> Self {
-        Self {
-            catalog_entities_service,
-            data_service_entities_service,
-            dataset_entities_service,
-            odrl_policies_service,
-            distributions_entity_service,
-        }
+    pub fn new(catalog_tree_entities_service: Arc<dyn CatalogTreeEntityTrait>) -> Self {
+        Self { catalog_tree_entities_service }
     }
 
     // =========================================================================
     // Public API
     // =========================================================================
 
-    pub async fn get_catalog(&self) -> anyhow::Result<Catalog> {
+    pub async fn get_catalog(
+        &self,
+        filter: &CatalogFilter,
+        pagination: &CatalogPagination,
+    ) -> anyhow::Result<(Catalog, CatalogPage)> {
+        // whole tree comes from the in-process snapshot, no query per node
+        let tree = self.catalog_tree_entities_service.get_catalog_tree().await?;
         // 1. Main catalog
-        let main_catalog_dto = self.fetch_main_catalog_dto().await?;
-        let main_catalog_urn = Urn::from_str(&main_catalog_dto.inner.id)?;
+        let main_catalog_dto = Self::fetch_main_catalog_dto(&tree)?;
+        let main_catalog_id = main_catalog_dto.inner.id.clone();
         // 1b. Main service
-        let main_dataservice_dto = self.fetch_main_dataservice_dto().await?;
-        // 2. Sub catalogs
-        let sub_catalogs = self.build_sub_catalogs(&main_catalog_urn).await?;
-        // 3. Datasets in main catalog
-        let datasets = self.build_datasets_for_catalog(&main_catalog_urn).await?;
-        // 3b. Dataservice in main catalog
-        let main_dataservice = self.map_data_service(main_dataservice_dto);
-        // 4. Assembly
-        let catalog =
-            self.map_main_catalog(main_catalog_dto, main_dataservice, sub_catalogs, datasets);
-        Ok(catalog)
+        let main_dataservice_dto = Self::fetch_main_dataservice_dto(&tree)?;
+        // 2. Datasets matching the filter, main catalog first and then every sub catalog
+        let main_matches = match filter.matches_catalog(main_catalog_dto) {
+            true => Self::filter_datasets_for_catalog(&tree, &main_catalog_id, filter),
+            false => vec![],
+        };
+        let sub_catalogs_matches = Self::filter_sub_catalogs(&tree, &main_catalog_id, filter);
+        let total = main_matches.len()
+            + sub_catalogs_matches.iter().map(|(_, matches)| matches.len()).sum::<usize>();
+        let page = pagination.page(total as u64);
+        // 3. Datasets in main catalog, only the ones in the page are built
+        let mut position = 0;
+        let datasets = self.build_datasets_in_page(&tree, main_matches, &page, &mut position)?;
+        // 4. Sub catalogs with datasets in the page, empty ones are listed in the first page
+        let mut sub_catalogs = vec![];
+        for (catalog_dto, matches) in sub_catalogs_matches {
+            let has_matches = !matches.is_empty();
+            let sub_datasets = self.build_datasets_in_page(&tree, matches, &page, &mut position)?;
+            if sub_datasets.is_empty() && (has_matches || page.offset > 0) {
+                continue;
+            }
+            let sub_dataservice = self.build_dataservices_for_catalog(&tree, &catalog_dto.inner.id);
+            sub_catalogs.push(self.map_subcatalog(
+                catalog_dto.clone(),
+                sub_dataservice,
+                sub_datasets,
+            ));
+        }
+        // 4b. Dataservice in main catalog
+        let main_dataservice = self.map_data_service(main_dataservice_dto.clone());
+        // 5. Assembly
+        let catalog = self.map_main_catalog(
+            main_catalog_dto.clone(),
+            main_dataservice,
+            sub_catalogs,
+            datasets,
+        );
+        Ok((catalog, page))
     }
 
     pub async fn get_dataset(&self, dataset_id: &Urn) -> anyhow::Result<Dataset> {
-        // 1. fetch dataset
-        let dataset_dto = self.fetch_dataset_dto(dataset_id).await?;
-        // 2. build policies
-        let odrl_offers = self.build_odrl_policies(dataset_id).await?;
-        // 3. build distributions
-        let distributions = self.build_distributions_with_services(dataset_id).await?;
-        // 4. final mapping
-        let dataset = self.map_dataset(dataset_dto, odrl_offers, distributions);
-        Ok(dataset)
+        let tree = self.catalog_tree_entities_service.get_catalog_tree().await?;
+        self.build_dataset(&tree, dataset_id.as_str())
     }
 
     // =========================================================================
     // Builders
     // =========================================================================
-    async fn build_sub_catalogs(&self, exclude_id: &Urn) -> anyhow::Result<Vec<CatalogMinimized>> {
-        let catalogs_dtos =
-            self.catalog_entities_service.get_all_catalogs(None, None, false).await?;
-        let mut dcat_catalogs = Vec::with_capacity(catalogs_dtos.len());
-        for catalog_dto in catalogs_dtos {
-            let catalog_urn = Urn::from_str(&catalog_dto.inner.id)?;
-            if &catalog_urn == exclude_id {
-                continue;
-            }
-            let sub_datasets = self.build_datasets_for_catalog(&catalog_urn).await?;
-            let sub_dataservice = self.build_dataservices_for_catalog(&catalog_urn).await?;
-            dcat_catalogs.push(self.map_subcatalog(catalog_dto, sub_dataservice, sub_datasets));
-        }
-        Ok(dcat_catalogs)
+    fn filter_sub_catalogs<'a>(
+        tree: &'a CatalogTreeSnapshot,
+        exclude_id: &str,
+        filter: &CatalogFilter,
+    ) -> Vec<(&'a CatalogDto, Vec<&'a DatasetDto>)> {
+        tree.sub_catalogs()
+            .filter(|catalog_dto| catalog_dto.inner.id != exclude_id)
+            .filter(|catalog_dto| filter.matches_catalog(catalog_dto))
+            .map(|catalog_dto| {
+                let matches =
+                    Self::filter_datasets_for_catalog(tree, &catalog_dto.inner.id, filter);
+                (catalog_dto, matches)
+            })
+            .collect()
     }
-    async fn build_datasets_for_catalog(&self, catalog_id: &Urn) -> anyhow::Result<Vec<Dataset>> {
-        let datasets_dtos =
-            self.dataset_entities_service.get_datasets_by_catalog_id(catalog_id).await?;
-        let mut dcat_datasets = Vec::with_capacity(datasets_dtos.len());
 
+    fn filter_datasets_for_catalog<'a>(
+        tree: &'a CatalogTreeSnapshot,
+        catalog_id: &str,
+        filter: &CatalogFilter,
+    ) -> Vec<&'a DatasetDto> {
+        tree.datasets_of(catalog_id)
+            .iter()
+            .filter(|dataset_dto| filter.matches_dataset(dataset_dto))
+            .filter(|dataset_dto| {
+                filter.matches_distributions(tree.distributions_of(&dataset_dto.inner.id))
+            })
+            .collect()
+    }
+
+    fn build_datasets_in_page(
+        &self,
+        tree: &CatalogTreeSnapshot,
+        datasets_dtos: Vec<&DatasetDto>,
+        page: &CatalogPage,
+        position: &mut u64,
+    ) -> anyhow::Result<Vec<Dataset>> {
+        let mut dcat_datasets = vec![];
         for dataset_dto in datasets_dtos {
-            let dataset_urn = Urn::from_str(&dataset_dto.inner.id)?;
-            let dcat_dataset = self.get_dataset(&dataset_urn).await?;
-            dcat_datasets.push(dcat_dataset);
+            if page.contains(*position) {
+                dcat_datasets.push(self.build_dataset(tree, &dataset_dto.inner.id)?);
+            }
+            *position += 1;
         }
-
         Ok(dcat_datasets)
     }
 
-    async fn build_dataservices_for_catalog(
+    fn build_dataset(
         &self,
-        catalog_id: &Urn,
-    ) -> anyhow::Result<Vec<DataService>> {
-        let dataservices_dtos =
-            self.data_service_entities_service.get_data_services_by_catalog_id(catalog_id).await?;
-        let mut dcat_dataservices = Vec::with_capacity(dataservices_dtos.len());
-
-        for dataservices_dto in dataservices_dtos {
-            let dcat_dataservice = self.map_data_service(dataservices_dto);
-            dcat_dataservices.push(dcat_dataservice);
-        }
-        Ok(dcat_dataservices)
+        tree: &CatalogTreeSnapshot,
+        dataset_id: &str,
+    ) -> anyhow::Result<Dataset> {
+        // 1. fetch dataset
+        let dataset_dto = Self::fetch_dataset_dto(tree, dataset_id)?;
+        // 2. build policies
+        let odrl_offers = self.build_odrl_policies(tree, dataset_id)?;
+        // 3. build distributions
+        let distributions = self.build_distributions_with_services(tree, dataset_id)?;
+        // 4. final mapping
+        let dataset = self.map_dataset(dataset_dto.clone(), odrl_offers, distributions);
+        Ok(dataset)
     }
 
-    async fn build_odrl_policies(&self, entity_id: &Urn) -> anyhow::Result<Vec<OdrlOffer>> {
-        let policies_dtos = self
-            .odrl_policies_service
-            .get_all_odrl_offers_by_entity(entity_id)
-            .await
-            .unwrap_or_default();
-        let offers = policies_dtos
-            .into_iter()
-            .map(|dto| self.map_odrl_policy(dto))
+    fn build_dataservices_for_catalog(
+        &self,
+        tree: &CatalogTreeSnapshot,
+        catalog_id: &str,
+    ) -> Vec<DataService> {
+        tree.data_services_of(catalog_id)
+            .iter()
+            .map(|dataservices_dto| self.map_data_service(dataservices_dto.clone()))
+            .collect()
+    }
+
+    fn build_odrl_policies(
+        &self,
+        tree: &CatalogTreeSnapshot,
+        entity_id: &str,
+    ) -> anyhow::Result<Vec<OdrlOffer>> {
+        let offers = tree
+            .odrl_offers_of(entity_id)
+            .iter()
+            .map(|dto| self.map_odrl_policy(dto.clone()))
             .collect::<Result<Vec<_>, _>>()?;
         Ok(offers)
     }
 
-    async fn build_distributions_with_services(
+    fn build_distributions_with_services(
         &self,
-        dataset_id: &Urn,
+        tree: &CatalogTreeSnapshot,
+        dataset_id: &str,
     ) -> anyhow::Result<Vec<Distribution>> {
-        let distributions_dtos =
-            self.distributions_entity_service.get_distributions_by_dataset_id(dataset_id).await?;
-        // batch dataservices
-        let access_services_ids: Vec<Urn> = distributions_dtos
-            .iter()
-            .map(|d| d.inner.dcat_access_service.clone())
-            .map(|id_str| Urn::from_str(&id_str))
-            .collect::<Result<Vec<_>, _>>()
-            .unwrap_or_default();
-
-        let services_batch = self
-            .data_service_entities_service
-            .get_batch_data_services(&access_services_ids)
-            .await?;
-        // index indices
-        let services_map: HashMap<String, DataService> = services_batch
-            .into_iter()
-            .map(|dto| (dto.inner.id.clone(), self.map_data_service(dto)))
-            .collect();
-
-        // map distributions
+        let distributions_dtos = tree.distributions_of(dataset_id);
         let mut distributions = Vec::with_capacity(distributions_dtos.len());
         for dist_dto in distributions_dtos {
-            let service_id = &dist_dto.inner.dcat_access_service;
-            let linked_service = services_map.get(service_id).cloned();
-
-            distributions.push(self.map_distribution(dist_dto, linked_service)?);
+            let linked_service = tree
+                .data_services_by_id
+                .get(&dist_dto.inner.dcat_access_service)
+                .map(|service_dto| self.map_data_service(service_dto.clone()));
+            distributions.push(self.map_distribution(dist_dto.clone(), linked_service)?);
         }
         Ok(distributions)
     }
@@ -187,8 +212,8 @@ impl OrchestrationPersistenceForProtocol {
     // FETCHERS
     // =========================================================================
 
-    async fn fetch_main_catalog_dto(&self) -> anyhow::Result<CatalogDto> {
-        match self.catalog_entities_service.get_main_catalog().await? {
+    fn fetch_main_catalog_dto(tree: &CatalogTreeSnapshot) -> anyhow::Result<&CatalogDto> {
+        match tree.main_catalog.as_ref() {
             Some(c) => Ok(c),
             None => {
                 let err = CommonErrors::missing_resource_new("", "Main catalog not found");
@@ -198,8 +223,8 @@ impl OrchestrationPersistenceForProtocol {
         }
     }
 
-    async fn fetch_main_dataservice_dto(&self) -> anyhow::Result<DataServiceDto> {
-        match self.data_service_entities_service.get_main_data_service().await? {
+    fn fetch_main_dataservice_dto(tree: &CatalogTreeSnapshot) -> anyhow::Result<&DataServiceDto> {
+        match tree.main_data_service.as_ref() {
             Some(c) => Ok(c),
             None => {
                 let err = CommonErrors::missing_resource_new("", "Main dataservice not found");
@@ -209,12 +234,14 @@ impl OrchestrationPersistenceForProtocol {
         }
     }
 
-    async fn fetch_dataset_dto(&self, dataset_id: &Urn) -> anyhow::Result<DatasetDto> {
-        match self.dataset_entities_service.get_dataset_by_id(dataset_id).await? {
+    fn fetch_dataset_dto<'a>(
+        tree: &'a CatalogTreeSnapshot,
+        dataset_id: &str,
+    ) -> anyhow::Result<&'a DatasetDto> {
+        match tree.datasets_by_id.get(dataset_id) {
             Some(d) => Ok(d),
             None => {
-                let err =
-                    CommonErrors::missing_resource_new(dataset_id.as_str(), "Dataset not found");
+                let err = CommonErrors::missing_resource_new(dataset_id, "Dataset not found");
                 error!("{}", err.log());
                 bail!(err)
             }
@@ -336,15 +363,33 @@ impl OrchestrationPersistenceForProtocol {
             context: ContextField::default(),
             _type: "Dataset".to_string(),
             id: dto.inner.id.clone(),
-            dcat: DatasetDcatDeclaration { theme: None, keyword: None },
+            dcat: DatasetDcatDeclaration {
+                theme: dto.inner.themes(),
+                keyword: dto.inner.keywords(),
+                contact_point: dto.inner.dcat_contact_point.clone(),
+                version: dto.inner.dcat_version.clone(),
+                previous_version: dto.inner.dcat_previous_version.clone(),
+            },
             dct: DatasetDctDeclaration {
-                conforms_to: dto.inner.dct_conforms_to,
-                creator: dto.inner.dct_creator,
+                conforms_to: dto.inner.dct_conforms_to.clone(),
+                creator: dto.inner.dct_creator.clone(),
                 identifier: dto.inner.id.clone(),
                 issued: dto.inner.dct_issued.naive_utc(),
                 modified: dto.inner.dct_modified.map(|d| d.naive_utc()),
-                title: dto.inner.dct_title,
+                title: dto.inner.dct_title.clone(),
                 description: vec![],
+                language: dto.inner.languages(),
+                license: dto.inner.dct_license.clone(),
+                access_rights: dto.inner.dct_access_rights.clone(),
+                spatial: dto.inner.dct_spatial.clone(),
+                temporal: match (dto.inner.dct_temporal_start_date, dto.inner.dct_temporal_end_date)
+                {
+                    (None, None) => None,
+                    (start_date, end_date) => Some(PeriodOfTime {
+                        start_date: start_date.map(|d| d.naive_utc()),
+                        end_date: end_date.map(|d| d.naive_utc()),
+                    }),
+                },
             },
             odrl_offer: policies,
             extra_fields: Default::default(),
@@ -374,6 +419,14 @@ impl OrchestrationPersistenceForProtocol {
             id: dto.inner.id.clone(),
             dcat: DistributionDcatDeclaration {
                 access_service: service.map(|d| CatalogServiceTypes::ServiceMinimized(d.into())),
+                byte_size: dto.inner.dcat_byte_size,
+                media_type: dto.inner.dcat_media_type,
+                checksum: match (dto.inner.spdx_checksum_algorithm, dto.inner.spdx_checksum_value) {
+                    (Some(algorithm), Some(checksum_value)) => {
+                        Some(Checksum { algorithm, checksum_value })
+                    }
+                    _ => None,
+                },
             },
             dct: DistributionDctDeclaration {
                 issued: dto.inner.dct_issued.naive_utc(),
@@ -381,6 +434,8 @@ impl OrchestrationPersistenceForProtocol {
                 title: dto.inner.dct_title,
                 description: vec![],
                 formats: format,
+                license: dto.inner.dct_license,
+                access_rights: dto.inner.dct_access_rights,
             },
             odrl_offer: vec![],
             extra_fields: Default::default(),
@@ -393,10 +448,11 @@ impl OrchestrationPersistenceForProtocol {
             _type: "DataService".to_string(),
             id: dto.inner.id.clone(),
             dcat: DataServiceDcatDeclaration {
-                theme: None,
-                keyword: None,
+                theme: dto.inner.themes(),
+                keyword: dto.inner.keywords(),
                 endpoint_description: dto.inner.dcat_endpoint_description,
                 endpoint_url: dto.inner.dcat_endpoint_url,
+                contact_point: dto.inner.dcat_contact_point,
             },
             dct: DataServiceDctDeclaration {
                 conforms_to: dto.inner.dct_conforms_to,
@@ -406,6 +462,8 @@ impl OrchestrationPersistenceForProtocol {
                 modified: dto.inner.dct_modified.map(|d| d.naive_utc()),
                 title: dto.inner.dct_title,
                 description: vec![],
+                license: dto.inner.dct_license,
+                access_rights: dto.inner.dct_access_rights,
             },
             odrl_offer: vec![],
             extra_fields: Default::default(),
@@ -427,3 +485,320 @@ impl OrchestrationPersistenceForProtocol {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::entities::catalog::NewCatalogModel;
+    use crate::data::entities::dataservice::NewDataServiceModel;
+    use crate::data::entities::dataset::NewDatasetModel;
+    use crate::data::entities::distribution::NewDistributionModel;
+    use crate::data::entities::odrl_offer::NewOdrlOfferModel;
+    use crate::data::entities::{catalog, dataservice, dataset, distribution, odrl_offer};
+    use crate::data::factory_sql::CatalogAgentRepoForSql;
+    use crate::data::factory_trait::CatalogAgentRepoTrait;
+    use crate::data::migrations::Migrator;
+    use crate::entities::catalog_tree::catalog_tree::CatalogTreeEntities;
+    use crate::entities::odrl_policies::CatalogEntityTypes;
+    use sea_orm::{ActiveValue, ConnectOptions, Database, DatabaseConnection, EntityTrait};
+    use sea_orm_migration::MigratorTrait;
+    use urn::UrnBuilder;
+
+    const SUB_CATALOGS: usize = 20;
+    const DATASETS_PER_CATALOG: usize = 250;
+    const INSERT_CHUNK: usize = 250;
+
+    fn urn(kind: &str, id: String) -> Urn {
+        UrnBuilder::new(kind, id.as_str()).build().unwrap()
+    }
+
+    async fn setup() -> DatabaseConnection {
+        // a single connection, every new in-memory connection is a new empty database
+        let mut options = ConnectOptions::new("sqlite::memory:");
+        options.max_connections(1).sqlx_logging(false);
+        let db = Database::connect(options).await.unwrap();
+        Migrator::up(&db, None).await.unwrap();
+        db
+    }
+
+    /// Main catalog plus `sub_catalogs`, every catalog with `datasets_per_catalog` datasets,
+    /// each dataset with a distribution and an offer
+    async fn seed(db: &DatabaseConnection, sub_catalogs: usize, datasets_per_catalog: usize) {
+        let main_catalog_id = urn("catalog", "main".to_string());
+        let main_service_id = urn("data-service", "main".to_string());
+        let mut main_catalog: catalog::ActiveModel = NewCatalogModel {
+            id: Some(main_catalog_id.clone()),
+            foaf_home_page: None,
+            dct_conforms_to: None,
+            dct_creator: None,
+            dct_title: Some("Main catalog".to_string()),
+            dspace_participant_id: None,
+        }
+        .into();
+        main_catalog.dspace_main_catalog = ActiveValue::Set(true);
+        let mut catalogs = vec![main_catalog];
+        let mut catalog_ids = vec![main_catalog_id.clone()];
+        for c in 0..sub_catalogs {
+            let catalog_id = urn("catalog", format!("sub-{}", c));
+            catalogs.push(
+                NewCatalogModel {
+                    id: Some(catalog_id.clone()),
+                    foaf_home_page: None,
+                    dct_conforms_to: None,
+                    dct_creator: None,
+                    dct_title: Some(format!("Sub catalog {}", c)),
+                    dspace_participant_id: None,
+                }
+                .into(),
+            );
+            catalog_ids.push(catalog_id);
+        }
+        catalog::Entity::insert_many(catalogs).exec(db).await.unwrap();
+        dataservice::Entity::insert(dataservice::ActiveModel::from(NewDataServiceModel {
+            id: Some(main_service_id.clone()),
+            dcat_endpoint_description: None,
+            dcat_endpoint_url: "http://127.0.0.1:1200".to_string(),
+            dct_conforms_to: None,
+            dct_creator: None,
+            dct_title: Some("Main data service".to_string()),
+            dct_description: None,
+            catalog_id: main_catalog_id.clone(),
+            dspace_main_data_service: true,
+            dcat_keyword: None,
+            dcat_theme: None,
+            dct_license: None,
+            dct_access_rights: None,
+            dcat_contact_point: None,
+        }))
+        .exec(db)
+        .await
+        .unwrap();
+
+        let mut datasets = vec![];
+        let mut distributions = vec![];
+        let mut offers = vec![];
+        for (c, catalog_id) in catalog_ids.iter().enumerate() {
+            for d in 0..datasets_per_catalog {
+                let dataset_id = urn("dataset", format!("{}-{}", c, d));
+                datasets.push(dataset::ActiveModel::from(NewDatasetModel {
+                    id: Some(dataset_id.clone()),
+                    dct_conforms_to: None,
+                    dct_creator: None,
+                    dct_title: Some(format!("Dataset {} of catalog {}", d, c)),
+                    dct_description: None,
+                    catalog_id: catalog_id.clone(),
+                    dcat_keyword: None,
+                    dcat_theme: None,
+                    dct_language: None,
+                    dct_license: None,
+                    dct_access_rights: None,
+                    dct_spatial: None,
+                    dct_temporal_start_date: None,
+                    dct_temporal_end_date: None,
+                    dcat_contact_point: None,
+                    dcat_version: None,
+                    dcat_previous_version: None,
+                }));
+                distributions.push(distribution::ActiveModel::from(NewDistributionModel {
+                    id: None,
+                    dct_title: None,
+                    dct_description: None,
+                    dct_formats: Some(DctFormats {
+                        protocol: FormatProtocol::Http,
+                        action: FormatAction::Pull,
+                    }),
+                    dcat_access_service: main_service_id.to_string(),
+                    dataset_id: dataset_id.clone(),
+                    dcat_byte_size: None,
+                    dcat_media_type: None,
+                    spdx_checksum_algorithm: None,
+                    spdx_checksum_value: None,
+                    dct_license: None,
+                    dct_access_rights: None,
+                }));
+                offers.push(odrl_offer::ActiveModel::from(NewOdrlOfferModel {
+                    id: None,
+                    odrl_offer: serde_json::from_value(serde_json::json!({})).unwrap(),
+                    entity_id: dataset_id,
+                    entity_type: CatalogEntityTypes::Dataset,
+                    source_template_id: None,
+                    source_template_version: None,
+                    instantiation_parameters: None,
+                }));
+            }
+        }
+        while !datasets.is_empty() {
+            let chunk = datasets.split_off(datasets.len().saturating_sub(INSERT_CHUNK));
+            dataset::Entity::insert_many(chunk).exec(db).await.unwrap();
+        }
+        while !distributions.is_empty() {
+            let chunk = distributions.split_off(distributions.len().saturating_sub(INSERT_CHUNK));
+            distribution::Entity::insert_many(chunk).exec(db).await.unwrap();
+        }
+        while !offers.is_empty() {
+            let chunk = offers.split_off(offers.len().saturating_sub(INSERT_CHUNK));
+            odrl_offer::Entity::insert_many(chunk).exec(db).await.unwrap();
+        }
+    }
+
+    fn datasets_in(catalog: &Catalog) -> usize {
+        let sub_catalog_datasets = match &catalog.catalogs {
+            CatalogCatalogTypes::CatalogMultipleMinimized(sub_catalogs) => sub_catalogs
+                .iter()
+                .map(|sub_catalog| match &sub_catalog.datasets {
+                    CatalogDatasetTypes::DatasetMultipleMinimized(d) => d.len(),
+                    CatalogDatasetTypes::DatasetMultipleOriginal(d) => d.len(),
+                })
+                .sum(),
+            CatalogCatalogTypes::CatalogMultipleOriginal(_) => 0,
+        };
+        let main_datasets = match &catalog.datasets {
+            CatalogDatasetTypes::DatasetMultipleMinimized(d) => d.len(),
+            CatalogDatasetTypes::DatasetMultipleOriginal(d) => d.len(),
+        };
+        main_datasets + sub_catalog_datasets
+    }
+
+    #[tokio::test]
+    async fn large_catalog_is_paged_from_one_snapshot() {
+        let db = setup().await;
+        seed(&db, SUB_CATALOGS, DATASETS_PER_CATALOG).await;
+        let expected = ((SUB_CATALOGS + 1) * DATASETS_PER_CATALOG) as u64;
+
+        let repo = Arc::new(CatalogAgentRepoForSql::create_repo(db.clone()));
+        let tree_service = Arc::new(CatalogTreeEntities::new(repo));
+        let persistence = OrchestrationPersistenceForProtocol::new(tree_service.clone());
+        let filter = CatalogFilter::from_value(&serde_json::Value::Null).unwrap();
+        let first_page = CatalogPagination { offset: 0, limit: Some(50) };
+
+        let (catalog, page) = persistence.get_catalog(&filter, &first_page).await.unwrap();
+        assert_eq!(page.total, expected);
+        assert_eq!(datasets_in(&catalog), 50);
+
+        // without writes the tree is not loaded again
+        let snapshot = tree_service.get_catalog_tree().await.unwrap();
+        let (catalog, _) = persistence.get_catalog(&filter, &first_page).await.unwrap();
+        assert_eq!(datasets_in(&catalog), 50);
+        assert!(Arc::ptr_eq(
+            &snapshot,
+            &tree_service.get_catalog_tree().await.unwrap()
+        ));
+
+        // walking every page sees every dataset exactly once
+        let mut seen = 0;
+        let mut pagination = Some(CatalogPagination { offset: 0, limit: Some(500) });
+        while let Some(current) = pagination {
+            let (catalog, page) = persistence.get_catalog(&filter, &current).await.unwrap();
+            seen += datasets_in(&catalog) as u64;
+            pagination = page.next();
+        }
+        assert_eq!(seen, expected);
+    }
+
+    #[tokio::test]
+    async fn catalog_without_pagination_is_whole() {
+        let db = setup().await;
+        seed(&db, 2, 30).await;
+        let tree_service = Arc::new(CatalogTreeEntities::new(Arc::new(
+            CatalogAgentRepoForSql::create_repo(db.clone()),
+        )));
+        let persistence = OrchestrationPersistenceForProtocol::new(tree_service);
+        let filter = CatalogFilter::from_value(&serde_json::Value::Null).unwrap();
+
+        let (catalog, page) =
+            persistence.get_catalog(&filter, &CatalogPagination::default()).await.unwrap();
+        assert_eq!(datasets_in(&catalog), 90);
+        assert!(page.next().is_none());
+    }
+
+    #[tokio::test]
+    async fn filter_narrows_the_catalog() {
+        let db = setup().await;
+        seed(&db, 2, 30).await;
+        let tree_service = Arc::new(CatalogTreeEntities::new(Arc::new(
+            CatalogAgentRepoForSql::create_repo(db.clone()),
+        )));
+        let persistence = OrchestrationPersistenceForProtocol::new(tree_service);
+        let get_catalog = |filter: serde_json::Value| {
+            let persistence = &persistence;
+            async move {
+                let filter = CatalogFilter::from_value(&filter).unwrap();
+                let (catalog, page) =
+                    persistence.get_catalog(&filter, &CatalogPagination::default()).await.unwrap();
+                (datasets_in(&catalog), page.total)
+            }
+        };
+
+        // one dataset per catalog, "Dataset 1 of catalog 0" and so on
+        assert_eq!(get_catalog(serde_json::json!({ "dct:title": "Dataset 1 OF" })).await, (3, 3));
+        // every dataset of the second sub catalog
+        assert_eq!(get_catalog(serde_json::json!({ "keyword": "catalog 2" })).await, (30, 30));
+        // filters in a list are combined
+        let both = serde_json::json!([{ "title": "dataset 1 of" }, { "keyword": "catalog 2" }]);
+        assert_eq!(get_catalog(both).await, (1, 1));
+        // no catalog belongs to the participant
+        let participant = serde_json::json!({ "participantId": "did:web:other.example" });
+        assert_eq!(get_catalog(participant).await, (0, 0));
+        assert!(CatalogFilter::from_value(&serde_json::json!({ "license": "cc-by" })).is_err());
+    }
+
+    fn late_dataset() -> NewDatasetModel {
+        NewDatasetModel {
+            id: None,
+            dct_conforms_to: None,
+            dct_creator: None,
+            dct_title: Some("Late dataset".to_string()),
+            dct_description: None,
+            catalog_id: urn("catalog", "main".to_string()),
+            dcat_keyword: None,
+            dcat_theme: None,
+            dct_language: None,
+            dct_license: None,
+            dct_access_rights: None,
+            dct_spatial: None,
+            dct_temporal_start_date: None,
+            dct_temporal_end_date: None,
+            dcat_contact_point: None,
+            dcat_version: None,
+            dcat_previous_version: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn snapshot_is_rebuilt_after_a_write() {
+        let db = setup().await;
+        seed(&db, 1, 3).await;
+        let repo = Arc::new(CatalogAgentRepoForSql::create_repo(db.clone()));
+        let tree_service = CatalogTreeEntities::new(repo.clone());
+
+        let before = tree_service.get_catalog_tree().await.unwrap();
+        let unchanged = tree_service.get_catalog_tree().await.unwrap();
+        assert_eq!(before.datasets_by_id.len(), unchanged.datasets_by_id.len());
+
+        repo.get_dataset_repo().create_dataset(&late_dataset()).await.unwrap();
+
+        let after = tree_service.get_catalog_tree().await.unwrap();
+        assert!(after.version > before.version);
+        assert_eq!(after.datasets_by_id.len(), before.datasets_by_id.len() + 1);
+    }
+
+    #[tokio::test]
+    async fn writes_of_another_replica_invalidate_the_snapshot() {
+        let db = setup().await;
+        seed(&db, 1, 3).await;
+        let writer = Arc::new(CatalogAgentRepoForSql::create_repo(db.clone()));
+        let reader =
+            CatalogTreeEntities::new(Arc::new(CatalogAgentRepoForSql::create_repo(db.clone())));
+
+        let before = reader.get_catalog_tree().await.unwrap();
+        // a failed write changes nothing, so the snapshot is kept
+        let missing = urn("dataset", "missing".to_string());
+        assert!(writer.get_dataset_repo().delete_dataset_by_id(&missing).await.is_err());
+        assert!(Arc::ptr_eq(&before, &reader.get_catalog_tree().await.unwrap()));
+
+        writer.get_dataset_repo().create_dataset(&late_dataset()).await.unwrap();
+        let after = reader.get_catalog_tree().await.unwrap();
+        assert!(after.version > before.version);
+        assert_eq!(after.datasets_by_id.len(), before.datasets_by_id.len() + 1);
+    }
+}
@@ -18,6 +18,9 @@
  */
 
 use crate::protocols::dsp::facades::FacadeTrait;
+use crate::protocols::dsp::orchestrator::protocol::catalog_filter::{
+    CatalogFilter, CatalogPage, CatalogPagination,
+};
 use crate::protocols::dsp::orchestrator::protocol::persistence::OrchestrationPersistenceForProtocol;
 use crate::protocols::dsp::orchestrator::protocol::ProtocolOrchestratorTrait;
 use crate::protocols::dsp::protocol_types::{
@@ -48,9 +51,11 @@ impl ProtocolOrchestratorService {
 impl ProtocolOrchestratorTrait for ProtocolOrchestratorService {
     async fn on_catalog_request(
         &self,
-        _input: &CatalogMessageWrapper<CatalogRequestMessageDto>,
-    ) -> anyhow::Result<Catalog> {
-        let catalog = self.persistence.get_catalog().await?;
+        input: &CatalogMessageWrapper<CatalogRequestMessageDto>,
+        pagination: &CatalogPagination,
+    ) -> anyhow::Result<(Catalog, CatalogPage)> {
+        let filter = CatalogFilter::from_value(&input.dto.filter)?;
+        let catalog = self.persistence.get_catalog(&filter, pagination).await?;
         Ok(catalog)
     }
 
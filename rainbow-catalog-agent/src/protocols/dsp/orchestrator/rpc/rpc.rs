@@ -14,8 +14,11 @@ use crate::protocols::dsp::validator::traits::validation_dsp_steps::ValidationDs
 use crate::protocols::dsp::validator::traits::validation_rpc_steps::ValidationRpcSteps;
 use anyhow::anyhow;
 use rainbow_common::errors::{CommonErrors, ErrorLog};
-use rainbow_common::http_client::HttpClient;
+use rainbow_common::facades::ssi_auth_facade::{send_with_peer_token, PeerTokenFacadeTrait};
+use rainbow_common::http_client::{HttpClient, RequestOptions};
 use rainbow_common::well_known::rpc::WellKnownRPCRequest;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
 use std::marker::PhantomData;
 use std::sync::Arc;
 use tracing::error;
@@ -25,6 +28,7 @@ pub struct RPCOrchestratorService {
     http_client: Arc<HttpClient>,
     facades: Arc<dyn FacadeTrait>,
     persistence: Arc<OrchestrationPersistenceForProtocolForRPC>,
+    peer_token_facade: Arc<dyn PeerTokenFacadeTrait>,
 }
 
 impl RPCOrchestratorService {
@@ -33,8 +37,43 @@ impl RPCOrchestratorService {
         http_client: Arc<HttpClient>,
         facades: Arc<dyn FacadeTrait>,
         persistence: Arc<OrchestrationPersistenceForProtocolForRPC>,
+        peer_token_facade: Arc<dyn PeerTokenFacadeTrait>,
     ) -> RPCOrchestratorService {
-        Self { validator, http_client, facades, persistence }
+        Self { validator, http_client, facades, persistence, peer_token_facade }
+    }
+
+    /// Posts a DSP message with the peer's own token, onboarding again once if it is rejected
+    async fn post_to_peer<T, R>(&self, peer: &str, url: &str, body: &T) -> anyhow::Result<R>
+    where
+        T: Serialize + Sync,
+        R: DeserializeOwned,
+    {
+        send_with_peer_token(
+            self.peer_token_facade.as_ref(),
+            peer,
+            RequestOptions::new(),
+            |options| async move {
+                self.http_client.post_json_with_options(url, body, &options).await
+            },
+        )
+        .await
+    }
+
+    /// Same as `post_to_peer` for the DSP requests sent as GET with a body
+    async fn get_from_peer<T, R>(&self, peer: &str, url: &str, body: &T) -> anyhow::Result<R>
+    where
+        T: Serialize + Sync,
+        R: DeserializeOwned,
+    {
+        send_with_peer_token(
+            self.peer_token_facade.as_ref(),
+            peer,
+            RequestOptions::new(),
+            |options| async move {
+                self.http_client.get_json_with_payload_and_options(url, body, &options).await
+            },
+        )
+        .await
     }
 }
 
@@ -70,20 +109,16 @@ impl RPCOrchestratorTrait for RPCOrchestratorService {
             .facades
             .get_catalog_rpc_path_facade()
             .await
-            .resolve_dataspace_current_path(&WellKnownRPCRequest { participant_id })
+            .resolve_dataspace_current_path(&WellKnownRPCRequest {
+                participant_id: participant_id.clone(),
+            })
             .await?;
 
         // send dsp message to peer to fetch catalog
         let peer_url = format!("{}/catalog/request", provider_address);
         let request_body: CatalogMessageWrapper<CatalogRequestMessageDto> = input.clone().into();
-        self.http_client.set_auth_token("blabla".to_string()).await;
-        let response = self
-            .http_client
-            .post_json::<CatalogMessageWrapper<CatalogRequestMessageDto>, Catalog>(
-                peer_url.as_str(),
-                &request_body,
-            )
-            .await?;
+        let response: Catalog =
+            self.post_to_peer(participant_id.as_str(), peer_url.as_str(), &request_body).await?;
 
         // hydrate cache
         let _ = self.persistence.set_catalog(&agent_peer, &response).await?;
@@ -106,14 +141,15 @@ impl RPCOrchestratorTrait for RPCOrchestratorService {
             .facades
             .get_catalog_rpc_path_facade()
             .await
-            .resolve_dataspace_current_path(&WellKnownRPCRequest { participant_id })
+            .resolve_dataspace_current_path(&WellKnownRPCRequest {
+                participant_id: participant_id.clone(),
+            })
             .await?;
         let dataset = input.get_dataset_id().unwrap_or("".to_string());
         let peer_url = format!("{}/catalog/datasets/{}", provider_address, dataset);
         let request_body: CatalogMessageWrapper<DatasetRequestMessage> = input.clone().into();
-        self.http_client.set_auth_token("blabla".to_string()).await;
         let response: Dataset =
-            self.http_client.get_json_with_payload(peer_url.as_str(), &request_body).await?;
+            self.get_from_peer(participant_id.as_str(), peer_url.as_str(), &request_body).await?;
 
         let response = RpcCatalogResponseMessageDto { request: input.clone(), response };
         Ok(response)
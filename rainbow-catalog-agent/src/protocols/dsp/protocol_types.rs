@@ -44,6 +44,7 @@ pub trait CatalogMessageTrait: Debug + Send + Sync {}
 #[serde(rename_all = "camelCase")]
 #[serde(deny_unknown_fields)]
 pub struct CatalogRequestMessageDto {
+    #[serde(default)]
     pub filter: serde_json::Value,
 }
 
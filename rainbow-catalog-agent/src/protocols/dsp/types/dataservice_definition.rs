@@ -17,7 +17,7 @@
  *
  */
 
-use crate::protocols::dsp::types::EntityTypes;
+use crate::protocols::dsp::types::{one_or_many, EntityTypes};
 use rainbow_common::dsp_common::context_field::ContextField;
 use rainbow_common::dsp_common::odrl::OdrlOffer;
 use serde::{Deserialize, Serialize};
@@ -55,16 +55,19 @@ pub struct DataServiceMinimized {
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct DataServiceDcatDeclaration {
     #[serde(rename = "theme")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub theme: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty", default, deserialize_with = "one_or_many")]
+    pub theme: Vec<String>,
     #[serde(rename = "keyword")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub keyword: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty", default, deserialize_with = "one_or_many")]
+    pub keyword: Vec<String>,
     #[serde(rename = "endpointDescription")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub endpoint_description: Option<String>,
     #[serde(rename = "endpointURL")]
     pub endpoint_url: String,
+    #[serde(rename = "contactPoint")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contact_point: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -88,6 +91,12 @@ pub struct DataServiceDctDeclaration {
     #[serde(rename = "description")]
     #[serde(skip_serializing_if = "Vec::is_empty", default)]
     pub description: Vec<String>,
+    #[serde(rename = "license")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub license: Option<String>,
+    #[serde(rename = "accessRights")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub access_rights: Option<String>,
 }
 
 impl Default for DataService {
@@ -97,10 +106,11 @@ impl Default for DataService {
             _type: EntityTypes::DataService.to_string(),
             id: "".to_string(),
             dcat: DataServiceDcatDeclaration {
-                theme: None,
-                keyword: None,
+                theme: vec![],
+                keyword: vec![],
                 endpoint_description: None,
                 endpoint_url: "".to_string(),
+                contact_point: None,
             },
             dct: DataServiceDctDeclaration {
                 conforms_to: None,
@@ -110,6 +120,8 @@ impl Default for DataService {
                 modified: None,
                 title: None,
                 description: vec![],
+                license: None,
+                access_rights: None,
             },
             odrl_offer: vec![],
             extra_fields: Value::default(),
@@ -19,7 +19,7 @@
 
 use crate::protocols::dsp::types::dataservice_definition::{DataService, DataServiceMinimized};
 use crate::protocols::dsp::types::distribution_definition::{Distribution, DistributionMinimized};
-use crate::protocols::dsp::types::CatalogDspTraitDefinition;
+use crate::protocols::dsp::types::{one_or_many, CatalogDspTraitDefinition};
 use rainbow_common::dsp_common::context_field::ContextField;
 use rainbow_common::dsp_common::odrl::OdrlOffer;
 use serde::{Deserialize, Serialize};
@@ -69,11 +69,20 @@ pub struct DatasetMinimized {
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct DatasetDcatDeclaration {
     #[serde(rename = "theme")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub theme: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty", default, deserialize_with = "one_or_many")]
+    pub theme: Vec<String>,
     #[serde(rename = "keyword")]
+    #[serde(skip_serializing_if = "Vec::is_empty", default, deserialize_with = "one_or_many")]
+    pub keyword: Vec<String>,
+    #[serde(rename = "contactPoint")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contact_point: Option<String>,
+    #[serde(rename = "version")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    #[serde(rename = "previousVersion")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub keyword: Option<String>,
+    pub previous_version: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -97,6 +106,32 @@ pub struct DatasetDctDeclaration {
     #[serde(rename = "description")]
     #[serde(skip_serializing_if = "Vec::is_empty", default)]
     pub description: Vec<String>,
+    #[serde(rename = "language")]
+    #[serde(skip_serializing_if = "Vec::is_empty", default, deserialize_with = "one_or_many")]
+    pub language: Vec<String>,
+    #[serde(rename = "license")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub license: Option<String>,
+    #[serde(rename = "accessRights")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub access_rights: Option<String>,
+    #[serde(rename = "spatial")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spatial: Option<String>,
+    #[serde(rename = "temporal")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temporal: Option<PeriodOfTime>,
+}
+
+/// dct:PeriodOfTime, either end may be open
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PeriodOfTime {
+    #[serde(rename = "startDate")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<chrono::NaiveDateTime>,
+    #[serde(rename = "endDate")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_date: Option<chrono::NaiveDateTime>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -68,6 +68,24 @@ pub struct DistributionMinimized {
 pub struct DistributionDcatDeclaration {
     #[serde(rename = "accessService")]
     pub access_service: Option<CatalogServiceTypes>,
+    #[serde(rename = "byteSize")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub byte_size: Option<i64>,
+    #[serde(rename = "mediaType")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub media_type: Option<String>,
+    #[serde(rename = "checksum")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub checksum: Option<Checksum>,
+}
+
+/// spdx:Checksum of the distribution bytes
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Checksum {
+    #[serde(rename = "algorithm")]
+    pub algorithm: String,
+    #[serde(rename = "checksumValue")]
+    pub checksum_value: String,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -85,4 +103,10 @@ pub struct DistributionDctDeclaration {
     pub description: Vec<String>,
     #[serde(rename = "formats")]
     pub formats: DctFormats,
+    #[serde(rename = "license")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub license: Option<String>,
+    #[serde(rename = "accessRights")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub access_rights: Option<String>,
 }
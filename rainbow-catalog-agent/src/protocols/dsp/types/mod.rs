@@ -19,6 +19,7 @@
 
 use anyhow::anyhow;
 use sea_orm::Value;
+use serde::{Deserialize, Deserializer};
 use std::fmt::Display;
 
 pub mod catalog_definition;
@@ -35,6 +36,24 @@ pub enum EntityTypes {
 
 pub trait CatalogDspTraitDefinition {}
 
+/// Multi-valued DCAT terms such as keyword or theme may come compacted to a single string
+pub(crate) fn one_or_many<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany {
+        One(String),
+        Many(Vec<String>),
+    }
+    Ok(match Option::<OneOrMany>::deserialize(deserializer)? {
+        Some(OneOrMany::One(value)) => vec![value],
+        Some(OneOrMany::Many(values)) => values,
+        None => vec![],
+    })
+}
+
 impl TryFrom<&str> for EntityTypes {
     type Error = anyhow::Error;
 
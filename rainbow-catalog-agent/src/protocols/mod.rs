@@ -1,2 +1,3 @@
+pub(crate) mod dcat_ap;
 pub(crate) mod dsp;
 pub(crate) mod protocol;
@@ -16,6 +16,7 @@
  *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
  *
  */
+use crate::setup::crawler_worker::CatalogCrawlerWorker;
 use crate::setup::grpc_worker::CatalogGrpcWorker;
 use crate::setup::http_worker::CatalogHttpWorker;
 use crate::{CatalogDto, DataServiceDto, NewCatalogDto, NewDataServiceDto};
@@ -25,6 +26,7 @@ use rainbow_common::config::services::{CatalogConfig, ContractsConfig, TransferC
 use rainbow_common::config::traits::{CommonConfigTrait, ConfigLoader};
 use rainbow_common::config::types::roles::RoleConfig;
 use rainbow_common::http_client::{HttpClient, HttpClientError};
+use rainbow_events::core::notification::notification_delivery::RainbowEventsDeliveryWorker;
 use std::str::FromStr;
 use std::sync::Arc;
 use tokio::sync::broadcast;
@@ -36,6 +38,7 @@ use ymir::config::traits::{ApiConfigTrait, HostsConfigTrait};
 use ymir::config::types::HostType;
 use ymir::data::entities::mates;
 use ymir::services::vault::vault_rs::VaultService;
+use ymir::services::vault::VaultTrait;
 
 pub struct CatalogAgentBoot;
 
@@ -186,6 +189,14 @@ impl BootstrapServiceTrait for CatalogAgentBoot {
         tracing::info!("Spawning gRPC subsystem...");
         let grpc_handle = CatalogGrpcWorker::spawn(config, vault.clone(), &cancel_token).await?;
 
+        tracing::info!("Spawning federated catalog crawler...");
+        let crawler_handle =
+            CatalogCrawlerWorker::spawn(config, vault.clone(), &cancel_token).await?;
+
+        tracing::info!("Spawning events delivery worker...");
+        let db_connection = vault.get_db_connection(config.common()).await;
+        let events_handle = RainbowEventsDeliveryWorker::spawn(db_connection, &cancel_token);
+
         // non-blocking thread
         let token_clone = cancel_token.clone();
         tokio::spawn(async move {
@@ -200,6 +211,12 @@ impl BootstrapServiceTrait for CatalogAgentBoot {
                 _ = async { grpc_handle.await } => {
                     tracing::error!("GRPC subsystem failed or stopped unexpectedly!");
                 }
+                _ = async { crawler_handle.await } => {
+                    tracing::error!("Crawler subsystem failed or stopped unexpectedly!");
+                }
+                _ = async { events_handle.await } => {
+                    tracing::error!("Events delivery subsystem failed or stopped unexpectedly!");
+                }
             }
 
             tracing::info!("Initiating internal graceful shutdown sequence...");
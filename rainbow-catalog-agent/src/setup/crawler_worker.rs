@@ -0,0 +1,118 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use crate::cache::factory::create_catalog_agent_cache;
+use crate::data::factory_sql::CatalogAgentRepoForSql;
+use crate::entities::peer_catalogs::peer_catalogs::PeerCatalogEntities;
+use crate::protocols::dsp::crawler::crawler::CatalogCrawlerService;
+use crate::protocols::dsp::crawler::CatalogCrawlerTrait;
+use crate::protocols::dsp::facades::well_known_rpc_facade::well_known_rpc_facade::WellKnownRPCFacadeForDSProtocol;
+use crate::protocols::dsp::facades::FacadeService;
+use rainbow_common::config::services::CatalogConfig;
+use rainbow_common::config::traits::CommonConfigTrait;
+use rainbow_common::facades::ssi_auth_facade::mates_facade::MatesFacadeService;
+use rainbow_common::facades::ssi_auth_facade::peer_token_facade::PeerTokenFacadeService;
+use rainbow_common::http_client::HttpClient;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tokio_util::sync::CancellationToken;
+use ymir::services::vault::vault_rs::VaultService;
+use ymir::services::vault::VaultTrait;
+
+/// How often the crawler wakes up to look for peers whose schedule is due
+const CRAWLER_TICK: Duration = Duration::from_secs(30);
+
+pub struct CatalogCrawlerWorker {}
+
+impl CatalogCrawlerWorker {
+    pub async fn spawn(
+        config: &CatalogConfig,
+        vault: Arc<VaultService>,
+        token: &CancellationToken,
+    ) -> anyhow::Result<JoinHandle<()>> {
+        let crawler = Self::create_crawler(config, vault.clone()).await?;
+        tracing::info!("Federated catalog crawler running every {:?}", CRAWLER_TICK);
+
+        let token = token.clone();
+        let handle = tokio::spawn(async move {
+            let mut interval = tokio::time::interval(CRAWLER_TICK);
+            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            loop {
+                tokio::select! {
+                    _ = token.cancelled() => {
+                        tracing::info!("Crawler received shutdown signal");
+                        break;
+                    }
+                    _ = interval.tick() => {
+                        if let Err(e) = crawler.crawl_due_peers().await {
+                            tracing::error!("Crawler round failed: {}", e);
+                        }
+                    }
+                }
+            }
+        });
+
+        Ok(handle)
+    }
+
+    async fn create_crawler(
+        config: &CatalogConfig,
+        vault: Arc<VaultService>,
+    ) -> anyhow::Result<Arc<dyn CatalogCrawlerTrait>> {
+        // conn
+        let db_connection = vault.get_db_connection(config.common()).await;
+        let http_client = Arc::new(HttpClient::new(10, 3));
+        let config = Arc::new(config.clone());
+
+        // repo
+        let catalog_agent_cache = create_catalog_agent_cache(config).await?;
+        let catalog_agent_repo =
+            Arc::new(CatalogAgentRepoForSql::create_repo(db_connection.clone()));
+
+        // entities
+        let peer_catalog_service = Arc::new(PeerCatalogEntities::new(
+            catalog_agent_repo.clone(),
+            catalog_agent_cache,
+        ));
+
+        // facades
+        let ssi_auth_config = Arc::new(config.ssi_auth());
+        let mates_facade =
+            Arc::new(MatesFacadeService::new(ssi_auth_config.clone(), http_client.clone()));
+        let peer_token_facade = Arc::new(PeerTokenFacadeService::new(
+            ssi_auth_config.clone(),
+            http_client.clone(),
+            mates_facade.clone(),
+        ));
+        let catalog_well_known_rpc_facade = Arc::new(WellKnownRPCFacadeForDSProtocol::new(
+            config.clone(),
+            http_client.clone(),
+        ));
+        let facades = Arc::new(FacadeService::new(catalog_well_known_rpc_facade));
+
+        Ok(Arc::new(CatalogCrawlerService::new(
+            peer_catalog_service,
+            mates_facade,
+            peer_token_facade,
+            facades,
+            http_client,
+        )))
+    }
+}
@@ -21,6 +21,7 @@ use crate::data::migrations::get_catalog_migrations;
 use rainbow_common::config::services::CatalogConfig;
 use rainbow_common::config::traits::CommonConfigTrait;
 use rainbow_connector::get_connector_migrations;
+use rainbow_events::data::migrations::get_events_migrations;
 use sea_orm::Database;
 use sea_orm_migration::{MigrationTrait, MigratorTrait};
 use std::sync::Arc;
@@ -34,8 +35,10 @@ impl MigratorTrait for CatalogAgentMigration {
         let mut migrations: Vec<Box<dyn MigrationTrait>> = vec![];
         let mut catalog_migrations = get_catalog_migrations();
         let mut connector_migrations = get_connector_migrations();
+        let mut events_migrations = get_events_migrations();
         migrations.append(&mut catalog_migrations);
         migrations.append(&mut connector_migrations);
+        migrations.append(&mut events_migrations);
         migrations
     }
 }
@@ -19,7 +19,7 @@
  *
  */
 
-use crate::cache::factory_redis::CatalogAgentCacheForRedis;
+use crate::cache::factory::create_catalog_agent_cache;
 use crate::data::factory_sql::CatalogAgentRepoForSql;
 use crate::entities::catalogs::catalogs::CatalogEntities;
 use crate::entities::data_services::data_services::DataServiceEntities;
@@ -47,7 +47,11 @@ use crate::http::distributions::DistributionEntityRouter;
 use crate::http::odrl_policies::OdrlOfferEntityRouter;
 use crate::http::policy_templates::PolicyTemplateEntityRouter;
 use rainbow_common::config::services::CatalogConfig;
-use rainbow_common::config::traits::{CacheConfigTrait, CommonConfigTrait};
+use rainbow_common::config::traits::CommonConfigTrait;
+use rainbow_events::core::notification::notification::RainbowEventsNotificationsService;
+use rainbow_events::core::notification::notification_publisher::RainbowEventsPublisher;
+use rainbow_events::data::repo::sql::EventsRepoForSql;
+use rainbow_events::data::repo::EventsRepoFactory;
 use sea_orm::Database;
 use std::sync::Arc;
 use tokio::net::TcpListener;
@@ -97,43 +101,49 @@ impl CatalogGrpcWorker {
     ) -> anyhow::Result<tonic::transport::server::Router> {
         // conn
         let db_connection = vault.get_db_connection(config.common()).await;
-        let cache_connection_url = config.get_full_cache_url();
-        let redis_client = redis::Client::open(cache_connection_url)?;
-        let redis_connection =
-            redis_client.get_multiplexed_async_connection().await.expect("Redis connection failed");
 
         // repo
-        let catalog_agent_cache =
-            Arc::new(CatalogAgentCacheForRedis::create_repo(redis_connection));
+        let catalog_agent_cache = create_catalog_agent_cache(config).await?;
         let catalog_agent_repo =
             Arc::new(CatalogAgentRepoForSql::create_repo(db_connection.clone()));
 
+        // events
+        let events_repo = Arc::new(EventsRepoForSql::create_repo(db_connection.clone()));
+        let events_publisher = Arc::new(RainbowEventsPublisher::new(Arc::new(
+            RainbowEventsNotificationsService::new(events_repo),
+        )));
+
         // entities
         let catalog_controller_service = Arc::new(CatalogEntities::new(
             catalog_agent_repo.clone(),
             catalog_agent_cache.clone(),
+            events_publisher.clone(),
         ));
         let catalog_router = CatalogEntityGrpc::new(catalog_controller_service.clone());
         let data_services_controller_service = Arc::new(DataServiceEntities::new(
             catalog_agent_repo.clone(),
             catalog_agent_cache.clone(),
+            events_publisher.clone(),
         ));
         let data_services_router =
             DataServiceEntityGrpc::new(data_services_controller_service.clone());
         let datasets_controller_service = Arc::new(DatasetEntities::new(
             catalog_agent_repo.clone(),
             catalog_agent_cache.clone(),
+            events_publisher.clone(),
         ));
         let datasets_router = DatasetEntityGrpc::new(datasets_controller_service.clone());
         let distributions_controller_service = Arc::new(DistributionEntities::new(
             catalog_agent_repo.clone(),
             catalog_agent_cache.clone(),
+            events_publisher.clone(),
         ));
         let distributions_router =
             DistributionEntityGrpc::new(distributions_controller_service.clone());
         let odrl_offer_controller_service = Arc::new(OdrlPolicyEntities::new(
             catalog_agent_repo.clone(),
             catalog_agent_cache.clone(),
+            events_publisher.clone(),
         ));
         let odrl_offer_router = OdrlPolicyEntityGrpc::new(odrl_offer_controller_service.clone());
         let policy_templates_controller_service =
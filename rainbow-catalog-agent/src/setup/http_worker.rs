@@ -16,8 +16,9 @@
  *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
  *
  */
-use crate::cache::factory_redis::CatalogAgentCacheForRedis;
+use crate::cache::factory::create_catalog_agent_cache;
 use crate::data::factory_sql::CatalogAgentRepoForSql;
+use crate::entities::catalog_tree::catalog_tree::CatalogTreeEntities;
 use crate::entities::catalogs::catalogs::CatalogEntities;
 use crate::entities::data_services::data_services::DataServiceEntities;
 use crate::entities::datasets::datasets::DatasetEntities;
@@ -29,22 +30,32 @@ use crate::entities::policy_templates::policy_templates::PolicyTemplateEntities;
 use crate::http::catalogs::CatalogEntityRouter;
 use crate::http::data_services::DataServiceEntityRouter;
 use crate::http::datasets::DatasetEntityRouter;
+use crate::http::dcat_ap::DcatApRouter;
 use crate::http::distributions::DistributionEntityRouter;
 use crate::http::odrl_policies::OdrlOfferEntityRouter;
 use crate::http::peer_catalog::PeerCatalogEntityRouter;
 use crate::http::policy_templates::PolicyTemplateEntityRouter;
+use crate::protocols::dcat_ap::dcat_ap::DcatApService;
 use crate::protocols::dsp::CatalogDSP;
 use crate::protocols::protocol::ProtocolPluginTrait;
 use axum::extract::Request;
 use axum::response::IntoResponse;
 use axum::{serve, Router};
 use rainbow_common::config::services::CatalogConfig;
-use rainbow_common::config::traits::{CacheConfigTrait, CommonConfigTrait};
+use rainbow_common::config::traits::CommonConfigTrait;
 use rainbow_common::errors::CommonErrors;
 use rainbow_common::facades::ssi_auth_facade::mates_facade::MatesFacadeService;
 use rainbow_common::http_client::HttpClient;
 use rainbow_common::well_known::WellKnownRoot;
 use rainbow_connector::ConnectorSetup;
+use rainbow_events::core::notification::notification::RainbowEventsNotificationsService;
+use rainbow_events::core::notification::notification_publisher::RainbowEventsPublisher;
+use rainbow_events::core::subscription::subscription::RainbowEventsSubscriptionService;
+use rainbow_events::core::subscription::subscription_types::SubscriptionEntities;
+use rainbow_events::data::repo::sql::EventsRepoForSql;
+use rainbow_events::data::repo::EventsRepoFactory;
+use rainbow_events::http::notification::notification::RainbowEventsNotificationRouter;
+use rainbow_events::http::subscription::subscription::RainbowEventsSubscriptionRouter;
 use sea_orm::Database;
 use std::ops::Deref;
 use std::sync::Arc;
@@ -131,16 +142,26 @@ pub async fn create_root_http_router(
     // ROOT Dependency Injection
     let db_connection = vault.get_db_connection(config.common()).await;
     let config = Arc::new(config.clone());
-    let cache_connection_url = config.get_full_cache_url();
-    let redis_client = redis::Client::open(cache_connection_url)?;
-    let redis_connection =
-        redis_client.get_multiplexed_async_connection().await.expect("Redis connection failed");
     let http_client = Arc::new(HttpClient::new(20, 3));
 
     // repo
-    let catalog_agent_cache = Arc::new(CatalogAgentCacheForRedis::create_repo(redis_connection));
+    let catalog_agent_cache = create_catalog_agent_cache(&config).await?;
     let catalog_agent_repo = Arc::new(CatalogAgentRepoForSql::create_repo(db_connection.clone()));
 
+    // events
+    let events_repo = Arc::new(EventsRepoForSql::create_repo(db_connection.clone()));
+    let subscription_service = Arc::new(RainbowEventsSubscriptionService::new(events_repo.clone()));
+    let notification_service = Arc::new(RainbowEventsNotificationsService::new(events_repo));
+    let events_publisher = Arc::new(RainbowEventsPublisher::new(notification_service.clone()));
+    let subscription_router = RainbowEventsSubscriptionRouter::new(
+        subscription_service,
+        Some(SubscriptionEntities::Catalog),
+    );
+    let notification_router = RainbowEventsNotificationRouter::new(
+        notification_service,
+        Some(SubscriptionEntities::Catalog),
+    );
+
     // facades
     let ssi_auth_config = Arc::new(config.ssi_auth());
     let mates_facade =
@@ -150,30 +171,35 @@ pub async fn create_root_http_router(
     let catalog_controller_service = Arc::new(CatalogEntities::new(
         catalog_agent_repo.clone(),
         catalog_agent_cache.clone(),
+        events_publisher.clone(),
     ));
     let catalog_router =
         CatalogEntityRouter::new(catalog_controller_service.clone(), config.clone());
     let data_services_controller_service = Arc::new(DataServiceEntities::new(
         catalog_agent_repo.clone(),
         catalog_agent_cache.clone(),
+        events_publisher.clone(),
     ));
     let data_services_router =
         DataServiceEntityRouter::new(data_services_controller_service.clone(), config.clone());
     let datasets_controller_service = Arc::new(DatasetEntities::new(
         catalog_agent_repo.clone(),
         catalog_agent_cache.clone(),
+        events_publisher.clone(),
     ));
     let datasets_router =
         DatasetEntityRouter::new(datasets_controller_service.clone(), config.clone());
     let distributions_controller_service = Arc::new(DistributionEntities::new(
         catalog_agent_repo.clone(),
         catalog_agent_cache.clone(),
+        events_publisher.clone(),
     ));
     let distributions_router =
         DistributionEntityRouter::new(distributions_controller_service.clone(), config.clone());
     let odrl_offer_controller_service = Arc::new(OdrlPolicyEntities::new(
         catalog_agent_repo.clone(),
         catalog_agent_cache.clone(),
+        events_publisher.clone(),
     ));
     let odrl_offer_router =
         OdrlOfferEntityRouter::new(odrl_offer_controller_service.clone(), config.clone());
@@ -189,8 +215,21 @@ pub async fn create_root_http_router(
         policy_engine_service.clone(),
         config.clone(),
     );
-    let peer_catalog_service = Arc::new(PeerCatalogEntities::new(catalog_agent_cache.clone()));
+    let peer_catalog_service = Arc::new(PeerCatalogEntities::new(
+        catalog_agent_repo.clone(),
+        catalog_agent_cache.clone(),
+    ));
     let peer_catalog_router = PeerCatalogEntityRouter::new(peer_catalog_service.clone());
+    let catalog_tree_service = Arc::new(CatalogTreeEntities::new(catalog_agent_repo.clone()));
+    let dcat_ap_service = Arc::new(DcatApService::new(
+        catalog_tree_service.clone(),
+        peer_catalog_service.clone(),
+        catalog_controller_service.clone(),
+        data_services_controller_service.clone(),
+        datasets_controller_service.clone(),
+        distributions_controller_service.clone(),
+    ));
+    let dcat_ap_router = DcatApRouter::new(dcat_ap_service.clone());
 
     // connector module
     let connector_router =
@@ -198,11 +237,7 @@ pub async fn create_root_http_router(
 
     // dsp
     let dsp_router = CatalogDSP::new(
-        catalog_controller_service.clone(),
-        data_services_controller_service.clone(),
-        datasets_controller_service.clone(),
-        odrl_offer_controller_service.clone(),
-        distributions_controller_service.clone(),
+        catalog_tree_service.clone(),
         peer_catalog_service.clone(),
         mates_facade.clone(),
         config.clone(),
@@ -241,6 +276,14 @@ pub async fn create_root_http_router(
             format!("{}/peer-catalogs", catalog_router_str.as_str()).as_str(),
             peer_catalog_router.router(),
         )
+        .nest(
+            format!("{}/dcat-ap", catalog_router_str.as_str()).as_str(),
+            dcat_ap_router.router(),
+        )
+        .nest(
+            catalog_router_str.as_str(),
+            subscription_router.router().merge(notification_router.router()),
+        )
         .nest("/dsp/current/catalog", dsp_router)
         .nest(connector_router_str.as_str(), connector_router);
 
@@ -19,6 +19,7 @@
 
 mod boot;
 pub mod cmd;
+mod crawler_worker;
 mod db_migrations;
 mod grpc_worker;
 mod http_worker;
@@ -80,6 +80,18 @@ pub enum DataPlaneSDPConfigTypes {
     NextHopAddressAuthType,
     #[serde(rename = "Direction")]
     Direction,
+    #[serde(rename = "UpstreamHopAddressScheme")]
+    UpstreamHopAddressScheme,
+    #[serde(rename = "UpstreamHopAddress")]
+    UpstreamHopAddress,
+    #[serde(rename = "UpstreamHopAddressAuthType")]
+    UpstreamHopAddressAuthType,
+    #[serde(rename = "UpstreamHopAddressAuth")]
+    UpstreamHopAddressAuth,
+    #[serde(rename = "DeliveryInterval")]
+    DeliveryInterval,
+    #[serde(rename = "DeliveryMaxRetries")]
+    DeliveryMaxRetries,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -19,3 +19,4 @@
 
 pub mod business;
 pub mod header;
+pub mod ssi;
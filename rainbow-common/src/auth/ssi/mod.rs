@@ -0,0 +1,172 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use crate::dsp_common::context_field::ContextField;
+use crate::errors::{CommonErrors, ErrorLog};
+use crate::facades::ssi_auth_facade::SSIAuthFacadeTrait;
+use crate::mates::Mates;
+use axum::extract::{Request, State};
+use axum::http::header::AUTHORIZATION;
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use axum::{middleware, Json, Router};
+use serde_json::json;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::RwLock;
+use tracing::{error, warn};
+
+pub const DEFAULT_PEER_CACHE_TTL: Duration = Duration::from_secs(300);
+
+/// Peer that presented a valid SSI token on a DSP endpoint
+#[derive(Debug, Clone)]
+pub struct VerifiedPeer {
+    pub participant_id: String,
+    pub mate: Mates,
+}
+
+tokio::task_local! {
+    static VERIFIED_PEER: Arc<VerifiedPeer>;
+}
+
+/// Peer of the DSP request being handled, if it went through `verify_ssi_token`.
+/// Orchestrators use it to bind processes and agreements to the caller.
+pub fn current_peer() -> Option<Arc<VerifiedPeer>> {
+    VERIFIED_PEER.try_with(|peer| peer.clone()).ok()
+}
+
+pub fn current_participant_id() -> Option<String> {
+    current_peer().map(|peer| peer.participant_id.clone())
+}
+
+struct CachedPeer {
+    peer: Arc<VerifiedPeer>,
+    expires_at: Instant,
+}
+
+/// State of the SSI auth middleware. Verified tokens are cached for `ttl`
+/// so the SSI service is not hit on every DSP message.
+#[derive(Clone)]
+pub struct SsiAuthLayer {
+    ssi_auth_facade: Arc<dyn SSIAuthFacadeTrait>,
+    cache: Arc<RwLock<HashMap<String, CachedPeer>>>,
+    ttl: Duration,
+    dsp_error_type: &'static str,
+}
+
+impl SsiAuthLayer {
+    /// `dsp_error_type` is the DSP error message of the protocol being protected,
+    /// i.e. `CatalogError`, `ContractNegotiationError` or `TransferError`
+    pub fn new(
+        ssi_auth_facade: Arc<dyn SSIAuthFacadeTrait>,
+        ttl: Duration,
+        dsp_error_type: &'static str,
+    ) -> Self {
+        Self { ssi_auth_facade, cache: Arc::new(RwLock::new(HashMap::new())), ttl, dsp_error_type }
+    }
+
+    async fn resolve_peer(&self, token: &str) -> anyhow::Result<Arc<VerifiedPeer>> {
+        if let Some(cached) = self.cache.read().await.get(token) {
+            if cached.expires_at > Instant::now() {
+                return Ok(cached.peer.clone());
+            }
+        }
+        let mate = self.ssi_auth_facade.verify_token(token.to_string()).await?;
+        let peer = Arc::new(VerifiedPeer { participant_id: mate.participant_id.clone(), mate });
+        let now = Instant::now();
+        let mut cache = self.cache.write().await;
+        cache.retain(|_, cached| cached.expires_at > now);
+        cache.insert(
+            token.to_string(),
+            CachedPeer { peer: peer.clone(), expires_at: now + self.ttl },
+        );
+        Ok(peer)
+    }
+
+    pub async fn invalidate(&self, token: &str) {
+        self.cache.write().await.remove(token);
+    }
+
+    fn reject(&self, err: CommonErrors) -> Response {
+        error!("{}", err.log());
+        let (CommonErrors::UnauthorizedError { info, .. }
+        | CommonErrors::ForbiddenError { info, .. }) = &err
+        else {
+            return (&err).into_response();
+        };
+        (
+            info.status_code,
+            Json(json!({
+                "@context": ContextField::default(),
+                "@type": self.dsp_error_type,
+                "code": info.error_code.to_string(),
+                "reason": [info.cause, info.message]
+            })),
+        )
+            .into_response()
+    }
+}
+
+/// Token of an `Authorization: Bearer <token>` header value. The scheme is case
+/// insensitive (RFC 7235), any other scheme or an empty token gives `None`.
+pub fn bearer_token(header_value: &str) -> Option<String> {
+    let (scheme, token) = header_value.trim().split_once(' ')?;
+    if !scheme.eq_ignore_ascii_case("Bearer") {
+        return None;
+    }
+    let token = token.trim();
+    (!token.is_empty()).then(|| token.to_string())
+}
+
+/// Axum middleware for DSP routers, use with `middleware::from_fn_with_state`.
+/// Requests without a valid bearer token from a known peer are rejected with a DSP error.
+pub async fn verify_ssi_token(
+    State(layer): State<SsiAuthLayer>,
+    mut request: Request,
+    next: Next,
+) -> Response {
+    let token = request.headers().get(AUTHORIZATION).and_then(|value| value.to_str().ok());
+    let Some(token) = token.and_then(bearer_token) else {
+        return layer.reject(CommonErrors::unauthorized_new("Missing bearer token"));
+    };
+    let peer = match layer.resolve_peer(&token).await {
+        Ok(peer) => peer,
+        Err(e) => {
+            return layer.reject(CommonErrors::unauthorized_new(
+                format!("Token could not be verified for a known peer: {}", e).as_str(),
+            ))
+        }
+    };
+    request.extensions_mut().insert(peer.clone());
+    VERIFIED_PEER.scope(peer, next.run(request)).await
+}
+
+/// Puts `verify_ssi_token` in front of a DSP router. With `enabled` off the router is
+/// left open and orchestrators see no verified peer, meant for closed networks only.
+pub fn with_ssi_auth(router: Router, layer: SsiAuthLayer, enabled: bool) -> Router {
+    if !enabled {
+        warn!(
+            "SSI token verification is disabled, {} endpoints are open",
+            layer.dsp_error_type
+        );
+        return router;
+    }
+    router.layer(middleware::from_fn_with_state(layer, verify_ssi_token))
+}
@@ -29,6 +29,14 @@ pub struct CommonConfig {
     pub db: DatabaseConfig,
     pub api: ApiConfig,
     pub is_local: bool,
+    /// Require SSI bearer tokens on inbound DSP endpoints. Only turn it off on
+    /// closed networks, peers then act without a verified identity
+    #[serde(default = "default_verify_ssi_tokens")]
+    pub verify_ssi_tokens: bool,
+}
+
+fn default_verify_ssi_tokens() -> bool {
+    true
 }
 
 impl CommonConfig {
@@ -44,6 +52,9 @@ impl CommonConfig {
     pub fn is_local(&self) -> bool {
         self.is_local
     }
+    pub fn verify_ssi_tokens(&self) -> bool {
+        self.verify_ssi_tokens
+    }
 }
 
 impl HostsConfigTrait for CommonConfig {
@@ -19,22 +19,33 @@
 
 use crate::config::services::{CommonConfig, MinKnownConfig};
 use crate::config::traits::{CommonConfigTrait, ConfigLoader};
+use crate::config::types::auto_responder::AutoResponderRule;
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct ContractsConfig {
     common: CommonConfig,
     ssi_auth: MinKnownConfig,
+    catalog: MinKnownConfig,
     is_catalog_datahub: bool,
+    #[serde(default)]
+    auto_responder: Vec<AutoResponderRule>,
 }
 
 impl ContractsConfig {
     pub fn ssi_auth(&self) -> MinKnownConfig {
         self.ssi_auth.clone()
     }
+    pub fn catalog(&self) -> MinKnownConfig {
+        self.catalog.clone()
+    }
     pub fn is_catalog_datahub(&self) -> bool {
         self.is_catalog_datahub
     }
+    /// Rules are evaluated in order, the first matching one decides
+    pub fn auto_responder_rules(&self) -> Vec<AutoResponderRule> {
+        self.auto_responder.clone()
+    }
 }
 
 impl ConfigLoader for ContractsConfig {
@@ -19,6 +19,8 @@
 
 use crate::config::services::{CommonConfig, MinKnownConfig};
 use crate::config::traits::{CommonConfigTrait, ConfigLoader};
+use crate::config::types::agreement_signatures::AgreementSignaturePolicy;
+use crate::config::types::data_plane_forwarding::DataPlaneForwardingConfig;
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -28,6 +30,12 @@ pub struct TransferConfig {
     catalog: MinKnownConfig,
     is_catalog_datahub: bool,
     ssi_auth: MinKnownConfig,
+    #[serde(default)]
+    duty_callback_allowlist: Vec<String>,
+    #[serde(default)]
+    agreement_signatures: AgreementSignaturePolicy,
+    #[serde(default)]
+    data_plane_forwarding: DataPlaneForwardingConfig,
 }
 
 impl TransferConfig {
@@ -43,6 +51,19 @@ impl TransferConfig {
     pub fn is_catalog_datahub(&self) -> bool {
         self.is_catalog_datahub
     }
+    /// Base urls callback duties may post to, none are allowed when empty
+    pub fn duty_callback_allowlist(&self) -> &Vec<String> {
+        &self.duty_callback_allowlist
+    }
+    /// Applied to the agreement signatures before a transfer is started and when the data
+    /// plane serves its session
+    pub fn agreement_signatures(&self) -> AgreementSignaturePolicy {
+        self.agreement_signatures
+    }
+    /// Size limits and header and query filters of the sessions the data plane provisions
+    pub fn data_plane_forwarding(&self) -> &DataPlaneForwardingConfig {
+        &self.data_plane_forwarding
+    }
 }
 impl ConfigLoader for TransferConfig {
     fn load(env_file: String) -> Self {
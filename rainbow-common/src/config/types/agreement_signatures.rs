@@ -0,0 +1,52 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// How agreements are gated on the parties' JWS over the agreement content. Signatures
+/// are a Rainbow extension to DSP, other connectors send their messages unsigned.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum AgreementSignaturePolicy {
+    /// Both parties signed and both signatures verify
+    Required,
+    /// A party may leave the agreement unsigned, but a signature that is there must verify
+    #[default]
+    Optional,
+}
+
+impl AgreementSignaturePolicy {
+    /// Whether the signature of one party, as reported by the negotiation agent, is acceptable
+    pub fn accepts(&self, signed: bool, verified: bool) -> bool {
+        match self {
+            AgreementSignaturePolicy::Required => signed && verified,
+            AgreementSignaturePolicy::Optional => !signed || verified,
+        }
+    }
+}
+
+impl Display for AgreementSignaturePolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AgreementSignaturePolicy::Required => write!(f, "required"),
+            AgreementSignaturePolicy::Optional => write!(f, "optional"),
+        }
+    }
+}
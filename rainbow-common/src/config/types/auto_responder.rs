@@ -0,0 +1,70 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt::Display;
+
+/// Pre-approved answer of the provider to incoming contract requests.
+/// Every non empty criterion must match, an empty one matches anything.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct AutoResponderRule {
+    pub name: String,
+    /// ODRL offer ids as published in the catalog
+    #[serde(default)]
+    pub offer_ids: Vec<String>,
+    /// Policy templates the requested offer was instantiated from
+    #[serde(default)]
+    pub policy_templates: Vec<String>,
+    /// Participant ids of the consumer
+    #[serde(default)]
+    pub participants: Vec<String>,
+    /// Attributes of the consumer as verified by the SSI service, i.e. `participant_type`
+    #[serde(default)]
+    pub credentials: BTreeMap<String, String>,
+    pub action: AutoResponderAction,
+    /// Sent as termination reason, and kept in the audit for the other actions
+    #[serde(default)]
+    pub reason: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum AutoResponderAction {
+    /// Counter offer with the requested offer, the rest of the negotiation stays manual
+    Offer,
+    /// Offer, then agree once the consumer accepts and finalize once verified
+    Accept,
+    /// Agree on the requested offer and finalize once verified
+    Agree,
+    /// Terminate the negotiation with the rule's reason
+    Terminate,
+}
+
+impl Display for AutoResponderAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AutoResponderAction::Offer => write!(f, "offer"),
+            AutoResponderAction::Accept => write!(f, "accept"),
+            AutoResponderAction::Agree => write!(f, "agree"),
+            AutoResponderAction::Terminate => write!(f, "terminate"),
+        }
+    }
+}
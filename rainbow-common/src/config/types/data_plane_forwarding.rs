@@ -0,0 +1,42 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use serde::{Deserialize, Serialize};
+
+/// Forwarding rules of the data plane proxy, written into every session it provisions.
+/// Header and query parameter names are case-insensitive, an empty allow list allows
+/// everything not denied.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct DataPlaneForwardingConfig {
+    /// Bytes a consumer may upload, unlimited when left out
+    #[serde(default)]
+    pub max_request_body_size: Option<u64>,
+    /// Bytes the data source may return, unlimited when left out
+    #[serde(default)]
+    pub max_response_body_size: Option<u64>,
+    #[serde(default)]
+    pub headers_allow: Vec<String>,
+    #[serde(default)]
+    pub headers_deny: Vec<String>,
+    #[serde(default)]
+    pub query_allow: Vec<String>,
+    #[serde(default)]
+    pub query_deny: Vec<String>,
+}
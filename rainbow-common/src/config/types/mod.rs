@@ -17,7 +17,10 @@
  *
  */
 
+pub mod agreement_signatures;
+pub mod auto_responder;
 pub mod cache;
+pub mod data_plane_forwarding;
 mod client;
 pub mod roles;
 mod gaia_config;
@@ -22,6 +22,7 @@ use serde_json::Value;
 pub mod context_field;
 pub mod data_address;
 pub mod odrl;
+pub mod odrl_evaluator;
 pub mod well_known_types;
 
 pub fn schema_compiler_util(schema_content: &str) -> Value {
@@ -0,0 +1,186 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use crate::dsp_common::odrl::{OdrlAgreement, OdrlMessageOffer, OdrlOffer, OdrlPolicyInfo};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use urn::Urn;
+
+pub mod odrl_evaluator;
+pub mod odrl_terms;
+
+/// Everything the evaluator knows about the request being authorized.
+/// Left operands not covered by the typed fields are resolved from `attributes`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OdrlRequestContext {
+    pub date_time: DateTime<Utc>,
+    pub purpose: Option<String>,
+    pub assignee: Option<String>,
+    pub target: Option<Urn>,
+    pub spatial: Option<String>,
+    pub counters: HashMap<String, i64>,
+    pub fulfilled_duties: Vec<String>,
+    pub attributes: HashMap<String, Value>,
+}
+
+impl Default for OdrlRequestContext {
+    fn default() -> Self {
+        Self {
+            date_time: Utc::now(),
+            purpose: None,
+            assignee: None,
+            target: None,
+            spatial: None,
+            counters: HashMap::new(),
+            fulfilled_duties: vec![],
+            attributes: HashMap::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum OdrlDecisionOutcome {
+    Permit,
+    Deny,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum OdrlRuleType {
+    Permission,
+    Prohibition,
+    Obligation,
+    Duty,
+}
+
+/// Trace of a single rule evaluation. `fired` is true when the rule applied to the
+/// requested action and all of its constraints were satisfied.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OdrlRuleEvaluation {
+    pub rule_type: OdrlRuleType,
+    pub index: usize,
+    pub action: String,
+    pub fired: bool,
+    pub reasons: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OdrlPolicyDecision {
+    pub outcome: OdrlDecisionOutcome,
+    pub action: String,
+    pub reason: String,
+    pub rules: Vec<OdrlRuleEvaluation>,
+    pub pending_obligations: Vec<String>,
+}
+
+impl OdrlPolicyDecision {
+    pub fn is_permit(&self) -> bool {
+        self.outcome == OdrlDecisionOutcome::Permit
+    }
+    pub fn fired_rules(&self) -> Vec<&OdrlRuleEvaluation> {
+        self.rules.iter().filter(|r| r.fired).collect()
+    }
+}
+
+/// Flattened view of any ODRL policy class, so agreements, offers and
+/// bare policy infos are evaluated by the same code path.
+#[derive(Debug, Clone)]
+pub struct OdrlPolicyView {
+    pub policy: OdrlPolicyInfo,
+    pub target: Option<Urn>,
+    pub assignee: Option<String>,
+}
+
+impl From<&OdrlAgreement> for OdrlPolicyView {
+    fn from(value: &OdrlAgreement) -> Self {
+        Self {
+            policy: OdrlPolicyInfo {
+                profile: value.profile.clone(),
+                permission: value.permission.clone(),
+                obligation: value.obligation.clone(),
+                prohibition: value.prohibition.clone(),
+            },
+            target: Some(value.target.clone()),
+            assignee: Some(value.assignee.clone()).filter(|a| !a.is_empty()),
+        }
+    }
+}
+
+impl From<&OdrlOffer> for OdrlPolicyView {
+    fn from(value: &OdrlOffer) -> Self {
+        Self {
+            policy: OdrlPolicyInfo {
+                profile: value.profile.clone(),
+                permission: value.permission.clone(),
+                obligation: value.obligation.clone(),
+                prohibition: value.prohibition.clone(),
+            },
+            target: value.target.clone(),
+            assignee: None,
+        }
+    }
+}
+
+impl From<&OdrlMessageOffer> for OdrlPolicyView {
+    fn from(value: &OdrlMessageOffer) -> Self {
+        Self {
+            policy: OdrlPolicyInfo {
+                profile: value.profile.clone(),
+                permission: value.permission.clone(),
+                obligation: value.obligation.clone(),
+                prohibition: value.prohibition.clone(),
+            },
+            target: Some(value.target.clone()),
+            assignee: None,
+        }
+    }
+}
+
+impl From<&OdrlPolicyInfo> for OdrlPolicyView {
+    fn from(value: &OdrlPolicyInfo) -> Self {
+        Self { policy: value.clone(), target: None, assignee: None }
+    }
+}
+
+#[mockall::automock]
+pub trait OdrlEvaluatorTrait: Send + Sync {
+    fn evaluate(
+        &self,
+        policy: &OdrlPolicyView,
+        action: &str,
+        context: &OdrlRequestContext,
+    ) -> OdrlPolicyDecision;
+    fn evaluate_agreement(
+        &self,
+        agreement: &OdrlAgreement,
+        action: &str,
+        context: &OdrlRequestContext,
+    ) -> OdrlPolicyDecision {
+        self.evaluate(&OdrlPolicyView::from(agreement), action, context)
+    }
+    fn evaluate_offer(
+        &self,
+        offer: &OdrlOffer,
+        action: &str,
+        context: &OdrlRequestContext,
+    ) -> OdrlPolicyDecision {
+        self.evaluate(&OdrlPolicyView::from(offer), action, context)
+    }
+}
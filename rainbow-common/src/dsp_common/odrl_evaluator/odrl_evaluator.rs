@@ -0,0 +1,469 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use crate::dsp_common::odrl::{
+    OdrlAtomicConstraint, OdrlConstraint, OdrlDuty, OdrlLogicalConstraint, OdrlRightOperand,
+    Operator,
+};
+use crate::dsp_common::odrl_evaluator::odrl_terms::{
+    normalize_term, parse_date_time, unwrap_json_ld,
+};
+use crate::dsp_common::odrl_evaluator::{
+    OdrlDecisionOutcome, OdrlEvaluatorTrait, OdrlPolicyDecision, OdrlPolicyView,
+    OdrlRequestContext, OdrlRuleEvaluation, OdrlRuleType,
+};
+use serde_json::Value;
+use std::cmp::Ordering;
+use tracing::debug;
+
+const ODRL_USE_ACTION: &str = "use";
+const ODRL_TRANSFER_ACTION: &str = "transfer";
+const TERM_SEPARATORS: [char; 5] = ['/', ':', '#', '-', '.'];
+
+/// Deny-overrides evaluator. Prohibitions are checked first, then any permission
+/// whose constraints hold and whose duty is fulfilled grants the action.
+/// Nothing is permitted by default.
+#[derive(Default)]
+pub struct OdrlEvaluatorService;
+
+impl OdrlEvaluatorService {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn evaluate_constraints(
+        &self,
+        constraints: &Option<Vec<OdrlConstraint>>,
+        context: &OdrlRequestContext,
+        reasons: &mut Vec<String>,
+    ) -> bool {
+        // top level constraints of a rule are conjunctive
+        let results = constraints
+            .iter()
+            .flatten()
+            .map(|constraint| self.evaluate_constraint(constraint, context, reasons))
+            .collect::<Vec<_>>();
+        results.into_iter().all(|satisfied| satisfied)
+    }
+
+    fn evaluate_constraint(
+        &self,
+        constraint: &OdrlConstraint,
+        context: &OdrlRequestContext,
+        reasons: &mut Vec<String>,
+    ) -> bool {
+        match constraint {
+            OdrlConstraint::Atomic(atomic) => self.evaluate_atomic(atomic, context, reasons),
+            OdrlConstraint::Logical(logical) => self.evaluate_logical(logical, context, reasons),
+        }
+    }
+
+    fn evaluate_logical(
+        &self,
+        constraint: &OdrlLogicalConstraint,
+        context: &OdrlRequestContext,
+        reasons: &mut Vec<String>,
+    ) -> bool {
+        if let Err(e) = constraint.validate() {
+            reasons.push(format!("Invalid logical constraint: {}", e));
+            return false;
+        }
+        if let Some(constraints) = &constraint.and {
+            let results = constraints
+                .iter()
+                .map(|c| self.evaluate_constraint(c, context, reasons))
+                .collect::<Vec<_>>();
+            return results.into_iter().all(|satisfied| satisfied);
+        }
+        if let Some(constraints) = &constraint.and_sequence {
+            for (index, c) in constraints.iter().enumerate() {
+                if !self.evaluate_constraint(c, context, reasons) {
+                    reasons.push(format!("andSequence stopped at constraint #{}", index));
+                    return false;
+                }
+            }
+            return true;
+        }
+        if let Some(constraints) = &constraint.or {
+            let mut inner_reasons = vec![];
+            let satisfied = constraints
+                .iter()
+                .any(|c| self.evaluate_constraint(c, context, &mut inner_reasons));
+            if !satisfied {
+                reasons.push(format!(
+                    "None of the 'or' constraints were satisfied: {}",
+                    inner_reasons.join("; ")
+                ));
+            }
+            return satisfied;
+        }
+        if let Some(constraints) = &constraint.xone {
+            let mut inner_reasons = vec![];
+            let count = constraints
+                .iter()
+                .filter(|c| self.evaluate_constraint(c, context, &mut inner_reasons))
+                .count();
+            if count != 1 {
+                reasons.push(format!(
+                    "Exactly one 'xone' constraint must be satisfied, found {}",
+                    count
+                ));
+            }
+            return count == 1;
+        }
+        false
+    }
+
+    fn evaluate_atomic(
+        &self,
+        constraint: &OdrlAtomicConstraint,
+        context: &OdrlRequestContext,
+        reasons: &mut Vec<String>,
+    ) -> bool {
+        let left_operand = normalize_term(&constraint.left_operand);
+        let left = match resolve_left_operand(left_operand, context) {
+            Some(left) => left,
+            None => {
+                reasons.push(format!(
+                    "Left operand '{}' is not available in the request context",
+                    left_operand
+                ));
+                return false;
+            }
+        };
+        let right = right_operand_value(&constraint.right_operand);
+        let satisfied = apply_operator(&constraint.operator, &left, &right);
+        if !satisfied {
+            reasons.push(format!(
+                "Constraint '{} {:?} {}' is not satisfied by {}",
+                left_operand, constraint.operator, right, left
+            ));
+        }
+        satisfied
+    }
+
+    /// Records the duty evaluation and returns true when the duty is still pending,
+    /// i.e. its constraints hold and the context doesn't report it as fulfilled.
+    fn evaluate_duty(
+        &self,
+        rule_type: OdrlRuleType,
+        index: usize,
+        duty: &OdrlDuty,
+        context: &OdrlRequestContext,
+        rules: &mut Vec<OdrlRuleEvaluation>,
+    ) -> bool {
+        let mut reasons = vec![];
+        let active = self.evaluate_constraints(&duty.constraint, context, &mut reasons);
+        let fulfilled = context
+            .fulfilled_duties
+            .iter()
+            .any(|d| normalize_term(d) == normalize_term(&duty.action));
+        if active {
+            let state = if fulfilled { "fulfilled" } else { "not fulfilled" };
+            reasons.push(format!("Duty '{}' is {}", duty.action, state));
+        }
+        rules.push(OdrlRuleEvaluation {
+            rule_type,
+            index,
+            action: duty.action.clone(),
+            fired: active,
+            reasons,
+        });
+        active && !fulfilled
+    }
+
+    fn decision(
+        &self,
+        outcome: OdrlDecisionOutcome,
+        action: &str,
+        reason: String,
+        rules: Vec<OdrlRuleEvaluation>,
+        pending_obligations: Vec<String>,
+    ) -> OdrlPolicyDecision {
+        debug!("ODRL decision for action '{}': {:?}. {}", action, outcome, reason);
+        OdrlPolicyDecision {
+            outcome,
+            action: action.to_string(),
+            reason,
+            rules,
+            pending_obligations,
+        }
+    }
+}
+
+impl OdrlEvaluatorTrait for OdrlEvaluatorService {
+    fn evaluate(
+        &self,
+        policy: &OdrlPolicyView,
+        action: &str,
+        context: &OdrlRequestContext,
+    ) -> OdrlPolicyDecision {
+        let mut rules = vec![];
+
+        // policy scope
+        if let (Some(requested), Some(target)) = (&context.target, &policy.target) {
+            if requested != target {
+                let reason =
+                    format!("Requested target {} is not the policy target {}", requested, target);
+                return self.decision(OdrlDecisionOutcome::Deny, action, reason, rules, vec![]);
+            }
+        }
+        if let (Some(requested), Some(assignee)) = (&context.assignee, &policy.assignee) {
+            if requested != assignee {
+                let reason = format!(
+                    "Requesting party {} is not the policy assignee {}",
+                    requested, assignee
+                );
+                return self.decision(OdrlDecisionOutcome::Deny, action, reason, rules, vec![]);
+            }
+        }
+
+        // obligations don't block the decision, they are reported back to the caller
+        let mut pending_obligations = vec![];
+        for (index, obligation) in policy.policy.obligation.iter().flatten().enumerate() {
+            if self.evaluate_duty(OdrlRuleType::Obligation, index, obligation, context, &mut rules)
+            {
+                pending_obligations.push(obligation.action.clone());
+            }
+        }
+
+        // prohibitions
+        for (index, prohibition) in policy.policy.prohibition.iter().flatten().enumerate() {
+            if !action_matches(&prohibition.action, action) {
+                continue;
+            }
+            let mut reasons = vec![];
+            let fired = self.evaluate_constraints(&prohibition.constraint, context, &mut reasons);
+            rules.push(OdrlRuleEvaluation {
+                rule_type: OdrlRuleType::Prohibition,
+                index,
+                action: prohibition.action.clone(),
+                fired,
+                reasons,
+            });
+        }
+        let fired_prohibition = rules
+            .iter()
+            .find(|r| r.rule_type == OdrlRuleType::Prohibition && r.fired)
+            .map(|r| r.index);
+        if let Some(index) = fired_prohibition {
+            let reason = format!("Action '{}' is prohibited by prohibition #{}", action, index);
+            return self.decision(
+                OdrlDecisionOutcome::Deny,
+                action,
+                reason,
+                rules,
+                pending_obligations,
+            );
+        }
+
+        // permissions
+        let mut applicable = false;
+        let mut granted_by = None;
+        for (index, permission) in policy.policy.permission.iter().flatten().enumerate() {
+            if !action_matches(&permission.action, action) {
+                continue;
+            }
+            applicable = true;
+            let mut reasons = vec![];
+            let constraints_ok =
+                self.evaluate_constraints(&permission.constraint, context, &mut reasons);
+            let duty_pending = match &permission.duty {
+                Some(duty) => {
+                    self.evaluate_duty(OdrlRuleType::Duty, index, duty, context, &mut rules)
+                }
+                None => false,
+            };
+            if duty_pending {
+                reasons.push("Permission duty must be fulfilled before exercising it".to_string());
+            }
+            let fired = constraints_ok && !duty_pending;
+            rules.push(OdrlRuleEvaluation {
+                rule_type: OdrlRuleType::Permission,
+                index,
+                action: permission.action.clone(),
+                fired,
+                reasons,
+            });
+            if fired && granted_by.is_none() {
+                granted_by = Some(index);
+            }
+        }
+
+        match (granted_by, applicable) {
+            (Some(index), _) => {
+                let reason = format!("Action '{}' is granted by permission #{}", action, index);
+                self.decision(
+                    OdrlDecisionOutcome::Permit,
+                    action,
+                    reason,
+                    rules,
+                    pending_obligations,
+                )
+            }
+            (None, true) => {
+                let reason = format!(
+                    "No permission for action '{}' has its constraints and duties satisfied",
+                    action
+                );
+                self.decision(OdrlDecisionOutcome::Deny, action, reason, rules, pending_obligations)
+            }
+            (None, false) => {
+                let reason = format!("No permission grants action '{}'", action);
+                self.decision(OdrlDecisionOutcome::Deny, action, reason, rules, pending_obligations)
+            }
+        }
+    }
+}
+
+/// `use` is the parent of every ODRL action but `transfer`.
+fn action_matches(rule_action: &str, requested_action: &str) -> bool {
+    let rule_action = normalize_term(rule_action);
+    let requested_action = normalize_term(requested_action);
+    rule_action == requested_action
+        || (rule_action == ODRL_USE_ACTION && requested_action != ODRL_TRANSFER_ACTION)
+}
+
+fn resolve_left_operand(left_operand: &str, context: &OdrlRequestContext) -> Option<Value> {
+    let typed = match left_operand {
+        "dateTime" => Some(Value::String(context.date_time.to_rfc3339())),
+        "purpose" => context.purpose.clone().map(Value::String),
+        "assignee" | "recipient" => context.assignee.clone().map(Value::String),
+        "spatial" => context.spatial.clone().map(Value::String),
+        _ => None,
+    };
+    typed
+        .or_else(|| context.counters.get(left_operand).map(|c| Value::from(*c)))
+        .or_else(|| context.attributes.get(left_operand).cloned())
+}
+
+fn right_operand_value(right_operand: &OdrlRightOperand) -> Value {
+    match right_operand {
+        OdrlRightOperand::Str(value) => Value::String(value.clone()),
+        OdrlRightOperand::Object(value) => unwrap_json_ld(&Value::Object(value.clone())),
+        OdrlRightOperand::Array(values) => {
+            Value::Array(values.iter().map(unwrap_json_ld).collect())
+        }
+    }
+}
+
+fn as_items(value: &Value) -> Vec<Value> {
+    match unwrap_json_ld(value) {
+        Value::Array(values) => values.iter().map(unwrap_json_ld).collect(),
+        other => vec![other],
+    }
+}
+
+fn as_text(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) => Some(s.clone()),
+        Value::Number(n) => Some(n.to_string()),
+        Value::Bool(b) => Some(b.to_string()),
+        _ => None,
+    }
+}
+
+fn as_number(value: &Value) -> Option<f64> {
+    match value {
+        Value::Number(n) => n.as_f64(),
+        Value::String(s) => s.trim().parse::<f64>().ok(),
+        _ => None,
+    }
+}
+
+fn compare_values(left: &Value, right: &Value) -> Option<Ordering> {
+    if let (Some(left), Some(right)) = (parse_date_time(left), parse_date_time(right)) {
+        return Some(left.cmp(&right));
+    }
+    if let (Some(left), Some(right)) = (as_number(left), as_number(right)) {
+        return left.partial_cmp(&right);
+    }
+    None
+}
+
+fn values_equal(left: &Value, right: &Value) -> bool {
+    let (left, right) = (unwrap_json_ld(left), unwrap_json_ld(right));
+    match (&left, &right) {
+        (Value::Array(_), _) | (_, Value::Array(_)) => {
+            let (left_items, right_items) = (as_items(&left), as_items(&right));
+            left_items.len() == right_items.len()
+                && left_items.iter().all(|l| right_items.iter().any(|r| values_equal(l, r)))
+        }
+        _ => match compare_values(&left, &right) {
+            Some(ordering) => ordering == Ordering::Equal,
+            None => {
+                left == right || (as_text(&left).is_some() && as_text(&left) == as_text(&right))
+            }
+        },
+    }
+}
+
+/// Taxonomy check for terms like `ES:MD` under `ES` or `purpose/research/medical` under `purpose/research`.
+fn is_narrower_or_equal(term: &Value, parent: &Value) -> bool {
+    if values_equal(term, parent) {
+        return true;
+    }
+    match (as_text(term), as_text(parent)) {
+        (Some(term), Some(parent)) => term
+            .strip_prefix(parent.as_str())
+            .and_then(|rest| rest.chars().next())
+            .map(|c| TERM_SEPARATORS.contains(&c))
+            .unwrap_or(false),
+        _ => false,
+    }
+}
+
+fn apply_operator(operator: &Operator, left: &Value, right: &Value) -> bool {
+    let left_items = as_items(left);
+    let right_items = as_items(right);
+    match operator {
+        Operator::Eq => values_equal(left, right),
+        Operator::Neq => !values_equal(left, right),
+        Operator::Gt => compare_values(left, right) == Some(Ordering::Greater),
+        Operator::Gteq => {
+            matches!(compare_values(left, right), Some(Ordering::Greater | Ordering::Equal))
+        }
+        Operator::Lt => compare_values(left, right) == Some(Ordering::Less),
+        Operator::Lteq => {
+            matches!(compare_values(left, right), Some(Ordering::Less | Ordering::Equal))
+        }
+        Operator::TermLteq => match compare_values(left, right) {
+            Some(ordering) => ordering != Ordering::Greater,
+            None => is_narrower_or_equal(left, right),
+        },
+        Operator::IsA => {
+            left_items.iter().any(|l| right_items.iter().any(|r| is_narrower_or_equal(l, r)))
+        }
+        Operator::HasPart => {
+            right_items.iter().all(|r| left_items.iter().any(|l| is_narrower_or_equal(r, l)))
+        }
+        Operator::IsPartOf => {
+            left_items.iter().all(|l| right_items.iter().any(|r| is_narrower_or_equal(l, r)))
+        }
+        Operator::IsAllOf => {
+            right_items.iter().all(|r| left_items.iter().any(|l| values_equal(l, r)))
+        }
+        Operator::IsAnyOf => {
+            left_items.iter().any(|l| right_items.iter().any(|r| values_equal(l, r)))
+        }
+        Operator::IsNoneOf => {
+            !left_items.iter().any(|l| right_items.iter().any(|r| values_equal(l, r)))
+        }
+    }
+}
@@ -0,0 +1,110 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde_json::Value;
+
+const ODRL_NAMESPACE: &str = "http://www.w3.org/ns/odrl/2/";
+const ODRL_PREFIX: &str = "odrl:";
+
+/// Atomic constraint read from raw ODRL JSON, with its terms normalized
+#[derive(Debug, Clone, PartialEq)]
+pub struct OdrlConstraintTerm {
+    pub left_operand: String,
+    pub operator: String,
+    pub right_operand: Value,
+}
+
+/// `odrl:dateTime`, the full IRI and the bare term all read as `dateTime`
+pub fn normalize_term(term: &str) -> &str {
+    let term = term.trim();
+    term.strip_prefix(ODRL_NAMESPACE).or_else(|| term.strip_prefix(ODRL_PREFIX)).unwrap_or(term)
+}
+
+/// Terms may come as plain strings or as JSON-LD nodes
+pub fn term_value(value: &Value) -> Option<String> {
+    match value {
+        Value::String(term) => Some(normalize_term(term).to_string()),
+        Value::Object(node) => {
+            node.get("@id").and_then(Value::as_str).map(|term| normalize_term(term).to_string())
+        }
+        _ => None,
+    }
+}
+
+/// Typed literals ({"@value": ..., "@type": ...}) and references ({"@id": ...}) are compared by value.
+pub fn unwrap_json_ld(value: &Value) -> Value {
+    match value {
+        Value::Object(map) => {
+            map.get("@value").or_else(|| map.get("@id")).cloned().unwrap_or(value.clone())
+        }
+        other => other.clone(),
+    }
+}
+
+/// Accepts xsd:dateTime and xsd:date, as plain strings or typed literals
+pub fn parse_date_time(value: &Value) -> Option<DateTime<Utc>> {
+    let value = unwrap_json_ld(value);
+    let value = value.as_str()?.trim();
+    if let Ok(date_time) = DateTime::parse_from_rfc3339(value) {
+        return Some(date_time.with_timezone(&Utc));
+    }
+    NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .ok()
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .map(|date_time| date_time.and_utc())
+}
+
+/// Single values and arrays are both accepted wherever ODRL allows a list
+pub fn as_list(value: &Value) -> Vec<&Value> {
+    match value {
+        Value::Array(values) => values.iter().collect(),
+        Value::Null => vec![],
+        value => vec![value],
+    }
+}
+
+/// Atomic constraints that must all hold: the top level ones and those nested in `and` or
+/// `andSequence`. Disjunctions are skipped, none of their branches binds on its own
+pub fn conjunctive_constraints(constraints: &Value) -> Vec<OdrlConstraintTerm> {
+    let mut collected = vec![];
+    collect_conjunctive_constraints(constraints, &mut collected);
+    collected
+}
+
+fn collect_conjunctive_constraints(constraints: &Value, collected: &mut Vec<OdrlConstraintTerm>) {
+    for constraint in as_list(constraints) {
+        if let Some(nested) = constraint.get("and").or_else(|| constraint.get("andSequence")) {
+            collect_conjunctive_constraints(nested, collected);
+            continue;
+        }
+        let left_operand = constraint.get("leftOperand").and_then(term_value);
+        let operator = constraint.get("operator").and_then(term_value);
+        let right_operand = constraint.get("rightOperand");
+        if let (Some(left_operand), Some(operator), Some(right_operand)) =
+            (left_operand, operator, right_operand)
+        {
+            collected.push(OdrlConstraintTerm {
+                left_operand,
+                operator,
+                right_operand: right_operand.clone(),
+            });
+        }
+    }
+}
@@ -111,6 +111,12 @@ pub enum CommonErrors {
         info: ErrorInfo,
         cause: String,
     },
+    #[error("Conflict Error")]
+    ConflictError {
+        #[serde(flatten)]
+        info: ErrorInfo,
+        cause: String,
+    },
     #[error("Database Error")]
     DatabaseError {
         #[serde(flatten)]
@@ -176,6 +182,7 @@ impl IntoResponse for &CommonErrors {
             | CommonErrors::FormatError { info, .. }
             | CommonErrors::UnauthorizedError { info, .. }
             | CommonErrors::ForbiddenError { info, .. }
+            | CommonErrors::ConflictError { info, .. }
             | CommonErrors::DatabaseError { info, .. }
             | CommonErrors::ReadError { info, .. }
             | CommonErrors::WriteError { info, .. }
@@ -238,6 +245,7 @@ impl ErrorLog for CommonErrors {
             | CommonErrors::UnauthorizedError { info, cause }
             | CommonErrors::ModuleNotActiveError { info, cause }
             | CommonErrors::ForbiddenError { info, cause }
+            | CommonErrors::ConflictError { info, cause }
             | CommonErrors::DatabaseError { info, cause }
             | CommonErrors::EnvVarError { info, cause }
             | CommonErrors::VaultError { info, cause }
@@ -403,6 +411,18 @@ impl CommonErrors {
             cause: cause.to_string(),
         }
     }
+    pub fn conflict_new(cause: &str) -> CommonErrors {
+        CommonErrors::ConflictError {
+            info: ErrorInfo {
+                message: "The resource is not in a state that allows this operation".to_string(),
+                error_code: 4400,
+                status_code: StatusCode::CONFLICT,
+                details: None,
+                cause: cause.to_string(),
+            },
+            cause: cause.to_string(),
+        }
+    }
     pub fn database_new(cause: &str) -> CommonErrors {
         CommonErrors::DatabaseError {
             info: ErrorInfo {
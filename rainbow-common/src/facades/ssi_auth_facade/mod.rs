@@ -17,10 +17,14 @@
  *
  */
 
+use crate::http_client::{HttpClientError, RequestOptions};
 use crate::mates::Mates;
 use async_trait::async_trait;
+use reqwest::StatusCode;
+use std::future::Future;
 
 pub mod mates_facade;
+pub mod peer_token_facade;
 pub mod ssi_auth_facade;
 
 #[mockall::automock]
@@ -35,4 +39,42 @@ pub trait MatesFacadeTrait: Send + Sync {
     async fn get_mate_by_id(&self, mate_id: String) -> anyhow::Result<Mates>;
     async fn get_mate_by_slug(&self, mate_slug: String) -> anyhow::Result<Mates>;
     async fn get_me_mate(&self) -> anyhow::Result<Mates>;
+    async fn get_all_mates(&self) -> anyhow::Result<Vec<Mates>>;
+}
+
+#[mockall::automock]
+#[async_trait]
+pub trait PeerTokenFacadeTrait: Send + Sync {
+    /// GNAP access token to present to `participant_id`, onboarding first if there is none yet
+    async fn get_peer_token(&self, participant_id: &str) -> anyhow::Result<String>;
+    /// Called when the peer rejected `rejected_token`, onboards again and returns the new token.
+    /// Fails with `PeerTokenError::InteractionPending` when the grant is still waiting on its
+    /// interaction.
+    async fn refresh_peer_token(
+        &self,
+        participant_id: &str,
+        rejected_token: &str,
+    ) -> anyhow::Result<String>;
+}
+
+/// Sends a request to `participant_id` with its GNAP token set on `options`. If the peer
+/// answers 401 the token is refreshed and the request sent once more.
+pub async fn send_with_peer_token<R, F, Fut>(
+    peer_token_facade: &dyn PeerTokenFacadeTrait,
+    participant_id: &str,
+    options: RequestOptions,
+    send: F,
+) -> anyhow::Result<R>
+where
+    F: Fn(RequestOptions) -> Fut,
+    Fut: Future<Output = Result<R, HttpClientError>>,
+{
+    let token = peer_token_facade.get_peer_token(participant_id).await?;
+    match send(options.clone().with_auth_token(token.as_str())).await {
+        Err(HttpClientError::HttpError { status: StatusCode::UNAUTHORIZED, .. }) => {
+            let token = peer_token_facade.refresh_peer_token(participant_id, &token).await?;
+            Ok(send(options.with_auth_token(token)).await?)
+        }
+        response => Ok(response?),
+    }
 }
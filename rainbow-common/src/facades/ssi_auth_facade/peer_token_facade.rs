@@ -0,0 +1,169 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use super::{MatesFacadeTrait, PeerTokenFacadeTrait};
+use crate::config::services::MinKnownConfig;
+use crate::errors::{CommonErrors, ErrorLog};
+use crate::http_client::HttpClient;
+use crate::mates::mates::ReachProvider;
+use crate::mates::Mates;
+use anyhow::bail;
+use async_trait::async_trait;
+use std::sync::Arc;
+use std::time::Duration;
+use thiserror::Error;
+use tokio::sync::Mutex;
+use tokio::time::Instant;
+use tracing::{error, info};
+use ymir::config::types::HostType;
+
+const SSI_AUTH_FACADE_ONBOARD_URL: &str = "/api/v1/onboard/provider";
+/// How long a refresh waits for the grant before handing the interaction back
+const DEFAULT_ONBOARDING_WAIT: Duration = Duration::from_secs(10);
+const ONBOARDING_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+#[derive(Debug, Error)]
+pub enum PeerTokenError {
+    /// Onboarding is a GNAP grant with an interaction, the token lands in the mates table
+    /// once the interaction at `interaction_uri` completes and the callback is received
+    #[error(
+        "Onboarding with peer {participant_id} waits for the interaction at {interaction_uri}"
+    )]
+    InteractionPending { participant_id: String, interaction_uri: String },
+}
+
+/// Outbound tokens are the ones the SSI auth service keeps in the mates table,
+/// nothing is stored on the http client so calls to different peers don't interfere.
+pub struct PeerTokenFacadeService {
+    config: Arc<MinKnownConfig>,
+    client: Arc<HttpClient>,
+    mates_facade: Arc<dyn MatesFacadeTrait>,
+    onboarding: Mutex<()>,
+    onboarding_wait: Duration,
+}
+
+impl PeerTokenFacadeService {
+    pub fn new(
+        config: Arc<MinKnownConfig>,
+        client: Arc<HttpClient>,
+        mates_facade: Arc<dyn MatesFacadeTrait>,
+    ) -> Self {
+        Self {
+            config,
+            client,
+            mates_facade,
+            onboarding: Mutex::new(()),
+            onboarding_wait: DEFAULT_ONBOARDING_WAIT,
+        }
+    }
+
+    pub fn with_onboarding_wait(mut self, onboarding_wait: Duration) -> Self {
+        self.onboarding_wait = onboarding_wait;
+        self
+    }
+
+    fn token_of(mate: &Mates) -> Option<String> {
+        mate.token.clone().filter(|token| !token.is_empty())
+    }
+
+    /// Starts the grant request, returning the uri of the interaction that completes it
+    async fn onboard(&self, mate: &Mates) -> anyhow::Result<String> {
+        let url = match mate.base_url.as_ref() {
+            Some(url) => url.clone(),
+            None => {
+                let err = CommonErrors::missing_resource_new(
+                    mate.participant_id.as_str(),
+                    "Peer has no base url to onboard against",
+                );
+                error!("{}", err.log());
+                bail!(err);
+            }
+        };
+        let ssi_auth_url = self.config.get_host(HostType::Http);
+        let onboard_url = format!("{}{}", ssi_auth_url, SSI_AUTH_FACADE_ONBOARD_URL);
+        let body = ReachProvider {
+            id: mate.participant_id.clone(),
+            slug: mate.participant_slug.clone(),
+            url,
+            actions: mate.token_actions.clone().unwrap_or_default(),
+        };
+        info!("Onboarding against peer {}", mate.participant_id);
+        let interaction_uri: String =
+            self.client.post_json_response(onboard_url.as_str(), &body).await?;
+        Ok(interaction_uri)
+    }
+
+    /// Polls the mates table until the grant issued a token other than `rejected_token`
+    async fn wait_for_token(&self, participant_id: &str, rejected_token: &str) -> Option<String> {
+        let deadline = Instant::now() + self.onboarding_wait;
+        loop {
+            // the mate may not be readable while the grant is being stored
+            if let Ok(mate) = self.mates_facade.get_mate_by_id(participant_id.to_string()).await {
+                if let Some(token) = Self::token_of(&mate).filter(|token| token != rejected_token) {
+                    return Some(token);
+                }
+            }
+            if Instant::now() >= deadline {
+                return None;
+            }
+            tokio::time::sleep(ONBOARDING_POLL_INTERVAL).await;
+        }
+    }
+}
+
+#[async_trait]
+impl PeerTokenFacadeTrait for PeerTokenFacadeService {
+    async fn get_peer_token(&self, participant_id: &str) -> anyhow::Result<String> {
+        if participant_id.is_empty() {
+            let err = CommonErrors::missing_resource_new("", "No peer associated to the process");
+            error!("{}", err.log());
+            bail!(err);
+        }
+        let mate = self.mates_facade.get_mate_by_id(participant_id.to_string()).await?;
+        match Self::token_of(&mate) {
+            Some(token) => Ok(token),
+            None => self.refresh_peer_token(participant_id, "").await,
+        }
+    }
+
+    async fn refresh_peer_token(
+        &self,
+        participant_id: &str,
+        rejected_token: &str,
+    ) -> anyhow::Result<String> {
+        // one onboarding at a time, whoever waited may find the token already renewed
+        let _guard = self.onboarding.lock().await;
+        let mate = self.mates_facade.get_mate_by_id(participant_id.to_string()).await?;
+        if let Some(token) = Self::token_of(&mate).filter(|token| token != rejected_token) {
+            return Ok(token);
+        }
+        let interaction_uri = self.onboard(&mate).await?;
+        match self.wait_for_token(participant_id, rejected_token).await {
+            Some(token) => Ok(token),
+            None => {
+                let err = PeerTokenError::InteractionPending {
+                    participant_id: participant_id.to_string(),
+                    interaction_uri,
+                };
+                error!("{}", err);
+                bail!(err);
+            }
+        }
+    }
+}
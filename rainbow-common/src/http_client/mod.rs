@@ -1,5 +1,7 @@
 use async_trait::async_trait;
 use bytes::Bytes;
+use rand::Rng;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue, RETRY_AFTER};
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 use std::sync::Arc;
@@ -13,7 +15,7 @@ pub enum HttpClientError {
     RequestError(#[from] reqwest::Error),
 
     #[error("HTTP Error {status}: {message}")]
-    HttpError { status: reqwest::StatusCode, message: String },
+    HttpError { status: reqwest::StatusCode, message: String, retry_after: Option<Duration> },
 
     #[error("Failed to read response body: {0}")]
     BodyReadError(reqwest::Error),
@@ -50,6 +52,14 @@ impl ApiResponse for () {
     }
 }
 
+/// Hands the raw response back, for callers that need its status or headers
+#[async_trait]
+impl ApiResponse for reqwest::Response {
+    async fn from_response(response: reqwest::Response) -> anyhow::Result<Self, HttpClientError> {
+        Ok(response)
+    }
+}
+
 #[async_trait]
 impl ApiResponse for String {
     async fn from_response(response: reqwest::Response) -> anyhow::Result<Self, HttpClientError> {
@@ -65,12 +75,108 @@ impl<T: DeserializeOwned + Send> ApiResponse for JsonResponse<T> {
     }
 }
 
+pub const IDEMPOTENCY_KEY_HEADER: &str = "Idempotency-Key";
+
+/// Exponential backoff with jitter, `base_delay * 2^attempt` capped at `max_delay`
+#[derive(Clone, Debug)]
+pub struct RetryPolicy {
+    pub max_retries: u32,
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            base_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(60),
+        }
+    }
+}
+
+impl RetryPolicy {
+    pub fn none() -> Self {
+        Self { max_retries: 0, ..Self::default() }
+    }
+
+    /// Somewhere between half and the full exponential delay, so clients hitting
+    /// the same peer don't retry in lockstep
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let delay =
+            self.base_delay.saturating_mul(2u32.saturating_pow(attempt)).min(self.max_delay);
+        let half = delay / 2;
+        let jitter_ms = rand::rng().random_range(0..=half.as_millis() as u64);
+        half + Duration::from_millis(jitter_ms)
+    }
+}
+
+/// Per call settings. Anything left unset falls back to the client defaults.
+/// POSTs are only retried when marked `idempotent` or sent with an idempotency key.
+#[derive(Clone, Debug, Default)]
+pub struct RequestOptions {
+    pub auth_token: Option<String>,
+    pub headers: HeaderMap,
+    pub timeout: Option<Duration>,
+    pub retry_policy: Option<RetryPolicy>,
+    pub idempotent: bool,
+    pub idempotency_key: Option<String>,
+}
+
+impl RequestOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_auth_token(mut self, token: impl Into<String>) -> Self {
+        self.auth_token = Some(token.into());
+        self
+    }
+
+    pub fn with_header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+        self.headers.insert(name, value);
+        self
+    }
+
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
+
+    pub fn idempotent(mut self) -> Self {
+        self.idempotent = true;
+        self
+    }
+
+    pub fn with_idempotency_key(mut self, key: impl Into<String>) -> Self {
+        self.idempotency_key = Some(key.into());
+        self
+    }
+
+    fn allows_retry(&self, method: &reqwest::Method) -> bool {
+        let idempotent_method = matches!(
+            *method,
+            reqwest::Method::GET
+                | reqwest::Method::HEAD
+                | reqwest::Method::OPTIONS
+                | reqwest::Method::PUT
+                | reqwest::Method::DELETE
+        );
+        idempotent_method || self.idempotent || self.idempotency_key.is_some()
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct HttpClient {
     client: reqwest::Client,
     auth_token: Arc<RwLock<Option<String>>>,
     limiter: Arc<Semaphore>,
-    max_retries: u32,
+    retry_policy: RetryPolicy,
 }
 
 impl HttpClient {
@@ -85,7 +191,7 @@ impl HttpClient {
             client,
             auth_token: Arc::new(RwLock::new(None)),
             limiter: Arc::new(Semaphore::new(concurrency_limit)),
-            max_retries: 3,
+            retry_policy: RetryPolicy::default(),
         }
     }
 
@@ -102,18 +208,33 @@ impl HttpClient {
         method: reqwest::Method,
         url: &str,
         body: Option<Bytes>,
-        content_type: Option<&str>, // <--- Nuevo parámetro para flexibilidad
+        content_type: Option<&str>,
+        options: &RequestOptions,
     ) -> Result<reqwest::Response, HttpClientError> {
         let mut builder = self.client.request(method, url);
-        let token_guard = self.auth_token.read().await;
-        if let Some(token) = token_guard.as_ref() {
-            builder = builder.bearer_auth(token);
+        // a per request token wins over the one set on the client
+        match options.auth_token.as_ref() {
+            Some(token) => builder = builder.bearer_auth(token),
+            None => {
+                let token_guard = self.auth_token.read().await;
+                if let Some(token) = token_guard.as_ref() {
+                    builder = builder.bearer_auth(token);
+                }
+            }
         }
-        drop(token_guard);
 
         if let Some(ct) = content_type {
             builder = builder.header(reqwest::header::CONTENT_TYPE, ct);
         }
+        if let Some(key) = options.idempotency_key.as_ref() {
+            builder = builder.header(IDEMPOTENCY_KEY_HEADER, key);
+        }
+        if !options.headers.is_empty() {
+            builder = builder.headers(options.headers.clone());
+        }
+        if let Some(timeout) = options.timeout {
+            builder = builder.timeout(timeout);
+        }
         if let Some(b) = body {
             builder = builder.body(b);
         }
@@ -121,35 +242,63 @@ impl HttpClient {
 
         let status = response.status();
         if status.is_client_error() || status.is_server_error() {
+            let retry_after = Self::parse_retry_after(response.headers());
             return Err(HttpClientError::HttpError {
                 status,
                 message: response.text().await.unwrap_or_default(),
+                retry_after,
             });
         }
 
         Ok(response)
     }
 
+    /// `Retry-After` is either delay seconds or an HTTP date
+    fn parse_retry_after(headers: &HeaderMap) -> Option<Duration> {
+        let value = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+        if let Ok(secs) = value.parse::<u64>() {
+            return Some(Duration::from_secs(secs));
+        }
+        let date = chrono::DateTime::parse_from_rfc2822(value).ok()?;
+        let delay = date.with_timezone(&chrono::Utc) - chrono::Utc::now();
+        Some(delay.to_std().unwrap_or(Duration::ZERO))
+    }
+
     async fn execute_with_retries(
         &self,
         method: reqwest::Method,
         url: &str,
         body: Option<Bytes>,
         content_type: Option<&str>,
+        options: &RequestOptions,
     ) -> Result<reqwest::Response, HttpClientError> {
+        let retry_policy = options.retry_policy.as_ref().unwrap_or(&self.retry_policy);
+        let retry_allowed = options.allows_retry(&method);
         let mut attempt = 1;
 
         loop {
             // cheap bytes cloning
             let body_clone = body.clone();
 
-            match self.perform_single_request(method.clone(), url, body_clone, content_type).await {
+            match self
+                .perform_single_request(method.clone(), url, body_clone, content_type, options)
+                .await
+            {
                 Ok(response) => return Ok(response),
                 Err(err) => {
-                    if !self.should_retry(&err, attempt) {
+                    if !retry_allowed || !Self::should_retry(&err, attempt, retry_policy) {
                         return Err(err);
                     }
-                    let backoff = Duration::from_secs(2u64.pow(attempt));
+                    let backoff = match &err {
+                        HttpClientError::HttpError { retry_after: Some(delay), .. } => {
+                            // the server knows better, but don't wait longer than the policy allows
+                            if *delay > retry_policy.max_delay {
+                                return Err(err);
+                            }
+                            *delay
+                        }
+                        _ => retry_policy.backoff(attempt),
+                    };
                     tokio::time::sleep(backoff).await;
                     attempt += 1;
                 }
@@ -157,12 +306,12 @@ impl HttpClient {
         }
     }
 
-    fn should_retry(&self, err: &HttpClientError, attempt: u32) -> bool {
-        if attempt > self.max_retries {
+    fn should_retry(err: &HttpClientError, attempt: u32, retry_policy: &RetryPolicy) -> bool {
+        if attempt > retry_policy.max_retries {
             return false;
         }
         match err {
-            HttpClientError::RequestError(_) => true,
+            HttpClientError::RequestError(e) => !e.is_builder(),
             HttpClientError::HttpError { status, .. } => {
                 status.is_server_error() || *status == reqwest::StatusCode::TOO_MANY_REQUESTS
             }
@@ -176,17 +325,29 @@ impl HttpClient {
         url: &str,
         body: Option<Bytes>,
         content_type: Option<&str>,
+        options: &RequestOptions,
     ) -> Result<reqwest::Response, HttpClientError> {
         let _permit =
             self.limiter.acquire().await.map_err(|_| HttpClientError::ConcurrencyError)?;
-        self.execute_with_retries(method, url, body, content_type).await
+        self.execute_with_retries(method, url, body, content_type, options).await
     }
 
     pub async fn get_json<R>(&self, url: &str) -> anyhow::Result<R, HttpClientError>
     where
         R: DeserializeOwned,
     {
-        let response = self.dispatch(reqwest::Method::GET, url, None, None).await?;
+        self.get_json_with_options(url, &RequestOptions::default()).await
+    }
+
+    pub async fn get_json_with_options<R>(
+        &self,
+        url: &str,
+        options: &RequestOptions,
+    ) -> anyhow::Result<R, HttpClientError>
+    where
+        R: DeserializeOwned,
+    {
+        let response = self.dispatch(reqwest::Method::GET, url, None, None, options).await?;
         Self::deserialize_internal(response).await
     }
 
@@ -195,6 +356,19 @@ impl HttpClient {
         url: &str,
         payload: &T,
     ) -> anyhow::Result<R, HttpClientError>
+    where
+        T: Serialize,
+        R: DeserializeOwned,
+    {
+        self.get_json_with_payload_and_options(url, payload, &RequestOptions::default()).await
+    }
+
+    pub async fn get_json_with_payload_and_options<T, R>(
+        &self,
+        url: &str,
+        payload: &T,
+        options: &RequestOptions,
+    ) -> anyhow::Result<R, HttpClientError>
     where
         T: Serialize,
         R: DeserializeOwned,
@@ -202,8 +376,15 @@ impl HttpClient {
         let bytes = serde_json::to_vec(payload)?;
         let body = Bytes::from(bytes);
 
-        let response =
-            self.dispatch(reqwest::Method::GET, url, Some(body), Some("application/json")).await?;
+        let response = self
+            .dispatch(
+                reqwest::Method::GET,
+                url,
+                Some(body),
+                Some("application/json"),
+                options,
+            )
+            .await?;
         Self::deserialize_internal(response).await
     }
 
@@ -212,6 +393,19 @@ impl HttpClient {
         url: &str,
         payload: &T,
     ) -> anyhow::Result<R, HttpClientError>
+    where
+        T: Serialize,
+        R: DeserializeOwned,
+    {
+        self.post_json_with_options(url, payload, &RequestOptions::default()).await
+    }
+
+    pub async fn post_json_with_options<T, R>(
+        &self,
+        url: &str,
+        payload: &T,
+        options: &RequestOptions,
+    ) -> anyhow::Result<R, HttpClientError>
     where
         T: Serialize,
         R: DeserializeOwned,
@@ -219,29 +413,95 @@ impl HttpClient {
         let bytes = serde_json::to_vec(payload)?;
         let body = Bytes::from(bytes);
 
-        let response =
-            self.dispatch(reqwest::Method::POST, url, Some(body), Some("application/json")).await?;
+        let response = self
+            .dispatch(
+                reqwest::Method::POST,
+                url,
+                Some(body),
+                Some("application/json"),
+                options,
+            )
+            .await?;
         Self::deserialize_internal(response).await
     }
 
+    pub async fn post_json_response<T, R>(
+        &self,
+        url: &str,
+        payload: &T,
+    ) -> anyhow::Result<R, HttpClientError>
+    where
+        T: Serialize,
+        R: ApiResponse,
+    {
+        self.post_json_response_with_options(url, payload, &RequestOptions::default()).await
+    }
+
+    pub async fn post_json_response_with_options<T, R>(
+        &self,
+        url: &str,
+        payload: &T,
+        options: &RequestOptions,
+    ) -> anyhow::Result<R, HttpClientError>
+    where
+        T: Serialize,
+        R: ApiResponse,
+    {
+        let bytes = serde_json::to_vec(payload)?;
+        let body = Bytes::from(bytes);
+
+        let response = self
+            .dispatch(
+                reqwest::Method::POST,
+                url,
+                Some(body),
+                Some("application/json"),
+                options,
+            )
+            .await?;
+        R::from_response(response).await
+    }
+
     pub async fn post_void<R>(&self, url: &str) -> anyhow::Result<R, HttpClientError>
     where
         R: ApiResponse,
     {
-        let response = self.dispatch(reqwest::Method::POST, url, None, None).await?;
+        let response = self
+            .dispatch(reqwest::Method::POST, url, None, None, &RequestOptions::default())
+            .await?;
 
         R::from_response(response).await
     }
 
     pub async fn put_json<T, R>(&self, url: &str, payload: &T) -> anyhow::Result<R, HttpClientError>
+    where
+        T: Serialize,
+        R: DeserializeOwned,
+    {
+        self.put_json_with_options(url, payload, &RequestOptions::default()).await
+    }
+
+    pub async fn put_json_with_options<T, R>(
+        &self,
+        url: &str,
+        payload: &T,
+        options: &RequestOptions,
+    ) -> anyhow::Result<R, HttpClientError>
     where
         T: Serialize,
         R: DeserializeOwned,
     {
         let bytes = serde_json::to_vec(payload)?;
         let body = Bytes::from(bytes);
-        let response =
-            self.dispatch(reqwest::Method::PUT, url, Some(body), Some("application/json")).await?;
+        let response = self
+            .dispatch(
+                reqwest::Method::PUT,
+                url,
+                Some(body),
+                Some("application/json"),
+                options,
+            )
+            .await?;
         Self::deserialize_internal(response).await
     }
 
@@ -249,7 +509,18 @@ impl HttpClient {
     where
         R: ApiResponse,
     {
-        let response = self.dispatch(reqwest::Method::DELETE, url, None, None).await?;
+        self.delete_with_options(url, &RequestOptions::default()).await
+    }
+
+    pub async fn delete_with_options<R>(
+        &self,
+        url: &str,
+        options: &RequestOptions,
+    ) -> anyhow::Result<R, HttpClientError>
+    where
+        R: ApiResponse,
+    {
+        let response = self.dispatch(reqwest::Method::DELETE, url, None, None, options).await?;
         R::from_response(response).await
     }
 
@@ -270,6 +541,7 @@ impl HttpClient {
                 url,
                 Some(body),
                 Some("application/x-www-form-urlencoded"),
+                &RequestOptions::default(),
             )
             .await?;
         R::from_response(response).await
@@ -284,3 +556,86 @@ impl HttpClient {
             .map_err(|source| HttpClientError::DeserializeError { source, raw_text })
     }
 }
+
+#[cfg(test)]
+mod test_http_client {
+    use super::*;
+
+    fn retry_after(value: &str) -> Option<Duration> {
+        let mut headers = HeaderMap::new();
+        headers.insert(RETRY_AFTER, HeaderValue::from_str(value).unwrap());
+        HttpClient::parse_retry_after(&headers)
+    }
+
+    #[test]
+    fn test_backoff_stays_within_half_and_full_delay() {
+        let policy = RetryPolicy {
+            max_retries: 5,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(60),
+        };
+        for attempt in 0..5 {
+            let full = Duration::from_millis(100 * 2u64.pow(attempt));
+            for _ in 0..50 {
+                let delay = policy.backoff(attempt);
+                assert!(delay >= full / 2 && delay <= full, "attempt {}: {:?}", attempt, delay);
+            }
+        }
+    }
+
+    #[test]
+    fn test_backoff_is_capped_at_max_delay() {
+        let policy = RetryPolicy {
+            max_retries: 40,
+            base_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(5),
+        };
+        // 2^attempt saturates instead of overflowing
+        for attempt in [3, 10, 31, 40] {
+            for _ in 0..50 {
+                let delay = policy.backoff(attempt);
+                assert!(delay >= Duration::from_millis(2500) && delay <= Duration::from_secs(5));
+            }
+        }
+    }
+
+    #[test]
+    fn test_only_idempotent_requests_are_retried() {
+        let options = RequestOptions::new();
+        for method in [
+            reqwest::Method::GET,
+            reqwest::Method::HEAD,
+            reqwest::Method::OPTIONS,
+            reqwest::Method::PUT,
+            reqwest::Method::DELETE,
+        ] {
+            assert!(options.allows_retry(&method), "{} should be retried", method);
+        }
+        assert!(!options.allows_retry(&reqwest::Method::POST));
+        assert!(!options.allows_retry(&reqwest::Method::PATCH));
+
+        assert!(RequestOptions::new().idempotent().allows_retry(&reqwest::Method::POST));
+        assert!(RequestOptions::new()
+            .with_idempotency_key("urn:uuid:1")
+            .allows_retry(&reqwest::Method::POST));
+    }
+
+    #[test]
+    fn test_retry_after_in_seconds() {
+        assert_eq!(retry_after("120"), Some(Duration::from_secs(120)));
+        assert_eq!(retry_after(" 0 "), Some(Duration::ZERO));
+        assert_eq!(retry_after("soon"), None);
+        assert_eq!(HttpClient::parse_retry_after(&HeaderMap::new()), None);
+    }
+
+    #[test]
+    fn test_retry_after_as_http_date() {
+        let date = chrono::Utc::now() + chrono::Duration::seconds(30);
+        let delay = retry_after(&date.to_rfc2822()).unwrap();
+        // the date has second precision and some time goes by before parsing it
+        assert!(delay > Duration::from_secs(28) && delay <= Duration::from_secs(30));
+
+        // a date already gone means retry right away
+        assert_eq!(retry_after("Wed, 21 Oct 2015 07:28:00 GMT"), Some(Duration::ZERO));
+    }
+}
@@ -20,7 +20,7 @@
 use crate::utils::get_urn;
 use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Mates {
     pub participant_id: String,
     pub participant_slug: String,
@@ -85,3 +85,12 @@ impl Mates {
 pub struct VerifyTokenRequest {
     pub token: String,
 }
+
+/// Body of the SSI auth service onboarding request towards a peer
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ReachProvider {
+    pub id: String,
+    pub slug: String,
+    pub url: String,
+    pub actions: String,
+}
@@ -0,0 +1,239 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+// Tests corresponding to 'rainbow-common\src\auth\ssi'
+
+use anyhow::anyhow;
+use axum::body::{to_bytes, Body};
+use axum::http::{header::AUTHORIZATION, Request, StatusCode};
+use axum::routing::get;
+use axum::Router;
+use rainbow_common::auth::ssi::{
+    bearer_token, current_participant_id, with_ssi_auth, SsiAuthLayer, DEFAULT_PEER_CACHE_TTL,
+};
+use rainbow_common::facades::ssi_auth_facade::MockSSIAuthFacadeTrait;
+use rainbow_common::mates::Mates;
+use serde_json::Value;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tower::ServiceExt;
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    const PEER: &str = "did:web:consumer.example.org";
+
+    fn mate() -> Mates {
+        Mates::default4consumer(
+            Some(PEER.to_string()),
+            "consumer".to_string(),
+            "https://consumer.example.org".to_string(),
+            None,
+            None,
+            false,
+        )
+    }
+
+    fn routes() -> Router {
+        Router::new().route(
+            "/transfers/request",
+            get(|| async { current_participant_id().unwrap_or_else(|| "anonymous".to_string()) }),
+        )
+    }
+
+    fn router(ssi_auth_facade: MockSSIAuthFacadeTrait, enabled: bool) -> Router {
+        let layer =
+            SsiAuthLayer::new(Arc::new(ssi_auth_facade), DEFAULT_PEER_CACHE_TTL, "TransferError");
+        with_ssi_auth(routes(), layer, enabled)
+    }
+
+    fn request(token: Option<&str>) -> Request<Body> {
+        let mut builder = Request::builder().uri("/transfers/request");
+        if let Some(token) = token {
+            builder = builder.header(AUTHORIZATION, format!("Bearer {}", token));
+        }
+        builder.body(Body::empty()).unwrap()
+    }
+
+    async fn body_of(response: axum::response::Response) -> String {
+        let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        String::from_utf8(bytes.to_vec()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_missing_token_is_rejected_with_dsp_error() {
+        let mut ssi_auth_facade = MockSSIAuthFacadeTrait::new();
+        ssi_auth_facade.expect_verify_token().never();
+
+        let response = router(ssi_auth_facade, true).oneshot(request(None)).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        let body: Value = serde_json::from_str(&body_of(response).await).unwrap();
+        assert_eq!(body["@type"], "TransferError");
+    }
+
+    #[tokio::test]
+    async fn test_unknown_token_is_rejected() {
+        let mut ssi_auth_facade = MockSSIAuthFacadeTrait::new();
+        ssi_auth_facade.expect_verify_token().times(1).returning(|_| Err(anyhow!("unknown peer")));
+
+        let response =
+            router(ssi_auth_facade, true).oneshot(request(Some("forged"))).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn test_verified_peer_is_visible_to_handlers() {
+        let mut ssi_auth_facade = MockSSIAuthFacadeTrait::new();
+        ssi_auth_facade
+            .expect_verify_token()
+            .withf(|token| token == "valid")
+            .times(1)
+            .returning(|_| Ok(mate()));
+
+        let response = router(ssi_auth_facade, true).oneshot(request(Some("valid"))).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(body_of(response).await, PEER);
+    }
+
+    #[tokio::test]
+    async fn test_verified_tokens_are_cached() {
+        let mut ssi_auth_facade = MockSSIAuthFacadeTrait::new();
+        ssi_auth_facade.expect_verify_token().times(1).returning(|_| Ok(mate()));
+        let router = router(ssi_auth_facade, true);
+
+        for _ in 0..3 {
+            let response = router.clone().oneshot(request(Some("valid"))).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_blank_token_is_rejected() {
+        let mut ssi_auth_facade = MockSSIAuthFacadeTrait::new();
+        ssi_auth_facade.expect_verify_token().never();
+
+        let response = router(ssi_auth_facade, true).oneshot(request(Some(" "))).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[test]
+    fn test_bearer_scheme_is_parsed() {
+        assert_eq!(bearer_token("Bearer abc"), Some("abc".to_string()));
+        assert_eq!(bearer_token("bearer abc"), Some("abc".to_string()));
+        assert_eq!(bearer_token("BEARER  abc "), Some("abc".to_string()));
+        assert_eq!(bearer_token("Basic dXNlcjpwYXNz"), None);
+        assert_eq!(bearer_token("Bearerabc"), None);
+        assert_eq!(bearer_token("abc"), None);
+        assert_eq!(bearer_token("Bearer "), None);
+    }
+
+    #[tokio::test]
+    async fn test_other_schemes_are_rejected() {
+        let mut ssi_auth_facade = MockSSIAuthFacadeTrait::new();
+        ssi_auth_facade.expect_verify_token().never();
+
+        let request = Request::builder()
+            .uri("/transfers/request")
+            .header(AUTHORIZATION, "Basic dmFsaWQ=")
+            .body(Body::empty())
+            .unwrap();
+        let response = router(ssi_auth_facade, true).oneshot(request).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn test_lowercase_bearer_scheme_is_accepted() {
+        let mut ssi_auth_facade = MockSSIAuthFacadeTrait::new();
+        ssi_auth_facade
+            .expect_verify_token()
+            .withf(|token| token == "valid")
+            .times(1)
+            .returning(|_| Ok(mate()));
+
+        let request = Request::builder()
+            .uri("/transfers/request")
+            .header(AUTHORIZATION, "bearer valid")
+            .body(Body::empty())
+            .unwrap();
+        let response = router(ssi_auth_facade, true).oneshot(request).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_expired_tokens_are_verified_again() {
+        let mut ssi_auth_facade = MockSSIAuthFacadeTrait::new();
+        ssi_auth_facade.expect_verify_token().times(2).returning(|_| Ok(mate()));
+        let layer = SsiAuthLayer::new(Arc::new(ssi_auth_facade), Duration::ZERO, "TransferError");
+        let router = with_ssi_auth(routes(), layer, true);
+
+        for _ in 0..2 {
+            let response = router.clone().oneshot(request(Some("valid"))).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_invalidated_token_is_verified_again() {
+        let verifications = Arc::new(AtomicUsize::new(0));
+        let mut ssi_auth_facade = MockSSIAuthFacadeTrait::new();
+        let counter = verifications.clone();
+        ssi_auth_facade.expect_verify_token().returning(move |_| {
+            match counter.fetch_add(1, Ordering::SeqCst) {
+                0 => Ok(mate()),
+                _ => Err(anyhow!("revoked")),
+            }
+        });
+        let layer =
+            SsiAuthLayer::new(Arc::new(ssi_auth_facade), DEFAULT_PEER_CACHE_TTL, "TransferError");
+        let router = with_ssi_auth(routes(), layer.clone(), true);
+
+        let response = router.clone().oneshot(request(Some("valid"))).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        layer.invalidate("valid").await;
+        let response = router.oneshot(request(Some("valid"))).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        assert_eq!(verifications.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_disabled_verification_leaves_router_open() {
+        let mut ssi_auth_facade = MockSSIAuthFacadeTrait::new();
+        ssi_auth_facade.expect_verify_token().never();
+
+        let response = router(ssi_auth_facade, false).oneshot(request(None)).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(body_of(response).await, "anonymous");
+    }
+
+    #[tokio::test]
+    async fn test_no_participant_outside_requests() {
+        assert_eq!(current_participant_id(), None);
+    }
+}
@@ -0,0 +1,391 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+// Tests corresponding to 'rainbow-common\src\dsp_common\odrl_evaluator'
+
+use chrono::{DateTime, Utc};
+use rainbow_common::dsp_common::odrl::OdrlPolicyInfo;
+use rainbow_common::dsp_common::odrl_evaluator::odrl_evaluator::OdrlEvaluatorService;
+use rainbow_common::dsp_common::odrl_evaluator::{
+    OdrlDecisionOutcome, OdrlEvaluatorTrait, OdrlPolicyDecision, OdrlPolicyView,
+    OdrlRequestContext, OdrlRuleType,
+};
+use serde_json::{json, Value};
+use std::str::FromStr;
+use urn::Urn;
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn now() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2026-01-01T00:00:00Z").unwrap().with_timezone(&Utc)
+    }
+
+    fn context() -> OdrlRequestContext {
+        OdrlRequestContext {
+            date_time: now(),
+            purpose: Some("research".to_string()),
+            spatial: Some("ES:MD".to_string()),
+            ..Default::default()
+        }
+    }
+
+    fn policy(policy: Value) -> OdrlPolicyView {
+        let policy: OdrlPolicyInfo = serde_json::from_value(policy).unwrap();
+        OdrlPolicyView::from(&policy)
+    }
+
+    fn evaluate(
+        policy_view: &OdrlPolicyView,
+        action: &str,
+        context: &OdrlRequestContext,
+    ) -> OdrlPolicyDecision {
+        OdrlEvaluatorService::new().evaluate(policy_view, action, context)
+    }
+
+    /// Whether a `use` permission holding only `constraint` grants `read`
+    fn holds(constraint: Value, context: &OdrlRequestContext) -> bool {
+        let policy_view =
+            policy(json!({ "permission": [{ "action": "use", "constraint": [constraint] }] }));
+        evaluate(&policy_view, "read", context).is_permit()
+    }
+
+    fn atomic(left_operand: &str, operator: &str, right_operand: Value) -> Value {
+        json!({ "leftOperand": left_operand, "operator": operator, "rightOperand": right_operand })
+    }
+
+    fn with_attribute(name: &str, value: Value) -> OdrlRequestContext {
+        let mut context = context();
+        context.attributes.insert(name.to_string(), value);
+        context
+    }
+
+    fn with_counter(name: &str, value: i64) -> OdrlRequestContext {
+        let mut context = context();
+        context.counters.insert(name.to_string(), value);
+        context
+    }
+
+    #[test]
+    fn test_eq_and_neq() {
+        assert!(holds(atomic("purpose", "eq", json!("research")), &context()));
+        assert!(!holds(atomic("purpose", "eq", json!("marketing")), &context()));
+        assert!(holds(atomic("purpose", "neq", json!("marketing")), &context()));
+        assert!(!holds(atomic("purpose", "neq", json!("research")), &context()));
+        // numbers compare by value, lists regardless of order
+        assert!(holds(atomic("count", "eq", json!("3")), &with_counter("count", 3)));
+        let tags = with_attribute("tags", json!(["a", "b"]));
+        assert!(holds(atomic("tags", "eq", json!(["b", "a"])), &tags));
+        assert!(!holds(atomic("tags", "eq", json!(["a"])), &tags));
+    }
+
+    #[test]
+    fn test_ordering_operators_on_numbers() {
+        let context = with_counter("count", 3);
+        assert!(holds(atomic("count", "gt", json!("2")), &context));
+        assert!(!holds(atomic("count", "gt", json!("3")), &context));
+        assert!(holds(atomic("count", "gteq", json!("3")), &context));
+        assert!(!holds(atomic("count", "gteq", json!("4")), &context));
+        assert!(holds(atomic("count", "lt", json!("4")), &context));
+        assert!(!holds(atomic("count", "lt", json!("3")), &context));
+        assert!(holds(atomic("count", "lteq", json!("3")), &context));
+        assert!(!holds(atomic("count", "lteq", json!("2")), &context));
+        assert!(holds(atomic("count", "termLteq", json!("3")), &context));
+        assert!(!holds(atomic("count", "termLteq", json!("2")), &context));
+    }
+
+    #[test]
+    fn test_ordering_operators_on_dates() {
+        assert!(holds(atomic("dateTime", "lt", json!("2027-01-01")), &context()));
+        assert!(!holds(
+            atomic("dateTime", "lt", json!("2025-12-31T23:59:59Z")),
+            &context()
+        ));
+        assert!(holds(
+            atomic("dateTime", "gteq", json!("2026-01-01T00:00:00Z")),
+            &context()
+        ));
+        assert!(holds(
+            atomic("dateTime", "lteq", json!("2026-01-01T01:00:00+01:00")),
+            &context()
+        ));
+        assert!(!holds(atomic("dateTime", "gt", json!("2026-01-01")), &context()));
+        // not comparable values never satisfy an ordering
+        assert!(!holds(atomic("purpose", "lt", json!("2027-01-01")), &context()));
+    }
+
+    #[test]
+    fn test_taxonomy_operators() {
+        let medical = OdrlRequestContext {
+            purpose: Some("purpose/research/medical".to_string()),
+            ..context()
+        };
+        assert!(holds(
+            atomic("purpose", "termLteq", json!("purpose/research")),
+            &medical
+        ));
+        let researcher =
+            OdrlRequestContext { purpose: Some("purpose/researcher".to_string()), ..context() };
+        assert!(!holds(
+            atomic("purpose", "termLteq", json!("purpose/research")),
+            &researcher
+        ));
+        assert!(holds(atomic("spatial", "isA", json!("ES")), &context()));
+        assert!(holds(atomic("spatial", "isA", json!(["FR", "ES"])), &context()));
+        assert!(!holds(atomic("spatial", "isA", json!("FR")), &context()));
+    }
+
+    #[test]
+    fn test_set_operators() {
+        let channels = with_attribute("channels", json!(["email", "sms"]));
+        assert!(holds(atomic("channels", "hasPart", json!(["email"])), &channels));
+        assert!(!holds(atomic("channels", "hasPart", json!(["fax"])), &channels));
+        assert!(holds(
+            atomic("channels", "isPartOf", json!(["email", "sms", "fax"])),
+            &channels
+        ));
+        assert!(!holds(atomic("channels", "isPartOf", json!(["email"])), &channels));
+        assert!(holds(
+            atomic("channels", "isAllOf", json!(["sms", "email"])),
+            &channels
+        ));
+        assert!(!holds(
+            atomic("channels", "isAllOf", json!(["email", "fax"])),
+            &channels
+        ));
+        assert!(holds(
+            atomic("purpose", "isAnyOf", json!(["teaching", "research"])),
+            &context()
+        ));
+        assert!(!holds(
+            atomic("purpose", "isAnyOf", json!(["teaching", "marketing"])),
+            &context()
+        ));
+        assert!(holds(atomic("purpose", "isNoneOf", json!(["marketing"])), &context()));
+        assert!(!holds(
+            atomic("purpose", "isNoneOf", json!(["marketing", "research"])),
+            &context()
+        ));
+    }
+
+    #[test]
+    fn test_missing_left_operand_is_not_satisfied() {
+        let policy_view = policy(json!({
+            "permission": [{ "action": "use", "constraint": [atomic("recipient", "eq", json!("did:web:consumer"))] }]
+        }));
+        let decision = evaluate(&policy_view, "read", &context());
+        assert_eq!(decision.outcome, OdrlDecisionOutcome::Deny);
+        assert!(decision.rules[0].reasons[0].contains("'recipient' is not available"));
+    }
+
+    #[test]
+    fn test_logical_constraints() {
+        let research = atomic("purpose", "eq", json!("research"));
+        let marketing = atomic("purpose", "eq", json!("marketing"));
+        let in_spain = atomic("spatial", "isA", json!("ES"));
+
+        assert!(holds(json!({ "and": [research, in_spain] }), &context()));
+        assert!(!holds(json!({ "and": [research, marketing] }), &context()));
+        assert!(holds(json!({ "or": [marketing, research] }), &context()));
+        assert!(!holds(json!({ "or": [marketing, marketing] }), &context()));
+        assert!(holds(json!({ "xone": [marketing, research] }), &context()));
+        assert!(!holds(json!({ "xone": [research, in_spain] }), &context()));
+        assert!(!holds(json!({ "xone": [marketing, marketing] }), &context()));
+        assert!(holds(json!({ "andSequence": [research, in_spain] }), &context()));
+        // nested logical constraints
+        assert!(holds(
+            json!({ "and": [{ "or": [marketing, research] }, in_spain] }),
+            &context()
+        ));
+    }
+
+    #[test]
+    fn test_and_sequence_stops_at_first_failure() {
+        let research = atomic("purpose", "eq", json!("research"));
+        let marketing = atomic("purpose", "eq", json!("marketing"));
+        let policy_view = policy(json!({
+            "permission": [{ "action": "use", "constraint": [{ "andSequence": [research, marketing, marketing] }] }]
+        }));
+        let decision = evaluate(&policy_view, "read", &context());
+        assert!(!decision.is_permit());
+        let reasons = &decision.rules[0].reasons;
+        assert_eq!(reasons.len(), 2);
+        assert_eq!(reasons[1], "andSequence stopped at constraint #1");
+    }
+
+    #[test]
+    fn test_logical_constraint_with_several_operators_is_refused() {
+        let research = atomic("purpose", "eq", json!("research"));
+        let policy_view = policy(json!({
+            "permission": [{ "action": "use", "constraint": [{ "and": [research], "or": [research] }] }]
+        }));
+        let decision = evaluate(&policy_view, "read", &context());
+        assert!(!decision.is_permit());
+        assert!(decision.rules[0].reasons[0].starts_with("Invalid logical constraint"));
+    }
+
+    #[test]
+    fn test_nothing_is_permitted_by_default() {
+        let decision = evaluate(&policy(json!({})), "read", &context());
+        assert_eq!(decision.outcome, OdrlDecisionOutcome::Deny);
+        assert_eq!(decision.reason, "No permission grants action 'read'");
+
+        // use covers every action but transfer
+        let policy_view = policy(json!({ "permission": [{ "action": "use" }] }));
+        assert!(evaluate(&policy_view, "odrl:read", &context()).is_permit());
+        assert!(!evaluate(&policy_view, "transfer", &context()).is_permit());
+    }
+
+    #[test]
+    fn test_any_permission_grants_the_action() {
+        let policy_view = policy(json!({
+            "permission": [
+                { "action": "read", "constraint": [atomic("purpose", "eq", json!("marketing"))] },
+                { "action": "read", "constraint": [atomic("purpose", "eq", json!("research"))] }
+            ]
+        }));
+        let decision = evaluate(&policy_view, "read", &context());
+        assert!(decision.is_permit());
+        assert_eq!(decision.reason, "Action 'read' is granted by permission #1");
+        assert_eq!(decision.fired_rules().len(), 1);
+    }
+
+    #[test]
+    fn test_prohibitions_override_permissions() {
+        let policy_view = policy(json!({
+            "permission": [{ "action": "use" }],
+            "prohibition": [
+                { "action": "distribute" },
+                { "action": "read", "constraint": [atomic("purpose", "eq", json!("marketing"))] }
+            ]
+        }));
+        let decision = evaluate(&policy_view, "distribute", &context());
+        assert_eq!(decision.outcome, OdrlDecisionOutcome::Deny);
+        assert_eq!(decision.reason, "Action 'distribute' is prohibited by prohibition #0");
+        assert!(decision.rules.iter().all(|rule| rule.rule_type == OdrlRuleType::Prohibition));
+
+        // a prohibition whose constraints don't hold doesn't apply
+        assert!(evaluate(&policy_view, "read", &context()).is_permit());
+        let marketing = OdrlRequestContext { purpose: Some("marketing".to_string()), ..context() };
+        let decision = evaluate(&policy_view, "read", &marketing);
+        assert_eq!(decision.reason, "Action 'read' is prohibited by prohibition #1");
+    }
+
+    #[test]
+    fn test_permission_duty_must_be_fulfilled() {
+        let policy_view = policy(json!({
+            "permission": [{ "action": "use", "duty": { "action": "odrl:compensate" } }]
+        }));
+        let decision = evaluate(&policy_view, "read", &context());
+        assert!(!decision.is_permit());
+        let duty = decision.rules.iter().find(|rule| rule.rule_type == OdrlRuleType::Duty).unwrap();
+        assert!(duty.fired);
+        assert_eq!(duty.reasons, vec!["Duty 'odrl:compensate' is not fulfilled"]);
+
+        let fulfilled =
+            OdrlRequestContext { fulfilled_duties: vec!["compensate".to_string()], ..context() };
+        assert!(evaluate(&policy_view, "read", &fulfilled).is_permit());
+    }
+
+    #[test]
+    fn test_duty_whose_constraints_do_not_hold_is_not_due() {
+        let policy_view = policy(json!({
+            "permission": [{
+                "action": "use",
+                "duty": { "action": "compensate", "constraint": [atomic("dateTime", "gt", json!("2030-01-01"))] }
+            }]
+        }));
+        let decision = evaluate(&policy_view, "read", &context());
+        assert!(decision.is_permit());
+        let duty = decision.rules.iter().find(|rule| rule.rule_type == OdrlRuleType::Duty).unwrap();
+        assert!(!duty.fired);
+    }
+
+    #[test]
+    fn test_obligations_are_reported_without_blocking() {
+        let policy_view = policy(json!({
+            "permission": [{ "action": "use" }],
+            "obligation": [
+                { "action": "inform" },
+                { "action": "delete", "constraint": [atomic("dateTime", "gt", json!("2030-01-01"))] },
+                { "action": "compensate" }
+            ]
+        }));
+        let context = OdrlRequestContext {
+            fulfilled_duties: vec!["odrl:compensate".to_string()],
+            ..context()
+        };
+        let decision = evaluate(&policy_view, "read", &context);
+        assert!(decision.is_permit());
+        assert_eq!(decision.pending_obligations, vec!["inform".to_string()]);
+    }
+
+    #[test]
+    fn test_policy_scope() {
+        let target = Urn::from_str("urn:dataset:weather").unwrap();
+        let policy_view = OdrlPolicyView {
+            target: Some(target.clone()),
+            assignee: Some("did:web:consumer".to_string()),
+            ..policy(json!({ "permission": [{ "action": "use" }] }))
+        };
+        let in_scope = OdrlRequestContext {
+            target: Some(target),
+            assignee: Some("did:web:consumer".to_string()),
+            ..context()
+        };
+        assert!(evaluate(&policy_view, "read", &in_scope).is_permit());
+
+        let other_target = OdrlRequestContext {
+            target: Some(Urn::from_str("urn:dataset:traffic").unwrap()),
+            ..in_scope.clone()
+        };
+        assert!(!evaluate(&policy_view, "read", &other_target).is_permit());
+        let other_assignee =
+            OdrlRequestContext { assignee: Some("did:web:other".to_string()), ..in_scope };
+        assert!(!evaluate(&policy_view, "read", &other_assignee).is_permit());
+    }
+
+    #[test]
+    fn test_json_ld_operands() {
+        // prefixed and full IRI terms read as the bare term
+        assert!(holds(atomic("odrl:purpose", "eq", json!("research")), &context()));
+        assert!(holds(
+            atomic("http://www.w3.org/ns/odrl/2/spatial", "isA", json!("ES")),
+            &context()
+        ));
+        // typed literals and references compare by value
+        let until = json!({ "@value": "2027-01-01T00:00:00Z", "@type": "xsd:dateTime" });
+        assert!(holds(atomic("dateTime", "lt", until), &context()));
+        let since =
+            json!({ "@value": "2026-06-01", "@type": "http://www.w3.org/2001/XMLSchema#date" });
+        assert!(!holds(atomic("dateTime", "gteq", since), &context()));
+        assert!(holds(
+            atomic("purpose", "eq", json!({ "@id": "research" })),
+            &context()
+        ));
+        let limit = json!({ "@value": 10, "@type": "xsd:integer" });
+        assert!(holds(atomic("count", "lt", limit), &with_counter("count", 3)));
+        let purposes = json!([{ "@value": "teaching" }, { "@id": "research" }]);
+        assert!(holds(atomic("purpose", "isAnyOf", purposes), &context()));
+        let attribute = with_attribute("level", json!({ "@value": "3", "@type": "xsd:integer" }));
+        assert!(holds(atomic("level", "eq", json!("3")), &attribute));
+    }
+}
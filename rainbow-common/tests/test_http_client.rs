@@ -0,0 +1,135 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+// Tests corresponding to 'rainbow-common\src\http_client'
+
+use axum::http::{header::RETRY_AFTER, StatusCode};
+use axum::response::IntoResponse;
+use axum::routing::get;
+use axum::Router;
+use rainbow_common::http_client::{HttpClient, HttpClientError, RequestOptions, RetryPolicy};
+use serde_json::{json, Value};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    /// Answers `status` to the first `failures` calls and 200 afterwards
+    async fn flaky_peer(
+        status: StatusCode,
+        retry_after: Option<&'static str>,
+        failures: usize,
+    ) -> (String, Arc<AtomicUsize>) {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = calls.clone();
+        let handler = move || {
+            let counter = counter.clone();
+            async move {
+                if counter.fetch_add(1, Ordering::SeqCst) < failures {
+                    let mut response = status.into_response();
+                    if let Some(retry_after) = retry_after {
+                        response.headers_mut().insert(RETRY_AFTER, retry_after.parse().unwrap());
+                    }
+                    return response;
+                }
+                axum::Json(json!({ "ok": true })).into_response()
+            }
+        };
+        let router = Router::new().route("/", get(handler.clone()).post(handler));
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, router).await.unwrap() });
+        (format!("http://{}/", address), calls)
+    }
+
+    fn fast_retries() -> RequestOptions {
+        RequestOptions::new().with_retry_policy(RetryPolicy {
+            max_retries: 3,
+            base_delay: Duration::from_millis(10),
+            max_delay: Duration::from_secs(2),
+        })
+    }
+
+    #[tokio::test]
+    async fn test_service_unavailable_is_retried() {
+        let (url, calls) = flaky_peer(StatusCode::SERVICE_UNAVAILABLE, None, 2).await;
+        let client = HttpClient::new(4, 5);
+
+        let body: Value = client.get_json_with_options(&url, &fast_retries()).await.unwrap();
+        assert_eq!(body, json!({ "ok": true }));
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_too_many_requests_waits_for_retry_after() {
+        let (url, calls) = flaky_peer(StatusCode::TOO_MANY_REQUESTS, Some("1"), 1).await;
+        let client = HttpClient::new(4, 5);
+
+        let started = Instant::now();
+        let _: Value = client.get_json_with_options(&url, &fast_retries()).await.unwrap();
+        // Retry-After wins over the 10ms backoff of the policy
+        assert!(started.elapsed() >= Duration::from_secs(1));
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_retry_after_beyond_max_delay_gives_up() {
+        let (url, calls) = flaky_peer(StatusCode::SERVICE_UNAVAILABLE, Some("3600"), 1).await;
+        let client = HttpClient::new(4, 5);
+
+        let err = client.get_json_with_options::<Value>(&url, &fast_retries()).await.unwrap_err();
+        match err {
+            HttpClientError::HttpError { status, retry_after, .. } => {
+                assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+                assert_eq!(retry_after, Some(Duration::from_secs(3600)));
+            }
+            other => panic!("Expected HttpError, got {:?}", other),
+        }
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_retries_stop_at_max_retries() {
+        let (url, calls) = flaky_peer(StatusCode::SERVICE_UNAVAILABLE, None, usize::MAX).await;
+        let client = HttpClient::new(4, 5);
+
+        assert!(client.get_json_with_options::<Value>(&url, &fast_retries()).await.is_err());
+        assert_eq!(calls.load(Ordering::SeqCst), 4);
+    }
+
+    #[tokio::test]
+    async fn test_post_is_only_retried_when_idempotent() {
+        let (url, calls) = flaky_peer(StatusCode::SERVICE_UNAVAILABLE, None, 2).await;
+        let client = HttpClient::new(4, 5);
+        let payload = json!({ "hello": "world" });
+
+        let result =
+            client.post_json_with_options::<_, Value>(&url, &payload, &fast_retries()).await;
+        assert!(result.is_err());
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        let options = fast_retries().with_idempotency_key("urn:uuid:1");
+        let _: Value = client.post_json_with_options(&url, &payload, &options).await.unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+}
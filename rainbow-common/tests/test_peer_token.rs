@@ -0,0 +1,252 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+// Tests corresponding to 'rainbow-common\src\facades\ssi_auth_facade'
+
+use anyhow::anyhow;
+use axum::http::{header::AUTHORIZATION, HeaderMap, StatusCode};
+use axum::routing::post;
+use axum::Router;
+use rainbow_common::config::services::MinKnownConfig;
+use rainbow_common::facades::ssi_auth_facade::peer_token_facade::{
+    PeerTokenError, PeerTokenFacadeService,
+};
+use rainbow_common::facades::ssi_auth_facade::{
+    send_with_peer_token, MockMatesFacadeTrait, MockPeerTokenFacadeTrait, PeerTokenFacadeTrait,
+};
+use rainbow_common::http_client::{HttpClient, RequestOptions};
+use rainbow_common::mates::Mates;
+use serde_json::{json, Value};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    const PEER: &str = "did:web:provider.example.org";
+
+    /// Peer that only accepts `Bearer fresh`, counting every call it gets
+    async fn peer() -> (String, Arc<AtomicUsize>) {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = calls.clone();
+        let router = Router::new().route(
+            "/catalog/request",
+            post(move |headers: HeaderMap| {
+                let counter = counter.clone();
+                async move {
+                    counter.fetch_add(1, Ordering::SeqCst);
+                    match headers.get(AUTHORIZATION).and_then(|value| value.to_str().ok()) {
+                        Some("Bearer fresh") => Ok(axum::Json(json!({ "ok": true }))),
+                        _ => Err(StatusCode::UNAUTHORIZED),
+                    }
+                }
+            }),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, router).await.unwrap() });
+        (format!("http://{}/catalog/request", address), calls)
+    }
+
+    async fn send(
+        peer_token_facade: &MockPeerTokenFacadeTrait,
+        url: &str,
+    ) -> anyhow::Result<Value> {
+        let client = HttpClient::new(4, 5);
+        let body = json!({ "@type": "CatalogRequestMessage" });
+        send_with_peer_token(peer_token_facade, PEER, RequestOptions::new(), |options| {
+            let client = &client;
+            let body = &body;
+            async move { client.post_json_with_options(url, body, &options).await }
+        })
+        .await
+    }
+
+    #[tokio::test]
+    async fn test_current_token_is_used() {
+        let (url, calls) = peer().await;
+        let mut peer_token_facade = MockPeerTokenFacadeTrait::new();
+        peer_token_facade
+            .expect_get_peer_token()
+            .withf(|participant_id| participant_id == PEER)
+            .times(1)
+            .returning(|_| Ok("fresh".to_string()));
+        peer_token_facade.expect_refresh_peer_token().never();
+
+        let body = send(&peer_token_facade, &url).await.unwrap();
+
+        assert_eq!(body, json!({ "ok": true }));
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_rejected_token_is_refreshed_once() {
+        let (url, calls) = peer().await;
+        let mut peer_token_facade = MockPeerTokenFacadeTrait::new();
+        peer_token_facade.expect_get_peer_token().returning(|_| Ok("stale".to_string()));
+        peer_token_facade
+            .expect_refresh_peer_token()
+            .withf(|participant_id, rejected| participant_id == PEER && rejected == "stale")
+            .times(1)
+            .returning(|_, _| Ok("fresh".to_string()));
+
+        let body = send(&peer_token_facade, &url).await.unwrap();
+
+        assert_eq!(body, json!({ "ok": true }));
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_second_rejection_is_returned() {
+        let (url, calls) = peer().await;
+        let mut peer_token_facade = MockPeerTokenFacadeTrait::new();
+        peer_token_facade.expect_get_peer_token().returning(|_| Ok("stale".to_string()));
+        peer_token_facade
+            .expect_refresh_peer_token()
+            .times(1)
+            .returning(|_, _| Ok("still-stale".to_string()));
+
+        assert!(send(&peer_token_facade, &url).await.is_err());
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_failed_refresh_is_returned() {
+        let (url, calls) = peer().await;
+        let mut peer_token_facade = MockPeerTokenFacadeTrait::new();
+        peer_token_facade.expect_get_peer_token().returning(|_| Ok("stale".to_string()));
+        peer_token_facade
+            .expect_refresh_peer_token()
+            .returning(|_, _| Err(anyhow!("onboarding failed")));
+
+        let err = send(&peer_token_facade, &url).await.unwrap_err();
+
+        assert_eq!(err.to_string(), "onboarding failed");
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    const INTERACTION_URI: &str = "http://127.0.0.1:7001/interaction/1";
+
+    /// SSI auth service whose onboarding only starts the grant, answering with the
+    /// interaction uri. Counts the onboardings.
+    async fn ssi_auth() -> (Arc<MinKnownConfig>, Arc<AtomicUsize>) {
+        let onboardings = Arc::new(AtomicUsize::new(0));
+        let counter = onboardings.clone();
+        let router = Router::new().route(
+            "/api/v1/onboard/provider",
+            post(move |axum::Json(body): axum::Json<Value>| {
+                let counter = counter.clone();
+                async move {
+                    assert_eq!(body["id"], json!(PEER));
+                    counter.fetch_add(1, Ordering::SeqCst);
+                    INTERACTION_URI
+                }
+            }),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        tokio::spawn(async move { axum::serve(listener, router).await.unwrap() });
+        let config = serde_json::from_value::<MinKnownConfig>(json!({
+            "hosts": {
+                "http": { "protocol": "http", "url": "127.0.0.1", "port": port.to_string() },
+                "grpc": null,
+                "graphql": null
+            },
+            "api_version": "v1"
+        }))
+        .unwrap();
+        (Arc::new(config), onboardings)
+    }
+
+    fn mate(token: &str) -> Mates {
+        Mates::default4consumer(
+            Some(PEER.to_string()),
+            "provider".to_string(),
+            "http://provider.example.org".to_string(),
+            Some(token.to_string()),
+            Some("talk".to_string()),
+            false,
+        )
+    }
+
+    /// Mates table where the grant lands after `reads_until_granted` reads of the mate
+    fn mates(reads_until_granted: usize) -> MockMatesFacadeTrait {
+        let reads = AtomicUsize::new(0);
+        let mut mates_facade = MockMatesFacadeTrait::new();
+        mates_facade.expect_get_mate_by_id().returning(move |_| {
+            match reads.fetch_add(1, Ordering::SeqCst) < reads_until_granted {
+                true => Ok(mate("stale")),
+                false => Ok(mate("fresh")),
+            }
+        });
+        mates_facade
+    }
+
+    fn peer_token_facade(
+        config: Arc<MinKnownConfig>,
+        mates_facade: MockMatesFacadeTrait,
+    ) -> PeerTokenFacadeService {
+        PeerTokenFacadeService::new(config, Arc::new(HttpClient::new(4, 5)), Arc::new(mates_facade))
+            .with_onboarding_wait(Duration::from_secs(2))
+    }
+
+    #[tokio::test]
+    async fn test_refresh_waits_for_the_grant() {
+        let (config, onboardings) = ssi_auth().await;
+        // the first read sees the rejected token, the grant lands while polling
+        let peer_token_facade = peer_token_facade(config, mates(3));
+
+        let token = peer_token_facade.refresh_peer_token(PEER, "stale").await.unwrap();
+
+        assert_eq!(token, "fresh");
+        assert_eq!(onboardings.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_pending_interaction_is_returned() {
+        let (config, onboardings) = ssi_auth().await;
+        let peer_token_facade = peer_token_facade(config, mates(usize::MAX))
+            .with_onboarding_wait(Duration::from_millis(300));
+
+        let err = peer_token_facade.refresh_peer_token(PEER, "stale").await.unwrap_err();
+
+        match err.downcast_ref::<PeerTokenError>() {
+            Some(PeerTokenError::InteractionPending { participant_id, interaction_uri }) => {
+                assert_eq!(participant_id, PEER);
+                assert_eq!(interaction_uri, INTERACTION_URI);
+            }
+            None => panic!("expected a pending interaction, got {}", err),
+        }
+        assert_eq!(onboardings.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_token_renewed_meanwhile_skips_onboarding() {
+        let (config, onboardings) = ssi_auth().await;
+        let peer_token_facade = peer_token_facade(config, mates(0));
+
+        let token = peer_token_facade.refresh_peer_token(PEER, "stale").await.unwrap();
+
+        assert_eq!(token, "fresh");
+        assert_eq!(onboardings.load(Ordering::SeqCst), 0);
+    }
+}
@@ -0,0 +1,301 @@
+use anyhow::bail;
+use bytes::Bytes;
+use futures::stream::BoxStream;
+use futures::{StreamExt, TryStreamExt};
+use rainbow_common::dsp_common::data_address::{DataAddress, EndpointProperty};
+use rainbow_common::errors::helpers::BadFormat;
+use rainbow_common::errors::{CommonErrors, ErrorLog};
+use reqwest::header::{AUTHORIZATION, CONTENT_DISPOSITION};
+use reqwest::{Body, Client};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use tokio_util::io::ReaderStream;
+use tracing::error;
+use url::Url;
+
+pub const DOWNSTREAM_HOP_URL_FIELD: &str = "DownstreamHopAddressUrl";
+pub const DOWNSTREAM_HOP_AUTH_FIELD: &str = "DownstreamHopAddressAuth";
+pub const DOWNSTREAM_HOP_AUTH_CONTENT_FIELD: &str = "DownstreamHopAddressAuthContent";
+pub const UPSTREAM_HOP_PROTOCOL_FIELD: &str = "UpstreamHopAddressProtocol";
+pub const UPSTREAM_HOP_URL_FIELD: &str = "UpstreamHopAddressUrl";
+pub const UPSTREAM_HOP_AUTH_FIELD: &str = "UpstreamHopAddressAuth";
+pub const UPSTREAM_HOP_AUTH_CONTENT_FIELD: &str = "UpstreamHopAddressAuthContent";
+const AUTH_TYPE_PROPERTY: &str = "authType";
+const AUTHORIZATION_PROPERTY: &str = "authorization";
+
+pub type DataStream = BoxStream<'static, anyhow::Result<Bytes>>;
+
+/// A single unit of data read from a source. File sources pointing to a folder
+/// yield one object per file.
+pub struct DataObject {
+    pub name: Option<String>,
+    pub stream: DataStream,
+}
+
+#[derive(Debug, Clone)]
+pub enum DataSourceEndpoint {
+    Http { url: String, authorization: Option<String> },
+    File { path: PathBuf },
+}
+
+#[derive(Debug, Clone)]
+pub enum DataSinkEndpoint {
+    HttpPush { url: String, authorization: Option<String> },
+}
+
+impl DataSourceEndpoint {
+    /// Source is the downstream hop stored on provision
+    pub fn from_fields(fields: &HashMap<String, String>) -> anyhow::Result<Self> {
+        let endpoint =
+            fields.get(DOWNSTREAM_HOP_URL_FIELD).filter(|e| !e.is_empty()).ok_or_else(|| {
+                let err = CommonErrors::format_new(
+                    BadFormat::Received,
+                    "Data plane session has no downstream hop address to read from",
+                );
+                error!("{}", err.log());
+                err
+            })?;
+        let authorization = authorization_header(
+            fields.get(DOWNSTREAM_HOP_AUTH_FIELD).map(|a| a.as_str()),
+            fields.get(DOWNSTREAM_HOP_AUTH_CONTENT_FIELD).map(|a| a.as_str()),
+        );
+        let url = parse_endpoint(endpoint)?;
+        match url.scheme() {
+            "http" | "https" => Ok(Self::Http { url: url.to_string(), authorization }),
+            "file" => Ok(Self::File { path: file_path(&url)? }),
+            scheme => {
+                let err = CommonErrors::format_new(
+                    BadFormat::Received,
+                    format!("Data source scheme {} is not supported", scheme).as_str(),
+                );
+                error!("{}", err.log());
+                bail!(err)
+            }
+        }
+    }
+
+    pub async fn ping(&self, client: &Client) -> anyhow::Result<()> {
+        match self {
+            Self::Http { url, authorization } => {
+                let mut request = client.head(url);
+                if let Some(authorization) = authorization {
+                    request = request.header(AUTHORIZATION, authorization);
+                }
+                let response = request.send().await.map_err(|e| {
+                    let err = CommonErrors::petition_new(url, "HEAD", None, &e.to_string());
+                    error!("{}", err.log());
+                    err
+                })?;
+                if !response.status().is_success() {
+                    let err = CommonErrors::petition_new(
+                        url,
+                        "HEAD",
+                        Some(response.status().as_u16()),
+                        "Data source is not reachable",
+                    );
+                    error!("{}", err.log());
+                    bail!(err)
+                }
+                Ok(())
+            }
+            Self::File { path } => {
+                tokio::fs::metadata(path).await.map_err(|e| {
+                    let err = CommonErrors::read_new(&path.to_string_lossy(), &e.to_string());
+                    error!("{}", err.log());
+                    err
+                })?;
+                Ok(())
+            }
+        }
+    }
+
+    pub async fn open(&self, client: &Client) -> anyhow::Result<Vec<DataObject>> {
+        match self {
+            Self::Http { url, authorization } => {
+                let mut request = client.get(url);
+                if let Some(authorization) = authorization {
+                    request = request.header(AUTHORIZATION, authorization);
+                }
+                let response = request.send().await.map_err(|e| {
+                    let err = CommonErrors::petition_new(url, "GET", None, &e.to_string());
+                    error!("{}", err.log());
+                    err
+                })?;
+                if !response.status().is_success() {
+                    let err = CommonErrors::petition_new(
+                        url,
+                        "GET",
+                        Some(response.status().as_u16()),
+                        "Data source answered with an error",
+                    );
+                    error!("{}", err.log());
+                    bail!(err)
+                }
+                let stream = response.bytes_stream().map_err(anyhow::Error::from).boxed();
+                Ok(vec![DataObject { name: None, stream }])
+            }
+            Self::File { path } => {
+                let metadata = tokio::fs::metadata(path).await.map_err(|e| {
+                    let err = CommonErrors::read_new(&path.to_string_lossy(), &e.to_string());
+                    error!("{}", err.log());
+                    err
+                })?;
+                let mut paths = vec![];
+                if metadata.is_dir() {
+                    let mut entries = tokio::fs::read_dir(path).await?;
+                    while let Some(entry) = entries.next_entry().await? {
+                        if entry.file_type().await?.is_file() {
+                            paths.push(entry.path());
+                        }
+                    }
+                    paths.sort();
+                } else {
+                    paths.push(path.clone());
+                }
+                let mut objects = vec![];
+                for path in paths {
+                    let file = tokio::fs::File::open(&path).await.map_err(|e| {
+                        let err = CommonErrors::read_new(&path.to_string_lossy(), &e.to_string());
+                        error!("{}", err.log());
+                        err
+                    })?;
+                    objects.push(DataObject {
+                        name: path.file_name().map(|n| n.to_string_lossy().to_string()),
+                        stream: ReaderStream::new(file).map_err(anyhow::Error::from).boxed(),
+                    });
+                }
+                Ok(objects)
+            }
+        }
+    }
+}
+
+impl DataSinkEndpoint {
+    /// Sink is the consumer supplied data address, so only http(s) pushes are accepted.
+    /// Local paths are never written on behalf of a peer.
+    pub fn from_data_address(data_address: &DataAddress) -> anyhow::Result<Self> {
+        let property = |name: &str| {
+            data_address
+                .endpoint_properties
+                .iter()
+                .find(|p| p.name == name)
+                .map(|p| p.value.as_str())
+        };
+        let authorization =
+            authorization_header(property(AUTH_TYPE_PROPERTY), property(AUTHORIZATION_PROPERTY));
+        let url = parse_endpoint(&data_address.endpoint)?;
+        match url.scheme() {
+            "http" | "https" => Ok(Self::HttpPush { url: url.to_string(), authorization }),
+            scheme => {
+                let err = CommonErrors::forbidden_new(
+                    format!("Data sink scheme {} is not allowed for pushed data", scheme).as_str(),
+                );
+                error!("{}", err.log());
+                bail!(err)
+            }
+        }
+    }
+
+    /// Writes the object into the sink and returns the number of bytes delivered
+    pub async fn deliver(&self, client: &Client, object: DataObject) -> anyhow::Result<u64> {
+        let bytes = Arc::new(AtomicU64::new(0));
+        let counter = bytes.clone();
+        let mut stream = object
+            .stream
+            .inspect_ok(move |chunk| {
+                counter.fetch_add(chunk.len() as u64, Ordering::Relaxed);
+            })
+            .boxed();
+        match self {
+            Self::HttpPush { url, authorization } => {
+                let mut request = client.post(url);
+                if let Some(authorization) = authorization {
+                    request = request.header(AUTHORIZATION, authorization);
+                }
+                if let Some(name) = &object.name {
+                    request = request
+                        .header(CONTENT_DISPOSITION, format!("attachment; filename=\"{}\"", name));
+                }
+                let response =
+                    request.body(Body::wrap_stream(stream)).send().await.map_err(|e| {
+                        let err = CommonErrors::consumer_new(url, "POST", None, &e.to_string());
+                        error!("{}", err.log());
+                        err
+                    })?;
+                if !response.status().is_success() {
+                    let err = CommonErrors::consumer_new(
+                        url,
+                        "POST",
+                        Some(response.status().as_u16()),
+                        "Data sink rejected the pushed data",
+                    );
+                    error!("{}", err.log());
+                    bail!(err)
+                }
+            }
+        }
+        Ok(bytes.load(Ordering::Relaxed))
+    }
+}
+
+/// Sink of a push session is the upstream hop, i.e. the consumer DataAddress
+pub fn sink_address_from_fields(fields: &HashMap<String, String>) -> Option<DataAddress> {
+    let field = |key: &str| fields.get(key).filter(|value| !value.is_empty()).cloned();
+    let endpoint = field(UPSTREAM_HOP_URL_FIELD)?;
+    let mut endpoint_properties = vec![];
+    if let Some(auth_type) = field(UPSTREAM_HOP_AUTH_FIELD) {
+        endpoint_properties.push(EndpointProperty {
+            _type: "EndpointProperty".to_string(),
+            name: AUTH_TYPE_PROPERTY.to_string(),
+            value: auth_type,
+        });
+    }
+    if let Some(authorization) = field(UPSTREAM_HOP_AUTH_CONTENT_FIELD) {
+        endpoint_properties.push(EndpointProperty {
+            _type: "EndpointProperty".to_string(),
+            name: AUTHORIZATION_PROPERTY.to_string(),
+            value: authorization,
+        });
+    }
+    Some(DataAddress {
+        _type: "DataAddress".to_string(),
+        endpoint_type: field(UPSTREAM_HOP_PROTOCOL_FIELD).unwrap_or_default(),
+        endpoint,
+        endpoint_properties,
+    })
+}
+
+fn parse_endpoint(endpoint: &str) -> anyhow::Result<Url> {
+    Url::parse(endpoint).map_err(|e| {
+        let err = CommonErrors::format_new(
+            BadFormat::Received,
+            format!("Endpoint {} is not a valid url: {}", endpoint, e).as_str(),
+        );
+        error!("{}", err.log());
+        anyhow::anyhow!(err)
+    })
+}
+
+fn file_path(url: &Url) -> anyhow::Result<PathBuf> {
+    url.to_file_path().map_err(|_| {
+        let err = CommonErrors::format_new(
+            BadFormat::Received,
+            format!("Endpoint {} is not a valid file path", url).as_str(),
+        );
+        error!("{}", err.log());
+        anyhow::anyhow!(err)
+    })
+}
+
+/// DSP endpoint properties carry the auth type and the credential separately
+fn authorization_header(auth_type: Option<&str>, credential: Option<&str>) -> Option<String> {
+    let credential = credential.filter(|c| !c.is_empty())?;
+    match auth_type.filter(|t| !t.is_empty()) {
+        Some(auth_type) if !credential.starts_with(auth_type) => {
+            Some(format!("{} {}", auth_type, credential))
+        }
+        _ => Some(credential.to_string()),
+    }
+}
@@ -1,11 +1,156 @@
+use crate::coordinator::data_source_connector::connectors::{
+    sink_address_from_fields, DataSinkEndpoint, DataSourceEndpoint,
+};
+use crate::coordinator::data_source_connector::delivery::{
+    DeliverySchedule, DELIVERED_BYTES_FIELD, DELIVERY_ERROR_FIELD, LAST_DELIVERY_AT_FIELD,
+};
 use crate::coordinator::data_source_connector::DataSourceConnectorTrait;
+use crate::entities::data_plane_process::{
+    DataPlaneProcessDto, DataPlaneProcessEntitiesTrait, EditDataPlaneProcessDto,
+};
+use anyhow::bail;
+use rainbow_common::adv_protocol::interplane::{DataPlaneProcessDirection, DataPlaneProcessState};
 use rainbow_common::dsp_common::data_address::DataAddress;
+use rainbow_common::errors::{CommonErrors, ErrorLog};
+use reqwest::Client;
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Mutex;
+use tokio::task::JoinHandle;
+use tokio_util::sync::CancellationToken;
+use tracing::{error, info, warn};
 use urn::Urn;
+use uuid::Uuid;
+
+const STOP_GRACE_PERIOD: Duration = Duration::from_secs(10);
+
+struct SessionTask {
+    task_id: Uuid,
+    cancellation_token: CancellationToken,
+    handle: JoinHandle<()>,
+}
+
+type SessionTasks = Arc<Mutex<HashMap<String, SessionTask>>>;
+
+/// Moves data from the session source (downstream hop) into a sink DataAddress.
+/// Each session runs in its own task, cancelled on stop. Deliveries follow the
+/// session DeliverySchedule, so a stopped session can be resumed by starting it again.
+/// A session whose delivery keeps failing past its retries is terminated.
+pub struct DataSourceConnector {
+    dataplane_process_entity: Arc<dyn DataPlaneProcessEntitiesTrait>,
+    client: Client,
+    sessions: SessionTasks,
+}
 
-pub struct DataSourceConnector;
 impl DataSourceConnector {
-    pub fn new() -> Self {
-        Self {}
+    pub fn new(dataplane_process_entity: Arc<dyn DataPlaneProcessEntitiesTrait>) -> Self {
+        Self {
+            dataplane_process_entity,
+            client: Client::new(),
+            sessions: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    async fn get_dataplane(&self, session_id: &Urn) -> anyhow::Result<DataPlaneProcessDto> {
+        self.dataplane_process_entity.get_data_plane_process_by_id(session_id).await?.ok_or_else(
+            || {
+                let err = CommonErrors::missing_resource_new(
+                    session_id.to_string().as_str(),
+                    "Data plane session not found",
+                );
+                error!("{}", err.log());
+                err.into()
+            },
+        )
+    }
+
+    async fn run_session(
+        client: Client,
+        dataplane_process_entity: Arc<dyn DataPlaneProcessEntitiesTrait>,
+        session_id: Urn,
+        source: DataSourceEndpoint,
+        sink: DataSinkEndpoint,
+        schedule: DeliverySchedule,
+        mut delivered: u64,
+    ) -> anyhow::Result<u64> {
+        loop {
+            delivered +=
+                Self::deliver_with_retries(&client, &session_id, &source, &sink, &schedule).await?;
+            let fields = HashMap::from([
+                (LAST_DELIVERY_AT_FIELD.to_string(), chrono::Utc::now().to_rfc3339()),
+                (DELIVERED_BYTES_FIELD.to_string(), delivered.to_string()),
+            ]);
+            dataplane_process_entity
+                .put_data_plane_process(
+                    &session_id,
+                    &EditDataPlaneProcessDto { state: None, fields: Some(fields) },
+                )
+                .await?;
+            match schedule.interval {
+                Some(interval) => tokio::time::sleep(interval).await,
+                None => return Ok(delivered),
+            }
+        }
+    }
+
+    /// Streaming ended on an error, the session cannot deliver anymore
+    async fn terminate_session(
+        dataplane_process_entity: &dyn DataPlaneProcessEntitiesTrait,
+        session_id: &Urn,
+        reason: &anyhow::Error,
+    ) {
+        let fields = HashMap::from([(DELIVERY_ERROR_FIELD.to_string(), reason.to_string())]);
+        if let Err(e) = dataplane_process_entity
+            .put_data_plane_process(
+                session_id,
+                &EditDataPlaneProcessDto {
+                    state: Some(DataPlaneProcessState::TERMINATED.to_string()),
+                    fields: Some(fields),
+                },
+            )
+            .await
+        {
+            error!("Data plane session {} could not be terminated: {}", session_id, e);
+        }
+    }
+
+    async fn deliver_with_retries(
+        client: &Client,
+        session_id: &Urn,
+        source: &DataSourceEndpoint,
+        sink: &DataSinkEndpoint,
+        schedule: &DeliverySchedule,
+    ) -> anyhow::Result<u64> {
+        let mut attempt = 0;
+        loop {
+            match Self::deliver_once(client, source, sink).await {
+                Ok(bytes) => return Ok(bytes),
+                Err(e) if attempt < schedule.max_retries => {
+                    attempt += 1;
+                    let backoff = DeliverySchedule::retry_backoff(attempt);
+                    warn!(
+                        "Delivery of data plane session {} failed ({}), retry {}/{} in {:?}",
+                        session_id, e, attempt, schedule.max_retries, backoff
+                    );
+                    tokio::time::sleep(backoff).await;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    async fn deliver_once(
+        client: &Client,
+        source: &DataSourceEndpoint,
+        sink: &DataSinkEndpoint,
+    ) -> anyhow::Result<u64> {
+        let mut delivered = 0;
+        for object in source.open(client).await? {
+            delivered += sink.deliver(client, object).await?;
+        }
+        Ok(delivered)
     }
 }
 
@@ -16,14 +161,131 @@ impl DataSourceConnectorTrait for DataSourceConnector {
         session_id: &Urn,
         sink_address: &DataAddress,
     ) -> anyhow::Result<()> {
-        todo!()
+        let dataplane = self.get_dataplane(session_id).await?;
+        let fields = &dataplane.data_plane_fields;
+        let source = DataSourceEndpoint::from_fields(fields)?;
+        let sink = DataSinkEndpoint::from_data_address(sink_address)?;
+        let schedule = DeliverySchedule::from_fields(fields)?;
+        let already_delivered = fields.get(LAST_DELIVERY_AT_FIELD).is_some_and(|at| !at.is_empty());
+        // resuming a one-shot session that already delivered must not send the data again
+        if schedule.is_one_shot() && already_delivered {
+            info!(
+                "Data plane session {} already delivered, nothing to resume",
+                session_id
+            );
+            return Ok(());
+        }
+        let delivered =
+            fields.get(DELIVERED_BYTES_FIELD).and_then(|b| b.parse::<u64>().ok()).unwrap_or(0);
+        let first_delivery_in = schedule.first_delivery_delay(
+            fields.get(LAST_DELIVERY_AT_FIELD).map(|at| at.as_str()),
+            chrono::Utc::now(),
+        );
+
+        let mut sessions = self.sessions.lock().await;
+        let session_key = session_id.to_string();
+        if sessions.get(&session_key).is_some_and(|task| !task.handle.is_finished()) {
+            let err = CommonErrors::conflict_new(
+                format!("Data plane session {} is already streaming", session_key).as_str(),
+            );
+            error!("{}", err.log());
+            bail!(err)
+        }
+
+        let task_id = Uuid::new_v4();
+        let cancellation_token = CancellationToken::new();
+        let handle = tokio::spawn({
+            let client = self.client.clone();
+            let dataplane_process_entity = self.dataplane_process_entity.clone();
+            let session_id = session_id.clone();
+            let sessions = self.sessions.clone();
+            let cancellation_token = cancellation_token.clone();
+            let session_key = session_key.clone();
+            async move {
+                tokio::select! {
+                    _ = cancellation_token.cancelled() => {
+                        info!("Streaming of data plane session {} stopped", session_key);
+                    }
+                    result = async {
+                        tokio::time::sleep(first_delivery_in).await;
+                        Self::run_session(
+                            client,
+                            dataplane_process_entity.clone(),
+                            session_id.clone(),
+                            source,
+                            sink,
+                            schedule,
+                            delivered,
+                        )
+                        .await
+                    } => match result {
+                        Ok(bytes) => info!(
+                            "Streaming of data plane session {} finished, {} bytes delivered",
+                            session_key, bytes
+                        ),
+                        Err(e) => {
+                            error!("Streaming of data plane session {} failed: {}", session_key, e);
+                            Self::terminate_session(
+                                dataplane_process_entity.as_ref(),
+                                &session_id,
+                                &e,
+                            )
+                            .await;
+                        }
+                    }
+                }
+                // a restarted session has a new task registered under the same key
+                let mut sessions = sessions.lock().await;
+                if sessions.get(&session_key).is_some_and(|task| task.task_id == task_id) {
+                    sessions.remove(&session_key);
+                }
+            }
+        });
+        sessions.insert(session_key, SessionTask { task_id, cancellation_token, handle });
+        Ok(())
     }
 
     async fn stop_streaming(&self, session_id: &Urn) -> anyhow::Result<()> {
-        todo!()
+        let task = self.sessions.lock().await.remove(&session_id.to_string());
+        let Some(mut task) = task else {
+            return Ok(());
+        };
+        task.cancellation_token.cancel();
+        if tokio::time::timeout(STOP_GRACE_PERIOD, &mut task.handle).await.is_err() {
+            warn!("Data plane session {} did not stop in time, aborting", session_id);
+            task.handle.abort();
+        }
+        Ok(())
+    }
+
+    async fn resume_sessions(&self) -> anyhow::Result<()> {
+        let sessions = self
+            .dataplane_process_entity
+            .get_data_plane_processes_by_state(
+                &DataPlaneProcessState::STARTED.to_string(),
+                &DataPlaneProcessDirection::PUSH.to_string(),
+            )
+            .await?;
+        for session in sessions {
+            let Ok(session_id) = Urn::from_str(&session.inner.id) else {
+                warn!("Data plane session {} has no valid id, not resumed", session.inner.id);
+                continue;
+            };
+            let Some(sink_address) = sink_address_from_fields(&session.data_plane_fields) else {
+                warn!("Data plane session {} has no sink, not resumed", session_id);
+                continue;
+            };
+            match self.start_streaming(&session_id, &sink_address).await {
+                Ok(()) => info!("Streaming of data plane session {} resumed", session_id),
+                Err(e) => warn!("Data plane session {} could not be resumed: {}", session_id, e),
+            }
+        }
+        Ok(())
     }
 
     async fn ping_source(&self, session_id: &Urn) -> anyhow::Result<()> {
-        todo!()
+        let dataplane = self.get_dataplane(session_id).await?;
+        let source = DataSourceEndpoint::from_fields(&dataplane.data_plane_fields)?;
+        source.ping(&self.client).await
     }
 }
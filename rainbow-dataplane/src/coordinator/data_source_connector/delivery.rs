@@ -0,0 +1,87 @@
+use chrono::{DateTime, Utc};
+use rainbow_common::errors::helpers::BadFormat;
+use rainbow_common::errors::{CommonErrors, ErrorLog};
+use std::collections::HashMap;
+use std::time::Duration;
+use tracing::error;
+
+pub const DELIVERY_INTERVAL_FIELD: &str = "DeliveryInterval";
+pub const DELIVERY_MAX_RETRIES_FIELD: &str = "DeliveryMaxRetries";
+pub const LAST_DELIVERY_AT_FIELD: &str = "LastDeliveryAt";
+pub const DELIVERED_BYTES_FIELD: &str = "DeliveredBytes";
+pub const DELIVERY_ERROR_FIELD: &str = "DeliveryError";
+const DEFAULT_MAX_RETRIES: u32 = 3;
+const MAX_RETRY_BACKOFF: Duration = Duration::from_secs(60);
+
+/// How a push session delivers: once, or every `interval` until stopped.
+/// Each delivery is retried up to `max_retries` times with exponential backoff.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeliverySchedule {
+    pub interval: Option<Duration>,
+    pub max_retries: u32,
+}
+
+impl Default for DeliverySchedule {
+    fn default() -> Self {
+        Self { interval: None, max_retries: DEFAULT_MAX_RETRIES }
+    }
+}
+
+impl DeliverySchedule {
+    pub fn from_fields(fields: &HashMap<String, String>) -> anyhow::Result<Self> {
+        let field = |key: &str| fields.get(key).map(|v| v.trim()).filter(|v| !v.is_empty());
+        let interval = match field(DELIVERY_INTERVAL_FIELD) {
+            Some(interval) => match interval.parse::<u64>() {
+                Ok(0) => None,
+                Ok(secs) => Some(Duration::from_secs(secs)),
+                Err(_) => {
+                    let err = CommonErrors::format_new(
+                        BadFormat::Received,
+                        format!("Delivery interval must be a number of seconds, got {}", interval)
+                            .as_str(),
+                    );
+                    error!("{}", err.log());
+                    return Err(err.into());
+                }
+            },
+            None => None,
+        };
+        let max_retries = match field(DELIVERY_MAX_RETRIES_FIELD) {
+            Some(retries) => retries.parse::<u32>().map_err(|_| {
+                let err = CommonErrors::format_new(
+                    BadFormat::Received,
+                    format!("Delivery max retries must be a positive number, got {}", retries)
+                        .as_str(),
+                );
+                error!("{}", err.log());
+                err
+            })?,
+            None => DEFAULT_MAX_RETRIES,
+        };
+        Ok(Self { interval, max_retries })
+    }
+
+    pub fn is_one_shot(&self) -> bool {
+        self.interval.is_none()
+    }
+
+    /// Wait before the first delivery of a (re)started session, so a periodic session
+    /// keeps its pace across stops and restarts of the data plane
+    pub fn first_delivery_delay(
+        &self,
+        last_delivery_at: Option<&str>,
+        now: DateTime<Utc>,
+    ) -> Duration {
+        let last_delivery_at =
+            last_delivery_at.and_then(|at| DateTime::parse_from_rfc3339(at).ok());
+        let (Some(interval), Some(last_delivery_at)) = (self.interval, last_delivery_at) else {
+            return Duration::ZERO;
+        };
+        let elapsed = (now - last_delivery_at.with_timezone(&Utc)).to_std().unwrap_or_default();
+        interval.saturating_sub(elapsed)
+    }
+
+    pub fn retry_backoff(attempt: u32) -> Duration {
+        Duration::from_secs(2u64.saturating_pow(attempt)).min(MAX_RETRY_BACKOFF)
+    }
+}
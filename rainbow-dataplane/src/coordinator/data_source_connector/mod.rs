@@ -1,8 +1,11 @@
+pub(crate) mod connectors;
 pub mod data_source_connector;
+pub(crate) mod delivery;
 
 use rainbow_common::dsp_common::data_address::DataAddress;
 use urn::Urn;
 
+#[mockall::automock]
 #[async_trait::async_trait]
 pub trait DataSourceConnectorTrait: Send + Sync {
     async fn start_streaming(
@@ -11,5 +14,7 @@ pub trait DataSourceConnectorTrait: Send + Sync {
         sink_address: &DataAddress,
     ) -> anyhow::Result<()>;
     async fn stop_streaming(&self, session_id: &Urn) -> anyhow::Result<()>;
+    /// Starts again the push sessions left started, e.g. before a restart of the data plane
+    async fn resume_sessions(&self) -> anyhow::Result<()>;
     async fn ping_source(&self, session_id: &Urn) -> anyhow::Result<()>;
 }
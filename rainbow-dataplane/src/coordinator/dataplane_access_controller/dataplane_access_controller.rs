@@ -1,7 +1,16 @@
+use crate::coordinator::data_source_connector::connectors::sink_address_from_fields;
+use crate::coordinator::data_source_connector::delivery::{
+    DELIVERY_INTERVAL_FIELD, DELIVERY_MAX_RETRIES_FIELD,
+};
 use crate::coordinator::data_source_connector::DataSourceConnectorTrait;
 use crate::coordinator::dataplane_access_controller::DataPlaneAccessControllerTrait;
 use crate::entities::data_plane_process::{
-    DataPlaneProcessEntitiesTrait, EditDataPlaneProcessDto, NewDataPlaneProcessDto,
+    DataPlaneProcessDto, DataPlaneProcessEntitiesTrait, EditDataPlaneProcessDto,
+    NewDataPlaneProcessDto,
+};
+use crate::testing_proxy::http::forwarding::{
+    PROXY_HEADERS_ALLOW_FIELD, PROXY_HEADERS_DENY_FIELD, PROXY_MAX_REQUEST_BODY_FIELD,
+    PROXY_MAX_RESPONSE_BODY_FIELD, PROXY_QUERY_ALLOW_FIELD, PROXY_QUERY_DENY_FIELD,
 };
 use rainbow_common::adv_protocol::interplane::data_plane_provision::{
     DataPlaneProvisionRequest, DataPlaneProvisionResponse,
@@ -21,14 +30,23 @@ use rainbow_common::adv_protocol::interplane::{
 use rainbow_common::config::services::TransferConfig;
 use rainbow_common::config::traits::CommonConfigTrait;
 use rainbow_common::dcat_formats::FormatAction;
+use rainbow_common::errors::helpers::BadFormat;
+use rainbow_common::errors::{CommonErrors, ErrorLog};
+use rainbow_events::core::notification::notification_publisher::RainbowEventsPublisherTrait;
+use rainbow_events::core::notification::notification_types::{
+    RainbowEventsNotificationEvent, RainbowEventsNotificationMessageCategory,
+    RainbowEventsNotificationMessageOperation, RainbowEventsNotificationMessageTypes,
+};
 use std::collections::HashMap;
 use std::sync::Arc;
+use tracing::error;
 use ymir::config::traits::HostsConfigTrait;
 use ymir::config::types::HostType;
 
 pub struct DataPlaneAccessControllerService {
     data_source_connector_service: Arc<dyn DataSourceConnectorTrait>,
     dataplane_process_entity: Arc<dyn DataPlaneProcessEntitiesTrait>,
+    events: Arc<dyn RainbowEventsPublisherTrait>,
     config: Arc<TransferConfig>,
 }
 
@@ -36,9 +54,23 @@ impl DataPlaneAccessControllerService {
     pub fn new(
         data_source_connector_service: Arc<dyn DataSourceConnectorTrait>,
         dataplane_process_entity: Arc<dyn DataPlaneProcessEntitiesTrait>,
+        events: Arc<dyn RainbowEventsPublisherTrait>,
         config: Arc<TransferConfig>,
     ) -> Self {
-        Self { data_source_connector_service, dataplane_process_entity, config }
+        Self { data_source_connector_service, dataplane_process_entity, events, config }
+    }
+
+    /// Only the process itself goes in the snapshot, its fields carry the hop credentials
+    fn notify_state_change(&self, subcategory: &str, dp_process: &DataPlaneProcessDto) {
+        let event = RainbowEventsNotificationEvent::new(
+            RainbowEventsNotificationMessageCategory::DataPlane,
+            subcategory,
+            RainbowEventsNotificationMessageTypes::RainbowEntitiesMessage,
+            RainbowEventsNotificationMessageOperation::Update,
+        )
+        .with_entity_id("sessionId", &dp_process.inner.id)
+        .with_snapshot(&dp_process.inner);
+        self.events.publish(event);
     }
 }
 
@@ -81,15 +113,68 @@ impl DataPlaneAccessControllerTrait for DataPlaneAccessControllerService {
         );
         dataplane_fields.insert(String::from("DownstreamHopAddressAuth"), "".to_string());
         dataplane_fields.insert(String::from("DownstreamHopAddressAuthContent"), "".to_string());
-        dataplane_fields.insert(String::from("UpstreamHopAddressProtocol"), "".to_string());
-        dataplane_fields.insert(String::from("UpstreamHopAddressUrl"), "".to_string());
-        dataplane_fields.insert(String::from("UpstreamHopAddressAuth"), "".to_string());
-        dataplane_fields.insert(String::from("UpstreamHopAddressAuthContent"), "".to_string());
+        // upstream hop is only known on push, where it is the consumer sink
+        let sdp_config_content = |config_type: DataPlaneSDPConfigTypes| {
+            sdp_config
+                .iter()
+                .find(|s| s._type == config_type)
+                .map(|s| s.content.to_string())
+                .unwrap_or_default()
+        };
+        dataplane_fields.insert(
+            String::from("UpstreamHopAddressProtocol"),
+            sdp_config_content(DataPlaneSDPConfigTypes::UpstreamHopAddressScheme),
+        );
+        dataplane_fields.insert(
+            String::from("UpstreamHopAddressUrl"),
+            sdp_config_content(DataPlaneSDPConfigTypes::UpstreamHopAddress),
+        );
+        dataplane_fields.insert(
+            String::from("UpstreamHopAddressAuth"),
+            sdp_config_content(DataPlaneSDPConfigTypes::UpstreamHopAddressAuthType),
+        );
+        dataplane_fields.insert(
+            String::from("UpstreamHopAddressAuthContent"),
+            sdp_config_content(DataPlaneSDPConfigTypes::UpstreamHopAddressAuth),
+        );
+        dataplane_fields.insert(
+            String::from(DELIVERY_INTERVAL_FIELD),
+            sdp_config_content(DataPlaneSDPConfigTypes::DeliveryInterval),
+        );
+        dataplane_fields.insert(
+            String::from(DELIVERY_MAX_RETRIES_FIELD),
+            sdp_config_content(DataPlaneSDPConfigTypes::DeliveryMaxRetries),
+        );
+        // forwarding rules of the proxy, later config changes leave the session as provisioned
+        let forwarding = self.config.data_plane_forwarding();
+        let limit = |limit: Option<u64>| limit.map(|limit| limit.to_string()).unwrap_or_default();
+        dataplane_fields.insert(
+            String::from(PROXY_MAX_REQUEST_BODY_FIELD),
+            limit(forwarding.max_request_body_size),
+        );
+        dataplane_fields.insert(
+            String::from(PROXY_MAX_RESPONSE_BODY_FIELD),
+            limit(forwarding.max_response_body_size),
+        );
+        dataplane_fields.insert(
+            String::from(PROXY_HEADERS_ALLOW_FIELD),
+            forwarding.headers_allow.join(","),
+        );
+        dataplane_fields.insert(
+            String::from(PROXY_HEADERS_DENY_FIELD),
+            forwarding.headers_deny.join(","),
+        );
+        dataplane_fields.insert(
+            String::from(PROXY_QUERY_ALLOW_FIELD),
+            forwarding.query_allow.join(","),
+        );
+        dataplane_fields
+            .insert(String::from(PROXY_QUERY_DENY_FIELD), forwarding.query_deny.join(","));
         let dataplane_response = self
             .dataplane_process_entity
             .create_data_plane_process(&NewDataPlaneProcessDto {
                 id: input.session_id.clone(),
-                direction: next_hop_direction_as.to_string(),
+                direction: DataPlaneProcessDirection::from(next_hop_direction_as).to_string(),
                 state: DataPlaneProcessState::REQUESTED.to_string(),
                 fields: Some(dataplane_fields),
             })
@@ -146,6 +231,35 @@ impl DataPlaneAccessControllerTrait for DataPlaneAccessControllerService {
     }
 
     async fn data_plane_start(&self, input: &DataPlaneStart) -> anyhow::Result<DataPlaneStartAck> {
+        let dp_process = self
+            .dataplane_process_entity
+            .get_data_plane_process_by_id(&input.session_id)
+            .await?
+            .ok_or_else(|| {
+                let err = CommonErrors::missing_resource_new(
+                    input.session_id.to_string().as_str(),
+                    "Data plane session not found",
+                );
+                error!("{}", err.log());
+                err
+            })?;
+        // push sessions are driven by the data plane, pull sessions wait for the consumer
+        if let Ok(DataPlaneProcessDirection::PUSH) =
+            dp_process.inner.direction.parse::<DataPlaneProcessDirection>()
+        {
+            let sink_address =
+                sink_address_from_fields(&dp_process.data_plane_fields).ok_or_else(|| {
+                    let err = CommonErrors::format_new(
+                        BadFormat::Received,
+                        "Push data plane session has no upstream hop address to deliver to",
+                    );
+                    error!("{}", err.log());
+                    err
+                })?;
+            self.data_source_connector_service
+                .start_streaming(&input.session_id, &sink_address)
+                .await?;
+        }
         let dp_process = self
             .dataplane_process_entity
             .put_data_plane_process(
@@ -156,6 +270,7 @@ impl DataPlaneAccessControllerTrait for DataPlaneAccessControllerService {
                 },
             )
             .await?;
+        self.notify_state_change("DataPlaneStart", &dp_process);
         Ok(DataPlaneStartAck {
             _type: DataPlaneControllerMessages::DataPlaneStartAck,
             version: DataPlaneControllerVersion::Version10,
@@ -164,6 +279,7 @@ impl DataPlaneAccessControllerTrait for DataPlaneAccessControllerService {
     }
 
     async fn data_plane_stop(&self, input: &DataPlaneStop) -> anyhow::Result<DataPlaneStopAck> {
+        self.data_source_connector_service.stop_streaming(&input.session_id).await?;
         let dp_process = self
             .dataplane_process_entity
             .put_data_plane_process(
@@ -174,6 +290,7 @@ impl DataPlaneAccessControllerTrait for DataPlaneAccessControllerService {
                 },
             )
             .await?;
+        self.notify_state_change("DataPlaneStop", &dp_process);
         Ok(DataPlaneStopAck {
             _type: DataPlaneControllerMessages::DataPlaneStopAck,
             version: DataPlaneControllerVersion::Version10,
@@ -22,12 +22,19 @@ use serde::{Deserialize, Serialize};
 use urn::Urn;
 
 #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize)]
-#[sea_orm(table_name = "transfer_events")]
+#[sea_orm(table_name = "data_plane_process")]
 pub struct Model {
-    #[sea_orm(primary_key)]
+    #[sea_orm(primary_key, auto_increment = false)]
     pub id: String,
     pub state: String,
     pub direction: String,
+    /// Requests the PDP let through, taken before the evaluation so concurrent ones never share a count
+    pub permitted_requests: i64,
+    /// Running totals of the exchanges forwarded by the proxy, so aggregates never scan the events
+    pub exchange_count: i64,
+    pub exchange_bytes_in: i64,
+    pub exchange_bytes_out: i64,
+    pub last_exchange_at: Option<DateTimeWithTimeZone>,
     pub created_at: DateTimeWithTimeZone,
     pub updated_at: Option<DateTimeWithTimeZone>,
 }
@@ -66,6 +73,11 @@ impl From<NewDataPlaneProcessModel> for ActiveModel {
             id: ActiveValue::Set(value.id.to_string()),
             state: ActiveValue::Set(value.state),
             direction: ActiveValue::Set(value.direction),
+            permitted_requests: ActiveValue::Set(0),
+            exchange_count: ActiveValue::Set(0),
+            exchange_bytes_in: ActiveValue::Set(0),
+            exchange_bytes_out: ActiveValue::Set(0),
+            last_exchange_at: ActiveValue::Set(None),
             created_at: ActiveValue::Set(chrono::Utc::now().into()),
             updated_at: ActiveValue::Set(None),
         }
@@ -0,0 +1,83 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use sea_orm_migration::prelude::*;
+
+pub struct Migration;
+impl MigrationName for Migration {
+    fn name(&self) -> &str {
+        "m20251128_0000005_exchange_totals"
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // one column per statement, sqlite does not take several alter options at once
+        for column in [
+            DataPlaneProcess::ExchangeCount,
+            DataPlaneProcess::ExchangeBytesIn,
+            DataPlaneProcess::ExchangeBytesOut,
+        ] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(DataPlaneProcess::Table)
+                        .add_column(ColumnDef::new(column).big_integer().not_null().default(0))
+                        .to_owned(),
+                )
+                .await?;
+        }
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(DataPlaneProcess::Table)
+                    .add_column(
+                        ColumnDef::new(DataPlaneProcess::LastExchangeAt).timestamp_with_time_zone(),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for column in [
+            DataPlaneProcess::ExchangeCount,
+            DataPlaneProcess::ExchangeBytesIn,
+            DataPlaneProcess::ExchangeBytesOut,
+            DataPlaneProcess::LastExchangeAt,
+        ] {
+            manager
+                .alter_table(
+                    Table::alter().table(DataPlaneProcess::Table).drop_column(column).to_owned(),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+pub enum DataPlaneProcess {
+    Table,
+    ExchangeCount,
+    ExchangeBytesIn,
+    ExchangeBytesOut,
+    LastExchangeAt,
+}
@@ -23,11 +23,15 @@ use sea_orm_migration::prelude::*;
 pub mod m20251128_0000001_data_plane_process;
 pub mod m20251128_0000002_data_plane_fields;
 pub mod m20251128_0000003_transfer_events;
+pub mod m20251128_0000004_permitted_requests;
+pub mod m20251128_0000005_exchange_totals;
 
 pub fn get_dataplane_migrations() -> Vec<Box<dyn MigrationTrait>> {
     vec![
         Box::new(m20251128_0000001_data_plane_process::Migration),
         Box::new(m20251128_0000002_data_plane_fields::Migration),
         Box::new(m20251128_0000003_transfer_events::Migration),
+        Box::new(m20251128_0000004_permitted_requests::Migration),
+        Box::new(m20251128_0000005_exchange_totals::Migration),
     ]
 }
@@ -5,9 +5,10 @@ use crate::data::entities::data_plane_process::{
 use crate::data::repo_traits::data_plane_process_repo::{
     DataPlaneProcessRepoErrors, DataPlaneProcessRepoTrait,
 };
+use sea_orm::sea_query::Expr;
 use sea_orm::{
     ActiveModelTrait, ActiveValue, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter,
-    QuerySelect,
+    QuerySelect, TransactionTrait,
 };
 use urn::Urn;
 
@@ -38,6 +39,22 @@ impl DataPlaneProcessRepoTrait for DataPlaneProcessRepoForSql {
         }
     }
 
+    async fn get_data_plane_processes_by_state(
+        &self,
+        state: &str,
+        direction: &str,
+    ) -> anyhow::Result<Vec<data_plane_process::Model>, DataPlaneProcessRepoErrors> {
+        let processes = data_plane_process::Entity::find()
+            .filter(data_plane_process::Column::State.eq(state))
+            .filter(data_plane_process::Column::Direction.eq(direction))
+            .all(&self.db_connection)
+            .await;
+        match processes {
+            Ok(processes) => Ok(processes),
+            Err(e) => Err(DataPlaneProcessRepoErrors::ErrorFetchingDataplaneProcess(e.into())),
+        }
+    }
+
     async fn get_batch_data_plane_processes(
         &self,
         ids: &Vec<Urn>,
@@ -124,4 +141,84 @@ impl DataPlaneProcessRepoTrait for DataPlaneProcessRepoForSql {
             Err(e) => Err(DataPlaneProcessRepoErrors::ErrorDeletingDataplaneProcess(e.into())),
         }
     }
+
+    async fn add_permitted_requests(
+        &self,
+        process_id: &Urn,
+        delta: i64,
+    ) -> anyhow::Result<i64, DataPlaneProcessRepoErrors> {
+        let id = process_id.to_string();
+        // the row stays locked by the update until commit, so the count read back is ours
+        let txn = match self.db_connection.begin().await {
+            Ok(txn) => txn,
+            Err(e) => {
+                return Err(DataPlaneProcessRepoErrors::ErrorUpdatingDataplaneProcess(e.into()))
+            }
+        };
+        let updated = data_plane_process::Entity::update_many()
+            .col_expr(
+                data_plane_process::Column::PermittedRequests,
+                Expr::col(data_plane_process::Column::PermittedRequests).add(delta),
+            )
+            .filter(data_plane_process::Column::Id.eq(id.clone()))
+            .exec(&txn)
+            .await;
+        match updated {
+            Ok(updated) if updated.rows_affected == 0 => {
+                return Err(DataPlaneProcessRepoErrors::DataplaneProcessNotFound)
+            }
+            Ok(_) => {}
+            Err(e) => {
+                return Err(DataPlaneProcessRepoErrors::ErrorUpdatingDataplaneProcess(e.into()))
+            }
+        }
+        let process = match data_plane_process::Entity::find_by_id(id).one(&txn).await {
+            Ok(Some(process)) => process,
+            Ok(None) => return Err(DataPlaneProcessRepoErrors::DataplaneProcessNotFound),
+            Err(e) => {
+                return Err(DataPlaneProcessRepoErrors::ErrorFetchingDataplaneProcess(e.into()))
+            }
+        };
+        match txn.commit().await {
+            Ok(_) => Ok(process.permitted_requests),
+            Err(e) => Err(DataPlaneProcessRepoErrors::ErrorUpdatingDataplaneProcess(e.into())),
+        }
+    }
+
+    async fn add_exchange(
+        &self,
+        process_id: &Urn,
+        bytes_in: i64,
+        bytes_out: i64,
+    ) -> anyhow::Result<(), DataPlaneProcessRepoErrors> {
+        let id = process_id.to_string();
+        // a single statement, concurrent exchanges never lose each other's bytes
+        let updated = data_plane_process::Entity::update_many()
+            .col_expr(
+                data_plane_process::Column::ExchangeCount,
+                Expr::col(data_plane_process::Column::ExchangeCount).add(1),
+            )
+            .col_expr(
+                data_plane_process::Column::ExchangeBytesIn,
+                Expr::col(data_plane_process::Column::ExchangeBytesIn).add(bytes_in),
+            )
+            .col_expr(
+                data_plane_process::Column::ExchangeBytesOut,
+                Expr::col(data_plane_process::Column::ExchangeBytesOut).add(bytes_out),
+            )
+            .col_expr(
+                data_plane_process::Column::LastExchangeAt,
+                Expr::value(chrono::Utc::now().fixed_offset()),
+            )
+            .filter(data_plane_process::Column::Id.eq(id))
+            .exec(&self.db_connection)
+            .await;
+        match updated {
+            Ok(updated) if updated.rows_affected == 0 => {
+                Err(DataPlaneProcessRepoErrors::DataplaneProcessNotFound)
+            }
+            Ok(_) => Ok(()),
+            Err(e) => Err(DataPlaneProcessRepoErrors::ErrorUpdatingDataplaneProcess(e.into())),
+        }
+    }
 }
@@ -17,6 +17,12 @@ pub trait DataPlaneProcessRepoTrait: Send + Sync + 'static {
         &self,
         ids: &Vec<Urn>,
     ) -> anyhow::Result<Vec<data_plane_process::Model>, DataPlaneProcessRepoErrors>;
+    /// Every process in `state` going in `direction`, unpaged
+    async fn get_data_plane_processes_by_state(
+        &self,
+        state: &str,
+        direction: &str,
+    ) -> anyhow::Result<Vec<data_plane_process::Model>, DataPlaneProcessRepoErrors>;
     async fn get_data_plane_processes_by_id(
         &self,
         process_id: &Urn,
@@ -34,6 +40,20 @@ pub trait DataPlaneProcessRepoTrait: Send + Sync + 'static {
         &self,
         process_id: &Urn,
     ) -> anyhow::Result<(), DataPlaneProcessRepoErrors>;
+    /// Adds `delta` to the permitted requests of the process and returns the new count.
+    /// Concurrent callers never read back the same count
+    async fn add_permitted_requests(
+        &self,
+        process_id: &Urn,
+        delta: i64,
+    ) -> anyhow::Result<i64, DataPlaneProcessRepoErrors>;
+    /// Adds one forwarded exchange and its bytes to the running totals of the process
+    async fn add_exchange(
+        &self,
+        process_id: &Urn,
+        bytes_in: i64,
+        bytes_out: i64,
+    ) -> anyhow::Result<(), DataPlaneProcessRepoErrors>;
 }
 
 #[derive(Debug, Error)]
@@ -100,6 +100,29 @@ impl DataPlaneProcessEntitiesTrait for DataPlaneProcessEntityService {
         Ok(dtos)
     }
 
+    async fn get_data_plane_processes_by_state(
+        &self,
+        state: &str,
+        direction: &str,
+    ) -> anyhow::Result<Vec<DataPlaneProcessDto>> {
+        let dp_processes = self
+            .data_plane_repo
+            .get_data_plane_process_repo()
+            .get_data_plane_processes_by_state(state, direction)
+            .await
+            .map_err(|error| {
+                let err = CommonErrors::database_new(&error.to_string());
+                error!("{}", err.log());
+                err
+            })?;
+        let mut dtos = Vec::with_capacity(dp_processes.len());
+        for p in dp_processes {
+            let dto = self.enrich_process(p).await?;
+            dtos.push(dto);
+        }
+        Ok(dtos)
+    }
+
     async fn get_data_plane_process_by_id(
         &self,
         id: &Urn,
@@ -271,4 +294,28 @@ impl DataPlaneProcessEntitiesTrait for DataPlaneProcessEntityService {
             })?;
         Ok(())
     }
+
+    async fn add_permitted_requests(&self, id: &Urn, delta: i64) -> anyhow::Result<i64> {
+        let permitted_requests = self
+            .data_plane_repo
+            .get_data_plane_process_repo()
+            .add_permitted_requests(id, delta)
+            .await
+            .map_err(|error| match error {
+                DataPlaneProcessRepoErrors::DataplaneProcessNotFound => {
+                    let err = CommonErrors::missing_resource_new(
+                        &id.to_string(),
+                        "Dataplane process not found for counting requests",
+                    );
+                    error!("{}", err.log());
+                    err
+                }
+                _ => {
+                    let err = CommonErrors::database_new(&error.to_string());
+                    error!("{}", err.log());
+                    err
+                }
+            })?;
+        Ok(permitted_requests)
+    }
 }
@@ -38,6 +38,7 @@ impl From<NewDataPlaneProcessDto> for NewDataPlaneProcessModel {
     }
 }
 
+#[mockall::automock]
 #[async_trait::async_trait]
 pub trait DataPlaneProcessEntitiesTrait: Send + Sync + 'static {
     async fn get_all_data_plane_processes(
@@ -51,6 +52,13 @@ pub trait DataPlaneProcessEntitiesTrait: Send + Sync + 'static {
         ids: Vec<Urn>,
     ) -> anyhow::Result<Vec<DataPlaneProcessDto>>;
 
+    /// Every process in `state` going in `direction`, unpaged
+    async fn get_data_plane_processes_by_state(
+        &self,
+        state: &str,
+        direction: &str,
+    ) -> anyhow::Result<Vec<DataPlaneProcessDto>>;
+
     async fn get_data_plane_process_by_id(
         &self,
         id: &Urn,
@@ -68,4 +76,7 @@ pub trait DataPlaneProcessEntitiesTrait: Send + Sync + 'static {
     ) -> anyhow::Result<DataPlaneProcessDto>;
 
     async fn delete_data_plane_process(&self, id: &Urn) -> anyhow::Result<()>;
+
+    /// Moves the permitted requests counter of the process by `delta`, returns the new count
+    async fn add_permitted_requests(&self, id: &Urn, delta: i64) -> anyhow::Result<i64>;
 }
@@ -2,6 +2,7 @@ pub(crate) mod transfer_event_entity;
 
 use crate::data::entities::transfer_event;
 use crate::data::entities::transfer_event::NewTransferEventModel;
+use sea_orm::prelude::DateTimeWithTimeZone;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use urn::Urn;
@@ -33,12 +34,47 @@ pub struct EditTransferEventDto {
     pub payload: Option<Value>,
 }
 
+/// Events recorded by the data plane proxy for every exchange it forwards
+pub const EXCHANGE_TRANSFER_EVENT_FROM: &str = "data-plane-proxy";
+pub const EXCHANGE_TRANSFER_EVENT_TO: &str = "downstream-hop";
+
+/// Payload of an exchange event. Bytes in are read from the consumer, bytes out are
+/// sent back to it. Hashes are hex SHA-256 of the bodies as they crossed the proxy.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct TransferExchangePayload {
+    pub direction: String,
+    pub method: String,
+    pub status_code: Option<u16>,
+    pub bytes_in: u64,
+    pub bytes_out: u64,
+    pub latency_ms: u64,
+    pub duration_ms: u64,
+    pub request_payload_hash: Option<String>,
+    pub payload_hash: Option<String>,
+    pub completed: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct TransferEventAggregatesDto {
+    pub dataplane_process_id: String,
+    pub total_bytes_in: u64,
+    pub total_bytes_out: u64,
+    pub total_bytes: u64,
+    pub request_count: u64,
+    pub last_access: Option<DateTimeWithTimeZone>,
+}
+
 impl From<NewTransferEventDto> for NewTransferEventModel {
     fn from(value: NewTransferEventDto) -> Self {
         Self { from: value.from, to: value.to, payload: value.payload }
     }
 }
 
+#[mockall::automock]
 #[async_trait::async_trait]
 pub trait TransferEventEntitiesTrait: Send + Sync + 'static {
     async fn get_all_transfer_events(
@@ -63,4 +99,9 @@ pub trait TransferEventEntitiesTrait: Send + Sync + 'static {
         &self,
         new_transfer_event: &NewTransferEventDto,
     ) -> anyhow::Result<TransferEventDto>;
+
+    async fn get_transfer_event_aggregates_by_process_id(
+        &self,
+        process_id: &Urn,
+    ) -> anyhow::Result<TransferEventAggregatesDto>;
 }
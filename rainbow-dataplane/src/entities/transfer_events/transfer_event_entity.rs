@@ -1,7 +1,8 @@
 use crate::data::entities::transfer_event::NewTransferEventModel;
 use crate::data::factory_trait::DataPlaneRepoTrait;
 use crate::entities::transfer_events::{
-    NewTransferEventDto, TransferEventDto, TransferEventEntitiesTrait,
+    NewTransferEventDto, TransferEventAggregatesDto, TransferEventDto, TransferEventEntitiesTrait,
+    TransferExchangePayload, EXCHANGE_TRANSFER_EVENT_FROM,
 };
 use rainbow_common::errors::{CommonErrors, ErrorLog};
 use std::sync::Arc;
@@ -99,7 +100,7 @@ impl TransferEventEntitiesTrait for TransferEventEntityService {
         let created_event = self
             .data_plane_repo
             .get_transfer_events_repo()
-            .create_transfer_event(&new_transfer_event.id, &new_model)
+            .create_transfer_event(&new_transfer_event.dataplane_process_id, &new_model)
             .await
             .map_err(|e| {
                 let err = CommonErrors::database_new(&e.to_string());
@@ -107,6 +108,62 @@ impl TransferEventEntitiesTrait for TransferEventEntityService {
                 err
             })?;
 
+        // exchanges also feed the running totals of the process
+        if created_event.from == EXCHANGE_TRANSFER_EVENT_FROM {
+            if let Ok(exchange) =
+                serde_json::from_value::<TransferExchangePayload>(created_event.payload.clone())
+            {
+                self.data_plane_repo
+                    .get_data_plane_process_repo()
+                    .add_exchange(
+                        &new_transfer_event.dataplane_process_id,
+                        exchange.bytes_in as i64,
+                        exchange.bytes_out as i64,
+                    )
+                    .await
+                    .map_err(|e| {
+                        let err = CommonErrors::database_new(&e.to_string());
+                        error!("{}", err.log());
+                        err
+                    })?;
+            }
+        }
+
         Ok(TransferEventDto { inner: created_event })
     }
+
+    async fn get_transfer_event_aggregates_by_process_id(
+        &self,
+        process_id: &Urn,
+    ) -> anyhow::Result<TransferEventAggregatesDto> {
+        let process = self
+            .data_plane_repo
+            .get_data_plane_process_repo()
+            .get_data_plane_processes_by_id(process_id)
+            .await
+            .map_err(|e| {
+                let err = CommonErrors::database_new(&e.to_string());
+                error!("{}", err.log());
+                err
+            })?
+            .ok_or_else(|| {
+                let err = CommonErrors::missing_resource_new(
+                    &process_id.to_string(),
+                    "Dataplane process not found for aggregates",
+                );
+                error!("{}", err.log());
+                err
+            })?;
+
+        let total_bytes_in = process.exchange_bytes_in as u64;
+        let total_bytes_out = process.exchange_bytes_out as u64;
+        Ok(TransferEventAggregatesDto {
+            dataplane_process_id: process.id,
+            total_bytes_in,
+            total_bytes_out,
+            total_bytes: total_bytes_in + total_bytes_out,
+            request_count: process.exchange_count as u64,
+            last_access: process.last_exchange_at,
+        })
+    }
 }
@@ -20,9 +20,11 @@
 use axum::http::StatusCode;
 use axum::response::{IntoResponse, Response};
 use axum::Json;
+use rainbow_common::dsp_common::context_field::ContextField;
 use rainbow_common::errors::CommonErrors;
 use serde_json::json;
 use tracing::error;
+use urn::Urn;
 
 pub trait CustomToResponse {
     fn to_response(&self) -> Response;
@@ -45,3 +47,39 @@ impl CustomToResponse for anyhow::Error {
             .into_response()
     }
 }
+
+/// Renders the error as a DSP TransferError body so consumers hitting the data plane
+/// get the same error shape they get from the control plane.
+pub fn dsp_error_response(error: &CommonErrors, session_id: &Urn) -> Response {
+    let info = match error {
+        CommonErrors::PetitionError { info, .. }
+        | CommonErrors::ProviderError { info, .. }
+        | CommonErrors::ConsumerError { info, .. }
+        | CommonErrors::AuthorityError { info, .. }
+        | CommonErrors::MissingActionError { info, .. }
+        | CommonErrors::MissingResourceError { info, .. }
+        | CommonErrors::FormatError { info, .. }
+        | CommonErrors::UnauthorizedError { info, .. }
+        | CommonErrors::ForbiddenError { info, .. }
+        | CommonErrors::ConflictError { info, .. }
+        | CommonErrors::DatabaseError { info, .. }
+        | CommonErrors::FeatureNotImplError { info, .. }
+        | CommonErrors::ReadError { info, .. }
+        | CommonErrors::WriteError { info, .. }
+        | CommonErrors::ParseError { info, .. }
+        | CommonErrors::ModuleNotActiveError { info, .. }
+        | CommonErrors::EnvVarError { info, .. }
+        | CommonErrors::VaultError { info, .. } => info,
+    };
+    (
+        info.status_code,
+        Json(json!({
+            "@context": ContextField::default(),
+            "@type": "TransferError",
+            "providerPid": session_id,
+            "code": info.error_code.to_string(),
+            "reason": [info.cause, info.message]
+        })),
+    )
+        .into_response()
+}
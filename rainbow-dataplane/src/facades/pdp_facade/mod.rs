@@ -16,3 +16,21 @@
  *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
  *
  */
+
+use rainbow_common::dsp_common::odrl_evaluator::{OdrlPolicyDecision, OdrlRequestContext};
+use urn::Urn;
+
+pub(crate) mod pdp_facade;
+
+#[mockall::automock]
+#[async_trait::async_trait]
+pub trait PdpFacadeTrait: Send + Sync + 'static {
+    /// Evaluates the agreement bound to the data plane session for the given action
+    /// and records the decision as a transfer event of the session.
+    async fn authorize_session_request(
+        &self,
+        session_id: &Urn,
+        action: &str,
+        context: OdrlRequestContext,
+    ) -> anyhow::Result<OdrlPolicyDecision>;
+}
@@ -0,0 +1,233 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use crate::entities::data_plane_process::DataPlaneProcessEntitiesTrait;
+use crate::entities::transfer_events::{NewTransferEventDto, TransferEventEntitiesTrait};
+use crate::facades::pdp_facade::PdpFacadeTrait;
+use anyhow::bail;
+use rainbow_common::config::services::TransferConfig;
+use rainbow_common::config::traits::CommonConfigTrait;
+use rainbow_common::dsp_common::odrl::OdrlAgreement;
+use rainbow_common::dsp_common::odrl_evaluator::{
+    OdrlEvaluatorTrait, OdrlPolicyDecision, OdrlRequestContext,
+};
+use rainbow_common::errors::{CommonErrors, ErrorLog};
+use rainbow_common::http_client::HttpClient;
+use rainbow_common::utils::get_urn;
+use serde::Deserialize;
+use serde_json::json;
+use std::collections::HashSet;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use tracing::error;
+use urn::Urn;
+use ymir::config::traits::{ApiConfigTrait, HostsConfigTrait};
+use ymir::config::types::HostType;
+
+pub const PDP_TRANSFER_EVENT_FROM: &str = "pdp";
+pub const PDP_TRANSFER_EVENT_TO: &str = "data-plane-proxy";
+const PDP_COUNT_LEFT_OPERAND: &str = "count";
+
+/// Only the fields the PDP needs from the transfer agent process resource
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SessionTransferProcess {
+    agreement_id: String,
+}
+
+/// Only the fields the PDP needs from the negotiation agent agreement resource
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SessionAgreement {
+    agreement_content: OdrlAgreement,
+}
+
+/// Only the fields the PDP needs from the negotiation agent signature verification report
+#[derive(Debug, Deserialize)]
+struct SessionAgreementSignatures {
+    provider: SessionAgreementSignature,
+    consumer: SessionAgreementSignature,
+}
+
+#[derive(Debug, Deserialize)]
+struct SessionAgreementSignature {
+    signed: bool,
+    verified: bool,
+}
+
+pub struct PdpFacadeService {
+    config: Arc<TransferConfig>,
+    client: Arc<HttpClient>,
+    evaluator: Arc<dyn OdrlEvaluatorTrait>,
+    dataplane_process_entity: Arc<dyn DataPlaneProcessEntitiesTrait>,
+    transfer_event_entity: Arc<dyn TransferEventEntitiesTrait>,
+    /// Agreements whose signatures already met the policy, they do not change once stored
+    accepted_agreements: RwLock<HashSet<String>>,
+}
+
+impl PdpFacadeService {
+    pub fn new(
+        config: Arc<TransferConfig>,
+        client: Arc<HttpClient>,
+        evaluator: Arc<dyn OdrlEvaluatorTrait>,
+        dataplane_process_entity: Arc<dyn DataPlaneProcessEntitiesTrait>,
+        transfer_event_entity: Arc<dyn TransferEventEntitiesTrait>,
+    ) -> Self {
+        Self {
+            config,
+            client,
+            evaluator,
+            dataplane_process_entity,
+            transfer_event_entity,
+            accepted_agreements: RwLock::new(HashSet::new()),
+        }
+    }
+
+    async fn resolve_agreement(&self, session_id: &Urn) -> anyhow::Result<OdrlAgreement> {
+        // data plane session id is the transfer process id
+        let transfer_url = format!(
+            "{}{}/transfer-agent/transfer-processes/{}",
+            self.config.common().get_host(HostType::Http),
+            self.config.common().get_api_version(),
+            session_id
+        );
+        let process =
+            self.client.get_json::<SessionTransferProcess>(&transfer_url).await.map_err(|e| {
+                let err = CommonErrors::missing_resource_new(
+                    session_id.to_string().as_str(),
+                    format!("Unable to resolve transfer process of session: {}", e).as_str(),
+                );
+                error!("{}", err.log());
+                err
+            })?;
+
+        let agreement_url = format!(
+            "{}{}/negotiation-agent/agreements/{}",
+            self.config.contracts().get_host(HostType::Http),
+            self.config.contracts().get_api_version(),
+            process.agreement_id
+        );
+        let agreement =
+            self.client.get_json::<SessionAgreement>(&agreement_url).await.map_err(|e| {
+                let err = CommonErrors::missing_resource_new(
+                    process.agreement_id.as_str(),
+                    format!("Unable to resolve agreement of session: {}", e).as_str(),
+                );
+                error!("{}", err.log());
+                err
+            })?;
+        self.check_agreement_signatures(process.agreement_id.as_str()).await?;
+        Ok(agreement.agreement_content)
+    }
+
+    /// Refuses the session when the agreement signatures, as verified by the negotiation
+    /// agent, do not meet the signature policy the transfer was started under
+    async fn check_agreement_signatures(&self, agreement_id: &str) -> anyhow::Result<()> {
+        if self.accepted_agreements.read().await.contains(agreement_id) {
+            return Ok(());
+        }
+        let signatures_url = format!(
+            "{}{}/negotiation-agent/agreements/{}/signatures",
+            self.config.contracts().get_host(HostType::Http),
+            self.config.contracts().get_api_version(),
+            agreement_id
+        );
+        let signatures = self
+            .client
+            .get_json::<SessionAgreementSignatures>(&signatures_url)
+            .await
+            .map_err(|e| {
+                let err = CommonErrors::forbidden_new(
+                    format!("Unable to verify the signatures of agreement {}: {}", agreement_id, e)
+                        .as_str(),
+                );
+                error!("{}", err.log());
+                err
+            })?;
+        let policy = self.config.agreement_signatures();
+        let accepted = [&signatures.provider, &signatures.consumer]
+            .iter()
+            .all(|signature| policy.accepts(signature.signed, signature.verified));
+        if !accepted {
+            let err = CommonErrors::forbidden_new(
+                format!(
+                    "Agreement {} signatures do not meet the {} signature policy",
+                    agreement_id, policy
+                )
+                .as_str(),
+            );
+            error!("{}", err.log());
+            bail!(err);
+        }
+        self.accepted_agreements.write().await.insert(agreement_id.to_string());
+        Ok(())
+    }
+
+    /// Evaluates the request against the agreement and records the decision. The request
+    /// takes its slot in the session count before the evaluation, and gives it back if denied.
+    pub(crate) async fn authorize_with_agreement(
+        &self,
+        session_id: &Urn,
+        agreement: &OdrlAgreement,
+        action: &str,
+        mut context: OdrlRequestContext,
+    ) -> anyhow::Result<OdrlPolicyDecision> {
+        // count refers to this execution of the action as well
+        let permitted_requests =
+            self.dataplane_process_entity.add_permitted_requests(session_id, 1).await?;
+        context.counters.entry(PDP_COUNT_LEFT_OPERAND.to_string()).or_insert(permitted_requests);
+
+        let decision = self.evaluator.evaluate_agreement(agreement, action, &context);
+        if !decision.is_permit() {
+            self.dataplane_process_entity.add_permitted_requests(session_id, -1).await?;
+        }
+
+        self.transfer_event_entity
+            .create_transfer_event(&NewTransferEventDto {
+                id: get_urn(None),
+                dataplane_process_id: session_id.clone(),
+                from: PDP_TRANSFER_EVENT_FROM.to_string(),
+                to: PDP_TRANSFER_EVENT_TO.to_string(),
+                payload: json!({
+                    "agreementId": agreement.id,
+                    "action": decision.action,
+                    "outcome": decision.outcome,
+                    "reason": decision.reason,
+                    "firedRules": decision.fired_rules(),
+                    "pendingObligations": decision.pending_obligations,
+                }),
+            })
+            .await?;
+
+        Ok(decision)
+    }
+}
+
+#[async_trait::async_trait]
+impl PdpFacadeTrait for PdpFacadeService {
+    async fn authorize_session_request(
+        &self,
+        session_id: &Urn,
+        action: &str,
+        context: OdrlRequestContext,
+    ) -> anyhow::Result<OdrlPolicyDecision> {
+        let agreement = self.resolve_agreement(session_id).await?;
+        self.authorize_with_agreement(session_id, &agreement, action, context).await
+    }
+}
@@ -37,8 +37,12 @@ impl TransferEventsRouter {
     }
     pub fn router(self) -> Router {
         Router::new()
-            .route("/data-plane/:data_plane_id", get(Self::handle_get_data_plane_by_id))
-            .route("/:transfer_id", get(Self::handle_get_by_id))
+            .route("/data-plane/{data_plane_id}", get(Self::handle_get_data_plane_by_id))
+            .route(
+                "/data-plane/{data_plane_id}/aggregates",
+                get(Self::handle_get_data_plane_aggregates),
+            )
+            .route("/{transfer_id}", get(Self::handle_get_by_id))
             .with_state(self)
     }
     async fn handle_get_data_plane_by_id(
@@ -55,6 +59,24 @@ impl TransferEventsRouter {
         }
     }
 
+    async fn handle_get_data_plane_aggregates(
+        State(state): State<TransferEventsRouter>,
+        Path(data_plane_id): Path<String>,
+    ) -> impl IntoResponse {
+        let data_plane_id = match parse_urn(&data_plane_id) {
+            Ok(urn) => urn,
+            Err(resp) => return resp,
+        };
+        match state
+            .transfer_event_entity
+            .get_transfer_event_aggregates_by_process_id(&data_plane_id)
+            .await
+        {
+            Ok(aggregates) => (StatusCode::OK, Json(aggregates)).into_response(),
+            Err(e) => e.to_response(),
+        }
+    }
+
     async fn handle_get_by_id(
         State(state): State<TransferEventsRouter>,
         Path(transfer_id): Path<String>,
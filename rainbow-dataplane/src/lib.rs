@@ -26,5 +26,6 @@ pub(crate) mod facades;
 pub mod http;
 pub mod setup;
 pub mod testing_proxy;
+mod tests;
 
 pub use data::migrations::get_dataplane_migrations;
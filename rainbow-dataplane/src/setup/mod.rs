@@ -1,16 +1,21 @@
 use crate::coordinator::data_source_connector::data_source_connector::DataSourceConnector;
+use crate::coordinator::data_source_connector::DataSourceConnectorTrait;
 use crate::coordinator::dataplane_access_controller::dataplane_access_controller::DataPlaneAccessControllerService;
 use crate::coordinator::dataplane_access_controller::DataPlaneAccessControllerTrait;
 use crate::data::factory_sql::DataPlaneRepoForSql;
 use crate::data::factory_trait::DataPlaneRepoTrait;
 use crate::entities::data_plane_process::data_plane_process_entity::DataPlaneProcessEntityService;
 use crate::entities::transfer_events::transfer_event_entity::TransferEventEntityService;
+use crate::facades::pdp_facade::pdp_facade::PdpFacadeService;
 use crate::http::dataplane_info::DataPlaneRouter;
 use crate::http::transfer_events::TransferEventsRouter;
 use crate::testing_proxy::http::http::TestingHTTPProxy;
 use axum::Router;
 use rainbow_common::config::services::TransferConfig;
 use rainbow_common::config::traits::CommonConfigTrait;
+use rainbow_common::dsp_common::odrl_evaluator::odrl_evaluator::OdrlEvaluatorService;
+use rainbow_common::http_client::HttpClient;
+use rainbow_events::core::notification::notification_publisher::RainbowEventsPublisherTrait;
 use sea_orm::Database;
 use std::ops::Deref;
 use std::sync::Arc;
@@ -35,15 +40,22 @@ impl DataplaneSetup {
         &self,
         config: Arc<TransferConfig>,
         vault: Arc<VaultService>,
+        events_publisher: Arc<dyn RainbowEventsPublisherTrait>,
     ) -> Arc<dyn DataPlaneAccessControllerTrait> {
         let db_connection = vault.get_db_connection(config.deref().common()).await;
         let dataplane_repo = self.get_data_plane_repo(config.as_ref(), vault.clone()).await;
         let dataplane_process_entity =
             Arc::new(DataPlaneProcessEntityService::new(dataplane_repo.clone()));
-        let dataplane_source_connector = Arc::new(DataSourceConnector::new());
+        let dataplane_source_connector =
+            Arc::new(DataSourceConnector::new(dataplane_process_entity.clone()));
+        // push sessions started before a restart carry on delivering
+        if let Err(e) = dataplane_source_connector.resume_sessions().await {
+            tracing::error!("Push data plane sessions could not be resumed: {}", e);
+        }
         let controller = Arc::new(DataPlaneAccessControllerService::new(
             dataplane_source_connector.clone(),
             dataplane_process_entity.clone(),
+            events_publisher,
             config.clone(),
         ));
         controller
@@ -76,6 +88,22 @@ impl DataplaneSetup {
         let dataplane_repo = self.get_data_plane_repo(config, vault.clone()).await;
         let dataplane_process_entity =
             Arc::new(DataPlaneProcessEntityService::new(dataplane_repo.clone()));
-        TestingHTTPProxy::new(dataplane_process_entity.clone()).router()
+        let transfer_event_entity =
+            Arc::new(TransferEventEntityService::new(dataplane_repo.clone()));
+        let http_client = Arc::new(HttpClient::new(10, 10));
+        let odrl_evaluator = Arc::new(OdrlEvaluatorService::new());
+        let pdp_facade = Arc::new(PdpFacadeService::new(
+            Arc::new(config.clone()),
+            http_client.clone(),
+            odrl_evaluator.clone(),
+            dataplane_process_entity.clone(),
+            transfer_event_entity.clone(),
+        ));
+        TestingHTTPProxy::new(
+            dataplane_process_entity.clone(),
+            pdp_facade.clone(),
+            transfer_event_entity.clone(),
+        )
+        .router()
     }
 }
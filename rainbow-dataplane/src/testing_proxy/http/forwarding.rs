@@ -0,0 +1,177 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use axum::BoxError;
+use bytes::Bytes;
+use futures::{Stream, StreamExt};
+use reqwest::header::{HeaderMap, HeaderName};
+use std::collections::HashMap;
+use thiserror::Error;
+use url::Url;
+
+pub const PROXY_MAX_REQUEST_BODY_FIELD: &str = "ProxyMaxRequestBodySize";
+pub const PROXY_MAX_RESPONSE_BODY_FIELD: &str = "ProxyMaxResponseBodySize";
+pub const PROXY_HEADERS_ALLOW_FIELD: &str = "ProxyHeadersAllow";
+pub const PROXY_HEADERS_DENY_FIELD: &str = "ProxyHeadersDeny";
+pub const PROXY_QUERY_ALLOW_FIELD: &str = "ProxyQueryAllow";
+pub const PROXY_QUERY_DENY_FIELD: &str = "ProxyQueryDeny";
+
+/// Hop-by-hop headers, plus the ones the proxy rewrites itself, never cross the proxy
+const NON_FORWARDABLE_HEADERS: [&str; 10] = [
+    "connection",
+    "keep-alive",
+    "proxy-authenticate",
+    "proxy-authorization",
+    "te",
+    "trailer",
+    "transfer-encoding",
+    "upgrade",
+    "host",
+    "authorization",
+];
+
+/// Per-session forwarding rules, read from the data plane process fields.
+/// Lists are comma separated and case-insensitive. An empty allow list allows
+/// everything not denied. Limits are in bytes, empty means unlimited.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ForwardingPolicy {
+    pub max_request_body: Option<u64>,
+    pub max_response_body: Option<u64>,
+    pub headers_allow: Vec<String>,
+    pub headers_deny: Vec<String>,
+    pub query_allow: Vec<String>,
+    pub query_deny: Vec<String>,
+}
+
+impl ForwardingPolicy {
+    pub fn from_fields(fields: &HashMap<String, String>) -> Self {
+        let limit = |key: &str| fields.get(key).and_then(|v| v.trim().parse::<u64>().ok());
+        let list = |key: &str| {
+            fields
+                .get(key)
+                .map(|v| {
+                    v.split(',')
+                        .map(|item| item.trim().to_lowercase())
+                        .filter(|item| !item.is_empty())
+                        .collect()
+                })
+                .unwrap_or_default()
+        };
+        Self {
+            max_request_body: limit(PROXY_MAX_REQUEST_BODY_FIELD),
+            max_response_body: limit(PROXY_MAX_RESPONSE_BODY_FIELD),
+            headers_allow: list(PROXY_HEADERS_ALLOW_FIELD),
+            headers_deny: list(PROXY_HEADERS_DENY_FIELD),
+            query_allow: list(PROXY_QUERY_ALLOW_FIELD),
+            query_deny: list(PROXY_QUERY_DENY_FIELD),
+        }
+    }
+
+    fn is_allowed(name: &str, allow: &[String], deny: &[String]) -> bool {
+        let name = name.to_lowercase();
+        !deny.contains(&name) && (allow.is_empty() || allow.contains(&name))
+    }
+
+    /// Request headers that go downstream. Range and If-Range pass through unless denied.
+    pub fn request_headers(&self, headers: &HeaderMap) -> HeaderMap {
+        let mut forwarded = HeaderMap::new();
+        for (name, value) in headers.iter() {
+            if Self::is_forwardable(name)
+                && Self::is_allowed(name.as_str(), &self.headers_allow, &self.headers_deny)
+            {
+                forwarded.append(name.clone(), value.clone());
+            }
+        }
+        forwarded
+    }
+
+    /// Response headers that go back upstream. Only hop-by-hop headers are dropped,
+    /// so Content-Range and Accept-Ranges reach the consumer.
+    pub fn response_headers(&self, headers: &HeaderMap) -> HeaderMap {
+        let mut forwarded = HeaderMap::new();
+        for (name, value) in headers.iter() {
+            if Self::is_forwardable(name) {
+                forwarded.append(name.clone(), value.clone());
+            }
+        }
+        forwarded
+    }
+
+    fn is_forwardable(name: &HeaderName) -> bool {
+        !NON_FORWARDABLE_HEADERS.contains(&name.as_str())
+    }
+
+    /// Appends the allowed query parameters of the incoming request to the next hop
+    pub fn next_hop_url(&self, next_hop: &str, query: Option<&str>) -> anyhow::Result<Url> {
+        let mut url = Url::parse(next_hop)?;
+        if let Some(query) = query {
+            let pairs: Vec<(String, String)> = url::form_urlencoded::parse(query.as_bytes())
+                .filter(|(key, _)| Self::is_allowed(key, &self.query_allow, &self.query_deny))
+                .map(|(key, value)| (key.into_owned(), value.into_owned()))
+                .collect();
+            if !pairs.is_empty() {
+                url.query_pairs_mut().extend_pairs(pairs);
+            }
+        }
+        Ok(url)
+    }
+}
+
+/// A limited body went past its limit. Kept apart from transport errors so the
+/// proxy can answer 413 instead of blaming the peer.
+#[derive(Debug, Error)]
+#[error("body exceeds the session limit of {limit} bytes")]
+pub struct BodyLimitExceeded {
+    pub limit: u64,
+}
+
+impl BodyLimitExceeded {
+    /// Whether `error`, or any error down its source chain, is a body over its limit
+    pub fn is_cause_of(error: &(dyn std::error::Error + 'static)) -> bool {
+        let mut current = Some(error);
+        while let Some(error) = current {
+            if error.is::<Self>() {
+                return true;
+            }
+            current = error.source();
+        }
+        false
+    }
+}
+
+/// Passes the body through chunk by chunk, failing with [`BodyLimitExceeded`] once
+/// more than `limit` bytes went by
+pub fn limit_stream<S, E>(
+    stream: S,
+    limit: Option<u64>,
+) -> impl Stream<Item = Result<Bytes, BoxError>> + Send + 'static
+where
+    S: Stream<Item = Result<Bytes, E>> + Send + 'static,
+    E: Into<BoxError>,
+{
+    let mut seen: u64 = 0;
+    stream.map(move |chunk| {
+        let chunk = chunk.map_err(Into::into)?;
+        seen += chunk.len() as u64;
+        match limit {
+            Some(limit) if seen > limit => Err(BodyLimitExceeded { limit }.into()),
+            _ => Ok(chunk),
+        }
+    })
+}
@@ -18,24 +18,40 @@
  */
 
 #![allow(unused)]
+use crate::coordinator::data_source_connector::connectors::DataSourceEndpoint;
 use crate::entities::data_plane_process::{DataPlaneProcessDto, DataPlaneProcessEntitiesTrait};
-use axum::body::{to_bytes, Body};
+use crate::entities::transfer_events::TransferEventEntitiesTrait;
+use crate::errors::error_adapter::dsp_error_response;
+use crate::facades::pdp_facade::PdpFacadeTrait;
+use crate::testing_proxy::http::forwarding::{limit_stream, BodyLimitExceeded, ForwardingPolicy};
+use crate::testing_proxy::http::metering::{ExchangeRecorder, MeteredStream, StreamMetrics};
+use axum::body::Body;
 use axum::extract::{FromRef, Path, Request, State};
 use axum::response::{IntoResponse, Response};
 use axum::routing::any;
 use axum::Router;
 use hyper::Method;
 use rainbow_common::adv_protocol::interplane::{DataPlaneProcessDirection, DataPlaneProcessState};
+use rainbow_common::dsp_common::odrl_evaluator::OdrlRequestContext;
+use rainbow_common::errors::{CommonErrors, ErrorLog};
 use rainbow_common::utils::get_urn_from_string;
+use reqwest::header::{HeaderMap, AUTHORIZATION, CONTENT_LENGTH, TRANSFER_ENCODING};
 use reqwest::Response as ReqwestResponse;
 use reqwest::{Client, StatusCode};
 use std::sync::Arc;
-use tracing::info;
+use tracing::{error, info};
+
+/// Header a consumer uses to declare the purpose of the request for ODRL `purpose` constraints
+pub const PURPOSE_HEADER: &str = "x-dataspace-purpose";
+const ODRL_READ_ACTION: &str = "read";
+const ODRL_USE_ACTION: &str = "use";
 
 #[derive(Clone)]
 pub struct TestingHTTPProxy {
     client: Client,
     dataplane_service: Arc<dyn DataPlaneProcessEntitiesTrait>,
+    pdp_facade: Arc<dyn PdpFacadeTrait>,
+    transfer_event_entity: Arc<dyn TransferEventEntitiesTrait>,
 }
 
 impl FromRef<TestingHTTPProxy> for Client {
@@ -50,13 +66,29 @@ impl FromRef<TestingHTTPProxy> for Arc<dyn DataPlaneProcessEntitiesTrait> {
     }
 }
 
+impl FromRef<TestingHTTPProxy> for Arc<dyn PdpFacadeTrait> {
+    fn from_ref(input: &TestingHTTPProxy) -> Self {
+        input.pdp_facade.clone()
+    }
+}
+
+impl FromRef<TestingHTTPProxy> for Arc<dyn TransferEventEntitiesTrait> {
+    fn from_ref(input: &TestingHTTPProxy) -> Self {
+        input.transfer_event_entity.clone()
+    }
+}
+
 impl TestingHTTPProxy {
-    pub fn new(dataplane_service: Arc<dyn DataPlaneProcessEntitiesTrait>) -> Self {
+    pub fn new(
+        dataplane_service: Arc<dyn DataPlaneProcessEntitiesTrait>,
+        pdp_facade: Arc<dyn PdpFacadeTrait>,
+        transfer_event_entity: Arc<dyn TransferEventEntitiesTrait>,
+    ) -> Self {
         let client = reqwest::Client::new();
-        Self { client, dataplane_service }
+        Self { client, dataplane_service, pdp_facade, transfer_event_entity }
     }
     pub fn router(self) -> Router {
-        Router::new().route("/:data_plane_id", any(Self::forward_request)).with_state(self)
+        Router::new().route("/{data_plane_id}", any(Self::forward_request)).with_state(self)
     }
 
     async fn forward_request(
@@ -100,45 +132,135 @@ impl TestingHTTPProxy {
             }
         }
 
-        // ODRL Evaluation here!!!!!
-        // if you are Provider
-        // ODRL Evaluator facade
+        // PDP, every pull is evaluated against the agreement of the session
+        let action = match *req.method() {
+            Method::GET | Method::HEAD | Method::OPTIONS => ODRL_READ_ACTION,
+            _ => ODRL_USE_ACTION,
+        };
+        let context = OdrlRequestContext {
+            purpose: req
+                .headers()
+                .get(PURPOSE_HEADER)
+                .and_then(|purpose| purpose.to_str().ok())
+                .map(|purpose| purpose.to_string()),
+            ..Default::default()
+        };
+        match state.pdp_facade.authorize_session_request(&data_plane_id, action, context).await {
+            Ok(decision) if decision.is_permit() => {}
+            Ok(decision) => {
+                let err = CommonErrors::forbidden_new(decision.reason.as_str());
+                error!("{}", err.log());
+                return dsp_error_response(&err, &data_plane_id);
+            }
+            Err(e) => {
+                let err = match e.downcast::<CommonErrors>() {
+                    Ok(err) => err,
+                    Err(e) => CommonErrors::forbidden_new(
+                        format!("Policy could not be evaluated: {}", e).as_str(),
+                    ),
+                };
+                error!("{}", err.log());
+                return dsp_error_response(&err, &data_plane_id);
+            }
+        }
 
         // forward request downstream
-        let next_hop = dataplane.data_plane_fields.get("DownstreamHopUrl").unwrap().clone();
-        let body = std::mem::take(req.body_mut());
-        let body_bytes = match to_bytes(body, 2024) // MAX_BUFFER
-            .await
-        {
-            Ok(body_bytes) => body_bytes,
-            Err(_) => return (StatusCode::BAD_REQUEST, "body too big").into_response(),
+        let (next_hop, authorization) =
+            match DataSourceEndpoint::from_fields(&dataplane.data_plane_fields) {
+                Ok(DataSourceEndpoint::Http { url, authorization }) => (url, authorization),
+                Ok(DataSourceEndpoint::File { .. }) => {
+                    return (StatusCode::BAD_REQUEST, "next hop is not an http endpoint")
+                        .into_response()
+                }
+                Err(_) => return (StatusCode::BAD_REQUEST, "next hop not defined").into_response(),
+            };
+        let policy = ForwardingPolicy::from_fields(&dataplane.data_plane_fields);
+        let next_hop = match policy.next_hop_url(&next_hop, req.uri().query()) {
+            Ok(next_hop) => next_hop,
+            Err(_) => return (StatusCode::BAD_REQUEST, "next hop not valid").into_response(),
         };
+        let content_length = Self::content_length(req.headers());
+        if let (Some(limit), Some(length)) = (policy.max_request_body, content_length) {
+            if length > limit {
+                return (StatusCode::PAYLOAD_TOO_LARGE, "body too big").into_response();
+            }
+        }
         let method = match Method::try_from(req.method()) {
             Ok(method) => method,
             Err(_) => return (StatusCode::BAD_REQUEST, "method not allowed").into_response(),
         };
-        let res = state.client.request(method, next_hop).body(body_bytes).send().await;
-
-        // Notify && transfer event
-        // Notification
-        // Create TransferEvent
+        let has_body = content_length.is_some_and(|length| length > 0)
+            || req.headers().contains_key(TRANSFER_ENCODING);
+        let mut request =
+            state.client.request(method, next_hop).headers(policy.request_headers(req.headers()));
+        if let Some(authorization) = authorization {
+            request = request.header(AUTHORIZATION, authorization);
+        }
+        let mut exchange = ExchangeRecorder::new(
+            state.transfer_event_entity.clone(),
+            data_plane_id.clone(),
+            dataplane.inner.direction.clone(),
+            req.method().to_string(),
+        );
+        if has_body {
+            // the body is streamed as it arrives, never buffered
+            let body = std::mem::take(req.body_mut()).into_data_stream();
+            let body = exchange.meter_request(limit_stream(body, policy.max_request_body));
+            request = request.body(reqwest::Body::wrap_stream(body));
+        }
+        let res = request.send().await;
+        exchange.response_received();
 
         // forward request upstream
         match res {
-            Ok(res) => Self::forward_response_helper(res),
-            Err(_) => return (StatusCode::BAD_REQUEST, "peer connection problem").into_response(),
+            Ok(res) => Self::forward_response_helper(res, &policy, exchange),
+            // a chunked body that outgrew the limit halfway is on the consumer, not the peer
+            Err(e) if BodyLimitExceeded::is_cause_of(&e) => {
+                exchange.record(None, StreamMetrics::default(), Some(e.to_string()));
+                (StatusCode::PAYLOAD_TOO_LARGE, "body too big").into_response()
+            }
+            Err(e) => {
+                exchange.record(None, StreamMetrics::default(), Some(e.to_string()));
+                (StatusCode::BAD_REQUEST, "peer connection problem").into_response()
+            }
         }
     }
 
-    pub fn forward_response_helper(reqwest_response: ReqwestResponse) -> Response {
+    fn content_length(headers: &HeaderMap) -> Option<u64> {
+        headers.get(CONTENT_LENGTH)?.to_str().ok()?.parse::<u64>().ok()
+    }
+
+    pub fn forward_response_helper(
+        reqwest_response: ReqwestResponse,
+        policy: &ForwardingPolicy,
+        exchange: ExchangeRecorder,
+    ) -> Response {
         let status = reqwest_response.status();
-        let headers = reqwest_response.headers().clone();
-        let body_stream = reqwest_response.bytes_stream();
+        let headers = policy.response_headers(reqwest_response.headers());
+        if let (Some(limit), Some(length)) =
+            (policy.max_response_body, Self::content_length(&headers))
+        {
+            if length > limit {
+                let reason = "response too big";
+                exchange.record(
+                    Some(status.as_u16()),
+                    StreamMetrics::default(),
+                    Some(reason.into()),
+                );
+                return (StatusCode::BAD_GATEWAY, reason).into_response();
+            }
+        }
+        // without a content length hyper answers with chunked transfer encoding
+        let body_stream = limit_stream(reqwest_response.bytes_stream(), policy.max_response_body);
+        // the event is stored once the consumer got the whole body, or went away
+        let body_stream = MeteredStream::new(body_stream, move |metrics| {
+            exchange.record(Some(status.as_u16()), metrics, None)
+        });
         let body = Body::from_stream(body_stream);
         let mut response = Response::builder().status(status);
         let response_headers = response.headers_mut().unwrap();
         for (key, value) in headers.iter() {
-            response_headers.insert(key, value.clone());
+            response_headers.append(key, value.clone());
         }
 
         response.body(body).unwrap()
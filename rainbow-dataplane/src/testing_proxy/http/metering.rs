@@ -0,0 +1,195 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use crate::entities::transfer_events::{
+    NewTransferEventDto, TransferEventEntitiesTrait, TransferExchangePayload,
+    EXCHANGE_TRANSFER_EVENT_FROM, EXCHANGE_TRANSFER_EVENT_TO,
+};
+use axum::BoxError;
+use bytes::Bytes;
+use futures::stream::BoxStream;
+use futures::{Stream, StreamExt};
+use rainbow_common::utils::get_urn;
+use sha2::{Digest, Sha256};
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
+use tracing::{error, warn};
+use urn::Urn;
+
+/// What went through a body once it is over
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StreamMetrics {
+    pub bytes: u64,
+    pub sha256: String,
+    pub completed: bool,
+}
+
+type OnFinish = Box<dyn FnOnce(StreamMetrics) + Send>;
+
+/// Counts and hashes a body while it is streamed. `on_finish` runs exactly once,
+/// when the body ends, fails, or is dropped because the peer went away.
+pub struct MeteredStream {
+    inner: BoxStream<'static, Result<Bytes, BoxError>>,
+    bytes: u64,
+    hasher: Sha256,
+    on_finish: Option<OnFinish>,
+}
+
+impl MeteredStream {
+    pub fn new<S>(inner: S, on_finish: impl FnOnce(StreamMetrics) + Send + 'static) -> Self
+    where
+        S: Stream<Item = Result<Bytes, BoxError>> + Send + 'static,
+    {
+        Self {
+            inner: inner.boxed(),
+            bytes: 0,
+            hasher: Sha256::new(),
+            on_finish: Some(Box::new(on_finish)),
+        }
+    }
+
+    fn finish(&mut self, completed: bool) {
+        if let Some(on_finish) = self.on_finish.take() {
+            let hasher = std::mem::take(&mut self.hasher);
+            on_finish(StreamMetrics {
+                bytes: self.bytes,
+                sha256: format!("{:x}", hasher.finalize()),
+                completed,
+            });
+        }
+    }
+}
+
+impl Stream for MeteredStream {
+    type Item = Result<Bytes, BoxError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let polled = self.inner.poll_next_unpin(cx);
+        match &polled {
+            Poll::Ready(Some(Ok(chunk))) => {
+                self.bytes += chunk.len() as u64;
+                self.hasher.update(chunk);
+            }
+            Poll::Ready(Some(Err(_))) => self.finish(false),
+            Poll::Ready(None) => self.finish(true),
+            Poll::Pending => {}
+        }
+        polled
+    }
+}
+
+impl Drop for MeteredStream {
+    fn drop(&mut self) {
+        self.finish(false);
+    }
+}
+
+/// Collects the metrics of one proxied exchange and stores them as a TransferEvent
+pub struct ExchangeRecorder {
+    transfer_event_entity: Arc<dyn TransferEventEntitiesTrait>,
+    session_id: Urn,
+    direction: String,
+    method: String,
+    started: Instant,
+    latency: Duration,
+    request_metrics: Arc<Mutex<Option<StreamMetrics>>>,
+}
+
+impl ExchangeRecorder {
+    pub fn new(
+        transfer_event_entity: Arc<dyn TransferEventEntitiesTrait>,
+        session_id: Urn,
+        direction: String,
+        method: String,
+    ) -> Self {
+        Self {
+            transfer_event_entity,
+            session_id,
+            direction,
+            method,
+            started: Instant::now(),
+            latency: Duration::ZERO,
+            request_metrics: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// Wraps the request body so its size and hash end up in the event
+    pub fn meter_request<S>(&self, body: S) -> MeteredStream
+    where
+        S: Stream<Item = Result<Bytes, BoxError>> + Send + 'static,
+    {
+        let request_metrics = self.request_metrics.clone();
+        MeteredStream::new(body, move |metrics| {
+            if let Ok(mut request_metrics) = request_metrics.lock() {
+                *request_metrics = Some(metrics);
+            }
+        })
+    }
+
+    /// Time until the downstream hop answered with headers
+    pub fn response_received(&mut self) {
+        self.latency = self.started.elapsed();
+    }
+
+    pub fn record(self, status_code: Option<u16>, response: StreamMetrics, error: Option<String>) {
+        let request = self.request_metrics.lock().ok().and_then(|metrics| metrics.clone());
+        let payload = TransferExchangePayload {
+            direction: self.direction,
+            method: self.method,
+            status_code,
+            bytes_in: request.as_ref().map(|m| m.bytes).unwrap_or_default(),
+            bytes_out: response.bytes,
+            latency_ms: self.latency.as_millis() as u64,
+            duration_ms: self.started.elapsed().as_millis() as u64,
+            request_payload_hash: request.map(|m| m.sha256),
+            payload_hash: response.completed.then_some(response.sha256),
+            completed: response.completed,
+            error,
+        };
+        let payload = match serde_json::to_value(payload) {
+            Ok(payload) => payload,
+            Err(e) => {
+                error!("Transfer event payload could not be serialized: {}", e);
+                return;
+            }
+        };
+        let new_transfer_event = NewTransferEventDto {
+            id: get_urn(None),
+            dataplane_process_id: self.session_id,
+            from: EXCHANGE_TRANSFER_EVENT_FROM.to_string(),
+            to: EXCHANGE_TRANSFER_EVENT_TO.to_string(),
+            payload,
+        };
+        // bodies may finish while the runtime shuts down
+        let Ok(runtime) = tokio::runtime::Handle::try_current() else {
+            warn!(
+                "Transfer event for {} dropped, no runtime",
+                new_transfer_event.dataplane_process_id
+            );
+            return;
+        };
+        let transfer_event_entity = self.transfer_event_entity;
+        runtime.spawn(async move {
+            // errors are already logged by the entity
+            let _ = transfer_event_entity.create_transfer_event(&new_transfer_event).await;
+        });
+    }
+}
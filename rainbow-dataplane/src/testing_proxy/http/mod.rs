@@ -17,4 +17,6 @@
  *
  */
 
+pub mod forwarding;
 pub mod http;
+pub mod metering;
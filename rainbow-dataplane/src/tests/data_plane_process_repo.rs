@@ -0,0 +1,137 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+#[cfg(test)]
+mod test_data_plane_process_repo {
+    use crate::data::entities::data_plane_process::NewDataPlaneProcessModel;
+    use crate::data::migrations::get_dataplane_migrations;
+    use crate::data::repo_sql::data_plane_process_repo::DataPlaneProcessRepoForSql;
+    use crate::data::repo_traits::data_plane_process_repo::{
+        DataPlaneProcessRepoErrors, DataPlaneProcessRepoTrait,
+    };
+    use sea_orm::{ConnectOptions, Database};
+    use sea_orm_migration::{MigrationTrait, MigratorTrait};
+    use std::collections::HashSet;
+    use std::str::FromStr;
+    use std::sync::Arc;
+    use urn::Urn;
+
+    const PROCESS_ID: &str = "urn:data-plane-process:1";
+
+    struct Migrator;
+
+    impl MigratorTrait for Migrator {
+        fn migrations() -> Vec<Box<dyn MigrationTrait>> {
+            get_dataplane_migrations()
+        }
+    }
+
+    async fn setup() -> DataPlaneProcessRepoForSql {
+        // a single connection, every new in-memory connection is a new empty database
+        let mut options = ConnectOptions::new("sqlite::memory:");
+        options.max_connections(1).sqlx_logging(false);
+        let db = Database::connect(options).await.unwrap();
+        Migrator::up(&db, None).await.unwrap();
+        let repo = DataPlaneProcessRepoForSql::new(db);
+        repo.create_data_plane_processes(&NewDataPlaneProcessModel {
+            id: Urn::from_str(PROCESS_ID).unwrap(),
+            direction: "PULL".to_string(),
+            state: "STARTED".to_string(),
+        })
+        .await
+        .unwrap();
+        repo
+    }
+
+    #[tokio::test]
+    async fn test_new_processes_have_no_permitted_requests() {
+        let repo = setup().await;
+        let process = repo
+            .get_data_plane_processes_by_id(&Urn::from_str(PROCESS_ID).unwrap())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(process.permitted_requests, 0);
+    }
+
+    #[tokio::test]
+    async fn test_permitted_requests_move_by_delta() {
+        let repo = setup().await;
+        let id = Urn::from_str(PROCESS_ID).unwrap();
+        assert_eq!(repo.add_permitted_requests(&id, 1).await.unwrap(), 1);
+        assert_eq!(repo.add_permitted_requests(&id, 1).await.unwrap(), 2);
+        assert_eq!(repo.add_permitted_requests(&id, -1).await.unwrap(), 1);
+        let process = repo.get_data_plane_processes_by_id(&id).await.unwrap().unwrap();
+        assert_eq!(process.permitted_requests, 1);
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_requests_never_share_a_count() {
+        let repo = Arc::new(setup().await);
+        let id = Urn::from_str(PROCESS_ID).unwrap();
+        let handles = (0..10)
+            .map(|_| {
+                let repo = repo.clone();
+                let id = id.clone();
+                tokio::spawn(async move { repo.add_permitted_requests(&id, 1).await.unwrap() })
+            })
+            .collect::<Vec<_>>();
+        let mut counts = HashSet::new();
+        for handle in handles {
+            counts.insert(handle.await.unwrap());
+        }
+        assert_eq!(counts, (1..=10).collect::<HashSet<i64>>());
+    }
+
+    #[tokio::test]
+    async fn test_exchanges_add_up_in_the_process_totals() {
+        let repo = setup().await;
+        let id = Urn::from_str(PROCESS_ID).unwrap();
+        repo.add_exchange(&id, 10, 100).await.unwrap();
+        repo.add_exchange(&id, 5, 50).await.unwrap();
+        let process = repo.get_data_plane_processes_by_id(&id).await.unwrap().unwrap();
+        assert_eq!(process.exchange_count, 2);
+        assert_eq!(process.exchange_bytes_in, 15);
+        assert_eq!(process.exchange_bytes_out, 150);
+        assert!(process.last_exchange_at.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_unknown_process_is_not_found() {
+        let repo = setup().await;
+        let result = repo
+            .add_permitted_requests(&Urn::from_str("urn:data-plane-process:2").unwrap(), 1)
+            .await;
+        assert!(matches!(
+            result,
+            Err(DataPlaneProcessRepoErrors::DataplaneProcessNotFound)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_exchanges_of_an_unknown_process_are_not_found() {
+        let repo = setup().await;
+        let result =
+            repo.add_exchange(&Urn::from_str("urn:data-plane-process:2").unwrap(), 1, 1).await;
+        assert!(matches!(
+            result,
+            Err(DataPlaneProcessRepoErrors::DataplaneProcessNotFound)
+        ));
+    }
+}
@@ -0,0 +1,227 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+#[cfg(test)]
+mod test_data_source_connector {
+    use crate::coordinator::data_source_connector::connectors::{
+        DOWNSTREAM_HOP_URL_FIELD, UPSTREAM_HOP_URL_FIELD,
+    };
+    use crate::coordinator::data_source_connector::data_source_connector::DataSourceConnector;
+    use crate::coordinator::data_source_connector::delivery::{
+        DeliverySchedule, DELIVERY_ERROR_FIELD, DELIVERY_INTERVAL_FIELD,
+        DELIVERY_MAX_RETRIES_FIELD, LAST_DELIVERY_AT_FIELD,
+    };
+    use crate::coordinator::data_source_connector::{
+        DataSourceConnectorTrait, MockDataSourceConnectorTrait,
+    };
+    use crate::coordinator::dataplane_access_controller::dataplane_access_controller::DataPlaneAccessControllerService;
+    use crate::coordinator::dataplane_access_controller::DataPlaneAccessControllerTrait;
+    use crate::data::entities::data_plane_process;
+    use crate::entities::data_plane_process::{
+        DataPlaneProcessDto, EditDataPlaneProcessDto, MockDataPlaneProcessEntitiesTrait,
+    };
+    use axum::body::Bytes;
+    use axum::http::StatusCode;
+    use axum::routing::{get, post};
+    use axum::Router;
+    use rainbow_common::adv_protocol::interplane::data_plane_start::DataPlaneStart;
+    use rainbow_common::adv_protocol::interplane::{
+        DataPlaneControllerMessages, DataPlaneControllerVersion,
+    };
+    use rainbow_common::config::services::TransferConfig;
+    use rainbow_common::config::traits::ConfigLoader;
+    use rainbow_events::core::notification::notification_publisher::MockRainbowEventsPublisherTrait;
+    use std::collections::HashMap;
+    use std::str::FromStr;
+    use std::sync::Arc;
+    use std::time::Duration;
+    use tokio::sync::mpsc;
+    use urn::Urn;
+
+    const SESSION_ID: &str = "urn:data-plane-process:push";
+    const PAYLOAD: &str = "temperature,humidity\n21.5,40\n";
+
+    fn fields(entries: &[(&str, &str)]) -> HashMap<String, String> {
+        entries.iter().map(|(key, value)| (key.to_string(), value.to_string())).collect()
+    }
+
+    fn push_session(data_plane_fields: HashMap<String, String>) -> DataPlaneProcessDto {
+        DataPlaneProcessDto {
+            inner: data_plane_process::Model {
+                id: SESSION_ID.to_string(),
+                state: "STARTED".to_string(),
+                direction: "PUSH".to_string(),
+                permitted_requests: 0,
+                exchange_count: 0,
+                exchange_bytes_in: 0,
+                exchange_bytes_out: 0,
+                last_exchange_at: None,
+                created_at: chrono::Utc::now().into(),
+                updated_at: None,
+            },
+            data_plane_fields,
+        }
+    }
+
+    /// Source answering with `status` and the payload, returns its url
+    async fn serve_source(status: StatusCode) -> String {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/data", listener.local_addr().unwrap());
+        let app = Router::new().route("/data", get(move || async move { (status, PAYLOAD) }));
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+        url
+    }
+
+    /// Sink handing every pushed body to the receiver, returns its url
+    async fn serve_sink() -> (String, mpsc::UnboundedReceiver<Bytes>) {
+        let (pushed_tx, pushed_rx) = mpsc::unbounded_channel();
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/sink", listener.local_addr().unwrap());
+        let app = Router::new().route(
+            "/sink",
+            post(move |body: Bytes| async move {
+                pushed_tx.send(body).unwrap();
+                StatusCode::OK
+            }),
+        );
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+        (url, pushed_rx)
+    }
+
+    /// Entity serving the session and handing every edit to the receiver
+    fn entity_for(
+        session: DataPlaneProcessDto,
+    ) -> (
+        MockDataPlaneProcessEntitiesTrait,
+        mpsc::UnboundedReceiver<EditDataPlaneProcessDto>,
+    ) {
+        let (edits_tx, edits_rx) = mpsc::unbounded_channel();
+        let mut dataplane_service = MockDataPlaneProcessEntitiesTrait::new();
+        let data_plane_fields = session.data_plane_fields.clone();
+        dataplane_service
+            .expect_get_data_plane_processes_by_state()
+            .withf(|state, direction| state == "STARTED" && direction == "PUSH")
+            .returning(move |_, _| Ok(vec![push_session(data_plane_fields.clone())]));
+        let data_plane_fields = session.data_plane_fields.clone();
+        dataplane_service
+            .expect_get_data_plane_process_by_id()
+            .returning(move |_| Ok(Some(push_session(data_plane_fields.clone()))));
+        let data_plane_fields = session.data_plane_fields;
+        dataplane_service.expect_put_data_plane_process().returning(move |_, edit| {
+            edits_tx.send(edit.clone()).unwrap();
+            Ok(push_session(data_plane_fields.clone()))
+        });
+        (dataplane_service, edits_rx)
+    }
+
+    #[tokio::test]
+    async fn test_started_push_sessions_are_resumed() {
+        let source_url = serve_source(StatusCode::OK).await;
+        let (sink_url, mut pushed) = serve_sink().await;
+        let (dataplane_service, mut edits) = entity_for(push_session(fields(&[
+            (DOWNSTREAM_HOP_URL_FIELD, source_url.as_str()),
+            (UPSTREAM_HOP_URL_FIELD, sink_url.as_str()),
+        ])));
+        let connector = DataSourceConnector::new(Arc::new(dataplane_service));
+
+        connector.resume_sessions().await.unwrap();
+
+        let body = tokio::time::timeout(Duration::from_secs(5), pushed.recv()).await.unwrap();
+        assert_eq!(body.unwrap(), PAYLOAD.as_bytes());
+        let edit = tokio::time::timeout(Duration::from_secs(5), edits.recv()).await.unwrap();
+        let edit = edit.unwrap();
+        assert_eq!(edit.state, None);
+        assert!(edit.fields.unwrap().contains_key(LAST_DELIVERY_AT_FIELD));
+    }
+
+    #[tokio::test]
+    async fn test_session_is_terminated_once_retries_are_exhausted() {
+        let source_url = serve_source(StatusCode::INTERNAL_SERVER_ERROR).await;
+        let (sink_url, mut pushed) = serve_sink().await;
+        let (dataplane_service, mut edits) = entity_for(push_session(fields(&[
+            (DOWNSTREAM_HOP_URL_FIELD, source_url.as_str()),
+            (UPSTREAM_HOP_URL_FIELD, sink_url.as_str()),
+            (DELIVERY_INTERVAL_FIELD, "60"),
+            (DELIVERY_MAX_RETRIES_FIELD, "0"),
+        ])));
+        let connector = DataSourceConnector::new(Arc::new(dataplane_service));
+
+        connector.resume_sessions().await.unwrap();
+
+        let edit = tokio::time::timeout(Duration::from_secs(5), edits.recv()).await.unwrap();
+        let edit = edit.unwrap();
+        assert_eq!(edit.state.as_deref(), Some("TERMINATED"));
+        assert!(edit.fields.unwrap().get(DELIVERY_ERROR_FIELD).is_some_and(|e| !e.is_empty()));
+        assert!(pushed.try_recv().is_err());
+    }
+
+    #[test]
+    fn test_periodic_sessions_keep_their_pace_across_restarts() {
+        let now = chrono::Utc::now();
+        let periodic = DeliverySchedule { interval: Some(Duration::from_secs(60)), max_retries: 3 };
+        let twenty_seconds_ago = (now - chrono::TimeDelta::seconds(20)).to_rfc3339();
+        assert_eq!(
+            periodic.first_delivery_delay(Some(twenty_seconds_ago.as_str()), now),
+            Duration::from_secs(40)
+        );
+        let long_ago = (now - chrono::TimeDelta::hours(2)).to_rfc3339();
+        assert_eq!(
+            periodic.first_delivery_delay(Some(long_ago.as_str()), now),
+            Duration::ZERO
+        );
+        // never delivered, or delivered once only, goes right away
+        assert_eq!(periodic.first_delivery_delay(None, now), Duration::ZERO);
+        assert_eq!(
+            DeliverySchedule::default().first_delivery_delay(Some(long_ago.as_str()), now),
+            Duration::ZERO
+        );
+    }
+
+    #[tokio::test]
+    async fn test_push_start_without_sink_is_rejected() {
+        let mut dataplane_service = MockDataPlaneProcessEntitiesTrait::new();
+        dataplane_service.expect_get_data_plane_process_by_id().returning(|_| {
+            Ok(Some(push_session(fields(&[(
+                DOWNSTREAM_HOP_URL_FIELD,
+                "http://localhost/data",
+            )]))))
+        });
+        dataplane_service.expect_put_data_plane_process().times(0);
+        let mut connector = MockDataSourceConnectorTrait::new();
+        connector.expect_start_streaming().times(0);
+        let config =
+            TransferConfig::load("../static/environment/config/core.provider.yaml".to_string());
+        let controller = DataPlaneAccessControllerService::new(
+            Arc::new(connector),
+            Arc::new(dataplane_service),
+            Arc::new(MockRainbowEventsPublisherTrait::new()),
+            Arc::new(config),
+        );
+
+        let started = controller
+            .data_plane_start(&DataPlaneStart {
+                _type: DataPlaneControllerMessages::DataPlaneStart,
+                version: DataPlaneControllerVersion::Version10,
+                session_id: Urn::from_str(SESSION_ID).unwrap(),
+            })
+            .await;
+
+        assert!(started.is_err());
+    }
+}
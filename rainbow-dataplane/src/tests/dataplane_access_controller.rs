@@ -0,0 +1,161 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+#[cfg(test)]
+mod test_dataplane_access_controller {
+    use crate::coordinator::data_source_connector::connectors::{
+        DOWNSTREAM_HOP_URL_FIELD, UPSTREAM_HOP_URL_FIELD,
+    };
+    use crate::coordinator::data_source_connector::MockDataSourceConnectorTrait;
+    use crate::coordinator::dataplane_access_controller::dataplane_access_controller::DataPlaneAccessControllerService;
+    use crate::coordinator::dataplane_access_controller::DataPlaneAccessControllerTrait;
+    use crate::data::entities::data_plane_process;
+    use crate::entities::data_plane_process::{
+        DataPlaneProcessDto, MockDataPlaneProcessEntitiesTrait,
+    };
+    use rainbow_common::adv_protocol::interplane::data_plane_start::DataPlaneStart;
+    use rainbow_common::adv_protocol::interplane::data_plane_stop::DataPlaneStop;
+    use rainbow_common::adv_protocol::interplane::{
+        DataPlaneControllerMessages, DataPlaneControllerVersion,
+    };
+    use rainbow_common::config::services::TransferConfig;
+    use rainbow_common::config::traits::ConfigLoader;
+    use rainbow_events::core::notification::notification_publisher::MockRainbowEventsPublisherTrait;
+    use rainbow_events::core::notification::notification_types::{
+        RainbowEventsNotificationEvent, RainbowEventsNotificationMessageCategory,
+        RainbowEventsNotificationMessageOperation, RainbowEventsNotificationMessageTypes,
+    };
+    use serde_json::json;
+    use std::collections::HashMap;
+    use std::str::FromStr;
+    use std::sync::{Arc, Mutex};
+    use urn::Urn;
+
+    const SESSION_ID: &str = "urn:data-plane-process:events";
+
+    type Published = Arc<Mutex<Vec<RainbowEventsNotificationEvent>>>;
+
+    fn session(state: &str) -> DataPlaneProcessDto {
+        DataPlaneProcessDto {
+            inner: data_plane_process::Model {
+                id: SESSION_ID.to_string(),
+                state: state.to_string(),
+                direction: "PUSH".to_string(),
+                permitted_requests: 0,
+                exchange_count: 0,
+                exchange_bytes_in: 0,
+                exchange_bytes_out: 0,
+                last_exchange_at: None,
+                created_at: chrono::Utc::now().into(),
+                updated_at: None,
+            },
+            data_plane_fields: HashMap::from([
+                (
+                    DOWNSTREAM_HOP_URL_FIELD.to_string(),
+                    "http://provider.example/data".to_string(),
+                ),
+                (
+                    UPSTREAM_HOP_URL_FIELD.to_string(),
+                    "http://consumer.example/sink".to_string(),
+                ),
+            ]),
+        }
+    }
+
+    /// Controller over a push session whose store applies every state edit
+    fn controller() -> (DataPlaneAccessControllerService, Published) {
+        let mut dataplane_service = MockDataPlaneProcessEntitiesTrait::new();
+        dataplane_service
+            .expect_get_data_plane_process_by_id()
+            .returning(|_| Ok(Some(session("REQUESTED"))));
+        dataplane_service
+            .expect_put_data_plane_process()
+            .returning(|_, edit| Ok(session(edit.state.as_deref().unwrap())));
+        let mut connector = MockDataSourceConnectorTrait::new();
+        connector.expect_start_streaming().times(1).returning(|_, _| Ok(()));
+        connector.expect_stop_streaming().times(1).returning(|_| Ok(()));
+
+        let published: Published = Arc::new(Mutex::new(vec![]));
+        let recorded = published.clone();
+        let mut events = MockRainbowEventsPublisherTrait::new();
+        events.expect_publish().returning(move |event| recorded.lock().unwrap().push(event));
+
+        let config =
+            TransferConfig::load("../static/environment/config/core.provider.yaml".to_string());
+        let controller = DataPlaneAccessControllerService::new(
+            Arc::new(connector),
+            Arc::new(dataplane_service),
+            Arc::new(events),
+            Arc::new(config),
+        );
+        (controller, published)
+    }
+
+    fn assert_session_event(
+        event: &RainbowEventsNotificationEvent,
+        subcategory: &str,
+        state: &str,
+    ) {
+        assert_eq!(event.category, RainbowEventsNotificationMessageCategory::DataPlane);
+        assert_eq!(event.subcategory, subcategory);
+        assert_eq!(
+            event.message_type,
+            RainbowEventsNotificationMessageTypes::RainbowEntitiesMessage
+        );
+        assert_eq!(
+            event.message_operation,
+            RainbowEventsNotificationMessageOperation::Update
+        );
+        assert_eq!(
+            event.content.entity_ids.iter().collect::<Vec<_>>(),
+            vec![(&"sessionId".to_string(), &SESSION_ID.to_string())]
+        );
+        assert_eq!(event.content.snapshot["state"], json!(state));
+        // fields hold the hop credentials and never leave the data plane
+        assert!(event.content.snapshot.get("dataPlaneFields").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_start_and_stop_are_published() {
+        let (controller, published) = controller();
+        let session_id = Urn::from_str(SESSION_ID).unwrap();
+
+        controller
+            .data_plane_start(&DataPlaneStart {
+                _type: DataPlaneControllerMessages::DataPlaneStart,
+                version: DataPlaneControllerVersion::Version10,
+                session_id: session_id.clone(),
+            })
+            .await
+            .unwrap();
+        controller
+            .data_plane_stop(&DataPlaneStop {
+                _type: DataPlaneControllerMessages::DataPlaneStop,
+                version: DataPlaneControllerVersion::Version10,
+                session_id,
+            })
+            .await
+            .unwrap();
+
+        let published = published.lock().unwrap();
+        assert_eq!(published.len(), 2);
+        assert_session_event(&published[0], "DataPlaneStart", "STARTED");
+        assert_session_event(&published[1], "DataPlaneStop", "STOPPED");
+    }
+}
@@ -0,0 +1,408 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+#[cfg(test)]
+mod test_forwarding {
+    use crate::coordinator::data_source_connector::connectors::DOWNSTREAM_HOP_URL_FIELD;
+    use crate::coordinator::data_source_connector::MockDataSourceConnectorTrait;
+    use crate::coordinator::dataplane_access_controller::dataplane_access_controller::DataPlaneAccessControllerService;
+    use crate::coordinator::dataplane_access_controller::DataPlaneAccessControllerTrait;
+    use crate::data::entities::{data_plane_process, transfer_event};
+    use crate::entities::data_plane_process::{
+        DataPlaneProcessDto, MockDataPlaneProcessEntitiesTrait,
+    };
+    use crate::entities::transfer_events::{MockTransferEventEntitiesTrait, TransferEventDto};
+    use crate::facades::pdp_facade::MockPdpFacadeTrait;
+    use crate::testing_proxy::http::forwarding::{
+        limit_stream, BodyLimitExceeded, ForwardingPolicy, PROXY_HEADERS_ALLOW_FIELD,
+        PROXY_HEADERS_DENY_FIELD, PROXY_MAX_REQUEST_BODY_FIELD, PROXY_MAX_RESPONSE_BODY_FIELD,
+        PROXY_QUERY_ALLOW_FIELD, PROXY_QUERY_DENY_FIELD,
+    };
+    use crate::testing_proxy::http::http::TestingHTTPProxy;
+    use axum::body::Bytes;
+    use axum::extract::RawQuery;
+    use axum::routing::post;
+    use axum::{BoxError, Router};
+    use futures::StreamExt;
+    use rainbow_common::adv_protocol::interplane::data_plane_provision::DataPlaneProvisionRequest;
+    use rainbow_common::adv_protocol::interplane::{
+        DataPlaneControllerMessages, DataPlaneControllerVersion, DataPlaneSDPConfigField,
+        DataPlaneSDPConfigTypes,
+    };
+    use rainbow_common::config::services::TransferConfig;
+    use rainbow_common::config::traits::ConfigLoader;
+    use rainbow_common::dcat_formats::FormatAction;
+    use rainbow_common::dsp_common::odrl_evaluator::{OdrlDecisionOutcome, OdrlPolicyDecision};
+    use rainbow_events::core::notification::notification_publisher::MockRainbowEventsPublisherTrait;
+    use reqwest::header::{HeaderMap, HeaderValue};
+    use reqwest::StatusCode;
+    use serde_json::json;
+    use std::collections::HashMap;
+    use std::str::FromStr;
+    use std::sync::{Arc, Mutex};
+    use urn::Urn;
+
+    const SESSION_ID: &str = "urn:data-plane-process:1";
+
+    fn fields(entries: &[(&str, &str)]) -> HashMap<String, String> {
+        entries.iter().map(|(key, value)| (key.to_string(), value.to_string())).collect()
+    }
+
+    fn headers(entries: &[(&'static str, &'static str)]) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        for (name, value) in entries {
+            headers.append(*name, HeaderValue::from_static(value));
+        }
+        headers
+    }
+
+    fn chunks(count: usize, size: usize) -> impl futures::Stream<Item = Result<Bytes, BoxError>> {
+        futures::stream::iter((0..count).map(move |_| Ok(Bytes::from(vec![0u8; size]))))
+    }
+
+    #[test]
+    fn test_policy_is_read_from_the_session_fields() {
+        let policy = ForwardingPolicy::from_fields(&fields(&[
+            (PROXY_MAX_REQUEST_BODY_FIELD, " 1024 "),
+            (PROXY_MAX_RESPONSE_BODY_FIELD, "not a number"),
+            (PROXY_HEADERS_ALLOW_FIELD, "Accept, X-Custom,,"),
+            (PROXY_HEADERS_DENY_FIELD, "Cookie"),
+            (PROXY_QUERY_ALLOW_FIELD, ""),
+            (PROXY_QUERY_DENY_FIELD, "Token"),
+        ]));
+        assert_eq!(
+            policy,
+            ForwardingPolicy {
+                max_request_body: Some(1024),
+                max_response_body: None,
+                headers_allow: vec!["accept".to_string(), "x-custom".to_string()],
+                headers_deny: vec!["cookie".to_string()],
+                query_allow: vec![],
+                query_deny: vec!["token".to_string()],
+            }
+        );
+        assert_eq!(
+            ForwardingPolicy::from_fields(&HashMap::new()),
+            ForwardingPolicy::default()
+        );
+    }
+
+    #[test]
+    fn test_hop_by_hop_and_credential_headers_are_never_forwarded() {
+        let forwarded = ForwardingPolicy::default().request_headers(&headers(&[
+            ("connection", "keep-alive"),
+            ("transfer-encoding", "chunked"),
+            ("host", "consumer.example"),
+            ("authorization", "Bearer consumer-token"),
+            ("range", "bytes=0-99"),
+            ("accept", "application/json"),
+        ]));
+        assert_eq!(forwarded.len(), 2);
+        assert_eq!(forwarded.get("range").unwrap(), "bytes=0-99");
+        assert_eq!(forwarded.get("accept").unwrap(), "application/json");
+    }
+
+    #[test]
+    fn test_header_lists_filter_request_headers_only() {
+        let policy = ForwardingPolicy {
+            headers_allow: vec!["accept".to_string(), "cookie".to_string()],
+            headers_deny: vec!["cookie".to_string()],
+            ..Default::default()
+        };
+        let incoming = headers(&[
+            ("accept", "text/csv"),
+            ("cookie", "session=1"),
+            ("x-other", "1"),
+            ("content-range", "bytes 0-99/200"),
+        ]);
+        let forwarded = policy.request_headers(&incoming);
+        assert_eq!(forwarded.len(), 1);
+        assert!(forwarded.contains_key("accept"));
+
+        // the consumer gets every end-to-end header of the response
+        let returned = policy.response_headers(&incoming);
+        assert_eq!(returned.len(), 4);
+    }
+
+    #[test]
+    fn test_next_hop_url_keeps_allowed_query_parameters() {
+        let policy = ForwardingPolicy {
+            query_allow: vec!["page".to_string(), "token".to_string()],
+            query_deny: vec!["token".to_string()],
+            ..Default::default()
+        };
+        let url = policy
+            .next_hop_url("http://provider.example/data?fixed=1", Some("page=2&token=x&other=3"))
+            .unwrap();
+        assert_eq!(url.as_str(), "http://provider.example/data?fixed=1&page=2");
+
+        let url = policy.next_hop_url("http://provider.example/data", Some("other=3")).unwrap();
+        assert_eq!(url.as_str(), "http://provider.example/data");
+        assert!(policy.next_hop_url("not a url", None).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_bodies_within_the_limit_pass_untouched() {
+        let body = limit_stream(chunks(3, 100), Some(300)).collect::<Vec<_>>().await;
+        assert_eq!(body.len(), 3);
+        assert!(body.iter().all(|chunk| chunk.as_ref().is_ok_and(|c| c.len() == 100)));
+
+        let body = limit_stream(chunks(3, 100), None).collect::<Vec<_>>().await;
+        assert!(body.iter().all(Result::is_ok));
+    }
+
+    #[tokio::test]
+    async fn test_bodies_over_the_limit_fail_with_the_limit_error() {
+        let body = limit_stream(chunks(3, 100), Some(250)).collect::<Vec<_>>().await;
+        assert!(body[0].is_ok() && body[1].is_ok());
+        let err = body[2].as_ref().unwrap_err();
+        assert!(BodyLimitExceeded::is_cause_of(err.as_ref()));
+        assert_eq!(err.to_string(), "body exceeds the session limit of 250 bytes");
+    }
+
+    #[test]
+    fn test_limit_error_is_found_down_the_source_chain() {
+        let wrapped = anyhow::Error::new(BodyLimitExceeded { limit: 1 }).context("send failed");
+        assert!(BodyLimitExceeded::is_cause_of(wrapped.as_ref()));
+        let unrelated = anyhow::anyhow!("connection reset").context("send failed");
+        assert!(!BodyLimitExceeded::is_cause_of(unrelated.as_ref()));
+    }
+
+    /// Downstream hop that echoes the size of what it got, with the query and the request
+    /// header names it saw in the response headers
+    async fn serve_downstream() -> String {
+        let downstream = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let downstream_url = format!("http://{}/data", downstream.local_addr().unwrap());
+        let app = Router::new().route(
+            "/data",
+            post(
+                |RawQuery(query): RawQuery, headers: HeaderMap, body: Bytes| async move {
+                    let mut names = headers.keys().map(|name| name.as_str()).collect::<Vec<_>>();
+                    names.sort();
+                    let mut seen = HeaderMap::new();
+                    seen.insert("x-seen-query", query.unwrap_or_default().parse().unwrap());
+                    seen.insert("x-seen-headers", names.join(",").parse().unwrap());
+                    (seen, body.len().to_string())
+                },
+            ),
+        );
+        tokio::spawn(async move { axum::serve(downstream, app).await.unwrap() });
+        downstream_url
+    }
+
+    async fn serve_proxy(max_request_body: &str) -> String {
+        let downstream_url = serve_downstream().await;
+        serve_session(fields(&[
+            (DOWNSTREAM_HOP_URL_FIELD, downstream_url.as_str()),
+            (PROXY_MAX_REQUEST_BODY_FIELD, max_request_body),
+        ]))
+        .await
+    }
+
+    /// Proxy serving a started session with the given fields
+    async fn serve_session(data_plane_fields: HashMap<String, String>) -> String {
+        let mut dataplane_service = MockDataPlaneProcessEntitiesTrait::new();
+        dataplane_service.expect_get_data_plane_process_by_id().returning(move |id| {
+            Ok(Some(DataPlaneProcessDto {
+                inner: data_plane_process::Model {
+                    id: id.to_string(),
+                    state: "STARTED".to_string(),
+                    direction: "PULL".to_string(),
+                    permitted_requests: 0,
+                    exchange_count: 0,
+                    exchange_bytes_in: 0,
+                    exchange_bytes_out: 0,
+                    last_exchange_at: None,
+                    created_at: chrono::Utc::now().into(),
+                    updated_at: None,
+                },
+                data_plane_fields: data_plane_fields.clone(),
+            }))
+        });
+        let mut pdp_facade = MockPdpFacadeTrait::new();
+        pdp_facade.expect_authorize_session_request().returning(|_, action, _| {
+            Ok(OdrlPolicyDecision {
+                outcome: OdrlDecisionOutcome::Permit,
+                action: action.to_string(),
+                reason: "permitted".to_string(),
+                rules: vec![],
+                pending_obligations: vec![],
+            })
+        });
+        let mut transfer_event_entity = MockTransferEventEntitiesTrait::new();
+        transfer_event_entity.expect_create_transfer_event().returning(|new_event| {
+            Ok(TransferEventDto {
+                inner: transfer_event::Model {
+                    id: new_event.id.to_string(),
+                    dataplane_process_id: new_event.dataplane_process_id.to_string(),
+                    from: new_event.from.clone(),
+                    to: new_event.to.clone(),
+                    payload: new_event.payload.clone(),
+                    created_at: chrono::Utc::now().into(),
+                },
+            })
+        });
+        let proxy = TestingHTTPProxy::new(
+            Arc::new(dataplane_service),
+            Arc::new(pdp_facade),
+            Arc::new(transfer_event_entity),
+        );
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let proxy_url = format!("http://{}/{}", listener.local_addr().unwrap(), SESSION_ID);
+        tokio::spawn(async move { axum::serve(listener, proxy.router()).await.unwrap() });
+        proxy_url
+    }
+
+    #[tokio::test]
+    async fn test_chunked_body_within_the_limit_is_forwarded() {
+        let proxy_url = serve_proxy("1000").await;
+        let res = reqwest::Client::new()
+            .post(proxy_url)
+            .body(reqwest::Body::wrap_stream(chunks(5, 100)))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), StatusCode::OK);
+        assert_eq!(res.text().await.unwrap(), "500");
+    }
+
+    #[tokio::test]
+    async fn test_chunked_body_over_the_limit_is_payload_too_large() {
+        let proxy_url = serve_proxy("250").await;
+        let res = reqwest::Client::new()
+            .post(proxy_url)
+            .body(reqwest::Body::wrap_stream(chunks(5, 100)))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), StatusCode::PAYLOAD_TOO_LARGE);
+    }
+
+    fn provider_config(forwarding: serde_json::Value) -> Arc<TransferConfig> {
+        let config =
+            TransferConfig::load("../static/environment/config/core.provider.yaml".to_string());
+        let mut config = serde_json::to_value(config).unwrap();
+        config["data_plane_forwarding"] = forwarding;
+        Arc::new(serde_json::from_value(config).unwrap())
+    }
+
+    /// Fields of a pull session provisioned towards `next_hop`
+    async fn provision(config: Arc<TransferConfig>, next_hop: &str) -> HashMap<String, String> {
+        let provisioned = Arc::new(Mutex::new(HashMap::new()));
+        let mut dataplane_service = MockDataPlaneProcessEntitiesTrait::new();
+        let captured = provisioned.clone();
+        dataplane_service.expect_create_data_plane_process().times(1).returning(move |new| {
+            let data_plane_fields = new.fields.clone().unwrap_or_default();
+            *captured.lock().unwrap() = data_plane_fields.clone();
+            Ok(DataPlaneProcessDto {
+                inner: data_plane_process::Model {
+                    id: new.id.to_string(),
+                    state: new.state.clone(),
+                    direction: new.direction.clone(),
+                    permitted_requests: 0,
+                    exchange_count: 0,
+                    exchange_bytes_in: 0,
+                    exchange_bytes_out: 0,
+                    last_exchange_at: None,
+                    created_at: chrono::Utc::now().into(),
+                    updated_at: None,
+                },
+                data_plane_fields,
+            })
+        });
+        let controller = DataPlaneAccessControllerService::new(
+            Arc::new(MockDataSourceConnectorTrait::new()),
+            Arc::new(dataplane_service),
+            Arc::new(MockRainbowEventsPublisherTrait::new()),
+            config,
+        );
+        let config_field = |_type: DataPlaneSDPConfigTypes, content: &str| {
+            DataPlaneSDPConfigField { _type, format: None, content: content.to_string() }
+        };
+        controller
+            .data_plane_provision_request(&DataPlaneProvisionRequest {
+                _type: DataPlaneControllerMessages::DataPlaneProvisionRequest,
+                version: DataPlaneControllerVersion::Version10,
+                session_id: Urn::from_str(SESSION_ID).unwrap(),
+                sdp_request: vec![],
+                sdp_config: Some(vec![
+                    config_field(DataPlaneSDPConfigTypes::NextHopAddressScheme, "http"),
+                    config_field(DataPlaneSDPConfigTypes::NextHopAddress, next_hop),
+                    config_field(
+                        DataPlaneSDPConfigTypes::Direction,
+                        FormatAction::Pull.to_string().as_str(),
+                    ),
+                ]),
+            })
+            .await
+            .unwrap();
+        let data_plane_fields = provisioned.lock().unwrap();
+        data_plane_fields.clone()
+    }
+
+    #[tokio::test]
+    async fn test_provisioned_session_enforces_the_configured_policy() {
+        let config = provider_config(json!({
+            "max_request_body_size": 400,
+            "headers_deny": ["Cookie"],
+            "query_allow": ["page", "token"],
+            "query_deny": ["token"]
+        }));
+        let downstream_url = serve_downstream().await;
+        let data_plane_fields = provision(config, downstream_url.as_str()).await;
+        assert_eq!(data_plane_fields.get(PROXY_MAX_REQUEST_BODY_FIELD).unwrap(), "400");
+        assert_eq!(data_plane_fields.get(PROXY_MAX_RESPONSE_BODY_FIELD).unwrap(), "");
+        assert_eq!(data_plane_fields.get(PROXY_QUERY_ALLOW_FIELD).unwrap(), "page,token");
+        let proxy_url = serve_session(data_plane_fields).await;
+
+        let res = reqwest::Client::new()
+            .post(format!("{}?page=2&token=secret&other=1", proxy_url))
+            .header("cookie", "session=1")
+            .header("x-custom", "1")
+            .body(reqwest::Body::wrap_stream(chunks(3, 100)))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), StatusCode::OK);
+        assert_eq!(res.headers().get("x-seen-query").unwrap(), "page=2");
+        let seen_headers = res.headers().get("x-seen-headers").unwrap().to_str().unwrap();
+        assert!(seen_headers.split(',').any(|name| name == "x-custom"));
+        assert!(!seen_headers.split(',').any(|name| name == "cookie"));
+        assert_eq!(res.text().await.unwrap(), "300");
+
+        let res = reqwest::Client::new()
+            .post(proxy_url)
+            .body(reqwest::Body::wrap_stream(chunks(5, 100)))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), StatusCode::PAYLOAD_TOO_LARGE);
+    }
+
+    #[tokio::test]
+    async fn test_sessions_are_unrestricted_without_forwarding_config() {
+        let config = provider_config(json!({}));
+        let data_plane_fields = provision(config, "http://provider.example/data").await;
+        assert_eq!(
+            ForwardingPolicy::from_fields(&data_plane_fields),
+            ForwardingPolicy::default()
+        );
+    }
+}
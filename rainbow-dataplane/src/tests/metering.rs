@@ -0,0 +1,162 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+#[cfg(test)]
+mod test_metering {
+    use crate::data::entities::transfer_event;
+    use crate::entities::transfer_events::{
+        MockTransferEventEntitiesTrait, NewTransferEventDto, TransferEventDto,
+        TransferExchangePayload, EXCHANGE_TRANSFER_EVENT_FROM,
+    };
+    use crate::testing_proxy::http::metering::{ExchangeRecorder, MeteredStream, StreamMetrics};
+    use axum::body::Bytes;
+    use axum::BoxError;
+    use futures::StreamExt;
+    use std::str::FromStr;
+    use std::sync::{Arc, Mutex};
+    use tokio::sync::mpsc;
+    use urn::Urn;
+
+    const HELLO_SHA256: &str = "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824";
+
+    fn body(chunks: &[&'static str]) -> impl futures::Stream<Item = Result<Bytes, BoxError>> {
+        futures::stream::iter(
+            chunks.iter().map(|chunk| Ok(Bytes::from_static(chunk.as_bytes()))).collect::<Vec<_>>(),
+        )
+    }
+
+    /// Metered stream that leaves the metrics it finished with in `finished`
+    fn metered<S>(inner: S, finished: Arc<Mutex<Vec<StreamMetrics>>>) -> MeteredStream
+    where
+        S: futures::Stream<Item = Result<Bytes, BoxError>> + Send + 'static,
+    {
+        MeteredStream::new(inner, move |metrics| finished.lock().unwrap().push(metrics))
+    }
+
+    /// Event log that hands every stored event over to the test
+    fn events(
+        sender: mpsc::UnboundedSender<NewTransferEventDto>,
+    ) -> MockTransferEventEntitiesTrait {
+        let mut event_entity = MockTransferEventEntitiesTrait::new();
+        event_entity.expect_create_transfer_event().returning(move |new_event| {
+            sender.send(new_event.clone()).unwrap();
+            Ok(TransferEventDto {
+                inner: transfer_event::Model {
+                    id: new_event.id.to_string(),
+                    dataplane_process_id: new_event.dataplane_process_id.to_string(),
+                    from: new_event.from.clone(),
+                    to: new_event.to.clone(),
+                    payload: new_event.payload.clone(),
+                    created_at: chrono::Utc::now().into(),
+                },
+            })
+        });
+        event_entity
+    }
+
+    fn recorder(event_entity: MockTransferEventEntitiesTrait) -> ExchangeRecorder {
+        ExchangeRecorder::new(
+            Arc::new(event_entity),
+            Urn::from_str("urn:data-plane-process:1").unwrap(),
+            "PULL".to_string(),
+            "POST".to_string(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_whole_body_is_counted_and_hashed() {
+        let finished = Arc::new(Mutex::new(vec![]));
+        let chunks = metered(body(&["he", "llo"]), finished.clone()).collect::<Vec<_>>().await;
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(
+            *finished.lock().unwrap(),
+            vec![StreamMetrics { bytes: 5, sha256: HELLO_SHA256.to_string(), completed: true }]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_failed_body_is_not_completed() {
+        let finished = Arc::new(Mutex::new(vec![]));
+        let inner = body(&["he"]).chain(futures::stream::iter(vec![Err::<Bytes, BoxError>(
+            "peer went away".into(),
+        )]));
+        let _ = metered(inner, finished.clone()).collect::<Vec<_>>().await;
+        let finished = finished.lock().unwrap();
+        assert_eq!(finished.len(), 1);
+        assert_eq!(finished[0].bytes, 2);
+        assert!(!finished[0].completed);
+    }
+
+    #[tokio::test]
+    async fn test_dropped_body_finishes_once() {
+        let finished = Arc::new(Mutex::new(vec![]));
+        let mut stream = metered(body(&["he", "llo"]), finished.clone());
+        stream.next().await.unwrap().unwrap();
+        drop(stream);
+        let finished = finished.lock().unwrap();
+        assert_eq!(finished.len(), 1);
+        assert_eq!(finished[0].bytes, 2);
+        assert!(!finished[0].completed);
+    }
+
+    #[tokio::test]
+    async fn test_exchange_is_recorded_with_both_bodies() {
+        let (sender, mut receiver) = mpsc::unbounded_channel();
+        let mut exchange = recorder(events(sender));
+        let _ = exchange.meter_request(body(&["hello"])).collect::<Vec<_>>().await;
+        exchange.response_received();
+        exchange.record(
+            Some(200),
+            StreamMetrics { bytes: 7, sha256: "response-hash".to_string(), completed: true },
+            None,
+        );
+
+        let event = receiver.recv().await.unwrap();
+        assert_eq!(event.from, EXCHANGE_TRANSFER_EVENT_FROM);
+        assert_eq!(event.dataplane_process_id.to_string(), "urn:data-plane-process:1");
+        let payload: TransferExchangePayload = serde_json::from_value(event.payload).unwrap();
+        assert_eq!(payload.method, "POST");
+        assert_eq!(payload.status_code, Some(200));
+        assert_eq!(payload.bytes_in, 5);
+        assert_eq!(payload.bytes_out, 7);
+        assert_eq!(payload.request_payload_hash.as_deref(), Some(HELLO_SHA256));
+        assert_eq!(payload.payload_hash.as_deref(), Some("response-hash"));
+        assert!(payload.completed);
+        assert!(payload.error.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_incomplete_exchange_has_no_response_hash() {
+        let (sender, mut receiver) = mpsc::unbounded_channel();
+        recorder(events(sender)).record(
+            None,
+            StreamMetrics::default(),
+            Some("peer connection problem".to_string()),
+        );
+
+        let event = receiver.recv().await.unwrap();
+        let payload: TransferExchangePayload = serde_json::from_value(event.payload).unwrap();
+        assert_eq!(payload.status_code, None);
+        assert_eq!(payload.bytes_in, 0);
+        assert!(payload.request_payload_hash.is_none());
+        assert!(payload.payload_hash.is_none());
+        assert!(!payload.completed);
+        assert_eq!(payload.error.as_deref(), Some("peer connection problem"));
+    }
+}
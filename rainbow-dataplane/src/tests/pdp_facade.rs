@@ -0,0 +1,374 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+#[cfg(test)]
+mod test_pdp_facade {
+    use crate::data::entities::transfer_event;
+    use crate::entities::data_plane_process::MockDataPlaneProcessEntitiesTrait;
+    use crate::entities::transfer_events::{MockTransferEventEntitiesTrait, TransferEventDto};
+    use crate::facades::pdp_facade::pdp_facade::{PdpFacadeService, PDP_TRANSFER_EVENT_FROM};
+    use crate::facades::pdp_facade::PdpFacadeTrait;
+    use axum::routing::get;
+    use axum::{Json, Router};
+    use rainbow_common::config::services::TransferConfig;
+    use rainbow_common::dsp_common::odrl::OdrlAgreement;
+    use rainbow_common::dsp_common::odrl_evaluator::odrl_evaluator::OdrlEvaluatorService;
+    use rainbow_common::dsp_common::odrl_evaluator::{OdrlDecisionOutcome, OdrlRequestContext};
+    use rainbow_common::errors::CommonErrors;
+    use rainbow_common::http_client::HttpClient;
+    use serde_json::{json, Value};
+    use std::str::FromStr;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Arc, Mutex};
+    use urn::Urn;
+
+    const SESSION_ID: &str = "urn:data-plane-process:1";
+
+    fn session_id() -> Urn {
+        Urn::from_str(SESSION_ID).unwrap()
+    }
+
+    fn agreement(policy: Value) -> OdrlAgreement {
+        let mut agreement = serde_json::to_value(OdrlAgreement::default()).unwrap();
+        agreement.as_object_mut().unwrap().extend(policy.as_object().unwrap().clone());
+        serde_json::from_value(agreement).unwrap()
+    }
+
+    /// Session counter kept in memory, moved the way the repo moves it
+    fn counter(permitted_requests: Arc<Mutex<i64>>) -> MockDataPlaneProcessEntitiesTrait {
+        let mut process_entity = MockDataPlaneProcessEntitiesTrait::new();
+        process_entity.expect_add_permitted_requests().returning(move |_, delta| {
+            let mut permitted_requests = permitted_requests.lock().unwrap();
+            *permitted_requests += delta;
+            Ok(*permitted_requests)
+        });
+        process_entity
+    }
+
+    /// Event log kept in memory, holding the payload of every recorded decision
+    fn events(payloads: Arc<Mutex<Vec<Value>>>) -> MockTransferEventEntitiesTrait {
+        let mut event_entity = MockTransferEventEntitiesTrait::new();
+        event_entity.expect_create_transfer_event().returning(move |new_event| {
+            payloads.lock().unwrap().push(new_event.payload.clone());
+            Ok(TransferEventDto {
+                inner: transfer_event::Model {
+                    id: new_event.id.to_string(),
+                    dataplane_process_id: new_event.dataplane_process_id.to_string(),
+                    from: new_event.from.clone(),
+                    to: new_event.to.clone(),
+                    payload: new_event.payload.clone(),
+                    created_at: chrono::Utc::now().into(),
+                },
+            })
+        });
+        event_entity
+    }
+
+    fn pdp(
+        process_entity: MockDataPlaneProcessEntitiesTrait,
+        event_entity: MockTransferEventEntitiesTrait,
+    ) -> PdpFacadeService {
+        let config =
+            TransferConfig::load("../static/environment/config/core.provider.yaml".to_string());
+        PdpFacadeService::new(
+            Arc::new(config),
+            Arc::new(HttpClient::new(1, 1)),
+            Arc::new(OdrlEvaluatorService::new()),
+            Arc::new(process_entity),
+            Arc::new(event_entity),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_count_limit_holds_across_requests() {
+        let permitted_requests = Arc::new(Mutex::new(0));
+        let payloads = Arc::new(Mutex::new(vec![]));
+        let pdp = pdp(counter(permitted_requests.clone()), events(payloads.clone()));
+        let agreement = agreement(json!({
+            "permission": [{
+                "action": "use",
+                "constraint": [{ "leftOperand": "count", "operator": "lteq", "rightOperand": "2" }]
+            }]
+        }));
+
+        let mut outcomes = vec![];
+        for _ in 0..3 {
+            let decision = pdp
+                .authorize_with_agreement(
+                    &session_id(),
+                    &agreement,
+                    "read",
+                    OdrlRequestContext::default(),
+                )
+                .await
+                .unwrap();
+            outcomes.push(decision.outcome);
+        }
+        assert_eq!(
+            outcomes,
+            vec![
+                OdrlDecisionOutcome::Permit,
+                OdrlDecisionOutcome::Permit,
+                OdrlDecisionOutcome::Deny
+            ]
+        );
+        // the denied request gave its slot back
+        assert_eq!(*permitted_requests.lock().unwrap(), 2);
+        let recorded = payloads
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|payload| payload["outcome"].clone())
+            .collect::<Vec<_>>();
+        assert_eq!(recorded, vec![json!("Permit"), json!("Permit"), json!("Deny")]);
+    }
+
+    #[tokio::test]
+    async fn test_denied_requests_are_not_counted() {
+        let permitted_requests = Arc::new(Mutex::new(0));
+        let payloads = Arc::new(Mutex::new(vec![]));
+        let pdp = pdp(counter(permitted_requests.clone()), events(payloads.clone()));
+        let agreement = agreement(json!({
+            "permission": [{ "action": "use" }],
+            "prohibition": [{ "action": "distribute" }]
+        }));
+
+        for _ in 0..3 {
+            let decision = pdp
+                .authorize_with_agreement(
+                    &session_id(),
+                    &agreement,
+                    "distribute",
+                    OdrlRequestContext::default(),
+                )
+                .await
+                .unwrap();
+            assert!(!decision.is_permit());
+        }
+        assert_eq!(*permitted_requests.lock().unwrap(), 0);
+        let payloads = payloads.lock().unwrap();
+        assert_eq!(payloads.len(), 3);
+        assert_eq!(
+            payloads[0]["reason"],
+            json!("Action 'distribute' is prohibited by prohibition #0")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_decision_is_recorded_as_a_pdp_event() {
+        let mut process_entity = MockDataPlaneProcessEntitiesTrait::new();
+        process_entity.expect_add_permitted_requests().times(1).returning(|_, _| Ok(1));
+        let mut event_entity = MockTransferEventEntitiesTrait::new();
+        event_entity
+            .expect_create_transfer_event()
+            .withf(|new_event| {
+                new_event.from == PDP_TRANSFER_EVENT_FROM
+                    && new_event.dataplane_process_id.to_string() == SESSION_ID
+                    && new_event.payload["action"] == json!("read")
+                    && new_event.payload["outcome"] == json!("Permit")
+            })
+            .times(1)
+            .returning(|_| Err(anyhow::anyhow!("stop after the event")));
+        let pdp = pdp(process_entity, event_entity);
+        let agreement = agreement(json!({ "permission": [{ "action": "use" }] }));
+
+        let result = pdp
+            .authorize_with_agreement(
+                &session_id(),
+                &agreement,
+                "read",
+                OdrlRequestContext::default(),
+            )
+            .await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_unknown_session_is_refused_before_evaluating() {
+        let mut process_entity = MockDataPlaneProcessEntitiesTrait::new();
+        process_entity.expect_add_permitted_requests().times(1).returning(|id, _| {
+            Err(
+                CommonErrors::missing_resource_new(&id.to_string(), "Dataplane process not found")
+                    .into(),
+            )
+        });
+        let mut event_entity = MockTransferEventEntitiesTrait::new();
+        event_entity.expect_create_transfer_event().never();
+        let pdp = pdp(process_entity, event_entity);
+        let agreement = agreement(json!({ "permission": [{ "action": "use" }] }));
+
+        let err = pdp
+            .authorize_with_agreement(
+                &session_id(),
+                &agreement,
+                "read",
+                OdrlRequestContext::default(),
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<CommonErrors>(),
+            Some(CommonErrors::MissingResourceError { .. })
+        ));
+    }
+
+    fn signature(signed: bool, verified: bool) -> Value {
+        json!({ "signer": null, "signed": signed, "verified": verified })
+    }
+
+    /// Transfer and negotiation agents of the session, serving the process, its agreement
+    /// and the signature verification report. Counts the signature checks.
+    async fn serve_session(provider: Value, consumer: Value) -> (u16, Arc<AtomicUsize>) {
+        let checks = Arc::new(AtomicUsize::new(0));
+        let agreement = serde_json::to_value(agreement(json!({
+            "permission": [{ "action": "use" }]
+        })))
+        .unwrap();
+        let report = json!({
+            "agreementId": "urn:agreement:1",
+            "provider": provider,
+            "consumer": consumer,
+            "verified": false
+        });
+        let counted = checks.clone();
+        let router = Router::new()
+            .route(
+                "/api/v1/transfer-agent/transfer-processes/{id}",
+                get(|| async { Json(json!({ "agreementId": "urn:agreement:1" })) }),
+            )
+            .route(
+                "/api/v1/negotiation-agent/agreements/{id}",
+                get(move || async move { Json(json!({ "agreementContent": agreement })) }),
+            )
+            .route(
+                "/api/v1/negotiation-agent/agreements/{id}/signatures",
+                get(move || async move {
+                    counted.fetch_add(1, Ordering::SeqCst);
+                    Json(report)
+                }),
+            );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        tokio::spawn(async move { axum::serve(listener, router).await.unwrap() });
+        (port, checks)
+    }
+
+    fn session_pdp(
+        port: u16,
+        policy: &str,
+        process_entity: MockDataPlaneProcessEntitiesTrait,
+        event_entity: MockTransferEventEntitiesTrait,
+    ) -> PdpFacadeService {
+        let config =
+            TransferConfig::load("../static/environment/config/core.provider.yaml".to_string());
+        let mut config = serde_json::to_value(config).unwrap();
+        for service in ["common", "contracts"] {
+            config[service]["hosts"]["http"]["port"] = json!(port.to_string());
+        }
+        config["agreement_signatures"] = json!(policy);
+        PdpFacadeService::new(
+            Arc::new(serde_json::from_value(config).unwrap()),
+            Arc::new(HttpClient::new(1, 1)),
+            Arc::new(OdrlEvaluatorService::new()),
+            Arc::new(process_entity),
+            Arc::new(event_entity),
+        )
+    }
+
+    fn is_forbidden(err: &anyhow::Error) -> bool {
+        matches!(
+            err.downcast_ref::<CommonErrors>(),
+            Some(CommonErrors::ForbiddenError { .. })
+        )
+    }
+
+    #[tokio::test]
+    async fn test_unsigned_agreement_is_refused_under_required_policy() {
+        let (port, _) = serve_session(signature(true, true), signature(false, false)).await;
+        let mut process_entity = MockDataPlaneProcessEntitiesTrait::new();
+        process_entity.expect_add_permitted_requests().never();
+        let mut event_entity = MockTransferEventEntitiesTrait::new();
+        event_entity.expect_create_transfer_event().never();
+        let pdp = session_pdp(port, "required", process_entity, event_entity);
+
+        let err = pdp
+            .authorize_session_request(&session_id(), "read", OdrlRequestContext::default())
+            .await
+            .unwrap_err();
+        assert!(is_forbidden(&err));
+    }
+
+    #[tokio::test]
+    async fn test_failing_signature_is_refused_under_optional_policy() {
+        let (port, _) = serve_session(signature(true, false), signature(false, false)).await;
+        let mut process_entity = MockDataPlaneProcessEntitiesTrait::new();
+        process_entity.expect_add_permitted_requests().never();
+        let mut event_entity = MockTransferEventEntitiesTrait::new();
+        event_entity.expect_create_transfer_event().never();
+        let pdp = session_pdp(port, "optional", process_entity, event_entity);
+
+        let err = pdp
+            .authorize_session_request(&session_id(), "read", OdrlRequestContext::default())
+            .await
+            .unwrap_err();
+        assert!(is_forbidden(&err));
+    }
+
+    #[tokio::test]
+    async fn test_accepted_signatures_are_checked_once_per_agreement() {
+        let (port, checks) = serve_session(signature(true, true), signature(false, false)).await;
+        let permitted_requests = Arc::new(Mutex::new(0));
+        let payloads = Arc::new(Mutex::new(vec![]));
+        let pdp = session_pdp(
+            port,
+            "optional",
+            counter(permitted_requests.clone()),
+            events(payloads.clone()),
+        );
+
+        for _ in 0..2 {
+            let decision = pdp
+                .authorize_session_request(&session_id(), "read", OdrlRequestContext::default())
+                .await
+                .unwrap();
+            assert!(decision.is_permit());
+        }
+        assert_eq!(checks.load(Ordering::SeqCst), 1);
+        assert_eq!(*permitted_requests.lock().unwrap(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_unreadable_signature_report_is_refused() {
+        let (port, _) = serve_session(json!("not a report"), signature(false, false)).await;
+        let mut process_entity = MockDataPlaneProcessEntitiesTrait::new();
+        process_entity.expect_add_permitted_requests().never();
+        let pdp = session_pdp(
+            port,
+            "optional",
+            process_entity,
+            MockTransferEventEntitiesTrait::new(),
+        );
+
+        let err = pdp
+            .authorize_session_request(&session_id(), "read", OdrlRequestContext::default())
+            .await
+            .unwrap_err();
+        assert!(is_forbidden(&err));
+    }
+}
@@ -0,0 +1,169 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+#[cfg(test)]
+mod test_transfer_event_entity {
+    use crate::data::entities::data_plane_process::NewDataPlaneProcessModel;
+    use crate::data::factory_sql::DataPlaneRepoForSql;
+    use crate::data::factory_trait::DataPlaneRepoTrait;
+    use crate::data::migrations::get_dataplane_migrations;
+    use crate::entities::transfer_events::transfer_event_entity::TransferEventEntityService;
+    use crate::entities::transfer_events::{
+        NewTransferEventDto, TransferEventEntitiesTrait, TransferExchangePayload,
+        EXCHANGE_TRANSFER_EVENT_FROM, EXCHANGE_TRANSFER_EVENT_TO,
+    };
+    use crate::facades::pdp_facade::pdp_facade::PDP_TRANSFER_EVENT_FROM;
+    use rainbow_common::utils::get_urn;
+    use sea_orm::{ConnectOptions, Database};
+    use sea_orm_migration::{MigrationTrait, MigratorTrait};
+    use serde_json::json;
+    use std::str::FromStr;
+    use std::sync::Arc;
+    use urn::Urn;
+
+    const PROCESS_ID: &str = "urn:data-plane-process:1";
+
+    struct Migrator;
+
+    impl MigratorTrait for Migrator {
+        fn migrations() -> Vec<Box<dyn MigrationTrait>> {
+            get_dataplane_migrations()
+        }
+    }
+
+    async fn setup() -> TransferEventEntityService {
+        // a single connection, every new in-memory connection is a new empty database
+        let mut options = ConnectOptions::new("sqlite::memory:");
+        options.max_connections(1).sqlx_logging(false);
+        let db = Database::connect(options).await.unwrap();
+        Migrator::up(&db, None).await.unwrap();
+        let repo = Arc::new(DataPlaneRepoForSql::create_repo(db));
+        repo.get_data_plane_process_repo()
+            .create_data_plane_processes(&NewDataPlaneProcessModel {
+                id: Urn::from_str(PROCESS_ID).unwrap(),
+                direction: "PULL".to_string(),
+                state: "STARTED".to_string(),
+            })
+            .await
+            .unwrap();
+        TransferEventEntityService::new(repo)
+    }
+
+    fn exchange(bytes_in: u64, bytes_out: u64) -> NewTransferEventDto {
+        let payload = TransferExchangePayload {
+            direction: "PULL".to_string(),
+            method: "GET".to_string(),
+            status_code: Some(200),
+            bytes_in,
+            bytes_out,
+            completed: true,
+            ..Default::default()
+        };
+        NewTransferEventDto {
+            id: get_urn(None),
+            dataplane_process_id: Urn::from_str(PROCESS_ID).unwrap(),
+            from: EXCHANGE_TRANSFER_EVENT_FROM.to_string(),
+            to: EXCHANGE_TRANSFER_EVENT_TO.to_string(),
+            payload: serde_json::to_value(payload).unwrap(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_aggregates_of_a_process_without_exchanges_are_empty() {
+        let entity = setup().await;
+        let aggregates = entity
+            .get_transfer_event_aggregates_by_process_id(&Urn::from_str(PROCESS_ID).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(aggregates.dataplane_process_id, PROCESS_ID);
+        assert_eq!(aggregates.request_count, 0);
+        assert_eq!(aggregates.total_bytes, 0);
+        assert!(aggregates.last_access.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_exchanges_add_up_in_the_aggregates() {
+        let entity = setup().await;
+        entity.create_transfer_event(&exchange(10, 100)).await.unwrap();
+        entity.create_transfer_event(&exchange(5, 50)).await.unwrap();
+
+        let aggregates = entity
+            .get_transfer_event_aggregates_by_process_id(&Urn::from_str(PROCESS_ID).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(aggregates.request_count, 2);
+        assert_eq!(aggregates.total_bytes_in, 15);
+        assert_eq!(aggregates.total_bytes_out, 150);
+        assert_eq!(aggregates.total_bytes, 165);
+        assert!(aggregates.last_access.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_other_events_are_not_exchanges() {
+        let entity = setup().await;
+        entity
+            .create_transfer_event(&NewTransferEventDto {
+                from: PDP_TRANSFER_EVENT_FROM.to_string(),
+                payload: json!({ "bytesIn": 10, "bytesOut": 10 }),
+                ..exchange(0, 0)
+            })
+            .await
+            .unwrap();
+
+        let aggregates = entity
+            .get_transfer_event_aggregates_by_process_id(&Urn::from_str(PROCESS_ID).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(aggregates.request_count, 0);
+        assert_eq!(aggregates.total_bytes, 0);
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_exchanges_are_all_counted() {
+        let entity = Arc::new(setup().await);
+        let handles = (0..10)
+            .map(|_| {
+                let entity = entity.clone();
+                tokio::spawn(async move { entity.create_transfer_event(&exchange(1, 2)).await })
+            })
+            .collect::<Vec<_>>();
+        for handle in handles {
+            handle.await.unwrap().unwrap();
+        }
+
+        let aggregates = entity
+            .get_transfer_event_aggregates_by_process_id(&Urn::from_str(PROCESS_ID).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(aggregates.request_count, 10);
+        assert_eq!(aggregates.total_bytes_in, 10);
+        assert_eq!(aggregates.total_bytes_out, 20);
+    }
+
+    #[tokio::test]
+    async fn test_aggregates_of_an_unknown_process_fail() {
+        let entity = setup().await;
+        let result = entity
+            .get_transfer_event_aggregates_by_process_id(
+                &Urn::from_str("urn:data-plane-process:2").unwrap(),
+            )
+            .await;
+        assert!(result.is_err());
+    }
+}
@@ -18,14 +18,17 @@
  */
 
 use crate::core::notification::notification_types::{
-    RainbowEventsNotificationBroadcastRequest, RainbowEventsNotificationCreationRequest,
-    RainbowEventsNotificationResponse,
+    RainbowEventsDeliveryMetricsResponse, RainbowEventsNotificationBroadcastRequest,
+    RainbowEventsNotificationCreationRequest, RainbowEventsNotificationResponse,
 };
 use async_trait::async_trait;
 use urn::Urn;
 
 pub mod notification;
+pub mod notification_delivery;
 pub mod notification_err;
+pub mod notification_publisher;
+pub mod notification_signature;
 pub mod notification_types;
 
 #[mockall::automock]
@@ -46,6 +49,10 @@ pub trait RainbowEventsNotificationTrait: Send + Sync {
         &self,
         subscription_id: Urn,
     ) -> anyhow::Result<Vec<RainbowEventsNotificationResponse>>;
+    async fn get_dead_letter_notifications_by_subscription_id(
+        &self,
+        subscription_id: Urn,
+    ) -> anyhow::Result<Vec<RainbowEventsNotificationResponse>>;
 
     async fn get_notification_by_id(
         &self,
@@ -62,4 +69,21 @@ pub trait RainbowEventsNotificationTrait: Send + Sync {
         &self,
         input: RainbowEventsNotificationBroadcastRequest,
     ) -> anyhow::Result<()>;
+
+    /// Puts the notification back in the queue with a fresh retry budget and attempts it
+    async fn redeliver_notification(
+        &self,
+        subscription_id: Urn,
+        notification_id: Urn,
+    ) -> anyhow::Result<RainbowEventsNotificationResponse>;
+    /// Attempts the pending notifications whose retry is due, returns how many got delivered
+    async fn deliver_due_notifications(&self) -> anyhow::Result<usize>;
+
+    async fn get_delivery_metrics(
+        &self,
+    ) -> anyhow::Result<Vec<RainbowEventsDeliveryMetricsResponse>>;
+    async fn get_delivery_metrics_by_subscription_id(
+        &self,
+        subscription_id: Urn,
+    ) -> anyhow::Result<RainbowEventsDeliveryMetricsResponse>;
 }
@@ -18,23 +18,40 @@
  */
 
 use crate::core::notification::notification_err::NotificationErrors;
+use crate::core::notification::notification_signature::{
+    RainbowEventsSignature, EVENTS_ID_HEADER, EVENTS_SIGNATURE_HEADER, EVENTS_TIMESTAMP_HEADER,
+};
 use crate::core::notification::notification_types::{
-    RainbowEventsNotificationBroadcastRequest, RainbowEventsNotificationCreationRequest,
-    RainbowEventsNotificationResponse,
+    RainbowEventsDeliveryMetricsResponse, RainbowEventsNotificationBroadcastRequest,
+    RainbowEventsNotificationCreationRequest, RainbowEventsNotificationMessageCategory,
+    RainbowEventsNotificationResponse, RainbowEventsNotificationStatus,
 };
 use crate::core::notification::RainbowEventsNotificationTrait;
 use crate::core::subscription::subscription_err::SubscriptionErrors;
-use crate::data::repo::{EventsRepoFactory, NewNotification};
+use crate::core::subscription::subscription_filter::RainbowEventsSubscriptionFilter;
+use crate::data::entities::{notification, subscription};
+use crate::data::repo::{EditNotificationDelivery, EventsRepoFactory, NewNotification};
 use async_trait::async_trait;
-use rainbow_common::utils::{get_urn, get_urn_from_string};
+use rainbow_common::http_client::RetryPolicy;
+use rainbow_common::utils::get_urn_from_string;
+use reqwest::header::CONTENT_TYPE;
 use reqwest::Client;
 use std::sync::Arc;
 use std::time::Duration;
+use tracing::{error, warn};
 use urn::Urn;
 
+const DELIVERY_TIMEOUT: Duration = Duration::from_secs(10);
+/// Time a notification is kept off the queue while an attempt is in flight,
+/// it has to outlast the delivery timeout
+const DELIVERY_LEASE: Duration = Duration::from_secs(30);
+/// Due notifications taken on each round of the delivery worker
+const DELIVERY_BATCH: u64 = 100;
+
 pub struct RainbowEventsNotificationsService<T> {
     repo: Arc<T>,
     client: Client,
+    retry_policy: RetryPolicy,
 }
 impl<T> RainbowEventsNotificationsService<T>
 where
@@ -42,10 +59,162 @@ where
 {
     pub fn new(repo: Arc<T>) -> Self {
         let client = Client::builder()
-            .timeout(Duration::from_secs(10))
+            .timeout(DELIVERY_TIMEOUT)
             .build()
             .expect("Failed to build reqwest client");
-        Self { repo, client }
+        // 9 attempts spread over roughly four hours before dead-lettering
+        let retry_policy = RetryPolicy {
+            max_retries: 8,
+            base_delay: Duration::from_secs(30),
+            max_delay: Duration::from_secs(60 * 60),
+        };
+        Self { repo, client, retry_policy }
+    }
+
+    /// Whether the subscription is active and not expired
+    fn is_live(subscription: &subscription::Model, now: chrono::NaiveDateTime) -> bool {
+        subscription.active
+            && subscription.expiration_time.is_none_or(|expiration| expiration > now)
+    }
+
+    /// Whether the subscription is live, asked for notifications of this category
+    /// and its filter lets the notification through
+    fn is_subscribed(
+        subscription: &subscription::Model,
+        notification: &RainbowEventsNotificationBroadcastRequest,
+        now: chrono::NaiveDateTime,
+    ) -> bool {
+        Self::is_live(subscription, now)
+            && match notification.category {
+                RainbowEventsNotificationMessageCategory::TransferProcess => {
+                    subscription.transfer_process
+                }
+                RainbowEventsNotificationMessageCategory::Catalog => subscription.catalog,
+                RainbowEventsNotificationMessageCategory::ContractNegotiation => {
+                    subscription.contract_negotiation_process
+                }
+                RainbowEventsNotificationMessageCategory::DataPlane => subscription.data_plane,
+            }
+            && Self::matches_filter(subscription, notification)
+    }
+
+    fn matches_filter(
+        subscription: &subscription::Model,
+        notification: &RainbowEventsNotificationBroadcastRequest,
+    ) -> bool {
+        let Some(filter) = &subscription.filter else {
+            return true;
+        };
+        match serde_json::from_value::<RainbowEventsSubscriptionFilter>(filter.clone()) {
+            Ok(filter) => filter.matches(notification),
+            Err(e) => {
+                error!("Subscription {} has an unreadable filter: {}", subscription.id, e);
+                false
+            }
+        }
+    }
+
+    fn lease_until(now: chrono::NaiveDateTime) -> chrono::NaiveDateTime {
+        now + chrono::Duration::from_std(DELIVERY_LEASE).unwrap_or_default()
+    }
+
+    /// One attempt to POST the notification to its subscriber, the outcome is persisted.
+    /// The caller must hold the lease of the notification.
+    async fn deliver(
+        &self,
+        notification: notification::Model,
+    ) -> anyhow::Result<notification::Model> {
+        let notification_id = get_urn_from_string(&notification.id)?;
+        let subscription_id = get_urn_from_string(&notification.subscription_id)?;
+        let subscription = self
+            .repo
+            .get_subscription_by_id(subscription_id.clone())
+            .await
+            .map_err(|e| NotificationErrors::DbErr(e.into()))?
+            .ok_or(SubscriptionErrors::NotFound {
+                id: subscription_id,
+                entity: "Subscription".to_string(),
+            })?;
+
+        let now = chrono::Utc::now().naive_utc();
+        if !Self::is_live(&subscription, now) {
+            let notification = self
+                .repo
+                .put_notification_delivery(
+                    notification_id,
+                    EditNotificationDelivery {
+                        status: RainbowEventsNotificationStatus::DeadLetter.to_string(),
+                        attempts: notification.attempts,
+                        next_attempt_at: None,
+                        delivered_at: None,
+                        last_error: Some("Subscription is inactive or expired".to_string()),
+                    },
+                )
+                .await
+                .map_err(|e| NotificationErrors::DbErr(e.into()))?;
+            return Ok(notification);
+        }
+
+        let message = RainbowEventsNotificationResponse::try_from(notification.clone())?;
+        let body = serde_json::to_vec(&message)?;
+        let timestamp = now.and_utc().timestamp();
+        let mut request = self
+            .client
+            .post(&subscription.callback_address)
+            .header(CONTENT_TYPE, "application/json")
+            .header(EVENTS_ID_HEADER, &notification.id)
+            .header(EVENTS_TIMESTAMP_HEADER, timestamp.to_string());
+        if let Some(secret) = &subscription.secret {
+            request = request.header(
+                EVENTS_SIGNATURE_HEADER,
+                RainbowEventsSignature::sign(secret, timestamp, &body),
+            );
+        }
+        let outcome = match request.body(body).send().await {
+            Ok(res) if res.status().is_success() => Ok(()),
+            Ok(res) => Err(format!("Callback answered with status {}", res.status())),
+            Err(e) => Err(format!("Callback could not be reached: {}", e)),
+        };
+
+        let attempts = notification.attempts + 1;
+        let edit_delivery = match outcome {
+            Ok(()) => EditNotificationDelivery {
+                status: RainbowEventsNotificationStatus::Ok.to_string(),
+                attempts,
+                next_attempt_at: None,
+                delivered_at: Some(now),
+                last_error: None,
+            },
+            Err(e) if attempts as u32 > self.retry_policy.max_retries => {
+                warn!(
+                    "Notification {} dead-lettered after {} attempts: {}",
+                    notification.id, attempts, e
+                );
+                EditNotificationDelivery {
+                    status: RainbowEventsNotificationStatus::DeadLetter.to_string(),
+                    attempts,
+                    next_attempt_at: None,
+                    delivered_at: None,
+                    last_error: Some(e),
+                }
+            }
+            Err(e) => {
+                let backoff = self.retry_policy.backoff(attempts as u32 - 1);
+                EditNotificationDelivery {
+                    status: RainbowEventsNotificationStatus::Pending.to_string(),
+                    attempts,
+                    next_attempt_at: Some(now + chrono::Duration::from_std(backoff)?),
+                    delivered_at: None,
+                    last_error: Some(e),
+                }
+            }
+        };
+        let notification = self
+            .repo
+            .put_notification_delivery(notification_id, edit_delivery)
+            .await
+            .map_err(|e| NotificationErrors::DbErr(e.into()))?;
+        Ok(notification)
     }
 }
 
@@ -151,6 +320,7 @@ where
                     message_operation: input.message_operation.to_string(),
                     message_content: input.message_content,
                     status: input.status.to_string(),
+                    next_attempt_at: None,
                 },
             )
             .await
@@ -160,6 +330,22 @@ where
         Ok(notifications)
     }
 
+    async fn get_dead_letter_notifications_by_subscription_id(
+        &self,
+        subscription_id: Urn,
+    ) -> anyhow::Result<Vec<RainbowEventsNotificationResponse>> {
+        let notifications = self
+            .repo
+            .get_dead_letter_notifications_by_subscription_id(subscription_id)
+            .await
+            .map_err(|e| NotificationErrors::DbErr(e.into()))?;
+        let notifications = notifications
+            .iter()
+            .map(|sub| RainbowEventsNotificationResponse::try_from(sub.to_owned()).unwrap())
+            .collect();
+        Ok(notifications)
+    }
+
     async fn broadcast_notification(
         &self,
         input: RainbowEventsNotificationBroadcastRequest,
@@ -169,55 +355,367 @@ where
             .get_all_subscriptions()
             .await
             .map_err(|e| NotificationErrors::DbErr(e.into()))?;
-        for subscription in subscriptions {
-            let callback = subscription.callback_address;
-            let message = RainbowEventsNotificationResponse {
-                id: get_urn(None),
-                timestamp: subscription.created_at,
-                category: input.category.to_string(),
-                subcategory: input.subcategory.to_string(),
-                message_type: input.message_type.to_string(),
-                message_operation: input.message_operation.to_string(),
-                message_content: input.message_content.clone(),
-                subscription_id: get_urn_from_string(&subscription.id)?,
-            };
-            let res = self.client.post(callback).json(&message).send().await;
-            match res {
-                Ok(res) => {
-                    self.repo
-                        .create_notification(
-                            get_urn_from_string(&subscription.id)?,
-                            NewNotification {
-                                category: message.category.to_string(),
-                                subcategory: message.subcategory,
-                                message_type: message.message_type.to_string(),
-                                message_operation: message.message_operation,
-                                message_content: message.message_content,
-                                status: match res.status().is_success() {
-                                    true => "Ok".to_string(),
-                                    false => "Pending".to_string(),
-                                },
-                            },
-                        )
-                        .await?;
-                }
-                Err(_) => {
-                    self.repo
-                        .create_notification(
-                            get_urn_from_string(&subscription.id)?,
-                            NewNotification {
-                                category: message.category.to_string(),
-                                subcategory: message.subcategory,
-                                message_type: message.message_type.to_string(),
-                                message_operation: message.message_operation,
-                                message_content: message.message_content,
-                                status: "Pending".to_string(),
-                            },
-                        )
-                        .await?;
-                }
+        let now = chrono::Utc::now().naive_utc();
+        for subscription in subscriptions
+            .into_iter()
+            .filter(|subscription| Self::is_subscribed(subscription, &input, now))
+        {
+            // stored already leased, the delivery worker picks it up only if this attempt
+            // never records its outcome
+            let notification = self
+                .repo
+                .create_notification(
+                    get_urn_from_string(&subscription.id)?,
+                    NewNotification {
+                        category: input.category.to_string(),
+                        subcategory: input.subcategory.to_string(),
+                        message_type: input.message_type.to_string(),
+                        message_operation: input.message_operation.to_string(),
+                        message_content: input.message_content.clone(),
+                        status: RainbowEventsNotificationStatus::Pending.to_string(),
+                        next_attempt_at: Some(Self::lease_until(now)),
+                    },
+                )
+                .await
+                .map_err(|e| NotificationErrors::DbErr(e.into()))?;
+            if let Err(e) = self.deliver(notification).await {
+                error!("Notification for subscription {} failed: {}", subscription.id, e);
             }
         }
         Ok(())
     }
+
+    async fn redeliver_notification(
+        &self,
+        subscription_id: Urn,
+        notification_id: Urn,
+    ) -> anyhow::Result<RainbowEventsNotificationResponse> {
+        let notification = self
+            .repo
+            .get_notification_by_id(subscription_id.clone(), notification_id.clone())
+            .await
+            .map_err(|e| NotificationErrors::DbErr(e.into()))?
+            .filter(|notification| notification.subscription_id == subscription_id.to_string())
+            .ok_or(NotificationErrors::NotFound {
+                id: notification_id.clone(),
+                entity: "Notification".to_string(),
+            })?;
+
+        let now = chrono::Utc::now().naive_utc();
+        let notification = self
+            .repo
+            .put_notification_delivery(
+                notification_id,
+                EditNotificationDelivery {
+                    status: RainbowEventsNotificationStatus::Pending.to_string(),
+                    attempts: 0,
+                    next_attempt_at: Some(Self::lease_until(now)),
+                    delivered_at: None,
+                    last_error: notification.last_error,
+                },
+            )
+            .await
+            .map_err(|e| NotificationErrors::DbErr(e.into()))?;
+        let notification = self.deliver(notification).await?;
+        let notification = RainbowEventsNotificationResponse::try_from(notification)?;
+        Ok(notification)
+    }
+
+    async fn deliver_due_notifications(&self) -> anyhow::Result<usize> {
+        let now = chrono::Utc::now().naive_utc();
+        let notifications = self
+            .repo
+            .get_due_notifications(now, DELIVERY_BATCH)
+            .await
+            .map_err(|e| NotificationErrors::DbErr(e.into()))?;
+
+        let mut delivered = 0;
+        for notification in notifications {
+            let notification_id = get_urn_from_string(&notification.id)?;
+            // another worker sharing the database may have taken it
+            let claimed = self
+                .repo
+                .claim_notification(notification_id, now, Self::lease_until(now))
+                .await
+                .map_err(|e| NotificationErrors::DbErr(e.into()))?;
+            if !claimed {
+                continue;
+            }
+            let notification_id = notification.id.clone();
+            match self.deliver(notification).await {
+                Ok(notification)
+                    if notification.status == RainbowEventsNotificationStatus::Ok.to_string() =>
+                {
+                    delivered += 1
+                }
+                Ok(_) => {}
+                Err(e) => error!("Notification {} could not be retried: {}", notification_id, e),
+            }
+        }
+        Ok(delivered)
+    }
+
+    async fn get_delivery_metrics(
+        &self,
+    ) -> anyhow::Result<Vec<RainbowEventsDeliveryMetricsResponse>> {
+        let subscriptions = self
+            .repo
+            .get_all_subscriptions()
+            .await
+            .map_err(|e| NotificationErrors::DbErr(e.into()))?;
+        let notifications = self
+            .repo
+            .get_all_notifications()
+            .await
+            .map_err(|e| NotificationErrors::DbErr(e.into()))?;
+        let mut metrics = vec![];
+        for subscription in subscriptions {
+            let subscription_notifications: Vec<notification::Model> = notifications
+                .iter()
+                .filter(|notification| notification.subscription_id == subscription.id)
+                .cloned()
+                .collect();
+            metrics.push(RainbowEventsDeliveryMetricsResponse::from_notifications(
+                get_urn_from_string(&subscription.id)?,
+                &subscription_notifications,
+            ));
+        }
+        Ok(metrics)
+    }
+
+    async fn get_delivery_metrics_by_subscription_id(
+        &self,
+        subscription_id: Urn,
+    ) -> anyhow::Result<RainbowEventsDeliveryMetricsResponse> {
+        let notifications = self
+            .repo
+            .get_notifications_by_subscription_id(subscription_id.clone())
+            .await
+            .map_err(|e| NotificationErrors::DbErr(e.into()))?;
+        Ok(RainbowEventsDeliveryMetricsResponse::from_notifications(
+            subscription_id,
+            &notifications,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test_notification_delivery {
+    use super::*;
+    use crate::data::migrations::Migrator;
+    use crate::data::repo::sql::EventsRepoForSql;
+    use crate::data::repo::{NewSubscription, NotificationRepo, SubscriptionRepo};
+    use axum::body::Bytes;
+    use axum::http::HeaderMap;
+    use axum::routing::post;
+    use axum::Router;
+    use sea_orm::{ConnectOptions, Database};
+    use sea_orm_migration::MigratorTrait;
+    use serde_json::json;
+    use std::sync::Mutex;
+
+    const SECRET: &str = "subscription-secret";
+
+    type Received = Arc<Mutex<Vec<(HeaderMap, Bytes)>>>;
+
+    async fn service() -> (
+        RainbowEventsNotificationsService<EventsRepoForSql>,
+        Arc<EventsRepoForSql>,
+    ) {
+        // a single connection, every new in-memory connection is a new empty database
+        let mut options = ConnectOptions::new("sqlite::memory:");
+        options.max_connections(1).sqlx_logging(false);
+        let db = Database::connect(options).await.unwrap();
+        Migrator::up(&db, None).await.unwrap();
+        let repo = Arc::new(EventsRepoForSql::new(db));
+        (RainbowEventsNotificationsService::new(repo.clone()), repo)
+    }
+
+    /// Callback that records every delivery and answers 200
+    async fn callback(received: Received) -> String {
+        let app = Router::new().route(
+            "/callback",
+            post(move |headers: HeaderMap, body: Bytes| {
+                let received = received.clone();
+                async move { received.lock().unwrap().push((headers, body)) }
+            }),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+        format!("http://{}/callback", address)
+    }
+
+    /// Address nothing listens on, connections are refused right away
+    fn unreachable_callback() -> String {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        format!("http://{}/callback", listener.local_addr().unwrap())
+    }
+
+    async fn subscription(
+        repo: &EventsRepoForSql,
+        callback_address: String,
+        expiration_time: Option<chrono::NaiveDateTime>,
+    ) -> Urn {
+        let subscription = repo
+            .create_subscription(NewSubscription {
+                callback_address,
+                transfer_process: true,
+                contract_negotiation_process: true,
+                catalog: true,
+                data_plane: true,
+                active: true,
+                expiration_time,
+                secret: Some(SECRET.to_string()),
+                filter: None,
+            })
+            .await
+            .unwrap();
+        get_urn_from_string(&subscription.id).unwrap()
+    }
+
+    /// Due notification that already failed `attempts` times
+    async fn due_notification(
+        repo: &EventsRepoForSql,
+        subscription_id: &Urn,
+        attempts: i32,
+    ) -> Urn {
+        let notification = repo
+            .create_notification(
+                subscription_id.clone(),
+                NewNotification {
+                    category: "TransferProcess".to_string(),
+                    subcategory: "TransferStartMessage".to_string(),
+                    message_type: "DSProtocolMessage".to_string(),
+                    message_operation: "IncomingMessage".to_string(),
+                    message_content: json!({ "state": "STARTED" }),
+                    status: RainbowEventsNotificationStatus::Pending.to_string(),
+                    next_attempt_at: None,
+                },
+            )
+            .await
+            .unwrap();
+        let notification_id = get_urn_from_string(&notification.id).unwrap();
+        repo.put_notification_delivery(
+            notification_id.clone(),
+            EditNotificationDelivery {
+                status: RainbowEventsNotificationStatus::Pending.to_string(),
+                attempts,
+                next_attempt_at: None,
+                delivered_at: None,
+                last_error: None,
+            },
+        )
+        .await
+        .unwrap();
+        notification_id
+    }
+
+    async fn stored(
+        repo: &EventsRepoForSql,
+        subscription_id: &Urn,
+        notification_id: &Urn,
+    ) -> notification::Model {
+        repo.get_notification_by_id(subscription_id.clone(), notification_id.clone())
+            .await
+            .unwrap()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_delivery_is_signed_and_marked_ok() {
+        let (service, repo) = service().await;
+        let received = Received::default();
+        let subscription_id = subscription(&repo, callback(received.clone()).await, None).await;
+        let notification_id = due_notification(&repo, &subscription_id, 0).await;
+
+        assert_eq!(service.deliver_due_notifications().await.unwrap(), 1);
+
+        let notification = stored(&repo, &subscription_id, &notification_id).await;
+        assert_eq!(notification.status, RainbowEventsNotificationStatus::Ok.to_string());
+        assert_eq!(notification.attempts, 1);
+        assert!(notification.delivered_at.is_some());
+        assert_eq!(notification.next_attempt_at, None);
+
+        let received = received.lock().unwrap();
+        let (headers, body) = &received[0];
+        assert_eq!(headers[EVENTS_ID_HEADER], notification_id.to_string().as_str());
+        let timestamp: i64 = headers[EVENTS_TIMESTAMP_HEADER].to_str().unwrap().parse().unwrap();
+        let signature = headers[EVENTS_SIGNATURE_HEADER].to_str().unwrap();
+        assert!(RainbowEventsSignature::verify(
+            SECRET, timestamp, body, signature, timestamp
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_failed_delivery_is_retried_with_backoff() {
+        let (service, repo) = service().await;
+        let subscription_id = subscription(&repo, unreachable_callback(), None).await;
+        let notification_id = due_notification(&repo, &subscription_id, 0).await;
+        let before = chrono::Utc::now().naive_utc();
+
+        assert_eq!(service.deliver_due_notifications().await.unwrap(), 0);
+
+        let notification = stored(&repo, &subscription_id, &notification_id).await;
+        assert_eq!(
+            notification.status,
+            RainbowEventsNotificationStatus::Pending.to_string()
+        );
+        assert_eq!(notification.attempts, 1);
+        assert!(notification.last_error.unwrap().starts_with("Callback could not be reached"));
+        // first retry lands between half and the full base delay of 30 seconds
+        let wait = notification.next_attempt_at.unwrap() - before;
+        assert!(wait >= chrono::Duration::seconds(14), "retried after {}", wait);
+        assert!(wait <= chrono::Duration::seconds(31), "retried after {}", wait);
+        // not due again until then
+        assert_eq!(service.deliver_due_notifications().await.unwrap(), 0);
+        let notification = stored(&repo, &subscription_id, &notification_id).await;
+        assert_eq!(notification.attempts, 1);
+    }
+
+    #[tokio::test]
+    async fn test_last_failed_retry_is_dead_lettered() {
+        let (service, repo) = service().await;
+        let subscription_id = subscription(&repo, unreachable_callback(), None).await;
+        let retries = service.retry_policy.max_retries as i32;
+        let notification_id = due_notification(&repo, &subscription_id, retries).await;
+
+        service.deliver_due_notifications().await.unwrap();
+
+        let notification = stored(&repo, &subscription_id, &notification_id).await;
+        assert_eq!(
+            notification.status,
+            RainbowEventsNotificationStatus::DeadLetter.to_string()
+        );
+        assert_eq!(notification.attempts, retries + 1);
+        assert_eq!(notification.next_attempt_at, None);
+        assert!(notification.last_error.is_some());
+        let dead_letters =
+            repo.get_dead_letter_notifications_by_subscription_id(subscription_id).await.unwrap();
+        assert_eq!(dead_letters.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_expired_subscription_is_dead_lettered_without_an_attempt() {
+        let (service, repo) = service().await;
+        let received = Received::default();
+        let expired = chrono::Utc::now().naive_utc() - chrono::Duration::minutes(1);
+        let subscription_id =
+            subscription(&repo, callback(received.clone()).await, Some(expired)).await;
+        let notification_id = due_notification(&repo, &subscription_id, 2).await;
+
+        service.deliver_due_notifications().await.unwrap();
+
+        let notification = stored(&repo, &subscription_id, &notification_id).await;
+        assert_eq!(
+            notification.status,
+            RainbowEventsNotificationStatus::DeadLetter.to_string()
+        );
+        assert_eq!(notification.attempts, 2);
+        assert_eq!(
+            notification.last_error.as_deref(),
+            Some("Subscription is inactive or expired")
+        );
+        assert!(received.lock().unwrap().is_empty());
+    }
 }
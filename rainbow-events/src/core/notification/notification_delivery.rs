@@ -0,0 +1,65 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use crate::core::notification::notification::RainbowEventsNotificationsService;
+use crate::core::notification::RainbowEventsNotificationTrait;
+use crate::data::repo::sql::EventsRepoForSql;
+use crate::data::repo::EventsRepoFactory;
+use sea_orm::DatabaseConnection;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tokio_util::sync::CancellationToken;
+
+/// How often the worker looks for notifications whose retry is due
+const DELIVERY_TICK: Duration = Duration::from_secs(5);
+
+/// Retries failed webhook deliveries in the background until they succeed or get
+/// dead-lettered. Several workers may share a database, notifications are leased one by one.
+pub struct RainbowEventsDeliveryWorker {}
+
+impl RainbowEventsDeliveryWorker {
+    pub fn spawn(db_connection: DatabaseConnection, token: &CancellationToken) -> JoinHandle<()> {
+        let events_repo = Arc::new(EventsRepoForSql::create_repo(db_connection));
+        let notification_service: Arc<dyn RainbowEventsNotificationTrait> =
+            Arc::new(RainbowEventsNotificationsService::new(events_repo));
+        tracing::info!("Events delivery worker running every {:?}", DELIVERY_TICK);
+
+        let token = token.clone();
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(DELIVERY_TICK);
+            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            loop {
+                tokio::select! {
+                    _ = token.cancelled() => {
+                        tracing::info!("Events delivery worker received shutdown signal");
+                        break;
+                    }
+                    _ = interval.tick() => {
+                        match notification_service.deliver_due_notifications().await {
+                            Ok(0) => {}
+                            Ok(delivered) => tracing::debug!("{} pending notifications delivered", delivered),
+                            Err(e) => tracing::error!("Events delivery round failed: {}", e),
+                        }
+                    }
+                }
+            }
+        })
+    }
+}
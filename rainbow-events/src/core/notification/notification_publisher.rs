@@ -0,0 +1,56 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use crate::core::notification::notification_types::RainbowEventsNotificationEvent;
+use crate::core::notification::RainbowEventsNotificationTrait;
+use std::sync::Arc;
+use tracing::error;
+
+/// Entry point for producers. Publishing never blocks nor fails the operation that
+/// produced the event, delivery runs in the background and errors are only logged.
+#[mockall::automock]
+pub trait RainbowEventsPublisherTrait: Send + Sync {
+    fn publish(&self, event: RainbowEventsNotificationEvent);
+}
+
+pub struct RainbowEventsPublisher {
+    notification_service: Arc<dyn RainbowEventsNotificationTrait>,
+}
+
+impl RainbowEventsPublisher {
+    pub fn new(notification_service: Arc<dyn RainbowEventsNotificationTrait>) -> Self {
+        Self { notification_service }
+    }
+}
+
+impl RainbowEventsPublisherTrait for RainbowEventsPublisher {
+    fn publish(&self, event: RainbowEventsNotificationEvent) {
+        let notification_service = self.notification_service.clone();
+        tokio::spawn(async move {
+            let category = event.category.to_string();
+            let subcategory = event.subcategory.clone();
+            if let Err(e) = notification_service.broadcast_notification(event.into()).await {
+                error!(
+                    "Notification {}/{} could not be broadcast: {}",
+                    category, subcategory, e
+                );
+            }
+        });
+    }
+}
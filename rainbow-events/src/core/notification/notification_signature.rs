@@ -0,0 +1,171 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use hmac::{Hmac, Mac};
+use rand::Rng;
+use sha2::Sha256;
+
+/// Id of the notification, stable across retries so receivers can drop duplicates
+pub const EVENTS_ID_HEADER: &str = "X-Rainbow-Events-Id";
+/// Unix seconds at which the attempt was sent, covered by the signature
+pub const EVENTS_TIMESTAMP_HEADER: &str = "X-Rainbow-Events-Timestamp";
+/// `v1=<hex HMAC-SHA256 of "{timestamp}.{body}">` keyed with the subscription secret
+pub const EVENTS_SIGNATURE_HEADER: &str = "X-Rainbow-Events-Signature";
+/// Max drift between the signed timestamp and the receiver clock before a delivery is
+/// taken as a replay
+pub const EVENTS_SIGNATURE_TOLERANCE_SECS: i64 = 300;
+
+const SIGNATURE_VERSION: &str = "v1";
+
+type HmacSha256 = Hmac<Sha256>;
+
+pub struct RainbowEventsSignature;
+
+impl RainbowEventsSignature {
+    /// Random 256 bit secret, hex encoded
+    pub fn generate_secret() -> String {
+        let mut secret = [0u8; 32];
+        rand::rng().fill(&mut secret);
+        hex::encode(secret)
+    }
+
+    pub fn sign(secret: &str, timestamp: i64, body: &[u8]) -> String {
+        let mac = Self::mac(secret, timestamp, body);
+        format!("{}={}", SIGNATURE_VERSION, hex::encode(mac.finalize().into_bytes()))
+    }
+
+    /// Check a received delivery, meant for receivers written in Rust.
+    /// Fails on a bad signature and on a timestamp out of the tolerance window.
+    pub fn verify(secret: &str, timestamp: i64, body: &[u8], signature: &str, now: i64) -> bool {
+        if (now - timestamp).abs() > EVENTS_SIGNATURE_TOLERANCE_SECS {
+            return false;
+        }
+        let Some(signature) = signature
+            .strip_prefix(SIGNATURE_VERSION)
+            .and_then(|s| s.strip_prefix('='))
+            .and_then(|s| hex::decode(s).ok())
+        else {
+            return false;
+        };
+        // constant time comparison
+        Self::mac(secret, timestamp, body).verify_slice(&signature).is_ok()
+    }
+
+    fn mac(secret: &str, timestamp: i64, body: &[u8]) -> HmacSha256 {
+        let mut mac =
+            HmacSha256::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any size");
+        mac.update(timestamp.to_string().as_bytes());
+        mac.update(b".");
+        mac.update(body);
+        mac
+    }
+}
+
+#[cfg(test)]
+mod test_notification_signature {
+    use super::*;
+
+    const SECRET: &str = "6f1c0e6a2b5d4c3e";
+    const BODY: &[u8] = br#"{"notificationId":"urn:uuid:1"}"#;
+    const TIMESTAMP: i64 = 1_700_000_000;
+
+    #[test]
+    fn test_signature_round_trips() {
+        let signature = RainbowEventsSignature::sign(SECRET, TIMESTAMP, BODY);
+        assert!(RainbowEventsSignature::verify(
+            SECRET, TIMESTAMP, BODY, &signature, TIMESTAMP
+        ));
+    }
+
+    #[test]
+    fn test_signature_is_hmac_sha256_of_timestamp_and_body() {
+        // what a receiver in any language computes over "{timestamp}.{body}"
+        assert_eq!(
+            RainbowEventsSignature::sign(SECRET, TIMESTAMP, BODY),
+            "v1=4784c2952136e3489d9c6f2edad8f19a96676274c10ce6e222b39603ab06479d"
+        );
+    }
+
+    #[test]
+    fn test_tampered_deliveries_are_rejected() {
+        let signature = RainbowEventsSignature::sign(SECRET, TIMESTAMP, BODY);
+        let tampered_body = br#"{"notificationId":"urn:uuid:2"}"#;
+        assert!(!RainbowEventsSignature::verify(
+            SECRET,
+            TIMESTAMP,
+            tampered_body,
+            &signature,
+            TIMESTAMP
+        ));
+        assert!(!RainbowEventsSignature::verify(
+            "another-secret",
+            TIMESTAMP,
+            BODY,
+            &signature,
+            TIMESTAMP
+        ));
+        // the timestamp is signed, moving it breaks the signature
+        assert!(!RainbowEventsSignature::verify(
+            SECRET,
+            TIMESTAMP + 1,
+            BODY,
+            &signature,
+            TIMESTAMP
+        ));
+    }
+
+    #[test]
+    fn test_timestamps_out_of_tolerance_are_replays() {
+        let signature = RainbowEventsSignature::sign(SECRET, TIMESTAMP, BODY);
+        let edge = TIMESTAMP + EVENTS_SIGNATURE_TOLERANCE_SECS;
+        assert!(RainbowEventsSignature::verify(
+            SECRET, TIMESTAMP, BODY, &signature, edge
+        ));
+        assert!(!RainbowEventsSignature::verify(
+            SECRET,
+            TIMESTAMP,
+            BODY,
+            &signature,
+            edge + 1
+        ));
+        // a clock running behind the sender is bounded the same way
+        let behind = TIMESTAMP - EVENTS_SIGNATURE_TOLERANCE_SECS - 1;
+        assert!(!RainbowEventsSignature::verify(
+            SECRET, TIMESTAMP, BODY, &signature, behind
+        ));
+    }
+
+    #[test]
+    fn test_malformed_signatures_are_rejected() {
+        let signature = RainbowEventsSignature::sign(SECRET, TIMESTAMP, BODY);
+        let digest = signature.strip_prefix("v1=").unwrap();
+        for malformed in [digest.to_string(), format!("v2={}", digest), "v1=not-hex".to_string()] {
+            assert!(!RainbowEventsSignature::verify(
+                SECRET, TIMESTAMP, BODY, &malformed, TIMESTAMP
+            ));
+        }
+    }
+
+    #[test]
+    fn test_generated_secrets_are_random_256_bit_hex() {
+        let secret = RainbowEventsSignature::generate_secret();
+        assert_eq!(hex::decode(&secret).unwrap().len(), 32);
+        assert_ne!(secret, RainbowEventsSignature::generate_secret());
+    }
+}
@@ -20,7 +20,9 @@
 use crate::data::entities::notification;
 use rainbow_common::utils::get_urn_from_string;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fmt::{Display, Formatter};
+use tracing::error;
 use urn::Urn;
 
 #[derive(Serialize, Deserialize)]
@@ -38,6 +40,11 @@ pub struct RainbowEventsNotificationResponse {
     pub message_content: serde_json::Value,
     #[serde(rename = "subscriptionId")]
     pub subscription_id: Urn,
+    pub status: String,
+    pub attempts: i32,
+    #[serde(rename = "lastError")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_error: Option<String>,
 }
 
 impl TryFrom<notification::Model> for RainbowEventsNotificationResponse {
@@ -53,11 +60,14 @@ impl TryFrom<notification::Model> for RainbowEventsNotificationResponse {
             message_content: value.message_content,
             message_operation: value.message_operation,
             subscription_id: get_urn_from_string(&value.subscription_id)?,
+            status: value.status,
+            attempts: value.attempts,
+            last_error: value.last_error,
         })
     }
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum RainbowEventsNotificationMessageTypes {
     RPCMessage,
     DSProtocolMessage,
@@ -78,7 +88,7 @@ impl Display for RainbowEventsNotificationMessageTypes {
     }
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum RainbowEventsNotificationMessageCategory {
     TransferProcess,
     Catalog,
@@ -101,7 +111,7 @@ impl Display for RainbowEventsNotificationMessageCategory {
     }
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum RainbowEventsNotificationMessageOperation {
     Creation,
     Update,
@@ -130,6 +140,7 @@ impl Display for RainbowEventsNotificationMessageOperation {
 pub enum RainbowEventsNotificationStatus {
     Pending,
     Ok,
+    DeadLetter,
 }
 
 impl Display for RainbowEventsNotificationStatus {
@@ -137,6 +148,7 @@ impl Display for RainbowEventsNotificationStatus {
         match self {
             RainbowEventsNotificationStatus::Pending => Ok(f.write_str("Pending")?),
             RainbowEventsNotificationStatus::Ok => Ok(f.write_str("Ok")?),
+            RainbowEventsNotificationStatus::DeadLetter => Ok(f.write_str("DeadLetter")?),
         }
     }
 }
@@ -159,3 +171,106 @@ pub struct RainbowEventsNotificationBroadcastRequest {
     pub message_content: serde_json::Value,
     pub message_operation: RainbowEventsNotificationMessageOperation,
 }
+
+/// Delivery figures of a subscription, aggregated from its notifications
+#[derive(Serialize, Deserialize, Debug)]
+pub struct RainbowEventsDeliveryMetricsResponse {
+    #[serde(rename = "subscriptionId")]
+    pub subscription_id: Urn,
+    pub total: u64,
+    pub delivered: u64,
+    pub pending: u64,
+    #[serde(rename = "deadLetter")]
+    pub dead_letter: u64,
+    pub attempts: u64,
+    #[serde(rename = "lastDeliveredAt")]
+    pub last_delivered_at: Option<chrono::NaiveDateTime>,
+    #[serde(rename = "lastError")]
+    pub last_error: Option<String>,
+}
+
+impl RainbowEventsDeliveryMetricsResponse {
+    pub fn from_notifications(subscription_id: Urn, notifications: &[notification::Model]) -> Self {
+        let count = |status: RainbowEventsNotificationStatus| {
+            notifications.iter().filter(|n| n.status == status.to_string()).count() as u64
+        };
+        Self {
+            subscription_id,
+            total: notifications.len() as u64,
+            delivered: count(RainbowEventsNotificationStatus::Ok),
+            pending: count(RainbowEventsNotificationStatus::Pending),
+            dead_letter: count(RainbowEventsNotificationStatus::DeadLetter),
+            attempts: notifications.iter().map(|n| n.attempts.max(0) as u64).sum(),
+            last_delivered_at: notifications.iter().filter_map(|n| n.delivered_at).max(),
+            last_error: notifications
+                .iter()
+                .filter(|n| n.last_error.is_some())
+                .max_by_key(|n| n.timestamp)
+                .and_then(|n| n.last_error.clone()),
+        }
+    }
+}
+
+/// Content of every notification published by the agents, the ids of the entities involved
+/// and a snapshot of the entity right after the change
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RainbowEventsNotificationContent {
+    #[serde(rename = "entityIds")]
+    pub entity_ids: BTreeMap<String, String>,
+    pub snapshot: serde_json::Value,
+}
+
+/// Typed event emitted by a producer, turned into a broadcast request when published
+#[derive(Debug, Clone)]
+pub struct RainbowEventsNotificationEvent {
+    pub category: RainbowEventsNotificationMessageCategory,
+    pub subcategory: String,
+    pub message_type: RainbowEventsNotificationMessageTypes,
+    pub message_operation: RainbowEventsNotificationMessageOperation,
+    pub content: RainbowEventsNotificationContent,
+}
+
+impl RainbowEventsNotificationEvent {
+    pub fn new(
+        category: RainbowEventsNotificationMessageCategory,
+        subcategory: impl Into<String>,
+        message_type: RainbowEventsNotificationMessageTypes,
+        message_operation: RainbowEventsNotificationMessageOperation,
+    ) -> Self {
+        Self {
+            category,
+            subcategory: subcategory.into(),
+            message_type,
+            message_operation,
+            content: RainbowEventsNotificationContent {
+                entity_ids: BTreeMap::new(),
+                snapshot: serde_json::Value::Null,
+            },
+        }
+    }
+
+    pub fn with_entity_id(mut self, name: impl Into<String>, id: impl ToString) -> Self {
+        self.content.entity_ids.insert(name.into(), id.to_string());
+        self
+    }
+
+    pub fn with_snapshot<S: Serialize>(mut self, snapshot: &S) -> Self {
+        self.content.snapshot = serde_json::to_value(snapshot).unwrap_or_else(|e| {
+            error!("Notification snapshot could not be serialized: {}", e);
+            serde_json::Value::Null
+        });
+        self
+    }
+}
+
+impl From<RainbowEventsNotificationEvent> for RainbowEventsNotificationBroadcastRequest {
+    fn from(value: RainbowEventsNotificationEvent) -> Self {
+        Self {
+            category: value.category,
+            subcategory: value.subcategory,
+            message_type: value.message_type,
+            message_content: serde_json::to_value(value.content).unwrap_or_default(),
+            message_operation: value.message_operation,
+        }
+    }
+}
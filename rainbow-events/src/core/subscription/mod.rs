@@ -26,6 +26,7 @@ use urn::Urn;
 
 pub mod subscription;
 pub mod subscription_err;
+pub mod subscription_filter;
 pub mod subscription_types;
 
 #[mockall::automock]
@@ -53,4 +54,9 @@ pub trait RainbowEventsSubscriptionTrait: Send + Sync {
         subscription_type: SubscriptionEntities,
     ) -> anyhow::Result<RainbowEventsSubscriptionCreationResponse>;
     async fn delete_subscription_by_id(&self, subscription_id: Urn) -> anyhow::Result<()>;
+    /// Replaces the signing secret, the new one is returned once
+    async fn rotate_subscription_secret(
+        &self,
+        subscription_id: Urn,
+    ) -> anyhow::Result<RainbowEventsSubscriptionCreationResponse>;
 }
@@ -17,7 +17,9 @@
  *
  */
 
+use crate::core::notification::notification_signature::RainbowEventsSignature;
 use crate::core::subscription::subscription_err::SubscriptionErrors;
+use crate::core::subscription::subscription_filter::RainbowEventsSubscriptionFilter;
 use crate::core::subscription::subscription_types::{
     RainbowEventsSubscriptionCreationRequest, RainbowEventsSubscriptionCreationResponse,
     SubscriptionEntities,
@@ -38,6 +40,32 @@ impl<T> RainbowEventsSubscriptionService<T> {
     pub fn new(repo: Arc<T>) -> Self {
         Self { repo }
     }
+
+    fn validate_expiration_time(
+        expiration_time: &Option<chrono::NaiveDateTime>,
+    ) -> anyhow::Result<()> {
+        if let Some(expiration_time) = expiration_time {
+            if *expiration_time <= chrono::Utc::now().naive_utc() {
+                bail!(SubscriptionErrors::ExpirationTimeInThePast(
+                    expiration_time.to_string()
+                ))
+            }
+        }
+        Ok(())
+    }
+
+    /// Validated filter in its stored form
+    fn filter_to_value(
+        filter: &Option<RainbowEventsSubscriptionFilter>,
+    ) -> anyhow::Result<Option<serde_json::Value>> {
+        match filter {
+            Some(filter) => {
+                filter.validate().map_err(|e| SubscriptionErrors::InvalidFilter(e.to_string()))?;
+                Ok(Some(serde_json::to_value(filter)?))
+            }
+            None => Ok(None),
+        }
+    }
 }
 
 #[async_trait]
@@ -99,6 +127,8 @@ where
         subscription_id: Urn,
         input: RainbowEventsSubscriptionCreationRequest,
     ) -> anyhow::Result<RainbowEventsSubscriptionCreationResponse> {
+        Self::validate_expiration_time(&input.expiration_time)?;
+        let filter = Self::filter_to_value(&input.filter)?;
         let subscription = self
             .repo
             .put_subscription_by_id(
@@ -106,6 +136,7 @@ where
                 EditSubscription {
                     callback_address: Option::from(input.callback_address),
                     expiration_time: input.expiration_time,
+                    filter,
                     ..Default::default()
                 },
             )
@@ -120,6 +151,8 @@ where
         input: RainbowEventsSubscriptionCreationRequest,
         subscription_type: SubscriptionEntities,
     ) -> anyhow::Result<RainbowEventsSubscriptionCreationResponse> {
+        Self::validate_expiration_time(&input.expiration_time)?;
+        let filter = Self::filter_to_value(&input.filter)?;
         let subscription = self
             .repo
             .get_subscription_by_callback_string(input.callback_address.clone())
@@ -142,10 +175,14 @@ where
                 data_plane: subscription_type == SubscriptionEntities::DataPlaneProcess,
                 active: true,
                 expiration_time: input.expiration_time,
+                secret: Some(RainbowEventsSignature::generate_secret()),
+                filter,
             })
             .await
             .map_err(|e| SubscriptionErrors::DbErr(e.into()))?;
-        let subscription = RainbowEventsSubscriptionCreationResponse::try_from(subscription)?;
+        let secret = subscription.secret.clone();
+        let mut subscription = RainbowEventsSubscriptionCreationResponse::try_from(subscription)?;
+        subscription.secret = secret;
         Ok(subscription)
     }
 
@@ -157,4 +194,25 @@ where
             .map_err(|e| SubscriptionErrors::DbErr(e.into()))?;
         Ok(())
     }
+
+    async fn rotate_subscription_secret(
+        &self,
+        subscription_id: Urn,
+    ) -> anyhow::Result<RainbowEventsSubscriptionCreationResponse> {
+        let subscription = self
+            .repo
+            .put_subscription_by_id(
+                subscription_id,
+                EditSubscription {
+                    secret: Some(RainbowEventsSignature::generate_secret()),
+                    ..Default::default()
+                },
+            )
+            .await
+            .map_err(|e| SubscriptionErrors::DbErr(e.into()))?;
+        let secret = subscription.secret.clone();
+        let mut subscription = RainbowEventsSubscriptionCreationResponse::try_from(subscription)?;
+        subscription.secret = secret;
+        Ok(subscription)
+    }
 }
@@ -35,6 +35,10 @@ pub enum SubscriptionErrors {
     UrnUuidSchema(String),
     #[error("There is a subscription for this callback address. {0}")]
     SubscriptionCallbackAddressExists(String),
+    #[error("Expiration time is already past. {0}")]
+    ExpirationTimeInThePast(String),
+    #[error("Subscription filter is not valid. {0}")]
+    InvalidFilter(String),
 }
 
 #[derive(Serialize, Deserialize)]
@@ -0,0 +1,444 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use crate::core::notification::notification_types::{
+    RainbowEventsNotificationBroadcastRequest, RainbowEventsNotificationMessageOperation,
+};
+use anyhow::bail;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+/// Snapshot fields holding the peer of the entity, besides the `*ParticipantId` entity ids
+const PARTICIPANT_SNAPSHOT_FIELDS: [&str; 2] = ["associatedAgentPeer", "dspaceParticipantId"];
+
+/// Narrows the notifications a subscription receives. Every clause set must hold,
+/// a list clause holds when any of its entries does, and an empty filter lets everything through.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct RainbowEventsSubscriptionFilter {
+    /// Subcategory of the notification, the catalog entity (`Dataset`, `Distribution`...)
+    /// or the message that moved the process (`TransferTerminationMessage`, `Agreement`...)
+    #[serde(rename = "entityTypes", default, skip_serializing_if = "Vec::is_empty")]
+    pub entity_types: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub operations: Vec<RainbowEventsNotificationMessageOperation>,
+    /// State the process is in after the change, e.g. `TERMINATED`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub states: Vec<String>,
+    /// Peer participant ids, looked up in the `*ParticipantId` entity ids and in the snapshot
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub participants: Vec<String>,
+    /// Entity id name to pattern, `*` matches any run of characters
+    #[serde(rename = "entityIds", default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub entity_ids: BTreeMap<String, String>,
+    /// Predicates on the notification content, `{ "entityIds": {...}, "snapshot": {...} }`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub payload: Vec<RainbowEventsPayloadPredicate>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct RainbowEventsPayloadPredicate {
+    /// JSONPath subset, `$` followed by `.field`, `['field']` or `[index]` steps
+    pub path: String,
+    pub operator: RainbowEventsPayloadOperator,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<Value>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum RainbowEventsPayloadOperator {
+    Eq,
+    Ne,
+    In,
+    Contains,
+    Exists,
+}
+
+#[derive(Debug, PartialEq)]
+enum PathStep {
+    Field(String),
+    Index(usize),
+}
+
+impl RainbowEventsSubscriptionFilter {
+    pub fn validate(&self) -> anyhow::Result<()> {
+        if self
+            .entity_types
+            .iter()
+            .chain(&self.states)
+            .chain(&self.participants)
+            .any(|v| v.is_empty())
+        {
+            bail!("Filter values cannot be empty strings")
+        }
+        for (name, pattern) in &self.entity_ids {
+            if name.is_empty() || pattern.is_empty() {
+                bail!("Entity id filters need a name and a pattern")
+            }
+        }
+        for predicate in &self.payload {
+            predicate.validate()?;
+        }
+        Ok(())
+    }
+
+    pub fn matches(&self, notification: &RainbowEventsNotificationBroadcastRequest) -> bool {
+        let content = &notification.message_content;
+        let entity_ids = content.get("entityIds");
+        let snapshot = content.get("snapshot");
+
+        if !self.entity_types.is_empty() && !self.entity_types.contains(&notification.subcategory) {
+            return false;
+        }
+        if !self.operations.is_empty() && !self.operations.contains(&notification.message_operation)
+        {
+            return false;
+        }
+        if !self.states.is_empty() {
+            let state = snapshot.and_then(|s| s.get("state")).and_then(Value::as_str);
+            if !state.is_some_and(|state| self.states.iter().any(|s| s == state)) {
+                return false;
+            }
+        }
+        if !self.participants.is_empty() {
+            let from_ids = entity_ids
+                .and_then(Value::as_object)
+                .into_iter()
+                .flatten()
+                .filter(|(name, _)| name.ends_with("ParticipantId"))
+                .filter_map(|(_, id)| id.as_str());
+            let from_snapshot = PARTICIPANT_SNAPSHOT_FIELDS
+                .iter()
+                .filter_map(|field| snapshot.and_then(|s| s.get(*field)).and_then(Value::as_str));
+            if !from_ids.chain(from_snapshot).any(|p| self.participants.iter().any(|f| f == p)) {
+                return false;
+            }
+        }
+        for (name, pattern) in &self.entity_ids {
+            let id = entity_ids.and_then(|ids| ids.get(name)).and_then(Value::as_str);
+            if !id.is_some_and(|id| glob_matches(pattern, id)) {
+                return false;
+            }
+        }
+        self.payload.iter().all(|predicate| predicate.matches(content))
+    }
+}
+
+impl RainbowEventsPayloadPredicate {
+    fn validate(&self) -> anyhow::Result<()> {
+        parse_path(&self.path)?;
+        match (&self.operator, &self.value) {
+            (RainbowEventsPayloadOperator::Exists, _) => Ok(()),
+            (RainbowEventsPayloadOperator::In, Some(Value::Array(_))) => Ok(()),
+            (RainbowEventsPayloadOperator::In, _) => {
+                bail!("Predicate on {} needs an array value for `in`", self.path)
+            }
+            (_, None) => bail!("Predicate on {} needs a value", self.path),
+            _ => Ok(()),
+        }
+    }
+
+    fn matches(&self, content: &Value) -> bool {
+        let Ok(steps) = parse_path(&self.path) else {
+            return false;
+        };
+        let selected = steps.iter().try_fold(content, |value, step| match step {
+            PathStep::Field(field) => value.get(field),
+            PathStep::Index(index) => value.get(index),
+        });
+        let expected = self.value.as_ref().unwrap_or(&Value::Null);
+        match (&self.operator, selected) {
+            (RainbowEventsPayloadOperator::Exists, selected) => selected.is_some(),
+            (RainbowEventsPayloadOperator::Ne, selected) => selected != Some(expected),
+            (_, None) => false,
+            (RainbowEventsPayloadOperator::Eq, Some(selected)) => selected == expected,
+            (RainbowEventsPayloadOperator::In, Some(selected)) => {
+                expected.as_array().is_some_and(|values| values.contains(selected))
+            }
+            (RainbowEventsPayloadOperator::Contains, Some(Value::Array(values))) => {
+                values.contains(expected)
+            }
+            (RainbowEventsPayloadOperator::Contains, Some(Value::String(text))) => {
+                expected.as_str().is_some_and(|needle| text.contains(needle))
+            }
+            (RainbowEventsPayloadOperator::Contains, Some(_)) => false,
+        }
+    }
+}
+
+fn parse_path(path: &str) -> anyhow::Result<Vec<PathStep>> {
+    let Some(mut rest) = path.strip_prefix('$') else { bail!("Path {} must start with $", path) };
+    let mut steps = vec![];
+    while !rest.is_empty() {
+        if let Some(after_dot) = rest.strip_prefix('.') {
+            let end = after_dot.find(['.', '[']).unwrap_or(after_dot.len());
+            if end == 0 {
+                bail!("Path {} has an empty field", path)
+            }
+            steps.push(PathStep::Field(after_dot[..end].to_string()));
+            rest = &after_dot[end..];
+        } else if let Some(after_bracket) = rest.strip_prefix('[') {
+            let Some(end) = after_bracket.find(']') else {
+                bail!("Path {} has an unclosed bracket", path)
+            };
+            let inner = &after_bracket[..end];
+            let quoted = inner
+                .strip_prefix('\'')
+                .and_then(|s| s.strip_suffix('\''))
+                .or_else(|| inner.strip_prefix('"').and_then(|s| s.strip_suffix('"')));
+            match (quoted, inner.parse::<usize>()) {
+                (Some(field), _) if !field.is_empty() => {
+                    steps.push(PathStep::Field(field.to_string()))
+                }
+                (None, Ok(index)) => steps.push(PathStep::Index(index)),
+                _ => bail!("Path {} has an invalid step [{}]", path, inner),
+            }
+            rest = &after_bracket[end + 1..];
+        } else {
+            bail!("Path {} has an invalid step at {}", path, rest)
+        }
+    }
+    Ok(steps)
+}
+
+/// Pattern match where `*` stands for any run of characters, including none
+fn glob_matches(pattern: &str, value: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let first = parts.next().unwrap_or_default();
+    let Some(mut rest) = value.strip_prefix(first) else {
+        return false;
+    };
+    let parts: Vec<&str> = parts.collect();
+    let Some((last, middle)) = parts.split_last() else {
+        // no wildcard at all
+        return rest.is_empty();
+    };
+    for part in middle {
+        match rest.find(part) {
+            Some(position) => rest = &rest[position + part.len()..],
+            None => return false,
+        }
+    }
+    rest.len() >= last.len() && rest.ends_with(last)
+}
+
+#[cfg(test)]
+mod test_subscription_filter {
+    use super::*;
+    use crate::core::notification::notification_types::{
+        RainbowEventsNotificationEvent, RainbowEventsNotificationMessageCategory,
+        RainbowEventsNotificationMessageTypes,
+    };
+    use serde_json::json;
+
+    fn transfer_termination() -> RainbowEventsNotificationBroadcastRequest {
+        RainbowEventsNotificationEvent::new(
+            RainbowEventsNotificationMessageCategory::TransferProcess,
+            "TransferTerminationMessage",
+            RainbowEventsNotificationMessageTypes::DSProtocolMessage,
+            RainbowEventsNotificationMessageOperation::IncomingMessage,
+        )
+        .with_entity_id("processId", "urn:uuid:process-1")
+        .with_entity_id("agreementId", "urn:uuid:agreement-42")
+        .with_snapshot(&json!({
+            "id": "urn:uuid:process-1",
+            "state": "TERMINATED",
+            "associatedAgentPeer": "did:web:peer.example",
+            "agreementId": "urn:uuid:agreement-42",
+            "properties": { "tags": ["gold", "eu"], "size": 10 },
+            "messages": [{ "messageType": "TransferRequestMessage" }]
+        }))
+        .into()
+    }
+
+    fn agreement_creation() -> RainbowEventsNotificationBroadcastRequest {
+        RainbowEventsNotificationEvent::new(
+            RainbowEventsNotificationMessageCategory::ContractNegotiation,
+            "Agreement",
+            RainbowEventsNotificationMessageTypes::RPCMessage,
+            RainbowEventsNotificationMessageOperation::Creation,
+        )
+        .with_entity_id("agreementId", "urn:uuid:agreement-42")
+        .with_entity_id("consumerParticipantId", "did:web:consumer.example")
+        .with_entity_id("providerParticipantId", "did:web:provider.example")
+        .with_snapshot(&json!({ "id": "urn:uuid:agreement-42" }))
+        .into()
+    }
+
+    fn filter(value: Value) -> RainbowEventsSubscriptionFilter {
+        let filter: RainbowEventsSubscriptionFilter = serde_json::from_value(value).unwrap();
+        filter.validate().unwrap();
+        filter
+    }
+
+    #[test]
+    fn test_empty_filter_matches_everything() {
+        let filter = filter(json!({}));
+        assert!(filter.matches(&transfer_termination()));
+        assert!(filter.matches(&agreement_creation()));
+    }
+
+    #[test]
+    fn test_entity_type_and_operation() {
+        let filter = filter(json!({
+            "entityTypes": ["Agreement"],
+            "operations": ["Creation"]
+        }));
+        assert!(filter.matches(&agreement_creation()));
+        assert!(!filter.matches(&transfer_termination()));
+    }
+
+    #[test]
+    fn test_state() {
+        assert!(filter(json!({ "states": ["TERMINATED"] })).matches(&transfer_termination()));
+        assert!(!filter(json!({ "states": ["STARTED"] })).matches(&transfer_termination()));
+        // entities without a state never match a state filter
+        assert!(!filter(json!({ "states": ["TERMINATED"] })).matches(&agreement_creation()));
+    }
+
+    #[test]
+    fn test_participant() {
+        let peer = filter(json!({ "participants": ["did:web:peer.example"] }));
+        assert!(peer.matches(&transfer_termination()));
+        assert!(!peer.matches(&agreement_creation()));
+
+        let consumer = filter(json!({ "participants": ["did:web:consumer.example"] }));
+        assert!(consumer.matches(&agreement_creation()));
+        assert!(!consumer.matches(&transfer_termination()));
+    }
+
+    #[test]
+    fn test_entity_id_patterns() {
+        let by_agreement =
+            filter(json!({ "entityIds": { "agreementId": "urn:uuid:agreement-42" } }));
+        assert!(by_agreement.matches(&transfer_termination()));
+
+        let by_prefix = filter(json!({ "entityIds": { "agreementId": "urn:uuid:agreement-*" } }));
+        assert!(by_prefix.matches(&transfer_termination()));
+
+        let other = filter(json!({ "entityIds": { "agreementId": "urn:uuid:agreement-7*" } }));
+        assert!(!other.matches(&transfer_termination()));
+
+        // the id must be there
+        let missing = filter(json!({ "entityIds": { "datasetId": "*" } }));
+        assert!(!missing.matches(&transfer_termination()));
+    }
+
+    #[test]
+    fn test_glob() {
+        assert!(glob_matches("*", ""));
+        assert!(glob_matches("a*c", "abc"));
+        assert!(glob_matches("a*c", "ac"));
+        assert!(glob_matches("*b*", "abc"));
+        assert!(glob_matches("a*b*c", "axxbyyc"));
+        assert!(!glob_matches("a*b*c", "axxcyyb"));
+        assert!(!glob_matches("abc", "abcd"));
+        assert!(!glob_matches("ab*ab", "ab"));
+    }
+
+    #[test]
+    fn test_payload_predicates() {
+        let notification = transfer_termination();
+        let matches =
+            |predicate: Value| filter(json!({ "payload": [predicate] })).matches(&notification);
+
+        assert!(matches(
+            json!({ "path": "$.snapshot.state", "operator": "eq", "value": "TERMINATED" })
+        ));
+        assert!(matches(
+            json!({ "path": "$['snapshot']['properties'].size", "operator": "eq", "value": 10 })
+        ));
+        assert!(matches(
+            json!({ "path": "$.snapshot.state", "operator": "ne", "value": "STARTED" })
+        ));
+        assert!(matches(
+            json!({ "path": "$.snapshot.state", "operator": "in", "value": ["TERMINATED", "COMPLETED"] })
+        ));
+        assert!(matches(
+            json!({ "path": "$.snapshot.properties.tags", "operator": "contains", "value": "eu" })
+        ));
+        assert!(matches(
+            json!({ "path": "$.snapshot.agreementId", "operator": "contains", "value": "agreement" })
+        ));
+        assert!(matches(
+            json!({ "path": "$.snapshot.messages[0].messageType", "operator": "eq", "value": "TransferRequestMessage" })
+        ));
+        assert!(matches(
+            json!({ "path": "$.entityIds.processId", "operator": "exists" })
+        ));
+        assert!(matches(
+            json!({ "path": "$.snapshot.missing", "operator": "ne", "value": 1 })
+        ));
+
+        assert!(!matches(
+            json!({ "path": "$.snapshot.missing", "operator": "exists" })
+        ));
+        assert!(!matches(
+            json!({ "path": "$.snapshot.messages[3]", "operator": "exists" })
+        ));
+        assert!(!matches(
+            json!({ "path": "$.snapshot.missing", "operator": "eq", "value": "TERMINATED" })
+        ));
+        assert!(!matches(
+            json!({ "path": "$.snapshot.properties.tags", "operator": "contains", "value": "us" })
+        ));
+    }
+
+    #[test]
+    fn test_clauses_are_combined() {
+        let filter = filter(json!({
+            "states": ["TERMINATED"],
+            "entityIds": { "agreementId": "urn:uuid:agreement-42" },
+            "payload": [{ "path": "$.snapshot.properties.size", "operator": "eq", "value": 11 }]
+        }));
+        assert!(!filter.matches(&transfer_termination()));
+    }
+
+    #[test]
+    fn test_validation() {
+        let invalid = [
+            json!({ "states": [""] }),
+            json!({ "entityIds": { "agreementId": "" } }),
+            json!({ "payload": [{ "path": "snapshot.state", "operator": "exists" }] }),
+            json!({ "payload": [{ "path": "$.snapshot..state", "operator": "exists" }] }),
+            json!({ "payload": [{ "path": "$.snapshot[state]", "operator": "exists" }] }),
+            json!({ "payload": [{ "path": "$.snapshot['state'", "operator": "exists" }] }),
+            json!({ "payload": [{ "path": "$.snapshot.state", "operator": "eq" }] }),
+            json!({ "payload": [{ "path": "$.snapshot.state", "operator": "in", "value": "TERMINATED" }] }),
+        ];
+        for filter in invalid {
+            let parsed: RainbowEventsSubscriptionFilter =
+                serde_json::from_value(filter.clone()).unwrap();
+            assert!(parsed.validate().is_err(), "{} should be rejected", filter);
+        }
+
+        // unknown clauses and operators are rejected while deserializing
+        assert!(serde_json::from_value::<RainbowEventsSubscriptionFilter>(
+            json!({ "state": ["A"] })
+        )
+        .is_err());
+        assert!(serde_json::from_value::<RainbowEventsSubscriptionFilter>(
+            json!({ "payload": [{ "path": "$.a", "operator": "gt", "value": 1 }] })
+        )
+        .is_err());
+    }
+}
@@ -17,6 +17,7 @@
  *
  */
 
+use crate::core::subscription::subscription_filter::RainbowEventsSubscriptionFilter;
 use crate::data::entities::subscription;
 use crate::data::repo::NewSubscription;
 use anyhow::bail;
@@ -40,6 +41,9 @@ pub struct RainbowEventsSubscriptionCreationRequest {
     #[serde(rename = "expirationTime")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub expiration_time: Option<chrono::NaiveDateTime>,
+    #[serde(rename = "filter")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter: Option<RainbowEventsSubscriptionFilter>,
 }
 
 #[derive(Serialize, Deserialize, Debug, PartialEq)]
@@ -62,6 +66,8 @@ impl Into<NewSubscription> for RainbowEventsSubscriptionCreationRequestForTransf
             data_plane: false,
             active: true,
             expiration_time: self.expiration_time,
+            secret: None,
+            filter: None,
         }
     }
 }
@@ -86,6 +92,8 @@ impl Into<NewSubscription> for RainbowEventsSubscriptionCreationRequestForCatalo
             data_plane: false,
             active: true,
             expiration_time: self.expiration_time,
+            secret: None,
+            filter: None,
         }
     }
 }
@@ -110,6 +118,8 @@ impl Into<NewSubscription> for RainbowEventsSubscriptionCreationRequestForContra
             data_plane: false,
             active: true,
             expiration_time: self.expiration_time,
+            secret: None,
+            filter: None,
         }
     }
 }
@@ -134,6 +144,8 @@ impl Into<NewSubscription> for RainbowEventsSubscriptionCreationRequestForDataPl
             data_plane: true,
             active: true,
             expiration_time: self.expiration_time,
+            secret: None,
+            filter: None,
         }
     }
 }
@@ -164,6 +176,13 @@ pub struct RainbowEventsSubscriptionCreationResponse {
     pub subscription_entity: SubscriptionEntities,
     #[serde(rename = "active")]
     pub active: bool,
+    #[serde(rename = "filter")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<RainbowEventsSubscriptionFilter>,
+    /// Key of the delivery signatures, only shown when the subscription is created or rotated
+    #[serde(rename = "secret")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secret: Option<String>,
 }
 
 impl TryFrom<subscription::Model> for RainbowEventsSubscriptionCreationResponse {
@@ -189,6 +208,8 @@ impl TryFrom<subscription::Model> for RainbowEventsSubscriptionCreationResponse
             expiration_time: value.expiration_time,
             subscription_entity: entity,
             active: value.active,
+            filter: value.filter.map(serde_json::from_value).transpose()?,
+            secret: None,
         })
     }
 }
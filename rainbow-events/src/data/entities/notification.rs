@@ -22,7 +22,7 @@ use sea_orm::entity::prelude::*;
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
 #[sea_orm(table_name = "notifications")]
 pub struct Model {
-    #[sea_orm(primary_key)]
+    #[sea_orm(primary_key, auto_increment = false)]
     pub id: String,
     pub timestamp: chrono::NaiveDateTime,
     pub category: String,
@@ -32,6 +32,10 @@ pub struct Model {
     pub message_content: serde_json::Value,
     pub status: String,
     pub subscription_id: String,
+    pub attempts: i32,
+    pub next_attempt_at: Option<chrono::NaiveDateTime>,
+    pub delivered_at: Option<chrono::NaiveDateTime>,
+    pub last_error: Option<String>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
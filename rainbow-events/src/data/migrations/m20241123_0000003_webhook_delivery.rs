@@ -0,0 +1,99 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use sea_orm_migration::prelude::*;
+
+pub struct Migration;
+impl MigrationName for Migration {
+    fn name(&self) -> &str {
+        "m20241123_0000003_webhook_delivery"
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // one column per statement, sqlite cannot add several columns in one alter
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Subscriptions::Table)
+                    .add_column(ColumnDef::new(Subscriptions::Secret).string())
+                    .to_owned(),
+            )
+            .await?;
+        for column in Self::notification_columns() {
+            manager
+                .alter_table(
+                    Table::alter().table(Notifications::Table).add_column(column).to_owned(),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for column in [
+            Notifications::Attempts,
+            Notifications::NextAttemptAt,
+            Notifications::DeliveredAt,
+            Notifications::LastError,
+        ] {
+            manager
+                .alter_table(
+                    Table::alter().table(Notifications::Table).drop_column(column).to_owned(),
+                )
+                .await?;
+        }
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Subscriptions::Table)
+                    .drop_column(Subscriptions::Secret)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+impl Migration {
+    fn notification_columns() -> Vec<ColumnDef> {
+        vec![
+            ColumnDef::new(Notifications::Attempts).integer().not_null().default(0).to_owned(),
+            ColumnDef::new(Notifications::NextAttemptAt).date_time().to_owned(),
+            ColumnDef::new(Notifications::DeliveredAt).date_time().to_owned(),
+            ColumnDef::new(Notifications::LastError).string().to_owned(),
+        ]
+    }
+}
+
+#[derive(Iden)]
+pub enum Subscriptions {
+    Table,
+    Secret,
+}
+
+#[derive(Iden)]
+pub enum Notifications {
+    Table,
+    Attempts,
+    NextAttemptAt,
+    DeliveredAt,
+    LastError,
+}
@@ -21,11 +21,15 @@ use sea_orm_migration::prelude::*;
 
 pub mod m20241123_0000001_subscriptions;
 pub mod m20241123_0000002_notifications;
+pub mod m20241123_0000003_webhook_delivery;
+pub mod m20241123_0000004_subscription_filters;
 
 pub fn get_events_migrations() -> Vec<Box<dyn MigrationTrait>> {
     vec![
         Box::new(m20241123_0000001_subscriptions::Migration),
         Box::new(m20241123_0000002_notifications::Migration),
+        Box::new(m20241123_0000003_webhook_delivery::Migration),
+        Box::new(m20241123_0000004_subscription_filters::Migration),
     ]
 }
 
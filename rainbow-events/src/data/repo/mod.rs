@@ -40,6 +40,8 @@ pub struct NewSubscription {
     pub data_plane: bool,
     pub active: bool,
     pub expiration_time: Option<chrono::NaiveDateTime>,
+    pub secret: Option<String>,
+    pub filter: Option<serde_json::Value>,
 }
 
 pub struct EditSubscription {
@@ -50,6 +52,8 @@ pub struct EditSubscription {
     pub data_plane: Option<bool>,
     pub active: Option<bool>,
     pub expiration_time: Option<chrono::NaiveDateTime>,
+    pub secret: Option<String>,
+    pub filter: Option<serde_json::Value>,
 }
 
 impl Default for EditSubscription {
@@ -62,6 +66,8 @@ impl Default for EditSubscription {
             data_plane: None,
             active: None,
             expiration_time: None,
+            secret: None,
+            filter: None,
         }
     }
 }
@@ -101,6 +107,17 @@ pub struct NewNotification {
     pub message_operation: String,
     pub message_content: serde_json::Value,
     pub status: String,
+    /// None makes it due right away, a future time keeps other workers off while it is delivered
+    pub next_attempt_at: Option<chrono::NaiveDateTime>,
+}
+
+/// Outcome of a delivery attempt, every field is written
+pub struct EditNotificationDelivery {
+    pub status: String,
+    pub attempts: i32,
+    pub next_attempt_at: Option<chrono::NaiveDateTime>,
+    pub delivered_at: Option<chrono::NaiveDateTime>,
+    pub last_error: Option<String>,
 }
 
 #[async_trait]
@@ -120,6 +137,29 @@ pub trait NotificationRepo {
         &self,
         subscription_id: Urn,
     ) -> anyhow::Result<Vec<notification::Model>, EventRepoErrors>;
+    async fn get_dead_letter_notifications_by_subscription_id(
+        &self,
+        subscription_id: Urn,
+    ) -> anyhow::Result<Vec<notification::Model>, EventRepoErrors>;
+    /// Pending notifications whose next attempt is due, oldest first
+    async fn get_due_notifications(
+        &self,
+        now: chrono::NaiveDateTime,
+        limit: u64,
+    ) -> anyhow::Result<Vec<notification::Model>, EventRepoErrors>;
+    /// Moves the next attempt of a due notification to `lease_until`.
+    /// Returns false when another worker claimed it first.
+    async fn claim_notification(
+        &self,
+        notification_id: Urn,
+        now: chrono::NaiveDateTime,
+        lease_until: chrono::NaiveDateTime,
+    ) -> anyhow::Result<bool, EventRepoErrors>;
+    async fn put_notification_delivery(
+        &self,
+        notification_id: Urn,
+        edit_delivery: EditNotificationDelivery,
+    ) -> anyhow::Result<notification::Model, EventRepoErrors>;
 
     async fn get_notification_by_id(
         &self,
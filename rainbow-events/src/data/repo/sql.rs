@@ -22,14 +22,15 @@ use crate::data::entities::subscription;
 
 use crate::data::entities::notification::Model;
 use crate::data::repo::{
-    EditSubscription, EventRepoErrors, EventsRepoFactory, NewNotification, NewSubscription,
-    NotificationRepo, SubscriptionRepo,
+    EditNotificationDelivery, EditSubscription, EventRepoErrors, EventsRepoFactory,
+    NewNotification, NewSubscription, NotificationRepo, SubscriptionRepo,
 };
 use async_trait::async_trait;
 use rainbow_common::utils::get_urn;
 use sea_orm::prelude::Expr;
 use sea_orm::{
-    ActiveModelTrait, ActiveValue, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter,
+    ActiveModelTrait, ActiveValue, ColumnTrait, Condition, DatabaseConnection, EntityTrait,
+    QueryFilter, QueryOrder, QuerySelect,
 };
 use urn::Urn;
 
@@ -41,6 +42,13 @@ impl EventsRepoForSql {
     pub fn new(db_connection: DatabaseConnection) -> Self {
         Self { db_connection }
     }
+
+    /// Pending notifications from before delivery tracking have no next attempt and are due
+    fn is_due(now: chrono::NaiveDateTime) -> Condition {
+        Condition::any()
+            .add(notification::Column::NextAttemptAt.is_null())
+            .add(notification::Column::NextAttemptAt.lte(now))
+    }
 }
 
 impl EventsRepoFactory for EventsRepoForSql {
@@ -129,6 +137,12 @@ impl SubscriptionRepo for EventsRepoForSql {
         if let Some(expiration_time) = edit_subscription.expiration_time {
             old_active_model.expiration_time = ActiveValue::Set(Option::from(expiration_time));
         }
+        if let Some(secret) = edit_subscription.secret {
+            old_active_model.secret = ActiveValue::Set(Option::from(secret));
+        }
+        if let Some(filter) = edit_subscription.filter {
+            old_active_model.filter = ActiveValue::Set(Option::from(filter));
+        }
         old_active_model.updated_at =
             ActiveValue::Set(Option::from(chrono::Utc::now().naive_utc()));
         let model = old_active_model.update(&self.db_connection).await;
@@ -155,6 +169,8 @@ impl SubscriptionRepo for EventsRepoForSql {
             created_at: ActiveValue::Set(chrono::Utc::now().naive_utc()),
             updated_at: ActiveValue::Set(None),
             expiration_time: ActiveValue::Set(new_subscription.expiration_time),
+            secret: ActiveValue::Set(new_subscription.secret),
+            filter: ActiveValue::Set(new_subscription.filter),
         };
         let subscription =
             subscription::Entity::insert(model).exec_with_returning(&self.db_connection).await;
@@ -268,6 +284,98 @@ impl NotificationRepo for EventsRepoForSql {
         }
     }
 
+    async fn get_dead_letter_notifications_by_subscription_id(
+        &self,
+        subscription_id: Urn,
+    ) -> anyhow::Result<Vec<notification::Model>, EventRepoErrors> {
+        let subscription = self.get_subscription_by_id(subscription_id.clone()).await;
+        let _subscription = match subscription {
+            Ok(subscription) => match subscription {
+                Some(subscription) => subscription,
+                None => return Err(EventRepoErrors::SubscriptionNotFound),
+            },
+            Err(e) => return Err(EventRepoErrors::ErrorFetchingSubscription(e.into())),
+        };
+
+        let subscription_id = subscription_id.to_string();
+        let notifications = notification::Entity::find()
+            .filter(notification::Column::SubscriptionId.eq(subscription_id))
+            .filter(notification::Column::Status.eq("DeadLetter"))
+            .all(&self.db_connection)
+            .await;
+        match notifications {
+            Ok(notifications) => Ok(notifications),
+            Err(e) => Err(EventRepoErrors::ErrorFetchingNotification(e.into())),
+        }
+    }
+
+    async fn get_due_notifications(
+        &self,
+        now: chrono::NaiveDateTime,
+        limit: u64,
+    ) -> anyhow::Result<Vec<notification::Model>, EventRepoErrors> {
+        let notifications = notification::Entity::find()
+            .filter(notification::Column::Status.eq("Pending"))
+            .filter(Self::is_due(now))
+            .order_by_asc(notification::Column::Timestamp)
+            .limit(limit)
+            .all(&self.db_connection)
+            .await;
+        match notifications {
+            Ok(notifications) => Ok(notifications),
+            Err(e) => Err(EventRepoErrors::ErrorFetchingNotification(e.into())),
+        }
+    }
+
+    async fn claim_notification(
+        &self,
+        notification_id: Urn,
+        now: chrono::NaiveDateTime,
+        lease_until: chrono::NaiveDateTime,
+    ) -> anyhow::Result<bool, EventRepoErrors> {
+        let notification_id = notification_id.to_string();
+        let claimed = notification::Entity::update_many()
+            .col_expr(notification::Column::NextAttemptAt, Expr::value(lease_until))
+            .filter(notification::Column::Id.eq(notification_id))
+            .filter(notification::Column::Status.eq("Pending"))
+            .filter(Self::is_due(now))
+            .exec(&self.db_connection)
+            .await;
+        match claimed {
+            Ok(update_result) => Ok(update_result.rows_affected == 1),
+            Err(e) => Err(EventRepoErrors::ErrorUpdatingNotification(e.into())),
+        }
+    }
+
+    async fn put_notification_delivery(
+        &self,
+        notification_id: Urn,
+        edit_delivery: EditNotificationDelivery,
+    ) -> anyhow::Result<notification::Model, EventRepoErrors> {
+        let notification_id = notification_id.to_string();
+        let old_model =
+            notification::Entity::find_by_id(notification_id).one(&self.db_connection).await;
+        let old_model = match old_model {
+            Ok(old_model) => match old_model {
+                Some(old_model) => old_model,
+                None => return Err(EventRepoErrors::NotificationNotFound),
+            },
+            Err(e) => return Err(EventRepoErrors::ErrorFetchingNotification(e.into())),
+        };
+
+        let mut old_active_model: notification::ActiveModel = old_model.into();
+        old_active_model.status = ActiveValue::Set(edit_delivery.status);
+        old_active_model.attempts = ActiveValue::Set(edit_delivery.attempts);
+        old_active_model.next_attempt_at = ActiveValue::Set(edit_delivery.next_attempt_at);
+        old_active_model.delivered_at = ActiveValue::Set(edit_delivery.delivered_at);
+        old_active_model.last_error = ActiveValue::Set(edit_delivery.last_error);
+        let model = old_active_model.update(&self.db_connection).await;
+        match model {
+            Ok(model) => Ok(model),
+            Err(e) => Err(EventRepoErrors::ErrorUpdatingNotification(e.into())),
+        }
+    }
+
     async fn get_notification_by_id(
         &self,
         subscription_id: Urn,
@@ -314,6 +422,10 @@ impl NotificationRepo for EventsRepoForSql {
             message_content: ActiveValue::Set(new_notification.message_content),
             status: ActiveValue::Set(new_notification.status),
             subscription_id: ActiveValue::Set(subscription_id),
+            attempts: ActiveValue::Set(0),
+            next_attempt_at: ActiveValue::Set(new_notification.next_attempt_at),
+            delivered_at: ActiveValue::Set(None),
+            last_error: ActiveValue::Set(None),
         };
         let notification =
             notification::Entity::insert(model).exec_with_returning(&self.db_connection).await;
@@ -48,21 +48,34 @@ where
         Router::new()
             .route("/notifications", get(Self::handle_get_all_notifications))
             .route(
-                "/subscriptions/:sid/notifications",
+                "/subscriptions/{sid}/notifications",
                 get(Self::handle_get_notifications_by_subscription),
             )
             .route(
-                "/subscriptions/:sid/notifications-pending",
+                "/subscriptions/{sid}/notifications-pending",
                 get(Self::handle_get_pending),
             )
             .route(
-                "/subscriptions/:sid/ack-notifications-pending",
+                "/subscriptions/{sid}/ack-notifications-pending",
                 post(Self::handle_ack_pending),
             )
             .route(
-                "/subscriptions/:sid/notifications/:nid",
+                "/subscriptions/{sid}/notifications/{nid}",
                 get(Self::handle_get_notification_by_id),
             )
+            .route(
+                "/subscriptions/{sid}/notifications-dead-letter",
+                get(Self::handle_get_dead_letter),
+            )
+            .route(
+                "/subscriptions/{sid}/notifications/{nid}/redeliver",
+                post(Self::handle_redeliver_notification),
+            )
+            .route("/delivery-metrics", get(Self::handle_get_delivery_metrics))
+            .route(
+                "/subscriptions/{sid}/delivery-metrics",
+                get(Self::handle_get_delivery_metrics_by_subscription),
+            )
             .with_state((self.service, self.entity_type))
     }
     fn serialize_entity_type(entity: &Option<SubscriptionEntities>) -> String {
@@ -214,4 +227,112 @@ where
             },
         }
     }
+    async fn handle_get_dead_letter(
+        State((service, entity)): State<(Arc<T>, Option<SubscriptionEntities>)>,
+        Path(sid): Path<String>,
+    ) -> impl IntoResponse {
+        info!(
+            "GET {}/subscriptions/{}/notifications-dead-letter",
+            Self::serialize_entity_type(&entity),
+            sid
+        );
+        let sid = match get_urn_from_string(&sid) {
+            Ok(sid) => sid,
+            Err(_) => return NotificationErrors::UrnUuidSchema(sid.to_string()).into_response(),
+        };
+        match service.get_dead_letter_notifications_by_subscription_id(sid).await {
+            Ok(notifications) => (StatusCode::OK, Json(notifications)).into_response(),
+            Err(e) => match e.downcast::<NotificationErrors>() {
+                Ok(e_) => e_.into_response(),
+                Err(_) => (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(json!({
+                        "message": "Internal Server Error",
+                        "error_code": 5000
+                    })),
+                )
+                    .into_response(),
+            },
+        }
+    }
+    async fn handle_redeliver_notification(
+        State((service, entity)): State<(Arc<T>, Option<SubscriptionEntities>)>,
+        Path((sid, nid)): Path<(String, String)>,
+    ) -> impl IntoResponse {
+        info!(
+            "POST {}/subscriptions/{}/notifications/{}/redeliver",
+            Self::serialize_entity_type(&entity),
+            sid,
+            nid
+        );
+        let sid = match get_urn_from_string(&sid) {
+            Ok(sid) => sid,
+            Err(_) => return NotificationErrors::UrnUuidSchema(sid.to_string()).into_response(),
+        };
+        let nid = match get_urn_from_string(&nid) {
+            Ok(nid) => nid,
+            Err(_) => return NotificationErrors::UrnUuidSchema(nid.to_string()).into_response(),
+        };
+        match service.redeliver_notification(sid, nid).await {
+            Ok(notification) => (StatusCode::ACCEPTED, Json(notification)).into_response(),
+            Err(e) => match e.downcast::<NotificationErrors>() {
+                Ok(e_) => e_.into_response(),
+                Err(_) => (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(json!({
+                        "message": "Internal Server Error",
+                        "error_code": 5000
+                    })),
+                )
+                    .into_response(),
+            },
+        }
+    }
+    async fn handle_get_delivery_metrics(
+        State((service, entity)): State<(Arc<T>, Option<SubscriptionEntities>)>,
+    ) -> impl IntoResponse {
+        info!("GET {}/delivery-metrics", Self::serialize_entity_type(&entity));
+        match service.get_delivery_metrics().await {
+            Ok(metrics) => (StatusCode::OK, Json(metrics)).into_response(),
+            Err(e) => match e.downcast::<NotificationErrors>() {
+                Ok(e_) => e_.into_response(),
+                Err(_) => (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(json!({
+                        "message": "Internal Server Error",
+                        "error_code": 5000
+                    })),
+                )
+                    .into_response(),
+            },
+        }
+    }
+    async fn handle_get_delivery_metrics_by_subscription(
+        State((service, entity)): State<(Arc<T>, Option<SubscriptionEntities>)>,
+        Path(sid): Path<String>,
+    ) -> impl IntoResponse {
+        info!(
+            "GET {}/subscriptions/{}/delivery-metrics",
+            Self::serialize_entity_type(&entity),
+            sid
+        );
+        let sid = match get_urn_from_string(&sid) {
+            Ok(sid) => sid,
+            Err(_) => return NotificationErrors::UrnUuidSchema(sid.to_string()).into_response(),
+        };
+        match service.get_delivery_metrics_by_subscription_id(sid).await {
+            Ok(metrics) => (StatusCode::OK, Json(metrics)).into_response(),
+            Err(e) => match e.downcast::<NotificationErrors>() {
+                Ok(e_) => e_.into_response(),
+                Err(_) => (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(json!({
+                        "message": "Internal Server Error",
+                        "error_code": 5000
+                    })),
+                )
+                    .into_response(),
+            },
+        }
+    }
 }
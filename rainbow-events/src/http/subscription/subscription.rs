@@ -58,10 +58,11 @@ where
     pub fn router(self) -> Router {
         Router::new()
             .route("/subscriptions", get(Self::handle_get_all_subscriptions))
-            .route("/subscriptions/:id", get(Self::handle_get_subscription_by_id))
-            .route("/subscriptions/:id", put(Self::handle_put_subscription_by_id))
+            .route("/subscriptions/{id}", get(Self::handle_get_subscription_by_id))
+            .route("/subscriptions/{id}", put(Self::handle_put_subscription_by_id))
             .route("/subscriptions", post(Self::handle_post_subscription_by_id))
-            .route("/subscriptions/:id", delete(Self::handle_delete_subscription_by_id))
+            .route("/subscriptions/{id}", delete(Self::handle_delete_subscription_by_id))
+            .route("/subscriptions/{id}/rotate-secret", post(Self::handle_rotate_secret))
             .with_state((self.service, self.entity_type))
     }
     fn serialize_entity_type(entity: &Option<SubscriptionEntities>) -> String {
@@ -222,4 +223,32 @@ where
             },
         }
     }
+    async fn handle_rotate_secret(
+        State((service, entity)): State<(Arc<T>, Option<SubscriptionEntities>)>,
+        Path(id): Path<String>,
+    ) -> impl IntoResponse {
+        info!(
+            "POST {}/subscriptions/{}/rotate-secret",
+            Self::serialize_entity_type(&entity),
+            id
+        );
+        let id = match get_urn_from_string(&id) {
+            Ok(id) => id,
+            Err(_) => return SubscriptionErrors::UrnUuidSchema(id.to_string()).into_response(),
+        };
+        match service.rotate_subscription_secret(id).await {
+            Ok(subscription) => (StatusCode::OK, Json(subscription)).into_response(),
+            Err(e) => match e.downcast::<SubscriptionErrors>() {
+                Ok(e_) => e_.into_response(),
+                Err(_) => (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(json!({
+                        "message": "Internal Server Error",
+                        "error_code": 5000
+                    })),
+                )
+                    .into_response(),
+            },
+        }
+    }
 }
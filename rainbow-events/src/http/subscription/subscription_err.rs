@@ -70,6 +70,24 @@ impl IntoResponse for SubscriptionErrors {
                 }),
             )
                 .into_response(),
+            e @ SubscriptionErrors::ExpirationTimeInThePast { .. } => (
+                StatusCode::BAD_REQUEST,
+                Json(SubscriptionErrorMessage {
+                    code: "400".to_string(),
+                    title: "EXPIRATION_TIME_PAST".to_string(),
+                    message: e.to_string(),
+                }),
+            )
+                .into_response(),
+            e @ SubscriptionErrors::InvalidFilter { .. } => (
+                StatusCode::BAD_REQUEST,
+                Json(SubscriptionErrorMessage {
+                    code: "400".to_string(),
+                    title: "INVALID_FILTER".to_string(),
+                    message: e.to_string(),
+                }),
+            )
+                .into_response(),
         }
     }
 }
@@ -23,6 +23,7 @@ use rainbow_common::boot::BootstrapServiceTrait;
 use rainbow_common::config::traits::CommonConfigTrait;
 use rainbow_common::config::ApplicationConfig;
 use rainbow_common::http_client::{HttpClient, HttpClientError};
+use rainbow_events::core::notification::notification_delivery::RainbowEventsDeliveryWorker;
 use std::str::FromStr;
 use std::sync::Arc;
 use tokio::fs;
@@ -35,6 +36,7 @@ use ymir::config::traits::{ApiConfigTrait, HostsConfigTrait};
 use ymir::config::types::HostType;
 use ymir::data::entities::mates;
 use ymir::services::vault::vault_rs::VaultService;
+use ymir::services::vault::VaultTrait;
 
 pub struct CoreBoot;
 
@@ -186,6 +188,10 @@ impl BootstrapServiceTrait for CoreBoot {
         tracing::info!("Spawning HTTP subsystem...");
         let http_handle = CoreHttpWorker::spawn(config, vault.clone(), &cancel_token).await?;
 
+        tracing::info!("Spawning events delivery worker...");
+        let db_connection = vault.get_db_connection(config.monolith().common()).await;
+        let events_handle = RainbowEventsDeliveryWorker::spawn(db_connection, &cancel_token);
+
         // todo set grpc
 
         // non-blocking thread
@@ -199,6 +205,9 @@ impl BootstrapServiceTrait for CoreBoot {
                 _ = async { http_handle.await } => {
                     tracing::error!("HTTP subsystem failed or stopped unexpectedly!");
                 }
+                _ = async { events_handle.await } => {
+                    tracing::error!("Events delivery subsystem failed or stopped unexpectedly!");
+                }
             }
 
             tracing::info!("Initiating internal graceful shutdown sequence...");
@@ -40,6 +40,12 @@ pub struct Model {
     pub state: String,
     pub created_at: DateTimeWithTimeZone,
     pub updated_at: Option<DateTimeWithTimeZone>,
+    pub provider_signature: Option<Json>,
+    pub consumer_signature: Option<Json>,
+    pub valid_from: Option<DateTimeWithTimeZone>,
+    pub valid_until: Option<DateTimeWithTimeZone>,
+    pub revoked_at: Option<DateTimeWithTimeZone>,
+    pub revocation_reason: Option<String>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -83,6 +89,8 @@ pub struct NewAgreementModel {
     pub provider_participant_id: String,
     pub agreement_content: Json,
     pub target: Urn,
+    pub valid_from: Option<DateTimeWithTimeZone>,
+    pub valid_until: Option<DateTimeWithTimeZone>,
 }
 
 impl From<NewAgreementModel> for ActiveModel {
@@ -106,6 +114,12 @@ impl From<NewAgreementModel> for ActiveModel {
             state: ActiveValue::Set("REQUESTED".to_string()),
             created_at: ActiveValue::Set(chrono::Utc::now().into()),
             updated_at: ActiveValue::Set(None),
+            provider_signature: ActiveValue::Set(None),
+            consumer_signature: ActiveValue::Set(None),
+            valid_from: ActiveValue::Set(value.valid_from),
+            valid_until: ActiveValue::Set(value.valid_until),
+            revoked_at: ActiveValue::Set(None),
+            revocation_reason: ActiveValue::Set(None),
         }
     }
 }
@@ -118,10 +132,20 @@ impl From<&NewAgreementModel> for ActiveModel {
 
 pub struct EditAgreementModel {
     pub state: Option<String>,
+    pub provider_signature: Option<Json>,
+    pub consumer_signature: Option<Json>,
+    pub revoked_at: Option<DateTimeWithTimeZone>,
+    pub revocation_reason: Option<String>,
 }
 
 impl Default for EditAgreementModel {
     fn default() -> Self {
-        Self { state: None }
+        Self {
+            state: None,
+            provider_signature: None,
+            consumer_signature: None,
+            revoked_at: None,
+            revocation_reason: None,
+        }
     }
 }
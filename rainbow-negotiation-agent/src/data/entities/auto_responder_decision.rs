@@ -0,0 +1,118 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use sea_orm::prelude::{DateTimeWithTimeZone, Json};
+use sea_orm::{
+    ActiveModelBehavior, ActiveValue, DeriveEntityModel, DerivePrimaryKey, DeriveRelation,
+    EntityTrait, EnumIter, PrimaryKeyTrait, Related, RelationDef, RelationTrait,
+};
+use serde::{Deserialize, Serialize};
+use urn::{Urn, UrnBuilder};
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "negotiation_agent_auto_responder_decisions")]
+#[serde(rename_all = "camelCase")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: String,
+    pub negotiation_agent_process_id: String,
+    pub trigger: String,
+    pub rule_name: String,
+    pub action: String,
+    pub response: String,
+    pub offer_id: Option<String>,
+    pub participant_id: String,
+    pub matched_criteria: Json,
+    pub outcome: String,
+    pub error: Option<String>,
+    pub created_at: DateTimeWithTimeZone,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::negotiation_process::Entity",
+        from = "Column::NegotiationAgentProcessId",
+        to = "super::negotiation_process::Column::Id",
+        on_delete = "Cascade"
+    )]
+    Process,
+}
+
+impl Related<super::negotiation_process::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Process.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+#[derive(Clone)]
+pub struct NewAutoResponderDecisionModel {
+    pub id: Option<Urn>,
+    pub negotiation_agent_process_id: Urn,
+    pub trigger: String,
+    pub rule_name: String,
+    pub action: String,
+    pub response: String,
+    pub offer_id: Option<String>,
+    pub participant_id: String,
+    pub matched_criteria: Json,
+    pub outcome: String,
+    pub error: Option<String>,
+}
+
+#[derive(Clone, Default)]
+pub struct EditAutoResponderDecisionModel {
+    pub outcome: Option<String>,
+    pub error: Option<String>,
+}
+
+impl From<NewAutoResponderDecisionModel> for ActiveModel {
+    fn from(value: NewAutoResponderDecisionModel) -> Self {
+        let new_urn = UrnBuilder::new(
+            "negotiation-auto-responder-decision",
+            uuid::Uuid::new_v4().to_string().as_str(),
+        )
+        .build()
+        .expect("UrnBuilder failed");
+        Self {
+            id: ActiveValue::Set(value.id.unwrap_or(new_urn).to_string()),
+            negotiation_agent_process_id: ActiveValue::Set(
+                value.negotiation_agent_process_id.to_string(),
+            ),
+            trigger: ActiveValue::Set(value.trigger),
+            rule_name: ActiveValue::Set(value.rule_name),
+            action: ActiveValue::Set(value.action),
+            response: ActiveValue::Set(value.response),
+            offer_id: ActiveValue::Set(value.offer_id),
+            participant_id: ActiveValue::Set(value.participant_id),
+            matched_criteria: ActiveValue::Set(value.matched_criteria),
+            outcome: ActiveValue::Set(value.outcome),
+            error: ActiveValue::Set(value.error),
+            created_at: ActiveValue::Set(chrono::Utc::now().into()),
+        }
+    }
+}
+
+impl From<&NewAutoResponderDecisionModel> for ActiveModel {
+    fn from(value: &NewAutoResponderDecisionModel) -> Self {
+        value.clone().into()
+    }
+}
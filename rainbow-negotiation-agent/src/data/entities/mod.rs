@@ -18,6 +18,7 @@
  */
 
 pub(crate) mod agreement;
+pub(crate) mod auto_responder_decision;
 pub(crate) mod negotiation_message;
 pub(crate) mod negotiation_process;
 pub(crate) mod negotiation_process_identifier;
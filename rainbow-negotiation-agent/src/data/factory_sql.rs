@@ -19,11 +19,13 @@
 
 use crate::data::factory_trait::NegotiationAgentRepoTrait;
 use crate::data::repo_traits::agreement_repo::AgreementRepoTrait;
+use crate::data::repo_traits::auto_responder_decision_repo::AutoResponderDecisionRepoTrait;
 use crate::data::repo_traits::negotiation_message_repo::NegotiationMessageRepoTrait;
 use crate::data::repo_traits::negotiation_process_identifiers_repo::NegotiationIdentifierRepoTrait;
 use crate::data::repo_traits::negotiation_process_repo::NegotiationProcessRepoTrait;
 use crate::data::repo_traits::offer_repo::OfferRepoTrait;
 use crate::data::repos_sql::agreement_repo::AgreementRepoForSql;
+use crate::data::repos_sql::auto_responder_decision_repo::AutoResponderDecisionRepoForSql;
 use crate::data::repos_sql::negotiation_message_repo::NegotiationMessageRepoForSql;
 use crate::data::repos_sql::negotiation_process_identifiers_repo::NegotiationProcessIdentifierRepoForSql;
 use crate::data::repos_sql::negotiation_process_repo::NegotiationProcessRepoForSql;
@@ -37,6 +39,7 @@ pub struct NegotiationAgentRepoForSql {
     negotiation_message_repo: Arc<dyn NegotiationMessageRepoTrait>,
     offer_repo: Arc<dyn OfferRepoTrait>,
     agreement_repo: Arc<dyn AgreementRepoTrait>,
+    auto_responder_decision_repo: Arc<dyn AutoResponderDecisionRepoTrait>,
 }
 
 impl NegotiationAgentRepoForSql {
@@ -53,6 +56,9 @@ impl NegotiationAgentRepoForSql {
             )),
             offer_repo: Arc::new(OfferRepoForSql::new(db_connection.clone())),
             agreement_repo: Arc::new(AgreementRepoForSql::new(db_connection.clone())),
+            auto_responder_decision_repo: Arc::new(AutoResponderDecisionRepoForSql::new(
+                db_connection.clone(),
+            )),
         }
     }
 }
@@ -77,4 +83,8 @@ impl NegotiationAgentRepoTrait for NegotiationAgentRepoForSql {
     fn get_agreement_repo(&self) -> Arc<dyn AgreementRepoTrait> {
         self.agreement_repo.clone()
     }
+
+    fn get_auto_responder_decision_repo(&self) -> Arc<dyn AutoResponderDecisionRepoTrait> {
+        self.auto_responder_decision_repo.clone()
+    }
 }
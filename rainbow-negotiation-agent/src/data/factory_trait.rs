@@ -18,6 +18,7 @@
  */
 
 use crate::data::repo_traits::agreement_repo::AgreementRepoTrait;
+use crate::data::repo_traits::auto_responder_decision_repo::AutoResponderDecisionRepoTrait;
 use crate::data::repo_traits::negotiation_message_repo::NegotiationMessageRepoTrait;
 use crate::data::repo_traits::negotiation_process_identifiers_repo::NegotiationIdentifierRepoTrait;
 use crate::data::repo_traits::negotiation_process_repo::NegotiationProcessRepoTrait;
@@ -31,4 +32,5 @@ pub trait NegotiationAgentRepoTrait: Send + Sync + 'static {
     fn get_negotiation_process_identifiers_repo(&self) -> Arc<dyn NegotiationIdentifierRepoTrait>;
     fn get_offer_repo(&self) -> Arc<dyn OfferRepoTrait>;
     fn get_agreement_repo(&self) -> Arc<dyn AgreementRepoTrait>;
+    fn get_auto_responder_decision_repo(&self) -> Arc<dyn AutoResponderDecisionRepoTrait>;
 }
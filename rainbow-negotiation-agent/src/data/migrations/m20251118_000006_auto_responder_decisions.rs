@@ -0,0 +1,136 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use sea_orm_migration::prelude::*;
+
+pub struct Migration;
+impl MigrationName for Migration {
+    fn name(&self) -> &str {
+        "m20251118_000006_auto_responder_decisions"
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(NegotiationAgentAutoResponderDecisions::Table)
+                    .col(
+                        ColumnDef::new(NegotiationAgentAutoResponderDecisions::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(
+                            NegotiationAgentAutoResponderDecisions::NegotiationAgentProcessId,
+                        )
+                        .string()
+                        .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(NegotiationAgentAutoResponderDecisions::Trigger)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(NegotiationAgentAutoResponderDecisions::RuleName)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(NegotiationAgentAutoResponderDecisions::Action)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(NegotiationAgentAutoResponderDecisions::Response)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(NegotiationAgentAutoResponderDecisions::OfferId).string())
+                    .col(
+                        ColumnDef::new(NegotiationAgentAutoResponderDecisions::ParticipantId)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(NegotiationAgentAutoResponderDecisions::MatchedCriteria)
+                            .json_binary()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(NegotiationAgentAutoResponderDecisions::Outcome)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(NegotiationAgentAutoResponderDecisions::Error).string())
+                    .col(
+                        ColumnDef::new(NegotiationAgentAutoResponderDecisions::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-negotiation_auto_responder_decision-process_id")
+                            .from(
+                                NegotiationAgentAutoResponderDecisions::Table,
+                                NegotiationAgentAutoResponderDecisions::NegotiationAgentProcessId,
+                            )
+                            .to(NegotiationAgentProcess::Table, NegotiationAgentProcess::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(
+                Table::drop().table(NegotiationAgentAutoResponderDecisions::Table).to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(Iden)]
+pub enum NegotiationAgentAutoResponderDecisions {
+    Table,
+    Id,
+    NegotiationAgentProcessId,
+    Trigger,
+    RuleName,
+    Action,
+    Response,
+    OfferId,
+    ParticipantId,
+    MatchedCriteria,
+    Outcome,
+    Error,
+    CreatedAt,
+}
+
+#[derive(Iden)]
+pub enum NegotiationAgentProcess {
+    Table,
+    Id,
+}
@@ -0,0 +1,77 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+use sea_orm_migration::prelude::*;
+
+pub struct Migration;
+impl MigrationName for Migration {
+    fn name(&self) -> &str {
+        "m20251118_000008_agreement_lifecycle"
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(NegotiationAgentAgreements::Table)
+                    .add_column(
+                        ColumnDef::new(NegotiationAgentAgreements::ValidFrom)
+                            .timestamp_with_time_zone(),
+                    )
+                    .add_column(
+                        ColumnDef::new(NegotiationAgentAgreements::ValidUntil)
+                            .timestamp_with_time_zone(),
+                    )
+                    .add_column(
+                        ColumnDef::new(NegotiationAgentAgreements::RevokedAt)
+                            .timestamp_with_time_zone(),
+                    )
+                    .add_column(
+                        ColumnDef::new(NegotiationAgentAgreements::RevocationReason).string(),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(NegotiationAgentAgreements::Table)
+                    .drop_column(NegotiationAgentAgreements::ValidFrom)
+                    .drop_column(NegotiationAgentAgreements::ValidUntil)
+                    .drop_column(NegotiationAgentAgreements::RevokedAt)
+                    .drop_column(NegotiationAgentAgreements::RevocationReason)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(Iden)]
+pub enum NegotiationAgentAgreements {
+    Table,
+    ValidFrom,
+    ValidUntil,
+    RevokedAt,
+    RevocationReason,
+}
@@ -23,6 +23,9 @@ mod m20251118_000002_negotiation_messages;
 mod m20251118_000003_negotiation_process_identifiers;
 mod m20251118_000004_offers;
 mod m20251118_000005_agreements;
+mod m20251118_000006_auto_responder_decisions;
+mod m20251118_000007_agreement_signatures;
+mod m20251118_000008_agreement_lifecycle;
 
 pub fn get_negotiation_agent_migrations() -> Vec<Box<dyn MigrationTrait>> {
     vec![
@@ -31,5 +34,8 @@ pub fn get_negotiation_agent_migrations() -> Vec<Box<dyn MigrationTrait>> {
         Box::new(m20251118_000003_negotiation_process_identifiers::Migration),
         Box::new(m20251118_000004_offers::Migration),
         Box::new(m20251118_000005_agreements::Migration),
+        Box::new(m20251118_000006_auto_responder_decisions::Migration),
+        Box::new(m20251118_000007_agreement_signatures::Migration),
+        Box::new(m20251118_000008_agreement_lifecycle::Migration),
     ]
 }
@@ -0,0 +1,71 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use crate::data::entities::auto_responder_decision;
+use crate::data::entities::auto_responder_decision::{
+    EditAutoResponderDecisionModel, NewAutoResponderDecisionModel,
+};
+use anyhow::Error;
+use chrono::{DateTime, Utc};
+use thiserror::Error;
+use urn::Urn;
+
+#[async_trait::async_trait]
+pub trait AutoResponderDecisionRepoTrait: Send + Sync {
+    async fn get_all_auto_responder_decisions(
+        &self,
+        limit: Option<u64>,
+        page: Option<u64>,
+    ) -> anyhow::Result<Vec<auto_responder_decision::Model>, AutoResponderDecisionRepoErrors>;
+    async fn get_auto_responder_decisions_by_negotiation_process(
+        &self,
+        id: &Urn,
+    ) -> anyhow::Result<Vec<auto_responder_decision::Model>, AutoResponderDecisionRepoErrors>;
+    /// Decisions with the given outcome taken before `decided_before`, oldest first
+    async fn get_auto_responder_decisions_by_outcome(
+        &self,
+        outcome: &str,
+        decided_before: &DateTime<Utc>,
+    ) -> anyhow::Result<Vec<auto_responder_decision::Model>, AutoResponderDecisionRepoErrors>;
+    async fn get_auto_responder_decision_by_id(
+        &self,
+        id: &Urn,
+    ) -> anyhow::Result<Option<auto_responder_decision::Model>, AutoResponderDecisionRepoErrors>;
+    async fn create_auto_responder_decision(
+        &self,
+        new_model: &NewAutoResponderDecisionModel,
+    ) -> anyhow::Result<auto_responder_decision::Model, AutoResponderDecisionRepoErrors>;
+    async fn put_auto_responder_decision(
+        &self,
+        id: &Urn,
+        edit_model: &EditAutoResponderDecisionModel,
+    ) -> anyhow::Result<auto_responder_decision::Model, AutoResponderDecisionRepoErrors>;
+}
+
+#[derive(Debug, Error)]
+pub enum AutoResponderDecisionRepoErrors {
+    #[error("Auto responder decision not found")]
+    AutoResponderDecisionNotFound,
+    #[error("Error fetching auto responder decision. {0}")]
+    ErrorFetchingAutoResponderDecision(Error),
+    #[error("Error creating auto responder decision. {0}")]
+    ErrorCreatingAutoResponderDecision(Error),
+    #[error("Error updating auto responder decision. {0}")]
+    ErrorUpdatingAutoResponderDecision(Error),
+}
@@ -147,6 +147,18 @@ impl AgreementRepoTrait for AgreementRepoForSql {
         if let Some(state) = &edit_model.state {
             active_model.state = ActiveValue::Set(state.clone());
         }
+        if let Some(provider_signature) = &edit_model.provider_signature {
+            active_model.provider_signature = ActiveValue::Set(Some(provider_signature.clone()));
+        }
+        if let Some(consumer_signature) = &edit_model.consumer_signature {
+            active_model.consumer_signature = ActiveValue::Set(Some(consumer_signature.clone()));
+        }
+        if let Some(revoked_at) = &edit_model.revoked_at {
+            active_model.revoked_at = ActiveValue::Set(Some(revoked_at.clone()));
+        }
+        if let Some(revocation_reason) = &edit_model.revocation_reason {
+            active_model.revocation_reason = ActiveValue::Set(Some(revocation_reason.clone()));
+        }
         active_model.updated_at = ActiveValue::Set(Some(chrono::Utc::now().into()));
 
         let result = active_model.update(&self.db_connection).await;
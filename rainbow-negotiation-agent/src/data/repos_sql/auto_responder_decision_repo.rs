@@ -0,0 +1,173 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use crate::data::entities::auto_responder_decision;
+use crate::data::entities::auto_responder_decision::{
+    EditAutoResponderDecisionModel, Model, NewAutoResponderDecisionModel,
+};
+use crate::data::repo_traits::auto_responder_decision_repo::{
+    AutoResponderDecisionRepoErrors, AutoResponderDecisionRepoTrait,
+};
+use chrono::{DateTime, Utc};
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter,
+    QueryOrder, QuerySelect,
+};
+use urn::Urn;
+
+pub struct AutoResponderDecisionRepoForSql {
+    db_connection: DatabaseConnection,
+}
+
+impl AutoResponderDecisionRepoForSql {
+    pub fn new(db_connection: DatabaseConnection) -> Self {
+        Self { db_connection }
+    }
+}
+
+#[async_trait::async_trait]
+impl AutoResponderDecisionRepoTrait for AutoResponderDecisionRepoForSql {
+    async fn get_all_auto_responder_decisions(
+        &self,
+        limit: Option<u64>,
+        page: Option<u64>,
+    ) -> anyhow::Result<Vec<Model>, AutoResponderDecisionRepoErrors> {
+        let decisions = auto_responder_decision::Entity::find()
+            .limit(limit.unwrap_or(20))
+            .offset(page.map(|p| p * limit.unwrap_or(20)).unwrap_or(0))
+            .order_by_desc(auto_responder_decision::Column::CreatedAt)
+            .all(&self.db_connection)
+            .await;
+
+        match decisions {
+            Ok(decisions) => Ok(decisions),
+            Err(e) => Err(AutoResponderDecisionRepoErrors::ErrorFetchingAutoResponderDecision(
+                e.into(),
+            )),
+        }
+    }
+
+    async fn get_auto_responder_decisions_by_negotiation_process(
+        &self,
+        id: &Urn,
+    ) -> anyhow::Result<Vec<Model>, AutoResponderDecisionRepoErrors> {
+        let pid = id.to_string();
+        let decisions = auto_responder_decision::Entity::find()
+            .filter(auto_responder_decision::Column::NegotiationAgentProcessId.eq(pid))
+            .order_by_asc(auto_responder_decision::Column::CreatedAt)
+            .all(&self.db_connection)
+            .await;
+
+        match decisions {
+            Ok(decisions) => Ok(decisions),
+            Err(e) => Err(AutoResponderDecisionRepoErrors::ErrorFetchingAutoResponderDecision(
+                e.into(),
+            )),
+        }
+    }
+
+    async fn get_auto_responder_decisions_by_outcome(
+        &self,
+        outcome: &str,
+        decided_before: &DateTime<Utc>,
+    ) -> anyhow::Result<Vec<Model>, AutoResponderDecisionRepoErrors> {
+        let decisions = auto_responder_decision::Entity::find()
+            .filter(auto_responder_decision::Column::Outcome.eq(outcome))
+            .filter(auto_responder_decision::Column::CreatedAt.lte(*decided_before))
+            .order_by_asc(auto_responder_decision::Column::CreatedAt)
+            .all(&self.db_connection)
+            .await;
+
+        match decisions {
+            Ok(decisions) => Ok(decisions),
+            Err(e) => Err(AutoResponderDecisionRepoErrors::ErrorFetchingAutoResponderDecision(
+                e.into(),
+            )),
+        }
+    }
+
+    async fn get_auto_responder_decision_by_id(
+        &self,
+        id: &Urn,
+    ) -> anyhow::Result<Option<Model>, AutoResponderDecisionRepoErrors> {
+        let did = id.to_string();
+        let decision =
+            auto_responder_decision::Entity::find_by_id(did).one(&self.db_connection).await;
+
+        match decision {
+            Ok(decision) => Ok(decision),
+            Err(e) => Err(AutoResponderDecisionRepoErrors::ErrorFetchingAutoResponderDecision(
+                e.into(),
+            )),
+        }
+    }
+
+    async fn create_auto_responder_decision(
+        &self,
+        new_model: &NewAutoResponderDecisionModel,
+    ) -> anyhow::Result<Model, AutoResponderDecisionRepoErrors> {
+        let model: auto_responder_decision::ActiveModel = new_model.clone().into();
+        let result = auto_responder_decision::Entity::insert(model)
+            .exec_with_returning(&self.db_connection)
+            .await;
+
+        match result {
+            Ok(decision) => Ok(decision),
+            Err(e) => Err(AutoResponderDecisionRepoErrors::ErrorCreatingAutoResponderDecision(
+                e.into(),
+            )),
+        }
+    }
+
+    async fn put_auto_responder_decision(
+        &self,
+        id: &Urn,
+        edit_model: &EditAutoResponderDecisionModel,
+    ) -> anyhow::Result<Model, AutoResponderDecisionRepoErrors> {
+        let did = id.to_string();
+        let old_model =
+            auto_responder_decision::Entity::find_by_id(did).one(&self.db_connection).await;
+        let old_model = match old_model {
+            Ok(old_model) => match old_model {
+                Some(old_model) => old_model,
+                None => return Err(AutoResponderDecisionRepoErrors::AutoResponderDecisionNotFound),
+            },
+            Err(e) => {
+                return Err(AutoResponderDecisionRepoErrors::ErrorFetchingAutoResponderDecision(
+                    e.into(),
+                ));
+            }
+        };
+        let mut old_active_model: auto_responder_decision::ActiveModel = old_model.into();
+        if let Some(outcome) = &edit_model.outcome {
+            old_active_model.outcome = ActiveValue::Set(outcome.clone());
+        }
+        if let Some(error) = &edit_model.error {
+            old_active_model.error = ActiveValue::Set(Some(error.clone()));
+        }
+        let model = old_active_model.update(&self.db_connection).await;
+
+        match model {
+            Ok(model) => Ok(model),
+            Err(e) => Err(AutoResponderDecisionRepoErrors::ErrorUpdatingAutoResponderDecision(
+                e.into(),
+            )),
+        }
+    }
+}
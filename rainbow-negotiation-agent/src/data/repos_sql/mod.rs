@@ -18,6 +18,7 @@
  */
 
 pub(crate) mod agreement_repo;
+pub(crate) mod auto_responder_decision_repo;
 pub(crate) mod negotiation_message_repo;
 pub(crate) mod negotiation_process_identifiers_repo;
 pub(crate) mod negotiation_process_repo;
@@ -20,21 +20,85 @@
 use crate::data::entities::agreement::{EditAgreementModel, NewAgreementModel};
 use crate::data::factory_trait::NegotiationAgentRepoTrait;
 use crate::data::repo_traits::agreement_repo::AgreementRepoErrors;
+use crate::entities::agreement::signature::verify_stored_signature;
 use crate::entities::agreement::{
-    AgreementDto, EditAgreementDto, NegotiationAgentAgreementsTrait, NewAgreementDto,
+    AgreementDto, AgreementRevocationDto, AgreementRevocationStatusDto, AgreementSignatureDto,
+    AgreementSignaturesVerificationDto, EditAgreementDto, NegotiationAgentAgreementsTrait,
+    NewAgreementDto,
 };
+use anyhow::bail;
+use rainbow_common::config::types::roles::RoleConfig;
+use rainbow_common::errors::helpers::BadFormat;
 use rainbow_common::errors::{CommonErrors, ErrorLog};
+use rainbow_common::http_client::HttpClient;
+use std::str::FromStr;
 use std::sync::Arc;
 use tracing::error;
 use urn::Urn;
 
 pub struct NegotiationAgentAgreementsService {
     pub negotiation_repo: Arc<dyn NegotiationAgentRepoTrait>,
+    http_client: Arc<HttpClient>,
 }
 
 impl NegotiationAgentAgreementsService {
-    pub fn new(negotiation_repo: Arc<dyn NegotiationAgentRepoTrait>) -> Self {
-        Self { negotiation_repo }
+    pub fn new(
+        negotiation_repo: Arc<dyn NegotiationAgentRepoTrait>,
+        http_client: Arc<HttpClient>,
+    ) -> Self {
+        Self { negotiation_repo, http_client }
+    }
+
+    async fn put_agreement_model(
+        &self,
+        id: &Urn,
+        edit_model: &EditAgreementModel,
+    ) -> anyhow::Result<AgreementDto> {
+        let updated = self
+            .negotiation_repo
+            .get_agreement_repo()
+            .put_agreement(id, edit_model)
+            .await
+            .map_err(|e| match e {
+                AgreementRepoErrors::AgreementNotFound => {
+                    let err = CommonErrors::missing_resource_new(
+                        &id.to_string(),
+                        "Agreement not found for update",
+                    );
+                    error!("{}", err.log());
+                    err
+                }
+                _ => {
+                    let err = CommonErrors::database_new(&e.to_string());
+                    error!("{}", err.log());
+                    err
+                }
+            })?;
+
+        Ok(AgreementDto { inner: updated })
+    }
+
+    async fn get_agreement_role(&self, agreement: &AgreementDto) -> anyhow::Result<RoleConfig> {
+        let process_id = Urn::from_str(agreement.inner.negotiation_agent_process_id.as_str())?;
+        let process = self
+            .negotiation_repo
+            .get_negotiation_process_repo()
+            .get_negotiation_process_by_id(&process_id)
+            .await
+            .map_err(|e| {
+                let err = CommonErrors::database_new(&e.to_string());
+                error!("{}", err.log());
+                err
+            })?
+            .ok_or_else(|| {
+                let err = CommonErrors::missing_resource_new(
+                    &process_id.to_string(),
+                    "Negotiation process of the agreement not found",
+                );
+                error!("{}", err.log());
+                err
+            })?;
+        process.role.parse::<RoleConfig>()
     }
 }
 
@@ -170,6 +234,158 @@ impl NegotiationAgentAgreementsTrait for NegotiationAgentAgreementsService {
         Ok(AgreementDto { inner: updated })
     }
 
+    async fn put_agreement_signature(
+        &self,
+        id: &Urn,
+        role: &RoleConfig,
+        signature: &AgreementSignatureDto,
+    ) -> anyhow::Result<AgreementDto> {
+        let signature = serde_json::to_value(signature)?;
+        let edit_model = match role {
+            RoleConfig::Provider => {
+                EditAgreementModel { provider_signature: Some(signature), ..Default::default() }
+            }
+            RoleConfig::Consumer => {
+                EditAgreementModel { consumer_signature: Some(signature), ..Default::default() }
+            }
+            _ => {
+                let err = CommonErrors::parse_new("Only consumer or provider sign agreements");
+                error!("{}", err.log());
+                bail!(err);
+            }
+        };
+
+        let updated = self
+            .negotiation_repo
+            .get_agreement_repo()
+            .put_agreement(id, &edit_model)
+            .await
+            .map_err(|e| match e {
+                AgreementRepoErrors::AgreementNotFound => {
+                    let err = CommonErrors::missing_resource_new(
+                        &id.to_string(),
+                        "Agreement not found for signing",
+                    );
+                    error!("{}", err.log());
+                    err
+                }
+                _ => {
+                    let err = CommonErrors::database_new(&e.to_string());
+                    error!("{}", err.log());
+                    err
+                }
+            })?;
+
+        Ok(AgreementDto { inner: updated })
+    }
+
+    async fn verify_agreement_signatures(
+        &self,
+        id: &Urn,
+    ) -> anyhow::Result<Option<AgreementSignaturesVerificationDto>> {
+        let agreement = match self.get_agreement_by_id(id).await? {
+            Some(agreement) => agreement.inner,
+            None => return Ok(None),
+        };
+        let provider = verify_stored_signature(
+            &agreement.agreement_content,
+            &agreement.provider_signature,
+            agreement.provider_participant_id.as_str(),
+            self.http_client.as_ref(),
+        )
+        .await;
+        let consumer = verify_stored_signature(
+            &agreement.agreement_content,
+            &agreement.consumer_signature,
+            agreement.consumer_participant_id.as_str(),
+            self.http_client.as_ref(),
+        )
+        .await;
+        let verified = provider.verified && consumer.verified;
+        Ok(Some(AgreementSignaturesVerificationDto {
+            agreement_id: agreement.id,
+            provider,
+            consumer,
+            verified,
+        }))
+    }
+
+    async fn revoke_agreement(
+        &self,
+        id: &Urn,
+        revocation: &AgreementRevocationDto,
+    ) -> anyhow::Result<AgreementDto> {
+        let agreement = self.get_agreement_by_id(id).await?.ok_or_else(|| {
+            let err = CommonErrors::missing_resource_new(
+                &id.to_string(),
+                "Agreement not found for revocation",
+            );
+            error!("{}", err.log());
+            err
+        })?;
+        if self.get_agreement_role(&agreement).await? != RoleConfig::Provider {
+            let err = CommonErrors::forbidden_new("Only the provider can revoke an agreement");
+            error!("{}", err.log());
+            bail!(err);
+        }
+        if matches!(agreement.inner.state.as_str(), "REVOKED" | "EXPIRED") {
+            let err = CommonErrors::format_new(
+                BadFormat::Received,
+                format!("Agreement {} is already {}", id, agreement.inner.state).as_str(),
+            );
+            error!("{}", err.log());
+            bail!(err);
+        }
+
+        self.put_agreement_model(
+            id,
+            &EditAgreementModel {
+                state: Some("REVOKED".to_string()),
+                revoked_at: Some(chrono::Utc::now().into()),
+                revocation_reason: revocation.reason.clone(),
+                ..Default::default()
+            },
+        )
+        .await
+    }
+
+    async fn get_agreement_revocation_status(
+        &self,
+        id: &Urn,
+    ) -> anyhow::Result<Option<AgreementRevocationStatusDto>> {
+        let mut agreement = match self.get_agreement_by_id(id).await? {
+            Some(agreement) => agreement,
+            None => return Ok(None),
+        };
+        let now = chrono::Utc::now();
+        let expired = agreement.inner.valid_until.is_some_and(|until| until < now);
+        if expired && !matches!(agreement.inner.state.as_str(), "REVOKED" | "EXPIRED") {
+            agreement = self
+                .put_agreement_model(
+                    id,
+                    &EditAgreementModel {
+                        state: Some("EXPIRED".to_string()),
+                        ..Default::default()
+                    },
+                )
+                .await?;
+        }
+        let agreement = agreement.inner;
+        let revoked = agreement.state == "REVOKED";
+        let started = agreement.valid_from.is_none_or(|from| from <= now);
+        Ok(Some(AgreementRevocationStatusDto {
+            agreement_id: agreement.id,
+            state: agreement.state,
+            revoked,
+            expired,
+            valid: !revoked && !expired && started,
+            valid_from: agreement.valid_from,
+            valid_until: agreement.valid_until,
+            revoked_at: agreement.revoked_at,
+            revocation_reason: agreement.revocation_reason,
+        }))
+    }
+
     async fn delete_agreement(&self, id: &Urn) -> anyhow::Result<()> {
         self.negotiation_repo.get_agreement_repo().delete_agreement(id).await.map_err(
             |e| match e {
@@ -18,9 +18,15 @@
  */
 
 pub(crate) mod agreement;
+pub(crate) mod signature;
+pub(crate) mod validity;
 
 use crate::data::entities::agreement as agreement_model;
 use crate::data::entities::agreement::{EditAgreementModel, NewAgreementModel};
+use crate::entities::agreement::validity::AgreementValidityWindow;
+use rainbow_common::config::types::agreement_signatures::AgreementSignaturePolicy;
+use rainbow_common::config::types::roles::RoleConfig;
+use sea_orm::prelude::DateTimeWithTimeZone;
 use serde::{Deserialize, Serialize};
 use urn::Urn;
 
@@ -51,8 +57,70 @@ pub struct EditAgreementDto {
     pub state: Option<String>,
 }
 
+/// JWS over the agreement content issued by one of the parties. The public key is informative,
+/// verification resolves the key from the signer DID.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
+pub struct AgreementSignatureDto {
+    pub signer: String,
+    pub jws: String,
+    pub public_key: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AgreementSignatureVerificationDto {
+    pub signer: Option<String>,
+    /// False when the party sent its message without a signature, e.g. a non Rainbow peer
+    pub signed: bool,
+    pub verified: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AgreementSignaturesVerificationDto {
+    pub agreement_id: String,
+    pub provider: AgreementSignatureVerificationDto,
+    pub consumer: AgreementSignatureVerificationDto,
+    pub verified: bool,
+}
+
+impl AgreementSignaturesVerificationDto {
+    pub fn satisfies(&self, policy: &AgreementSignaturePolicy) -> bool {
+        [&self.provider, &self.consumer]
+            .iter()
+            .all(|party| policy.accepts(party.signed, party.verified))
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
+pub struct AgreementRevocationDto {
+    pub reason: Option<String>,
+}
+
+/// What a peer needs to know before relying on an agreement
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AgreementRevocationStatusDto {
+    pub agreement_id: String,
+    pub state: String,
+    pub revoked: bool,
+    pub expired: bool,
+    pub valid: bool,
+    pub valid_from: Option<DateTimeWithTimeZone>,
+    pub valid_until: Option<DateTimeWithTimeZone>,
+    pub revoked_at: Option<DateTimeWithTimeZone>,
+    pub revocation_reason: Option<String>,
+}
+
 impl From<NewAgreementDto> for NewAgreementModel {
     fn from(dto: NewAgreementDto) -> Self {
+        let window = AgreementValidityWindow::from_agreement(&dto.agreement_content);
         Self {
             id: dto.id,
             negotiation_agent_process_id: dto.negotiation_agent_process_id,
@@ -61,13 +129,15 @@ impl From<NewAgreementDto> for NewAgreementModel {
             provider_participant_id: dto.provider_participant_id,
             agreement_content: dto.agreement_content,
             target: dto.target,
+            valid_from: window.valid_from,
+            valid_until: window.valid_until,
         }
     }
 }
 
 impl From<EditAgreementDto> for EditAgreementModel {
     fn from(dto: EditAgreementDto) -> Self {
-        Self { state: dto.state }
+        Self { state: dto.state, ..Default::default() }
     }
 }
 
@@ -102,5 +172,31 @@ pub trait NegotiationAgentAgreementsTrait: Send + Sync + 'static {
         edit_model: &EditAgreementDto,
     ) -> anyhow::Result<AgreementDto>;
 
+    /// Stores the signature of the party playing `role` in the negotiation
+    async fn put_agreement_signature(
+        &self,
+        id: &Urn,
+        role: &RoleConfig,
+        signature: &AgreementSignatureDto,
+    ) -> anyhow::Result<AgreementDto>;
+
+    async fn verify_agreement_signatures(
+        &self,
+        id: &Urn,
+    ) -> anyhow::Result<Option<AgreementSignaturesVerificationDto>>;
+
+    /// Revokes an agreement this participant granted as provider
+    async fn revoke_agreement(
+        &self,
+        id: &Urn,
+        revocation: &AgreementRevocationDto,
+    ) -> anyhow::Result<AgreementDto>;
+
+    /// Reports revocation and expiry, moving agreements past their window to EXPIRED
+    async fn get_agreement_revocation_status(
+        &self,
+        id: &Urn,
+    ) -> anyhow::Result<Option<AgreementRevocationStatusDto>>;
+
     async fn delete_agreement(&self, id: &Urn) -> anyhow::Result<()>;
 }
@@ -0,0 +1,276 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use crate::entities::agreement::{AgreementSignatureDto, AgreementSignatureVerificationDto};
+use anyhow::{anyhow, bail};
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use jsonwebtoken::jwk::Jwk;
+use jsonwebtoken::{Algorithm, DecodingKey, EncodingKey, Header, Validation};
+use rainbow_common::errors::{CommonErrors, ErrorLog};
+use rainbow_common::http_client::{HttpClient, RequestOptions, RetryPolicy};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashSet;
+use std::time::Duration;
+use tracing::error;
+
+/// The whole agreement travels inside the token, so the JWS alone is evidence of what was agreed
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AgreementSignatureClaims {
+    pub iss: String,
+    pub sub: String,
+    pub iat: i64,
+    pub agreement: Value,
+}
+
+pub fn sign_agreement_content(
+    agreement: &Value,
+    signer: &str,
+    private_key: &str,
+    public_key: &str,
+) -> anyhow::Result<AgreementSignatureDto> {
+    let claims = AgreementSignatureClaims {
+        iss: signer.to_string(),
+        sub: agreement.get("@id").and_then(Value::as_str).unwrap_or_default().to_string(),
+        iat: chrono::Utc::now().timestamp(),
+        agreement: agreement.clone(),
+    };
+    let mut header = Header::new(Algorithm::RS256);
+    header.kid = Some(signer.to_string());
+    let key = EncodingKey::from_rsa_pem(private_key.as_bytes())?;
+    let jws = jsonwebtoken::encode(&header, &claims, &key)?;
+    Ok(AgreementSignatureDto {
+        signer: signer.to_string(),
+        jws,
+        public_key: public_key.to_string(),
+    })
+}
+
+const DID_RESOLUTION_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Only the parts of a DID document needed to find the signing key
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DidDocument {
+    id: String,
+    #[serde(default)]
+    verification_method: Vec<DidVerificationMethod>,
+    #[serde(default)]
+    assertion_method: Vec<Value>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DidVerificationMethod {
+    id: String,
+    public_key_jwk: Option<Jwk>,
+}
+
+fn forbidden(cause: String) -> anyhow::Error {
+    let err = CommonErrors::forbidden_new(cause.as_str());
+    error!("{}", err.log());
+    anyhow!(err)
+}
+
+/// Where a did:web document is served. `did:web:host%3Aport:api:v1` is at
+/// `https://host:port/api/v1/did.json`, a bare domain at `/.well-known/did.json`.
+/// Loopback hosts are fetched over plain http, as local deployments are.
+pub fn did_web_document_url(did: &str) -> Option<String> {
+    let mut segments = did
+        .strip_prefix("did:web:")?
+        .split(':')
+        .map(|segment| segment.replace("%3A", ":").replace("%3a", ":"));
+    let host = segments.next().filter(|host| !host.is_empty())?;
+    let path = segments.collect::<Vec<_>>();
+    let scheme = match host.split(':').next() {
+        Some("localhost") | Some("127.0.0.1") => "http",
+        _ => "https",
+    };
+    match path.is_empty() {
+        true => Some(format!("{}://{}/.well-known/did.json", scheme, host)),
+        false => Some(format!("{}://{}/{}/did.json", scheme, host, path.join("/"))),
+    }
+}
+
+async fn resolve_did_web_key(
+    did: &str,
+    fragment: Option<&str>,
+    client: &HttpClient,
+) -> anyhow::Result<Jwk> {
+    let url = did_web_document_url(did)
+        .ok_or_else(|| forbidden(format!("Signer {} is not a valid did:web", did)))?;
+    let options = RequestOptions::new()
+        .with_retry_policy(RetryPolicy::none())
+        .with_timeout(DID_RESOLUTION_TIMEOUT);
+    let document = client
+        .get_json_with_options::<DidDocument>(url.as_str(), &options)
+        .await
+        .map_err(|e| forbidden(format!("Unable to resolve the DID document of {}, {}", did, e)))?;
+    if document.id != did {
+        return Err(forbidden(format!(
+            "DID document at {} is not the one of {}",
+            url, did
+        )));
+    }
+    // a key named by the signer, otherwise the first one meant for assertions
+    let is_method = |method: &DidVerificationMethod, id: &str| {
+        method.id == id || method.id == format!("{}{}", did, id)
+    };
+    let wanted = match fragment {
+        Some(fragment) => vec![format!("#{}", fragment)],
+        None => document
+            .assertion_method
+            .iter()
+            .filter_map(|reference| reference.as_str())
+            .map(|reference| reference.strip_prefix(did).unwrap_or(reference).to_string())
+            .collect(),
+    };
+    let method = match wanted.is_empty() {
+        true => document.verification_method.iter().find(|m| m.public_key_jwk.is_some()),
+        false => wanted
+            .iter()
+            .find_map(|id| document.verification_method.iter().find(|m| is_method(m, id))),
+    };
+    method.and_then(|method| method.public_key_jwk.clone()).ok_or_else(|| {
+        forbidden(format!(
+            "DID document of {} holds no key to verify the signature",
+            did
+        ))
+    })
+}
+
+/// Resolves the verification key from the signer identity. A did:jwk is itself the
+/// document holding the key, a did:web document is fetched from the signer's domain,
+/// where the SSI auth service publishes it.
+pub async fn resolve_signer_key(signer: &str, client: &HttpClient) -> anyhow::Result<DecodingKey> {
+    let (did, fragment) = match signer.split_once('#') {
+        Some((did, fragment)) => (did, Some(fragment)),
+        None => (signer, None),
+    };
+    let jwk = if let Some(encoded_jwk) = did.strip_prefix("did:jwk:") {
+        URL_SAFE_NO_PAD
+            .decode(encoded_jwk)
+            .map_err(anyhow::Error::from)
+            .and_then(|jwk| serde_json::from_slice::<Jwk>(&jwk).map_err(anyhow::Error::from))
+            .map_err(|e| forbidden(format!("Signer {} is not a valid did:jwk, {}", signer, e)))?
+    } else if did.starts_with("did:web:") {
+        resolve_did_web_key(did, fragment, client).await?
+    } else {
+        return Err(forbidden(format!(
+            "Unable to resolve the signing key of {}, only did:jwk and did:web are supported",
+            signer
+        )));
+    };
+    DecodingKey::from_jwk(&jwk)
+        .map_err(|e| forbidden(format!("Signer {} does not hold a usable key, {}", signer, e)))
+}
+
+/// Checks the JWS against the key of its signer, the agreement content and, when known,
+/// the expected signer. The public key carried with the signature is never trusted.
+pub async fn verify_agreement_signature(
+    agreement: &Value,
+    signature: &AgreementSignatureDto,
+    expected_signer: Option<&str>,
+    client: &HttpClient,
+) -> anyhow::Result<()> {
+    if expected_signer.is_some_and(|s| s != signature.signer) {
+        let err = CommonErrors::forbidden_new(
+            format!(
+                "Agreement signed by {} instead of the expected party",
+                signature.signer
+            )
+            .as_str(),
+        );
+        error!("{}", err.log());
+        bail!(err);
+    }
+    let key = resolve_signer_key(signature.signer.as_str(), client).await?;
+    let mut validation = Validation::new(Algorithm::RS256);
+    validation.required_spec_claims = HashSet::new();
+    validation.validate_exp = false;
+    let claims = jsonwebtoken::decode::<AgreementSignatureClaims>(
+        signature.jws.as_str(),
+        &key,
+        &validation,
+    )
+    .map_err(|e| {
+        let err = CommonErrors::forbidden_new(
+            format!("Agreement signature is not valid, {}", e).as_str(),
+        );
+        error!("{}", err.log());
+        anyhow!(err)
+    })?
+    .claims;
+    if claims.iss != signature.signer {
+        let err = CommonErrors::forbidden_new(
+            format!("Agreement signed by {} instead of the expected party", claims.iss).as_str(),
+        );
+        error!("{}", err.log());
+        bail!(err);
+    }
+    if claims.agreement != *agreement {
+        let err =
+            CommonErrors::forbidden_new("Signed agreement does not match the stored agreement");
+        error!("{}", err.log());
+        bail!(err);
+    }
+    Ok(())
+}
+
+/// Verification report for the signature stored for one of the parties
+pub async fn verify_stored_signature(
+    agreement: &Value,
+    signature: &Option<Value>,
+    participant_id: &str,
+    client: &HttpClient,
+) -> AgreementSignatureVerificationDto {
+    let signature = match signature {
+        Some(signature) => signature,
+        None => {
+            return AgreementSignatureVerificationDto {
+                signer: None,
+                signed: false,
+                verified: false,
+                error: Some("Agreement is not signed by this party".to_string()),
+            };
+        }
+    };
+    let expected_signer = Some(participant_id).filter(|p| !p.is_empty());
+    let result = match serde_json::from_value::<AgreementSignatureDto>(signature.clone()) {
+        Ok(signature) => verify_agreement_signature(agreement, &signature, expected_signer, client)
+            .await
+            .map(|_| signature.signer),
+        Err(e) => Err(anyhow::Error::from(e)),
+    };
+    match result {
+        Ok(signer) => AgreementSignatureVerificationDto {
+            signer: Some(signer),
+            signed: true,
+            verified: true,
+            error: None,
+        },
+        Err(e) => AgreementSignatureVerificationDto {
+            signer: None,
+            signed: true,
+            verified: false,
+            error: Some(e.to_string()),
+        },
+    }
+}
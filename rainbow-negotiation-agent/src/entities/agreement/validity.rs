@@ -0,0 +1,91 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+use chrono::{DateTime, FixedOffset};
+use rainbow_common::dsp_common::odrl_evaluator::odrl_terms::{
+    as_list, conjunctive_constraints, parse_date_time,
+};
+use serde_json::Value;
+
+/// Validity window of an agreement, as bounded by the `dateTime` constraints of its permissions
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct AgreementValidityWindow {
+    pub valid_from: Option<DateTime<FixedOffset>>,
+    pub valid_until: Option<DateTime<FixedOffset>>,
+}
+
+impl AgreementValidityWindow {
+    /// Constraints of one permission are intersected. Permissions are alternatives, so the
+    /// agreement is valid while any of them is: the window spans from the earliest start to
+    /// the latest end, and a permission without a bound leaves that side open
+    pub fn from_agreement(agreement: &Value) -> Self {
+        let windows = agreement
+            .get("permission")
+            .map(as_list)
+            .unwrap_or_default()
+            .into_iter()
+            .map(Self::from_permission)
+            .collect::<Vec<_>>();
+        if windows.is_empty() {
+            return Self::default();
+        }
+        let valid_from = windows
+            .iter()
+            .map(|w| w.valid_from)
+            .collect::<Option<Vec<_>>>()
+            .and_then(|from| from.into_iter().min());
+        let valid_until = windows
+            .iter()
+            .map(|w| w.valid_until)
+            .collect::<Option<Vec<_>>>()
+            .and_then(|until| until.into_iter().max());
+        Self { valid_from, valid_until }
+    }
+
+    fn from_permission(permission: &Value) -> Self {
+        let mut window = Self::default();
+        let constraints = permission.get("constraint").map(conjunctive_constraints);
+        for constraint in constraints.into_iter().flatten() {
+            if constraint.left_operand != "dateTime" {
+                continue;
+            }
+            let date = match parse_date_time(&constraint.right_operand) {
+                Some(date) => date.fixed_offset(),
+                None => continue,
+            };
+            match constraint.operator.as_str() {
+                "gt" | "gteq" => window.narrow_from(date),
+                "lt" | "lteq" => window.narrow_until(date),
+                "eq" => {
+                    window.narrow_from(date);
+                    window.narrow_until(date);
+                }
+                _ => {}
+            }
+        }
+        window
+    }
+
+    fn narrow_from(&mut self, date: DateTime<FixedOffset>) {
+        self.valid_from = Some(self.valid_from.map_or(date, |d| d.max(date)));
+    }
+
+    fn narrow_until(&mut self, date: DateTime<FixedOffset>) {
+        self.valid_until = Some(self.valid_until.map_or(date, |d| d.min(date)));
+    }
+}
@@ -0,0 +1,173 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use crate::data::entities::auto_responder_decision::{
+    EditAutoResponderDecisionModel, NewAutoResponderDecisionModel,
+};
+use crate::data::factory_trait::NegotiationAgentRepoTrait;
+use crate::data::repo_traits::auto_responder_decision_repo::AutoResponderDecisionRepoErrors;
+use crate::entities::auto_responder_decision::{
+    AutoResponderDecisionDto, AutoResponderDecisionOutcome, EditAutoResponderDecisionDto,
+    NegotiationAgentAutoResponderDecisionsTrait, NewAutoResponderDecisionDto,
+};
+use chrono::{DateTime, Utc};
+use rainbow_common::errors::{CommonErrors, ErrorLog};
+use std::sync::Arc;
+use tracing::error;
+use urn::Urn;
+
+pub struct NegotiationAgentAutoResponderDecisionsService {
+    pub negotiation_repo: Arc<dyn NegotiationAgentRepoTrait>,
+}
+
+impl NegotiationAgentAutoResponderDecisionsService {
+    pub fn new(negotiation_repo: Arc<dyn NegotiationAgentRepoTrait>) -> Self {
+        Self { negotiation_repo }
+    }
+}
+
+#[async_trait::async_trait]
+impl NegotiationAgentAutoResponderDecisionsTrait for NegotiationAgentAutoResponderDecisionsService {
+    async fn get_all_auto_responder_decisions(
+        &self,
+        limit: Option<u64>,
+        page: Option<u64>,
+    ) -> anyhow::Result<Vec<AutoResponderDecisionDto>> {
+        let decisions = self
+            .negotiation_repo
+            .get_auto_responder_decision_repo()
+            .get_all_auto_responder_decisions(limit, page)
+            .await
+            .map_err(|e| {
+                let err = CommonErrors::database_new(&e.to_string());
+                error!("{}", err.log());
+                err
+            })?;
+
+        Ok(decisions.into_iter().map(|m| AutoResponderDecisionDto { inner: m }).collect())
+    }
+
+    async fn get_auto_responder_decisions_by_negotiation_process(
+        &self,
+        id: &Urn,
+    ) -> anyhow::Result<Vec<AutoResponderDecisionDto>> {
+        let decisions = self
+            .negotiation_repo
+            .get_auto_responder_decision_repo()
+            .get_auto_responder_decisions_by_negotiation_process(id)
+            .await
+            .map_err(|e| {
+                let err = CommonErrors::database_new(&e.to_string());
+                error!("{}", err.log());
+                err
+            })?;
+
+        Ok(decisions.into_iter().map(|m| AutoResponderDecisionDto { inner: m }).collect())
+    }
+
+    async fn get_pending_auto_responder_decisions(
+        &self,
+        decided_before: &DateTime<Utc>,
+    ) -> anyhow::Result<Vec<AutoResponderDecisionDto>> {
+        let decisions = self
+            .negotiation_repo
+            .get_auto_responder_decision_repo()
+            .get_auto_responder_decisions_by_outcome(
+                AutoResponderDecisionOutcome::Pending.to_string().as_str(),
+                decided_before,
+            )
+            .await
+            .map_err(|e| {
+                let err = CommonErrors::database_new(&e.to_string());
+                error!("{}", err.log());
+                err
+            })?;
+
+        Ok(decisions.into_iter().map(|m| AutoResponderDecisionDto { inner: m }).collect())
+    }
+
+    async fn get_auto_responder_decision_by_id(
+        &self,
+        id: &Urn,
+    ) -> anyhow::Result<Option<AutoResponderDecisionDto>> {
+        let decision = self
+            .negotiation_repo
+            .get_auto_responder_decision_repo()
+            .get_auto_responder_decision_by_id(id)
+            .await
+            .map_err(|e| {
+                let err = CommonErrors::database_new(&e.to_string());
+                error!("{}", err.log());
+                err
+            })?;
+
+        Ok(decision.map(|m| AutoResponderDecisionDto { inner: m }))
+    }
+
+    async fn create_auto_responder_decision(
+        &self,
+        new_model_dto: &NewAutoResponderDecisionDto,
+    ) -> anyhow::Result<AutoResponderDecisionDto> {
+        let new_model: NewAutoResponderDecisionModel = new_model_dto.clone().into();
+
+        let created = self
+            .negotiation_repo
+            .get_auto_responder_decision_repo()
+            .create_auto_responder_decision(&new_model)
+            .await
+            .map_err(|e| {
+                let err = CommonErrors::database_new(&e.to_string());
+                error!("{}", err.log());
+                err
+            })?;
+
+        Ok(AutoResponderDecisionDto { inner: created })
+    }
+
+    async fn put_auto_responder_decision(
+        &self,
+        id: &Urn,
+        edit_model_dto: &EditAutoResponderDecisionDto,
+    ) -> anyhow::Result<AutoResponderDecisionDto> {
+        let edit_model: EditAutoResponderDecisionModel = edit_model_dto.clone().into();
+
+        let updated = self
+            .negotiation_repo
+            .get_auto_responder_decision_repo()
+            .put_auto_responder_decision(id, &edit_model)
+            .await
+            .map_err(|e| match e {
+                AutoResponderDecisionRepoErrors::AutoResponderDecisionNotFound => {
+                    let err = CommonErrors::missing_resource_new(
+                        &id.to_string(),
+                        "Auto responder decision not found for update",
+                    );
+                    error!("{}", err.log());
+                    err
+                }
+                _ => {
+                    let err = CommonErrors::database_new(&e.to_string());
+                    error!("{}", err.log());
+                    err
+                }
+            })?;
+
+        Ok(AutoResponderDecisionDto { inner: updated })
+    }
+}
@@ -0,0 +1,142 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+pub(crate) mod auto_responder_decision;
+
+use crate::data::entities::auto_responder_decision as auto_responder_decision_model;
+use crate::data::entities::auto_responder_decision::{
+    EditAutoResponderDecisionModel, NewAutoResponderDecisionModel,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use urn::Urn;
+
+/// Pending decisions are answers still to be sent, the rest are final
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AutoResponderDecisionOutcome {
+    Pending,
+    Executed,
+    Failed,
+    Rejected,
+}
+
+impl Display for AutoResponderDecisionOutcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AutoResponderDecisionOutcome::Pending => write!(f, "PENDING"),
+            AutoResponderDecisionOutcome::Executed => write!(f, "EXECUTED"),
+            AutoResponderDecisionOutcome::Failed => write!(f, "FAILED"),
+            AutoResponderDecisionOutcome::Rejected => write!(f, "REJECTED"),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AutoResponderDecisionDto {
+    #[serde(flatten)]
+    pub inner: auto_responder_decision_model::Model,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
+pub struct NewAutoResponderDecisionDto {
+    pub id: Option<Urn>,
+    pub negotiation_agent_process_id: Urn,
+    pub trigger: String,
+    pub rule_name: String,
+    pub action: String,
+    pub response: String,
+    pub offer_id: Option<String>,
+    pub participant_id: String,
+    pub matched_criteria: serde_json::Value,
+    pub outcome: String,
+    pub error: Option<String>,
+}
+
+impl From<NewAutoResponderDecisionDto> for NewAutoResponderDecisionModel {
+    fn from(dto: NewAutoResponderDecisionDto) -> Self {
+        Self {
+            id: dto.id,
+            negotiation_agent_process_id: dto.negotiation_agent_process_id,
+            trigger: dto.trigger,
+            rule_name: dto.rule_name,
+            action: dto.action,
+            response: dto.response,
+            offer_id: dto.offer_id,
+            participant_id: dto.participant_id,
+            matched_criteria: dto.matched_criteria,
+            outcome: dto.outcome,
+            error: dto.error,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
+pub struct EditAutoResponderDecisionDto {
+    pub outcome: Option<String>,
+    pub error: Option<String>,
+}
+
+impl From<EditAutoResponderDecisionDto> for EditAutoResponderDecisionModel {
+    fn from(dto: EditAutoResponderDecisionDto) -> Self {
+        Self { outcome: dto.outcome, error: dto.error }
+    }
+}
+
+#[mockall::automock]
+#[async_trait::async_trait]
+pub trait NegotiationAgentAutoResponderDecisionsTrait: Send + Sync + 'static {
+    async fn get_all_auto_responder_decisions(
+        &self,
+        limit: Option<u64>,
+        page: Option<u64>,
+    ) -> anyhow::Result<Vec<AutoResponderDecisionDto>>;
+
+    async fn get_auto_responder_decisions_by_negotiation_process(
+        &self,
+        id: &Urn,
+    ) -> anyhow::Result<Vec<AutoResponderDecisionDto>>;
+
+    /// Answers decided before `decided_before` but not sent yet, oldest first
+    async fn get_pending_auto_responder_decisions(
+        &self,
+        decided_before: &DateTime<Utc>,
+    ) -> anyhow::Result<Vec<AutoResponderDecisionDto>>;
+
+    async fn get_auto_responder_decision_by_id(
+        &self,
+        id: &Urn,
+    ) -> anyhow::Result<Option<AutoResponderDecisionDto>>;
+
+    async fn create_auto_responder_decision(
+        &self,
+        new_model: &NewAutoResponderDecisionDto,
+    ) -> anyhow::Result<AutoResponderDecisionDto>;
+
+    async fn put_auto_responder_decision(
+        &self,
+        id: &Urn,
+        edit_model: &EditAutoResponderDecisionDto,
+    ) -> anyhow::Result<AutoResponderDecisionDto>;
+}
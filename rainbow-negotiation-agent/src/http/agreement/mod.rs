@@ -18,7 +18,7 @@
  */
 
 use crate::entities::agreement::{
-    EditAgreementDto, NegotiationAgentAgreementsTrait, NewAgreementDto,
+    AgreementRevocationDto, EditAgreementDto, NegotiationAgentAgreementsTrait, NewAgreementDto,
 };
 use crate::errors::error_adapter::CustomToResponse;
 use crate::http::common::{extract_payload, parse_urn};
@@ -74,17 +74,20 @@ impl NegotiationAgentAgreementsRouter {
             )
             .route("/batch", post(Self::handle_get_batch_agreements))
             .route(
-                "/:id",
+                "/{id}",
                 get(Self::handle_get_agreement_by_id)
                     .put(Self::handle_put_agreement)
                     .delete(Self::handle_delete_agreement),
             )
+            .route("/{id}/signatures", get(Self::handle_verify_agreement_signatures))
+            .route("/{id}/revoke", post(Self::handle_revoke_agreement))
+            .route("/{id}/revocation", get(Self::handle_get_agreement_revocation_status))
             .route(
-                "/process/:process_id",
+                "/process/{process_id}",
                 get(Self::handle_get_agreement_by_negotiation_process),
             )
             .route(
-                "/message/:message_id",
+                "/message/{message_id}",
                 get(Self::handle_get_agreement_by_negotiation_message),
             )
             .with_state(self)
@@ -176,6 +179,55 @@ impl NegotiationAgentAgreementsRouter {
         }
     }
 
+    async fn handle_verify_agreement_signatures(
+        State(state): State<NegotiationAgentAgreementsRouter>,
+        Path(id): Path<String>,
+    ) -> impl IntoResponse {
+        let id_urn = match parse_urn(&id) {
+            Ok(urn) => urn,
+            Err(resp) => return resp,
+        };
+        match state.service.verify_agreement_signatures(&id_urn).await {
+            Ok(Some(verification)) => (StatusCode::OK, Json(verification)).into_response(),
+            Ok(None) => (StatusCode::NOT_FOUND).into_response(),
+            Err(err) => err.to_response(),
+        }
+    }
+
+    async fn handle_revoke_agreement(
+        State(state): State<NegotiationAgentAgreementsRouter>,
+        Path(id): Path<String>,
+        input: Result<Json<AgreementRevocationDto>, JsonRejection>,
+    ) -> impl IntoResponse {
+        let id_urn = match parse_urn(&id) {
+            Ok(urn) => urn,
+            Err(resp) => return resp,
+        };
+        let input = match extract_payload(input) {
+            Ok(v) => v,
+            Err(e) => return e,
+        };
+        match state.service.revoke_agreement(&id_urn, &input).await {
+            Ok(revoked) => (StatusCode::OK, Json(revoked)).into_response(),
+            Err(err) => err.to_response(),
+        }
+    }
+
+    async fn handle_get_agreement_revocation_status(
+        State(state): State<NegotiationAgentAgreementsRouter>,
+        Path(id): Path<String>,
+    ) -> impl IntoResponse {
+        let id_urn = match parse_urn(&id) {
+            Ok(urn) => urn,
+            Err(resp) => return resp,
+        };
+        match state.service.get_agreement_revocation_status(&id_urn).await {
+            Ok(Some(status)) => (StatusCode::OK, Json(status)).into_response(),
+            Ok(None) => (StatusCode::NOT_FOUND).into_response(),
+            Err(err) => err.to_response(),
+        }
+    }
+
     async fn handle_get_agreement_by_negotiation_process(
         State(state): State<NegotiationAgentAgreementsRouter>,
         Path(process_id): Path<String>,
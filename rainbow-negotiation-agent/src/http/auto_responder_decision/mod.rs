@@ -0,0 +1,119 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use crate::entities::auto_responder_decision::NegotiationAgentAutoResponderDecisionsTrait;
+use crate::errors::error_adapter::CustomToResponse;
+use crate::http::common::parse_urn;
+use axum::{
+    Json, Router,
+    extract::{FromRef, Path, Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    routing::get,
+};
+use rainbow_common::config::services::ContractsConfig;
+use serde::Deserialize;
+use std::sync::Arc;
+
+/// Read only, decisions are written by the auto responder of the DSP orchestrator
+#[derive(Clone)]
+pub struct NegotiationAgentAutoResponderDecisionsRouter {
+    service: Arc<dyn NegotiationAgentAutoResponderDecisionsTrait>,
+    config: Arc<ContractsConfig>,
+}
+
+#[derive(Deserialize)]
+pub struct PaginationParams {
+    pub limit: Option<u64>,
+    pub page: Option<u64>,
+}
+
+impl FromRef<NegotiationAgentAutoResponderDecisionsRouter>
+    for Arc<dyn NegotiationAgentAutoResponderDecisionsTrait>
+{
+    fn from_ref(state: &NegotiationAgentAutoResponderDecisionsRouter) -> Self {
+        state.service.clone()
+    }
+}
+
+impl FromRef<NegotiationAgentAutoResponderDecisionsRouter> for Arc<ContractsConfig> {
+    fn from_ref(state: &NegotiationAgentAutoResponderDecisionsRouter) -> Self {
+        state.config.clone()
+    }
+}
+
+impl NegotiationAgentAutoResponderDecisionsRouter {
+    pub fn new(
+        service: Arc<dyn NegotiationAgentAutoResponderDecisionsTrait>,
+        config: Arc<ContractsConfig>,
+    ) -> Self {
+        Self { service, config }
+    }
+
+    pub fn router(self) -> Router {
+        Router::new()
+            .route("/", get(Self::handle_get_all_decisions))
+            .route("/{id}", get(Self::handle_get_decision_by_id))
+            .route(
+                "/process/{process_id}",
+                get(Self::handle_get_decisions_by_negotiation_process),
+            )
+            .with_state(self)
+    }
+
+    async fn handle_get_all_decisions(
+        State(state): State<NegotiationAgentAutoResponderDecisionsRouter>,
+        Query(params): Query<PaginationParams>,
+    ) -> impl IntoResponse {
+        match state.service.get_all_auto_responder_decisions(params.limit, params.page).await {
+            Ok(decisions) => (StatusCode::OK, Json(decisions)).into_response(),
+            Err(err) => err.to_response(),
+        }
+    }
+
+    async fn handle_get_decision_by_id(
+        State(state): State<NegotiationAgentAutoResponderDecisionsRouter>,
+        Path(id): Path<String>,
+    ) -> impl IntoResponse {
+        let id_urn = match parse_urn(&id) {
+            Ok(urn) => urn,
+            Err(resp) => return resp,
+        };
+        match state.service.get_auto_responder_decision_by_id(&id_urn).await {
+            Ok(Some(decision)) => (StatusCode::OK, Json(decision)).into_response(),
+            Ok(None) => (StatusCode::NOT_FOUND).into_response(),
+            Err(err) => err.to_response(),
+        }
+    }
+
+    async fn handle_get_decisions_by_negotiation_process(
+        State(state): State<NegotiationAgentAutoResponderDecisionsRouter>,
+        Path(process_id): Path<String>,
+    ) -> impl IntoResponse {
+        let process_urn = match parse_urn(&process_id) {
+            Ok(urn) => urn,
+            Err(resp) => return resp,
+        };
+        match state.service.get_auto_responder_decisions_by_negotiation_process(&process_urn).await
+        {
+            Ok(decisions) => (StatusCode::OK, Json(decisions)).into_response(),
+            Err(err) => err.to_response(),
+        }
+    }
+}
@@ -25,7 +25,10 @@ pub(crate) mod grpc;
 pub(crate) mod http;
 pub(crate) mod protocols;
 pub(crate) mod setup;
+mod tests;
 
-pub use entities::agreement::AgreementDto;
+pub use entities::agreement::{
+    AgreementDto, AgreementRevocationStatusDto, AgreementSignaturesVerificationDto,
+};
 pub use entities::offer::OfferDto;
 pub use setup::cmd::NegotiationCommands;
@@ -0,0 +1,87 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use crate::entities::agreement::AgreementSignatureDto;
+use crate::entities::agreement::signature::sign_agreement_content;
+use crate::protocols::dsp::agreement_signer::AgreementSignerTrait;
+use anyhow::anyhow;
+use rainbow_common::errors::{CommonErrors, ErrorLog};
+use rainbow_common::facades::ssi_auth_facade::MatesFacadeTrait;
+use serde_json::Value;
+use std::sync::Arc;
+use tracing::error;
+use ymir::services::vault::VaultTrait;
+use ymir::services::vault::vault_rs::VaultService;
+use ymir::types::secrets::StringHelper;
+
+/// Agreements are signed with the application key pair kept in vault,
+/// the same one the SSI auth service signs credentials with.
+pub struct AgreementSignerService {
+    vault: Arc<VaultService>,
+    mates_facade: Arc<dyn MatesFacadeTrait>,
+    private_key_path: String,
+    public_key_path: String,
+}
+
+impl AgreementSignerService {
+    /// Key locations are resolved on startup so a missing variable fails the boot, not a negotiation
+    pub fn new(
+        vault: Arc<VaultService>,
+        mates_facade: Arc<dyn MatesFacadeTrait>,
+    ) -> anyhow::Result<Self> {
+        Ok(Self {
+            vault,
+            mates_facade,
+            private_key_path: Self::key_path("VAULT_APP_PRIV_KEY")?,
+            public_key_path: Self::key_path("VAULT_APP_PUB_PKEY")?,
+        })
+    }
+
+    fn key_path(env_var: &str) -> anyhow::Result<String> {
+        std::env::var(env_var).map_err(|e| {
+            let err = CommonErrors::env_new(format!("{} is not set, {}", env_var, e));
+            error!("{}", err.log());
+            anyhow!(err)
+        })
+    }
+
+    async fn read_key(&self, path: &str) -> anyhow::Result<String> {
+        let key: StringHelper = self.vault.read(None, path).await.map_err(|e| {
+            let err = CommonErrors::vault_new(format!("Unable to read {}, {}", path, e));
+            error!("{}", err.log());
+            anyhow!(err)
+        })?;
+        Ok(key.data().to_string())
+    }
+}
+
+#[async_trait::async_trait]
+impl AgreementSignerTrait for AgreementSignerService {
+    async fn sign_agreement(&self, agreement: &Value) -> anyhow::Result<AgreementSignatureDto> {
+        let me = self.mates_facade.get_me_mate().await?;
+        let private_key = self.read_key(self.private_key_path.as_str()).await?;
+        let public_key = self.read_key(self.public_key_path.as_str()).await?;
+        sign_agreement_content(
+            agreement,
+            me.participant_id.as_str(),
+            private_key.as_str(),
+            public_key.as_str(),
+        )
+    }
+}
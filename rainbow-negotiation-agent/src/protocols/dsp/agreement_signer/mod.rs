@@ -0,0 +1,29 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use crate::entities::agreement::AgreementSignatureDto;
+use serde_json::Value;
+
+pub mod agreement_signer;
+
+#[async_trait::async_trait]
+pub trait AgreementSignerTrait: Send + Sync {
+    /// Signs the agreement content as this participant
+    async fn sign_agreement(&self, agreement: &Value) -> anyhow::Result<AgreementSignatureDto>;
+}
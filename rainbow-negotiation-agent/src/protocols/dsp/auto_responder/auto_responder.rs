@@ -0,0 +1,469 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use crate::entities::auto_responder_decision::{
+    AutoResponderDecisionDto, AutoResponderDecisionOutcome, EditAutoResponderDecisionDto,
+    NegotiationAgentAutoResponderDecisionsTrait, NewAutoResponderDecisionDto,
+};
+use crate::entities::negotiation_process::{NegotiationAgentProcessesTrait, NegotiationProcessDto};
+use crate::protocols::dsp::auto_responder::{
+    AutoResponderResponse, AutoResponderTrait, AutoResponderTrigger,
+};
+use crate::protocols::dsp::facades::FacadeTrait;
+use crate::protocols::dsp::orchestrator::rpc::RPCOrchestratorTrait;
+use crate::protocols::dsp::orchestrator::rpc::types::{
+    RpcNegotiationAgreementMessageDto, RpcNegotiationEventFinalizedMessageDto,
+    RpcNegotiationOfferMessageDto, RpcNegotiationTerminationMessageDto,
+};
+use crate::protocols::dsp::orchestrator::traits::orchestration_helpers::OrchestrationHelpers;
+use crate::protocols::dsp::protocol_types::NegotiationProcessState;
+use crate::protocols::dsp::validator::traits::validate_offer::ValidateOffer;
+use anyhow::anyhow;
+use chrono::{TimeDelta, Utc};
+use rainbow_common::auth::ssi::current_peer;
+use rainbow_common::config::types::auto_responder::{AutoResponderAction, AutoResponderRule};
+use rainbow_common::config::types::roles::RoleConfig;
+use rainbow_common::dsp_common::odrl::ContractRequestMessageOfferTypes;
+use serde_json::{Map, Value, json};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{error, info, warn};
+use urn::Urn;
+
+/// Answers still failing by then are given up, the consumer has most likely moved on
+const AUTO_RESPONSE_DEADLINE: Duration = Duration::from_secs(600);
+/// Answers are decided while the triggering message is handled, holding them back this long
+/// lets its ack reach the consumer before the answer does
+const AUTO_RESPONSE_MIN_AGE: Duration = Duration::from_secs(2);
+const AUTO_RESPONSE_TERMINATION_CODE: &str = "AUTO_RESPONDER";
+
+/// What the rules are matched against
+#[derive(Debug, Default)]
+pub(crate) struct AutoResponderContext {
+    pub(crate) offer_id: Option<String>,
+    pub(crate) offer: Option<ContractRequestMessageOfferTypes>,
+    pub(crate) policy_template: Option<String>,
+    pub(crate) participant_id: String,
+    pub(crate) credentials: BTreeMap<String, String>,
+}
+
+/// Answer decided for a process, before it is stored
+struct AutoResponderPlan {
+    process_id: Urn,
+    trigger: AutoResponderTrigger,
+    response: AutoResponderResponse,
+    rule: AutoResponderRule,
+    matched_criteria: Value,
+    offer_id: Option<String>,
+    offer: Option<ContractRequestMessageOfferTypes>,
+    participant_id: String,
+}
+
+pub struct AutoResponderService {
+    rules: Vec<AutoResponderRule>,
+    rpc_service: Arc<dyn RPCOrchestratorTrait>,
+    process_service: Arc<dyn NegotiationAgentProcessesTrait>,
+    facades: Arc<dyn FacadeTrait>,
+    validator_offer: Arc<dyn ValidateOffer>,
+    decisions_service: Arc<dyn NegotiationAgentAutoResponderDecisionsTrait>,
+}
+
+impl AutoResponderService {
+    pub fn new(
+        rules: Vec<AutoResponderRule>,
+        rpc_service: Arc<dyn RPCOrchestratorTrait>,
+        process_service: Arc<dyn NegotiationAgentProcessesTrait>,
+        facades: Arc<dyn FacadeTrait>,
+        validator_offer: Arc<dyn ValidateOffer>,
+        decisions_service: Arc<dyn NegotiationAgentAutoResponderDecisionsTrait>,
+    ) -> AutoResponderService {
+        Self { rules, rpc_service, process_service, facades, validator_offer, decisions_service }
+    }
+
+    /// Provider message answering `trigger` for a rule with `action`, if any
+    pub(crate) fn plan_response(
+        action: AutoResponderAction,
+        trigger: AutoResponderTrigger,
+    ) -> Option<AutoResponderResponse> {
+        match (action, trigger) {
+            (AutoResponderAction::Terminate, _) => Some(AutoResponderResponse::Termination),
+            (
+                AutoResponderAction::Offer | AutoResponderAction::Accept,
+                AutoResponderTrigger::ContractRequest,
+            ) => Some(AutoResponderResponse::Offer),
+            (AutoResponderAction::Agree, AutoResponderTrigger::ContractRequest) => {
+                Some(AutoResponderResponse::Agreement)
+            }
+            (
+                AutoResponderAction::Accept | AutoResponderAction::Agree,
+                AutoResponderTrigger::Accepted,
+            ) => Some(AutoResponderResponse::Agreement),
+            (
+                AutoResponderAction::Accept | AutoResponderAction::Agree,
+                AutoResponderTrigger::Verified,
+            ) => Some(AutoResponderResponse::Finalized),
+            (AutoResponderAction::Offer, _) => None,
+        }
+    }
+
+    /// Criteria of `rule` that matched, `None` if any of them did not
+    pub(crate) fn match_rule(
+        rule: &AutoResponderRule,
+        context: &AutoResponderContext,
+    ) -> Option<Value> {
+        fn any_of(expected: &[String], value: Option<&String>) -> Option<bool> {
+            if expected.is_empty() {
+                return None;
+            }
+            Some(value.is_some_and(|value| expected.contains(value)))
+        }
+
+        let mut matched = Map::new();
+        for (criterion, value, expected) in [
+            ("offerId", context.offer_id.as_ref(), &rule.offer_ids),
+            (
+                "policyTemplate",
+                context.policy_template.as_ref(),
+                &rule.policy_templates,
+            ),
+            ("participant", Some(&context.participant_id), &rule.participants),
+        ] {
+            match any_of(expected, value) {
+                Some(false) => return None,
+                Some(true) => {
+                    matched.insert(criterion.to_string(), json!(value));
+                }
+                None => {}
+            }
+        }
+        if !rule.credentials.is_empty() {
+            let credentials_match = rule
+                .credentials
+                .iter()
+                .all(|(attribute, expected)| context.credentials.get(attribute) == Some(expected));
+            if !credentials_match {
+                return None;
+            }
+            matched.insert("credentials".to_string(), json!(rule.credentials));
+        }
+        Some(Value::Object(matched))
+    }
+
+    /// Verified peer attributes as strings, nested values are not matchable
+    fn peer_credentials() -> BTreeMap<String, String> {
+        let Some(peer) = current_peer() else {
+            return BTreeMap::new();
+        };
+        let Ok(Value::Object(attributes)) = serde_json::to_value(&peer.mate) else {
+            return BTreeMap::new();
+        };
+        attributes
+            .into_iter()
+            .filter_map(|(attribute, value)| match value {
+                Value::String(value) => Some((attribute, value)),
+                Value::Bool(_) | Value::Number(_) => Some((attribute, value.to_string())),
+                _ => None,
+            })
+            .collect()
+    }
+
+    async fn build_context(&self, process: &NegotiationProcessDto) -> AutoResponderContext {
+        let last_offer = process.offers.iter().max_by_key(|offer| offer.created_at);
+        let offer_id = last_offer.map(|offer| offer.offer_id.clone());
+        let offer = last_offer.and_then(|offer| {
+            serde_json::from_value::<ContractRequestMessageOfferTypes>(offer.offer_content.clone())
+                .ok()
+        });
+
+        // the catalog is only asked when some rule needs the template
+        let needs_template = self.rules.iter().any(|rule| !rule.policy_templates.is_empty());
+        let policy_template = match (&offer_id, needs_template) {
+            (Some(offer_id), true) => self.resolve_policy_template(offer_id).await,
+            _ => None,
+        };
+
+        AutoResponderContext {
+            offer_id,
+            offer,
+            policy_template,
+            participant_id: current_peer()
+                .map(|peer| peer.participant_id.clone())
+                .unwrap_or_else(|| process.inner.associated_agent_peer.clone()),
+            credentials: Self::peer_credentials(),
+        }
+    }
+
+    async fn resolve_policy_template(&self, offer_id: &str) -> Option<String> {
+        let offer_urn = self.convert_str_to_urn(offer_id).ok()?;
+        let catalog_facade = self.facades.get_catalog_facade().await;
+        match catalog_facade.get_odrl_offer_by_id(&offer_urn).await {
+            Ok(offer) => offer.and_then(|offer| offer.inner.source_template_id),
+            Err(e) => {
+                // rules on templates just don't match, the request stays for a human
+                warn!("Auto responder could not resolve the template of {}: {}", offer_id, e);
+                None
+            }
+        }
+    }
+
+    /// Offers and agreements carry the consumer's terms, they are only sent as published
+    async fn validate_answered_offer(&self, plan: &AutoResponderPlan) -> anyhow::Result<()> {
+        if !matches!(
+            plan.response,
+            AutoResponderResponse::Offer | AutoResponderResponse::Agreement
+        ) {
+            return Ok(());
+        }
+        let offer = plan.offer.as_ref().ok_or_else(|| anyhow!("No offer to answer with"))?;
+        self.validator_offer.validate_catalog_offer(offer).await
+    }
+
+    async fn record_decision(
+        &self,
+        plan: &AutoResponderPlan,
+        outcome: AutoResponderDecisionOutcome,
+        error: Option<String>,
+    ) {
+        let decision = NewAutoResponderDecisionDto {
+            id: None,
+            negotiation_agent_process_id: plan.process_id.clone(),
+            trigger: plan.trigger.to_string(),
+            rule_name: plan.rule.name.clone(),
+            action: plan.rule.action.to_string(),
+            response: plan.response.to_string(),
+            offer_id: plan.offer_id.clone(),
+            participant_id: plan.participant_id.clone(),
+            matched_criteria: plan.matched_criteria.clone(),
+            outcome: outcome.to_string(),
+            error,
+        };
+        if let Err(e) = self.decisions_service.create_auto_responder_decision(&decision).await {
+            error!(
+                "Auto responder decision for {} could not be stored: {}",
+                plan.process_id, e
+            );
+        }
+    }
+
+    /// Why a pending answer no longer fits its process, if it does not
+    fn superseded_reason(
+        process: &NegotiationProcessDto,
+        decision: &AutoResponderDecisionDto,
+    ) -> Option<&'static str> {
+        if process.offers.iter().any(|offer| offer.created_at > decision.inner.created_at) {
+            return Some("A newer offer came in after the answer was decided");
+        }
+        match process.inner.state.parse::<NegotiationProcessState>() {
+            Ok(NegotiationProcessState::Finalized | NegotiationProcessState::Terminated) => {
+                Some("The negotiation ended before the answer was sent")
+            }
+            _ => None,
+        }
+    }
+
+    /// Failed answers stay pending until the deadline
+    fn retry_or_give_up(
+        decision: &AutoResponderDecisionDto,
+        error: anyhow::Error,
+    ) -> (AutoResponderDecisionOutcome, Option<String>) {
+        let age = Utc::now().signed_duration_since(decision.inner.created_at).to_std();
+        let outcome = match age {
+            Ok(age) if age >= AUTO_RESPONSE_DEADLINE => AutoResponderDecisionOutcome::Failed,
+            _ => AutoResponderDecisionOutcome::Pending,
+        };
+        (outcome, Some(error.to_string()))
+    }
+
+    async fn send_response(
+        &self,
+        decision: &AutoResponderDecisionDto,
+        process: &NegotiationProcessDto,
+    ) -> anyhow::Result<()> {
+        let consumer_pid = self.get_pid_by_role(process, RoleConfig::Consumer)?;
+        let provider_pid = self.get_pid_by_role(process, RoleConfig::Provider)?;
+        let response = decision
+            .inner
+            .response
+            .parse::<AutoResponderResponse>()
+            .map_err(|_| anyhow!("Unknown auto response {}", decision.inner.response))?;
+        match response {
+            AutoResponderResponse::Offer => {
+                // superseded answers never get here, so the last offer is the validated one
+                let offer = process
+                    .offers
+                    .iter()
+                    .max_by_key(|offer| offer.created_at)
+                    .ok_or_else(|| anyhow!("No offer to answer with"))?;
+                let offer = serde_json::from_value::<ContractRequestMessageOfferTypes>(
+                    offer.offer_content.clone(),
+                )?;
+                let input = RpcNegotiationOfferMessageDto { offer, provider_pid, consumer_pid };
+                self.rpc_service.setup_negotiation_offer_rpc(&input).await?;
+            }
+            AutoResponderResponse::Agreement => {
+                let input = RpcNegotiationAgreementMessageDto { provider_pid, consumer_pid };
+                self.rpc_service.setup_negotiation_agreement_rpc(&input).await?;
+            }
+            AutoResponderResponse::Finalized => {
+                let input = RpcNegotiationEventFinalizedMessageDto { provider_pid, consumer_pid };
+                self.rpc_service.setup_negotiation_event_finalized_rpc(&input).await?;
+            }
+            AutoResponderResponse::Termination => {
+                let reason = self
+                    .rules
+                    .iter()
+                    .find(|rule| rule.name == decision.inner.rule_name)
+                    .and_then(|rule| rule.reason.clone());
+                let input = RpcNegotiationTerminationMessageDto {
+                    consumer_pid,
+                    provider_pid,
+                    code: Some(AUTO_RESPONSE_TERMINATION_CODE.to_string()),
+                    reason: reason.map(|reason| vec![reason]),
+                };
+                self.rpc_service.setup_negotiation_termination_rpc(&input).await?;
+            }
+        }
+        Ok(())
+    }
+
+    async fn send_pending_response(
+        &self,
+        decision: &AutoResponderDecisionDto,
+    ) -> (AutoResponderDecisionOutcome, Option<String>) {
+        let process_id = match self.convert_str_to_urn(&decision.inner.negotiation_agent_process_id)
+        {
+            Ok(process_id) => process_id,
+            Err(e) => return (AutoResponderDecisionOutcome::Failed, Some(e.to_string())),
+        };
+        let process = match self.process_service.get_negotiation_process_by_id(&process_id).await {
+            Ok(Some(process)) => process,
+            Ok(None) => {
+                let error = "The negotiation process no longer exists".to_string();
+                return (AutoResponderDecisionOutcome::Failed, Some(error));
+            }
+            Err(e) => return Self::retry_or_give_up(decision, e),
+        };
+        if let Some(reason) = Self::superseded_reason(&process, decision) {
+            return (AutoResponderDecisionOutcome::Failed, Some(reason.to_string()));
+        }
+        match self.send_response(decision, &process).await {
+            Ok(()) => (AutoResponderDecisionOutcome::Executed, None),
+            Err(e) => Self::retry_or_give_up(decision, e),
+        }
+    }
+}
+
+impl OrchestrationHelpers for AutoResponderService {}
+
+#[async_trait::async_trait]
+impl AutoResponderTrait for AutoResponderService {
+    async fn on_consumer_message(
+        &self,
+        process: &NegotiationProcessDto,
+        trigger: AutoResponderTrigger,
+    ) {
+        if self.rules.is_empty() {
+            return;
+        }
+        if !matches!(process.inner.role.parse::<RoleConfig>(), Ok(RoleConfig::Provider)) {
+            return;
+        }
+
+        let context = self.build_context(process).await;
+        let Some((rule, matched_criteria)) = self
+            .rules
+            .iter()
+            .find_map(|rule| Self::match_rule(rule, &context).map(|matched| (rule, matched)))
+        else {
+            return;
+        };
+        let Some(response) = Self::plan_response(rule.action, trigger) else {
+            return;
+        };
+
+        let Ok(process_id) = self.convert_str_to_urn(process.inner.id.as_str()) else {
+            error!("Auto responder could not identify process {}", process.inner.id);
+            return;
+        };
+        let plan = AutoResponderPlan {
+            process_id,
+            trigger,
+            response,
+            rule: rule.clone(),
+            matched_criteria,
+            offer_id: context.offer_id,
+            offer: context.offer,
+            participant_id: context.participant_id,
+        };
+        // terms that drifted from the catalog are left for a human
+        if let Err(e) = self.validate_answered_offer(&plan).await {
+            warn!("Auto responder refused to answer {}: {}", plan.process_id, e);
+            self.record_decision(
+                &plan,
+                AutoResponderDecisionOutcome::Rejected,
+                Some(e.to_string()),
+            )
+            .await;
+            return;
+        }
+        // the consumer only knows the process once it gets our ack, the answer goes out later
+        self.record_decision(&plan, AutoResponderDecisionOutcome::Pending, None).await;
+    }
+
+    async fn send_pending_responses(&self) -> anyhow::Result<()> {
+        let decided_before = Utc::now() - TimeDelta::from_std(AUTO_RESPONSE_MIN_AGE)?;
+        let decisions =
+            self.decisions_service.get_pending_auto_responder_decisions(&decided_before).await?;
+        for decision in decisions {
+            let Ok(decision_id) = self.convert_str_to_urn(&decision.inner.id) else {
+                continue;
+            };
+            let (outcome, error) = self.send_pending_response(&decision).await;
+            match (&outcome, &error) {
+                (AutoResponderDecisionOutcome::Executed, _) => info!(
+                    "Auto responder sent {} for {} by rule {}",
+                    decision.inner.response,
+                    decision.inner.negotiation_agent_process_id,
+                    decision.inner.rule_name
+                ),
+                (AutoResponderDecisionOutcome::Pending, Some(e)) => warn!(
+                    "Auto responder failed to send {} for {}, retrying: {}",
+                    decision.inner.response, decision.inner.negotiation_agent_process_id, e
+                ),
+                (_, e) => error!(
+                    "Auto responder gave up sending {} for {}: {}",
+                    decision.inner.response,
+                    decision.inner.negotiation_agent_process_id,
+                    e.as_deref().unwrap_or_default()
+                ),
+            }
+
+            let edit_model =
+                EditAutoResponderDecisionDto { outcome: Some(outcome.to_string()), error };
+            if let Err(e) =
+                self.decisions_service.put_auto_responder_decision(&decision_id, &edit_model).await
+            {
+                // a decision left pending is tried again next round
+                error!("Auto responder decision {} could not be updated: {}", decision_id, e);
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,93 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+pub(crate) mod auto_responder;
+
+use crate::entities::negotiation_process::NegotiationProcessDto;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Consumer messages after which the provider is the one expected to move the negotiation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AutoResponderTrigger {
+    ContractRequest,
+    Accepted,
+    Verified,
+}
+
+impl Display for AutoResponderTrigger {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AutoResponderTrigger::ContractRequest => write!(f, "ContractRequestMessage"),
+            AutoResponderTrigger::Accepted => write!(f, "ContractNegotiationEventMessage:accepted"),
+            AutoResponderTrigger::Verified => write!(f, "ContractAgreementVerificationMessage"),
+        }
+    }
+}
+
+/// Provider message sent on behalf of a rule
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AutoResponderResponse {
+    Offer,
+    Agreement,
+    Finalized,
+    Termination,
+}
+
+impl Display for AutoResponderResponse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AutoResponderResponse::Offer => write!(f, "ContractOfferMessage"),
+            AutoResponderResponse::Agreement => write!(f, "ContractAgreementMessage"),
+            AutoResponderResponse::Finalized => {
+                write!(f, "ContractNegotiationEventMessage:finalized")
+            }
+            AutoResponderResponse::Termination => {
+                write!(f, "ContractNegotiationTerminationMessage")
+            }
+        }
+    }
+}
+
+impl FromStr for AutoResponderResponse {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ContractOfferMessage" => Ok(AutoResponderResponse::Offer),
+            "ContractAgreementMessage" => Ok(AutoResponderResponse::Agreement),
+            "ContractNegotiationEventMessage:finalized" => Ok(AutoResponderResponse::Finalized),
+            "ContractNegotiationTerminationMessage" => Ok(AutoResponderResponse::Termination),
+            _ => Err(()),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+pub trait AutoResponderTrait: Send + Sync + 'static {
+    /// Evaluates the rules for a provider process that just received `trigger` and stores
+    /// the answer of the first matching one as pending. It never fails the incoming message.
+    async fn on_consumer_message(
+        &self,
+        process: &NegotiationProcessDto,
+        trigger: AutoResponderTrigger,
+    );
+    /// Sends the pending answers, those that keep failing are given up after a while
+    async fn send_pending_responses(&self) -> anyhow::Result<()>;
+}
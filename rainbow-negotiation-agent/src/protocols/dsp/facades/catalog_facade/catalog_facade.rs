@@ -0,0 +1,59 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use crate::protocols::dsp::facades::catalog_facade::CatalogFacadeTrait;
+use rainbow_catalog_agent::OdrlPolicyDto;
+use rainbow_common::config::services::ContractsConfig;
+use rainbow_common::http_client::{HttpClient, HttpClientError};
+use reqwest::StatusCode;
+use std::sync::Arc;
+use urn::Urn;
+use ymir::config::types::HostType;
+
+pub struct CatalogFacadeService {
+    config: Arc<ContractsConfig>,
+    client: Arc<HttpClient>,
+}
+
+impl CatalogFacadeService {
+    pub fn new(config: Arc<ContractsConfig>, client: Arc<HttpClient>) -> Self {
+        Self { config, client }
+    }
+
+    fn catalog_agent_url(&self) -> String {
+        let catalog = self.config.catalog();
+        format!(
+            "{}/api/{}/catalog-agent",
+            catalog.get_host(HostType::Http),
+            catalog.get_api_version()
+        )
+    }
+}
+
+#[async_trait::async_trait]
+impl CatalogFacadeTrait for CatalogFacadeService {
+    async fn get_odrl_offer_by_id(&self, offer_id: &Urn) -> anyhow::Result<Option<OdrlPolicyDto>> {
+        let offer_url = format!("{}/odrl-policies/{}", self.catalog_agent_url(), offer_id);
+        match self.client.get_json::<OdrlPolicyDto>(offer_url.as_str()).await {
+            Ok(offer) => Ok(Some(offer)),
+            Err(HttpClientError::HttpError { status: StatusCode::NOT_FOUND, .. }) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
@@ -17,16 +17,29 @@
  *
  */
 
+use crate::protocols::dsp::facades::catalog_facade::CatalogFacadeTrait;
+use std::sync::Arc;
+
+pub mod catalog_facade;
+
 #[async_trait::async_trait]
-pub trait FacadeTrait: Send + Sync {}
+pub trait FacadeTrait: Send + Sync {
+    async fn get_catalog_facade(&self) -> Arc<dyn CatalogFacadeTrait>;
+}
 
-pub struct FacadeService {}
+pub struct FacadeService {
+    catalog_facade: Arc<dyn CatalogFacadeTrait>,
+}
 
 impl FacadeService {
-    pub fn new() -> FacadeService {
-        Self {}
+    pub fn new(catalog_facade: Arc<dyn CatalogFacadeTrait>) -> FacadeService {
+        Self { catalog_facade }
     }
 }
 
 #[async_trait::async_trait]
-impl FacadeTrait for FacadeService {}
+impl FacadeTrait for FacadeService {
+    async fn get_catalog_facade(&self) -> Arc<dyn CatalogFacadeTrait> {
+        self.catalog_facade.clone()
+    }
+}
@@ -18,6 +18,8 @@
  *
  */
 
+pub(crate) mod agreement_signer;
+pub(crate) mod auto_responder;
 mod errors;
 pub(crate) mod facades;
 pub(crate) mod http;
@@ -27,10 +29,15 @@ pub(crate) mod protocol_types;
 pub(crate) mod validator;
 
 use crate::entities::agreement::NegotiationAgentAgreementsTrait;
+use crate::entities::auto_responder_decision::NegotiationAgentAutoResponderDecisionsTrait;
 use crate::entities::negotiation_message::NegotiationAgentMessagesTrait;
 use crate::entities::negotiation_process::NegotiationAgentProcessesTrait;
 use crate::entities::offer::NegotiationAgentOffersTrait;
+use crate::protocols::dsp::agreement_signer::agreement_signer::AgreementSignerService;
+use crate::protocols::dsp::auto_responder::auto_responder::AutoResponderService;
+use crate::protocols::dsp::auto_responder::AutoResponderTrait;
 use crate::protocols::dsp::facades::FacadeService;
+use crate::protocols::dsp::facades::catalog_facade::catalog_facade::CatalogFacadeService;
 use crate::protocols::dsp::http::protocol::DspRouter;
 use crate::protocols::dsp::http::rpc::RpcRouter;
 use crate::protocols::dsp::orchestrator::orchestrator::OrchestratorService;
@@ -44,20 +51,32 @@ use crate::protocols::dsp::validator::validators::protocol::validate_state_trans
 use crate::protocols::dsp::validator::validators::protocol::validation_dsp_steps::ValidationDspStepsService;
 use crate::protocols::dsp::validator::validators::rpc::validate_state_transition::ValidatedStateTransitionServiceForRcp;
 use crate::protocols::dsp::validator::validators::rpc::validation_rpc_steps::ValidationRpcStepsService;
+use crate::protocols::dsp::validator::validators::validate_offer::ValidateOfferService;
 use crate::protocols::dsp::validator::validators::validate_payload::ValidatePayloadService;
+use crate::protocols::dsp::validator::validators::validate_signature::ValidateSignatureService;
 use crate::protocols::dsp::validator::validators::validation_helpers::ValidationHelperService;
 use crate::protocols::protocol::ProtocolPluginTrait;
 use axum::Router;
+use rainbow_common::auth::ssi::{DEFAULT_PEER_CACHE_TTL, SsiAuthLayer, with_ssi_auth};
 use rainbow_common::config::services::ContractsConfig;
+use rainbow_common::config::traits::CommonConfigTrait;
+use rainbow_common::facades::ssi_auth_facade::mates_facade::MatesFacadeService;
+use rainbow_common::facades::ssi_auth_facade::peer_token_facade::PeerTokenFacadeService;
+use rainbow_common::facades::ssi_auth_facade::ssi_auth_facade::SSIAuthFacadeService;
 use rainbow_common::http_client::HttpClient;
+use rainbow_events::core::notification::notification_publisher::RainbowEventsPublisherTrait;
 use std::sync::Arc;
+use ymir::services::vault::vault_rs::VaultService;
 
 pub struct NegotiationDSP {
     negotiation_agent_process_entities: Arc<dyn NegotiationAgentProcessesTrait>,
     negotiation_agent_message_service: Arc<dyn NegotiationAgentMessagesTrait>,
     negotiation_offer_service: Arc<dyn NegotiationAgentOffersTrait>,
     negotiation_agreement_service: Arc<dyn NegotiationAgentAgreementsTrait>,
+    auto_responder_decision_service: Arc<dyn NegotiationAgentAutoResponderDecisionsTrait>,
+    events_publisher: Arc<dyn RainbowEventsPublisherTrait>,
     config: Arc<ContractsConfig>,
+    vault: Arc<VaultService>,
 }
 
 impl NegotiationDSP {
@@ -66,16 +85,103 @@ impl NegotiationDSP {
         negotiation_agent_message_service: Arc<dyn NegotiationAgentMessagesTrait>,
         negotiation_offer_service: Arc<dyn NegotiationAgentOffersTrait>,
         negotiation_agreement_service: Arc<dyn NegotiationAgentAgreementsTrait>,
+        auto_responder_decision_service: Arc<dyn NegotiationAgentAutoResponderDecisionsTrait>,
+        events_publisher: Arc<dyn RainbowEventsPublisherTrait>,
         config: Arc<ContractsConfig>,
+        vault: Arc<VaultService>,
     ) -> Self {
         Self {
             negotiation_agent_message_service,
             negotiation_agent_process_entities,
             negotiation_offer_service,
             negotiation_agreement_service,
+            auto_responder_decision_service,
+            events_publisher,
             config,
+            vault,
         }
     }
+
+    /// Auto responder for the delivery worker. Answers are decided by the one built along
+    /// the router and handed over through the database.
+    pub fn build_auto_responder(&self) -> anyhow::Result<Arc<dyn AutoResponderTrait>> {
+        let http_client = Arc::new(HttpClient::new(10, 10));
+        let facades = self.build_facades(http_client.clone());
+        let validator_helper = Arc::new(ValidationHelperService::new(
+            self.negotiation_agent_process_entities.clone(),
+        ));
+        let validator_payload = Arc::new(ValidatePayloadService::new(validator_helper.clone()));
+        let rpc_orchestator =
+            self.build_rpc_orchestrator(http_client, validator_helper, validator_payload)?;
+        Ok(self.build_auto_responder_service(rpc_orchestator, facades))
+    }
+
+    fn build_facades(&self, http_client: Arc<HttpClient>) -> Arc<FacadeService> {
+        let catalog_facade = Arc::new(CatalogFacadeService::new(self.config.clone(), http_client));
+        Arc::new(FacadeService::new(catalog_facade))
+    }
+
+    fn build_rpc_orchestrator(
+        &self,
+        http_client: Arc<HttpClient>,
+        validator_helper: Arc<ValidationHelperService>,
+        validator_payload: Arc<ValidatePayloadService>,
+    ) -> anyhow::Result<Arc<RPCOrchestratorService>> {
+        let validator_state_machine_rpc =
+            Arc::new(ValidatedStateTransitionServiceForRcp::new(validator_helper.clone()));
+        let rpc_validator = Arc::new(ValidationRpcStepsService::new(
+            validator_payload,
+            validator_state_machine_rpc,
+            validator_helper,
+        ));
+        let persistence_rpc_service = Arc::new(OrchestrationPersistenceForRpc::new(
+            self.negotiation_agent_process_entities.clone(),
+            self.negotiation_agent_message_service.clone(),
+            self.negotiation_offer_service.clone(),
+            self.negotiation_agreement_service.clone(),
+        ));
+
+        // outbound tokens per peer, straight from the SSI auth service
+        let ssi_auth_config = Arc::new(self.config.ssi_auth());
+        let mates_facade =
+            Arc::new(MatesFacadeService::new(ssi_auth_config.clone(), http_client.clone()));
+        let peer_token_facade = Arc::new(PeerTokenFacadeService::new(
+            ssi_auth_config,
+            http_client.clone(),
+            mates_facade.clone(),
+        ));
+
+        // agreements are signed with this participant's key pair
+        let agreement_signer =
+            Arc::new(AgreementSignerService::new(self.vault.clone(), mates_facade)?);
+
+        Ok(Arc::new(RPCOrchestratorService::new(
+            rpc_validator,
+            persistence_rpc_service,
+            self.config.clone(),
+            http_client,
+            peer_token_facade,
+            self.events_publisher.clone(),
+            agreement_signer,
+        )))
+    }
+
+    /// Pre-approved answers go out through the rpc orchestrator as if sent by a human
+    fn build_auto_responder_service(
+        &self,
+        rpc_orchestator: Arc<RPCOrchestratorService>,
+        facades: Arc<FacadeService>,
+    ) -> Arc<AutoResponderService> {
+        let validator_offer = Arc::new(ValidateOfferService::new(facades.clone()));
+        Arc::new(AutoResponderService::new(
+            self.config.auto_responder_rules(),
+            rpc_orchestator,
+            self.negotiation_agent_process_entities.clone(),
+            facades,
+            validator_offer,
+            self.auto_responder_decision_service.clone(),
+        ))
+    }
 }
 
 #[async_trait::async_trait]
@@ -95,6 +201,9 @@ impl ProtocolPluginTrait for NegotiationDSP {
     async fn build_router(&self) -> anyhow::Result<Router> {
         let http_client = Arc::new(HttpClient::new(10, 10));
 
+        // facades
+        let facades = self.build_facades(http_client.clone());
+
         // Validator
         let validator_helper = Arc::new(ValidationHelperService::new(
             self.negotiation_agent_process_entities.clone(),
@@ -102,16 +211,13 @@ impl ProtocolPluginTrait for NegotiationDSP {
         let validator_payload = Arc::new(ValidatePayloadService::new(validator_helper.clone()));
         let validator_state_machine_dsp =
             Arc::new(ValidatedStateTransitionServiceForDsp::new(validator_helper.clone()));
+        let validator_offer = Arc::new(ValidateOfferService::new(facades.clone()));
+        let validator_signature = Arc::new(ValidateSignatureService::new(http_client.clone()));
         let dsp_validator = Arc::new(ValidationDspStepsService::new(
             validator_payload.clone(),
             validator_state_machine_dsp.clone(),
-            validator_helper.clone(),
-        ));
-        let validator_state_machine_rpc =
-            Arc::new(ValidatedStateTransitionServiceForRcp::new(validator_helper.clone()));
-        let rpc_validator = Arc::new(ValidationRpcStepsService::new(
-            validator_payload.clone(),
-            validator_state_machine_rpc.clone(),
+            validator_offer.clone(),
+            validator_signature.clone(),
             validator_helper.clone(),
         ));
 
@@ -122,39 +228,47 @@ impl ProtocolPluginTrait for NegotiationDSP {
             self.negotiation_offer_service.clone(),
             self.negotiation_agreement_service.clone(),
         ));
-        let persistence_rpc_service = Arc::new(OrchestrationPersistenceForRpc::new(
-            self.negotiation_agent_process_entities.clone(),
-            self.negotiation_agent_message_service.clone(),
-            self.negotiation_offer_service.clone(),
-            self.negotiation_agreement_service.clone(),
-        ));
-
-        // facades
-        let facades = Arc::new(FacadeService::new());
 
         // orchestrators
+        let rpc_orchestator = self.build_rpc_orchestrator(
+            http_client.clone(),
+            validator_helper.clone(),
+            validator_payload.clone(),
+        )?;
+        // answers are only decided here, the auto responder delivery worker sends them
+        let auto_responder =
+            self.build_auto_responder_service(rpc_orchestator.clone(), facades.clone());
         let http_orchestator = Arc::new(ProtocolOrchestratorService::new(
             dsp_validator.clone(),
             persistence_protocol_service.clone(),
             facades.clone(),
+            auto_responder,
+            self.events_publisher.clone(),
             self.config.clone(),
         ));
-        let rpc_orchestator = Arc::new(RPCOrchestratorService::new(
-            rpc_validator.clone(),
-            persistence_rpc_service,
-            self.config.clone(),
-            http_client.clone(),
-        ));
         let orchestrator_service = Arc::new(OrchestratorService::new(
             http_orchestator.clone(),
             rpc_orchestator.clone(),
         ));
 
+        // every DSP message must come from a peer known to the SSI service
+        let ssi_auth_config = Arc::new(self.config.ssi_auth());
+        let ssi_auth_facade =
+            Arc::new(SSIAuthFacadeService::new(ssi_auth_config, http_client.clone()));
+        let ssi_auth_layer =
+            SsiAuthLayer::new(ssi_auth_facade, DEFAULT_PEER_CACHE_TTL, "ContractNegotiationError");
+
         // router
         let dsp_router = DspRouter::new(orchestrator_service.clone(), self.config.clone());
         let rcp_router = RpcRouter::new(orchestrator_service.clone(), self.config.clone());
 
-        Ok(Router::new().merge(dsp_router.router()).merge(rcp_router.router()))
+        let dsp_router = with_ssi_auth(
+            dsp_router.router(),
+            ssi_auth_layer,
+            self.config.common().verify_ssi_tokens(),
+        );
+
+        Ok(Router::new().merge(dsp_router).merge(rcp_router.router()))
     }
 
     fn build_grpc_router(&self) -> anyhow::Result<Option<Router>> {
@@ -1,5 +1,6 @@
 use crate::entities::agreement::{
-    AgreementDto, EditAgreementDto, NegotiationAgentAgreementsTrait, NewAgreementDto,
+    AgreementDto, AgreementSignatureDto, EditAgreementDto, NegotiationAgentAgreementsTrait,
+    NewAgreementDto,
 };
 use crate::entities::negotiation_message::{
     NegotiationAgentMessagesTrait, NegotiationMessageDto, NewNegotiationMessageDto,
@@ -17,6 +18,7 @@ use crate::protocols::dsp::protocol_types::{
 };
 use anyhow::bail;
 use async_trait::async_trait;
+use rainbow_common::auth::ssi::current_participant_id;
 use rainbow_common::config::types::roles::RoleConfig;
 use rainbow_common::dsp_common::odrl::ContractRequestMessageOfferTypes;
 use rainbow_common::errors::{CommonErrors, ErrorLog};
@@ -147,6 +149,30 @@ impl OrchestrationExtractors for OrchestrationPersistenceForProtocol {
 }
 
 impl OrchestrationPersistenceForProtocol {
+    /// Attaches a party's signature to the agreement of the process
+    pub async fn update_agreement_signature(
+        &self,
+        mut process: NegotiationProcessDto,
+        role: &RoleConfig,
+        signature: &AgreementSignatureDto,
+    ) -> anyhow::Result<NegotiationProcessDto> {
+        let agreement_id = match process.agreement.as_ref() {
+            Some(agreement) => self.convert_str_to_urn(agreement.id.as_str())?,
+            None => {
+                let err = CommonErrors::missing_resource_new(
+                    process.inner.id.as_str(),
+                    "Agreement not found",
+                );
+                error!("{}", err.log());
+                bail!(err);
+            }
+        };
+        let agreement =
+            self.agreement_service.put_agreement_signature(&agreement_id, role, signature).await?;
+        process.agreement = Some(agreement.inner);
+        Ok(process)
+    }
+
     pub async fn fetch_process(&self, id: &str) -> anyhow::Result<NegotiationProcessDto> {
         let urn = self.convert_str_to_urn(id)?;
         let process = self
@@ -156,6 +182,17 @@ impl OrchestrationPersistenceForProtocol {
             .ok_or_else(|| {
                 CommonErrors::missing_resource_new(urn.to_string().as_str(), "Process not found")
             })?;
+        // processes opened by a verified peer only accept messages from that same peer
+        if let Some(participant_id) = current_participant_id() {
+            let peer = &process.inner.associated_agent_peer;
+            if !peer.is_empty() && *peer != participant_id {
+                let err = CommonErrors::forbidden_new(
+                    format!("Process {} belongs to another participant", urn).as_str(),
+                );
+                error!("{}", err.log());
+                bail!(err);
+            }
+        }
         Ok(process)
     }
 
@@ -207,7 +244,7 @@ impl OrchestrationPersistenceForProtocol {
                 id: Some(id),
                 state: state.to_string(),
                 state_attribute: None, // O el valor por defecto que corresponda
-                associated_agent_peer: "".to_string(), // O extraer del mensaje si existe
+                associated_agent_peer: current_participant_id().unwrap_or_default(),
                 protocol: "DSP".to_string(),
                 callback_address: Some(callback),
                 role: role.to_string(),
@@ -291,8 +328,8 @@ impl OrchestrationPersistenceForProtocol {
                 id: Some(id),
                 negotiation_agent_process_id: pid.clone(),
                 negotiation_agent_message_id: mid.clone(),
-                consumer_participant_id: peer.to_string(),
-                provider_participant_id: "".to_string(),
+                consumer_participant_id: "".to_string(),
+                provider_participant_id: peer.to_string(),
                 agreement_content: serde_json::to_value(agreement).unwrap(),
                 target,
             })
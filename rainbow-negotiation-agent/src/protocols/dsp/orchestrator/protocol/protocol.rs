@@ -17,9 +17,11 @@
  *
  */
 
+use crate::protocols::dsp::auto_responder::{AutoResponderTrait, AutoResponderTrigger};
 use crate::protocols::dsp::facades::FacadeTrait;
 use crate::protocols::dsp::orchestrator::protocol::ProtocolOrchestratorTrait;
 use crate::protocols::dsp::orchestrator::protocol::persistence::OrchestrationPersistenceForProtocol;
+use crate::protocols::dsp::orchestrator::traits::orchestration_notifications::OrchestrationNotifications;
 use crate::protocols::dsp::persistence::NegotiationPersistenceTrait;
 use crate::protocols::dsp::protocol_types::{
     NegotiationAckMessageDto, NegotiationAgreementMessageDto, NegotiationEventMessageDto,
@@ -30,12 +32,19 @@ use crate::protocols::dsp::protocol_types::{
 };
 use crate::protocols::dsp::validator::traits::validation_dsp_steps::ValidationDspSteps;
 use rainbow_common::config::services::ContractsConfig;
+use rainbow_common::config::types::roles::RoleConfig;
+use rainbow_events::core::notification::notification_publisher::RainbowEventsPublisherTrait;
+use rainbow_events::core::notification::notification_types::{
+    RainbowEventsNotificationMessageOperation, RainbowEventsNotificationMessageTypes,
+};
 use std::sync::Arc;
 
 pub struct ProtocolOrchestratorService {
     facades: Arc<dyn FacadeTrait>,
     validator: Arc<dyn ValidationDspSteps>,
     persistence_service: Arc<OrchestrationPersistenceForProtocol>,
+    auto_responder: Arc<dyn AutoResponderTrait>,
+    events: Arc<dyn RainbowEventsPublisherTrait>,
     _config: Arc<ContractsConfig>,
 }
 
@@ -44,9 +53,24 @@ impl ProtocolOrchestratorService {
         validator: Arc<dyn ValidationDspSteps>,
         persistence_service: Arc<OrchestrationPersistenceForProtocol>,
         facades: Arc<dyn FacadeTrait>,
+        auto_responder: Arc<dyn AutoResponderTrait>,
+        events: Arc<dyn RainbowEventsPublisherTrait>,
         _config: Arc<ContractsConfig>,
     ) -> ProtocolOrchestratorService {
-        ProtocolOrchestratorService { validator, persistence_service, _config, facades }
+        ProtocolOrchestratorService {
+            validator,
+            persistence_service,
+            auto_responder,
+            events,
+            _config,
+            facades,
+        }
+    }
+}
+
+impl OrchestrationNotifications for ProtocolOrchestratorService {
+    fn get_events_publisher(&self) -> Arc<dyn RainbowEventsPublisherTrait> {
+        self.events.clone()
     }
 }
 
@@ -70,6 +94,16 @@ impl ProtocolOrchestratorTrait for ProtocolOrchestratorService {
         // persist
         let negotiation = self.persistence_service.create_new(&input.dto).await?;
         // notify
+        self.notify_process_transition(
+            &negotiation,
+            &input._type,
+            RainbowEventsNotificationMessageTypes::DSProtocolMessage,
+            RainbowEventsNotificationMessageOperation::IncomingMessage,
+        );
+        // auto respond
+        self.auto_responder
+            .on_consumer_message(&negotiation, AutoResponderTrigger::ContractRequest)
+            .await;
         let negotiation_process_dto = NegotiationProcessMessageWrapper::try_from(negotiation)?;
         Ok((negotiation_process_dto, false))
     }
@@ -85,6 +119,16 @@ impl ProtocolOrchestratorTrait for ProtocolOrchestratorService {
         let negotiation =
             self.persistence_service.update_with_offer(id.as_str(), &input.dto).await?;
         // notify
+        self.notify_process_transition(
+            &negotiation,
+            &input._type,
+            RainbowEventsNotificationMessageTypes::DSProtocolMessage,
+            RainbowEventsNotificationMessageOperation::IncomingMessage,
+        );
+        // auto respond
+        self.auto_responder
+            .on_consumer_message(&negotiation, AutoResponderTrigger::ContractRequest)
+            .await;
         let negotiation_process_dto = NegotiationProcessMessageWrapper::try_from(negotiation)?;
         Ok(negotiation_process_dto)
     }
@@ -97,8 +141,22 @@ impl ProtocolOrchestratorTrait for ProtocolOrchestratorService {
         self.validator.on_contract_agreement_verification(&id, &input).await?;
 
         // persist
-        let negotiation = self.persistence_service.update(id.as_str(), &input.dto).await?;
+        let mut negotiation = self.persistence_service.update(id.as_str(), &input.dto).await?;
+        if let Some(signature) = input.dto.signature.as_ref() {
+            negotiation = self
+                .persistence_service
+                .update_agreement_signature(negotiation, &RoleConfig::Consumer, signature)
+                .await?;
+        }
         // notify
+        self.notify_process_transition(
+            &negotiation,
+            &input._type,
+            RainbowEventsNotificationMessageTypes::DSProtocolMessage,
+            RainbowEventsNotificationMessageOperation::IncomingMessage,
+        );
+        // auto respond
+        self.auto_responder.on_consumer_message(&negotiation, AutoResponderTrigger::Verified).await;
         let negotiation_process_dto = NegotiationProcessMessageWrapper::try_from(negotiation)?;
         Ok(negotiation_process_dto)
     }
@@ -112,6 +170,12 @@ impl ProtocolOrchestratorTrait for ProtocolOrchestratorService {
         // persist
         let negotiation = self.persistence_service.create_new(&input.dto).await?;
         // notify
+        self.notify_process_transition(
+            &negotiation,
+            &input._type,
+            RainbowEventsNotificationMessageTypes::DSProtocolMessage,
+            RainbowEventsNotificationMessageOperation::IncomingMessage,
+        );
         let negotiation_process_dto = NegotiationProcessMessageWrapper::try_from(negotiation)?;
         Ok((negotiation_process_dto, false))
     }
@@ -127,6 +191,12 @@ impl ProtocolOrchestratorTrait for ProtocolOrchestratorService {
         let negotiation =
             self.persistence_service.update_with_offer(id.as_str(), &input.dto).await?;
         // notify
+        self.notify_process_transition(
+            &negotiation,
+            &input._type,
+            RainbowEventsNotificationMessageTypes::DSProtocolMessage,
+            RainbowEventsNotificationMessageOperation::IncomingMessage,
+        );
         let negotiation_process_dto = NegotiationProcessMessageWrapper::try_from(negotiation)?;
         Ok(negotiation_process_dto)
     }
@@ -139,9 +209,25 @@ impl ProtocolOrchestratorTrait for ProtocolOrchestratorService {
         self.validator.on_contract_agreement(id, &input).await?;
 
         // persist
-        let negotiation =
+        let mut negotiation =
             self.persistence_service.update_with_new_agreement(id.as_str(), &input.dto).await?;
+        if let Some(signature) = input.dto.signature.as_ref() {
+            negotiation = self
+                .persistence_service
+                .update_agreement_signature(negotiation, &RoleConfig::Provider, signature)
+                .await?;
+        }
         // notify
+        self.notify_process_transition(
+            &negotiation,
+            &input._type,
+            RainbowEventsNotificationMessageTypes::DSProtocolMessage,
+            RainbowEventsNotificationMessageOperation::IncomingMessage,
+        );
+        self.notify_agreement_creation(
+            &negotiation,
+            RainbowEventsNotificationMessageTypes::DSProtocolMessage,
+        );
         let negotiation_process_dto = NegotiationProcessMessageWrapper::try_from(negotiation)?;
         Ok(negotiation_process_dto)
     }
@@ -163,6 +249,18 @@ impl ProtocolOrchestratorTrait for ProtocolOrchestratorService {
             }
         };
         // notify
+        self.notify_process_transition(
+            &negotiation,
+            &input._type,
+            RainbowEventsNotificationMessageTypes::DSProtocolMessage,
+            RainbowEventsNotificationMessageOperation::IncomingMessage,
+        );
+        // auto respond
+        if input.dto.event_type == NegotiationEventType::ACCEPTED {
+            self.auto_responder
+                .on_consumer_message(&negotiation, AutoResponderTrigger::Accepted)
+                .await;
+        }
         let negotiation_process_dto = NegotiationProcessMessageWrapper::try_from(negotiation)?;
         Ok(negotiation_process_dto)
     }
@@ -177,6 +275,12 @@ impl ProtocolOrchestratorTrait for ProtocolOrchestratorService {
         // persist
         let negotiation = self.persistence_service.update(id.as_str(), &input.dto).await?;
         // notify
+        self.notify_process_transition(
+            &negotiation,
+            &input._type,
+            RainbowEventsNotificationMessageTypes::DSProtocolMessage,
+            RainbowEventsNotificationMessageOperation::IncomingMessage,
+        );
         let negotiation_process_dto = NegotiationProcessMessageWrapper::try_from(negotiation)?;
         Ok(negotiation_process_dto)
     }
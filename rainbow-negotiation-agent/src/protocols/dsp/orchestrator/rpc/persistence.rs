@@ -1,5 +1,6 @@
 use crate::entities::agreement::{
-    AgreementDto, EditAgreementDto, NegotiationAgentAgreementsTrait, NewAgreementDto,
+    AgreementDto, AgreementSignatureDto, EditAgreementDto, NegotiationAgentAgreementsTrait,
+    NewAgreementDto,
 };
 use crate::entities::negotiation_message::{
     NegotiationAgentMessagesTrait, NegotiationMessageDto, NewNegotiationMessageDto,
@@ -139,6 +140,30 @@ impl OrchestrationPersistenceForRpc {
 }
 
 impl OrchestrationPersistenceForRpc {
+    /// Attaches a party's signature to the agreement of the process
+    pub async fn update_agreement_signature(
+        &self,
+        mut process: NegotiationProcessDto,
+        role: &RoleConfig,
+        signature: &AgreementSignatureDto,
+    ) -> anyhow::Result<NegotiationProcessDto> {
+        let agreement_id = match process.agreement.as_ref() {
+            Some(agreement) => self.convert_str_to_urn(agreement.id.as_str())?,
+            None => {
+                let err = CommonErrors::missing_resource_new(
+                    process.inner.id.as_str(),
+                    "Agreement not found",
+                );
+                error!("{}", err.log());
+                bail!(err);
+            }
+        };
+        let agreement =
+            self.agreement_service.put_agreement_signature(&agreement_id, role, signature).await?;
+        process.agreement = Some(agreement.inner);
+        Ok(process)
+    }
+
     pub async fn fetch_process(&self, id: &str) -> anyhow::Result<NegotiationProcessDto> {
         let urn = self.convert_str_to_urn(id)?;
         let process = self
@@ -220,7 +245,7 @@ impl OrchestrationPersistenceForRpc {
                 id: Some(id),
                 state: state.to_string(),
                 state_attribute: None,
-                associated_agent_peer: "".to_string(),
+                associated_agent_peer: message.get_associated_agent_peer().unwrap_or_default(),
                 protocol: "DSP".to_string(),
                 callback_address: Some(callback),
                 role: role.to_string(),
@@ -303,8 +328,8 @@ impl OrchestrationPersistenceForRpc {
                 id: Some(id),
                 negotiation_agent_process_id: pid.clone(),
                 negotiation_agent_message_id: mid.clone(),
-                consumer_participant_id: "".to_string(),
-                provider_participant_id: peer.to_string(),
+                consumer_participant_id: peer.to_string(),
+                provider_participant_id: "".to_string(),
                 agreement_content: serde_json::to_value(agreement).unwrap(),
                 target,
             })
@@ -18,6 +18,7 @@
  */
 
 use crate::entities::negotiation_process::NegotiationProcessDto;
+use crate::protocols::dsp::agreement_signer::AgreementSignerTrait;
 use crate::protocols::dsp::orchestrator::rpc::RPCOrchestratorTrait;
 use crate::protocols::dsp::orchestrator::rpc::persistence::OrchestrationPersistenceForRpc;
 use crate::protocols::dsp::orchestrator::rpc::types::{
@@ -29,6 +30,7 @@ use crate::protocols::dsp::orchestrator::rpc::types::{
     RpcNegotiationVerificationMessageDto,
 };
 use crate::protocols::dsp::orchestrator::traits::orchestration_helpers::OrchestrationHelpers;
+use crate::protocols::dsp::orchestrator::traits::orchestration_notifications::OrchestrationNotifications;
 use crate::protocols::dsp::persistence::NegotiationPersistenceTrait;
 use crate::protocols::dsp::protocol_types::{
     NegotiationAckMessageDto, NegotiationAgreementMessageDto, NegotiationEventMessageDto,
@@ -38,13 +40,23 @@ use crate::protocols::dsp::protocol_types::{
     NegotiationTerminationMessageDto, NegotiationVerificationMessageDto,
 };
 use crate::protocols::dsp::validator::traits::validation_rpc_steps::ValidationRpcSteps;
+use anyhow::bail;
 use rainbow_common::config::services::ContractsConfig;
 use rainbow_common::config::types::roles::RoleConfig;
 use rainbow_common::dsp_common::context_field::ContextField;
 use rainbow_common::dsp_common::odrl::{OdrlAgreement, OdrlMessageOffer, OdrlTypes};
-use rainbow_common::http_client::HttpClient;
+use rainbow_common::errors::{CommonErrors, ErrorLog};
+use rainbow_common::facades::ssi_auth_facade::{PeerTokenFacadeTrait, send_with_peer_token};
+use rainbow_common::http_client::{HttpClient, RequestOptions};
+use rainbow_events::core::notification::notification_publisher::RainbowEventsPublisherTrait;
+use rainbow_events::core::notification::notification_types::{
+    RainbowEventsNotificationMessageOperation, RainbowEventsNotificationMessageTypes,
+};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
 use std::str::FromStr;
 use std::sync::Arc;
+use tracing::error;
 use urn::Urn;
 
 #[allow(unused)]
@@ -53,6 +65,9 @@ pub struct RPCOrchestratorService {
     persistence_service: Arc<OrchestrationPersistenceForRpc>,
     _config: Arc<ContractsConfig>,
     http_client: Arc<HttpClient>,
+    peer_token_facade: Arc<dyn PeerTokenFacadeTrait>,
+    events: Arc<dyn RainbowEventsPublisherTrait>,
+    agreement_signer: Arc<dyn AgreementSignerTrait>,
 }
 
 impl RPCOrchestratorService {
@@ -61,13 +76,47 @@ impl RPCOrchestratorService {
         persistence_service: Arc<OrchestrationPersistenceForRpc>,
         _config: Arc<ContractsConfig>,
         http_client: Arc<HttpClient>,
+        peer_token_facade: Arc<dyn PeerTokenFacadeTrait>,
+        events: Arc<dyn RainbowEventsPublisherTrait>,
+        agreement_signer: Arc<dyn AgreementSignerTrait>,
     ) -> RPCOrchestratorService {
-        RPCOrchestratorService { validator, persistence_service, _config, http_client }
+        RPCOrchestratorService {
+            validator,
+            persistence_service,
+            _config,
+            http_client,
+            peer_token_facade,
+            events,
+            agreement_signer,
+        }
+    }
+
+    /// Posts a DSP message with the peer's own token, onboarding again once if it is rejected
+    async fn post_to_peer<T, R>(&self, peer: &str, url: &str, body: &T) -> anyhow::Result<R>
+    where
+        T: Serialize + Sync,
+        R: DeserializeOwned,
+    {
+        send_with_peer_token(
+            self.peer_token_facade.as_ref(),
+            peer,
+            RequestOptions::new(),
+            |options| async move {
+                self.http_client.post_json_with_options(url, body, &options).await
+            },
+        )
+        .await
     }
 }
 
 impl OrchestrationHelpers for RPCOrchestratorService {}
 
+impl OrchestrationNotifications for RPCOrchestratorService {
+    fn get_events_publisher(&self) -> Arc<dyn RainbowEventsPublisherTrait> {
+        self.events.clone()
+    }
+}
+
 #[async_trait::async_trait]
 impl RPCOrchestratorTrait for RPCOrchestratorService {
     async fn setup_negotiation_request_init_rpc(
@@ -78,17 +127,25 @@ impl RPCOrchestratorTrait for RPCOrchestratorService {
 
         // send to peer
         let provider_address = self.get_rpc_provider_address_safely(input)?;
+        let peer = input.get_associated_agent_peer().unwrap_or_default();
         let peer_url = format!("{}/negotiations/request", provider_address);
         let request_body: NegotiationProcessMessageWrapper<NegotiationRequestInitMessageDto> =
             input.clone().into();
-        self.http_client.set_auth_token("blabla".to_string()).await;
         let response: NegotiationProcessMessageWrapper<NegotiationAckMessageDto> =
-            self.http_client.post_json(peer_url.as_str(), &request_body).await?;
+            self.post_to_peer(peer.as_str(), peer_url.as_str(), &request_body).await?;
 
         // persist
         let negotiation_process =
             self.persistence_service.create_new(input, &request_body.dto, &response.dto).await?;
 
+        // notify
+        self.notify_process_transition(
+            &negotiation_process,
+            &request_body._type,
+            RainbowEventsNotificationMessageTypes::RPCMessage,
+            RainbowEventsNotificationMessageOperation::OutgoingMessage,
+        );
+
         let response = RpcNegotiationMessageDto {
             request: input.clone(),
             response,
@@ -110,14 +167,14 @@ impl RPCOrchestratorTrait for RPCOrchestratorService {
         let role_identifier = self.parse_role_into_identifier(&role)?.to_string();
         let identifier = current_process.identifiers.get(&role_identifier).unwrap();
         let peer_address = current_process.inner.callback_address.unwrap();
+        let peer = current_process.inner.associated_agent_peer.clone();
 
         // send to peer
         let peer_url = format!("{}/negotiations/{}/request", peer_address, identifier);
         let request_body: NegotiationProcessMessageWrapper<NegotiationRequestMessageDto> =
             input.clone().into();
-        self.http_client.set_auth_token("blabla".to_string()).await;
         let response: NegotiationProcessMessageWrapper<NegotiationAckMessageDto> =
-            self.http_client.post_json(peer_url.as_str(), &request_body).await?;
+            self.post_to_peer(peer.as_str(), peer_url.as_str(), &request_body).await?;
 
         // persist
         let negotiation_process = self
@@ -125,6 +182,14 @@ impl RPCOrchestratorTrait for RPCOrchestratorService {
             .update_with_offer(id.as_str(), input, &request_body.dto, &response.dto)
             .await?;
 
+        // notify
+        self.notify_process_transition(
+            &negotiation_process,
+            &request_body._type,
+            RainbowEventsNotificationMessageTypes::RPCMessage,
+            RainbowEventsNotificationMessageOperation::OutgoingMessage,
+        );
+
         let response = RpcNegotiationMessageDto {
             request: input.clone(),
             response,
@@ -141,17 +206,25 @@ impl RPCOrchestratorTrait for RPCOrchestratorService {
 
         // send to peer
         let provider_address = self.get_rpc_provider_address_safely(input)?;
+        let peer = input.get_associated_agent_peer().unwrap_or_default();
         let peer_url = format!("{}/negotiations/offers", provider_address);
         let request_body: NegotiationProcessMessageWrapper<NegotiationOfferInitMessageDto> =
             input.clone().into();
-        self.http_client.set_auth_token("blabla".to_string()).await;
         let response: NegotiationProcessMessageWrapper<NegotiationAckMessageDto> =
-            self.http_client.post_json(peer_url.as_str(), &request_body).await?;
+            self.post_to_peer(peer.as_str(), peer_url.as_str(), &request_body).await?;
 
         // persist
         let negotiation_process =
             self.persistence_service.create_new(input, &request_body.dto, &response.dto).await?;
 
+        // notify
+        self.notify_process_transition(
+            &negotiation_process,
+            &request_body._type,
+            RainbowEventsNotificationMessageTypes::RPCMessage,
+            RainbowEventsNotificationMessageOperation::OutgoingMessage,
+        );
+
         let response = RpcNegotiationMessageDto {
             request: input.clone(),
             response,
@@ -173,14 +246,14 @@ impl RPCOrchestratorTrait for RPCOrchestratorService {
         let role_identifier = self.parse_role_into_identifier(&role)?.to_string();
         let identifier = current_process.identifiers.get(&role_identifier).unwrap();
         let peer_address = current_process.inner.callback_address.unwrap();
+        let peer = current_process.inner.associated_agent_peer.clone();
 
         // send to peer
         let peer_url = format!("{}/negotiations/{}/offers", peer_address, identifier);
         let request_body: NegotiationProcessMessageWrapper<NegotiationOfferMessageDto> =
             input.clone().into();
-        self.http_client.set_auth_token("blabla".to_string()).await;
         let response: NegotiationProcessMessageWrapper<NegotiationAckMessageDto> =
-            self.http_client.post_json(peer_url.as_str(), &request_body).await?;
+            self.post_to_peer(peer.as_str(), peer_url.as_str(), &request_body).await?;
 
         // persist
         let negotiation_process = self
@@ -188,6 +261,14 @@ impl RPCOrchestratorTrait for RPCOrchestratorService {
             .update_with_offer(id.as_str(), input, &request_body.dto, &response.dto)
             .await?;
 
+        // notify
+        self.notify_process_transition(
+            &negotiation_process,
+            &request_body._type,
+            RainbowEventsNotificationMessageTypes::RPCMessage,
+            RainbowEventsNotificationMessageOperation::OutgoingMessage,
+        );
+
         let response = RpcNegotiationMessageDto {
             request: input.clone(),
             response,
@@ -208,6 +289,7 @@ impl RPCOrchestratorTrait for RPCOrchestratorService {
         let role_identifier = self.parse_role_into_identifier(&role)?.to_string();
         let identifier = current_process.identifiers.get(&role_identifier).unwrap();
         let peer_address = current_process.inner.callback_address.unwrap();
+        let peer = current_process.inner.associated_agent_peer.clone();
 
         // get last offer
         let last_offer = self
@@ -232,15 +314,35 @@ impl RPCOrchestratorTrait for RPCOrchestratorService {
             timestamp: Some(chrono::Utc::now().timestamp().to_string()),
             prohibition: offer.prohibition,
         };
-        self.http_client.set_auth_token("blabla".to_string()).await;
+        let signature = self
+            .agreement_signer
+            .sign_agreement(&serde_json::to_value(&request_body.dto.agreement)?)
+            .await?;
+        request_body.dto.signature = Some(signature.clone());
         let response: NegotiationProcessMessageWrapper<NegotiationAckMessageDto> =
-            self.http_client.post_json(peer_url.as_str(), &request_body).await?;
+            self.post_to_peer(peer.as_str(), peer_url.as_str(), &request_body).await?;
 
         // persist
         let negotiation_process = self
             .persistence_service
             .update_with_new_agreement(id.as_str(), input, &request_body.dto, &response.dto)
             .await?;
+        let negotiation_process = self
+            .persistence_service
+            .update_agreement_signature(negotiation_process, &RoleConfig::Provider, &signature)
+            .await?;
+        self.notify_agreement_creation(
+            &negotiation_process,
+            RainbowEventsNotificationMessageTypes::RPCMessage,
+        );
+
+        // notify
+        self.notify_process_transition(
+            &negotiation_process,
+            &request_body._type,
+            RainbowEventsNotificationMessageTypes::RPCMessage,
+            RainbowEventsNotificationMessageOperation::OutgoingMessage,
+        );
 
         let response = RpcNegotiationMessageDto {
             request: input.clone(),
@@ -263,21 +365,48 @@ impl RPCOrchestratorTrait for RPCOrchestratorService {
         let role_identifier = self.parse_role_into_identifier(&role)?.to_string();
         let identifier = current_process.identifiers.get(&role_identifier).unwrap();
         let peer_address = current_process.inner.callback_address.unwrap();
+        let peer = current_process.inner.associated_agent_peer.clone();
+
+        // sign the agreement received from the provider
+        let agreement = match current_process.agreement.as_ref() {
+            Some(agreement) => &agreement.agreement_content,
+            None => {
+                let err = CommonErrors::missing_resource_new(
+                    current_process.inner.id.as_str(),
+                    "Agreement not found for this process",
+                );
+                error!("{}", err.log());
+                bail!(err);
+            }
+        };
+        let signature = self.agreement_signer.sign_agreement(agreement).await?;
 
         // send to peer
         let peer_url =
             format!("{}/negotiations/{}/agreement/verification", peer_address, identifier);
-        let request_body: NegotiationProcessMessageWrapper<NegotiationVerificationMessageDto> =
+        let mut request_body: NegotiationProcessMessageWrapper<NegotiationVerificationMessageDto> =
             input.clone().into();
-        self.http_client.set_auth_token("blabla".to_string()).await;
+        request_body.dto.signature = Some(signature.clone());
         let response: NegotiationProcessMessageWrapper<NegotiationAckMessageDto> =
-            self.http_client.post_json(peer_url.as_str(), &request_body).await?;
+            self.post_to_peer(peer.as_str(), peer_url.as_str(), &request_body).await?;
 
         // persist
         let negotiation_process = self
             .persistence_service
             .update_with_agreement(id.as_str(), input, &request_body.dto, &response.dto)
             .await?;
+        let negotiation_process = self
+            .persistence_service
+            .update_agreement_signature(negotiation_process, &RoleConfig::Consumer, &signature)
+            .await?;
+
+        // notify
+        self.notify_process_transition(
+            &negotiation_process,
+            &request_body._type,
+            RainbowEventsNotificationMessageTypes::RPCMessage,
+            RainbowEventsNotificationMessageOperation::OutgoingMessage,
+        );
 
         let response = RpcNegotiationMessageDto {
             request: input.clone(),
@@ -300,14 +429,14 @@ impl RPCOrchestratorTrait for RPCOrchestratorService {
         let role_identifier = self.parse_role_into_identifier(&role)?.to_string();
         let identifier = current_process.identifiers.get(&role_identifier).unwrap();
         let peer_address = current_process.inner.callback_address.unwrap();
+        let peer = current_process.inner.associated_agent_peer.clone();
 
         // send to peer
         let peer_url = format!("{}/negotiations/{}/events", peer_address, identifier);
         let request_body: NegotiationProcessMessageWrapper<NegotiationEventMessageDto> =
             input.clone().into();
-        self.http_client.set_auth_token("blabla".to_string()).await;
         let response: NegotiationProcessMessageWrapper<NegotiationAckMessageDto> =
-            self.http_client.post_json(peer_url.as_str(), &request_body).await?;
+            self.post_to_peer(peer.as_str(), peer_url.as_str(), &request_body).await?;
 
         // persist
         let negotiation_process = self
@@ -315,6 +444,14 @@ impl RPCOrchestratorTrait for RPCOrchestratorService {
             .update(id.as_str(), input, &request_body.dto, &response.dto)
             .await?;
 
+        // notify
+        self.notify_process_transition(
+            &negotiation_process,
+            &request_body._type,
+            RainbowEventsNotificationMessageTypes::RPCMessage,
+            RainbowEventsNotificationMessageOperation::OutgoingMessage,
+        );
+
         let response = RpcNegotiationMessageDto {
             request: input.clone(),
             response,
@@ -336,14 +473,14 @@ impl RPCOrchestratorTrait for RPCOrchestratorService {
         let role_identifier = self.parse_role_into_identifier(&role)?.to_string();
         let identifier = current_process.identifiers.get(&role_identifier).unwrap();
         let peer_address = current_process.inner.callback_address.unwrap();
+        let peer = current_process.inner.associated_agent_peer.clone();
 
         // send to peer
         let peer_url = format!("{}/negotiations/{}/events", peer_address, identifier);
         let request_body: NegotiationProcessMessageWrapper<NegotiationEventMessageDto> =
             input.clone().into();
-        self.http_client.set_auth_token("blabla".to_string()).await;
         let response: NegotiationProcessMessageWrapper<NegotiationAckMessageDto> =
-            self.http_client.post_json(peer_url.as_str(), &request_body).await?;
+            self.post_to_peer(peer.as_str(), peer_url.as_str(), &request_body).await?;
 
         // persist
         let negotiation_process = self
@@ -351,6 +488,14 @@ impl RPCOrchestratorTrait for RPCOrchestratorService {
             .update_with_agreement(id.as_str(), input, &request_body.dto, &response.dto)
             .await?;
 
+        // notify
+        self.notify_process_transition(
+            &negotiation_process,
+            &request_body._type,
+            RainbowEventsNotificationMessageTypes::RPCMessage,
+            RainbowEventsNotificationMessageOperation::OutgoingMessage,
+        );
+
         let response = RpcNegotiationMessageDto {
             request: input.clone(),
             response,
@@ -372,14 +517,14 @@ impl RPCOrchestratorTrait for RPCOrchestratorService {
         let role_identifier = self.parse_role_into_identifier(&role)?.to_string();
         let identifier = current_process.identifiers.get(&role_identifier).unwrap();
         let peer_address = current_process.inner.callback_address.unwrap();
+        let peer = current_process.inner.associated_agent_peer.clone();
 
         // send to peer
         let peer_url = format!("{}/negotiations/{}/termination", peer_address, identifier);
         let request_body: NegotiationProcessMessageWrapper<NegotiationTerminationMessageDto> =
             input.clone().into();
-        self.http_client.set_auth_token("blabla".to_string()).await;
         let response: NegotiationProcessMessageWrapper<NegotiationAckMessageDto> =
-            self.http_client.post_json(peer_url.as_str(), &request_body).await?;
+            self.post_to_peer(peer.as_str(), peer_url.as_str(), &request_body).await?;
 
         // persist
         let negotiation_process = self
@@ -387,6 +532,14 @@ impl RPCOrchestratorTrait for RPCOrchestratorService {
             .update(id.as_str(), input, &request_body.dto, &response.dto)
             .await?;
 
+        // notify
+        self.notify_process_transition(
+            &negotiation_process,
+            &request_body._type,
+            RainbowEventsNotificationMessageTypes::RPCMessage,
+            RainbowEventsNotificationMessageOperation::OutgoingMessage,
+        );
+
         let response = RpcNegotiationMessageDto {
             request: input.clone(),
             response,
@@ -278,9 +278,9 @@ impl RpcNegotiationProcessMessageTrait for RpcNegotiationOfferInitMessageDto {
 #[serde(rename_all = "camelCase")]
 #[serde(deny_unknown_fields)]
 pub struct RpcNegotiationOfferMessageDto {
-    offer: ContractRequestMessageOfferTypes,
-    provider_pid: Urn,
-    consumer_pid: Urn,
+    pub offer: ContractRequestMessageOfferTypes,
+    pub provider_pid: Urn,
+    pub consumer_pid: Urn,
 }
 
 impl Into<NegotiationProcessMessageWrapper<NegotiationOfferMessageDto>>
@@ -353,8 +353,8 @@ impl RpcNegotiationProcessMessageTrait for RpcNegotiationOfferMessageDto {
 #[serde(rename_all = "camelCase")]
 #[serde(deny_unknown_fields)]
 pub struct RpcNegotiationAgreementMessageDto {
-    provider_pid: Urn,
-    consumer_pid: Urn,
+    pub provider_pid: Urn,
+    pub consumer_pid: Urn,
 }
 
 impl Into<NegotiationProcessMessageWrapper<NegotiationAgreementMessageDto>>
@@ -368,6 +368,7 @@ impl Into<NegotiationProcessMessageWrapper<NegotiationAgreementMessageDto>>
                 consumer_pid: self.consumer_pid,
                 provider_pid: self.provider_pid,
                 agreement: OdrlAgreement::default(),
+                signature: None,
             },
         }
     }
@@ -440,6 +441,7 @@ impl Into<NegotiationProcessMessageWrapper<NegotiationVerificationMessageDto>>
             dto: NegotiationVerificationMessageDto {
                 consumer_pid: self.consumer_pid,
                 provider_pid: self.provider_pid,
+                signature: None,
             },
         }
     }
@@ -572,8 +574,8 @@ impl RpcNegotiationProcessMessageTrait for RpcNegotiationEventAcceptedMessageDto
 #[serde(rename_all = "camelCase")]
 #[serde(deny_unknown_fields)]
 pub struct RpcNegotiationEventFinalizedMessageDto {
-    provider_pid: Urn,
-    consumer_pid: Urn,
+    pub provider_pid: Urn,
+    pub consumer_pid: Urn,
 }
 
 impl Into<NegotiationProcessMessageWrapper<NegotiationEventMessageDto>>
@@ -1,2 +1,3 @@
 pub(crate) mod orchestration_extractors;
 pub(crate) mod orchestration_helpers;
+pub(crate) mod orchestration_notifications;
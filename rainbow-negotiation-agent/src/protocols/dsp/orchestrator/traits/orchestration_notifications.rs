@@ -0,0 +1,57 @@
+use crate::entities::negotiation_process::NegotiationProcessDto;
+use crate::protocols::dsp::protocol_types::NegotiationProcessMessageType;
+use rainbow_events::core::notification::notification_publisher::RainbowEventsPublisherTrait;
+use rainbow_events::core::notification::notification_types::{
+    RainbowEventsNotificationEvent, RainbowEventsNotificationMessageCategory,
+    RainbowEventsNotificationMessageOperation, RainbowEventsNotificationMessageTypes,
+};
+use std::sync::Arc;
+
+/// Publishes the negotiation process as it was persisted after each transition
+pub trait OrchestrationNotifications: Send + Sync + 'static {
+    fn get_events_publisher(&self) -> Arc<dyn RainbowEventsPublisherTrait>;
+
+    fn notify_process_transition(
+        &self,
+        process: &NegotiationProcessDto,
+        message: &NegotiationProcessMessageType,
+        message_type: RainbowEventsNotificationMessageTypes,
+        operation: RainbowEventsNotificationMessageOperation,
+    ) {
+        let event = process.identifiers.iter().fold(
+            RainbowEventsNotificationEvent::new(
+                RainbowEventsNotificationMessageCategory::ContractNegotiation,
+                message.to_string(),
+                message_type,
+                operation,
+            )
+            .with_entity_id("processId", &process.inner.id)
+            .with_snapshot(process),
+            |event, (name, id)| event.with_entity_id(name, id),
+        );
+        self.get_events_publisher().publish(event);
+    }
+
+    fn notify_agreement_creation(
+        &self,
+        process: &NegotiationProcessDto,
+        message_type: RainbowEventsNotificationMessageTypes,
+    ) {
+        let Some(agreement) = &process.agreement else {
+            return;
+        };
+        let event = RainbowEventsNotificationEvent::new(
+            RainbowEventsNotificationMessageCategory::ContractNegotiation,
+            "Agreement",
+            message_type,
+            RainbowEventsNotificationMessageOperation::Creation,
+        )
+        .with_entity_id("agreementId", &agreement.id)
+        .with_entity_id("processId", &process.inner.id)
+        .with_entity_id("consumerParticipantId", &agreement.consumer_participant_id)
+        .with_entity_id("providerParticipantId", &agreement.provider_participant_id)
+        .with_entity_id("target", &agreement.target)
+        .with_snapshot(agreement);
+        self.get_events_publisher().publish(event);
+    }
+}
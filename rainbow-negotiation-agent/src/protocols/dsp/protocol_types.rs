@@ -17,6 +17,7 @@
  *
  */
 
+use crate::entities::agreement::AgreementSignatureDto;
 use crate::entities::negotiation_process::NegotiationProcessDto;
 use anyhow::bail;
 use rainbow_common::dsp_common::context_field::ContextField;
@@ -283,6 +284,9 @@ pub struct NegotiationAgreementMessageDto {
     pub consumer_pid: Urn,
     pub provider_pid: Urn,
     pub agreement: OdrlAgreement,
+    // provider's JWS over the agreement
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signature: Option<AgreementSignatureDto>,
 }
 
 impl NegotiationProcessMessageTrait for NegotiationAgreementMessageDto {
@@ -336,6 +340,9 @@ impl NegotiationProcessMessageTrait for NegotiationAgreementMessageDto {
 pub struct NegotiationVerificationMessageDto {
     pub consumer_pid: Urn,
     pub provider_pid: Urn,
+    // consumer's JWS over the agreement
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signature: Option<AgreementSignatureDto>,
 }
 
 impl NegotiationProcessMessageTrait for NegotiationVerificationMessageDto {
@@ -17,7 +17,9 @@
  *
  */
 
+pub(crate) mod validate_offer;
 pub(crate) mod validate_payload;
+pub(crate) mod validate_signature;
 pub(crate) mod validate_state_transition;
 pub(crate) mod validation_dsp_steps;
 pub(crate) mod validation_helpers;
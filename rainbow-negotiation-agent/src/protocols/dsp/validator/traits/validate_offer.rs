@@ -0,0 +1,37 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+use crate::entities::negotiation_process::NegotiationProcessDto;
+use crate::protocols::dsp::protocol_types::NegotiationProcessMessageTrait;
+use rainbow_common::dsp_common::odrl::ContractRequestMessageOfferTypes;
+
+#[async_trait::async_trait]
+pub trait ValidateOffer: Send + Sync + 'static {
+    /// Validates the requested offer against the one published in the catalog agent,
+    /// or against a counter offer previously sent by this agent in the same process
+    async fn validate_requested_offer(
+        &self,
+        payload: &dyn NegotiationProcessMessageTrait,
+        dto: Option<&NegotiationProcessDto>,
+    ) -> anyhow::Result<()>; // catalog call
+    /// Validates offer terms against the ones published in the catalog agent only
+    async fn validate_catalog_offer(
+        &self,
+        offer: &ContractRequestMessageOfferTypes,
+    ) -> anyhow::Result<()>; // catalog call
+}
@@ -0,0 +1,33 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+use crate::entities::agreement::AgreementSignatureDto;
+use crate::entities::negotiation_process::NegotiationProcessDto;
+use serde_json::Value;
+
+#[async_trait::async_trait]
+pub trait ValidateSignature: Send + Sync + 'static {
+    /// Validates the JWS sent by the counterparty over the agreement of the process.
+    /// A message without signature passes, only a signature that is there has to verify.
+    async fn validate_agreement_signature(
+        &self,
+        agreement: &Value,
+        signature: Option<&AgreementSignatureDto>,
+        dto: &NegotiationProcessDto,
+    ) -> anyhow::Result<()>;
+}
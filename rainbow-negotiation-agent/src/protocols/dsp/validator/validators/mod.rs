@@ -19,5 +19,7 @@
 
 pub(crate) mod protocol;
 pub(crate) mod rpc;
+pub(crate) mod validate_offer;
 pub(crate) mod validate_payload;
+pub(crate) mod validate_signature;
 pub(crate) mod validation_helpers;
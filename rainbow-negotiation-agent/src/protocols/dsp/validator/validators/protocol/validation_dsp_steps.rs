@@ -23,24 +23,39 @@ use crate::protocols::dsp::protocol_types::{
     NegotiationRequestMessageDto, NegotiationTerminationMessageDto,
     NegotiationVerificationMessageDto,
 };
+use crate::protocols::dsp::validator::traits::validate_offer::ValidateOffer;
 use crate::protocols::dsp::validator::traits::validate_payload::ValidatePayload;
+use crate::protocols::dsp::validator::traits::validate_signature::ValidateSignature;
 use crate::protocols::dsp::validator::traits::validate_state_transition::ValidateStateTransition;
 use crate::protocols::dsp::validator::traits::validation_dsp_steps::ValidationDspSteps;
 use crate::protocols::dsp::validator::traits::validation_helpers::ValidationHelpers;
+use anyhow::bail;
+use rainbow_common::errors::{CommonErrors, ErrorLog};
 use std::sync::Arc;
+use tracing::error;
 
 pub struct ValidationDspStepsService {
     payload_validator: Arc<dyn ValidatePayload>,
     step_transition_validator: Arc<dyn ValidateStateTransition>,
+    offer_validator: Arc<dyn ValidateOffer>,
+    signature_validator: Arc<dyn ValidateSignature>,
     helpers: Arc<dyn ValidationHelpers>,
 }
 impl ValidationDspStepsService {
     pub fn new(
         payload_validator: Arc<dyn ValidatePayload>,
         step_transition_validator: Arc<dyn ValidateStateTransition>,
+        offer_validator: Arc<dyn ValidateOffer>,
+        signature_validator: Arc<dyn ValidateSignature>,
         helpers: Arc<dyn ValidationHelpers>,
     ) -> Self {
-        Self { payload_validator, step_transition_validator, helpers }
+        Self {
+            payload_validator,
+            step_transition_validator,
+            offer_validator,
+            signature_validator,
+            helpers,
+        }
     }
 }
 
@@ -53,6 +68,7 @@ impl ValidationDspSteps for ValidationDspStepsService {
         self.payload_validator.validate_with_json_schema(&input.dto).await?;
         self.payload_validator.validate_identifiers_as_urn(&input.dto).await?;
         self.payload_validator.validate_auth(&input.dto).await?;
+        self.offer_validator.validate_requested_offer(&input.dto, None).await?;
         Ok(())
     }
 
@@ -76,6 +92,7 @@ impl ValidationDspSteps for ValidationDspStepsService {
         self.step_transition_validator
             .validate_state_transition(&current_state, &message_type)
             .await?;
+        self.offer_validator.validate_requested_offer(&input.dto, Some(&dto)).await?;
         Ok(())
     }
 
@@ -132,6 +149,10 @@ impl ValidationDspSteps for ValidationDspStepsService {
         self.step_transition_validator
             .validate_state_transition(&current_state, &message_type)
             .await?;
+        let agreement = serde_json::to_value(&input.dto.agreement)?;
+        self.signature_validator
+            .validate_agreement_signature(&agreement, input.dto.signature.as_ref(), &dto)
+            .await?;
         Ok(())
     }
 
@@ -155,6 +176,20 @@ impl ValidationDspSteps for ValidationDspStepsService {
         self.step_transition_validator
             .validate_state_transition(&current_state, &message_type)
             .await?;
+        let agreement = match dto.agreement.as_ref() {
+            Some(agreement) => &agreement.agreement_content,
+            None => {
+                let err = CommonErrors::missing_resource_new(
+                    dto.inner.id.as_str(),
+                    "Agreement not found for this process",
+                );
+                error!("{}", err.log());
+                bail!(err);
+            }
+        };
+        self.signature_validator
+            .validate_agreement_signature(agreement, input.dto.signature.as_ref(), &dto)
+            .await?;
         Ok(())
     }
 
@@ -0,0 +1,291 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use crate::entities::negotiation_process::NegotiationProcessDto;
+use crate::protocols::dsp::facades::FacadeTrait;
+use crate::protocols::dsp::protocol_types::{
+    NegotiationProcessMessageTrait, NegotiationProcessMessageType,
+};
+use crate::protocols::dsp::validator::traits::validate_offer::ValidateOffer;
+use anyhow::{anyhow, bail};
+use rainbow_common::dsp_common::odrl::{
+    ContractRequestMessageOfferTypes, OdrlObligation, OdrlPermission, OdrlPolicyInfo,
+};
+use rainbow_common::errors::helpers::BadFormat;
+use rainbow_common::errors::{CommonErrors, ErrorLog};
+use serde::Serialize;
+use serde_json::{Map, Value};
+use std::sync::Arc;
+use tracing::error;
+use urn::Urn;
+
+/// Terms of an offer reduced to a form where equivalent policies compare equal
+#[derive(Debug, PartialEq)]
+pub(crate) struct OfferTerms {
+    target: String,
+    permission: Vec<String>,
+    prohibition: Vec<String>,
+    obligation: Vec<String>,
+}
+
+impl OfferTerms {
+    pub(crate) fn new(
+        target: String,
+        permission: &Option<Vec<OdrlPermission>>,
+        prohibition: &Option<Vec<OdrlObligation>>,
+        obligation: &Option<Vec<OdrlObligation>>,
+    ) -> anyhow::Result<Self> {
+        Ok(Self {
+            target,
+            permission: normalize_rules(permission)?,
+            prohibition: normalize_rules(prohibition)?,
+            obligation: normalize_rules(obligation)?,
+        })
+    }
+}
+
+fn normalize_rules<T: Serialize>(rules: &Option<Vec<T>>) -> anyhow::Result<Vec<String>> {
+    let mut rules = rules
+        .iter()
+        .flatten()
+        .map(|rule| serde_json::to_value(rule).map(|value| normalize_value(value).to_string()))
+        .collect::<Result<Vec<_>, _>>()?;
+    rules.sort();
+    rules.dedup();
+    Ok(rules)
+}
+
+pub(crate) fn normalize_value(value: Value) -> Value {
+    match value {
+        Value::Object(map) => {
+            let operator = map.get("operator").and_then(Value::as_str).map(str::to_string);
+            let mut normalized = Map::new();
+            for (key, value) in map {
+                let value = match (key.as_str(), normalize_value(value)) {
+                    // order carries no meaning in these lists, andSequence is left as is
+                    ("constraint" | "and" | "or" | "xone", Value::Array(items)) => sorted(items),
+                    ("rightOperand", Value::Array(items))
+                        if matches!(
+                            operator.as_deref(),
+                            Some("isAnyOf" | "isAllOf" | "isNoneOf")
+                        ) =>
+                    {
+                        sorted(items)
+                    }
+                    (_, value) => value,
+                };
+                // a missing constraint list and an empty one mean the same
+                if value.is_null() || (key == "constraint" && value == Value::Array(vec![])) {
+                    continue;
+                }
+                normalized.insert(key, value);
+            }
+            Value::Object(normalized)
+        }
+        Value::Array(items) => Value::Array(items.into_iter().map(normalize_value).collect()),
+        value => value,
+    }
+}
+
+fn sorted(mut items: Vec<Value>) -> Value {
+    items.sort_by_key(|item| item.to_string());
+    items.dedup();
+    Value::Array(items)
+}
+
+/// Referencing an offer only by its id means accepting its terms as they are
+pub(crate) fn compare_offer_terms(
+    offer: &ContractRequestMessageOfferTypes,
+    reference: &OfferTerms,
+) -> anyhow::Result<()> {
+    let (offer_id, requested) = match offer {
+        ContractRequestMessageOfferTypes::OfferMessage(offer) => (
+            &offer.id,
+            OfferTerms::new(
+                offer.target.to_string(),
+                &offer.permission,
+                &offer.prohibition,
+                &offer.obligation,
+            )?,
+        ),
+        ContractRequestMessageOfferTypes::OfferId(_) => return Ok(()),
+    };
+    if requested.target != reference.target {
+        let err = CommonErrors::format_new(
+            BadFormat::Received,
+            format!(
+                "Requested target {} does not match the target of offer {}",
+                requested.target, offer_id
+            )
+            .as_str(),
+        );
+        error!("{}", err.log());
+        bail!(err);
+    }
+    for (rules, requested_rules, reference_rules) in [
+        ("permissions", &requested.permission, &reference.permission),
+        ("prohibitions", &requested.prohibition, &reference.prohibition),
+        ("obligations", &requested.obligation, &reference.obligation),
+    ] {
+        if requested_rules != reference_rules {
+            let err = CommonErrors::format_new(
+                BadFormat::Received,
+                format!("Requested {} do not match the ones of offer {}", rules, offer_id).as_str(),
+            );
+            error!("{}", err.log());
+            bail!(err);
+        }
+    }
+    Ok(())
+}
+
+pub struct ValidateOfferService {
+    facades: Arc<dyn FacadeTrait>,
+}
+impl ValidateOfferService {
+    pub fn new(facades: Arc<dyn FacadeTrait>) -> Self {
+        Self { facades }
+    }
+
+    /// Last ContractOfferMessage sent by this agent in the process under the same offer id
+    fn get_counter_offer(
+        &self,
+        dto: &NegotiationProcessDto,
+        offer_id: &Urn,
+    ) -> anyhow::Result<Option<OfferTerms>> {
+        let offer_message_type = NegotiationProcessMessageType::NegotiationOfferMessage.to_string();
+        let sent_offer_messages = dto
+            .messages
+            .iter()
+            .filter(|m| m.direction == "OUTBOUND" && m.message_type == offer_message_type)
+            .map(|m| m.id.as_str())
+            .collect::<Vec<_>>();
+        let counter_offer = dto
+            .offers
+            .iter()
+            .filter(|o| sent_offer_messages.contains(&o.negotiation_agent_message_id.as_str()))
+            .filter(|o| o.offer_id == offer_id.to_string())
+            .max_by_key(|o| o.created_at);
+        let counter_offer = match counter_offer {
+            Some(counter_offer) => counter_offer,
+            None => return Ok(None),
+        };
+        let content = serde_json::from_value::<ContractRequestMessageOfferTypes>(
+            counter_offer.offer_content.clone(),
+        )
+        .map_err(|e| {
+            let err = CommonErrors::parse_new(
+                format!("Stored offer {} is not a valid ODRL offer, {}", offer_id, e).as_str(),
+            );
+            error!("{}", err.log());
+            anyhow!(err)
+        })?;
+        match content {
+            ContractRequestMessageOfferTypes::OfferMessage(offer) => Ok(Some(OfferTerms::new(
+                offer.target.to_string(),
+                &offer.permission,
+                &offer.prohibition,
+                &offer.obligation,
+            )?)),
+            ContractRequestMessageOfferTypes::OfferId(_) => Ok(None),
+        }
+    }
+
+    async fn get_catalog_offer(&self, offer_id: &Urn) -> anyhow::Result<OfferTerms> {
+        let offer = self
+            .facades
+            .get_catalog_facade()
+            .await
+            .get_odrl_offer_by_id(offer_id)
+            .await
+            .map_err(|e| {
+                let err = CommonErrors::petition_new(
+                    format!("odrl-policies/{}", offer_id).as_str(),
+                    "GET",
+                    None,
+                    format!("Offer could not be resolved in the catalog, {}", e).as_str(),
+                );
+                error!("{}", err.log());
+                anyhow!(err)
+            })?
+            .ok_or_else(|| {
+                let err = CommonErrors::missing_resource_new(
+                    offer_id.to_string().as_str(),
+                    "Requested offer is not published in the catalog",
+                );
+                error!("{}", err.log());
+                anyhow!(err)
+            })?;
+        let policy =
+            serde_json::from_value::<OdrlPolicyInfo>(offer.inner.odrl_offer).map_err(|e| {
+                let err = CommonErrors::parse_new(
+                    format!("Catalog offer {} is not a valid ODRL policy, {}", offer_id, e)
+                        .as_str(),
+                );
+                error!("{}", err.log());
+                anyhow!(err)
+            })?;
+        OfferTerms::new(
+            offer.inner.entity,
+            &policy.permission,
+            &policy.prohibition,
+            &policy.obligation,
+        )
+    }
+}
+
+#[async_trait::async_trait]
+impl ValidateOffer for ValidateOfferService {
+    async fn validate_requested_offer(
+        &self,
+        payload: &dyn NegotiationProcessMessageTrait,
+        dto: Option<&NegotiationProcessDto>,
+    ) -> anyhow::Result<()> {
+        let offer = match payload.get_offer() {
+            Some(offer) => offer,
+            None => return Ok(()),
+        };
+        let offer_id = match &offer {
+            ContractRequestMessageOfferTypes::OfferMessage(m) => m.id.clone(),
+            ContractRequestMessageOfferTypes::OfferId(i) => i.id.clone(),
+        };
+        let counter_offer = match dto {
+            Some(dto) => self.get_counter_offer(dto, &offer_id)?,
+            None => None,
+        };
+        let reference = match counter_offer {
+            Some(counter_offer) => counter_offer,
+            None => self.get_catalog_offer(&offer_id).await?,
+        };
+
+        compare_offer_terms(&offer, &reference)
+    }
+
+    async fn validate_catalog_offer(
+        &self,
+        offer: &ContractRequestMessageOfferTypes,
+    ) -> anyhow::Result<()> {
+        let offer_id = match offer {
+            ContractRequestMessageOfferTypes::OfferMessage(m) => m.id.clone(),
+            ContractRequestMessageOfferTypes::OfferId(i) => i.id.clone(),
+        };
+        let reference = self.get_catalog_offer(&offer_id).await?;
+        compare_offer_terms(offer, &reference)
+    }
+}
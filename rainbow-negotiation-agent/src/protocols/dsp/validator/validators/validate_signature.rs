@@ -0,0 +1,72 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+use crate::entities::agreement::AgreementSignatureDto;
+use crate::entities::agreement::signature::verify_agreement_signature;
+use crate::entities::negotiation_process::NegotiationProcessDto;
+use crate::protocols::dsp::validator::traits::validate_signature::ValidateSignature;
+use rainbow_common::auth::ssi::current_participant_id;
+use rainbow_common::http_client::HttpClient;
+use serde_json::Value;
+use std::sync::Arc;
+use tracing::warn;
+
+pub struct ValidateSignatureService {
+    http_client: Arc<HttpClient>,
+}
+
+impl ValidateSignatureService {
+    pub fn new(http_client: Arc<HttpClient>) -> Self {
+        Self { http_client }
+    }
+}
+
+#[async_trait::async_trait]
+impl ValidateSignature for ValidateSignatureService {
+    async fn validate_agreement_signature(
+        &self,
+        agreement: &Value,
+        signature: Option<&AgreementSignatureDto>,
+        dto: &NegotiationProcessDto,
+    ) -> anyhow::Result<()> {
+        // signatures are not part of DSP, the agreement is kept as unsigned by the peer
+        // and the transfer side decides on it with its signature policy
+        let signature = match signature {
+            Some(signature) => signature,
+            None => {
+                warn!(
+                    "Agreement message of process {} is not signed by the peer",
+                    dto.inner.id
+                );
+                return Ok(());
+            }
+        };
+        // the signer must be the authenticated peer, or the peer the process was opened with
+        let expected_signer = current_participant_id().or_else(|| {
+            let peer = &dto.inner.associated_agent_peer;
+            (!peer.is_empty()).then(|| peer.clone())
+        });
+        verify_agreement_signature(
+            agreement,
+            signature,
+            expected_signer.as_deref(),
+            self.http_client.as_ref(),
+        )
+        .await
+    }
+}
@@ -0,0 +1,128 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+use crate::data::factory_sql::NegotiationAgentRepoForSql;
+use crate::entities::agreement::agreement::NegotiationAgentAgreementsService;
+use crate::entities::auto_responder_decision::auto_responder_decision::NegotiationAgentAutoResponderDecisionsService;
+use crate::entities::negotiation_message::negotiation_message::NegotiationAgentMessagesService;
+use crate::entities::negotiation_process::negotiation_process::NegotiationAgentProcessesService;
+use crate::entities::offer::offer::NegotiationAgentOffersService;
+use crate::protocols::dsp::NegotiationDSP;
+use crate::protocols::dsp::auto_responder::AutoResponderTrait;
+use rainbow_common::config::services::ContractsConfig;
+use rainbow_common::config::traits::CommonConfigTrait;
+use rainbow_common::http_client::HttpClient;
+use rainbow_events::core::notification::notification::RainbowEventsNotificationsService;
+use rainbow_events::core::notification::notification_publisher::RainbowEventsPublisher;
+use rainbow_events::data::repo::EventsRepoFactory;
+use rainbow_events::data::repo::sql::EventsRepoForSql;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tokio_util::sync::CancellationToken;
+use ymir::services::vault::VaultTrait;
+use ymir::services::vault::vault_rs::VaultService;
+
+/// How often decided answers are looked up and sent
+const AUTO_RESPONDER_TICK: Duration = Duration::from_secs(2);
+
+/// Sends the answers the auto responder decided while handling consumer messages.
+/// They live in the database, so a restart picks them up where it left them.
+pub struct NegotiationAutoResponderWorker {}
+
+impl NegotiationAutoResponderWorker {
+    pub async fn spawn(
+        config: &ContractsConfig,
+        vault: Arc<VaultService>,
+        token: &CancellationToken,
+    ) -> anyhow::Result<JoinHandle<()>> {
+        let token = token.clone();
+        // without rules nothing is ever decided
+        if config.auto_responder_rules().is_empty() {
+            tracing::info!("No auto responder rules, answers delivery is off");
+            return Ok(tokio::spawn(async move { token.cancelled().await }));
+        }
+
+        let auto_responder = Self::create_auto_responder(config, vault).await?;
+        tracing::info!("Auto responder delivery running every {:?}", AUTO_RESPONDER_TICK);
+
+        let handle = tokio::spawn(async move {
+            let mut interval = tokio::time::interval(AUTO_RESPONDER_TICK);
+            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            loop {
+                tokio::select! {
+                    _ = token.cancelled() => {
+                        tracing::info!("Auto responder delivery received shutdown signal");
+                        break;
+                    }
+                    _ = interval.tick() => {
+                        if let Err(e) = auto_responder.send_pending_responses().await {
+                            tracing::error!("Auto responder delivery round failed: {}", e);
+                        }
+                    }
+                }
+            }
+        });
+
+        Ok(handle)
+    }
+
+    async fn create_auto_responder(
+        config: &ContractsConfig,
+        vault: Arc<VaultService>,
+    ) -> anyhow::Result<Arc<dyn AutoResponderTrait>> {
+        // conn
+        let db_connection = vault.get_db_connection(config.common()).await;
+        let http_client = Arc::new(HttpClient::new(10, 10));
+        let config = Arc::new(config.clone());
+
+        // repo
+        let negotiation_repo =
+            Arc::new(NegotiationAgentRepoForSql::create_repo(db_connection.clone()));
+
+        // entities
+        let process_service =
+            Arc::new(NegotiationAgentProcessesService::new(negotiation_repo.clone()));
+        let message_service =
+            Arc::new(NegotiationAgentMessagesService::new(negotiation_repo.clone()));
+        let offer_service = Arc::new(NegotiationAgentOffersService::new(negotiation_repo.clone()));
+        let agreement_service = Arc::new(NegotiationAgentAgreementsService::new(
+            negotiation_repo.clone(),
+            http_client,
+        ));
+        let decision_service =
+            Arc::new(NegotiationAgentAutoResponderDecisionsService::new(negotiation_repo));
+
+        // events
+        let events_repo = Arc::new(EventsRepoForSql::create_repo(db_connection));
+        let notification_service = Arc::new(RainbowEventsNotificationsService::new(events_repo));
+        let events_publisher = Arc::new(RainbowEventsPublisher::new(notification_service));
+
+        NegotiationDSP::new(
+            process_service,
+            message_service,
+            offer_service,
+            agreement_service,
+            decision_service,
+            events_publisher,
+            config,
+            vault,
+        )
+        .build_auto_responder()
+    }
+}
@@ -16,15 +16,18 @@
  *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
  *
  */
+use crate::setup::auto_responder_worker::NegotiationAutoResponderWorker;
 use crate::setup::grpc_worker::NegotiationGrpcWorker;
 use crate::setup::http_worker::NegotiationHttpWorker;
 use rainbow_common::boot::BootstrapServiceTrait;
 use rainbow_common::config::services::ContractsConfig;
-use rainbow_common::config::traits::ConfigLoader;
+use rainbow_common::config::traits::{CommonConfigTrait, ConfigLoader};
+use rainbow_events::core::notification::notification_delivery::RainbowEventsDeliveryWorker;
 use std::sync::Arc;
 use tokio::sync::broadcast;
 use tokio::sync::broadcast::Sender;
 use tokio_util::sync::CancellationToken;
+use ymir::services::vault::VaultTrait;
 use ymir::services::vault::vault_rs::VaultService;
 
 pub struct NegotiationAgentBoot;
@@ -69,6 +72,14 @@ impl BootstrapServiceTrait for NegotiationAgentBoot {
         let grpc_handle =
             NegotiationGrpcWorker::spawn(config, vault.clone(), &cancel_token).await?;
 
+        tracing::info!("Spawning events delivery worker...");
+        let db_connection = vault.get_db_connection(config.common()).await;
+        let events_handle = RainbowEventsDeliveryWorker::spawn(db_connection, &cancel_token);
+
+        tracing::info!("Spawning auto responder delivery worker...");
+        let auto_responder_handle =
+            NegotiationAutoResponderWorker::spawn(config, vault.clone(), &cancel_token).await?;
+
         // non-blocking thread
         let token_clone = cancel_token.clone();
         tokio::spawn(async move {
@@ -83,6 +94,12 @@ impl BootstrapServiceTrait for NegotiationAgentBoot {
                 _ = async { grpc_handle.await } => {
                     tracing::error!("GRPC subsystem failed or stopped unexpectedly!");
                 }
+                _ = async { events_handle.await } => {
+                    tracing::error!("Events delivery subsystem failed or stopped unexpectedly!");
+                }
+                _ = async { auto_responder_handle.await } => {
+                    tracing::error!("Auto responder delivery subsystem failed or stopped unexpectedly!");
+                }
             }
 
             tracing::info!("Initiating internal graceful shutdown sequence...");
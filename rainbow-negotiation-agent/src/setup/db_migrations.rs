@@ -19,6 +19,7 @@
 use crate::data::migrations::get_negotiation_agent_migrations;
 use rainbow_common::config::services::ContractsConfig;
 use rainbow_common::config::traits::CommonConfigTrait;
+use rainbow_events::data::migrations::get_events_migrations;
 use sea_orm_migration::{MigrationTrait, MigratorTrait};
 use std::sync::Arc;
 use ymir::services::vault::VaultTrait;
@@ -30,8 +31,10 @@ impl MigratorTrait for NegotiationAgentMigration {
     fn migrations() -> Vec<Box<dyn MigrationTrait>> {
         let mut migrations: Vec<Box<dyn MigrationTrait>> = vec![];
         let mut negotiation_agent_migrations = get_negotiation_agent_migrations();
+        let mut events_migrations = get_events_migrations();
 
         migrations.append(&mut negotiation_agent_migrations);
+        migrations.append(&mut events_migrations);
         migrations
     }
 }
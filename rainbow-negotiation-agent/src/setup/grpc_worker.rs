@@ -34,6 +34,7 @@ use crate::grpc::negotiation_process::NegotiationAgentProcessesGrpc;
 use crate::grpc::offer::NegotiationAgentOfferGrpc;
 use rainbow_common::config::services::ContractsConfig;
 use rainbow_common::config::traits::CommonConfigTrait;
+use rainbow_common::http_client::HttpClient;
 use sea_orm::Database;
 use std::sync::Arc;
 use tokio::net::TcpListener;
@@ -98,8 +99,12 @@ impl NegotiationGrpcWorker {
         let offer_controller_service =
             Arc::new(NegotiationAgentOffersService::new(negotiation_repo.clone()));
         let offer_controller = NegotiationAgentOfferGrpc::new(offer_controller_service.clone());
-        let agreement_controller_service =
-            Arc::new(NegotiationAgentAgreementsService::new(negotiation_repo.clone()));
+        // resolves did:web signers of the stored agreement signatures
+        let http_client = Arc::new(HttpClient::new(10, 10));
+        let agreement_controller_service = Arc::new(NegotiationAgentAgreementsService::new(
+            negotiation_repo.clone(),
+            http_client.clone(),
+        ));
         let agreement_controller =
             NegotiationAgentAgreementGrpc::new(agreement_controller_service.clone());
 
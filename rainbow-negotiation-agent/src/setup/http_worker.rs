@@ -19,10 +19,12 @@
 
 use crate::data::factory_sql::NegotiationAgentRepoForSql;
 use crate::entities::agreement::agreement::NegotiationAgentAgreementsService;
+use crate::entities::auto_responder_decision::auto_responder_decision::NegotiationAgentAutoResponderDecisionsService;
 use crate::entities::negotiation_message::negotiation_message::NegotiationAgentMessagesService;
 use crate::entities::negotiation_process::negotiation_process::NegotiationAgentProcessesService;
 use crate::entities::offer::offer::NegotiationAgentOffersService;
 use crate::http::agreement::NegotiationAgentAgreementsRouter;
+use crate::http::auto_responder_decision::NegotiationAgentAutoResponderDecisionsRouter;
 use crate::http::negotiation_message::NegotiationAgentMessagesRouter;
 use crate::http::negotiation_process::NegotiationAgentProcessesRouter;
 use crate::http::offer::NegotiationAgentOffersRouter;
@@ -34,7 +36,16 @@ use axum::{Router, serve};
 use rainbow_common::config::services::ContractsConfig;
 use rainbow_common::config::traits::CommonConfigTrait;
 use rainbow_common::errors::CommonErrors;
+use rainbow_common::http_client::HttpClient;
 use rainbow_common::well_known::WellKnownRoot;
+use rainbow_events::core::notification::notification::RainbowEventsNotificationsService;
+use rainbow_events::core::notification::notification_publisher::RainbowEventsPublisher;
+use rainbow_events::core::subscription::subscription::RainbowEventsSubscriptionService;
+use rainbow_events::core::subscription::subscription_types::SubscriptionEntities;
+use rainbow_events::data::repo::EventsRepoFactory;
+use rainbow_events::data::repo::sql::EventsRepoForSql;
+use rainbow_events::http::notification::notification::RainbowEventsNotificationRouter;
+use rainbow_events::http::subscription::subscription::RainbowEventsSubscriptionRouter;
 use std::sync::Arc;
 use tokio::net::TcpListener;
 use tokio::task::JoinHandle;
@@ -134,10 +145,35 @@ pub async fn create_root_http_router(
         Arc::new(NegotiationAgentOffersService::new(negotiation_repo.clone()));
     let offer_router =
         NegotiationAgentOffersRouter::new(offer_controller_service.clone(), config.clone());
-    let agreement_controller_service =
-        Arc::new(NegotiationAgentAgreementsService::new(negotiation_repo.clone()));
+    // resolves did:web signers of the stored agreement signatures
+    let http_client = Arc::new(HttpClient::new(10, 10));
+    let agreement_controller_service = Arc::new(NegotiationAgentAgreementsService::new(
+        negotiation_repo.clone(),
+        http_client.clone(),
+    ));
     let agreement_router =
         NegotiationAgentAgreementsRouter::new(agreement_controller_service.clone(), config.clone());
+    let auto_responder_decision_controller_service = Arc::new(
+        NegotiationAgentAutoResponderDecisionsService::new(negotiation_repo.clone()),
+    );
+    let auto_responder_decision_router = NegotiationAgentAutoResponderDecisionsRouter::new(
+        auto_responder_decision_controller_service.clone(),
+        config.clone(),
+    );
+
+    // events
+    let events_repo = Arc::new(EventsRepoForSql::create_repo(db_connection.clone()));
+    let subscription_service = Arc::new(RainbowEventsSubscriptionService::new(events_repo.clone()));
+    let notification_service = Arc::new(RainbowEventsNotificationsService::new(events_repo));
+    let events_publisher = Arc::new(RainbowEventsPublisher::new(notification_service.clone()));
+    let subscription_router = RainbowEventsSubscriptionRouter::new(
+        subscription_service,
+        Some(SubscriptionEntities::ContractNegotiationProcess),
+    );
+    let notification_router = RainbowEventsNotificationRouter::new(
+        notification_service,
+        Some(SubscriptionEntities::ContractNegotiationProcess),
+    );
 
     // dsp
     let dsp_router = NegotiationDSP::new(
@@ -145,7 +181,10 @@ pub async fn create_root_http_router(
         messages_controller_service.clone(),
         offer_controller_service.clone(),
         agreement_controller_service.clone(),
+        auto_responder_decision_controller_service.clone(),
+        events_publisher,
         config.clone(),
+        vault.clone(),
     )
     .build_router()
     .await?;
@@ -169,6 +208,14 @@ pub async fn create_root_http_router(
             format!("{}/agreements", router_str.as_str()).as_str(),
             agreement_router.router(),
         )
+        .nest(
+            format!("{}/auto-responder-decisions", router_str.as_str()).as_str(),
+            auto_responder_decision_router.router(),
+        )
+        .nest(
+            router_str.as_str(),
+            subscription_router.router().merge(notification_router.router()),
+        )
         .nest("/dsp/current/negotiations", dsp_router);
 
     Ok(router)
@@ -17,6 +17,7 @@
  *
  */
 
+pub(crate) mod auto_responder_worker;
 mod boot;
 pub(crate) mod cmd;
 pub(crate) mod db_migrations;
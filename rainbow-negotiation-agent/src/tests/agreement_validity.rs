@@ -0,0 +1,133 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+#[cfg(test)]
+mod test_agreement_validity {
+    use crate::entities::agreement::validity::AgreementValidityWindow;
+    use chrono::{DateTime, FixedOffset};
+    use serde_json::{json, Value};
+
+    fn date(value: &str) -> Option<DateTime<FixedOffset>> {
+        Some(DateTime::parse_from_rfc3339(value).unwrap())
+    }
+
+    fn date_constraint(operator: &str, value: &str) -> Value {
+        json!({ "leftOperand": "dateTime", "operator": operator, "rightOperand": value })
+    }
+
+    #[test]
+    fn test_unconstrained_agreement_is_always_valid() {
+        let window = AgreementValidityWindow::from_agreement(&json!({
+            "permission": [{ "action": "use" }]
+        }));
+        assert_eq!(window, AgreementValidityWindow::default());
+    }
+
+    #[test]
+    fn test_constraints_of_a_permission_are_intersected() {
+        let window = AgreementValidityWindow::from_agreement(&json!({
+            "permission": [{
+                "action": "use",
+                "constraint": [
+                    date_constraint("gteq", "2026-01-01T00:00:00Z"),
+                    date_constraint("gt", "2026-02-01T00:00:00Z"),
+                    date_constraint("lt", "2026-12-31T00:00:00Z"),
+                    { "and": [date_constraint("lteq", "2026-06-30T00:00:00Z")] }
+                ]
+            }]
+        }));
+        assert_eq!(window.valid_from, date("2026-02-01T00:00:00Z"));
+        assert_eq!(window.valid_until, date("2026-06-30T00:00:00Z"));
+    }
+
+    #[test]
+    fn test_permissions_are_alternatives() {
+        let window = AgreementValidityWindow::from_agreement(&json!({
+            "permission": [
+                {
+                    "action": "use",
+                    "constraint": [
+                        date_constraint("gteq", "2026-01-01T00:00:00Z"),
+                        date_constraint("lt", "2026-03-01T00:00:00Z")
+                    ]
+                },
+                {
+                    "action": "read",
+                    "constraint": [
+                        date_constraint("gteq", "2026-02-01T00:00:00Z"),
+                        date_constraint("lt", "2026-06-01T00:00:00Z")
+                    ]
+                }
+            ]
+        }));
+        assert_eq!(window.valid_from, date("2026-01-01T00:00:00Z"));
+        assert_eq!(window.valid_until, date("2026-06-01T00:00:00Z"));
+    }
+
+    #[test]
+    fn test_open_permission_leaves_the_window_open() {
+        let window = AgreementValidityWindow::from_agreement(&json!({
+            "permission": [
+                { "action": "use", "constraint": [date_constraint("lt", "2026-03-01T00:00:00Z")] },
+                { "action": "read", "constraint": [date_constraint("gteq", "2026-02-01T00:00:00Z")] }
+            ]
+        }));
+        assert_eq!(window.valid_from, None);
+        assert_eq!(window.valid_until, None);
+    }
+
+    #[test]
+    fn test_disjunctions_do_not_bound_the_window() {
+        let window = AgreementValidityWindow::from_agreement(&json!({
+            "permission": {
+                "action": "use",
+                "constraint": [{
+                    "or": [
+                        date_constraint("lt", "2026-03-01T00:00:00Z"),
+                        { "leftOperand": "purpose", "operator": "eq", "rightOperand": "research" }
+                    ]
+                }]
+            }
+        }));
+        assert_eq!(window, AgreementValidityWindow::default());
+    }
+
+    #[test]
+    fn test_typed_literals_and_prefixed_terms() {
+        let window = AgreementValidityWindow::from_agreement(&json!({
+            "permission": [{
+                "action": "use",
+                "constraint": [
+                    {
+                        "leftOperand": "odrl:dateTime",
+                        "operator": { "@id": "odrl:lteq" },
+                        "rightOperand": { "@value": "2026-05-01", "@type": "xsd:date" }
+                    },
+                    {
+                        "leftOperand": "http://www.w3.org/ns/odrl/2/dateTime",
+                        "operator": "eq",
+                        "rightOperand": "not a date"
+                    }
+                ]
+            }]
+        }));
+        assert_eq!(window.valid_from, None);
+        assert_eq!(window.valid_until, date("2026-05-01T00:00:00Z"));
+    }
+}
@@ -0,0 +1,166 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+#[cfg(test)]
+mod test_auto_responder {
+    use crate::protocols::dsp::auto_responder::auto_responder::{
+        AutoResponderContext, AutoResponderService,
+    };
+    use crate::protocols::dsp::auto_responder::{AutoResponderResponse, AutoResponderTrigger};
+    use rainbow_common::config::types::auto_responder::{AutoResponderAction, AutoResponderRule};
+    use serde_json::json;
+    use std::collections::BTreeMap;
+
+    fn rule(action: AutoResponderAction) -> AutoResponderRule {
+        AutoResponderRule {
+            name: "rule".to_string(),
+            offer_ids: vec![],
+            policy_templates: vec![],
+            participants: vec![],
+            credentials: BTreeMap::new(),
+            action,
+            reason: None,
+        }
+    }
+
+    fn context() -> AutoResponderContext {
+        AutoResponderContext {
+            offer_id: Some("urn:offer:1".to_string()),
+            policy_template: Some("urn:template:1".to_string()),
+            participant_id: "did:web:consumer".to_string(),
+            credentials: BTreeMap::from([("participant_type".to_string(), "Partner".to_string())]),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_plan_response_per_action_and_trigger() {
+        use AutoResponderAction::*;
+        use AutoResponderResponse as Response;
+        use AutoResponderTrigger::*;
+
+        let cases = [
+            (Offer, ContractRequest, Some(Response::Offer)),
+            (Offer, Accepted, None),
+            (Offer, Verified, None),
+            (Accept, ContractRequest, Some(Response::Offer)),
+            (Accept, Accepted, Some(Response::Agreement)),
+            (Accept, Verified, Some(Response::Finalized)),
+            (Agree, ContractRequest, Some(Response::Agreement)),
+            (Agree, Accepted, Some(Response::Agreement)),
+            (Agree, Verified, Some(Response::Finalized)),
+            (Terminate, ContractRequest, Some(Response::Termination)),
+            (Terminate, Accepted, Some(Response::Termination)),
+            (Terminate, Verified, Some(Response::Termination)),
+        ];
+        for (action, trigger, expected) in cases {
+            assert_eq!(
+                AutoResponderService::plan_response(action, trigger),
+                expected,
+                "{:?} on {}",
+                action,
+                trigger
+            );
+        }
+    }
+
+    #[test]
+    fn test_rule_without_criteria_matches_anything() {
+        let matched =
+            AutoResponderService::match_rule(&rule(AutoResponderAction::Accept), &context());
+        assert_eq!(matched, Some(json!({})));
+
+        let matched = AutoResponderService::match_rule(
+            &rule(AutoResponderAction::Accept),
+            &AutoResponderContext::default(),
+        );
+        assert_eq!(matched, Some(json!({})));
+    }
+
+    #[test]
+    fn test_matched_criteria_are_reported() {
+        let mut rule = rule(AutoResponderAction::Agree);
+        rule.offer_ids = vec!["urn:offer:0".to_string(), "urn:offer:1".to_string()];
+        rule.policy_templates = vec!["urn:template:1".to_string()];
+        rule.participants = vec!["did:web:consumer".to_string()];
+        rule.credentials =
+            BTreeMap::from([("participant_type".to_string(), "Partner".to_string())]);
+
+        let matched = AutoResponderService::match_rule(&rule, &context());
+        assert_eq!(
+            matched,
+            Some(json!({
+                "offerId": "urn:offer:1",
+                "policyTemplate": "urn:template:1",
+                "participant": "did:web:consumer",
+                "credentials": { "participant_type": "Partner" }
+            }))
+        );
+    }
+
+    #[test]
+    fn test_any_failing_criterion_rejects_the_rule() {
+        let mut offer_rule = rule(AutoResponderAction::Accept);
+        offer_rule.offer_ids = vec!["urn:offer:2".to_string()];
+        assert_eq!(AutoResponderService::match_rule(&offer_rule, &context()), None);
+
+        let mut participant_rule = rule(AutoResponderAction::Accept);
+        participant_rule.participants = vec!["did:web:other".to_string()];
+        assert_eq!(AutoResponderService::match_rule(&participant_rule, &context()), None);
+
+        let mut credentials_rule = rule(AutoResponderAction::Accept);
+        credentials_rule.credentials =
+            BTreeMap::from([("participant_type".to_string(), "Operator".to_string())]);
+        assert_eq!(AutoResponderService::match_rule(&credentials_rule, &context()), None);
+    }
+
+    #[test]
+    fn test_criteria_on_unknown_values_do_not_match() {
+        let unknown = AutoResponderContext {
+            participant_id: "did:web:consumer".to_string(),
+            ..Default::default()
+        };
+
+        let mut template_rule = rule(AutoResponderAction::Accept);
+        template_rule.policy_templates = vec!["urn:template:1".to_string()];
+        assert_eq!(AutoResponderService::match_rule(&template_rule, &unknown), None);
+
+        let mut offer_rule = rule(AutoResponderAction::Accept);
+        offer_rule.offer_ids = vec!["urn:offer:1".to_string()];
+        assert_eq!(AutoResponderService::match_rule(&offer_rule, &unknown), None);
+
+        let mut credentials_rule = rule(AutoResponderAction::Accept);
+        credentials_rule.credentials =
+            BTreeMap::from([("participant_type".to_string(), "Partner".to_string())]);
+        assert_eq!(AutoResponderService::match_rule(&credentials_rule, &unknown), None);
+    }
+
+    #[test]
+    fn test_stored_responses_parse_back() {
+        for response in [
+            AutoResponderResponse::Offer,
+            AutoResponderResponse::Agreement,
+            AutoResponderResponse::Finalized,
+            AutoResponderResponse::Termination,
+        ] {
+            assert_eq!(response.to_string().parse::<AutoResponderResponse>(), Ok(response));
+        }
+        assert!("ContractRequestMessage".parse::<AutoResponderResponse>().is_err());
+    }
+}
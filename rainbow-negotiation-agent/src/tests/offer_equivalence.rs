@@ -0,0 +1,198 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+#[cfg(test)]
+mod test_offer_equivalence {
+    use crate::protocols::dsp::validator::validators::validate_offer::{
+        compare_offer_terms, normalize_value, OfferTerms,
+    };
+    use rainbow_common::dsp_common::odrl::{ContractRequestMessageOfferTypes, OdrlPolicyInfo};
+    use rainbow_common::errors::CommonErrors;
+    use serde_json::{json, Value};
+
+    const OFFER_ID: &str = "urn:offer:1";
+    const TARGET: &str = "urn:dataset:1";
+
+    fn constraint(left_operand: &str, operator: &str, right_operand: Value) -> Value {
+        json!({ "leftOperand": left_operand, "operator": operator, "rightOperand": right_operand })
+    }
+
+    fn terms(policy: Value) -> OfferTerms {
+        let policy: OdrlPolicyInfo = serde_json::from_value(policy).unwrap();
+        OfferTerms::new(
+            TARGET.to_string(),
+            &policy.permission,
+            &policy.prohibition,
+            &policy.obligation,
+        )
+        .unwrap()
+    }
+
+    fn requested(target: &str, policy: Value) -> ContractRequestMessageOfferTypes {
+        let mut offer = json!({ "@id": OFFER_ID, "@type": "Offer", "target": target });
+        offer.as_object_mut().unwrap().extend(policy.as_object().unwrap().clone());
+        serde_json::from_value(offer).unwrap()
+    }
+
+    fn refusal(result: anyhow::Result<()>) -> String {
+        match result.unwrap_err().downcast::<CommonErrors>() {
+            Ok(CommonErrors::FormatError { cause, .. }) => cause,
+            other => panic!("Expected FormatError, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_constraint_order_is_ignored() {
+        let purpose = constraint("purpose", "eq", json!("research"));
+        let count = constraint("count", "lteq", json!("5"));
+        assert_eq!(
+            normalize_value(json!({ "action": "use", "constraint": [purpose, count] })),
+            normalize_value(json!({ "action": "use", "constraint": [count, purpose] }))
+        );
+    }
+
+    #[test]
+    fn test_missing_empty_and_null_constraints_are_equal() {
+        let bare = normalize_value(json!({ "action": "use" }));
+        assert_eq!(normalize_value(json!({ "action": "use", "constraint": [] })), bare);
+        assert_eq!(normalize_value(json!({ "action": "use", "constraint": null })), bare);
+        assert_eq!(normalize_value(json!({ "action": "use", "duty": null })), bare);
+    }
+
+    #[test]
+    fn test_set_operands_are_compared_as_sets() {
+        for operator in ["isAnyOf", "isAllOf", "isNoneOf"] {
+            assert_eq!(
+                normalize_value(constraint("spatial", operator, json!(["ES", "FR", "ES"]))),
+                normalize_value(constraint("spatial", operator, json!(["FR", "ES"]))),
+                "{}",
+                operator
+            );
+        }
+        // any other operator keeps the list as sent
+        assert_ne!(
+            normalize_value(constraint("spatial", "eq", json!(["ES", "FR"]))),
+            normalize_value(constraint("spatial", "eq", json!(["FR", "ES"])))
+        );
+    }
+
+    #[test]
+    fn test_logical_constraints_ignore_order_except_and_sequence() {
+        let purpose = constraint("purpose", "eq", json!("research"));
+        let count = constraint("count", "lteq", json!("5"));
+        for operand in ["and", "or", "xone"] {
+            assert_eq!(
+                normalize_value(json!({ operand: [purpose, count] })),
+                normalize_value(json!({ operand: [count, purpose] })),
+                "{}",
+                operand
+            );
+        }
+        assert_ne!(
+            normalize_value(json!({ "andSequence": [purpose, count] })),
+            normalize_value(json!({ "andSequence": [count, purpose] }))
+        );
+    }
+
+    #[test]
+    fn test_nested_constraints_are_normalized() {
+        let purpose = constraint("purpose", "eq", json!("research"));
+        let spatial = |countries: Value| constraint("spatial", "isAnyOf", countries);
+        assert_eq!(
+            normalize_value(json!({ "and": [purpose, spatial(json!(["ES", "FR"]))] })),
+            normalize_value(json!({ "and": [spatial(json!(["FR", "ES"])), purpose] }))
+        );
+    }
+
+    #[test]
+    fn test_rule_order_and_duplicates_are_ignored() {
+        let read = json!({ "action": "read" });
+        let use_for_research = json!({
+            "action": "use",
+            "constraint": [constraint("purpose", "eq", json!("research"))]
+        });
+        assert_eq!(
+            terms(json!({ "permission": [read, use_for_research] })),
+            terms(json!({ "permission": [use_for_research, read, read] }))
+        );
+        // the same rule as a prohibition is a different offer
+        assert_ne!(
+            terms(json!({ "permission": [read] })),
+            terms(json!({ "prohibition": [read] }))
+        );
+    }
+
+    #[test]
+    fn test_offer_referenced_by_id_accepts_the_reference() {
+        let offer: ContractRequestMessageOfferTypes =
+            serde_json::from_value(json!({ "@id": OFFER_ID })).unwrap();
+        let reference = terms(json!({ "permission": [{ "action": "use" }] }));
+        assert!(compare_offer_terms(&offer, &reference).is_ok());
+    }
+
+    #[test]
+    fn test_equivalent_offer_is_accepted() {
+        let purpose = constraint("purpose", "eq", json!("research"));
+        let count = constraint("count", "lteq", json!("5"));
+        let reference = terms(json!({
+            "permission": [{ "action": "use", "constraint": [purpose, count] }]
+        }));
+        let offer = requested(
+            TARGET,
+            json!({ "permission": [{ "action": "use", "constraint": [count, purpose] }] }),
+        );
+        assert!(compare_offer_terms(&offer, &reference).is_ok());
+    }
+
+    #[test]
+    fn test_changed_terms_are_refused() {
+        let reference = terms(json!({
+            "permission": [{
+                "action": "use",
+                "constraint": [constraint("count", "lteq", json!("5"))]
+            }]
+        }));
+
+        let loosened = requested(
+            TARGET,
+            json!({
+                "permission": [{
+                    "action": "use",
+                    "constraint": [constraint("count", "lteq", json!("50"))]
+                }]
+            }),
+        );
+        assert!(refusal(compare_offer_terms(&loosened, &reference))
+            .starts_with("Requested permissions do not match the ones of offer"));
+
+        let unconstrained = requested(TARGET, json!({ "permission": [{ "action": "use" }] }));
+        assert!(refusal(compare_offer_terms(&unconstrained, &reference))
+            .starts_with("Requested permissions do not match the ones of offer"));
+
+        let other_target =
+            requested("urn:dataset:2", json!({ "permission": [{ "action": "use" }] }));
+        assert_eq!(
+            refusal(compare_offer_terms(&other_target, &reference)),
+            format!(
+                "Requested target urn:dataset:2 does not match the target of offer {}",
+                OFFER_ID
+            )
+        );
+    }
+}
@@ -0,0 +1,195 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+#[cfg(test)]
+mod test_orchestration_notifications {
+    use crate::data::entities::{agreement, negotiation_process};
+    use crate::entities::negotiation_process::NegotiationProcessDto;
+    use crate::protocols::dsp::orchestrator::traits::orchestration_notifications::OrchestrationNotifications;
+    use crate::protocols::dsp::protocol_types::NegotiationProcessMessageType;
+    use rainbow_events::core::notification::notification_publisher::{
+        MockRainbowEventsPublisherTrait, RainbowEventsPublisherTrait,
+    };
+    use rainbow_events::core::notification::notification_types::{
+        RainbowEventsNotificationEvent, RainbowEventsNotificationMessageCategory,
+        RainbowEventsNotificationMessageOperation, RainbowEventsNotificationMessageTypes,
+    };
+    use serde_json::json;
+    use std::collections::HashMap;
+    use std::sync::{Arc, Mutex};
+
+    const PROCESS_ID: &str = "urn:negotiation-process:1";
+    const AGREEMENT_ID: &str = "urn:agreement:1";
+
+    type Published = Arc<Mutex<Vec<RainbowEventsNotificationEvent>>>;
+
+    /// Same notifications the protocol and rpc orchestrators send
+    struct Orchestrator {
+        events: Arc<dyn RainbowEventsPublisherTrait>,
+    }
+
+    impl OrchestrationNotifications for Orchestrator {
+        fn get_events_publisher(&self) -> Arc<dyn RainbowEventsPublisherTrait> {
+            self.events.clone()
+        }
+    }
+
+    fn orchestrator() -> (Orchestrator, Published) {
+        let published: Published = Arc::new(Mutex::new(vec![]));
+        let recorded = published.clone();
+        let mut events = MockRainbowEventsPublisherTrait::new();
+        events.expect_publish().returning(move |event| recorded.lock().unwrap().push(event));
+        (Orchestrator { events: Arc::new(events) }, published)
+    }
+
+    fn process(agreement: Option<agreement::Model>) -> NegotiationProcessDto {
+        NegotiationProcessDto {
+            inner: negotiation_process::Model {
+                id: PROCESS_ID.to_string(),
+                state: "AGREED".to_string(),
+                state_attribute: None,
+                associated_agent_peer: "did:web:consumer.example".to_string(),
+                protocol: "DSP".to_string(),
+                callback_address: Some("https://consumer.example/dsp".to_string()),
+                role: "Provider".to_string(),
+                properties: json!({}),
+                error_details: None,
+                created_at: chrono::Utc::now().into(),
+                updated_at: None,
+            },
+            identifiers: HashMap::from([
+                ("providerPid".to_string(), "urn:provider-pid:1".to_string()),
+                ("consumerPid".to_string(), "urn:consumer-pid:1".to_string()),
+            ]),
+            messages: vec![],
+            offers: vec![],
+            agreement,
+        }
+    }
+
+    fn agreement() -> agreement::Model {
+        agreement::Model {
+            id: AGREEMENT_ID.to_string(),
+            negotiation_agent_process_id: PROCESS_ID.to_string(),
+            negotiation_agent_message_id: "urn:negotiation-message:1".to_string(),
+            consumer_participant_id: "did:web:consumer.example".to_string(),
+            provider_participant_id: "did:web:provider.example".to_string(),
+            agreement_content: json!({ "permission": [{ "action": "use" }] }),
+            target: "urn:dataset:1".to_string(),
+            state: "ACTIVE".to_string(),
+            created_at: chrono::Utc::now().into(),
+            updated_at: None,
+            provider_signature: None,
+            consumer_signature: None,
+            valid_from: None,
+            valid_until: None,
+            revoked_at: None,
+            revocation_reason: None,
+        }
+    }
+
+    fn entity_ids(event: &RainbowEventsNotificationEvent) -> Vec<(&str, &str)> {
+        event.content.entity_ids.iter().map(|(name, id)| (name.as_str(), id.as_str())).collect()
+    }
+
+    #[test]
+    fn test_transition_names_the_message_and_the_process_ids() {
+        let (orchestrator, published) = orchestrator();
+
+        orchestrator.notify_process_transition(
+            &process(None),
+            &NegotiationProcessMessageType::NegotiationRequestMessage,
+            RainbowEventsNotificationMessageTypes::DSProtocolMessage,
+            RainbowEventsNotificationMessageOperation::IncomingMessage,
+        );
+
+        let published = published.lock().unwrap();
+        assert_eq!(published.len(), 1);
+        let event = &published[0];
+        assert_eq!(
+            event.category,
+            RainbowEventsNotificationMessageCategory::ContractNegotiation
+        );
+        assert_eq!(event.subcategory, "ContractRequestMessage");
+        assert_eq!(
+            event.message_type,
+            RainbowEventsNotificationMessageTypes::DSProtocolMessage
+        );
+        assert_eq!(
+            event.message_operation,
+            RainbowEventsNotificationMessageOperation::IncomingMessage
+        );
+        assert_eq!(
+            entity_ids(event),
+            vec![
+                ("consumerPid", "urn:consumer-pid:1"),
+                ("processId", PROCESS_ID),
+                ("providerPid", "urn:provider-pid:1"),
+            ]
+        );
+        assert_eq!(event.content.snapshot["id"], json!(PROCESS_ID));
+    }
+
+    #[test]
+    fn test_agreement_creation_names_the_agreement_parties() {
+        let (orchestrator, published) = orchestrator();
+
+        orchestrator.notify_agreement_creation(
+            &process(Some(agreement())),
+            RainbowEventsNotificationMessageTypes::RPCMessage,
+        );
+
+        let published = published.lock().unwrap();
+        assert_eq!(published.len(), 1);
+        let event = &published[0];
+        assert_eq!(
+            event.category,
+            RainbowEventsNotificationMessageCategory::ContractNegotiation
+        );
+        assert_eq!(event.subcategory, "Agreement");
+        assert_eq!(event.message_type, RainbowEventsNotificationMessageTypes::RPCMessage);
+        assert_eq!(
+            event.message_operation,
+            RainbowEventsNotificationMessageOperation::Creation
+        );
+        assert_eq!(
+            entity_ids(event),
+            vec![
+                ("agreementId", AGREEMENT_ID),
+                ("consumerParticipantId", "did:web:consumer.example"),
+                ("processId", PROCESS_ID),
+                ("providerParticipantId", "did:web:provider.example"),
+                ("target", "urn:dataset:1"),
+            ]
+        );
+        assert_eq!(event.content.snapshot["id"], json!(AGREEMENT_ID));
+    }
+
+    #[test]
+    fn test_no_agreement_no_creation_event() {
+        let (orchestrator, published) = orchestrator();
+
+        orchestrator.notify_agreement_creation(
+            &process(None),
+            RainbowEventsNotificationMessageTypes::DSProtocolMessage,
+        );
+
+        assert!(published.lock().unwrap().is_empty());
+    }
+}
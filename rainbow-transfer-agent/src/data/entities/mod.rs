@@ -17,6 +17,7 @@
  *
  */
 
+pub(crate) mod transfer_duty;
 pub(crate) mod transfer_message;
 pub mod transfer_process;
 pub(crate) mod transfer_process_identifier;
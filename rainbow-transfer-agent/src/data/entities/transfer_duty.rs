@@ -0,0 +1,114 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+use rainbow_common::utils::get_urn;
+use sea_orm::entity::prelude::*;
+use sea_orm::ActiveValue;
+use serde::{Deserialize, Serialize};
+use urn::Urn;
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "transfer_agent_duties")]
+#[serde(rename_all = "camelCase")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: String,
+    pub transfer_agent_process_id: String,
+    pub agreement_id: String,
+    pub rule_type: String,
+    pub action: String,
+    pub execution_kind: String,
+    pub callback_address: Option<String>,
+    pub state: String,
+    pub due_at: Option<DateTimeWithTimeZone>,
+    pub attempts: i32,
+    pub last_error: Option<String>,
+    pub duty: Json,
+    pub created_at: DateTimeWithTimeZone,
+    pub updated_at: Option<DateTimeWithTimeZone>,
+    pub fulfilled_at: Option<DateTimeWithTimeZone>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::transfer_process::Entity",
+        from = "Column::TransferAgentProcessId",
+        to = "super::transfer_process::Column::Id",
+        on_delete = "Cascade"
+    )]
+    Process,
+}
+
+impl Related<super::transfer_process::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Process.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+#[derive(Clone)]
+pub struct NewTransferDutyModel {
+    pub id: Option<Urn>,
+    pub transfer_agent_process_id: Urn,
+    pub agreement_id: Urn,
+    pub rule_type: String,
+    pub action: String,
+    pub execution_kind: String,
+    pub callback_address: Option<String>,
+    pub state: String,
+    pub due_at: Option<DateTimeWithTimeZone>,
+    pub duty: Json,
+}
+
+impl From<NewTransferDutyModel> for ActiveModel {
+    fn from(dto: NewTransferDutyModel) -> Self {
+        Self {
+            id: ActiveValue::Set(dto.id.unwrap_or(get_urn(None)).to_string()),
+            transfer_agent_process_id: ActiveValue::Set(dto.transfer_agent_process_id.to_string()),
+            agreement_id: ActiveValue::Set(dto.agreement_id.to_string()),
+            rule_type: ActiveValue::Set(dto.rule_type),
+            action: ActiveValue::Set(dto.action),
+            execution_kind: ActiveValue::Set(dto.execution_kind),
+            callback_address: ActiveValue::Set(dto.callback_address),
+            state: ActiveValue::Set(dto.state),
+            due_at: ActiveValue::Set(dto.due_at),
+            attempts: ActiveValue::Set(0),
+            last_error: ActiveValue::Set(None),
+            duty: ActiveValue::Set(dto.duty),
+            created_at: ActiveValue::Set(chrono::Utc::now().into()),
+            updated_at: ActiveValue::Set(None),
+            fulfilled_at: ActiveValue::Set(None),
+        }
+    }
+}
+
+pub struct EditTransferDutyModel {
+    pub state: Option<String>,
+    pub attempts: Option<i32>,
+    pub last_error: Option<String>,
+    pub due_at: Option<DateTimeWithTimeZone>,
+    pub fulfilled_at: Option<DateTimeWithTimeZone>,
+}
+
+impl Default for EditTransferDutyModel {
+    fn default() -> Self {
+        Self { state: None, attempts: None, last_error: None, due_at: None, fulfilled_at: None }
+    }
+}
@@ -18,9 +18,11 @@
  */
 
 use crate::data::factory_trait::TransferAgentRepoTrait;
+use crate::data::repo_traits::transfer_duty_repo::TransferDutyRepoTrait;
 use crate::data::repo_traits::transfer_message_repo::TransferMessageRepoTrait;
 use crate::data::repo_traits::transfer_process_identifier_repo::TransferIdentifierRepoTrait;
 use crate::data::repo_traits::transfer_process_repo::TransferProcessRepoTrait;
+use crate::data::repos_sql::transfer_duty_repo::TransferDutyRepoForSql;
 use crate::data::repos_sql::transfer_message_repo::TransferMessageRepoForSql;
 use crate::data::repos_sql::transfer_process_identifier_repo::TransferIdentifierRepoForSql;
 use crate::data::repos_sql::transfer_process_repo::TransferProcessRepoForSql;
@@ -31,6 +33,7 @@ pub struct TransferAgentRepoForSql {
     transfer_process_repo: Arc<dyn TransferProcessRepoTrait>,
     transfer_process_identifier_repo: Arc<dyn TransferIdentifierRepoTrait>,
     transfer_message_repo: Arc<dyn TransferMessageRepoTrait>,
+    transfer_duty_repo: Arc<dyn TransferDutyRepoTrait>,
 }
 
 impl TransferAgentRepoForSql {
@@ -41,6 +44,7 @@ impl TransferAgentRepoForSql {
                 db_connection.clone(),
             )),
             transfer_message_repo: Arc::new(TransferMessageRepoForSql::new(db_connection.clone())),
+            transfer_duty_repo: Arc::new(TransferDutyRepoForSql::new(db_connection.clone())),
         }
     }
 }
@@ -55,4 +59,7 @@ impl TransferAgentRepoTrait for TransferAgentRepoForSql {
     fn get_transfer_process_identifiers_repo(&self) -> Arc<dyn TransferIdentifierRepoTrait> {
         self.transfer_process_identifier_repo.clone()
     }
+    fn get_transfer_duty_repo(&self) -> Arc<dyn TransferDutyRepoTrait> {
+        self.transfer_duty_repo.clone()
+    }
 }
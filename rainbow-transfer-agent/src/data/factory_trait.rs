@@ -17,6 +17,7 @@
  *
  */
 
+use crate::data::repo_traits::transfer_duty_repo::TransferDutyRepoTrait;
 use crate::data::repo_traits::transfer_message_repo::TransferMessageRepoTrait;
 use crate::data::repo_traits::transfer_process_identifier_repo::TransferIdentifierRepoTrait;
 use crate::data::repo_traits::transfer_process_repo::TransferProcessRepoTrait;
@@ -27,4 +28,5 @@ pub trait TransferAgentRepoTrait: Send + Sync + 'static {
     fn get_transfer_process_repo(&self) -> Arc<dyn TransferProcessRepoTrait>;
     fn get_transfer_message_repo(&self) -> Arc<dyn TransferMessageRepoTrait>;
     fn get_transfer_process_identifiers_repo(&self) -> Arc<dyn TransferIdentifierRepoTrait>;
+    fn get_transfer_duty_repo(&self) -> Arc<dyn TransferDutyRepoTrait>;
 }
@@ -0,0 +1,109 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+use sea_orm_migration::prelude::*;
+
+pub struct Migration;
+impl MigrationName for Migration {
+    fn name(&self) -> &str {
+        "m20251118_000004_transfer_duties"
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(TransferAgentDuties::Table)
+                    .col(ColumnDef::new(TransferAgentDuties::Id).string().not_null().primary_key())
+                    .col(
+                        ColumnDef::new(TransferAgentDuties::TransferAgentProcessId)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(TransferAgentDuties::AgreementId).string().not_null())
+                    .col(ColumnDef::new(TransferAgentDuties::RuleType).string().not_null())
+                    .col(ColumnDef::new(TransferAgentDuties::Action).string().not_null())
+                    .col(ColumnDef::new(TransferAgentDuties::ExecutionKind).string().not_null())
+                    .col(ColumnDef::new(TransferAgentDuties::CallbackAddress).string())
+                    .col(ColumnDef::new(TransferAgentDuties::State).string().not_null())
+                    .col(ColumnDef::new(TransferAgentDuties::DueAt).timestamp_with_time_zone())
+                    .col(
+                        ColumnDef::new(TransferAgentDuties::Attempts)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(ColumnDef::new(TransferAgentDuties::LastError).string())
+                    .col(ColumnDef::new(TransferAgentDuties::Duty).json_binary().not_null())
+                    .col(
+                        ColumnDef::new(TransferAgentDuties::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(TransferAgentDuties::UpdatedAt).timestamp_with_time_zone())
+                    .col(
+                        ColumnDef::new(TransferAgentDuties::FulfilledAt).timestamp_with_time_zone(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-transfer_duties-process_id")
+                            .from(
+                                TransferAgentDuties::Table,
+                                TransferAgentDuties::TransferAgentProcessId,
+                            )
+                            .to(TransferAgentProcess::Table, TransferAgentProcess::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.drop_table(Table::drop().table(TransferAgentDuties::Table).to_owned()).await
+    }
+}
+
+#[derive(Iden)]
+pub enum TransferAgentDuties {
+    Table,
+    Id,
+    TransferAgentProcessId,
+    AgreementId,
+    RuleType,
+    Action,
+    ExecutionKind,
+    CallbackAddress,
+    State,
+    DueAt,
+    Attempts,
+    LastError,
+    Duty,
+    CreatedAt,
+    UpdatedAt,
+    FulfilledAt,
+}
+
+#[derive(Iden)]
+pub enum TransferAgentProcess {
+    Table,
+    Id,
+}
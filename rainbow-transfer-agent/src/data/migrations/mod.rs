@@ -22,11 +22,13 @@ use sea_orm_migration::prelude::*;
 mod m20251118_000001_transfer_process;
 mod m20251118_000002_transfer_messages;
 mod m20251118_000003_transfer_process_identifiers;
+mod m20251118_000004_transfer_duties;
 
 pub fn get_transfer_agent_migrations() -> Vec<Box<dyn MigrationTrait>> {
     vec![
         Box::new(m20251118_000001_transfer_process::Migration),
         Box::new(m20251118_000002_transfer_messages::Migration),
         Box::new(m20251118_000003_transfer_process_identifiers::Migration),
+        Box::new(m20251118_000004_transfer_duties::Migration),
     ]
 }
@@ -17,6 +17,7 @@
  *
  */
 
+pub(crate) mod transfer_duty_repo;
 pub(crate) mod transfer_message_repo;
 pub(crate) mod transfer_process_identifier_repo;
 pub(crate) mod transfer_process_repo;
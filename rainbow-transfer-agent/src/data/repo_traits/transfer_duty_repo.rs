@@ -0,0 +1,76 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+use crate::data::entities::transfer_duty;
+use crate::data::entities::transfer_duty::{EditTransferDutyModel, NewTransferDutyModel};
+use anyhow::Error;
+use chrono::{DateTime, Utc};
+use thiserror::Error;
+use urn::Urn;
+
+#[mockall::automock]
+#[async_trait::async_trait]
+pub trait TransferDutyRepoTrait: Send + Sync {
+    async fn get_all_transfer_duties(
+        &self,
+        limit: Option<u64>,
+        page: Option<u64>,
+    ) -> anyhow::Result<Vec<transfer_duty::Model>, TransferDutyRepoErrors>;
+    async fn get_duties_by_process_id(
+        &self,
+        process_id: &Urn,
+    ) -> anyhow::Result<Vec<transfer_duty::Model>, TransferDutyRepoErrors>;
+    /// Duties in any of the given states and execution kinds whose due date is before `due_before`
+    async fn get_duties_due_before(
+        &self,
+        states: &Vec<String>,
+        execution_kinds: &Vec<String>,
+        due_before: &DateTime<Utc>,
+    ) -> anyhow::Result<Vec<transfer_duty::Model>, TransferDutyRepoErrors>;
+    /// Duties in any of the given states and execution kinds that have no due date
+    async fn get_undated_duties(
+        &self,
+        states: &Vec<String>,
+        execution_kinds: &Vec<String>,
+    ) -> anyhow::Result<Vec<transfer_duty::Model>, TransferDutyRepoErrors>;
+    async fn get_transfer_duty_by_id(
+        &self,
+        id: &Urn,
+    ) -> anyhow::Result<Option<transfer_duty::Model>, TransferDutyRepoErrors>;
+    async fn create_transfer_duty(
+        &self,
+        new_model: &NewTransferDutyModel,
+    ) -> anyhow::Result<transfer_duty::Model, TransferDutyRepoErrors>;
+    async fn put_transfer_duty(
+        &self,
+        id: &Urn,
+        edit_model: &EditTransferDutyModel,
+    ) -> anyhow::Result<transfer_duty::Model, TransferDutyRepoErrors>;
+}
+
+#[derive(Debug, Error)]
+pub enum TransferDutyRepoErrors {
+    #[error("Transfer Duty not found")]
+    TransferDutyNotFound,
+    #[error("Error fetching transfer duty. {0}")]
+    ErrorFetchingTransferDuty(Error),
+    #[error("Error creating transfer duty. {0}")]
+    ErrorCreatingTransferDuty(Error),
+    #[error("Error updating transfer duty. {0}")]
+    ErrorUpdatingTransferDuty(Error),
+}
@@ -35,6 +35,10 @@ pub trait TransferProcessRepoTrait: Send + Sync {
         &self,
         ids: &Vec<Urn>,
     ) -> anyhow::Result<Vec<transfer_process::Model>, TransferProcessRepoErrors>;
+    async fn get_transfer_processes_by_states(
+        &self,
+        states: &Vec<String>,
+    ) -> anyhow::Result<Vec<transfer_process::Model>, TransferProcessRepoErrors>;
     async fn get_transfer_process_by_id(
         &self,
         id: &Urn,
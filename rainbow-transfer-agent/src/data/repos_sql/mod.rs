@@ -17,6 +17,7 @@
  *
  */
 
+pub(super) mod transfer_duty_repo;
 pub(super) mod transfer_message_repo;
 pub(super) mod transfer_process_identifier_repo;
 pub(super) mod transfer_process_repo;
@@ -0,0 +1,177 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+use crate::data::entities::transfer_duty;
+use crate::data::entities::transfer_duty::{EditTransferDutyModel, NewTransferDutyModel};
+use crate::data::repo_traits::transfer_duty_repo::{TransferDutyRepoErrors, TransferDutyRepoTrait};
+use chrono::{DateTime, Utc};
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter,
+    QueryOrder, QuerySelect,
+};
+use urn::Urn;
+
+pub struct TransferDutyRepoForSql {
+    db_connection: DatabaseConnection,
+}
+
+impl TransferDutyRepoForSql {
+    pub fn new(db_connection: DatabaseConnection) -> Self {
+        Self { db_connection }
+    }
+}
+
+#[async_trait::async_trait]
+impl TransferDutyRepoTrait for TransferDutyRepoForSql {
+    async fn get_all_transfer_duties(
+        &self,
+        limit: Option<u64>,
+        page: Option<u64>,
+    ) -> anyhow::Result<Vec<transfer_duty::Model>, TransferDutyRepoErrors> {
+        let duties = transfer_duty::Entity::find()
+            .limit(limit.unwrap_or(20))
+            .offset(page.map(|p| p * limit.unwrap_or(20)).unwrap_or(0))
+            .order_by_desc(transfer_duty::Column::CreatedAt)
+            .all(&self.db_connection)
+            .await;
+
+        match duties {
+            Ok(duties) => Ok(duties),
+            Err(e) => Err(TransferDutyRepoErrors::ErrorFetchingTransferDuty(e.into())),
+        }
+    }
+
+    async fn get_duties_by_process_id(
+        &self,
+        process_id: &Urn,
+    ) -> anyhow::Result<Vec<transfer_duty::Model>, TransferDutyRepoErrors> {
+        let pid = process_id.to_string();
+        let duties = transfer_duty::Entity::find()
+            .filter(transfer_duty::Column::TransferAgentProcessId.eq(pid))
+            .order_by_asc(transfer_duty::Column::CreatedAt)
+            .all(&self.db_connection)
+            .await;
+
+        match duties {
+            Ok(duties) => Ok(duties),
+            Err(e) => Err(TransferDutyRepoErrors::ErrorFetchingTransferDuty(e.into())),
+        }
+    }
+
+    async fn get_duties_due_before(
+        &self,
+        states: &Vec<String>,
+        execution_kinds: &Vec<String>,
+        due_before: &DateTime<Utc>,
+    ) -> anyhow::Result<Vec<transfer_duty::Model>, TransferDutyRepoErrors> {
+        let duties = transfer_duty::Entity::find()
+            .filter(transfer_duty::Column::State.is_in(states.clone()))
+            .filter(transfer_duty::Column::ExecutionKind.is_in(execution_kinds.clone()))
+            .filter(transfer_duty::Column::DueAt.lte(*due_before))
+            .order_by_asc(transfer_duty::Column::DueAt)
+            .all(&self.db_connection)
+            .await;
+
+        match duties {
+            Ok(duties) => Ok(duties),
+            Err(e) => Err(TransferDutyRepoErrors::ErrorFetchingTransferDuty(e.into())),
+        }
+    }
+
+    async fn get_undated_duties(
+        &self,
+        states: &Vec<String>,
+        execution_kinds: &Vec<String>,
+    ) -> anyhow::Result<Vec<transfer_duty::Model>, TransferDutyRepoErrors> {
+        let duties = transfer_duty::Entity::find()
+            .filter(transfer_duty::Column::State.is_in(states.clone()))
+            .filter(transfer_duty::Column::ExecutionKind.is_in(execution_kinds.clone()))
+            .filter(transfer_duty::Column::DueAt.is_null())
+            .order_by_asc(transfer_duty::Column::CreatedAt)
+            .all(&self.db_connection)
+            .await;
+
+        match duties {
+            Ok(duties) => Ok(duties),
+            Err(e) => Err(TransferDutyRepoErrors::ErrorFetchingTransferDuty(e.into())),
+        }
+    }
+
+    async fn get_transfer_duty_by_id(
+        &self,
+        id: &Urn,
+    ) -> anyhow::Result<Option<transfer_duty::Model>, TransferDutyRepoErrors> {
+        let did = id.to_string();
+        let duty = transfer_duty::Entity::find_by_id(did).one(&self.db_connection).await;
+        match duty {
+            Ok(duty) => Ok(duty),
+            Err(e) => Err(TransferDutyRepoErrors::ErrorFetchingTransferDuty(e.into())),
+        }
+    }
+
+    async fn create_transfer_duty(
+        &self,
+        new_model: &NewTransferDutyModel,
+    ) -> anyhow::Result<transfer_duty::Model, TransferDutyRepoErrors> {
+        let model: transfer_duty::ActiveModel = new_model.clone().into();
+        let result =
+            transfer_duty::Entity::insert(model).exec_with_returning(&self.db_connection).await;
+        match result {
+            Ok(duty) => Ok(duty),
+            Err(e) => Err(TransferDutyRepoErrors::ErrorCreatingTransferDuty(e.into())),
+        }
+    }
+
+    async fn put_transfer_duty(
+        &self,
+        id: &Urn,
+        edit_model: &EditTransferDutyModel,
+    ) -> anyhow::Result<transfer_duty::Model, TransferDutyRepoErrors> {
+        let did = id.to_string();
+        let old_model = transfer_duty::Entity::find_by_id(did).one(&self.db_connection).await;
+        let old_model = match old_model {
+            Ok(old_model) => match old_model {
+                Some(old_model) => old_model,
+                None => return Err(TransferDutyRepoErrors::TransferDutyNotFound),
+            },
+            Err(e) => return Err(TransferDutyRepoErrors::ErrorFetchingTransferDuty(e.into())),
+        };
+        let mut old_active_model: transfer_duty::ActiveModel = old_model.into();
+        if let Some(state) = &edit_model.state {
+            old_active_model.state = ActiveValue::Set(state.clone());
+        }
+        if let Some(attempts) = &edit_model.attempts {
+            old_active_model.attempts = ActiveValue::Set(*attempts);
+        }
+        if let Some(last_error) = &edit_model.last_error {
+            old_active_model.last_error = ActiveValue::Set(Some(last_error.clone()));
+        }
+        if let Some(due_at) = &edit_model.due_at {
+            old_active_model.due_at = ActiveValue::Set(Some(*due_at));
+        }
+        if let Some(fulfilled_at) = &edit_model.fulfilled_at {
+            old_active_model.fulfilled_at = ActiveValue::Set(Some(*fulfilled_at));
+        }
+        old_active_model.updated_at = ActiveValue::Set(Some(chrono::Utc::now().into()));
+        let model = old_active_model.update(&self.db_connection).await;
+        match model {
+            Ok(model) => Ok(model),
+            Err(e) => Err(TransferDutyRepoErrors::ErrorUpdatingTransferDuty(e.into())),
+        }
+    }
+}
@@ -74,6 +74,20 @@ impl TransferProcessRepoTrait for TransferProcessRepoForSql {
         }
     }
 
+    async fn get_transfer_processes_by_states(
+        &self,
+        states: &Vec<String>,
+    ) -> anyhow::Result<Vec<transfer_process::Model>, TransferProcessRepoErrors> {
+        let transfer_process = transfer_process::Entity::find()
+            .filter(transfer_process::Column::State.is_in(states.clone()))
+            .all(&self.db_connection)
+            .await;
+        match transfer_process {
+            Ok(transfer_process) => Ok(transfer_process),
+            Err(e) => Err(TransferProcessRepoErrors::ErrorFetchingTransferProcess(e.into())),
+        }
+    }
+
     async fn get_transfer_process_by_id(
         &self,
         id: &Urn,
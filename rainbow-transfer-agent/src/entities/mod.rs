@@ -17,5 +17,6 @@
  *
  */
 
+pub(crate) mod transfer_duties;
 pub(crate) mod transfer_messages;
 pub(crate) mod transfer_process;
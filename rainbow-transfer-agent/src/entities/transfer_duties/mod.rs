@@ -0,0 +1,187 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+use crate::data::entities::transfer_duty as transfer_duty_model;
+use crate::data::entities::transfer_duty::NewTransferDutyModel;
+use anyhow::bail;
+use chrono::{DateTime, FixedOffset, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::FromStr;
+use urn::Urn;
+
+pub(crate) mod transfer_duties;
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub enum TransferDutyState {
+    Pending,
+    Fulfilled,
+    Failed,
+}
+
+impl Display for TransferDutyState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let str = match self {
+            TransferDutyState::Pending => "PENDING".to_string(),
+            TransferDutyState::Fulfilled => "FULFILLED".to_string(),
+            TransferDutyState::Failed => "FAILED".to_string(),
+        };
+        write!(f, "{}", str)
+    }
+}
+
+impl FromStr for TransferDutyState {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "PENDING" => Ok(TransferDutyState::Pending),
+            "FULFILLED" => Ok(TransferDutyState::Fulfilled),
+            "FAILED" => Ok(TransferDutyState::Failed),
+            _ => bail!("Invalid transfer duty state: {}", s),
+        }
+    }
+}
+
+/// How a duty gets discharged. Everything but `Manual` is executed by the duty scheduler,
+/// manual duties (deleting the transferred data among them) are listed once overdue and
+/// fulfilled through the API
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub enum TransferDutyExecutionKind {
+    Callback,
+    Notify,
+    Manual,
+}
+
+impl TransferDutyExecutionKind {
+    pub fn automatic() -> Vec<TransferDutyExecutionKind> {
+        vec![TransferDutyExecutionKind::Callback, TransferDutyExecutionKind::Notify]
+    }
+}
+
+impl Display for TransferDutyExecutionKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let str = match self {
+            TransferDutyExecutionKind::Callback => "CALLBACK".to_string(),
+            TransferDutyExecutionKind::Notify => "NOTIFY".to_string(),
+            TransferDutyExecutionKind::Manual => "MANUAL".to_string(),
+        };
+        write!(f, "{}", str)
+    }
+}
+
+impl FromStr for TransferDutyExecutionKind {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "CALLBACK" => Ok(TransferDutyExecutionKind::Callback),
+            "NOTIFY" => Ok(TransferDutyExecutionKind::Notify),
+            "MANUAL" => Ok(TransferDutyExecutionKind::Manual),
+            _ => bail!("Invalid transfer duty execution kind: {}", s),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TransferDutyDto {
+    #[serde(flatten)]
+    pub inner: transfer_duty_model::Model,
+    pub overdue: bool,
+}
+
+impl From<transfer_duty_model::Model> for TransferDutyDto {
+    fn from(model: transfer_duty_model::Model) -> Self {
+        let overdue = model.state == TransferDutyState::Pending.to_string()
+            && model.due_at.map(|due_at| due_at < Utc::now()).unwrap_or(false);
+        Self { inner: model, overdue }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
+pub struct NewTransferDutyDto {
+    pub id: Option<Urn>,
+    pub transfer_agent_process_id: Urn,
+    pub agreement_id: Urn,
+    pub rule_type: String,
+    pub action: String,
+    pub execution_kind: TransferDutyExecutionKind,
+    pub callback_address: Option<String>,
+    pub due_at: Option<DateTime<FixedOffset>>,
+    pub duty: serde_json::Value,
+}
+
+impl From<NewTransferDutyDto> for NewTransferDutyModel {
+    fn from(dto: NewTransferDutyDto) -> Self {
+        Self {
+            id: dto.id,
+            transfer_agent_process_id: dto.transfer_agent_process_id,
+            agreement_id: dto.agreement_id,
+            rule_type: dto.rule_type,
+            action: dto.action,
+            execution_kind: dto.execution_kind.to_string(),
+            callback_address: dto.callback_address,
+            state: TransferDutyState::Pending.to_string(),
+            due_at: dto.due_at,
+            duty: dto.duty,
+        }
+    }
+}
+
+#[mockall::automock]
+#[async_trait::async_trait]
+pub trait TransferAgentDutiesTrait: Send + Sync + 'static {
+    async fn get_all_transfer_duties(
+        &self,
+        limit: Option<u64>,
+        page: Option<u64>,
+    ) -> anyhow::Result<Vec<TransferDutyDto>>;
+    async fn get_duties_by_process_id(
+        &self,
+        process_id: &Urn,
+    ) -> anyhow::Result<Vec<TransferDutyDto>>;
+    async fn get_transfer_duty_by_id(&self, id: &Urn) -> anyhow::Result<TransferDutyDto>;
+    /// Pending duties of any kind whose due date has passed
+    async fn get_overdue_duties(&self) -> anyhow::Result<Vec<TransferDutyDto>>;
+    /// Pending automatic duties ready to be executed
+    async fn get_due_automatic_duties(&self) -> anyhow::Result<Vec<TransferDutyDto>>;
+    /// Pending delete duties without a deadline, due once their transfer has ended
+    async fn get_duties_awaiting_transfer_end(&self) -> anyhow::Result<Vec<TransferDutyDto>>;
+    async fn create_transfer_duty(
+        &self,
+        new_model: &NewTransferDutyDto,
+    ) -> anyhow::Result<TransferDutyDto>;
+    /// Sets the due date of a pending duty
+    async fn set_transfer_duty_due_date(
+        &self,
+        id: &Urn,
+        due_at: DateTime<FixedOffset>,
+    ) -> anyhow::Result<TransferDutyDto>;
+    /// Marks a pending duty as discharged
+    async fn fulfill_transfer_duty(&self, id: &Urn) -> anyhow::Result<TransferDutyDto>;
+    /// Records a failed execution, the duty is given up once `max_attempts` is reached
+    async fn record_transfer_duty_failure(
+        &self,
+        id: &Urn,
+        error: &str,
+        max_attempts: i32,
+    ) -> anyhow::Result<TransferDutyDto>;
+}
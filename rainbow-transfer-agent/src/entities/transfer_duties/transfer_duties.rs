@@ -0,0 +1,245 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+use crate::data::entities::transfer_duty::{EditTransferDutyModel, NewTransferDutyModel};
+use crate::data::factory_trait::TransferAgentRepoTrait;
+use crate::data::repo_traits::transfer_duty_repo::TransferDutyRepoErrors;
+use crate::entities::transfer_duties::{
+    NewTransferDutyDto, TransferAgentDutiesTrait, TransferDutyDto, TransferDutyExecutionKind,
+    TransferDutyState,
+};
+use chrono::{DateTime, FixedOffset};
+use rainbow_common::errors::helpers::BadFormat;
+use rainbow_common::errors::{CommonErrors, ErrorLog};
+use std::sync::Arc;
+use tracing::error;
+use urn::Urn;
+
+const DELETE_ACTION: &str = "delete";
+
+pub struct TransferAgentDutiesService {
+    pub transfer_repo: Arc<dyn TransferAgentRepoTrait>,
+}
+
+impl TransferAgentDutiesService {
+    pub fn new(transfer_repo: Arc<dyn TransferAgentRepoTrait>) -> Self {
+        Self { transfer_repo }
+    }
+
+    fn map_repo_error(id: &Urn, e: TransferDutyRepoErrors) -> CommonErrors {
+        let err = match e {
+            TransferDutyRepoErrors::TransferDutyNotFound => {
+                CommonErrors::missing_resource_new(&id.to_string(), "Transfer Duty not found")
+            }
+            _ => CommonErrors::database_new(&e.to_string()),
+        };
+        error!("{}", err.log());
+        err
+    }
+
+    async fn get_pending_duty(&self, id: &Urn) -> anyhow::Result<TransferDutyDto> {
+        let duty = self.get_transfer_duty_by_id(id).await?;
+        if duty.inner.state != TransferDutyState::Pending.to_string() {
+            let err = CommonErrors::format_new(
+                BadFormat::Received,
+                format!("Transfer Duty {} is already {}", id, duty.inner.state).as_str(),
+            );
+            error!("{}", err.log());
+            return Err(err.into());
+        }
+        Ok(duty)
+    }
+
+    async fn get_pending_duties_due_now(
+        &self,
+        execution_kinds: Vec<TransferDutyExecutionKind>,
+    ) -> anyhow::Result<Vec<TransferDutyDto>> {
+        let states = vec![TransferDutyState::Pending.to_string()];
+        let execution_kinds = execution_kinds.iter().map(|k| k.to_string()).collect();
+        let duties = self
+            .transfer_repo
+            .get_transfer_duty_repo()
+            .get_duties_due_before(&states, &execution_kinds, &chrono::Utc::now())
+            .await
+            .map_err(|e| {
+                let err = CommonErrors::database_new(&e.to_string());
+                error!("{}", err.log());
+                err
+            })?;
+        Ok(duties.into_iter().map(TransferDutyDto::from).collect())
+    }
+}
+
+#[async_trait::async_trait]
+impl TransferAgentDutiesTrait for TransferAgentDutiesService {
+    async fn get_all_transfer_duties(
+        &self,
+        limit: Option<u64>,
+        page: Option<u64>,
+    ) -> anyhow::Result<Vec<TransferDutyDto>> {
+        let duties = self
+            .transfer_repo
+            .get_transfer_duty_repo()
+            .get_all_transfer_duties(limit, page)
+            .await
+            .map_err(|e| {
+                let err = CommonErrors::database_new(&e.to_string());
+                error!("{}", err.log());
+                err
+            })?;
+        Ok(duties.into_iter().map(TransferDutyDto::from).collect())
+    }
+
+    async fn get_duties_by_process_id(
+        &self,
+        process_id: &Urn,
+    ) -> anyhow::Result<Vec<TransferDutyDto>> {
+        let duties = self
+            .transfer_repo
+            .get_transfer_duty_repo()
+            .get_duties_by_process_id(process_id)
+            .await
+            .map_err(|e| {
+                let err = CommonErrors::database_new(&e.to_string());
+                error!("{}", err.log());
+                err
+            })?;
+        Ok(duties.into_iter().map(TransferDutyDto::from).collect())
+    }
+
+    async fn get_transfer_duty_by_id(&self, id: &Urn) -> anyhow::Result<TransferDutyDto> {
+        let duty = self
+            .transfer_repo
+            .get_transfer_duty_repo()
+            .get_transfer_duty_by_id(id)
+            .await
+            .map_err(|e| Self::map_repo_error(id, e))?
+            .ok_or_else(|| {
+                Self::map_repo_error(id, TransferDutyRepoErrors::TransferDutyNotFound)
+            })?;
+        Ok(TransferDutyDto::from(duty))
+    }
+
+    async fn get_overdue_duties(&self) -> anyhow::Result<Vec<TransferDutyDto>> {
+        let mut execution_kinds = TransferDutyExecutionKind::automatic();
+        execution_kinds.push(TransferDutyExecutionKind::Manual);
+        self.get_pending_duties_due_now(execution_kinds).await
+    }
+
+    async fn get_due_automatic_duties(&self) -> anyhow::Result<Vec<TransferDutyDto>> {
+        self.get_pending_duties_due_now(TransferDutyExecutionKind::automatic()).await
+    }
+
+    async fn get_duties_awaiting_transfer_end(&self) -> anyhow::Result<Vec<TransferDutyDto>> {
+        let states = vec![TransferDutyState::Pending.to_string()];
+        let execution_kinds = vec![TransferDutyExecutionKind::Manual.to_string()];
+        let duties = self
+            .transfer_repo
+            .get_transfer_duty_repo()
+            .get_undated_duties(&states, &execution_kinds)
+            .await
+            .map_err(|e| {
+                let err = CommonErrors::database_new(&e.to_string());
+                error!("{}", err.log());
+                err
+            })?;
+        Ok(duties
+            .into_iter()
+            .filter(|duty| duty.action == DELETE_ACTION)
+            .map(TransferDutyDto::from)
+            .collect())
+    }
+
+    async fn create_transfer_duty(
+        &self,
+        new_model_dto: &NewTransferDutyDto,
+    ) -> anyhow::Result<TransferDutyDto> {
+        let new_model: NewTransferDutyModel = new_model_dto.clone().into();
+        let created = self
+            .transfer_repo
+            .get_transfer_duty_repo()
+            .create_transfer_duty(&new_model)
+            .await
+            .map_err(|e| {
+                let err = CommonErrors::database_new(&e.to_string());
+                error!("{}", err.log());
+                err
+            })?;
+        Ok(TransferDutyDto::from(created))
+    }
+
+    async fn set_transfer_duty_due_date(
+        &self,
+        id: &Urn,
+        due_at: DateTime<FixedOffset>,
+    ) -> anyhow::Result<TransferDutyDto> {
+        self.get_pending_duty(id).await?;
+        let edit_model = EditTransferDutyModel { due_at: Some(due_at), ..Default::default() };
+        let updated = self
+            .transfer_repo
+            .get_transfer_duty_repo()
+            .put_transfer_duty(id, &edit_model)
+            .await
+            .map_err(|e| Self::map_repo_error(id, e))?;
+        Ok(TransferDutyDto::from(updated))
+    }
+
+    async fn fulfill_transfer_duty(&self, id: &Urn) -> anyhow::Result<TransferDutyDto> {
+        let duty = self.get_pending_duty(id).await?;
+        let edit_model = EditTransferDutyModel {
+            state: Some(TransferDutyState::Fulfilled.to_string()),
+            attempts: Some(duty.inner.attempts + 1),
+            fulfilled_at: Some(chrono::Utc::now().into()),
+            ..Default::default()
+        };
+        let updated = self
+            .transfer_repo
+            .get_transfer_duty_repo()
+            .put_transfer_duty(id, &edit_model)
+            .await
+            .map_err(|e| Self::map_repo_error(id, e))?;
+        Ok(TransferDutyDto::from(updated))
+    }
+
+    async fn record_transfer_duty_failure(
+        &self,
+        id: &Urn,
+        error: &str,
+        max_attempts: i32,
+    ) -> anyhow::Result<TransferDutyDto> {
+        let duty = self.get_pending_duty(id).await?;
+        let attempts = duty.inner.attempts + 1;
+        let state = match attempts >= max_attempts {
+            true => Some(TransferDutyState::Failed.to_string()),
+            false => None,
+        };
+        let edit_model = EditTransferDutyModel {
+            state,
+            attempts: Some(attempts),
+            last_error: Some(error.to_string()),
+            ..Default::default()
+        };
+        let updated = self
+            .transfer_repo
+            .get_transfer_duty_repo()
+            .put_transfer_duty(id, &edit_model)
+            .await
+            .map_err(|e| Self::map_repo_error(id, e))?;
+        Ok(TransferDutyDto::from(updated))
+    }
+}
@@ -104,6 +104,10 @@ pub trait TransferAgentProcessesTrait: Send + Sync + 'static {
         &self,
         ids: &Vec<Urn>,
     ) -> anyhow::Result<Vec<TransferProcessDto>>;
+    async fn get_transfer_processes_by_states(
+        &self,
+        states: &Vec<String>,
+    ) -> anyhow::Result<Vec<TransferProcessDto>>;
     async fn get_transfer_process_by_id(&self, id: &Urn) -> anyhow::Result<TransferProcessDto>;
     async fn get_transfer_process_by_key_id(
         &self,
@@ -143,6 +143,30 @@ impl TransferAgentProcessesTrait for TransferAgentProcessesService {
         Ok(dtos)
     }
 
+    async fn get_transfer_processes_by_states(
+        &self,
+        states: &Vec<String>,
+    ) -> anyhow::Result<Vec<TransferProcessDto>> {
+        let processes = self
+            .transfer_repo
+            .get_transfer_process_repo()
+            .get_transfer_processes_by_states(states)
+            .await
+            .map_err(|e| {
+                let err = CommonErrors::database_new(&e.to_string());
+                error!("{}", err.log());
+                err
+            })?;
+
+        let mut dtos = Vec::with_capacity(processes.len());
+        for p in processes {
+            let dto = self.enrich_process(p).await?;
+            dtos.push(dto);
+        }
+
+        Ok(dtos)
+    }
+
     async fn get_transfer_process_by_id(&self, id: &Urn) -> anyhow::Result<TransferProcessDto> {
         let process = self
             .transfer_repo
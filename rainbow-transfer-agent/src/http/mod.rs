@@ -18,5 +18,6 @@
  */
 
 pub(crate) mod common;
+pub(crate) mod transfer_duties;
 pub(crate) mod transfer_messages;
 pub(crate) mod transfer_process;
@@ -0,0 +1,133 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+use axum::{
+    extract::{FromRef, Path, Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    routing::{get, post},
+    Json, Router,
+};
+use serde::Deserialize;
+use std::sync::Arc;
+
+use crate::entities::transfer_duties::TransferAgentDutiesTrait;
+use crate::errors::error_adapter::CustomToResponse;
+use crate::http::common::parse_urn;
+use rainbow_common::config::services::TransferConfig;
+
+#[derive(Clone)]
+pub struct TransferAgentDutiesRouter {
+    service: Arc<dyn TransferAgentDutiesTrait>,
+    config: Arc<TransferConfig>,
+}
+
+#[derive(Deserialize)]
+pub struct PaginationParams {
+    pub limit: Option<u64>,
+    pub page: Option<u64>,
+}
+
+impl FromRef<TransferAgentDutiesRouter> for Arc<dyn TransferAgentDutiesTrait> {
+    fn from_ref(state: &TransferAgentDutiesRouter) -> Self {
+        state.service.clone()
+    }
+}
+
+impl FromRef<TransferAgentDutiesRouter> for Arc<TransferConfig> {
+    fn from_ref(state: &TransferAgentDutiesRouter) -> Self {
+        state.config.clone()
+    }
+}
+
+impl TransferAgentDutiesRouter {
+    pub fn new(service: Arc<dyn TransferAgentDutiesTrait>, config: Arc<TransferConfig>) -> Self {
+        Self { service, config }
+    }
+
+    pub fn router(self) -> Router {
+        Router::new()
+            .route("/", get(Self::handle_get_all_duties))
+            .route("/overdue", get(Self::handle_get_overdue_duties))
+            .route("/{id}", get(Self::handle_get_duty_by_id))
+            .route("/{id}/fulfill", post(Self::handle_fulfill_duty))
+            .route("/process/{process_id}", get(Self::handle_get_duties_by_process_id))
+            .with_state(self)
+    }
+
+    async fn handle_get_all_duties(
+        State(state): State<TransferAgentDutiesRouter>,
+        Query(params): Query<PaginationParams>,
+    ) -> impl IntoResponse {
+        match state.service.get_all_transfer_duties(params.limit, params.page).await {
+            Ok(duties) => (StatusCode::OK, Json(duties)).into_response(),
+            Err(err) => err.to_response(),
+        }
+    }
+
+    async fn handle_get_overdue_duties(
+        State(state): State<TransferAgentDutiesRouter>,
+    ) -> impl IntoResponse {
+        match state.service.get_overdue_duties().await {
+            Ok(duties) => (StatusCode::OK, Json(duties)).into_response(),
+            Err(err) => err.to_response(),
+        }
+    }
+
+    async fn handle_get_duty_by_id(
+        State(state): State<TransferAgentDutiesRouter>,
+        Path(id): Path<String>,
+    ) -> impl IntoResponse {
+        let id_urn = match parse_urn(&id) {
+            Ok(urn) => urn,
+            Err(resp) => return resp,
+        };
+        match state.service.get_transfer_duty_by_id(&id_urn).await {
+            Ok(duty) => (StatusCode::OK, Json(duty)).into_response(),
+            Err(err) => err.to_response(),
+        }
+    }
+
+    async fn handle_fulfill_duty(
+        State(state): State<TransferAgentDutiesRouter>,
+        Path(id): Path<String>,
+    ) -> impl IntoResponse {
+        let id_urn = match parse_urn(&id) {
+            Ok(urn) => urn,
+            Err(resp) => return resp,
+        };
+        match state.service.fulfill_transfer_duty(&id_urn).await {
+            Ok(duty) => (StatusCode::OK, Json(duty)).into_response(),
+            Err(err) => err.to_response(),
+        }
+    }
+
+    async fn handle_get_duties_by_process_id(
+        State(state): State<TransferAgentDutiesRouter>,
+        Path(process_id): Path<String>,
+    ) -> impl IntoResponse {
+        let process_urn = match parse_urn(&process_id) {
+            Ok(urn) => urn,
+            Err(resp) => return resp,
+        };
+        match state.service.get_duties_by_process_id(&process_urn).await {
+            Ok(duties) => (StatusCode::OK, Json(duties)).into_response(),
+            Err(err) => err.to_response(),
+        }
+    }
+}
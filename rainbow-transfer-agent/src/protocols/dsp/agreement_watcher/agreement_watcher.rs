@@ -0,0 +1,121 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+use crate::entities::transfer_process::{TransferAgentProcessesTrait, TransferProcessDto};
+use crate::protocols::dsp::agreement_watcher::AgreementWatcherTrait;
+use crate::protocols::dsp::facades::agreement_facade::AgreementFacadeTrait;
+use crate::protocols::dsp::facades::termination_facade::TransferTerminationFacadeTrait;
+use crate::protocols::dsp::protocol_types::TransferProcessState;
+use rainbow_negotiation_agent::AgreementRevocationStatusDto;
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::Arc;
+use tracing::{error, info};
+use urn::Urn;
+
+pub struct AgreementWatcherService {
+    transfer_process_service: Arc<dyn TransferAgentProcessesTrait>,
+    agreement_facade: Arc<dyn AgreementFacadeTrait>,
+    termination_facade: Arc<dyn TransferTerminationFacadeTrait>,
+}
+
+impl AgreementWatcherService {
+    pub fn new(
+        transfer_process_service: Arc<dyn TransferAgentProcessesTrait>,
+        agreement_facade: Arc<dyn AgreementFacadeTrait>,
+        termination_facade: Arc<dyn TransferTerminationFacadeTrait>,
+    ) -> Self {
+        Self { transfer_process_service, agreement_facade, termination_facade }
+    }
+
+    async fn terminate_transfer(
+        &self,
+        process: &TransferProcessDto,
+        status: &AgreementRevocationStatusDto,
+    ) -> anyhow::Result<()> {
+        let (code, reason) = match status.revoked {
+            true => (
+                "AGREEMENT_REVOKED",
+                status
+                    .revocation_reason
+                    .clone()
+                    .unwrap_or(format!("Agreement {} was revoked", status.agreement_id)),
+            ),
+            false => (
+                "AGREEMENT_EXPIRED",
+                format!("Agreement {} expired", status.agreement_id),
+            ),
+        };
+        self.termination_facade.terminate_transfer(process, code, reason).await
+    }
+}
+
+#[async_trait::async_trait]
+impl AgreementWatcherTrait for AgreementWatcherService {
+    async fn terminate_transfers_of_invalid_agreements(&self) -> anyhow::Result<()> {
+        let ongoing_states = vec![
+            TransferProcessState::Requested.to_string(),
+            TransferProcessState::Started.to_string(),
+            TransferProcessState::Suspended.to_string(),
+        ];
+        let processes =
+            self.transfer_process_service.get_transfer_processes_by_states(&ongoing_states).await?;
+
+        let mut processes_by_agreement: HashMap<String, Vec<TransferProcessDto>> = HashMap::new();
+        for process in processes {
+            processes_by_agreement
+                .entry(process.inner.agreement_id.clone())
+                .or_default()
+                .push(process);
+        }
+
+        for (agreement_id, processes) in processes_by_agreement {
+            // one broken process must not keep the others running under an invalid agreement
+            let agreement_urn = match Urn::from_str(agreement_id.as_str()) {
+                Ok(agreement_urn) => agreement_urn,
+                Err(e) => {
+                    error!("Agreement id {} is not a valid urn: {}", agreement_id, e);
+                    continue;
+                }
+            };
+            let status =
+                match self.agreement_facade.get_agreement_revocation_status(&agreement_urn).await {
+                    Ok(status) => status,
+                    Err(e) => {
+                        error!("Unable to check agreement {}: {}", agreement_id, e);
+                        continue;
+                    }
+                };
+            if !status.revoked && !status.expired {
+                continue;
+            }
+            for process in processes {
+                match self.terminate_transfer(&process, &status).await {
+                    Ok(_) => info!(
+                        "Transfer process {} terminated, agreement {} is {}",
+                        process.inner.id, agreement_id, status.state
+                    ),
+                    Err(e) => {
+                        error!("Unable to terminate transfer process {}: {}", process.inner.id, e)
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
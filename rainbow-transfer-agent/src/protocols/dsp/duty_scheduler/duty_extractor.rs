@@ -0,0 +1,161 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+use crate::entities::transfer_duties::TransferDutyExecutionKind;
+use chrono::{DateTime, Duration, FixedOffset};
+use rainbow_common::dsp_common::odrl_evaluator::odrl_terms::{
+    as_list, conjunctive_constraints, parse_date_time, term_value, unwrap_json_ld,
+    OdrlConstraintTerm,
+};
+use serde_json::Value;
+
+/// A duty found in the agreement, ready to be persisted
+#[derive(Debug, Clone)]
+pub struct ExtractedDuty {
+    pub rule_type: String,
+    pub action: String,
+    pub execution_kind: TransferDutyExecutionKind,
+    pub callback_address: Option<String>,
+    pub due_at: Option<DateTime<FixedOffset>>,
+    pub duty: Value,
+}
+
+/// Collects the agreement obligations and the duties attached to its permissions.
+/// Due dates come from `elapsedTime` constraints, counted from `started_at`, or from
+/// `dateTime` constraints. Automatic duties without either are due right away, manual ones
+/// stay undated. The agent has no way to erase data out of the consumer sink, so delete
+/// duties are manual, and undated ones become due once the transfer ends
+pub fn extract_duties(
+    agreement_content: &Value,
+    started_at: &DateTime<FixedOffset>,
+) -> Vec<ExtractedDuty> {
+    let obligations = agreement_content
+        .get("obligation")
+        .map(as_list)
+        .unwrap_or_default()
+        .into_iter()
+        .map(|duty| ("obligation", duty));
+    let permission_duties = agreement_content
+        .get("permission")
+        .map(as_list)
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|permission| permission.get("duty"))
+        .flat_map(as_list)
+        .map(|duty| ("duty", duty));
+
+    obligations
+        .chain(permission_duties)
+        .filter_map(|(rule_type, duty)| extract_duty(rule_type, duty, started_at))
+        .collect()
+}
+
+fn extract_duty(
+    rule_type: &str,
+    duty: &Value,
+    started_at: &DateTime<FixedOffset>,
+) -> Option<ExtractedDuty> {
+    let action = duty.get("action").and_then(term_value)?;
+
+    let constraints = duty.get("constraint").map(conjunctive_constraints).unwrap_or_default();
+    let callback_address = constraints
+        .iter()
+        .filter(|constraint| constraint.left_operand == "deliveryChannel")
+        .filter_map(|constraint| literal_value(&constraint.right_operand))
+        .find(|address| address.starts_with("http://") || address.starts_with("https://"));
+
+    let execution_kind = match (action.as_str(), &callback_address) {
+        ("inform" | "notify", Some(_)) => TransferDutyExecutionKind::Callback,
+        ("inform" | "notify", None) => TransferDutyExecutionKind::Notify,
+        _ => TransferDutyExecutionKind::Manual,
+    };
+
+    let due_at = constraints
+        .iter()
+        .filter_map(|constraint| due_date_from_constraint(constraint, started_at))
+        .min()
+        .or(match execution_kind {
+            TransferDutyExecutionKind::Manual => None,
+            _ => Some(*started_at),
+        });
+
+    Some(ExtractedDuty {
+        rule_type: rule_type.to_string(),
+        action,
+        execution_kind,
+        callback_address,
+        due_at,
+        duty: duty.clone(),
+    })
+}
+
+fn due_date_from_constraint(
+    constraint: &OdrlConstraintTerm,
+    started_at: &DateTime<FixedOffset>,
+) -> Option<DateTime<FixedOffset>> {
+    match (constraint.left_operand.as_str(), constraint.operator.as_str()) {
+        ("elapsedTime", "eq" | "lt" | "lteq" | "gt" | "gteq") => {
+            let duration = parse_duration(literal_value(&constraint.right_operand)?.as_str())?;
+            started_at.checked_add_signed(duration)
+        }
+        ("dateTime", "eq" | "lt" | "lteq") => {
+            parse_date_time(&constraint.right_operand).map(|date| date.fixed_offset())
+        }
+        _ => None,
+    }
+}
+
+/// ISO 8601 durations such as P30D or PT12H. Years and months count as 365 and 30 days.
+/// Amounts that don't fit a duration are rejected instead of overflowing
+pub(crate) fn parse_duration(value: &str) -> Option<Duration> {
+    let value = value.strip_prefix('P')?;
+    let (date_part, time_part) = value.split_once('T').unwrap_or((value, ""));
+    let mut total = Duration::zero();
+    let mut found = false;
+    for (part, is_time) in [(date_part, false), (time_part, true)] {
+        let mut number = String::new();
+        for c in part.chars() {
+            if c.is_ascii_digit() {
+                number.push(c);
+                continue;
+            }
+            let amount = number.parse::<i64>().ok()?;
+            number.clear();
+            let duration = match (c, is_time) {
+                ('Y', false) => amount.checked_mul(365).and_then(Duration::try_days),
+                ('M', false) => amount.checked_mul(30).and_then(Duration::try_days),
+                ('W', false) => Duration::try_weeks(amount),
+                ('D', false) => Duration::try_days(amount),
+                ('H', true) => Duration::try_hours(amount),
+                ('M', true) => Duration::try_minutes(amount),
+                ('S', true) => Duration::try_seconds(amount),
+                _ => return None,
+            }?;
+            total = total.checked_add(&duration)?;
+            found = true;
+        }
+        if !number.is_empty() {
+            return None;
+        }
+    }
+    found.then_some(total)
+}
+
+fn literal_value(value: &Value) -> Option<String> {
+    unwrap_json_ld(value).as_str().map(str::to_string)
+}
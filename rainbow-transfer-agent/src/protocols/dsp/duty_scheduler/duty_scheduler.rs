@@ -0,0 +1,292 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+use crate::entities::transfer_duties::{
+    NewTransferDutyDto, TransferAgentDutiesTrait, TransferDutyDto, TransferDutyExecutionKind,
+};
+use crate::entities::transfer_process::{TransferAgentProcessesTrait, TransferProcessDto};
+use crate::protocols::dsp::duty_scheduler::duty_extractor::extract_duties;
+use crate::protocols::dsp::duty_scheduler::DutySchedulerTrait;
+use crate::protocols::dsp::facades::agreement_facade::AgreementFacadeTrait;
+use crate::protocols::dsp::protocol_types::TransferProcessState;
+use anyhow::{anyhow, bail};
+use chrono::Utc;
+use rainbow_common::config::services::TransferConfig;
+use rainbow_common::config::types::roles::RoleConfig;
+use rainbow_common::errors::helpers::BadFormat;
+use rainbow_common::errors::{CommonErrors, ErrorLog};
+use rainbow_common::http_client::HttpClient;
+use rainbow_events::core::notification::notification_publisher::RainbowEventsPublisherTrait;
+use rainbow_events::core::notification::notification_types::{
+    RainbowEventsNotificationEvent, RainbowEventsNotificationMessageCategory,
+    RainbowEventsNotificationMessageOperation, RainbowEventsNotificationMessageTypes,
+};
+use serde::Serialize;
+use std::str::FromStr;
+use std::sync::Arc;
+use tracing::{error, info};
+use url::Url;
+use urn::Urn;
+
+/// Failed executions of an automatic duty before it is marked as FAILED
+const MAX_DUTY_ATTEMPTS: i32 = 5;
+const DUTY_NOTIFICATION_SUBCATEGORY: &str = "TransferDuty";
+
+/// Body posted to the delivery channel of a callback duty
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DutyCallbackMessage {
+    duty_id: String,
+    agreement_id: String,
+    transfer_process_id: String,
+    action: String,
+    executed_at: String,
+}
+
+pub struct DutySchedulerService {
+    duties_service: Arc<dyn TransferAgentDutiesTrait>,
+    transfer_process_service: Arc<dyn TransferAgentProcessesTrait>,
+    agreement_facade: Arc<dyn AgreementFacadeTrait>,
+    events: Arc<dyn RainbowEventsPublisherTrait>,
+    http_client: Arc<HttpClient>,
+    config: Arc<TransferConfig>,
+}
+
+impl DutySchedulerService {
+    pub fn new(
+        duties_service: Arc<dyn TransferAgentDutiesTrait>,
+        transfer_process_service: Arc<dyn TransferAgentProcessesTrait>,
+        agreement_facade: Arc<dyn AgreementFacadeTrait>,
+        events: Arc<dyn RainbowEventsPublisherTrait>,
+        http_client: Arc<HttpClient>,
+        config: Arc<TransferConfig>,
+    ) -> Self {
+        Self {
+            duties_service,
+            transfer_process_service,
+            agreement_facade,
+            events,
+            http_client,
+            config,
+        }
+    }
+
+    async fn get_duty_process(&self, duty: &TransferDutyDto) -> anyhow::Result<TransferProcessDto> {
+        let process_id = Urn::from_str(duty.inner.transfer_agent_process_id.as_str())?;
+        self.transfer_process_service.get_transfer_process_by_id(&process_id).await
+    }
+
+    fn has_ended(process: &TransferProcessDto) -> anyhow::Result<bool> {
+        let state = process.inner.state.parse::<TransferProcessState>()?;
+        Ok(matches!(
+            state,
+            TransferProcessState::Completed | TransferProcessState::Terminated
+        ))
+    }
+
+    /// Callback addresses come from the agreement, so only the configured base urls are reachable
+    fn check_callback_address(&self, duty_id: &str, callback_address: &str) -> anyhow::Result<()> {
+        let allowlist = self.config.duty_callback_allowlist();
+        if is_allowed_callback(allowlist, callback_address) {
+            return Ok(());
+        }
+        let err = CommonErrors::forbidden_new(
+            format!(
+                "Callback address {} of duty {} is not in the duty callback allowlist",
+                callback_address, duty_id
+            )
+            .as_str(),
+        );
+        error!("{}", err.log());
+        bail!(err)
+    }
+
+    fn publish_duty(&self, duty: &TransferDutyDto) {
+        let event = RainbowEventsNotificationEvent::new(
+            RainbowEventsNotificationMessageCategory::TransferProcess,
+            DUTY_NOTIFICATION_SUBCATEGORY,
+            RainbowEventsNotificationMessageTypes::RainbowEntitiesMessage,
+            RainbowEventsNotificationMessageOperation::Update,
+        )
+        .with_entity_id("dutyId", &duty.inner.id)
+        .with_entity_id("processId", &duty.inner.transfer_agent_process_id)
+        .with_entity_id("agreementId", &duty.inner.agreement_id)
+        .with_snapshot(duty);
+        self.events.publish(event);
+    }
+
+    /// Undated delete duties get a due date once their transfer has ended, which lists them
+    /// as overdue until the operator fulfills them
+    async fn date_duties_awaiting_transfer_end(&self) -> anyhow::Result<()> {
+        for duty in self.duties_service.get_duties_awaiting_transfer_end().await? {
+            let process = self.get_duty_process(&duty).await;
+            match process.and_then(|process| Self::has_ended(&process)) {
+                Ok(true) => {}
+                Ok(false) => continue,
+                Err(e) => {
+                    error!("Unable to check the transfer of duty {}: {}", duty.inner.id, e);
+                    continue;
+                }
+            }
+            let dated = match Urn::from_str(duty.inner.id.as_str()) {
+                Ok(duty_id) => {
+                    self.duties_service
+                        .set_transfer_duty_due_date(&duty_id, Utc::now().fixed_offset())
+                        .await
+                }
+                Err(e) => Err(e.into()),
+            };
+            match dated {
+                Ok(duty) => {
+                    info!("Duty {} ({}) is due", duty.inner.id, duty.inner.action);
+                    self.publish_duty(&duty);
+                }
+                Err(e) => error!("Unable to date duty {}: {}", duty.inner.id, e),
+            }
+        }
+        Ok(())
+    }
+
+    async fn execute_duty(&self, duty: &TransferDutyDto) -> anyhow::Result<()> {
+        let execution_kind = duty.inner.execution_kind.parse::<TransferDutyExecutionKind>()?;
+        match execution_kind {
+            TransferDutyExecutionKind::Callback => {
+                let callback_address = duty.inner.callback_address.clone().ok_or_else(|| {
+                    let err = CommonErrors::parse_new(
+                        format!("Callback duty {} has no delivery channel", duty.inner.id).as_str(),
+                    );
+                    error!("{}", err.log());
+                    anyhow!(err)
+                })?;
+                self.check_callback_address(&duty.inner.id, &callback_address)?;
+                let message = DutyCallbackMessage {
+                    duty_id: duty.inner.id.clone(),
+                    agreement_id: duty.inner.agreement_id.clone(),
+                    transfer_process_id: duty.inner.transfer_agent_process_id.clone(),
+                    action: duty.inner.action.clone(),
+                    executed_at: Utc::now().to_rfc3339(),
+                };
+                self.http_client
+                    .post_json_response::<_, ()>(callback_address.as_str(), &message)
+                    .await?;
+            }
+            TransferDutyExecutionKind::Notify => self.publish_duty(duty),
+            TransferDutyExecutionKind::Manual => {
+                let err = CommonErrors::format_new(
+                    BadFormat::Unknown,
+                    format!("Duty {} must be fulfilled manually", duty.inner.id).as_str(),
+                );
+                error!("{}", err.log());
+                return Err(anyhow!(err));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl DutySchedulerTrait for DutySchedulerService {
+    async fn schedule_duties(
+        &self,
+        process: &TransferProcessDto,
+    ) -> anyhow::Result<Vec<TransferDutyDto>> {
+        if process.inner.role.parse::<RoleConfig>()? != RoleConfig::Consumer {
+            return Ok(vec![]);
+        }
+
+        // a restarted transfer keeps the duties scheduled on its first start
+        let process_id = Urn::from_str(process.inner.id.as_str())?;
+        let scheduled = self.duties_service.get_duties_by_process_id(&process_id).await?;
+        if !scheduled.is_empty() {
+            return Ok(scheduled);
+        }
+
+        let agreement_id = Urn::from_str(process.inner.agreement_id.as_str())?;
+        let agreement = self.agreement_facade.get_agreement(&agreement_id).await?;
+        let started_at = Utc::now().fixed_offset();
+
+        let mut duties = vec![];
+        for extracted in extract_duties(&agreement.inner.agreement_content, &started_at) {
+            let new_duty = NewTransferDutyDto {
+                id: None,
+                transfer_agent_process_id: process_id.clone(),
+                agreement_id: agreement_id.clone(),
+                rule_type: extracted.rule_type,
+                action: extracted.action,
+                execution_kind: extracted.execution_kind,
+                callback_address: extracted.callback_address,
+                due_at: extracted.due_at,
+                duty: extracted.duty,
+            };
+            duties.push(self.duties_service.create_transfer_duty(&new_duty).await?);
+        }
+        info!(
+            "Scheduled {} duties for transfer process {}",
+            duties.len(),
+            process_id
+        );
+        Ok(duties)
+    }
+
+    async fn execute_due_duties(&self) -> anyhow::Result<()> {
+        self.date_duties_awaiting_transfer_end().await?;
+        let duties = self.duties_service.get_due_automatic_duties().await?;
+        // one broken duty must not hold back the rest of the round
+        for duty in duties {
+            let duty_id = match Urn::from_str(duty.inner.id.as_str()) {
+                Ok(duty_id) => duty_id,
+                Err(e) => {
+                    error!("Duty id {} is not a valid urn: {}", duty.inner.id, e);
+                    continue;
+                }
+            };
+            match self.execute_duty(&duty).await {
+                Ok(_) => match self.duties_service.fulfill_transfer_duty(&duty_id).await {
+                    Ok(_) => info!("Duty {} ({}) fulfilled", duty_id, duty.inner.action),
+                    Err(e) => error!("Unable to fulfill duty {}: {}", duty_id, e),
+                },
+                Err(e) => {
+                    error!("Duty {} ({}) failed: {}", duty_id, duty.inner.action, e);
+                    if let Err(e) = self
+                        .duties_service
+                        .record_transfer_duty_failure(&duty_id, &e.to_string(), MAX_DUTY_ATTEMPTS)
+                        .await
+                    {
+                        error!("Unable to record the failure of duty {}: {}", duty_id, e);
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Same scheme, host and port as an allowlisted base url, under its path
+pub(crate) fn is_allowed_callback(allowlist: &[String], callback_address: &str) -> bool {
+    let Ok(address) = Url::parse(callback_address) else {
+        return false;
+    };
+    allowlist.iter().filter_map(|allowed| Url::parse(allowed).ok()).any(|allowed| {
+        let base_path = allowed.path().trim_end_matches('/');
+        allowed.scheme() == address.scheme()
+            && allowed.host_str() == address.host_str()
+            && allowed.port_or_known_default() == address.port_or_known_default()
+            && (address.path() == base_path
+                || address.path().starts_with(format!("{}/", base_path).as_str()))
+    })
+}
@@ -0,0 +1,36 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+use crate::entities::transfer_duties::TransferDutyDto;
+use crate::entities::transfer_process::TransferProcessDto;
+
+pub(crate) mod duty_extractor;
+pub(crate) mod duty_scheduler;
+
+#[async_trait::async_trait]
+pub trait DutySchedulerTrait: Send + Sync {
+    /// Extracts the duties of the agreement behind a started transfer and schedules them.
+    /// Duties bind the assignee, so only consumer processes get them
+    async fn schedule_duties(
+        &self,
+        process: &TransferProcessDto,
+    ) -> anyhow::Result<Vec<TransferDutyDto>>;
+    /// Executes the automatic duties whose due date has been reached, and dates the delete
+    /// duties without a deadline whose transfer has ended
+    async fn execute_due_duties(&self) -> anyhow::Result<()>;
+}
@@ -60,6 +60,7 @@ impl From<CommonErrors> for TransferProcessMessageWrapper<TransferErrorDto> {
             CommonErrors::FormatError { info, .. } => info,
             CommonErrors::UnauthorizedError { info, .. } => info,
             CommonErrors::ForbiddenError { info, .. } => info,
+            CommonErrors::ConflictError { info, .. } => info,
             CommonErrors::DatabaseError { info, .. } => info,
             CommonErrors::FeatureNotImplError { info, .. } => info,
             CommonErrors::ReadError { info, .. } => info,
@@ -95,6 +96,7 @@ impl From<DspTransferError> for TransferProcessMessageWrapper<TransferErrorDto>
             CommonErrors::FormatError { info, .. } => info,
             CommonErrors::UnauthorizedError { info, .. } => info,
             CommonErrors::ForbiddenError { info, .. } => info,
+            CommonErrors::ConflictError { info, .. } => info,
             CommonErrors::DatabaseError { info, .. } => info,
             CommonErrors::FeatureNotImplError { info, .. } => info,
             CommonErrors::ReadError { info, .. } => info,
@@ -0,0 +1,96 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+use crate::protocols::dsp::facades::agreement_facade::AgreementFacadeTrait;
+use anyhow::bail;
+use rainbow_common::config::services::TransferConfig;
+use rainbow_common::errors::{CommonErrors, ErrorLog};
+use rainbow_common::http_client::HttpClient;
+use rainbow_negotiation_agent::{
+    AgreementDto, AgreementRevocationStatusDto, AgreementSignaturesVerificationDto,
+};
+use std::sync::Arc;
+use tracing::error;
+use urn::Urn;
+use ymir::config::types::HostType;
+
+pub struct AgreementFacadeServiceForDSProtocol {
+    config: Arc<TransferConfig>,
+    client: Arc<HttpClient>,
+}
+
+impl AgreementFacadeServiceForDSProtocol {
+    pub fn new(config: Arc<TransferConfig>, client: Arc<HttpClient>) -> Self {
+        Self { config, client }
+    }
+}
+
+#[async_trait::async_trait]
+impl AgreementFacadeTrait for AgreementFacadeServiceForDSProtocol {
+    async fn get_agreement(&self, agreement_id: &Urn) -> anyhow::Result<AgreementDto> {
+        let contracts_url = self.config.contracts().get_host(HostType::Http);
+        let agreement_url = format!(
+            "{}/api/v1/negotiation-agent/agreements/{}",
+            contracts_url, agreement_id
+        );
+        let agreement = self.client.get_json::<AgreementDto>(agreement_url.as_str()).await?;
+        Ok(agreement)
+    }
+
+    async fn verify_agreement_signatures(
+        &self,
+        agreement_id: &Urn,
+    ) -> anyhow::Result<AgreementSignaturesVerificationDto> {
+        let contracts_url = self.config.contracts().get_host(HostType::Http);
+        let signatures_url = format!(
+            "{}/api/v1/negotiation-agent/agreements/{}/signatures",
+            contracts_url, agreement_id
+        );
+        let verification = self
+            .client
+            .get_json::<AgreementSignaturesVerificationDto>(signatures_url.as_str())
+            .await?;
+        let policy = self.config.agreement_signatures();
+        if !verification.satisfies(&policy) {
+            let err = CommonErrors::forbidden_new(
+                format!(
+                    "Agreement {} signatures do not meet the {} signature policy",
+                    agreement_id, policy
+                )
+                .as_str(),
+            );
+            error!("{}", err.log());
+            bail!(err);
+        }
+        Ok(verification)
+    }
+
+    async fn get_agreement_revocation_status(
+        &self,
+        agreement_id: &Urn,
+    ) -> anyhow::Result<AgreementRevocationStatusDto> {
+        let contracts_url = self.config.contracts().get_host(HostType::Http);
+        let revocation_url = format!(
+            "{}/api/v1/negotiation-agent/agreements/{}/revocation",
+            contracts_url, agreement_id
+        );
+        let status =
+            self.client.get_json::<AgreementRevocationStatusDto>(revocation_url.as_str()).await?;
+        Ok(status)
+    }
+}
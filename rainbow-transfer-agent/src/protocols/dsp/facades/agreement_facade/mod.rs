@@ -0,0 +1,40 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+use rainbow_negotiation_agent::{
+    AgreementDto, AgreementRevocationStatusDto, AgreementSignaturesVerificationDto,
+};
+use urn::Urn;
+
+pub mod agreement_facade;
+
+#[mockall::automock]
+#[async_trait::async_trait]
+pub trait AgreementFacadeTrait: Send + Sync {
+    async fn get_agreement(&self, agreement_id: &Urn) -> anyhow::Result<AgreementDto>;
+    /// Fails unless the parties' signatures over the agreement meet the configured policy
+    async fn verify_agreement_signatures(
+        &self,
+        agreement_id: &Urn,
+    ) -> anyhow::Result<AgreementSignaturesVerificationDto>;
+    /// Revocation and expiry of the agreement as reported by the negotiation agent
+    async fn get_agreement_revocation_status(
+        &self,
+        agreement_id: &Urn,
+    ) -> anyhow::Result<AgreementRevocationStatusDto>;
+}
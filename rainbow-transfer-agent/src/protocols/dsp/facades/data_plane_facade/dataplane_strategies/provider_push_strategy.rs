@@ -1,6 +1,7 @@
 use crate::protocols::dsp::facades::data_plane_facade::dataplane_strategies::DataPlaneStrategyTrait;
 use crate::protocols::dsp::facades::data_plane_facade::DataPlaneFacadeTrait;
 use crate::protocols::dsp::protocol_types::DataAddressDto;
+use anyhow::bail;
 use rainbow_catalog_agent::DataServiceDto;
 use rainbow_common::adv_protocol::interplane::data_plane_provision::DataPlaneProvisionRequest;
 use rainbow_common::adv_protocol::interplane::data_plane_start::DataPlaneStart;
@@ -10,8 +11,11 @@ use rainbow_common::adv_protocol::interplane::{
     DataPlaneSDPConfigTypes, DataPlaneSDPFieldTypes, DataPlaneSDPRequestField,
 };
 use rainbow_common::dcat_formats::{DctFormats, FormatAction};
+use rainbow_common::errors::helpers::BadFormat;
+use rainbow_common::errors::{CommonErrors, ErrorLog};
 use rainbow_dataplane::coordinator::dataplane_access_controller::DataPlaneAccessControllerTrait;
 use std::sync::Arc;
+use tracing::error;
 use url::Url;
 use urn::Urn;
 
@@ -41,6 +45,8 @@ impl DataPlaneFacadeTrait for ProviderPushDataplaneStrategy {
         data_service: &Option<DataServiceDto>,
         data_address: &Option<DataAddressDto>,
     ) -> anyhow::Result<()> {
+        // refuse the request before anything is provisioned
+        consumer_sink_url(data_address)?;
         Ok(())
     }
 
@@ -56,6 +62,19 @@ impl DataPlaneFacadeTrait for ProviderPushDataplaneStrategy {
         let endpoint_scheme = endpoint_url.scheme().to_string();
         let endpoint_address = endpoint_url.to_string();
 
+        // on provider push the consumer DataAddress is the sink the dataplane delivers to
+        let sink_url = consumer_sink_url(data_address)?;
+        let endpoint_properties =
+            data_address.as_ref().and_then(|a| a.endpoint_properties.as_ref());
+        let endpoint_property = |name: &str| {
+            endpoint_properties
+                .into_iter()
+                .flatten()
+                .find(|p| p.name == name)
+                .map(|p| p.value.to_string())
+                .unwrap_or_default()
+        };
+
         let provision_request = self
             .dataplane_controller_access
             .data_plane_provision_request(&DataPlaneProvisionRequest {
@@ -96,6 +115,36 @@ impl DataPlaneFacadeTrait for ProviderPushDataplaneStrategy {
                         format: Some("dcterms:transferDirection".to_string()),
                         content: FormatAction::Push.to_string(),
                     },
+                    DataPlaneSDPConfigField {
+                        _type: DataPlaneSDPConfigTypes::UpstreamHopAddressScheme,
+                        format: Some("https://www.iana.org/assignments/uri-schemes/uri-schemes.xhtml".to_string()),
+                        content: sink_url.scheme().to_string(),
+                    },
+                    DataPlaneSDPConfigField {
+                        _type: DataPlaneSDPConfigTypes::UpstreamHopAddress,
+                        format: Some("uri".to_string()),
+                        content: sink_url.to_string(),
+                    },
+                    DataPlaneSDPConfigField {
+                        _type: DataPlaneSDPConfigTypes::UpstreamHopAddressAuthType,
+                        format: Some("https://www.iana.org/assignments/http-authschemes/http-authschemes.xhtml".to_string()),
+                        content: endpoint_property("authType"),
+                    },
+                    DataPlaneSDPConfigField {
+                        _type: DataPlaneSDPConfigTypes::UpstreamHopAddressAuth,
+                        format: Some("jwt".to_string()),
+                        content: endpoint_property("authorization"),
+                    },
+                    DataPlaneSDPConfigField {
+                        _type: DataPlaneSDPConfigTypes::DeliveryInterval,
+                        format: Some("seconds".to_string()),
+                        content: endpoint_property("deliveryInterval"),
+                    },
+                    DataPlaneSDPConfigField {
+                        _type: DataPlaneSDPConfigTypes::DeliveryMaxRetries,
+                        format: Some("integer".to_string()),
+                        content: endpoint_property("deliveryMaxRetries"),
+                    },
                 ]),
             })
             .await?;
@@ -162,3 +211,39 @@ impl DataPlaneFacadeTrait for ProviderPushDataplaneStrategy {
         Ok(())
     }
 }
+
+/// The sink of a provider push is chosen by the consumer, so it must be a remote
+/// http(s) endpoint. Any other scheme would let a peer point the dataplane at local resources.
+pub(crate) fn consumer_sink_url(data_address: &Option<DataAddressDto>) -> anyhow::Result<Url> {
+    let endpoint = data_address.as_ref().and_then(|a| a.endpoint.as_deref()).ok_or_else(|| {
+        let err = CommonErrors::format_new(
+            BadFormat::Received,
+            "Push transfer requests must include the consumer dataAddress",
+        );
+        error!("{}", err.log());
+        err
+    })?;
+    let sink_url = Url::parse(endpoint).map_err(|e| {
+        let err = CommonErrors::format_new(
+            BadFormat::Received,
+            format!("Consumer dataAddress endpoint {} is not a valid url: {}", endpoint, e)
+                .as_str(),
+        );
+        error!("{}", err.log());
+        err
+    })?;
+    match sink_url.scheme() {
+        "http" | "https" => Ok(sink_url),
+        scheme => {
+            let err = CommonErrors::forbidden_new(
+                format!(
+                    "Consumer dataAddress scheme {} is not allowed for push transfers",
+                    scheme
+                )
+                .as_str(),
+            );
+            error!("{}", err.log());
+            bail!(err)
+        }
+    }
+}
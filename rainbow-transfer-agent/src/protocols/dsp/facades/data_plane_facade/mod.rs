@@ -23,7 +23,7 @@ use rainbow_common::dcat_formats::DctFormats;
 use urn::Urn;
 
 pub mod data_plane_facade;
-mod dataplane_strategies;
+pub(crate) mod dataplane_strategies;
 pub(crate) mod dataplane_strategy_factory;
 
 #[mockall::automock]
@@ -0,0 +1,117 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use rainbow_catalog_agent::{DataServiceDto, DistributionDto};
+use rainbow_common::config::services::TransferConfig;
+use rainbow_common::http_client::HttpClient;
+use rainbow_negotiation_agent::AgreementDto;
+use serde_json::Value;
+use std::sync::Arc;
+use urn::Urn;
+use ymir::config::types::HostType;
+
+/// Lookups the data service resolver needs from the catalog and contracts agents
+#[mockall::automock]
+#[async_trait::async_trait]
+pub trait CatalogResolverClientTrait: Send + Sync {
+    async fn get_agreement(&self, agreement_id: &Urn) -> anyhow::Result<AgreementDto>;
+    async fn get_distributions_by_dataset_id(
+        &self,
+        dataset_id: &Urn,
+    ) -> anyhow::Result<Vec<DistributionDto>>;
+    async fn get_data_service_by_id(&self, data_service_id: &Urn)
+        -> anyhow::Result<DataServiceDto>;
+}
+
+pub struct CatalogResolverHttpClient {
+    config: Arc<TransferConfig>,
+    client: Arc<HttpClient>,
+}
+
+impl CatalogResolverHttpClient {
+    pub fn new(config: Arc<TransferConfig>, client: Arc<HttpClient>) -> Self {
+        Self { config, client }
+    }
+}
+
+#[async_trait::async_trait]
+impl CatalogResolverClientTrait for CatalogResolverHttpClient {
+    async fn get_agreement(&self, agreement_id: &Urn) -> anyhow::Result<AgreementDto> {
+        let contracts_url = self.config.contracts().get_host(HostType::Http);
+        let agreement_url = format!(
+            "{}/api/v1/negotiation-agent/agreements/{}",
+            contracts_url, agreement_id
+        );
+        let agreement = self.client.get_json::<AgreementDto>(agreement_url.as_str()).await?;
+        Ok(agreement)
+    }
+
+    async fn get_distributions_by_dataset_id(
+        &self,
+        dataset_id: &Urn,
+    ) -> anyhow::Result<Vec<DistributionDto>> {
+        let catalog_url = self.config.catalog().get_host(HostType::Http);
+        let distributions_url = format!(
+            "{}/api/v1/catalog-agent/distributions/dataset/{}",
+            catalog_url, dataset_id
+        );
+        let distributions = self.client.get_json::<Vec<Value>>(distributions_url.as_str()).await?;
+        distributions.into_iter().map(distribution_from_catalog).collect()
+    }
+
+    async fn get_data_service_by_id(
+        &self,
+        data_service_id: &Urn,
+    ) -> anyhow::Result<DataServiceDto> {
+        let catalog_url = self.config.catalog().get_host(HostType::Http);
+        let data_service_url = format!(
+            "{}/api/v1/catalog-agent/data-services/{}",
+            catalog_url, data_service_id
+        );
+        let data_service =
+            self.client.get_json::<DataServiceDto>(data_service_url.as_str()).await?;
+        Ok(data_service)
+    }
+}
+
+/// The catalog agent answers with camelCase keys while the distribution model
+/// deserializes from its snake_case column names
+pub(crate) fn distribution_from_catalog(distribution: Value) -> anyhow::Result<DistributionDto> {
+    let distribution = match distribution {
+        Value::Object(fields) => Value::Object(
+            fields.into_iter().map(|(key, value)| (camel_to_snake_case(&key), value)).collect(),
+        ),
+        other => other,
+    };
+    let distribution = serde_json::from_value::<DistributionDto>(distribution)?;
+    Ok(distribution)
+}
+
+fn camel_to_snake_case(key: &str) -> String {
+    let mut snake_case = String::with_capacity(key.len() + 4);
+    for c in key.chars() {
+        if c.is_ascii_uppercase() {
+            snake_case.push('_');
+            snake_case.push(c.to_ascii_lowercase());
+        } else {
+            snake_case.push(c);
+        }
+    }
+    snake_case
+}
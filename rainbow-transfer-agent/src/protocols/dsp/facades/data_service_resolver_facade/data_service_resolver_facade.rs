@@ -17,77 +17,136 @@
  *
  */
 
+use crate::protocols::dsp::facades::data_service_resolver_facade::catalog_client::CatalogResolverClientTrait;
 use crate::protocols::dsp::facades::data_service_resolver_facade::DataServiceFacadeTrait;
 use anyhow::bail;
-use rainbow_catalog_agent::{DataServiceDto, DatasetDto, DistributionDto};
-use rainbow_common::config::services::TransferConfig;
+use rainbow_catalog_agent::{DataServiceDto, DistributionDto};
 use rainbow_common::dcat_formats::DctFormats;
 use rainbow_common::errors::helpers::BadFormat;
 use rainbow_common::errors::{CommonErrors, ErrorLog};
-use rainbow_common::http_client::HttpClient;
 use rainbow_common::utils::get_urn_from_string;
-use rainbow_negotiation_agent::AgreementDto;
-use serde::{Deserialize, Serialize};
-use std::str::FromStr;
 use std::sync::Arc;
-use tracing::error;
+use tracing::{error, warn};
 use urn::Urn;
-use ymir::config::types::HostType;
 
 pub struct DataServiceFacadeServiceForDSProtocol {
-    config: Arc<TransferConfig>,
-    client: Arc<HttpClient>,
+    catalog_client: Arc<dyn CatalogResolverClientTrait>,
 }
 
 impl DataServiceFacadeServiceForDSProtocol {
-    pub fn new(config: Arc<TransferConfig>, client: Arc<HttpClient>) -> Self {
-        Self { config, client }
+    pub fn new(catalog_client: Arc<dyn CatalogResolverClientTrait>) -> Self {
+        Self { catalog_client }
     }
-}
 
-#[async_trait::async_trait]
-impl DataServiceFacadeTrait for DataServiceFacadeServiceForDSProtocol {
-    async fn resolve_data_service_by_agreement_id(
+    /// Resolves the access service of the dataset distribution offering `formats`.
+    /// Candidates are tried oldest first, so the same distribution wins across requests.
+    pub(crate) async fn resolve_data_service_by_dataset_id(
         &self,
-        agreement_id: &Urn,
+        dataset_id: &Urn,
         formats: Option<&DctFormats>,
     ) -> anyhow::Result<DataServiceDto> {
-        let contracts_url = self.config.contracts().get_host(HostType::Http);
-        let catalog_url = self.config.catalog().get_host(HostType::Http);
-        let agreement_url = format!(
-            "{}/api/v1/negotiation-agent/agreements/{}",
-            contracts_url, agreement_id
-        );
-        let data_service_url = format!("{}/api/v1/catalog-agent/data-services", catalog_url);
+        let requested_format = formats.map(|f| f.to_string()).unwrap_or_else(|| "any".to_string());
+        let distributions = self.catalog_client.get_distributions_by_dataset_id(dataset_id).await?;
+        if distributions.is_empty() {
+            let err = CommonErrors::missing_resource_new(
+                dataset_id.to_string().as_str(),
+                format!("Dataset {} has no distributions", dataset_id).as_str(),
+            );
+            error!("{}", err.log());
+            bail!(err);
+        }
 
-        // resolve agreement
-        let agreement = self.client.get_json::<AgreementDto>(agreement_url.as_str()).await?;
-        let agreement_target = get_urn_from_string(&agreement.inner.target)?;
+        let offered_formats = distributions
+            .iter()
+            .filter_map(|distribution| distribution.inner.dct_format.clone())
+            .collect::<Vec<_>>();
+        let candidates = Self::select_distributions(distributions, formats);
+        if candidates.is_empty() {
+            let err = CommonErrors::format_new(
+                BadFormat::Received,
+                format!(
+                    "No distribution of dataset {} is offered in format {}, available formats: [{}]",
+                    dataset_id,
+                    requested_format,
+                    offered_formats.join(", ")
+                )
+                .as_str(),
+            );
+            error!("{}", err.log());
+            bail!(err);
+        }
 
-        // resolve dataset entity
-        let datasets_url = format!(
-            "{}/api/v1/catalog-agent/datasets/{}",
-            catalog_url,
-            agreement_target.clone()
-        );
-        let dataset = self.client.get_json::<DatasetDto>(datasets_url.as_str()).await?;
-        let dataset_id = get_urn_from_string(&dataset.inner.id)?;
+        let mut failures = Vec::new();
+        for distribution in candidates {
+            let access_service_id =
+                match get_urn_from_string(&distribution.inner.dcat_access_service) {
+                    Ok(access_service_id) => access_service_id,
+                    Err(e) => {
+                        failures.push(format!("{}: {}", distribution.inner.id, e));
+                        continue;
+                    }
+                };
+            match self.catalog_client.get_data_service_by_id(&access_service_id).await {
+                Ok(data_service) => return Ok(data_service),
+                Err(e) => {
+                    warn!(
+                        "Access service {} of distribution {} could not be resolved: {}",
+                        access_service_id, distribution.inner.id, e
+                    );
+                    failures.push(format!(
+                        "{} (access service {}): {}",
+                        distribution.inner.id, access_service_id, e
+                    ));
+                }
+            }
+        }
 
-        // resolve distribution entity
-        let distribution_url = format!(
-            "{}/api/v1/catalog-agent/distributions/dataset/{}/format/{}",
-            catalog_url,
-            dataset_id.clone(),
-            formats.unwrap().to_string()
+        let err = CommonErrors::missing_resource_new(
+            dataset_id.to_string().as_str(),
+            format!(
+                "No access service could be resolved for dataset {} in format {}: [{}]",
+                dataset_id,
+                requested_format,
+                failures.join("; ")
+            )
+            .as_str(),
         );
-        let distribution =
-            self.client.get_json::<DistributionDto>(distribution_url.as_str()).await?;
-        let access_service_id = Urn::from_str(distribution.inner.id.as_str())?;
+        error!("{}", err.log());
+        bail!(err);
+    }
 
-        // resolve Data service entity
-        let data_service =
-            self.client.get_json::<DataServiceDto>(data_service_url.as_str()).await?;
+    /// Distributions offering `formats` (all of them when no format is given),
+    /// ordered by issue date and then by id
+    fn select_distributions(
+        distributions: Vec<DistributionDto>,
+        formats: Option<&DctFormats>,
+    ) -> Vec<DistributionDto> {
+        let requested_format = formats.map(|f| f.to_string());
+        let mut candidates = distributions
+            .into_iter()
+            .filter(|distribution| match &requested_format {
+                Some(requested_format) => {
+                    distribution.inner.dct_format.as_ref() == Some(requested_format)
+                }
+                None => true,
+            })
+            .collect::<Vec<_>>();
+        candidates.sort_by(|a, b| {
+            a.inner.dct_issued.cmp(&b.inner.dct_issued).then_with(|| a.inner.id.cmp(&b.inner.id))
+        });
+        candidates
+    }
+}
 
-        Ok(data_service)
+#[async_trait::async_trait]
+impl DataServiceFacadeTrait for DataServiceFacadeServiceForDSProtocol {
+    async fn resolve_data_service_by_agreement_id(
+        &self,
+        agreement_id: &Urn,
+        formats: Option<&DctFormats>,
+    ) -> anyhow::Result<DataServiceDto> {
+        let agreement = self.catalog_client.get_agreement(agreement_id).await?;
+        let dataset_id = get_urn_from_string(&agreement.inner.target)?;
+        self.resolve_data_service_by_dataset_id(&dataset_id, formats).await
     }
 }
@@ -21,6 +21,7 @@ use rainbow_catalog_agent::DataServiceDto;
 use rainbow_common::dcat_formats::DctFormats;
 use urn::Urn;
 
+pub mod catalog_client;
 pub mod data_service_resolver_facade;
 
 #[async_trait::async_trait]
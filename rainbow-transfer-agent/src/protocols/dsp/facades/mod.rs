@@ -18,30 +18,36 @@
  *
  */
 
+use crate::protocols::dsp::facades::agreement_facade::AgreementFacadeTrait;
 use crate::protocols::dsp::facades::data_plane_facade::DataPlaneFacadeTrait;
 use crate::protocols::dsp::facades::data_service_resolver_facade::DataServiceFacadeTrait;
 use std::sync::Arc;
 
+pub mod agreement_facade;
 pub mod data_plane_facade;
 pub mod data_service_resolver_facade;
+pub mod termination_facade;
 
 #[async_trait::async_trait]
 pub trait FacadeTrait: Send + Sync {
     async fn get_data_service_facade(&self) -> Arc<dyn DataServiceFacadeTrait>;
     async fn get_data_plane_facade(&self) -> Arc<dyn DataPlaneFacadeTrait>;
+    async fn get_agreement_facade(&self) -> Arc<dyn AgreementFacadeTrait>;
 }
 
 pub struct FacadeService {
     data_service_resolver_facade: Arc<dyn DataServiceFacadeTrait>,
     data_plane_facade: Arc<dyn DataPlaneFacadeTrait>,
+    agreement_facade: Arc<dyn AgreementFacadeTrait>,
 }
 
 impl FacadeService {
     pub fn new(
         data_service_resolver_facade: Arc<dyn DataServiceFacadeTrait>,
         data_plane_facade: Arc<dyn DataPlaneFacadeTrait>,
+        agreement_facade: Arc<dyn AgreementFacadeTrait>,
     ) -> FacadeService {
-        Self { data_service_resolver_facade, data_plane_facade }
+        Self { data_service_resolver_facade, data_plane_facade, agreement_facade }
     }
 }
 
@@ -54,4 +60,8 @@ impl FacadeTrait for FacadeService {
     async fn get_data_plane_facade(&self) -> Arc<dyn DataPlaneFacadeTrait> {
         self.data_plane_facade.clone()
     }
+
+    async fn get_agreement_facade(&self) -> Arc<dyn AgreementFacadeTrait> {
+        self.agreement_facade.clone()
+    }
 }
@@ -0,0 +1,35 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use crate::entities::transfer_process::TransferProcessDto;
+
+pub mod termination_facade;
+
+#[mockall::automock]
+#[async_trait::async_trait]
+pub trait TransferTerminationFacadeTrait: Send + Sync {
+    /// Terminates an ongoing transfer through the rpc termination, so the peer is notified
+    /// and the dataplane hooks run as if the termination had been requested by hand
+    async fn terminate_transfer(
+        &self,
+        process: &TransferProcessDto,
+        code: &str,
+        reason: String,
+    ) -> anyhow::Result<()>;
+}
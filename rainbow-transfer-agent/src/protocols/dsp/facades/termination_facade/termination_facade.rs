@@ -0,0 +1,77 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use crate::entities::transfer_process::TransferProcessDto;
+use crate::protocols::dsp::facades::termination_facade::TransferTerminationFacadeTrait;
+use crate::protocols::dsp::orchestrator::rpc::types::RpcTransferTerminationMessageDto;
+use anyhow::anyhow;
+use rainbow_common::config::services::TransferConfig;
+use rainbow_common::config::traits::CommonConfigTrait;
+use rainbow_common::errors::{CommonErrors, ErrorLog};
+use rainbow_common::http_client::HttpClient;
+use std::str::FromStr;
+use std::sync::Arc;
+use tracing::error;
+use urn::Urn;
+use ymir::config::types::HostType;
+
+pub struct TransferTerminationFacadeService {
+    config: Arc<TransferConfig>,
+    client: Arc<HttpClient>,
+}
+
+impl TransferTerminationFacadeService {
+    pub fn new(config: Arc<TransferConfig>, client: Arc<HttpClient>) -> Self {
+        Self { config, client }
+    }
+
+    fn get_identifier(process: &TransferProcessDto, key: &str) -> anyhow::Result<Urn> {
+        let identifier = process.identifiers.get(key).ok_or_else(|| {
+            let err = CommonErrors::parse_new(
+                format!("Transfer process {} has no {}", process.inner.id, key).as_str(),
+            );
+            error!("{}", err.log());
+            anyhow!(err)
+        })?;
+        Ok(Urn::from_str(identifier.as_str())?)
+    }
+}
+
+#[async_trait::async_trait]
+impl TransferTerminationFacadeTrait for TransferTerminationFacadeService {
+    async fn terminate_transfer(
+        &self,
+        process: &TransferProcessDto,
+        code: &str,
+        reason: String,
+    ) -> anyhow::Result<()> {
+        let termination = RpcTransferTerminationMessageDto {
+            consumer_pid: Self::get_identifier(process, "consumerPid")?,
+            provider_pid: Self::get_identifier(process, "providerPid")?,
+            code: Some(code.to_string()),
+            reason: Some(vec![reason]),
+        };
+        let url = format!(
+            "{}/dsp/current/transfers/rpc/setup-termination",
+            self.config.common().get_host(HostType::Http)
+        );
+        self.client.post_json::<_, serde_json::Value>(url.as_str(), &termination).await?;
+        Ok(())
+    }
+}
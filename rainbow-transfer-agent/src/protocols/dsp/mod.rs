@@ -17,6 +17,8 @@
  *
  */
 
+pub(crate) mod agreement_watcher;
+pub(crate) mod duty_scheduler;
 mod errors;
 pub(crate) mod facades;
 pub(crate) mod http;
@@ -26,10 +28,14 @@ pub(crate) mod protocol_types;
 pub(crate) mod transfer_types;
 pub(crate) mod validator;
 
+use crate::entities::transfer_duties::TransferAgentDutiesTrait;
 use crate::entities::transfer_messages::TransferAgentMessagesTrait;
 use crate::entities::transfer_process::TransferAgentProcessesTrait;
+use crate::protocols::dsp::duty_scheduler::duty_scheduler::DutySchedulerService;
+use crate::protocols::dsp::facades::agreement_facade::agreement_facade::AgreementFacadeServiceForDSProtocol;
 use crate::protocols::dsp::facades::data_plane_facade::data_plane_facade::DataPlaneProviderFacadeForDSProtocol;
 use crate::protocols::dsp::facades::data_plane_facade::dataplane_strategy_factory::DataPlaneStrategyFactory;
+use crate::protocols::dsp::facades::data_service_resolver_facade::catalog_client::CatalogResolverHttpClient;
 use crate::protocols::dsp::facades::data_service_resolver_facade::data_service_resolver_facade::DataServiceFacadeServiceForDSProtocol;
 use crate::protocols::dsp::facades::FacadeService;
 use crate::protocols::dsp::http::protocol::DspRouter;
@@ -45,9 +51,15 @@ use crate::protocols::dsp::validator::validators::validate_payload::ValidatePayl
 use crate::protocols::dsp::validator::validators::validation_helpers::ValidationHelperService;
 use crate::protocols::protocol::ProtocolPluginTrait;
 use axum::Router;
+use rainbow_common::auth::ssi::{with_ssi_auth, SsiAuthLayer, DEFAULT_PEER_CACHE_TTL};
 use rainbow_common::config::services::TransferConfig;
+use rainbow_common::config::traits::CommonConfigTrait;
+use rainbow_common::facades::ssi_auth_facade::mates_facade::MatesFacadeService;
+use rainbow_common::facades::ssi_auth_facade::peer_token_facade::PeerTokenFacadeService;
+use rainbow_common::facades::ssi_auth_facade::ssi_auth_facade::SSIAuthFacadeService;
 use rainbow_common::http_client::HttpClient;
 use rainbow_dataplane::setup::DataplaneSetup;
+use rainbow_events::core::notification::notification_publisher::RainbowEventsPublisherTrait;
 use std::sync::Arc;
 use validator::validators::protocol::validate_state_transition::ValidatedStateTransitionServiceForDsp;
 use validator::validators::rpc::validation_rpc_steps::ValidationRpcStepsService;
@@ -56,6 +68,8 @@ use ymir::services::vault::vault_rs::VaultService;
 pub struct TransferDSP {
     transfer_agent_process_entities: Arc<dyn TransferAgentProcessesTrait>,
     transfer_agent_message_service: Arc<dyn TransferAgentMessagesTrait>,
+    transfer_agent_duties_service: Arc<dyn TransferAgentDutiesTrait>,
+    events_publisher: Arc<dyn RainbowEventsPublisherTrait>,
     config: Arc<TransferConfig>,
     vault: Arc<VaultService>,
 }
@@ -64,10 +78,19 @@ impl TransferDSP {
     pub fn new(
         transfer_agent_message_service: Arc<dyn TransferAgentMessagesTrait>,
         transfer_agent_process_entities: Arc<dyn TransferAgentProcessesTrait>,
+        transfer_agent_duties_service: Arc<dyn TransferAgentDutiesTrait>,
+        events_publisher: Arc<dyn RainbowEventsPublisherTrait>,
         config: Arc<TransferConfig>,
         vault: Arc<VaultService>,
     ) -> Self {
-        Self { transfer_agent_message_service, transfer_agent_process_entities, config, vault }
+        Self {
+            transfer_agent_message_service,
+            transfer_agent_process_entities,
+            transfer_agent_duties_service,
+            events_publisher,
+            config,
+            vault,
+        }
     }
 }
 
@@ -120,8 +143,13 @@ impl ProtocolPluginTrait for TransferDSP {
 
         // dataplane
         let dataplane = DataplaneSetup::new();
-        let dataplane_controller =
-            dataplane.get_data_plane_controller(self.config.clone(), self.vault.clone()).await;
+        let dataplane_controller = dataplane
+            .get_data_plane_controller(
+                self.config.clone(),
+                self.vault.clone(),
+                self.events_publisher.clone(),
+            )
+            .await;
         let dataplane_strategy_factory =
             Arc::new(DataPlaneStrategyFactory::new(dataplane_controller.clone()));
         let dataplane_facade = Arc::new(DataPlaneProviderFacadeForDSProtocol::new(
@@ -130,7 +158,15 @@ impl ProtocolPluginTrait for TransferDSP {
         ));
 
         // data service resolver
-        let data_service_resolver = Arc::new(DataServiceFacadeServiceForDSProtocol::new(
+        let catalog_resolver_client = Arc::new(CatalogResolverHttpClient::new(
+            self.config.clone(),
+            http_client.clone(),
+        ));
+        let data_service_resolver =
+            Arc::new(DataServiceFacadeServiceForDSProtocol::new(catalog_resolver_client));
+
+        // agreement signatures
+        let agreement_facade = Arc::new(AgreementFacadeServiceForDSProtocol::new(
             self.config.clone(),
             http_client.clone(),
         ));
@@ -139,6 +175,27 @@ impl ProtocolPluginTrait for TransferDSP {
         let facades = Arc::new(FacadeService::new(
             data_service_resolver.clone(),
             dataplane_facade.clone(),
+            agreement_facade.clone(),
+        ));
+
+        // duties
+        let duty_scheduler = Arc::new(DutySchedulerService::new(
+            self.transfer_agent_duties_service.clone(),
+            self.transfer_agent_process_entities.clone(),
+            agreement_facade.clone(),
+            self.events_publisher.clone(),
+            http_client.clone(),
+            self.config.clone(),
+        ));
+
+        // outbound tokens per peer, straight from the SSI auth service
+        let ssi_auth_config = Arc::new(self.config.ssi_auth().clone());
+        let mates_facade =
+            Arc::new(MatesFacadeService::new(ssi_auth_config.clone(), http_client.clone()));
+        let peer_token_facade = Arc::new(PeerTokenFacadeService::new(
+            ssi_auth_config.clone(),
+            http_client.clone(),
+            mates_facade,
         ));
 
         // orchestrators
@@ -146,23 +203,40 @@ impl ProtocolPluginTrait for TransferDSP {
             dsp_validator.clone(),
             persistence_protocol_service.clone(),
             facades.clone(),
+            self.events_publisher.clone(),
+            duty_scheduler.clone(),
+            self.config.clone(),
         ));
         let rpc_orchestator = Arc::new(RPCOrchestratorService::new(
             rcp_validator.clone(),
             persistence_rpc_service,
             http_client.clone(),
             facades.clone(),
+            self.events_publisher.clone(),
+            peer_token_facade,
         ));
         let orchestrator_service = Arc::new(OrchestratorService::new(
             http_orchestator.clone(),
             rpc_orchestator.clone(),
         ));
 
+        // every DSP message must come from a peer known to the SSI service
+        let ssi_auth_facade =
+            Arc::new(SSIAuthFacadeService::new(ssi_auth_config, http_client.clone()));
+        let ssi_auth_layer =
+            SsiAuthLayer::new(ssi_auth_facade, DEFAULT_PEER_CACHE_TTL, "TransferError");
+
         // router
         let dsp_router = DspRouter::new(orchestrator_service.clone());
         let rcp_router = RpcRouter::new(orchestrator_service.clone());
 
-        Ok(Router::new().merge(dsp_router.router()).merge(rcp_router.router()))
+        let dsp_router = with_ssi_auth(
+            dsp_router.router(),
+            ssi_auth_layer,
+            self.config.common().verify_ssi_tokens(),
+        );
+
+        Ok(Router::new().merge(dsp_router).merge(rcp_router.router()))
     }
 
     fn build_grpc_router(&self) -> anyhow::Result<Option<Router>> {
@@ -26,17 +26,36 @@ use crate::protocols::dsp::protocol_types::{
 };
 use std::str::FromStr;
 
+use crate::protocols::dsp::duty_scheduler::DutySchedulerTrait;
 use crate::protocols::dsp::facades::FacadeTrait;
+use crate::protocols::dsp::orchestrator::traits::orchestration_notifications::OrchestrationNotifications;
 use crate::protocols::dsp::validator::traits::validation_dsp_steps::ValidationDspSteps;
-use anyhow::anyhow;
+use anyhow::{anyhow, bail};
+use rainbow_common::auth::ssi::current_participant_id;
+use rainbow_common::config::services::TransferConfig;
+use rainbow_common::config::traits::CommonConfigTrait;
 use rainbow_common::dcat_formats::DctFormats;
+use rainbow_common::errors::helpers::BadFormat;
+use rainbow_common::errors::{CommonErrors, ErrorLog};
+use rainbow_events::core::notification::notification_publisher::RainbowEventsPublisherTrait;
+use rainbow_events::core::notification::notification_types::{
+    RainbowEventsNotificationMessageOperation, RainbowEventsNotificationMessageTypes,
+};
+use rainbow_negotiation_agent::{AgreementDto, AgreementRevocationStatusDto};
 use std::sync::Arc;
+use tracing::error;
 use urn::Urn;
 
+/// Negotiation agent state of a finalized agreement
+const AGREEMENT_FINAL_STATE: &str = "ACTIVE";
+
 pub struct ProtocolOrchestratorService {
     facades: Arc<dyn FacadeTrait>,
     validator: Arc<dyn ValidationDspSteps>,
     pub persistence_service: Arc<dyn TransferPersistenceTrait>,
+    events: Arc<dyn RainbowEventsPublisherTrait>,
+    duty_scheduler: Arc<dyn DutySchedulerTrait>,
+    config: Arc<TransferConfig>,
 }
 
 impl ProtocolOrchestratorService {
@@ -44,8 +63,95 @@ impl ProtocolOrchestratorService {
         validator: Arc<dyn ValidationDspSteps>,
         persistence_service: Arc<dyn TransferPersistenceTrait>,
         facades: Arc<dyn FacadeTrait>,
+        events: Arc<dyn RainbowEventsPublisherTrait>,
+        duty_scheduler: Arc<dyn DutySchedulerTrait>,
+        config: Arc<TransferConfig>,
     ) -> ProtocolOrchestratorService {
-        ProtocolOrchestratorService { validator, persistence_service, facades }
+        ProtocolOrchestratorService {
+            validator,
+            persistence_service,
+            facades,
+            events,
+            duty_scheduler,
+            config,
+        }
+    }
+
+    /// Only the consumer the agreement was concluded with can transfer under it,
+    /// and only while the agreement is finalized and in force
+    async fn verify_agreement_for_transfer(&self, agreement_id: &Urn) -> anyhow::Result<()> {
+        let agreement_facade = self.facades.get_agreement_facade().await;
+        let agreement = agreement_facade.get_agreement(agreement_id).await.map_err(|e| {
+            let err = CommonErrors::missing_resource_new(
+                agreement_id.to_string().as_str(),
+                format!("Agreement could not be resolved: {}", e).as_str(),
+            );
+            error!("{}", err.log());
+            err
+        })?;
+
+        // with SSI verification turned off there is no identity to bind the agreement to
+        let participant_id = match current_participant_id() {
+            None if !self.config.common().verify_ssi_tokens() => {
+                Some(agreement.inner.consumer_participant_id.clone())
+            }
+            participant_id => participant_id,
+        };
+        let status = agreement_facade.get_agreement_revocation_status(agreement_id).await?;
+        if let Some(err) = refuse_transfer_under_agreement(
+            agreement_id,
+            &agreement,
+            participant_id.as_deref(),
+            &status,
+        ) {
+            error!("{}", err.log());
+            bail!(err);
+        }
+        Ok(())
+    }
+}
+
+/// Reason to refuse a transfer requested by `participant_id` under the agreement, if any
+pub(crate) fn refuse_transfer_under_agreement(
+    agreement_id: &Urn,
+    agreement: &AgreementDto,
+    participant_id: Option<&str>,
+    status: &AgreementRevocationStatusDto,
+) -> Option<CommonErrors> {
+    let participant_id = match participant_id {
+        Some(participant_id) => participant_id,
+        None => {
+            return Some(CommonErrors::unauthorized_new(
+                "Transfer requests must come from an authenticated participant",
+            ))
+        }
+    };
+    let refusal = if agreement.inner.consumer_participant_id != participant_id {
+        format!(
+            "Agreement {} was not concluded with participant {}",
+            agreement_id, participant_id
+        )
+    } else if status.revoked {
+        format!(
+            "Agreement {} was revoked: {}",
+            agreement_id,
+            status.revocation_reason.as_deref().unwrap_or("no reason given")
+        )
+    } else if status.expired {
+        format!("Agreement {} has expired", agreement_id)
+    } else if status.state != AGREEMENT_FINAL_STATE {
+        format!("Agreement {} is not finalized, it is {}", agreement_id, status.state)
+    } else if !status.valid {
+        format!("Agreement {} is not in force yet", agreement_id)
+    } else {
+        return None;
+    };
+    Some(CommonErrors::forbidden_new(refusal.as_str()))
+}
+
+impl OrchestrationNotifications for ProtocolOrchestratorService {
+    fn get_events_publisher(&self) -> Arc<dyn RainbowEventsPublisherTrait> {
+        self.events.clone()
     }
 }
 
@@ -68,9 +174,24 @@ impl ProtocolOrchestratorTrait for ProtocolOrchestratorService {
         let input = Arc::new(input.clone());
         self.validator.on_transfer_request(&input).await?;
 
-        // resolve data service
+        // only agreements whose signatures meet the policy can be transferred
         let agreement_id = input.dto.get_agreement_id().ok_or(anyhow!("no agreement id"))?;
-        let dct_formats = input.dto.format.parse::<DctFormats>()?;
+        self.facades
+            .get_agreement_facade()
+            .await
+            .verify_agreement_signatures(&agreement_id)
+            .await?;
+        self.verify_agreement_for_transfer(&agreement_id).await?;
+
+        // resolve data service, refusing formats no distribution of the dataset offers
+        let dct_formats = input.dto.format.parse::<DctFormats>().map_err(|e| {
+            let err = CommonErrors::format_new(
+                BadFormat::Received,
+                format!("Format {} is not valid: {}", input.dto.format, e).as_str(),
+            );
+            error!("{}", err.log());
+            err
+        })?;
         let data_service = self
             .facades
             .get_data_service_facade()
@@ -101,6 +222,7 @@ impl ProtocolOrchestratorTrait for ProtocolOrchestratorService {
             .create_process(
                 "DSP",
                 "INBOUND",
+                current_participant_id().unwrap_or_default().as_str(),
                 None,
                 None,
                 Arc::new(input.dto.clone()),
@@ -122,6 +244,12 @@ impl ProtocolOrchestratorTrait for ProtocolOrchestratorService {
             .await?;
 
         // notify
+        self.notify_process_transition(
+            &transfer_process,
+            &input._type,
+            RainbowEventsNotificationMessageTypes::DSProtocolMessage,
+            RainbowEventsNotificationMessageOperation::IncomingMessage,
+        );
 
         let transfer_process_dto = TransferProcessMessageWrapper::try_from(transfer_process)?;
         Ok((transfer_process_dto, false))
@@ -158,7 +286,20 @@ impl ProtocolOrchestratorTrait for ProtocolOrchestratorService {
             .await
             .on_transfer_start_post(&transfer_process_id)
             .await?;
+        // duties of the agreement start counting from here
+        if let Err(e) = self.duty_scheduler.schedule_duties(&transfer_process).await {
+            error!(
+                "Unable to schedule duties of transfer process {}: {}",
+                transfer_process_id, e
+            );
+        }
         // notify
+        self.notify_process_transition(
+            &transfer_process,
+            &input._type,
+            RainbowEventsNotificationMessageTypes::DSProtocolMessage,
+            RainbowEventsNotificationMessageOperation::IncomingMessage,
+        );
 
         let transfer_process_dto = TransferProcessMessageWrapper::try_from(transfer_process)?;
         Ok(transfer_process_dto)
@@ -198,6 +339,12 @@ impl ProtocolOrchestratorTrait for ProtocolOrchestratorService {
             .await?;
 
         // notify
+        self.notify_process_transition(
+            &transfer_process,
+            &input._type,
+            RainbowEventsNotificationMessageTypes::DSProtocolMessage,
+            RainbowEventsNotificationMessageOperation::IncomingMessage,
+        );
 
         let transfer_process_dto = TransferProcessMessageWrapper::try_from(transfer_process)?;
         Ok(transfer_process_dto)
@@ -237,6 +384,12 @@ impl ProtocolOrchestratorTrait for ProtocolOrchestratorService {
             .await?;
 
         // notify
+        self.notify_process_transition(
+            &transfer_process,
+            &input._type,
+            RainbowEventsNotificationMessageTypes::DSProtocolMessage,
+            RainbowEventsNotificationMessageOperation::IncomingMessage,
+        );
 
         let transfer_process_dto = TransferProcessMessageWrapper::try_from(transfer_process)?;
         Ok(transfer_process_dto)
@@ -276,6 +429,12 @@ impl ProtocolOrchestratorTrait for ProtocolOrchestratorService {
             .await?;
 
         // notify
+        self.notify_process_transition(
+            &transfer_process,
+            &input._type,
+            RainbowEventsNotificationMessageTypes::DSProtocolMessage,
+            RainbowEventsNotificationMessageOperation::IncomingMessage,
+        );
 
         let transfer_process_dto = TransferProcessMessageWrapper::try_from(transfer_process)?;
         Ok(transfer_process_dto)
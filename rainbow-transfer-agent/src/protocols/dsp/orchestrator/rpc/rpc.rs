@@ -24,6 +24,7 @@ use crate::protocols::dsp::orchestrator::rpc::types::{
     RpcTransferStartMessageDto, RpcTransferSuspensionMessageDto, RpcTransferTerminationMessageDto,
 };
 use crate::protocols::dsp::orchestrator::rpc::RPCOrchestratorTrait;
+use crate::protocols::dsp::orchestrator::traits::orchestration_notifications::OrchestrationNotifications;
 use crate::protocols::dsp::persistence::TransferPersistenceTrait;
 use crate::protocols::dsp::protocol_types::{
     TransferCompletionMessageDto, TransferProcessAckDto, TransferProcessMessageTrait,
@@ -33,7 +34,14 @@ use crate::protocols::dsp::protocol_types::{
 use crate::protocols::dsp::validator::traits::validation_rpc_steps::ValidationRpcSteps;
 use rainbow_common::dcat_formats::DctFormats;
 use rainbow_common::dsp_common::context_field::ContextField;
-use rainbow_common::http_client::HttpClient;
+use rainbow_common::facades::ssi_auth_facade::{send_with_peer_token, PeerTokenFacadeTrait};
+use rainbow_common::http_client::{HttpClient, RequestOptions};
+use rainbow_events::core::notification::notification_publisher::RainbowEventsPublisherTrait;
+use rainbow_events::core::notification::notification_types::{
+    RainbowEventsNotificationMessageOperation, RainbowEventsNotificationMessageTypes,
+};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
 use std::str::FromStr;
 use std::sync::Arc;
 use urn::Urn;
@@ -44,6 +52,8 @@ pub struct RPCOrchestratorService {
     persistence_service: Arc<dyn TransferPersistenceTrait>,
     http_client: Arc<HttpClient>,
     facades: Arc<dyn FacadeTrait>,
+    events: Arc<dyn RainbowEventsPublisherTrait>,
+    peer_token_facade: Arc<dyn PeerTokenFacadeTrait>,
 }
 
 impl RPCOrchestratorService {
@@ -52,8 +62,40 @@ impl RPCOrchestratorService {
         persistence_service: Arc<dyn TransferPersistenceTrait>,
         http_client: Arc<HttpClient>,
         facades: Arc<dyn FacadeTrait>,
+        events: Arc<dyn RainbowEventsPublisherTrait>,
+        peer_token_facade: Arc<dyn PeerTokenFacadeTrait>,
     ) -> RPCOrchestratorService {
-        RPCOrchestratorService { validator, persistence_service, http_client, facades }
+        RPCOrchestratorService {
+            validator,
+            persistence_service,
+            http_client,
+            facades,
+            events,
+            peer_token_facade,
+        }
+    }
+
+    /// Posts a DSP message with the peer's own token, onboarding again once if it is rejected
+    async fn post_to_peer<T, R>(&self, peer: &str, url: &str, body: &T) -> anyhow::Result<R>
+    where
+        T: Serialize + Sync,
+        R: DeserializeOwned,
+    {
+        send_with_peer_token(
+            self.peer_token_facade.as_ref(),
+            peer,
+            RequestOptions::new(),
+            |options| async move {
+                self.http_client.post_json_with_options(url, body, &options).await
+            },
+        )
+        .await
+    }
+}
+
+impl OrchestrationNotifications for RPCOrchestratorService {
+    fn get_events_publisher(&self) -> Arc<dyn RainbowEventsPublisherTrait> {
+        self.events.clone()
     }
 }
 
@@ -71,14 +113,16 @@ impl RPCOrchestratorTrait for RPCOrchestratorService {
         // create url
         let peer_url = format!("{}/transfers/request", provider_address);
         // request
-        let response: TransferProcessMessageWrapper<TransferProcessAckDto> =
-            self.http_client.post_json(peer_url.as_str(), &request_body).await?;
+        let response: TransferProcessMessageWrapper<TransferProcessAckDto> = self
+            .post_to_peer(input.associated_agent_peer.as_str(), peer_url.as_str(), &request_body)
+            .await?;
         // persist
         let transfer_process = self
             .persistence_service
             .create_process(
                 "DSP",
                 "OUTBOUND",
+                input.associated_agent_peer.as_str(),
                 Some(response.dto.provider_pid.clone()),
                 Some(provider_address),
                 Arc::new(request_body.clone().dto),
@@ -98,6 +142,14 @@ impl RPCOrchestratorTrait for RPCOrchestratorService {
             )
             .await?;
 
+        // notify
+        self.notify_process_transition(
+            &transfer_process,
+            &request_body._type,
+            RainbowEventsNotificationMessageTypes::RPCMessage,
+            RainbowEventsNotificationMessageOperation::OutgoingMessage,
+        );
+
         let response = RpcTransferMessageDto {
             request: input.clone(),
             response,
@@ -153,6 +205,13 @@ impl RPCOrchestratorTrait for RPCOrchestratorService {
             .await
             .on_transfer_start_post(&Urn::from_str(transfer_process.inner.id.as_str())?)
             .await?;
+        // notify
+        self.notify_process_transition(
+            &transfer_process,
+            &transfer_process_into_trait.get_message(),
+            RainbowEventsNotificationMessageTypes::RPCMessage,
+            RainbowEventsNotificationMessageOperation::OutgoingMessage,
+        );
         // bye!
         let response = RpcTransferMessageDto {
             request: input.clone(),
@@ -211,6 +270,13 @@ impl RPCOrchestratorTrait for RPCOrchestratorService {
             .await
             .on_transfer_suspension_post(&Urn::from_str(transfer_process.inner.id.as_str())?)
             .await?;
+        // notify
+        self.notify_process_transition(
+            &transfer_process,
+            &transfer_process_into_trait.get_message(),
+            RainbowEventsNotificationMessageTypes::RPCMessage,
+            RainbowEventsNotificationMessageOperation::OutgoingMessage,
+        );
         // bye!
         let response = RpcTransferMessageDto {
             request: input.clone(),
@@ -265,6 +331,13 @@ impl RPCOrchestratorTrait for RPCOrchestratorService {
             .await
             .on_transfer_completion_post(&Urn::from_str(transfer_process.inner.id.as_str())?)
             .await?;
+        // notify
+        self.notify_process_transition(
+            &transfer_process,
+            &transfer_process_into_trait.get_message(),
+            RainbowEventsNotificationMessageTypes::RPCMessage,
+            RainbowEventsNotificationMessageOperation::OutgoingMessage,
+        );
         // bye!
         let response = RpcTransferMessageDto {
             request: input.clone(),
@@ -323,6 +396,13 @@ impl RPCOrchestratorTrait for RPCOrchestratorService {
             .await
             .on_transfer_termination_post(&Urn::from_str(transfer_process.inner.id.as_str())?)
             .await?;
+        // notify
+        self.notify_process_transition(
+            &transfer_process,
+            &transfer_process_into_trait.get_message(),
+            RainbowEventsNotificationMessageTypes::RPCMessage,
+            RainbowEventsNotificationMessageOperation::OutgoingMessage,
+        );
         // bye!
         let response = RpcTransferMessageDto {
             request: input.clone(),
@@ -360,8 +440,9 @@ impl RPCOrchestratorService {
             dto: payload.as_ref().clone(),
         };
         // send message to peer url
+        let peer = transfer_process.inner.associated_agent_peer.as_str();
         let response: TransferProcessMessageWrapper<TransferProcessAckDto> =
-            self.http_client.post_json(peer_url.as_str(), &message).await?;
+            self.post_to_peer(peer, peer_url.as_str(), &message).await?;
         // persist
         let transfer_process = self
             .persistence_service
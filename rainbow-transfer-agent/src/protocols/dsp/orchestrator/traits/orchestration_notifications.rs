@@ -0,0 +1,53 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use crate::entities::transfer_process::TransferProcessDto;
+use crate::protocols::dsp::protocol_types::TransferProcessMessageType;
+use rainbow_events::core::notification::notification_publisher::RainbowEventsPublisherTrait;
+use rainbow_events::core::notification::notification_types::{
+    RainbowEventsNotificationEvent, RainbowEventsNotificationMessageCategory,
+    RainbowEventsNotificationMessageOperation, RainbowEventsNotificationMessageTypes,
+};
+use std::sync::Arc;
+
+/// Publishes the transfer process as it was persisted after each transition
+pub trait OrchestrationNotifications: Send + Sync + 'static {
+    fn get_events_publisher(&self) -> Arc<dyn RainbowEventsPublisherTrait>;
+
+    fn notify_process_transition(
+        &self,
+        process: &TransferProcessDto,
+        message: &TransferProcessMessageType,
+        message_type: RainbowEventsNotificationMessageTypes,
+        operation: RainbowEventsNotificationMessageOperation,
+    ) {
+        let event = process.identifiers.iter().fold(
+            RainbowEventsNotificationEvent::new(
+                RainbowEventsNotificationMessageCategory::TransferProcess,
+                message.to_string(),
+                message_type,
+                operation,
+            )
+            .with_entity_id("processId", &process.inner.id)
+            .with_snapshot(process),
+            |event, (name, id)| event.with_entity_id(name, id),
+        );
+        self.get_events_publisher().publish(event);
+    }
+}
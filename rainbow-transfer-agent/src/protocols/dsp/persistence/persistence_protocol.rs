@@ -29,6 +29,7 @@ use crate::protocols::dsp::protocol_types::{
 };
 use crate::protocols::dsp::transfer_types::TransferState;
 use anyhow::bail;
+use rainbow_common::auth::ssi::current_participant_id;
 use rainbow_common::config::types::roles::RoleConfig;
 use rainbow_common::errors::CommonErrors;
 use rainbow_common::errors::ErrorLog;
@@ -79,6 +80,17 @@ impl TransferPersistenceTrait for TransferPersistenceForProtocolService {
                     err
                 },
             )?;
+        // processes opened by a verified peer only accept messages from that same peer
+        if let Some(participant_id) = current_participant_id() {
+            let peer = &transfer_process.inner.associated_agent_peer;
+            if !peer.is_empty() && *peer != participant_id {
+                let err = CommonErrors::forbidden_new(
+                    format!("Process {} belongs to another participant", urn).as_str(),
+                );
+                error!("{}", err.log());
+                bail!(err);
+            }
+        }
         Ok(transfer_process)
     }
 
@@ -86,6 +98,7 @@ impl TransferPersistenceTrait for TransferPersistenceForProtocolService {
         &self,
         protocol: &str,
         direction: &str,
+        associated_agent_peer: &str,
         provider_pid: Option<Urn>,
         provider_address: Option<String>,
         payload_dto: Arc<dyn TransferProcessMessageTrait>,
@@ -118,7 +131,7 @@ impl TransferPersistenceTrait for TransferPersistenceForProtocolService {
             .create_transfer_process(&NewTransferProcessDto {
                 id: Some(transfer_process_id.clone()),
                 state: TransferState::REQUESTED.to_string(),
-                associated_agent_peer: "".to_string(),
+                associated_agent_peer: associated_agent_peer.to_string(),
                 protocol: protocol.to_string(),
                 transfer_direction: format,
                 agreement_id,
@@ -0,0 +1,103 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+use crate::data::factory_sql::TransferAgentRepoForSql;
+use crate::entities::transfer_process::transfer_process::TransferAgentProcessesService;
+use crate::protocols::dsp::agreement_watcher::agreement_watcher::AgreementWatcherService;
+use crate::protocols::dsp::agreement_watcher::AgreementWatcherTrait;
+use crate::protocols::dsp::facades::agreement_facade::agreement_facade::AgreementFacadeServiceForDSProtocol;
+use crate::protocols::dsp::facades::termination_facade::termination_facade::TransferTerminationFacadeService;
+use rainbow_common::config::services::TransferConfig;
+use rainbow_common::config::traits::CommonConfigTrait;
+use rainbow_common::http_client::HttpClient;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tokio_util::sync::CancellationToken;
+use ymir::services::vault::vault_rs::VaultService;
+use ymir::services::vault::VaultTrait;
+
+/// How often ongoing transfers are checked against revoked or expired agreements
+const AGREEMENT_WATCHER_TICK: Duration = Duration::from_secs(60);
+
+pub struct TransferAgreementWatcherWorker {}
+
+impl TransferAgreementWatcherWorker {
+    pub async fn spawn(
+        config: &TransferConfig,
+        vault: Arc<VaultService>,
+        token: &CancellationToken,
+    ) -> anyhow::Result<JoinHandle<()>> {
+        let watcher = Self::create_watcher(config, vault.clone()).await?;
+        tracing::info!("Agreement watcher running every {:?}", AGREEMENT_WATCHER_TICK);
+
+        let token = token.clone();
+        let handle = tokio::spawn(async move {
+            let mut interval = tokio::time::interval(AGREEMENT_WATCHER_TICK);
+            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            loop {
+                tokio::select! {
+                    _ = token.cancelled() => {
+                        tracing::info!("Agreement watcher received shutdown signal");
+                        break;
+                    }
+                    _ = interval.tick() => {
+                        if let Err(e) = watcher.terminate_transfers_of_invalid_agreements().await {
+                            tracing::error!("Agreement watcher round failed: {}", e);
+                        }
+                    }
+                }
+            }
+        });
+
+        Ok(handle)
+    }
+
+    async fn create_watcher(
+        config: &TransferConfig,
+        vault: Arc<VaultService>,
+    ) -> anyhow::Result<Arc<dyn AgreementWatcherTrait>> {
+        // conn
+        let db_connection = vault.get_db_connection(config.common()).await;
+        let http_client = Arc::new(HttpClient::new(10, 3));
+        let config = Arc::new(config.clone());
+
+        // repo
+        let transfer_repo = Arc::new(TransferAgentRepoForSql::create_repo(db_connection.clone()));
+
+        // entities
+        let transfer_process_service =
+            Arc::new(TransferAgentProcessesService::new(transfer_repo.clone()));
+
+        // facades
+        let agreement_facade = Arc::new(AgreementFacadeServiceForDSProtocol::new(
+            config.clone(),
+            http_client.clone(),
+        ));
+        let termination_facade = Arc::new(TransferTerminationFacadeService::new(
+            config.clone(),
+            http_client.clone(),
+        ));
+
+        Ok(Arc::new(AgreementWatcherService::new(
+            transfer_process_service,
+            agreement_facade,
+            termination_facade,
+        )))
+    }
+}
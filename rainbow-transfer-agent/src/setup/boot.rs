@@ -16,16 +16,20 @@
  *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
  *
  */
+use crate::setup::agreement_watcher_worker::TransferAgreementWatcherWorker;
+use crate::setup::duty_scheduler_worker::TransferDutySchedulerWorker;
 use crate::setup::grpc_worker::TransferGrpcWorker;
 use crate::setup::http_worker::TransferHttpWorker;
 use rainbow_common::boot::BootstrapServiceTrait;
 use rainbow_common::config::services::TransferConfig;
-use rainbow_common::config::traits::ConfigLoader;
+use rainbow_common::config::traits::{CommonConfigTrait, ConfigLoader};
+use rainbow_events::core::notification::notification_delivery::RainbowEventsDeliveryWorker;
 use std::sync::Arc;
 use tokio::sync::broadcast;
 use tokio::sync::broadcast::Sender;
 use tokio_util::sync::CancellationToken;
 use ymir::services::vault::vault_rs::VaultService;
+use ymir::services::vault::VaultTrait;
 
 pub struct TransferBoot;
 
@@ -67,6 +71,18 @@ impl BootstrapServiceTrait for TransferBoot {
         tracing::info!("Spawning gRPC subsystem...");
         let grpc_handle = TransferGrpcWorker::spawn(config, vault.clone(), &cancel_token).await?;
 
+        tracing::info!("Spawning events delivery worker...");
+        let db_connection = vault.get_db_connection(config.common()).await;
+        let events_handle = RainbowEventsDeliveryWorker::spawn(db_connection, &cancel_token);
+
+        tracing::info!("Spawning agreement watcher...");
+        let agreement_watcher_handle =
+            TransferAgreementWatcherWorker::spawn(config, vault.clone(), &cancel_token).await?;
+
+        tracing::info!("Spawning duty scheduler...");
+        let duty_scheduler_handle =
+            TransferDutySchedulerWorker::spawn(config, vault.clone(), &cancel_token).await?;
+
         // non-blocking thread
         let token_clone = cancel_token.clone();
         tokio::spawn(async move {
@@ -81,6 +97,15 @@ impl BootstrapServiceTrait for TransferBoot {
                 _ = async { grpc_handle.await } => {
                     tracing::error!("GRPC subsystem failed or stopped unexpectedly!");
                 }
+                _ = async { events_handle.await } => {
+                    tracing::error!("Events delivery subsystem failed or stopped unexpectedly!");
+                }
+                _ = async { agreement_watcher_handle.await } => {
+                    tracing::error!("Agreement watcher failed or stopped unexpectedly!");
+                }
+                _ = async { duty_scheduler_handle.await } => {
+                    tracing::error!("Duty scheduler failed or stopped unexpectedly!");
+                }
             }
 
             tracing::info!("Initiating internal graceful shutdown sequence...");
@@ -20,6 +20,7 @@ use crate::data::migrations::get_transfer_agent_migrations;
 use rainbow_common::config::services::TransferConfig;
 use rainbow_common::config::traits::CommonConfigTrait;
 use rainbow_dataplane::get_dataplane_migrations;
+use rainbow_events::data::migrations::get_events_migrations;
 use sea_orm_migration::{MigrationTrait, MigratorTrait};
 use std::sync::Arc;
 use ymir::services::vault::vault_rs::VaultService;
@@ -32,9 +33,11 @@ impl MigratorTrait for TransferAgentMigration {
         let mut migrations: Vec<Box<dyn MigrationTrait>> = vec![];
         let mut transfer_agent_migrations = get_transfer_agent_migrations();
         let mut data_plane_migrations = get_dataplane_migrations();
+        let mut events_migrations = get_events_migrations();
 
         migrations.append(&mut transfer_agent_migrations);
         migrations.append(&mut data_plane_migrations);
+        migrations.append(&mut events_migrations);
         migrations
     }
 }
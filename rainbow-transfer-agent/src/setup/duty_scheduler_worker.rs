@@ -0,0 +1,112 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+use crate::data::factory_sql::TransferAgentRepoForSql;
+use crate::entities::transfer_duties::transfer_duties::TransferAgentDutiesService;
+use crate::entities::transfer_process::transfer_process::TransferAgentProcessesService;
+use crate::protocols::dsp::duty_scheduler::duty_scheduler::DutySchedulerService;
+use crate::protocols::dsp::duty_scheduler::DutySchedulerTrait;
+use crate::protocols::dsp::facades::agreement_facade::agreement_facade::AgreementFacadeServiceForDSProtocol;
+use rainbow_common::config::services::TransferConfig;
+use rainbow_common::config::traits::CommonConfigTrait;
+use rainbow_common::http_client::HttpClient;
+use rainbow_events::core::notification::notification::RainbowEventsNotificationsService;
+use rainbow_events::core::notification::notification_publisher::RainbowEventsPublisher;
+use rainbow_events::data::repo::sql::EventsRepoForSql;
+use rainbow_events::data::repo::EventsRepoFactory;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tokio_util::sync::CancellationToken;
+use ymir::services::vault::vault_rs::VaultService;
+use ymir::services::vault::VaultTrait;
+
+/// How often due duties are looked up and executed
+const DUTY_SCHEDULER_TICK: Duration = Duration::from_secs(60);
+
+pub struct TransferDutySchedulerWorker {}
+
+impl TransferDutySchedulerWorker {
+    pub async fn spawn(
+        config: &TransferConfig,
+        vault: Arc<VaultService>,
+        token: &CancellationToken,
+    ) -> anyhow::Result<JoinHandle<()>> {
+        let scheduler = Self::create_scheduler(config, vault.clone()).await?;
+        tracing::info!("Duty scheduler running every {:?}", DUTY_SCHEDULER_TICK);
+
+        let token = token.clone();
+        let handle = tokio::spawn(async move {
+            let mut interval = tokio::time::interval(DUTY_SCHEDULER_TICK);
+            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            loop {
+                tokio::select! {
+                    _ = token.cancelled() => {
+                        tracing::info!("Duty scheduler received shutdown signal");
+                        break;
+                    }
+                    _ = interval.tick() => {
+                        if let Err(e) = scheduler.execute_due_duties().await {
+                            tracing::error!("Duty scheduler round failed: {}", e);
+                        }
+                    }
+                }
+            }
+        });
+
+        Ok(handle)
+    }
+
+    async fn create_scheduler(
+        config: &TransferConfig,
+        vault: Arc<VaultService>,
+    ) -> anyhow::Result<Arc<dyn DutySchedulerTrait>> {
+        // conn
+        let db_connection = vault.get_db_connection(config.common()).await;
+        let http_client = Arc::new(HttpClient::new(10, 3));
+        let config = Arc::new(config.clone());
+
+        // repo
+        let transfer_repo = Arc::new(TransferAgentRepoForSql::create_repo(db_connection.clone()));
+
+        // entities
+        let transfer_process_service =
+            Arc::new(TransferAgentProcessesService::new(transfer_repo.clone()));
+        let duties_service = Arc::new(TransferAgentDutiesService::new(transfer_repo.clone()));
+
+        // events
+        let events_repo = Arc::new(EventsRepoForSql::create_repo(db_connection.clone()));
+        let notification_service = Arc::new(RainbowEventsNotificationsService::new(events_repo));
+        let events_publisher = Arc::new(RainbowEventsPublisher::new(notification_service));
+
+        // facades
+        let agreement_facade = Arc::new(AgreementFacadeServiceForDSProtocol::new(
+            config.clone(),
+            http_client.clone(),
+        ));
+
+        Ok(Arc::new(DutySchedulerService::new(
+            duties_service,
+            transfer_process_service,
+            agreement_facade,
+            events_publisher,
+            http_client,
+            config,
+        )))
+    }
+}
@@ -18,8 +18,10 @@
  */
 
 use crate::data::factory_sql::TransferAgentRepoForSql;
+use crate::entities::transfer_duties::transfer_duties::TransferAgentDutiesService;
 use crate::entities::transfer_messages::transfer_messages::TransferAgentMessagesService;
 use crate::entities::transfer_process::transfer_process::TransferAgentProcessesService;
+use crate::http::transfer_duties::TransferAgentDutiesRouter;
 use crate::http::transfer_messages::TransferAgentMessagesRouter;
 use crate::http::transfer_process::TransferAgentProcessesRouter;
 use crate::protocols::dsp::TransferDSP;
@@ -31,6 +33,14 @@ use rainbow_common::config::services::TransferConfig;
 use rainbow_common::config::traits::CommonConfigTrait;
 use rainbow_common::errors::CommonErrors;
 use rainbow_common::well_known::WellKnownRoot;
+use rainbow_events::core::notification::notification::RainbowEventsNotificationsService;
+use rainbow_events::core::notification::notification_publisher::RainbowEventsPublisher;
+use rainbow_events::core::subscription::subscription::RainbowEventsSubscriptionService;
+use rainbow_events::core::subscription::subscription_types::SubscriptionEntities;
+use rainbow_events::data::repo::sql::EventsRepoForSql;
+use rainbow_events::data::repo::EventsRepoFactory;
+use rainbow_events::http::notification::notification::RainbowEventsNotificationRouter;
+use rainbow_events::http::subscription::subscription::RainbowEventsSubscriptionRouter;
 use std::sync::Arc;
 use tokio::net::TcpListener;
 use tokio::task::JoinHandle;
@@ -122,11 +132,35 @@ pub async fn create_root_http_router(
         Arc::new(TransferAgentProcessesService::new(transfer_repo.clone()));
     let entities_router =
         TransferAgentProcessesRouter::new(entities_controller_service.clone(), config.clone());
+    let duties_controller_service =
+        Arc::new(TransferAgentDutiesService::new(transfer_repo.clone()));
+    let duties_router =
+        TransferAgentDutiesRouter::new(duties_controller_service.clone(), config.clone());
+
+    // events
+    let events_repo = Arc::new(EventsRepoForSql::create_repo(db_connection.clone()));
+    let subscription_service = Arc::new(RainbowEventsSubscriptionService::new(events_repo.clone()));
+    let notification_service = Arc::new(RainbowEventsNotificationsService::new(events_repo));
+    let events_publisher = Arc::new(RainbowEventsPublisher::new(notification_service.clone()));
+    let subscription_router = RainbowEventsSubscriptionRouter::new(
+        subscription_service.clone(),
+        Some(SubscriptionEntities::TransferProcess),
+    );
+    let dataplane_subscription_router = RainbowEventsSubscriptionRouter::new(
+        subscription_service,
+        Some(SubscriptionEntities::DataPlaneProcess),
+    );
+    let notification_router = RainbowEventsNotificationRouter::new(
+        notification_service,
+        Some(SubscriptionEntities::TransferProcess),
+    );
 
     // dsp
     let dsp_router = TransferDSP::new(
         messages_controller_service.clone(),
         entities_controller_service.clone(),
+        duties_controller_service.clone(),
+        events_publisher,
         config.clone(),
         vault.clone(),
     )
@@ -143,6 +177,18 @@ pub async fn create_root_http_router(
             format!("{}/transfer-processes", router_str.as_str()).as_str(),
             entities_router.router(),
         )
+        .nest(
+            format!("{}/transfer-duties", router_str.as_str()).as_str(),
+            duties_router.router(),
+        )
+        .nest(
+            router_str.as_str(),
+            subscription_router.router().merge(notification_router.router()),
+        )
+        .nest(
+            format!("{}/data-plane", router_str.as_str()).as_str(),
+            dataplane_subscription_router.router(),
+        )
         .nest("/dsp/current/transfers", dsp_router);
     Ok(router)
 }
@@ -17,9 +17,11 @@
  *
  */
 
+mod agreement_watcher_worker;
 mod boot;
 pub mod cmd;
 mod db_migrations;
+mod duty_scheduler_worker;
 mod grpc_worker;
 mod http_worker;
 pub use http_worker::create_root_http_router;
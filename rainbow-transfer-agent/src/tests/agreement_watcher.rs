@@ -0,0 +1,188 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+#[cfg(test)]
+mod test_agreement_watcher {
+    use crate::entities::transfer_process::{MockTransferAgentProcessesTrait, TransferProcessDto};
+    use crate::protocols::dsp::agreement_watcher::agreement_watcher::AgreementWatcherService;
+    use crate::protocols::dsp::agreement_watcher::AgreementWatcherTrait;
+    use crate::protocols::dsp::facades::agreement_facade::MockAgreementFacadeTrait;
+    use crate::protocols::dsp::facades::termination_facade::MockTransferTerminationFacadeTrait;
+    use anyhow::anyhow;
+    use rainbow_negotiation_agent::AgreementRevocationStatusDto;
+    use serde_json::json;
+    use std::sync::Arc;
+
+    fn process(id: &str, agreement_id: &str) -> TransferProcessDto {
+        serde_json::from_value(json!({
+            "id": id,
+            "state": "STARTED",
+            "stateAttribute": null,
+            "associatedAgentPeer": "did:web:provider",
+            "protocol": "dsp",
+            "transferDirection": "PULL",
+            "agreementId": agreement_id,
+            "callbackAddress": null,
+            "role": "Consumer",
+            "properties": {},
+            "errorDetails": null,
+            "createdAt": "2025-01-01T00:00:00+00:00",
+            "updatedAt": null,
+            "identifiers": { "consumerPid": id, "providerPid": "urn:provider-pid:1" },
+            "messages": []
+        }))
+        .unwrap()
+    }
+
+    fn status(agreement_id: &str, revoked: bool, expired: bool) -> AgreementRevocationStatusDto {
+        AgreementRevocationStatusDto {
+            agreement_id: agreement_id.to_string(),
+            state: match (revoked, expired) {
+                (true, _) => "REVOKED".to_string(),
+                (_, true) => "EXPIRED".to_string(),
+                _ => "ACTIVE".to_string(),
+            },
+            revoked,
+            expired,
+            valid: !revoked && !expired,
+            valid_from: None,
+            valid_until: None,
+            revoked_at: None,
+            revocation_reason: revoked.then(|| "Consumer breached the agreement".to_string()),
+        }
+    }
+
+    fn watcher(
+        processes: Vec<TransferProcessDto>,
+        agreement_facade: MockAgreementFacadeTrait,
+        termination_facade: MockTransferTerminationFacadeTrait,
+    ) -> AgreementWatcherService {
+        let mut process_service = MockTransferAgentProcessesTrait::new();
+        process_service
+            .expect_get_transfer_processes_by_states()
+            .returning(move |_| Ok(processes.clone()));
+        AgreementWatcherService::new(
+            Arc::new(process_service),
+            Arc::new(agreement_facade),
+            Arc::new(termination_facade),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_terminates_every_transfer_of_a_revoked_agreement() {
+        let mut agreement_facade = MockAgreementFacadeTrait::new();
+        agreement_facade
+            .expect_get_agreement_revocation_status()
+            .times(1)
+            .returning(|id| Ok(status(id.to_string().as_str(), true, false)));
+        let mut termination_facade = MockTransferTerminationFacadeTrait::new();
+        termination_facade
+            .expect_terminate_transfer()
+            .withf(|_, code, reason| {
+                code == "AGREEMENT_REVOKED" && reason == "Consumer breached the agreement"
+            })
+            .times(2)
+            .returning(|_, _, _| Ok(()));
+
+        let watcher = watcher(
+            vec![
+                process("urn:process:1", "urn:agreement:1"),
+                process("urn:process:2", "urn:agreement:1"),
+            ],
+            agreement_facade,
+            termination_facade,
+        );
+        watcher.terminate_transfers_of_invalid_agreements().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_keeps_transfers_of_agreements_in_force() {
+        let mut agreement_facade = MockAgreementFacadeTrait::new();
+        agreement_facade
+            .expect_get_agreement_revocation_status()
+            .returning(|id| Ok(status(id.to_string().as_str(), false, false)));
+        let mut termination_facade = MockTransferTerminationFacadeTrait::new();
+        termination_facade.expect_terminate_transfer().never();
+
+        let watcher = watcher(
+            vec![process("urn:process:1", "urn:agreement:1")],
+            agreement_facade,
+            termination_facade,
+        );
+        watcher.terminate_transfers_of_invalid_agreements().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_malformed_agreement_id_does_not_stop_the_round() {
+        let mut agreement_facade = MockAgreementFacadeTrait::new();
+        agreement_facade
+            .expect_get_agreement_revocation_status()
+            .times(1)
+            .returning(|id| Ok(status(id.to_string().as_str(), false, true)));
+        let mut termination_facade = MockTransferTerminationFacadeTrait::new();
+        termination_facade
+            .expect_terminate_transfer()
+            .withf(|process, code, _| {
+                process.inner.id == "urn:process:2" && code == "AGREEMENT_EXPIRED"
+            })
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+
+        let watcher = watcher(
+            vec![
+                process("urn:process:1", "not an agreement id"),
+                process("urn:process:2", "urn:agreement:2"),
+            ],
+            agreement_facade,
+            termination_facade,
+        );
+        watcher.terminate_transfers_of_invalid_agreements().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_failures_are_contained_to_their_agreement() {
+        let mut agreement_facade = MockAgreementFacadeTrait::new();
+        agreement_facade.expect_get_agreement_revocation_status().returning(|id| {
+            match id.to_string().as_str() {
+                "urn:agreement:1" => Err(anyhow!("negotiation agent unreachable")),
+                id => Ok(status(id, false, true)),
+            }
+        });
+        let mut termination_facade = MockTransferTerminationFacadeTrait::new();
+        termination_facade
+            .expect_terminate_transfer()
+            .withf(|process, _, _| process.inner.agreement_id == "urn:agreement:2")
+            .times(2)
+            .returning(|process, _, _| match process.inner.id.as_str() {
+                "urn:process:2" => Err(anyhow!("peer unreachable")),
+                _ => Ok(()),
+            });
+
+        let watcher = watcher(
+            vec![
+                process("urn:process:1", "urn:agreement:1"),
+                process("urn:process:2", "urn:agreement:2"),
+                process("urn:process:3", "urn:agreement:2"),
+            ],
+            agreement_facade,
+            termination_facade,
+        );
+        watcher.terminate_transfers_of_invalid_agreements().await.unwrap();
+    }
+}
@@ -0,0 +1,339 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+#[cfg(test)]
+mod test_data_service_resolver_facade {
+    use crate::protocols::dsp::facades::data_service_resolver_facade::catalog_client::{
+        distribution_from_catalog, MockCatalogResolverClientTrait,
+    };
+    use crate::protocols::dsp::facades::data_service_resolver_facade::data_service_resolver_facade::DataServiceFacadeServiceForDSProtocol;
+    use crate::protocols::dsp::facades::data_service_resolver_facade::DataServiceFacadeTrait;
+    use anyhow::anyhow;
+    use rainbow_catalog_agent::{DataServiceDto, DistributionDto};
+    use rainbow_common::dcat_formats::DctFormats;
+    use rainbow_common::errors::CommonErrors;
+    use rainbow_negotiation_agent::AgreementDto;
+    use serde_json::json;
+    use std::str::FromStr;
+    use std::sync::Arc;
+    use urn::Urn;
+
+    const DATASET_ID: &str = "urn:dataset:1";
+    const AGREEMENT_ID: &str = "urn:agreement:1";
+
+    fn urn(id: &str) -> Urn {
+        Urn::from_str(id).unwrap()
+    }
+
+    fn http_pull() -> DctFormats {
+        DctFormats::from_str("http+pull").unwrap()
+    }
+
+    fn distribution(id: &str, format: &str, access_service: &str, issued: &str) -> DistributionDto {
+        // shaped as the catalog agent answers, with camelCase keys
+        distribution_from_catalog(json!({
+            "id": id,
+            "dctIssued": issued,
+            "dcatAccessService": access_service,
+            "datasetId": DATASET_ID,
+            "dctFormat": format
+        }))
+        .unwrap()
+    }
+
+    fn data_service(id: &str) -> DataServiceDto {
+        serde_json::from_value(json!({
+            "id": id,
+            "dcatEndpointUrl": format!("https://{}.example.org/data", id.replace(':', "-")),
+            "dctIssued": "2025-01-01T00:00:00+00:00",
+            "catalogId": "urn:catalog:1",
+            "dspaceMainDataService": false
+        }))
+        .unwrap()
+    }
+
+    fn agreement(target: &str) -> AgreementDto {
+        serde_json::from_value(json!({
+            "id": AGREEMENT_ID,
+            "negotiationAgentProcessId": "urn:process:1",
+            "negotiationAgentMessageId": "urn:message:1",
+            "consumerParticipantId": "did:web:consumer",
+            "providerParticipantId": "did:web:provider",
+            "agreementContent": {},
+            "target": target,
+            "state": "ACTIVE",
+            "createdAt": "2025-01-01T00:00:00+00:00"
+        }))
+        .unwrap()
+    }
+
+    fn catalog_with_distributions(
+        distributions: Vec<DistributionDto>,
+    ) -> MockCatalogResolverClientTrait {
+        let mut catalog = MockCatalogResolverClientTrait::new();
+        catalog
+            .expect_get_distributions_by_dataset_id()
+            .withf(|dataset_id| dataset_id.to_string() == DATASET_ID)
+            .returning(move |_| Ok(distributions.clone()));
+        catalog
+    }
+
+    fn facade(catalog: MockCatalogResolverClientTrait) -> DataServiceFacadeServiceForDSProtocol {
+        DataServiceFacadeServiceForDSProtocol::new(Arc::new(catalog))
+    }
+
+    #[test]
+    fn test_distribution_from_catalog_reads_camel_case_keys() {
+        let distribution = distribution(
+            "urn:distribution:1",
+            "Http+Pull",
+            "urn:data-service:http",
+            "2025-01-01T00:00:00+00:00",
+        );
+        assert_eq!(distribution.inner.dcat_access_service, "urn:data-service:http");
+        assert_eq!(distribution.inner.dataset_id, DATASET_ID);
+        assert_eq!(distribution.inner.dct_format, Some("Http+Pull".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_resolves_access_service_of_matching_distribution() {
+        let mut catalog = catalog_with_distributions(vec![
+            distribution(
+                "urn:distribution:kafka",
+                "Kafka+Push",
+                "urn:data-service:kafka",
+                "2024-01-01T00:00:00+00:00",
+            ),
+            distribution(
+                "urn:distribution:http",
+                "Http+Pull",
+                "urn:data-service:http",
+                "2025-01-01T00:00:00+00:00",
+            ),
+        ]);
+        catalog
+            .expect_get_data_service_by_id()
+            .withf(|id| id.to_string() == "urn:data-service:http")
+            .times(1)
+            .returning(|id| Ok(data_service(id.to_string().as_str())));
+
+        let data_service = facade(catalog)
+            .resolve_data_service_by_dataset_id(&urn(DATASET_ID), Some(&http_pull()))
+            .await
+            .unwrap();
+        assert_eq!(data_service.inner.id, "urn:data-service:http");
+    }
+
+    #[tokio::test]
+    async fn test_chooses_oldest_distribution_when_several_match() {
+        let mut catalog = catalog_with_distributions(vec![
+            distribution(
+                "urn:distribution:b",
+                "Http+Pull",
+                "urn:data-service:newer",
+                "2025-06-01T00:00:00+00:00",
+            ),
+            distribution(
+                "urn:distribution:c",
+                "Http+Pull",
+                "urn:data-service:older-c",
+                "2025-01-01T00:00:00+00:00",
+            ),
+            distribution(
+                "urn:distribution:a",
+                "Http+Pull",
+                "urn:data-service:older-a",
+                "2025-01-01T00:00:00+00:00",
+            ),
+        ]);
+        catalog
+            .expect_get_data_service_by_id()
+            .withf(|id| id.to_string() == "urn:data-service:older-a")
+            .times(1)
+            .returning(|id| Ok(data_service(id.to_string().as_str())));
+
+        let data_service = facade(catalog)
+            .resolve_data_service_by_dataset_id(&urn(DATASET_ID), Some(&http_pull()))
+            .await
+            .unwrap();
+        assert_eq!(data_service.inner.id, "urn:data-service:older-a");
+    }
+
+    #[tokio::test]
+    async fn test_falls_back_to_next_distribution_when_access_service_is_missing() {
+        let mut catalog = catalog_with_distributions(vec![
+            distribution(
+                "urn:distribution:1",
+                "Http+Pull",
+                "urn:data-service:gone",
+                "2024-01-01T00:00:00+00:00",
+            ),
+            distribution(
+                "urn:distribution:2",
+                "Http+Pull",
+                "urn:data-service:http",
+                "2025-01-01T00:00:00+00:00",
+            ),
+        ]);
+        catalog
+            .expect_get_data_service_by_id()
+            .withf(|id| id.to_string() == "urn:data-service:gone")
+            .times(1)
+            .returning(|_| Err(anyhow!("not found")));
+        catalog
+            .expect_get_data_service_by_id()
+            .withf(|id| id.to_string() == "urn:data-service:http")
+            .times(1)
+            .returning(|id| Ok(data_service(id.to_string().as_str())));
+
+        let data_service = facade(catalog)
+            .resolve_data_service_by_dataset_id(&urn(DATASET_ID), Some(&http_pull()))
+            .await
+            .unwrap();
+        assert_eq!(data_service.inner.id, "urn:data-service:http");
+    }
+
+    #[tokio::test]
+    async fn test_fails_when_no_distribution_offers_format() {
+        let mut catalog = catalog_with_distributions(vec![distribution(
+            "urn:distribution:kafka",
+            "Kafka+Push",
+            "urn:data-service:kafka",
+            "2025-01-01T00:00:00+00:00",
+        )]);
+        catalog.expect_get_data_service_by_id().never();
+
+        let err = facade(catalog)
+            .resolve_data_service_by_dataset_id(&urn(DATASET_ID), Some(&http_pull()))
+            .await
+            .unwrap_err();
+        match err.downcast::<CommonErrors>() {
+            Ok(CommonErrors::FormatError { cause, .. }) => {
+                assert!(cause.contains("Http+Pull"));
+                assert!(cause.contains("Kafka+Push"));
+            }
+            other => panic!("Expected FormatError, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_fails_when_dataset_has_no_distributions() {
+        let catalog = catalog_with_distributions(vec![]);
+
+        let err = facade(catalog)
+            .resolve_data_service_by_dataset_id(&urn(DATASET_ID), Some(&http_pull()))
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err.downcast::<CommonErrors>(),
+            Ok(CommonErrors::MissingResourceError { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_fails_when_no_access_service_resolves() {
+        let mut catalog = catalog_with_distributions(vec![distribution(
+            "urn:distribution:1",
+            "Http+Pull",
+            "urn:data-service:gone",
+            "2025-01-01T00:00:00+00:00",
+        )]);
+        catalog.expect_get_data_service_by_id().times(1).returning(|_| Err(anyhow!("not found")));
+
+        let err = facade(catalog)
+            .resolve_data_service_by_dataset_id(&urn(DATASET_ID), Some(&http_pull()))
+            .await
+            .unwrap_err();
+        match err.downcast::<CommonErrors>() {
+            Ok(CommonErrors::MissingResourceError { cause, .. }) => {
+                assert!(cause.contains("urn:distribution:1"));
+                assert!(cause.contains("urn:data-service:gone"));
+            }
+            other => panic!("Expected MissingResourceError, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_refuses_format_missing_from_camel_case_catalog_response() {
+        // exactly what the catalog agent serializes for /distributions/dataset/{id}
+        let catalog_response = json!([{
+            "id": "urn:distribution:kafka",
+            "dctIssued": "2025-01-01T00:00:00+00:00",
+            "dctModified": null,
+            "dctTitle": "Kafka stream",
+            "dctDescription": null,
+            "dcatAccessService": "urn:data-service:kafka",
+            "datasetId": DATASET_ID,
+            "dctFormat": "Kafka+Push",
+            "dcatByteSize": null,
+            "dcatMediaType": null,
+            "spdxChecksumAlgorithm": null,
+            "spdxChecksumValue": null,
+            "dctLicense": null,
+            "dctAccessRights": null
+        }]);
+        let distributions = catalog_response
+            .as_array()
+            .unwrap()
+            .iter()
+            .cloned()
+            .map(distribution_from_catalog)
+            .collect::<anyhow::Result<Vec<_>>>()
+            .unwrap();
+        assert_eq!(distributions[0].inner.dct_format, Some("Kafka+Push".to_string()));
+
+        let mut catalog = catalog_with_distributions(distributions);
+        catalog.expect_get_agreement().times(1).returning(|_| Ok(agreement(DATASET_ID)));
+        catalog.expect_get_data_service_by_id().never();
+
+        let err = facade(catalog)
+            .resolve_data_service_by_agreement_id(&urn(AGREEMENT_ID), Some(&http_pull()))
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err.downcast::<CommonErrors>(),
+            Ok(CommonErrors::FormatError { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_resolves_through_agreement_target() {
+        let mut catalog = catalog_with_distributions(vec![distribution(
+            "urn:distribution:http",
+            "Http+Pull",
+            "urn:data-service:http",
+            "2025-01-01T00:00:00+00:00",
+        )]);
+        catalog
+            .expect_get_agreement()
+            .withf(|id| id.to_string() == AGREEMENT_ID)
+            .times(1)
+            .returning(|_| Ok(agreement(DATASET_ID)));
+        catalog
+            .expect_get_data_service_by_id()
+            .times(1)
+            .returning(|id| Ok(data_service(id.to_string().as_str())));
+
+        let data_service = facade(catalog)
+            .resolve_data_service_by_agreement_id(&urn(AGREEMENT_ID), Some(&http_pull()))
+            .await
+            .unwrap();
+        assert_eq!(data_service.inner.id, "urn:data-service:http");
+    }
+}
@@ -0,0 +1,168 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+#[cfg(test)]
+mod test_duty_extractor {
+    use crate::entities::transfer_duties::TransferDutyExecutionKind;
+    use crate::protocols::dsp::duty_scheduler::duty_extractor::{extract_duties, parse_duration};
+    use chrono::{DateTime, Duration, FixedOffset};
+    use serde_json::json;
+
+    fn started_at() -> DateTime<FixedOffset> {
+        DateTime::parse_from_rfc3339("2025-01-01T00:00:00+00:00").unwrap()
+    }
+
+    #[test]
+    fn test_delete_duty_without_deadline_is_manual_and_undated() {
+        let agreement = json!({
+            "permission": [{ "action": "use", "duty": [{ "action": "odrl:delete" }] }]
+        });
+        let duties = extract_duties(&agreement, &started_at());
+        assert_eq!(duties.len(), 1);
+        assert_eq!(duties[0].rule_type, "duty");
+        assert_eq!(duties[0].action, "delete");
+        assert_eq!(duties[0].execution_kind, TransferDutyExecutionKind::Manual);
+        assert_eq!(duties[0].due_at, None);
+    }
+
+    #[test]
+    fn test_delete_duty_is_due_after_elapsed_time() {
+        let agreement = json!({
+            "obligation": {
+                "action": { "@id": "http://www.w3.org/ns/odrl/2/delete" },
+                "constraint": [{
+                    "leftOperand": "odrl:elapsedTime",
+                    "operator": "odrl:lteq",
+                    "rightOperand": { "@value": "P30D", "@type": "xsd:duration" }
+                }]
+            }
+        });
+        let duties = extract_duties(&agreement, &started_at());
+        assert_eq!(duties.len(), 1);
+        assert_eq!(duties[0].rule_type, "obligation");
+        assert_eq!(duties[0].due_at, Some(started_at() + Duration::days(30)));
+    }
+
+    #[test]
+    fn test_earliest_deadline_wins() {
+        let agreement = json!({
+            "obligation": [{
+                "action": "delete",
+                "constraint": [{
+                    "and": [
+                        { "leftOperand": "elapsedTime", "operator": "lteq", "rightOperand": "P1Y" },
+                        {
+                            "leftOperand": "dateTime",
+                            "operator": "lt",
+                            "rightOperand": { "@value": "2025-02-01", "@type": "xsd:date" }
+                        }
+                    ]
+                }]
+            }]
+        });
+        let duties = extract_duties(&agreement, &started_at());
+        let expected = DateTime::parse_from_rfc3339("2025-02-01T00:00:00+00:00").unwrap();
+        assert_eq!(duties[0].due_at, Some(expected));
+    }
+
+    #[test]
+    fn test_disjunctive_deadlines_are_ignored() {
+        let agreement = json!({
+            "obligation": [{
+                "action": "delete",
+                "constraint": [{
+                    "or": [
+                        { "leftOperand": "elapsedTime", "operator": "lteq", "rightOperand": "P1D" },
+                        { "leftOperand": "elapsedTime", "operator": "lteq", "rightOperand": "P2D" }
+                    ]
+                }]
+            }]
+        });
+        let duties = extract_duties(&agreement, &started_at());
+        assert_eq!(duties[0].due_at, None);
+    }
+
+    #[test]
+    fn test_inform_duty_with_http_delivery_channel_is_a_callback() {
+        let agreement = json!({
+            "permission": [{
+                "action": "use",
+                "duty": {
+                    "action": "inform",
+                    "constraint": [{
+                        "leftOperand": "deliveryChannel",
+                        "operator": "eq",
+                        "rightOperand": { "@id": "https://provider.example.org/duties" }
+                    }]
+                }
+            }]
+        });
+        let duties = extract_duties(&agreement, &started_at());
+        assert_eq!(duties[0].execution_kind, TransferDutyExecutionKind::Callback);
+        assert_eq!(
+            duties[0].callback_address.as_deref(),
+            Some("https://provider.example.org/duties")
+        );
+        assert_eq!(duties[0].due_at, Some(started_at()));
+    }
+
+    #[test]
+    fn test_other_duties_are_manual() {
+        let agreement = json!({
+            "obligation": [{ "action": "compensate" }, { "action": "notify" }, { "target": "x" }]
+        });
+        let duties = extract_duties(&agreement, &started_at());
+        assert_eq!(duties.len(), 2);
+        assert_eq!(duties[0].execution_kind, TransferDutyExecutionKind::Manual);
+        assert_eq!(duties[0].due_at, None);
+        assert_eq!(duties[1].execution_kind, TransferDutyExecutionKind::Notify);
+    }
+
+    #[test]
+    fn test_parses_iso_durations() {
+        assert_eq!(parse_duration("P1DT2H"), Some(Duration::hours(26)));
+        assert_eq!(parse_duration("PT90M"), Some(Duration::minutes(90)));
+        assert_eq!(parse_duration("P2W"), Some(Duration::weeks(2)));
+        assert_eq!(parse_duration("P1M"), Some(Duration::days(30)));
+        assert_eq!(parse_duration("P"), None);
+        assert_eq!(parse_duration("P1H"), None);
+        assert_eq!(parse_duration("P1D2"), None);
+        assert_eq!(parse_duration("30D"), None);
+    }
+
+    #[test]
+    fn test_overflowing_durations_are_rejected() {
+        assert_eq!(parse_duration("P9223372036854775807Y"), None);
+        assert_eq!(parse_duration("P99999999999999999D"), None);
+        assert_eq!(parse_duration("P99999999999999999999S"), None);
+
+        let agreement = json!({
+            "obligation": [{
+                "action": "delete",
+                "constraint": [{
+                    "leftOperand": "elapsedTime",
+                    "operator": "lteq",
+                    "rightOperand": "P99999999Y"
+                }]
+            }]
+        });
+        let duties = extract_duties(&agreement, &started_at());
+        assert_eq!(duties[0].due_at, None);
+    }
+}
@@ -0,0 +1,276 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+#[cfg(test)]
+mod test_duty_scheduler {
+    use crate::data::entities::transfer_duty;
+    use crate::entities::transfer_duties::{MockTransferAgentDutiesTrait, TransferDutyDto};
+    use crate::entities::transfer_process::{MockTransferAgentProcessesTrait, TransferProcessDto};
+    use crate::protocols::dsp::duty_scheduler::duty_scheduler::{
+        is_allowed_callback, DutySchedulerService,
+    };
+    use crate::protocols::dsp::duty_scheduler::DutySchedulerTrait;
+    use crate::protocols::dsp::facades::agreement_facade::MockAgreementFacadeTrait;
+    use anyhow::anyhow;
+    use chrono::Utc;
+    use rainbow_common::config::services::TransferConfig;
+    use rainbow_common::config::traits::ConfigLoader;
+    use rainbow_common::http_client::HttpClient;
+    use rainbow_events::core::notification::notification_publisher::MockRainbowEventsPublisherTrait;
+    use serde_json::json;
+    use std::sync::Arc;
+
+    fn duty(id: &str, process_id: &str, execution_kind: &str) -> TransferDutyDto {
+        TransferDutyDto::from(transfer_duty::Model {
+            id: id.to_string(),
+            transfer_agent_process_id: process_id.to_string(),
+            agreement_id: "urn:agreement:1".to_string(),
+            rule_type: "obligation".to_string(),
+            action: execution_kind.to_lowercase(),
+            execution_kind: execution_kind.to_string(),
+            callback_address: None,
+            state: "PENDING".to_string(),
+            due_at: None,
+            attempts: 0,
+            last_error: None,
+            duty: json!({}),
+            created_at: Utc::now().fixed_offset(),
+            updated_at: None,
+            fulfilled_at: None,
+        })
+    }
+
+    fn process(id: &str, state: &str) -> TransferProcessDto {
+        serde_json::from_value(json!({
+            "id": id,
+            "state": state,
+            "stateAttribute": null,
+            "associatedAgentPeer": "did:web:provider",
+            "protocol": "dsp",
+            "transferDirection": "PULL",
+            "agreementId": "urn:agreement:1",
+            "callbackAddress": null,
+            "role": "Consumer",
+            "properties": {},
+            "errorDetails": null,
+            "createdAt": "2025-01-01T00:00:00+00:00",
+            "updatedAt": null,
+            "identifiers": { "consumerPid": id, "providerPid": "urn:provider-pid:1" },
+            "messages": []
+        }))
+        .unwrap()
+    }
+
+    /// Processes whose id ends in "ended" are completed, the rest are still started
+    fn process_service() -> MockTransferAgentProcessesTrait {
+        let mut process_service = MockTransferAgentProcessesTrait::new();
+        process_service.expect_get_transfer_process_by_id().returning(|id| {
+            let id = id.to_string();
+            match id.ends_with("ended") {
+                true => Ok(process(id.as_str(), "COMPLETED")),
+                false => Ok(process(id.as_str(), "STARTED")),
+            }
+        });
+        process_service
+    }
+
+    fn duties_service(
+        due: Vec<TransferDutyDto>,
+        awaiting_transfer_end: Vec<TransferDutyDto>,
+    ) -> MockTransferAgentDutiesTrait {
+        let mut duties_service = MockTransferAgentDutiesTrait::new();
+        duties_service.expect_get_due_automatic_duties().returning(move || Ok(due.clone()));
+        duties_service
+            .expect_get_duties_awaiting_transfer_end()
+            .returning(move || Ok(awaiting_transfer_end.clone()));
+        duties_service
+    }
+
+    fn config(callback_allowlist: Vec<&str>) -> Arc<TransferConfig> {
+        let config =
+            TransferConfig::load("../static/environment/config/core.consumer.yaml".to_string());
+        let mut config = serde_json::to_value(config).unwrap();
+        config["duty_callback_allowlist"] = json!(callback_allowlist);
+        Arc::new(serde_json::from_value(config).unwrap())
+    }
+
+    fn scheduler(
+        duties_service: MockTransferAgentDutiesTrait,
+        events: MockRainbowEventsPublisherTrait,
+        callback_allowlist: Vec<&str>,
+    ) -> DutySchedulerService {
+        DutySchedulerService::new(
+            Arc::new(duties_service),
+            Arc::new(process_service()),
+            Arc::new(MockAgreementFacadeTrait::new()),
+            Arc::new(events),
+            Arc::new(HttpClient::new(1, 1)),
+            config(callback_allowlist),
+        )
+    }
+
+    fn delete_duty(id: &str, process_id: &str) -> TransferDutyDto {
+        let mut delete_duty = duty(id, process_id, "MANUAL");
+        delete_duty.inner.action = "delete".to_string();
+        delete_duty
+    }
+
+    #[tokio::test]
+    async fn test_undated_delete_duty_is_due_once_the_transfer_ends() {
+        let mut duties_service = duties_service(
+            vec![],
+            vec![
+                delete_duty("urn:duty:1", "urn:process:ended"),
+                delete_duty("urn:duty:2", "urn:process:2"),
+            ],
+        );
+        duties_service
+            .expect_set_transfer_duty_due_date()
+            .withf(|id, due_at| id.to_string() == "urn:duty:1" && *due_at <= Utc::now())
+            .times(1)
+            .returning(|_, due_at| {
+                let mut dated = delete_duty("urn:duty:1", "urn:process:ended");
+                dated.inner.due_at = Some(due_at);
+                Ok(dated)
+            });
+        // deleting the data is up to the operator, the scheduler never discharges it
+        duties_service.expect_fulfill_transfer_duty().never();
+        let mut events = MockRainbowEventsPublisherTrait::new();
+        events
+            .expect_publish()
+            .withf(|event| {
+                event.subcategory == "TransferDuty"
+                    && event.content.entity_ids.get("dutyId").map(String::as_str)
+                        == Some("urn:duty:1")
+                    && event.content.snapshot["action"] == "delete"
+            })
+            .times(1)
+            .return_const(());
+
+        let scheduler = scheduler(duties_service, events, vec![]);
+        scheduler.execute_due_duties().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_undated_delete_duty_failing_to_date_does_not_stop_the_round() {
+        let mut duties_service = duties_service(
+            vec![duty("urn:duty:3", "urn:process:1", "NOTIFY")],
+            vec![
+                delete_duty("urn:duty:1", "urn:process:ended"),
+                delete_duty("urn:duty:2", "urn:process:ended"),
+            ],
+        );
+        duties_service.expect_set_transfer_duty_due_date().times(2).returning(|id, _| {
+            match id.to_string().as_str() {
+                "urn:duty:1" => Err(anyhow!("database unreachable")),
+                id => Ok(delete_duty(id, "urn:process:ended")),
+            }
+        });
+        duties_service
+            .expect_fulfill_transfer_duty()
+            .withf(|id| id.to_string() == "urn:duty:3")
+            .times(1)
+            .returning(|_| Ok(duty("urn:duty:3", "urn:process:1", "NOTIFY")));
+        let mut events = MockRainbowEventsPublisherTrait::new();
+        events.expect_publish().times(2).return_const(());
+
+        let scheduler = scheduler(duties_service, events, vec![]);
+        scheduler.execute_due_duties().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_callback_outside_the_allowlist_is_refused() {
+        let mut callback = duty("urn:duty:1", "urn:process:1", "CALLBACK");
+        callback.inner.callback_address = Some("https://attacker.example.org/duties".to_string());
+        let mut duties_service = duties_service(vec![callback], vec![]);
+        duties_service.expect_fulfill_transfer_duty().never();
+        duties_service
+            .expect_record_transfer_duty_failure()
+            .withf(|id, error, _| id.to_string() == "urn:duty:1" && error.contains("allowlist"))
+            .times(1)
+            .returning(|_, _, _| Ok(duty("urn:duty:1", "urn:process:1", "CALLBACK")));
+
+        let scheduler = scheduler(
+            duties_service,
+            MockRainbowEventsPublisherTrait::new(),
+            vec!["https://provider.example.org/duties"],
+        );
+        scheduler.execute_due_duties().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_broken_duties_do_not_stop_the_round() {
+        let mut duties_service = duties_service(
+            vec![
+                duty("not a duty id", "urn:process:1", "NOTIFY"),
+                duty("urn:duty:2", "urn:process:2", "MANUAL"),
+                duty("urn:duty:3", "urn:process:1", "NOTIFY"),
+                duty("urn:duty:4", "urn:process:1", "NOTIFY"),
+            ],
+            vec![],
+        );
+        duties_service
+            .expect_record_transfer_duty_failure()
+            .withf(|id, _, _| id.to_string() == "urn:duty:2")
+            .times(1)
+            .returning(|_, _, _| Err(anyhow!("database unreachable")));
+        duties_service.expect_fulfill_transfer_duty().times(2).returning(|id| {
+            match id.to_string().as_str() {
+                "urn:duty:3" => Err(anyhow!("database unreachable")),
+                id => Ok(duty(id, "urn:process:1", "NOTIFY")),
+            }
+        });
+        let mut events = MockRainbowEventsPublisherTrait::new();
+        events.expect_publish().times(2).return_const(());
+
+        let scheduler = scheduler(duties_service, events, vec![]);
+        scheduler.execute_due_duties().await.unwrap();
+    }
+
+    #[test]
+    fn test_callback_allowlist_matches_origin_and_path() {
+        let allowlist = vec![
+            "https://provider.example.org/duties".to_string(),
+            "http://localhost:8080/".to_string(),
+        ];
+        assert!(is_allowed_callback(&allowlist, "https://provider.example.org/duties"));
+        assert!(is_allowed_callback(
+            &allowlist,
+            "https://provider.example.org/duties/1"
+        ));
+        assert!(is_allowed_callback(
+            &allowlist,
+            "https://provider.example.org:443/duties"
+        ));
+        assert!(is_allowed_callback(&allowlist, "http://localhost:8080/any/path"));
+        assert!(!is_allowed_callback(
+            &allowlist,
+            "https://provider.example.org/dutiesx"
+        ));
+        assert!(!is_allowed_callback(&allowlist, "https://provider.example.org/other"));
+        assert!(!is_allowed_callback(&allowlist, "http://provider.example.org/duties"));
+        assert!(!is_allowed_callback(
+            &allowlist,
+            "https://provider.example.org.evil/duties"
+        ));
+        assert!(!is_allowed_callback(&allowlist, "http://localhost:8081/"));
+        assert!(!is_allowed_callback(&allowlist, "not a url"));
+        assert!(!is_allowed_callback(&[], "https://provider.example.org/duties"));
+    }
+}
@@ -17,4 +17,11 @@
  *
  */
 
-// coming soon...
+mod agreement_watcher;
+mod data_service_resolver_facade;
+mod duty_extractor;
+mod duty_scheduler;
+mod orchestration_notifications;
+mod protocol_orchestrator;
+mod provider_push_strategy;
+mod transfer_duty_repo;
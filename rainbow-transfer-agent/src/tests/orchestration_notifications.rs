@@ -0,0 +1,119 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+#[cfg(test)]
+mod test_orchestration_notifications {
+    use crate::data::entities::transfer_process;
+    use crate::entities::transfer_process::TransferProcessDto;
+    use crate::protocols::dsp::orchestrator::traits::orchestration_notifications::OrchestrationNotifications;
+    use crate::protocols::dsp::protocol_types::TransferProcessMessageType;
+    use rainbow_events::core::notification::notification_publisher::{
+        MockRainbowEventsPublisherTrait, RainbowEventsPublisherTrait,
+    };
+    use rainbow_events::core::notification::notification_types::{
+        RainbowEventsNotificationEvent, RainbowEventsNotificationMessageCategory,
+        RainbowEventsNotificationMessageOperation, RainbowEventsNotificationMessageTypes,
+    };
+    use serde_json::json;
+    use std::collections::HashMap;
+    use std::sync::{Arc, Mutex};
+
+    const PROCESS_ID: &str = "urn:transfer-process:1";
+
+    /// Same notifications the protocol and rpc orchestrators send
+    struct Orchestrator {
+        events: Arc<dyn RainbowEventsPublisherTrait>,
+    }
+
+    impl OrchestrationNotifications for Orchestrator {
+        fn get_events_publisher(&self) -> Arc<dyn RainbowEventsPublisherTrait> {
+            self.events.clone()
+        }
+    }
+
+    fn process() -> TransferProcessDto {
+        TransferProcessDto {
+            inner: transfer_process::Model {
+                id: PROCESS_ID.to_string(),
+                state: "STARTED".to_string(),
+                state_attribute: None,
+                associated_agent_peer: "did:web:consumer.example".to_string(),
+                protocol: "DSP".to_string(),
+                transfer_direction: "PULL".to_string(),
+                agreement_id: "urn:agreement:1".to_string(),
+                callback_address: Some("https://consumer.example/dsp".to_string()),
+                role: "Provider".to_string(),
+                properties: json!({}),
+                error_details: None,
+                created_at: chrono::Utc::now().into(),
+                updated_at: None,
+            },
+            identifiers: HashMap::from([
+                ("providerPid".to_string(), "urn:provider-pid:1".to_string()),
+                ("consumerPid".to_string(), "urn:consumer-pid:1".to_string()),
+            ]),
+            messages: vec![],
+        }
+    }
+
+    #[test]
+    fn test_transition_names_the_message_and_the_process_ids() {
+        let published = Arc::new(Mutex::new(Vec::<RainbowEventsNotificationEvent>::new()));
+        let recorded = published.clone();
+        let mut events = MockRainbowEventsPublisherTrait::new();
+        events.expect_publish().returning(move |event| recorded.lock().unwrap().push(event));
+        let orchestrator = Orchestrator { events: Arc::new(events) };
+
+        orchestrator.notify_process_transition(
+            &process(),
+            &TransferProcessMessageType::TransferStartMessage,
+            RainbowEventsNotificationMessageTypes::RPCMessage,
+            RainbowEventsNotificationMessageOperation::OutgoingMessage,
+        );
+
+        let published = published.lock().unwrap();
+        assert_eq!(published.len(), 1);
+        let event = &published[0];
+        assert_eq!(
+            event.category,
+            RainbowEventsNotificationMessageCategory::TransferProcess
+        );
+        assert_eq!(event.subcategory, "TransferStartMessage");
+        assert_eq!(event.message_type, RainbowEventsNotificationMessageTypes::RPCMessage);
+        assert_eq!(
+            event.message_operation,
+            RainbowEventsNotificationMessageOperation::OutgoingMessage
+        );
+        let entity_ids = event
+            .content
+            .entity_ids
+            .iter()
+            .map(|(name, id)| (name.as_str(), id.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            entity_ids,
+            vec![
+                ("consumerPid", "urn:consumer-pid:1"),
+                ("processId", PROCESS_ID),
+                ("providerPid", "urn:provider-pid:1"),
+            ]
+        );
+        assert_eq!(event.content.snapshot["state"], json!("STARTED"));
+    }
+}
@@ -0,0 +1,143 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+#[cfg(test)]
+mod test_protocol_orchestrator {
+    use crate::protocols::dsp::orchestrator::protocol::protocol::refuse_transfer_under_agreement;
+    use rainbow_common::errors::CommonErrors;
+    use rainbow_negotiation_agent::{AgreementDto, AgreementRevocationStatusDto};
+    use serde_json::json;
+    use std::str::FromStr;
+    use urn::Urn;
+
+    const AGREEMENT_ID: &str = "urn:agreement:1";
+    const CONSUMER: &str = "did:web:consumer";
+
+    fn agreement_id() -> Urn {
+        Urn::from_str(AGREEMENT_ID).unwrap()
+    }
+
+    fn agreement() -> AgreementDto {
+        serde_json::from_value(json!({
+            "id": AGREEMENT_ID,
+            "negotiationAgentProcessId": "urn:process:1",
+            "negotiationAgentMessageId": "urn:message:1",
+            "consumerParticipantId": CONSUMER,
+            "providerParticipantId": "did:web:provider",
+            "agreementContent": {},
+            "target": "urn:dataset:1",
+            "state": "ACTIVE",
+            "createdAt": "2025-01-01T00:00:00+00:00"
+        }))
+        .unwrap()
+    }
+
+    fn in_force() -> AgreementRevocationStatusDto {
+        AgreementRevocationStatusDto {
+            agreement_id: AGREEMENT_ID.to_string(),
+            state: "ACTIVE".to_string(),
+            revoked: false,
+            expired: false,
+            valid: true,
+            valid_from: None,
+            valid_until: None,
+            revoked_at: None,
+            revocation_reason: None,
+        }
+    }
+
+    fn forbidden_cause(err: Option<CommonErrors>) -> String {
+        match err {
+            Some(CommonErrors::ForbiddenError { cause, .. }) => cause,
+            other => panic!("Expected ForbiddenError, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_accepts_consumer_of_agreement_in_force() {
+        let refusal = refuse_transfer_under_agreement(
+            &agreement_id(),
+            &agreement(),
+            Some(CONSUMER),
+            &in_force(),
+        );
+        assert!(refusal.is_none());
+    }
+
+    #[test]
+    fn test_refuses_unauthenticated_caller() {
+        let refusal =
+            refuse_transfer_under_agreement(&agreement_id(), &agreement(), None, &in_force());
+        assert!(matches!(refusal, Some(CommonErrors::UnauthorizedError { .. })));
+    }
+
+    #[test]
+    fn test_refuses_other_participant() {
+        let refusal = refuse_transfer_under_agreement(
+            &agreement_id(),
+            &agreement(),
+            Some("did:web:intruder"),
+            &in_force(),
+        );
+        assert!(forbidden_cause(refusal).contains("did:web:intruder"));
+    }
+
+    #[test]
+    fn test_refuses_revoked_agreement() {
+        let status = AgreementRevocationStatusDto {
+            state: "REVOKED".to_string(),
+            revoked: true,
+            valid: false,
+            revocation_reason: Some("breach of contract".to_string()),
+            ..in_force()
+        };
+        let refusal =
+            refuse_transfer_under_agreement(&agreement_id(), &agreement(), Some(CONSUMER), &status);
+        assert!(forbidden_cause(refusal).contains("breach of contract"));
+    }
+
+    #[test]
+    fn test_refuses_expired_agreement() {
+        let status = AgreementRevocationStatusDto {
+            state: "EXPIRED".to_string(),
+            expired: true,
+            valid: false,
+            ..in_force()
+        };
+        let refusal =
+            refuse_transfer_under_agreement(&agreement_id(), &agreement(), Some(CONSUMER), &status);
+        assert!(forbidden_cause(refusal).contains("expired"));
+    }
+
+    #[test]
+    fn test_refuses_agreement_not_finalized() {
+        let status = AgreementRevocationStatusDto { state: "REQUESTED".to_string(), ..in_force() };
+        let refusal =
+            refuse_transfer_under_agreement(&agreement_id(), &agreement(), Some(CONSUMER), &status);
+        assert!(forbidden_cause(refusal).contains("REQUESTED"));
+    }
+
+    #[test]
+    fn test_refuses_agreement_not_in_force_yet() {
+        let status = AgreementRevocationStatusDto { valid: false, ..in_force() };
+        let refusal =
+            refuse_transfer_under_agreement(&agreement_id(), &agreement(), Some(CONSUMER), &status);
+        assert!(forbidden_cause(refusal).contains("not in force yet"));
+    }
+}
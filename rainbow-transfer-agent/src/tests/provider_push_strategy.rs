@@ -0,0 +1,66 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+#[cfg(test)]
+mod test_provider_push_strategy {
+    use crate::protocols::dsp::facades::data_plane_facade::dataplane_strategies::provider_push_strategy::consumer_sink_url;
+    use crate::protocols::dsp::protocol_types::DataAddressDto;
+    use rainbow_common::errors::CommonErrors;
+
+    fn data_address(endpoint: &str) -> Option<DataAddressDto> {
+        Some(DataAddressDto {
+            endpoint_type: "HTTP".to_string(),
+            endpoint: Some(endpoint.to_string()),
+            endpoint_properties: None,
+        })
+    }
+
+    #[test]
+    fn test_accepts_http_sinks() {
+        let sink = consumer_sink_url(&data_address("https://consumer.example/sink")).unwrap();
+        assert_eq!(sink.as_str(), "https://consumer.example/sink");
+    }
+
+    #[test]
+    fn test_refuses_file_sinks() {
+        let err = consumer_sink_url(&data_address("file:///etc/cron.d/job")).unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<CommonErrors>(),
+            Some(CommonErrors::ForbiddenError { .. })
+        ));
+    }
+
+    #[test]
+    fn test_refuses_other_schemes() {
+        let err = consumer_sink_url(&data_address("ftp://consumer.example/sink")).unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<CommonErrors>(),
+            Some(CommonErrors::ForbiddenError { .. })
+        ));
+    }
+
+    #[test]
+    fn test_refuses_missing_data_address() {
+        let err = consumer_sink_url(&None).unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<CommonErrors>(),
+            Some(CommonErrors::FormatError { .. })
+        ));
+    }
+}
@@ -0,0 +1,153 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+#[cfg(test)]
+mod test_transfer_duty_repo {
+    use crate::data::entities::transfer_duty::{self, EditTransferDutyModel, NewTransferDutyModel};
+    use crate::data::entities::transfer_process::NewTransferProcessModel;
+    use crate::data::get_transfer_agent_migrations;
+    use crate::data::repo_traits::transfer_duty_repo::TransferDutyRepoTrait;
+    use crate::data::repo_traits::transfer_process_repo::TransferProcessRepoTrait;
+    use crate::data::repos_sql::transfer_duty_repo::TransferDutyRepoForSql;
+    use crate::data::repos_sql::transfer_process_repo::TransferProcessRepoForSql;
+    use chrono::{DateTime, Duration, FixedOffset, Utc};
+    use sea_orm::{ConnectOptions, Database, DatabaseConnection};
+    use sea_orm_migration::{MigrationTrait, MigratorTrait};
+    use serde_json::json;
+    use std::str::FromStr;
+    use urn::Urn;
+
+    struct Migrator;
+
+    impl MigratorTrait for Migrator {
+        fn migrations() -> Vec<Box<dyn MigrationTrait>> {
+            get_transfer_agent_migrations()
+        }
+    }
+
+    async fn setup() -> DatabaseConnection {
+        // a single connection, every new in-memory connection is a new empty database
+        let mut options = ConnectOptions::new("sqlite::memory:");
+        options.max_connections(1).sqlx_logging(false);
+        let db = Database::connect(options).await.unwrap();
+        Migrator::up(&db, None).await.unwrap();
+        TransferProcessRepoForSql::new(db.clone())
+            .create_transfer_process(&NewTransferProcessModel {
+                id: Some(Urn::from_str("urn:process:1").unwrap()),
+                state: "STARTED".to_string(),
+                role: "Consumer".to_string(),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        db
+    }
+
+    async fn create_duty(
+        repo: &TransferDutyRepoForSql,
+        id: &str,
+        execution_kind: &str,
+        state: &str,
+        due_at: Option<DateTime<FixedOffset>>,
+    ) {
+        repo.create_transfer_duty(&NewTransferDutyModel {
+            id: Some(Urn::from_str(id).unwrap()),
+            transfer_agent_process_id: Urn::from_str("urn:process:1").unwrap(),
+            agreement_id: Urn::from_str("urn:agreement:1").unwrap(),
+            rule_type: "obligation".to_string(),
+            action: execution_kind.to_lowercase(),
+            execution_kind: execution_kind.to_string(),
+            callback_address: None,
+            state: state.to_string(),
+            due_at,
+            duty: json!({ "action": execution_kind.to_lowercase() }),
+        })
+        .await
+        .unwrap();
+    }
+
+    async fn seed(repo: &TransferDutyRepoForSql) {
+        let yesterday = (Utc::now() - Duration::days(1)).fixed_offset();
+        let tomorrow = (Utc::now() + Duration::days(1)).fixed_offset();
+        create_duty(repo, "urn:duty:undated-delete", "MANUAL", "PENDING", None).await;
+        create_duty(repo, "urn:duty:dated-delete", "MANUAL", "PENDING", Some(yesterday)).await;
+        create_duty(repo, "urn:duty:future-delete", "MANUAL", "PENDING", Some(tomorrow)).await;
+        create_duty(repo, "urn:duty:fulfilled-delete", "MANUAL", "FULFILLED", None).await;
+        create_duty(repo, "urn:duty:undated-notify", "NOTIFY", "PENDING", None).await;
+        create_duty(repo, "urn:duty:dated-notify", "NOTIFY", "PENDING", Some(yesterday)).await;
+    }
+
+    fn ids(duties: Vec<transfer_duty::Model>) -> Vec<String> {
+        duties.into_iter().map(|duty| duty.id).collect()
+    }
+
+    #[tokio::test]
+    async fn test_undated_duties_filter_by_state_and_kind() {
+        let repo = TransferDutyRepoForSql::new(setup().await);
+        seed(&repo).await;
+
+        let duties = repo
+            .get_undated_duties(&vec!["PENDING".to_string()], &vec!["MANUAL".to_string()])
+            .await
+            .unwrap();
+        assert_eq!(ids(duties), vec!["urn:duty:undated-delete"]);
+    }
+
+    #[tokio::test]
+    async fn test_duties_due_before_skip_undated_and_future_ones() {
+        let repo = TransferDutyRepoForSql::new(setup().await);
+        seed(&repo).await;
+
+        let duties = repo
+            .get_duties_due_before(
+                &vec!["PENDING".to_string()],
+                &vec!["MANUAL".to_string(), "NOTIFY".to_string()],
+                &Utc::now(),
+            )
+            .await
+            .unwrap();
+        let mut ids = ids(duties);
+        ids.sort();
+        assert_eq!(ids, vec!["urn:duty:dated-delete", "urn:duty:dated-notify"]);
+    }
+
+    #[tokio::test]
+    async fn test_dating_an_undated_duty_makes_it_due() {
+        let repo = TransferDutyRepoForSql::new(setup().await);
+        seed(&repo).await;
+        let id = Urn::from_str("urn:duty:undated-delete").unwrap();
+
+        let edit_model = EditTransferDutyModel {
+            due_at: Some((Utc::now() - Duration::minutes(1)).fixed_offset()),
+            ..Default::default()
+        };
+        let dated = repo.put_transfer_duty(&id, &edit_model).await.unwrap();
+        assert_eq!(dated.state, "PENDING");
+        assert!(dated.due_at.is_some());
+
+        let states = vec!["PENDING".to_string()];
+        let execution_kinds = vec!["MANUAL".to_string()];
+        let undated = repo.get_undated_duties(&states, &execution_kinds).await.unwrap();
+        assert!(undated.is_empty());
+        let due = repo.get_duties_due_before(&states, &execution_kinds, &Utc::now()).await.unwrap();
+        let mut ids = ids(due);
+        ids.sort();
+        assert_eq!(ids, vec!["urn:duty:dated-delete", "urn:duty:undated-delete"]);
+    }
+}